@@ -0,0 +1,66 @@
+-- ===========================================
+-- Partition lifecycle management
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Audit trail / approval workflow for DROP PARTITION and partition management permissions
+
+CREATE TABLE IF NOT EXISTS partition_drop_requests (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    cluster_id INTEGER NOT NULL,
+    organization_id INTEGER,
+
+    database_name VARCHAR(255) NOT NULL,
+    table_name VARCHAR(255) NOT NULL,
+    partitions TEXT NOT NULL,           -- JSON array of partition names
+    statements TEXT NOT NULL,           -- JSON array of statement batches
+    reason TEXT,
+
+    -- 'pending' | 'rejected' | 'executing' | 'completed' | 'failed'
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    requested_by INTEGER NOT NULL,
+    requested_by_name VARCHAR(255) NOT NULL,
+    approver_id INTEGER,
+    approver_name VARCHAR(255),
+    approval_comment TEXT,
+
+    execution_result TEXT,              -- JSON array of per-statement results
+    executed_at TIMESTAMP,
+
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+
+    FOREIGN KEY (cluster_id) REFERENCES clusters(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_pdr_cluster ON partition_drop_requests(cluster_id);
+CREATE INDEX IF NOT EXISTS idx_pdr_status ON partition_drop_requests(status);
+CREATE INDEX IF NOT EXISTS idx_pdr_created_at ON partition_drop_requests(created_at DESC);
+
+-- Menu and API permissions
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('menu:cluster-ops:partitions', '分区管理', 'menu', 'cluster-ops:partitions', 'view', '查看分区生命周期管理'),
+('api:clusters:partitions:dynamic', '查询动态分区配置', 'api', 'clusters', 'partitions:dynamic', 'GET /api/clusters/partitions/dynamic'),
+('api:clusters:partitions:ttl:candidates', '查询缺少TTL的表', 'api', 'clusters', 'partitions:ttl:candidates', 'GET /api/clusters/partitions/ttl-candidates'),
+('api:clusters:partitions:drop:preview', '预览删除分区', 'api', 'clusters', 'partitions:drop:preview', 'POST /api/clusters/partitions/drop/preview'),
+('api:clusters:partitions:drop', '删除分区', 'api', 'clusters', 'partitions:drop', 'POST /api/clusters/partitions/drop'),
+('api:clusters:partitions:drop:requests', '查询删除分区记录', 'api', 'clusters', 'partitions:drop:requests', 'GET /api/clusters/partitions/drop/requests'),
+('api:clusters:partitions:drop:approve', '审批删除分区', 'api', 'clusters', 'partitions:drop:approve', 'POST /api/clusters/partitions/drop/approve'),
+('api:clusters:partitions:drop:reject', '驳回删除分区', 'api', 'clusters', 'partitions:drop:reject', 'POST /api/clusters/partitions/drop/reject');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:cluster-ops')
+WHERE code = 'menu:cluster-ops:partitions';
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:cluster-ops:partitions')
+WHERE code LIKE 'api:clusters:partitions:%';
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code = 'menu:cluster-ops:partitions' OR code LIKE 'api:clusters:partitions:%';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code = 'menu:cluster-ops:partitions' OR code LIKE 'api:clusters:partitions:%';
@@ -0,0 +1,52 @@
+-- ===========================================
+-- Load job monitoring
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Hourly load throughput / failure history and load monitoring permissions
+
+CREATE TABLE IF NOT EXISTS load_job_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    cluster_id INTEGER NOT NULL,
+    bucket_start VARCHAR(19) NOT NULL,     -- Cluster local time, 'yyyy-MM-dd HH:00:00'
+    job_type VARCHAR(32) NOT NULL,         -- BROKER, INSERT, STREAM_LOAD, SPARK, ...
+    finished_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    loaded_rows INTEGER NOT NULL DEFAULT 0,
+    filtered_rows INTEGER NOT NULL DEFAULT 0,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+
+    UNIQUE (cluster_id, bucket_start, job_type),
+    FOREIGN KEY (cluster_id) REFERENCES clusters(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_load_history_cluster_time ON load_job_history(cluster_id, updated_at);
+
+-- Menu and API permissions
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('menu:queries:loads', '导入任务', 'menu', 'queries', 'view', '查看导入任务监控'),
+('api:clusters:loads', '查询导入任务', 'api', 'clusters', 'loads', 'GET /api/clusters/loads'),
+('api:clusters:loads:error:sample', '查看导入错误数据', 'api', 'clusters', 'loads:error:sample', 'GET /api/clusters/loads/error-sample'),
+('api:clusters:loads:routine', '查询Routine Load', 'api', 'clusters', 'loads:routine', 'GET /api/clusters/loads/routine'),
+('api:clusters:loads:routine:control', '暂停/恢复/停止Routine Load', 'api', 'clusters', 'loads:routine:control', 'POST /api/clusters/loads/routine/control'),
+('api:clusters:loads:history', '导入历史趋势', 'api', 'clusters', 'loads:history', 'GET /api/clusters/loads/history');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:queries')
+WHERE code = 'menu:queries:loads';
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:queries:loads')
+WHERE code IN (
+    'api:clusters:loads', 'api:clusters:loads:error:sample', 'api:clusters:loads:routine',
+    'api:clusters:loads:routine:control', 'api:clusters:loads:history'
+);
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code = 'menu:queries:loads' OR code LIKE 'api:clusters:loads%';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code = 'menu:queries:loads' OR code LIKE 'api:clusters:loads%';
@@ -0,0 +1,47 @@
+-- ===========================================
+-- Routine Load offset history
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Periodic Routine Load progress / lag snapshots used for lag trends and stall alerts
+
+CREATE TABLE IF NOT EXISTS routine_load_snapshots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    cluster_id INTEGER NOT NULL,
+    job_id VARCHAR(64) NOT NULL,
+    job_name VARCHAR(255) NOT NULL,
+    database_name VARCHAR(255) NOT NULL,
+    table_name VARCHAR(255) NOT NULL DEFAULT '',
+    state VARCHAR(32) NOT NULL,            -- NEED_SCHEDULE, RUNNING, PAUSED, ...
+    total_lag INTEGER,
+    loaded_rows INTEGER,
+    error_rows INTEGER,
+    partitions TEXT NOT NULL DEFAULT '[]', -- JSON: per Kafka partition offsets and lag
+    reason_of_state_changed TEXT,
+    stalled BOOLEAN NOT NULL DEFAULT 0,    -- Offsets not advancing while lag is pending
+    collected_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    FOREIGN KEY (cluster_id) REFERENCES clusters(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_routine_load_snapshots_job ON routine_load_snapshots(cluster_id, job_id, collected_at);
+CREATE INDEX IF NOT EXISTS idx_routine_load_snapshots_name ON routine_load_snapshots(cluster_id, database_name, job_name, collected_at);
+CREATE INDEX IF NOT EXISTS idx_routine_load_snapshots_time ON routine_load_snapshots(collected_at);
+
+-- API permissions
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:loads:routine:history', 'Routine Load延迟趋势', 'api', 'clusters', 'loads:routine:history', 'GET /api/clusters/loads/routine/history'),
+('api:clusters:loads:routine:alerts', 'Routine Load告警', 'api', 'clusters', 'loads:routine:alerts', 'GET /api/clusters/loads/routine/alerts');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:queries:loads')
+WHERE code IN ('api:clusters:loads:routine:history', 'api:clusters:loads:routine:alerts');
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code IN ('api:clusters:loads:routine:history', 'api:clusters:loads:routine:alerts');
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code IN ('api:clusters:loads:routine:history', 'api:clusters:loads:routine:alerts');
@@ -0,0 +1,30 @@
+-- ===========================================
+-- Compaction management
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Menu and API permissions for manual compaction, BE compaction status and tuning
+
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('menu:cluster-ops:compaction', 'Compaction管理', 'menu', 'cluster-ops:compaction', 'view', '查看和调优Compaction'),
+('api:clusters:compaction:trigger', '手动触发Compaction', 'api', 'clusters', 'compaction:trigger', 'POST /api/clusters/compaction/trigger'),
+('api:clusters:compaction:backends', '查询BE Compaction状态', 'api', 'clusters', 'compaction:backends', 'GET /api/clusters/compaction/backends'),
+('api:clusters:compaction:config', '调整Compaction参数', 'api', 'clusters', 'compaction:config', 'POST /api/clusters/compaction/config'),
+('api:clusters:compaction:pressure', 'Compaction压力趋势', 'api', 'clusters', 'compaction:pressure', 'GET /api/clusters/compaction/pressure');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:cluster-ops')
+WHERE code = 'menu:cluster-ops:compaction';
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:cluster-ops:compaction')
+WHERE code LIKE 'api:clusters:compaction:%';
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code = 'menu:cluster-ops:compaction' OR code LIKE 'api:clusters:compaction:%';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code = 'menu:cluster-ops:compaction' OR code LIKE 'api:clusters:compaction:%';
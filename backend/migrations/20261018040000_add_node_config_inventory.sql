@@ -0,0 +1,48 @@
+-- ===========================================
+-- Node config inventory
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Track FE / BE config changes per node (persist flag, rollback) and add inventory permissions
+
+CREATE TABLE IF NOT EXISTS node_config_changes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    cluster_id INTEGER NOT NULL,
+    node_type VARCHAR(8) NOT NULL,         -- FE, BE
+    node VARCHAR(255) NOT NULL,            -- host:port
+    config_name VARCHAR(255) NOT NULL,
+    old_value TEXT,                        -- NULL when the previous value could not be read
+    new_value TEXT NOT NULL,
+    persisted BOOLEAN NOT NULL DEFAULT 0,  -- Written to fe.conf / be.conf, survives restarts
+    status VARCHAR(20) NOT NULL,           -- applied, failed, rolled_back
+    error_message TEXT,
+    changed_by VARCHAR(100),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    rolled_back_at TIMESTAMP,
+
+    FOREIGN KEY (cluster_id) REFERENCES clusters(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_node_config_changes_cluster ON node_config_changes(cluster_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_node_config_changes_name ON node_config_changes(cluster_id, config_name);
+
+-- API permissions
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:configs:nodes', '查看节点配置清单', 'api', 'clusters', 'configs:nodes', 'GET /api/clusters/configs/nodes'),
+('api:clusters:configs:divergence', '查看配置不一致', 'api', 'clusters', 'configs:divergence', 'GET /api/clusters/configs/divergence'),
+('api:clusters:configs:apply', '修改节点配置', 'api', 'clusters', 'configs:apply', 'POST /api/clusters/configs/apply'),
+('api:clusters:configs:changes', '查询配置变更记录', 'api', 'clusters', 'configs:changes', 'GET /api/clusters/configs/changes'),
+('api:clusters:configs:rollback', '回滚配置变更', 'api', 'clusters', 'configs:rollback', 'POST /api/clusters/configs/rollback');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:variables')
+WHERE code LIKE 'api:clusters:configs:%';
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code LIKE 'api:clusters:configs:%';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code LIKE 'api:clusters:configs:%';
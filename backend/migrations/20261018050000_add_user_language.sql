@@ -0,0 +1,8 @@
+-- ===========================================
+-- User language preference
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Store the preferred UI / diagnostics language per user ('zh' or 'en').
+--          NULL means "follow the browser Accept-Language header".
+
+ALTER TABLE users ADD COLUMN language VARCHAR(10);
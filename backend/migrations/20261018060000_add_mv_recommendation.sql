@@ -0,0 +1,22 @@
+-- ===========================================
+-- Materialized view recommendation
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Permission for workload-based materialized view recommendations
+
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:materialized_views:recommendations', '物化视图推荐', 'api', 'clusters', 'materialized_views:recommendations', 'GET /api/clusters/materialized_views/recommendations');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:materialized-views')
+WHERE code = 'api:clusters:materialized_views:recommendations';
+
+-- Grant new permission to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code = 'api:clusters:materialized_views:recommendations';
+
+-- Grant new permission to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code = 'api:clusters:materialized_views:recommendations';
@@ -0,0 +1,48 @@
+-- ===========================================
+-- Materialized view refresh history
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: MV refresh task runs kept beyond the FE retention, for duration trends and failure alerts
+
+CREATE TABLE IF NOT EXISTS mv_refresh_runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    cluster_id INTEGER NOT NULL,
+    task_run_id VARCHAR(128) NOT NULL,      -- task_runs.QUERY_ID (StarRocks) / TaskId (Doris)
+    task_name VARCHAR(255) NOT NULL,
+    database_name VARCHAR(255) NOT NULL,
+    mv_name VARCHAR(255) NOT NULL,
+    state VARCHAR(32) NOT NULL,             -- PENDING, RUNNING, SUCCESS, FAILED, ...
+    create_time TIMESTAMP,                  -- Cluster clock
+    finish_time TIMESTAMP,
+    duration_ms INTEGER,
+    error_message TEXT,
+    refreshed_partitions TEXT NOT NULL DEFAULT '[]', -- JSON array of MV partition names
+    refreshed_rows INTEGER,
+    collected_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    UNIQUE (cluster_id, task_run_id),
+    FOREIGN KEY (cluster_id) REFERENCES clusters(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_mv_refresh_runs_mv ON mv_refresh_runs(cluster_id, database_name, mv_name, create_time);
+CREATE INDEX IF NOT EXISTS idx_mv_refresh_runs_state ON mv_refresh_runs(cluster_id, state);
+CREATE INDEX IF NOT EXISTS idx_mv_refresh_runs_time ON mv_refresh_runs(collected_at);
+
+-- API permissions
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:materialized_views:refresh_history', '物化视图刷新历史', 'api', 'clusters', 'materialized_views:refresh_history', 'GET /api/clusters/materialized_views/:mv_name/refresh_history'),
+('api:clusters:materialized_views:refresh_alerts', '物化视图刷新告警', 'api', 'clusters', 'materialized_views:refresh_alerts', 'GET /api/clusters/materialized_views/refresh_alerts');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:materialized-views')
+WHERE code IN ('api:clusters:materialized_views:refresh_history', 'api:clusters:materialized_views:refresh_alerts');
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code IN ('api:clusters:materialized_views:refresh_history', 'api:clusters:materialized_views:refresh_alerts');
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code IN ('api:clusters:materialized_views:refresh_history', 'api:clusters:materialized_views:refresh_alerts');
@@ -0,0 +1,23 @@
+-- ===========================================
+-- Materialized view usage analysis
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Rewrite hit rate and refresh cost per MV, to find views worth dropping
+
+-- API permissions
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:materialized_views:usage', '物化视图使用分析', 'api', 'clusters', 'materialized_views:usage', 'GET /api/clusters/materialized_views/usage');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:materialized-views')
+WHERE code = 'api:clusters:materialized_views:usage';
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code = 'api:clusters:materialized_views:usage';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code = 'api:clusters:materialized_views:usage';
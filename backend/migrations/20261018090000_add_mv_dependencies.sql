@@ -0,0 +1,23 @@
+-- ===========================================
+-- Materialized view dependency graph
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Cross-database DAG of MVs and base tables with staleness of each MV
+
+-- API permissions
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:materialized_views:dependencies', '物化视图依赖关系', 'api', 'clusters', 'materialized_views:dependencies', 'GET /api/clusters/materialized_views/dependencies');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:materialized-views')
+WHERE code = 'api:clusters:materialized_views:dependencies';
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code = 'api:clusters:materialized_views:dependencies';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code = 'api:clusters:materialized_views:dependencies';
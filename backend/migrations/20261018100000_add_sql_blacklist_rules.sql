@@ -0,0 +1,74 @@
+-- ===========================================
+-- Managed SQL blacklist rules
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Keep blacklist rules (owner, reason, expiry) in Stellar, track their apply state per
+-- cluster so they can be re-applied after FE restarts and synced across clusters
+
+CREATE TABLE IF NOT EXISTS sql_blacklist_rules (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    organization_id INTEGER,
+    pattern TEXT NOT NULL,
+    reason TEXT,
+    owner_id INTEGER NOT NULL,
+    owner_name VARCHAR(255) NOT NULL,
+    expires_at TIMESTAMP,                   -- UTC, rule is removed from clusters afterwards
+    enabled BOOLEAN NOT NULL DEFAULT 1,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_sql_blacklist_rules_org ON sql_blacklist_rules(organization_id);
+
+CREATE TABLE IF NOT EXISTS sql_blacklist_rule_targets (
+    rule_id INTEGER NOT NULL,
+    cluster_id INTEGER NOT NULL,
+    remote_id VARCHAR(255),                 -- Blacklist ID (StarRocks) / block rule name (Doris)
+    applied_pattern TEXT,                   -- Pattern currently on the cluster
+    -- 'pending' | 'applied' | 'removed' | 'failed'
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    last_error TEXT,
+    detached BOOLEAN NOT NULL DEFAULT 0,    -- Cluster dropped from the rule, removal pending
+    synced_at TIMESTAMP,
+
+    PRIMARY KEY (rule_id, cluster_id),
+    FOREIGN KEY (rule_id) REFERENCES sql_blacklist_rules(id) ON DELETE CASCADE,
+    FOREIGN KEY (cluster_id) REFERENCES clusters(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_sql_blacklist_targets_cluster ON sql_blacklist_rule_targets(cluster_id);
+
+-- API permissions
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:sql:blacklist:test', '测试SQL黑名单规则', 'api', 'clusters', 'sql:blacklist:test', 'POST /api/clusters/sql-blacklist/test'),
+('api:clusters:sql:blacklist:rules', '查看托管SQL黑名单规则', 'api', 'clusters', 'sql:blacklist:rules', 'GET /api/clusters/sql-blacklist/rules'),
+('api:clusters:sql:blacklist:rules:create', '创建托管SQL黑名单规则', 'api', 'clusters', 'sql:blacklist:rules:create', 'POST /api/clusters/sql-blacklist/rules'),
+('api:clusters:sql:blacklist:rules:update', '修改托管SQL黑名单规则', 'api', 'clusters', 'sql:blacklist:rules:update', 'PUT /api/clusters/sql-blacklist/rules/:id'),
+('api:clusters:sql:blacklist:rules:delete', '删除托管SQL黑名单规则', 'api', 'clusters', 'sql:blacklist:rules:delete', 'DELETE /api/clusters/sql-blacklist/rules/:id'),
+('api:clusters:sql:blacklist:rules:sync', '同步托管SQL黑名单规则', 'api', 'clusters', 'sql:blacklist:rules:sync', 'POST /api/clusters/sql-blacklist/rules/:id/sync');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:queries:blacklist')
+WHERE code IN (
+    'api:clusters:sql:blacklist:test', 'api:clusters:sql:blacklist:rules',
+    'api:clusters:sql:blacklist:rules:create', 'api:clusters:sql:blacklist:rules:update',
+    'api:clusters:sql:blacklist:rules:delete', 'api:clusters:sql:blacklist:rules:sync'
+);
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code IN (
+    'api:clusters:sql:blacklist:test', 'api:clusters:sql:blacklist:rules',
+    'api:clusters:sql:blacklist:rules:create', 'api:clusters:sql:blacklist:rules:update',
+    'api:clusters:sql:blacklist:rules:delete', 'api:clusters:sql:blacklist:rules:sync'
+);
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code IN (
+    'api:clusters:sql:blacklist:test', 'api:clusters:sql:blacklist:rules',
+    'api:clusters:sql:blacklist:rules:create', 'api:clusters:sql:blacklist:rules:update',
+    'api:clusters:sql:blacklist:rules:delete', 'api:clusters:sql:blacklist:rules:sync'
+);
@@ -0,0 +1,52 @@
+-- ===========================================
+-- Resource group / workload group management
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Per-group usage history recorded by the metrics collector and resource group
+-- management permissions
+
+CREATE TABLE IF NOT EXISTS resource_group_usage_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    cluster_id INTEGER NOT NULL,
+    group_name VARCHAR(255) NOT NULL,
+    running_queries INTEGER NOT NULL DEFAULT 0,
+    queued_queries INTEGER NOT NULL DEFAULT 0,
+    cpu_cores_used REAL,                    -- StarRocks
+    cpu_usage_pct REAL,                     -- Averaged over BEs
+    mem_used_bytes INTEGER,
+    collected_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (cluster_id) REFERENCES clusters(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_rg_usage_cluster_time ON resource_group_usage_history(cluster_id, collected_at);
+CREATE INDEX IF NOT EXISTS idx_rg_usage_group ON resource_group_usage_history(cluster_id, group_name, collected_at);
+
+-- Menu and API permissions
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('menu:cluster-ops:resource-groups', '资源组管理', 'menu', 'cluster-ops:resource-groups', 'view', '查看资源组 / Workload Group'),
+('api:clusters:resource:groups', '查询资源组', 'api', 'clusters', 'resource:groups', 'GET /api/clusters/resource-groups'),
+('api:clusters:resource:groups:create', '创建资源组', 'api', 'clusters', 'resource:groups:create', 'POST /api/clusters/resource-groups'),
+('api:clusters:resource:groups:alter', '修改资源组', 'api', 'clusters', 'resource:groups:alter', 'PUT /api/clusters/resource-groups/:name'),
+('api:clusters:resource:groups:drop', '删除资源组', 'api', 'clusters', 'resource:groups:drop', 'DELETE /api/clusters/resource-groups/:name'),
+('api:clusters:resource:groups:usage', '查询资源组实时负载', 'api', 'clusters', 'resource:groups:usage', 'GET /api/clusters/resource-groups/usage'),
+('api:clusters:resource:groups:history', '查询资源组历史负载', 'api', 'clusters', 'resource:groups:history', 'GET /api/clusters/resource-groups/history');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:cluster-ops')
+WHERE code = 'menu:cluster-ops:resource-groups';
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:cluster-ops:resource-groups')
+WHERE code = 'api:clusters:resource:groups' OR code LIKE 'api:clusters:resource:groups:%';
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code IN ('menu:cluster-ops:resource-groups', 'api:clusters:resource:groups')
+   OR code LIKE 'api:clusters:resource:groups:%';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code IN ('menu:cluster-ops:resource-groups', 'api:clusters:resource:groups')
+   OR code LIKE 'api:clusters:resource:groups:%';
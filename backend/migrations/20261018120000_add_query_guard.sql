@@ -0,0 +1,79 @@
+-- ===========================================
+-- Query guard
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Policies that flag or kill runaway queries (elapsed time, scan bytes, memory, users,
+-- tables) and the log of every action taken
+
+CREATE TABLE IF NOT EXISTS query_guard_policies (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    organization_id INTEGER,
+    cluster_id INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    mode VARCHAR(20) NOT NULL DEFAULT 'dry_run',  -- 'dry_run' | 'enforce'
+    enabled BOOLEAN NOT NULL DEFAULT 1,
+    max_elapsed_seconds INTEGER,
+    max_scan_bytes INTEGER,
+    max_memory_bytes INTEGER,
+    users TEXT NOT NULL DEFAULT '[]',             -- JSON array, guarded users (all when empty)
+    exempt_users TEXT NOT NULL DEFAULT '[]',      -- JSON array, never touched
+    tables TEXT NOT NULL DEFAULT '[]',            -- JSON array, guarded tables (all when empty)
+    notify_webhook TEXT,
+    created_by VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (cluster_id) REFERENCES clusters(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_query_guard_policies_cluster ON query_guard_policies(cluster_id);
+
+CREATE TABLE IF NOT EXISTS query_guard_actions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    policy_id INTEGER NOT NULL,
+    policy_name VARCHAR(255) NOT NULL,
+    cluster_id INTEGER NOT NULL,
+    query_id VARCHAR(64) NOT NULL,
+    user VARCHAR(255) NOT NULL,
+    database VARCHAR(255) NOT NULL DEFAULT '',
+    sql TEXT NOT NULL DEFAULT '',
+    elapsed_seconds REAL,
+    scan_bytes INTEGER,
+    memory_bytes INTEGER,
+    reasons TEXT NOT NULL,
+    action VARCHAR(20) NOT NULL,                  -- 'flagged' | 'killed' | 'kill_failed'
+    error TEXT,
+    notified BOOLEAN NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (policy_id) REFERENCES query_guard_policies(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_query_guard_actions_policy ON query_guard_actions(policy_id, query_id);
+CREATE INDEX IF NOT EXISTS idx_query_guard_actions_time ON query_guard_actions(created_at);
+
+-- Menu and API permissions
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('menu:queries:guard', '大查询熔断', 'menu', 'queries:guard', 'view', '查看大查询熔断策略'),
+('api:clusters:query:guard:policies', '查询熔断策略', 'api', 'clusters', 'query:guard:policies', 'GET /api/clusters/query-guard/policies'),
+('api:clusters:query:guard:policies:create', '创建熔断策略', 'api', 'clusters', 'query:guard:policies:create', 'POST /api/clusters/query-guard/policies'),
+('api:clusters:query:guard:policies:update', '修改熔断策略', 'api', 'clusters', 'query:guard:policies:update', 'PUT /api/clusters/query-guard/policies/:id'),
+('api:clusters:query:guard:policies:delete', '删除熔断策略', 'api', 'clusters', 'query:guard:policies:delete', 'DELETE /api/clusters/query-guard/policies/:id'),
+('api:clusters:query:guard:preview', '预览熔断策略', 'api', 'clusters', 'query:guard:preview', 'POST /api/clusters/query-guard/preview'),
+('api:clusters:query:guard:actions', '查询熔断记录', 'api', 'clusters', 'query:guard:actions', 'GET /api/clusters/query-guard/actions');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:queries')
+WHERE code = 'menu:queries:guard';
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:queries:guard')
+WHERE code LIKE 'api:clusters:query:guard:%';
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code = 'menu:queries:guard' OR code LIKE 'api:clusters:query:guard:%';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code = 'menu:queries:guard' OR code LIKE 'api:clusters:query:guard:%';
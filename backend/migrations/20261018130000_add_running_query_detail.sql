@@ -0,0 +1,22 @@
+-- ===========================================
+-- Running query detail
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Permission for the running-query detail view (in-flight profile and progress)
+
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:queries:detail', '查看运行中查询详情', 'api', 'clusters', 'queries:detail', 'GET /api/clusters/queries/:query_id/detail');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:queries:execution')
+WHERE code = 'api:clusters:queries:detail';
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code = 'api:clusters:queries:detail';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code = 'api:clusters:queries:detail';
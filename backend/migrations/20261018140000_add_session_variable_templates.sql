@@ -0,0 +1,42 @@
+-- ===========================================
+-- Session variable templates
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Named session-variable templates per organization ("big ETL", "dashboard", ...)
+-- selectable in the SQL editor, and one-click apply of parameter suggestions
+
+CREATE TABLE IF NOT EXISTS session_variable_templates (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    organization_id INTEGER,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    variables TEXT NOT NULL DEFAULT '{}',        -- JSON object, variable name -> value
+    created_by VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (organization_id) REFERENCES organizations(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_session_variable_templates_org ON session_variable_templates(organization_id, name);
+
+-- API permissions, used from the SQL editor
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:session:templates', '查询会话变量模板', 'api', 'clusters', 'session:templates', 'GET /api/clusters/session-templates'),
+('api:clusters:session:templates:create', '创建会话变量模板', 'api', 'clusters', 'session:templates:create', 'POST /api/clusters/session-templates'),
+('api:clusters:session:templates:update', '修改会话变量模板', 'api', 'clusters', 'session:templates:update', 'PUT /api/clusters/session-templates/:id'),
+('api:clusters:session:templates:delete', '删除会话变量模板', 'api', 'clusters', 'session:templates:delete', 'DELETE /api/clusters/session-templates/:id'),
+('api:clusters:session:variables:apply', '应用参数建议', 'api', 'clusters', 'session:variables:apply', 'POST /api/clusters/session-variables/apply');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:queries:execution')
+WHERE code LIKE 'api:clusters:session:templates%' OR code = 'api:clusters:session:variables:apply';
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code LIKE 'api:clusters:session:templates%' OR code = 'api:clusters:session:variables:apply';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code LIKE 'api:clusters:session:templates%' OR code = 'api:clusters:session:variables:apply';
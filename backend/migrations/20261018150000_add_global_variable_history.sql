@@ -0,0 +1,58 @@
+-- ===========================================
+-- Global variable history
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Snapshots of global variables and the per-variable diffs between them, including who
+-- changed a variable and changes made outside Stellar, for history and rollback
+
+CREATE TABLE IF NOT EXISTS global_variable_snapshots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    cluster_id INTEGER NOT NULL,
+    variables TEXT NOT NULL,                      -- JSON object, variable name -> value
+    changed_count INTEGER NOT NULL DEFAULT 0,
+    created_by VARCHAR(255),
+    created_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (cluster_id) REFERENCES clusters(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_global_variable_snapshots_cluster ON global_variable_snapshots(cluster_id, created_at);
+
+CREATE TABLE IF NOT EXISTS global_variable_changes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    cluster_id INTEGER NOT NULL,
+    snapshot_id INTEGER NOT NULL,
+    variable_name VARCHAR(255) NOT NULL,
+    old_value TEXT,                               -- NULL when the variable is new
+    new_value TEXT,                               -- NULL when the variable disappeared
+    source VARCHAR(20) NOT NULL,                  -- 'stellar' | 'rollback' | 'out_of_band'
+    changed_by VARCHAR(255),
+    changed_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (snapshot_id) REFERENCES global_variable_snapshots(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_global_variable_changes_variable ON global_variable_changes(cluster_id, variable_name);
+CREATE INDEX IF NOT EXISTS idx_global_variable_changes_snapshot ON global_variable_changes(snapshot_id);
+
+-- API permissions
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:variables:history', '查询变量变更历史', 'api', 'clusters', 'variables:history', 'GET /api/clusters/variables/history'),
+('api:clusters:variables:snapshots', '查询变量快照', 'api', 'clusters', 'variables:snapshots', 'GET /api/clusters/variables/snapshots'),
+('api:clusters:variables:snapshots:create', '创建变量快照', 'api', 'clusters', 'variables:snapshots:create', 'POST /api/clusters/variables/snapshots'),
+('api:clusters:variables:rollback', '回滚全局变量', 'api', 'clusters', 'variables:rollback', 'POST /api/clusters/variables/snapshots/:id/rollback');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:variables')
+WHERE code IN ('api:clusters:variables:history', 'api:clusters:variables:snapshots',
+               'api:clusters:variables:snapshots:create', 'api:clusters:variables:rollback');
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code IN ('api:clusters:variables:history', 'api:clusters:variables:snapshots',
+               'api:clusters:variables:snapshots:create', 'api:clusters:variables:rollback');
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code IN ('api:clusters:variables:history', 'api:clusters:variables:snapshots',
+               'api:clusters:variables:snapshots:create', 'api:clusters:variables:rollback');
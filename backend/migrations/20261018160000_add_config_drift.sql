@@ -0,0 +1,23 @@
+-- ===========================================
+-- Cross-cluster config drift
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Compare global variables and FE config of several clusters and export the script
+-- converging them onto a reference cluster
+
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:config:drift', '跨集群配置比对', 'api', 'clusters', 'config:drift', 'POST /api/clusters/config-drift');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:variables')
+WHERE code = 'api:clusters:config:drift';
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code = 'api:clusters:config:drift';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code = 'api:clusters:config:drift';
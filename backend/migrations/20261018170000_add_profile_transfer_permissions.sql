@@ -0,0 +1,24 @@
+-- ===========================================
+-- Profile export / import permissions
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Seed the permissions checked by the profile export and import endpoints, which
+-- were registered without them and denied every request
+
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:profiles:export', '导出Profile', 'api', 'clusters', 'profiles:export', 'POST /api/clusters/profiles/export'),
+('api:clusters:profiles:import', '导入Profile', 'api', 'clusters', 'profiles:import', 'POST /api/clusters/profiles/import');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:queries:profiles')
+WHERE code IN ('api:clusters:profiles:export', 'api:clusters:profiles:import');
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code IN ('api:clusters:profiles:export', 'api:clusters:profiles:import');
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code IN ('api:clusters:profiles:export', 'api:clusters:profiles:import');
@@ -0,0 +1,22 @@
+-- ===========================================
+-- SQL lint permission
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Seed the permission checked by the rule-based EXPLAIN lint of the SQL editor
+
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:sql:lint', 'SQL执行计划检查', 'api', 'clusters', 'sql:lint', 'POST /api/clusters/:cluster_id/sql/lint');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:queries:execution')
+WHERE code = 'api:clusters:sql:lint';
+
+-- Grant new permissions to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code = 'api:clusters:sql:lint';
+
+-- Grant new permissions to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code = 'api:clusters:sql:lint';
@@ -0,0 +1,22 @@
+-- ===========================================
+-- Materialized view recommendation creation
+-- ===========================================
+-- Date: 2026-10-18
+-- Purpose: Permission for creating a recommended materialized view from its candidate
+
+INSERT OR IGNORE INTO permissions (code, name, type, resource, action, description) VALUES
+('api:clusters:materialized_views:recommendations:create', '创建推荐物化视图', 'api', 'clusters', 'materialized_views:recommendations:create', 'POST /api/clusters/materialized_views/recommendations/:pattern_id');
+
+UPDATE permissions
+SET parent_id = (SELECT id FROM permissions WHERE code = 'menu:materialized-views')
+WHERE code = 'api:clusters:materialized_views:recommendations:create';
+
+-- Grant new permission to admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='admin'), id FROM permissions
+WHERE code = 'api:clusters:materialized_views:recommendations:create';
+
+-- Grant new permission to super_admin role
+INSERT OR IGNORE INTO role_permissions (role_id, permission_id)
+SELECT (SELECT id FROM roles WHERE code='super_admin'), id FROM permissions
+WHERE code = 'api:clusters:materialized_views:recommendations:create';
@@ -0,0 +1,373 @@
+//! stellar-profile - batch query profile analysis from the command line
+//!
+//! Runs the same analyzer as the web UI over profile files, directories or stdin
+//! and prints the diagnostics as a table, JSON or Markdown.
+//!
+//! Exit codes: `0` no findings, `1` findings at or above `--min-severity`,
+//! `2` at least one input could not be read or parsed.
+
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use stellar::services::profile_analyzer::{
+    AnalysisContext, DiagnosticResult, Locale, ProfileAnalysisResponse,
+    analyze_profile_with_context,
+};
+
+#[derive(Parser, Debug)]
+#[command(name = "stellar-profile")]
+#[command(version, about = "Analyze StarRocks/Doris query profiles offline")]
+struct Args {
+    /// Profile files or directories (searched recursively for .txt/.profile/.log files);
+    /// `-` reads stdin
+    #[arg(required = true, value_name = "PATH")]
+    paths: Vec<PathBuf>,
+
+    /// Output format
+    #[arg(long, short, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+
+    /// Only report diagnostics of this severity or higher
+    #[arg(long, value_enum, default_value_t = Severity::Info)]
+    min_severity: Severity,
+
+    /// Only report these rules, by id or id prefix (e.g. `S001,J`)
+    #[arg(long, value_delimiter = ',', value_name = "RULES")]
+    rules: Vec<String>,
+
+    /// Language of messages and suggestions (zh, en)
+    #[arg(long, default_value = "zh", value_parser = parse_locale)]
+    lang: Locale,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+    Markdown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Map the analyzer's `Info`/`Warning`/`Error` strings
+    fn from_diagnostic(severity: &str) -> Self {
+        match severity {
+            "Error" => Severity::Error,
+            "Warning" => Severity::Warning,
+            _ => Severity::Info,
+        }
+    }
+}
+
+/// File extensions picked up when expanding a directory
+const PROFILE_EXTENSIONS: &[&str] = &["txt", "profile", "log"];
+
+/// Analysis result of one input, trimmed to what the CLI reports
+#[derive(Debug, Serialize)]
+struct FileReport {
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    performance_score: Option<f64>,
+    diagnostics: Vec<DiagnosticResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl FileReport {
+    fn failed(path: String, error: String) -> Self {
+        Self {
+            path,
+            query_id: None,
+            total_time: None,
+            performance_score: None,
+            diagnostics: Vec::new(),
+            error: Some(error),
+        }
+    }
+
+    fn from_response(path: String, response: ProfileAnalysisResponse, args: &Args) -> Self {
+        let diagnostics = response
+            .diagnostics
+            .into_iter()
+            .filter(|d| Severity::from_diagnostic(&d.severity) >= args.min_severity)
+            .filter(|d| rule_selected(&d.rule_id, &args.rules))
+            .collect();
+        Self {
+            path,
+            query_id: response.summary.as_ref().map(|s| s.query_id.clone()),
+            total_time: response.summary.as_ref().map(|s| s.total_time.clone()),
+            performance_score: Some(response.performance_score),
+            diagnostics,
+            error: None,
+        }
+    }
+}
+
+fn parse_locale(tag: &str) -> Result<Locale, String> {
+    Locale::parse(tag).ok_or_else(|| format!("unsupported language '{}', use zh or en", tag))
+}
+
+/// Empty filter selects every rule; otherwise match by id or id prefix
+fn rule_selected(rule_id: &str, rules: &[String]) -> bool {
+    rules.is_empty()
+        || rules.iter().any(|r| {
+            let r = r.trim();
+            !r.is_empty() && rule_id.to_uppercase().starts_with(&r.to_uppercase())
+        })
+}
+
+/// Expand directories into the files they contain, sorted for stable output
+fn collect_inputs(paths: &[PathBuf]) -> Vec<PathBuf> {
+    let mut inputs = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            let mut files = Vec::new();
+            collect_dir(path, &mut files);
+            files.sort();
+            inputs.extend(files);
+        } else {
+            inputs.push(path.clone());
+        }
+    }
+    inputs
+}
+
+fn collect_dir(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if hidden {
+            continue;
+        }
+        if path.is_dir() {
+            collect_dir(&path, files);
+        } else if path.is_file()
+            && path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| PROFILE_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()))
+        {
+            files.push(path);
+        }
+    }
+}
+
+fn read_input(path: &Path) -> std::io::Result<String> {
+    if path == Path::new("-") {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+fn analyze(path: &Path, args: &Args) -> FileReport {
+    let name = path.display().to_string();
+    let text = match read_input(path) {
+        Ok(text) => text,
+        Err(e) => return FileReport::failed(name, format!("read failed: {}", e)),
+    };
+    let context = AnalysisContext { locale: args.lang, ..Default::default() };
+    match analyze_profile_with_context(&text, &context) {
+        Ok(response) => FileReport::from_response(name, response, args),
+        Err(e) => FileReport::failed(name, format!("analysis failed: {}", e)),
+    }
+}
+
+// ============================================================================
+// Output
+// ============================================================================
+
+fn render_table(reports: &[FileReport]) -> String {
+    let mut out = String::new();
+    for report in reports {
+        out.push_str(&format!("== {}\n", report.path));
+        if let Some(error) = &report.error {
+            out.push_str(&format!("   ERROR: {}\n\n", error));
+            continue;
+        }
+        out.push_str(&format!(
+            "   query_id: {}  total: {}  score: {:.1}\n",
+            report.query_id.as_deref().unwrap_or("-"),
+            report.total_time.as_deref().unwrap_or("-"),
+            report.performance_score.unwrap_or(0.0)
+        ));
+        if report.diagnostics.is_empty() {
+            out.push_str("   no findings\n\n");
+            continue;
+        }
+
+        let rule_width = report
+            .diagnostics
+            .iter()
+            .map(|d| d.rule_id.len())
+            .max()
+            .unwrap_or(4);
+        let node_width = report
+            .diagnostics
+            .iter()
+            .map(|d| d.node_path.chars().count())
+            .max()
+            .unwrap_or(4);
+        out.push_str(&format!(
+            "   {:<8} {:<rule_width$} {:<node_width$} MESSAGE\n",
+            "SEVERITY", "RULE", "NODE"
+        ));
+        for d in &report.diagnostics {
+            out.push_str(&format!(
+                "   {:<8} {:<rule_width$} {:<node_width$} {}\n",
+                d.severity.to_uppercase(),
+                d.rule_id,
+                d.node_path,
+                d.message
+            ));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn render_markdown(reports: &[FileReport]) -> String {
+    let mut out = String::from("# Profile analysis\n\n");
+    for report in reports {
+        out.push_str(&format!("## {}\n\n", report.path));
+        if let Some(error) = &report.error {
+            out.push_str(&format!("**Error:** {}\n\n", error));
+            continue;
+        }
+        out.push_str(&format!(
+            "- Query ID: `{}`\n- Total time: {}\n- Score: {:.1}\n\n",
+            report.query_id.as_deref().unwrap_or("-"),
+            report.total_time.as_deref().unwrap_or("-"),
+            report.performance_score.unwrap_or(0.0)
+        ));
+        if report.diagnostics.is_empty() {
+            out.push_str("No findings.\n\n");
+            continue;
+        }
+        out.push_str("| Severity | Rule | Node | Message | Suggestions |\n");
+        out.push_str("|---|---|---|---|---|\n");
+        for d in &report.diagnostics {
+            out.push_str(&format!(
+                "| {} | {} {} | {} | {} | {} |\n",
+                d.severity,
+                d.rule_id,
+                escape_cell(&d.rule_name),
+                escape_cell(&d.node_path),
+                escape_cell(&d.message),
+                escape_cell(&d.suggestions.join("<br>"))
+            ));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let reports: Vec<FileReport> = collect_inputs(&args.paths)
+        .iter()
+        .map(|path| analyze(path, &args))
+        .collect();
+
+    let output = match args.format {
+        OutputFormat::Table => render_table(&reports),
+        OutputFormat::Markdown => render_markdown(&reports),
+        OutputFormat::Json => match serde_json::to_string_pretty(&reports) {
+            Ok(json) => json + "\n",
+            Err(e) => {
+                eprintln!("failed to serialize results: {}", e);
+                return ExitCode::from(2);
+            },
+        },
+    };
+    print!("{}", output);
+
+    if reports.is_empty() || reports.iter().any(|r| r.error.is_some()) {
+        ExitCode::from(2)
+    } else if reports.iter().any(|r| !r.diagnostics.is_empty()) {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic(rule_id: &str, severity: &str) -> DiagnosticResult {
+        DiagnosticResult {
+            rule_id: rule_id.to_string(),
+            rule_name: "name".to_string(),
+            severity: severity.to_string(),
+            node_path: "HASH_JOIN (plan_node_id=3)".to_string(),
+            plan_node_id: Some(3),
+            message: "a | b".to_string(),
+            reason: String::new(),
+            suggestions: vec!["fix".to_string()],
+            parameter_suggestions: Vec::new(),
+            threshold_metadata: None,
+        }
+    }
+
+    #[test]
+    fn test_rule_selected_by_id_or_prefix() {
+        assert!(rule_selected("S001", &[]));
+        assert!(rule_selected("S001", &["s001".to_string()]));
+        assert!(rule_selected("J002", &["S".to_string(), "J".to_string()]));
+        assert!(!rule_selected("J002", &["S001".to_string()]));
+    }
+
+    #[test]
+    fn test_severity_order() {
+        assert!(Severity::from_diagnostic("Error") > Severity::from_diagnostic("Warning"));
+        assert!(Severity::from_diagnostic("Warning") > Severity::from_diagnostic("Info"));
+    }
+
+    #[test]
+    fn test_collect_inputs_walks_fixture_directory() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/profiles");
+        let inputs = collect_inputs(std::slice::from_ref(&dir));
+        assert!(inputs.iter().any(|p| p.ends_with("profile1.txt")));
+        assert!(!inputs.iter().any(|p| p.ends_with("profile1.png")));
+        assert!(inputs.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_render_markdown_escapes_cells() {
+        let report = FileReport {
+            path: "q.txt".to_string(),
+            query_id: Some("q1".to_string()),
+            total_time: Some("1s".to_string()),
+            performance_score: Some(80.0),
+            diagnostics: vec![diagnostic("J002", "Warning")],
+            error: None,
+        };
+        let markdown = render_markdown(&[report]);
+        assert!(markdown.contains("| Warning | J002 name |"));
+        assert!(markdown.contains("a \\| b"));
+    }
+}
@@ -0,0 +1,156 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::services::TimeRange;
+use crate::services::compaction_service::{
+    BackendCompactionStatus, CompactionConfigResult, CompactionConfigUpdate,
+    CompactionPressurePoint, CompactionTriggerResult, ManualCompactionRequest,
+};
+use crate::utils::ApiResult;
+
+#[derive(Debug, Deserialize)]
+pub struct CompactionPressureParams {
+    #[serde(default = "default_time_range")]
+    pub time_range: TimeRange,
+}
+
+fn default_time_range() -> TimeRange {
+    TimeRange::Hours24
+}
+
+/// POST /api/clusters/compaction/trigger - Manually compact a tablet or partition
+#[utoipa::path(
+    post,
+    path = "/api/clusters/compaction/trigger",
+    request_body = ManualCompactionRequest,
+    responses(
+        (status = 200, description = "Per-replica trigger results", body = Vec<CompactionTriggerResult>),
+        (status = 400, description = "Invalid target"),
+        (status = 404, description = "Tablet or partition not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Compaction"
+)]
+pub async fn trigger_compaction(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(req): Json<ManualCompactionRequest>,
+) -> ApiResult<Json<Vec<CompactionTriggerResult>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let results = state
+        .compaction_service
+        .trigger_compaction(&cluster, &req)
+        .await?;
+    Ok(Json(results))
+}
+
+/// GET /api/clusters/compaction/backends - Compaction queues and thread settings per BE
+#[utoipa::path(
+    get,
+    path = "/api/clusters/compaction/backends",
+    responses(
+        (status = 200, description = "Per-BE compaction status", body = Vec<BackendCompactionStatus>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Compaction"
+)]
+pub async fn list_backend_compaction_status(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+) -> ApiResult<Json<Vec<BackendCompactionStatus>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let statuses = state
+        .compaction_service
+        .list_backend_status(&cluster)
+        .await?;
+    Ok(Json(statuses))
+}
+
+/// POST /api/clusters/compaction/config - Tune a compaction config via BE update_config
+#[utoipa::path(
+    post,
+    path = "/api/clusters/compaction/config",
+    request_body = CompactionConfigUpdate,
+    responses(
+        (status = 200, description = "Per-BE update results", body = Vec<CompactionConfigResult>),
+        (status = 400, description = "Config is not tunable or value is invalid")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Compaction"
+)]
+pub async fn update_compaction_config(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(req): Json<CompactionConfigUpdate>,
+) -> ApiResult<Json<Vec<CompactionConfigResult>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let results = state
+        .compaction_service
+        .update_config(&cluster, &req)
+        .await?;
+    Ok(Json(results))
+}
+
+/// GET /api/clusters/compaction/pressure - Compaction score history
+#[utoipa::path(
+    get,
+    path = "/api/clusters/compaction/pressure",
+    params(
+        ("time_range" = Option<String>, Query, description = "1h, 6h, 24h or 3d (default: 24h)"),
+    ),
+    responses(
+        (status = 200, description = "Compaction pressure points", body = Vec<CompactionPressurePoint>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Compaction"
+)]
+pub async fn get_compaction_pressure(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<CompactionPressureParams>,
+) -> ApiResult<Json<Vec<CompactionPressurePoint>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let points = state
+        .compaction_service
+        .get_pressure_history(cluster.id, &params.time_range)
+        .await?;
+    Ok(Json(points))
+}
@@ -0,0 +1,92 @@
+use axum::{Json, extract::State};
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::handlers::variables::fetch_frontend_configs;
+use crate::models::Cluster;
+use crate::services::MySQLClient;
+use crate::services::config_drift_service::{
+    ClusterSettings, ConfigDriftReport, ConfigDriftRequest, DriftFilter, MAX_DRIFT_CLUSTERS,
+    build_drift_report,
+};
+use crate::services::global_variable_history_service::fetch_global_variables;
+use crate::utils::{ApiError, ApiResult};
+
+async fn fetch_cluster_settings(state: &AppState, cluster: Cluster) -> ApiResult<ClusterSettings> {
+    let pool = state.mysql_pool_manager.get_pool(&cluster).await?;
+    let mysql_client = MySQLClient::from_pool(pool);
+    let fetch_error = |what: &str, e: ApiError| {
+        ApiError::internal_error(format!("{} of {}: {}", what, cluster.name, e))
+    };
+
+    let variables = fetch_global_variables(&mysql_client)
+        .await
+        .map_err(|e| fetch_error("Global variables", e))?;
+    let configs = fetch_frontend_configs(&mysql_client)
+        .await
+        .map_err(|e| fetch_error("FE config", e))?;
+
+    Ok(ClusterSettings {
+        cluster_id: cluster.id,
+        cluster_name: cluster.name.clone(),
+        cluster_type: cluster.cluster_type,
+        variables,
+        immutable_configs: configs
+            .iter()
+            .filter(|c| c.is_mutable == Some(false))
+            .map(|c| c.name.clone())
+            .collect(),
+        configs: configs.into_iter().map(|c| (c.name, c.value)).collect(),
+    })
+}
+
+/// POST /api/clusters/config-drift - Compare global variables and FE config of clusters
+#[utoipa::path(
+    post,
+    path = "/api/clusters/config-drift",
+    request_body = ConfigDriftRequest,
+    responses(
+        (status = 200, description = "Normalized diff and convergence scripts", body = ConfigDriftReport),
+        (status = 400, description = "Fewer than two or too many clusters"),
+        (status = 403, description = "Cluster outside your organization")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Config Drift"
+)]
+pub async fn compare_config_drift(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(request): Json<ConfigDriftRequest>,
+) -> ApiResult<Json<ConfigDriftReport>> {
+    let mut cluster_ids = Vec::new();
+    for id in &request.cluster_ids {
+        if !cluster_ids.contains(id) {
+            cluster_ids.push(*id);
+        }
+    }
+    if cluster_ids.len() < 2 || cluster_ids.len() > MAX_DRIFT_CLUSTERS {
+        return Err(ApiError::validation_error(format!(
+            "Compare between 2 and {} different clusters",
+            MAX_DRIFT_CLUSTERS
+        )));
+    }
+    let reference_cluster_id = request.reference_cluster_id.unwrap_or(cluster_ids[0]);
+    if !cluster_ids.contains(&reference_cluster_id) {
+        return Err(ApiError::validation_error("The reference cluster must be one of cluster_ids"));
+    }
+
+    let mut settings = Vec::with_capacity(cluster_ids.len());
+    for cluster_id in cluster_ids {
+        let cluster = state.cluster_service.get_cluster(cluster_id).await?;
+        if !org_ctx.is_super_admin && cluster.organization_id != org_ctx.organization_id {
+            return Err(ApiError::forbidden(format!(
+                "Cluster {} does not belong to your organization",
+                cluster.name
+            )));
+        }
+        settings.push(fetch_cluster_settings(&state, cluster).await?);
+    }
+
+    let filter = DriftFilter::new(request.include_volatile, &request.ignore_keys);
+    Ok(Json(build_drift_report(&settings, reference_cluster_id, &filter)))
+}
@@ -0,0 +1,302 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::services::load_job_service::{
+    LoadErrorSample, LoadHistoryPoint, LoadJob, LoadJobFilter, RoutineLoadAction, RoutineLoadJob,
+};
+use crate::services::routine_load_history_service::{RoutineLoadAlert, RoutineLoadSnapshot};
+use crate::utils::ApiResult;
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorSampleParams {
+    pub url: String,
+    #[serde(default = "default_sample_lines")]
+    pub limit: usize,
+}
+
+fn default_sample_lines() -> usize {
+    50
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RoutineLoadParams {
+    pub database: Option<String>,
+    #[serde(default)]
+    pub include_stopped: bool,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RoutineLoadControlRequest {
+    pub database: String,
+    pub name: String,
+    pub action: RoutineLoadAction,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoadHistoryParams {
+    #[serde(default = "default_history_hours")]
+    pub hours: i64,
+    pub job_type: Option<String>,
+}
+
+fn default_history_hours() -> i64 {
+    24
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RoutineLoadHistoryParams {
+    pub database: String,
+    pub job_name: String,
+    #[serde(default = "default_history_hours")]
+    pub hours: i64,
+}
+
+/// GET /api/clusters/loads - List load jobs
+#[utoipa::path(
+    get,
+    path = "/api/clusters/loads",
+    params(
+        ("state" = Option<String>, Query, description = "Filter by state, e.g. LOADING, FINISHED, CANCELLED"),
+        ("job_type" = Option<String>, Query, description = "Filter by type: BROKER, INSERT, STREAM_LOAD, SPARK"),
+        ("database" = Option<String>, Query, description = "Database name filter"),
+        ("limit" = Option<usize>, Query, description = "Maximum jobs (default: 500)"),
+    ),
+    responses(
+        (status = 200, description = "Load jobs", body = Vec<LoadJob>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Loads"
+)]
+pub async fn list_load_jobs(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(filter): Query<LoadJobFilter>,
+) -> ApiResult<Json<Vec<LoadJob>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let jobs = state.load_job_service.list_jobs(&cluster, &filter).await?;
+    Ok(Json(jobs))
+}
+
+/// GET /api/clusters/loads/error-sample - Rejected rows of a load job
+#[utoipa::path(
+    get,
+    path = "/api/clusters/loads/error-sample",
+    params(
+        ("url" = String, Query, description = "Error URL reported by the load job"),
+        ("limit" = Option<usize>, Query, description = "Maximum lines (default: 50)"),
+    ),
+    responses(
+        (status = 200, description = "Rejected row sample", body = LoadErrorSample),
+        (status = 403, description = "URL does not point at a node of the cluster")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Loads"
+)]
+pub async fn get_load_error_sample(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<ErrorSampleParams>,
+) -> ApiResult<Json<LoadErrorSample>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let sample = state
+        .load_job_service
+        .fetch_error_sample(&cluster, &params.url, params.limit.clamp(1, 1000))
+        .await?;
+    Ok(Json(sample))
+}
+
+/// GET /api/clusters/loads/routine - Routine Load jobs with per-partition lag
+#[utoipa::path(
+    get,
+    path = "/api/clusters/loads/routine",
+    params(
+        ("database" = Option<String>, Query, description = "Database name filter"),
+        ("include_stopped" = Option<bool>, Query, description = "Include STOPPED / CANCELLED jobs"),
+    ),
+    responses(
+        (status = 200, description = "Routine Load jobs", body = Vec<RoutineLoadJob>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Loads"
+)]
+pub async fn list_routine_loads(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<RoutineLoadParams>,
+) -> ApiResult<Json<Vec<RoutineLoadJob>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let jobs = state
+        .load_job_service
+        .list_routine_loads(&cluster, params.database.as_deref(), params.include_stopped)
+        .await?;
+    Ok(Json(jobs))
+}
+
+/// POST /api/clusters/loads/routine/control - Pause, resume or stop a Routine Load job
+#[utoipa::path(
+    post,
+    path = "/api/clusters/loads/routine/control",
+    request_body = RoutineLoadControlRequest,
+    responses(
+        (status = 200, description = "Operation executed"),
+        (status = 400, description = "Invalid request")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Loads"
+)]
+pub async fn control_routine_load(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(req): Json<RoutineLoadControlRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    state
+        .load_job_service
+        .control_routine_load(&cluster, &req.database, &req.name, req.action)
+        .await?;
+
+    Ok((StatusCode::OK, Json(json!({ "message": "Routine load operation executed successfully" }))))
+}
+
+/// GET /api/clusters/loads/history - Hourly load throughput and failure rate
+#[utoipa::path(
+    get,
+    path = "/api/clusters/loads/history",
+    params(
+        ("hours" = Option<i64>, Query, description = "Look-back window in hours (default: 24)"),
+        ("job_type" = Option<String>, Query, description = "Filter by load type"),
+    ),
+    responses(
+        (status = 200, description = "Hourly load history", body = Vec<LoadHistoryPoint>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Loads"
+)]
+pub async fn get_load_history(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<LoadHistoryParams>,
+) -> ApiResult<Json<Vec<LoadHistoryPoint>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let points = state
+        .load_job_service
+        .get_history(cluster.id, params.hours.clamp(1, 24 * 90), params.job_type.as_deref())
+        .await?;
+    Ok(Json(points))
+}
+
+/// GET /api/clusters/loads/routine/history - Recorded offsets and lag of a Routine Load job
+#[utoipa::path(
+    get,
+    path = "/api/clusters/loads/routine/history",
+    params(
+        ("database" = String, Query, description = "Database of the job"),
+        ("job_name" = String, Query, description = "Routine Load job name"),
+        ("hours" = Option<i64>, Query, description = "Look-back window in hours (default: 24)"),
+    ),
+    responses(
+        (status = 200, description = "Routine Load snapshots, oldest first", body = Vec<RoutineLoadSnapshot>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Loads"
+)]
+pub async fn get_routine_load_history(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<RoutineLoadHistoryParams>,
+) -> ApiResult<Json<Vec<RoutineLoadSnapshot>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let snapshots = state
+        .routine_load_history_service
+        .get_history(cluster.id, &params.database, &params.job_name, params.hours.clamp(1, 24 * 30))
+        .await?;
+    Ok(Json(snapshots))
+}
+
+/// GET /api/clusters/loads/routine/alerts - Paused or stalled Routine Load jobs
+#[utoipa::path(
+    get,
+    path = "/api/clusters/loads/routine/alerts",
+    responses(
+        (status = 200, description = "Routine Load jobs needing attention", body = Vec<RoutineLoadAlert>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Loads"
+)]
+pub async fn get_routine_load_alerts(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+) -> ApiResult<Json<Vec<RoutineLoadAlert>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let alerts = state
+        .routine_load_history_service
+        .get_alerts(cluster.id)
+        .await?;
+    Ok(Json(alerts))
+}
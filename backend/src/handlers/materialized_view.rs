@@ -4,17 +4,26 @@ use axum::{
     http::StatusCode,
     response::IntoResponse,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::sync::Arc;
 
 use crate::AppState;
 use crate::models::{
-    AlterMaterializedViewRequest, CreateMaterializedViewRequest, MaterializedView,
-    MaterializedViewDDL, RefreshMaterializedViewRequest,
+    AlterMaterializedViewRequest, Cluster, ClusterType, CreateMaterializedViewRequest,
+    MaterializedView, MaterializedViewDDL, RefreshMaterializedViewRequest,
 };
-use crate::services::{MaterializedViewService, MySQLClient, create_adapter};
-use crate::utils::ApiResult;
+use crate::services::mv_refresh_history_service::{
+    DEFAULT_FAILURE_STREAK, MvRefreshAlert, MvRefreshRun,
+};
+use crate::services::profile_analyzer::analyzer::rules::parse_duration_ms;
+use crate::services::profile_analyzer::parser::core::SectionParser;
+use crate::services::{
+    AuditLogService, ClusterAdapter, MaterializedViewService, MvCandidate, MvDependencyGraph,
+    MvRecommendationOptions, MvRecommender, MvUsageObservation, MvUsageStats, MySQLClient,
+    WorkloadQuery, analyze_mv_usage, build_mv_dependency_graph, create_adapter,
+};
+use crate::utils::{ApiError, ApiResult};
 
 #[derive(Debug, Deserialize)]
 pub struct ListMVParams {
@@ -33,6 +42,75 @@ pub struct CancelRefreshParams {
     pub force: bool,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RecommendMVParams {
+    /// Audit log window in hours (default: 168)
+    pub hours: Option<i32>,
+    /// Minimum executions of a pattern (default: 3)
+    pub min_count: Option<usize>,
+    /// Maximum number of candidates (default: 20)
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct MVRecommendationResponse {
+    pub candidates: Vec<MvCandidate>,
+    /// Number of workload queries that were mined
+    pub analyzed_queries: usize,
+    pub hours: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RefreshHistoryParams {
+    pub database: Option<String>,
+    #[serde(default = "default_refresh_history_hours")]
+    pub hours: i64,
+}
+
+fn default_refresh_history_hours() -> i64 {
+    24 * 7
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RefreshAlertParams {
+    /// Failed refreshes in a row before an MV is flagged (default: 3)
+    pub failures: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MVUsageParams {
+    /// Analysis window in hours (default: 168)
+    pub hours: Option<i32>,
+    pub database: Option<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct MVUsageResponse {
+    /// Materialized views, least useful first
+    pub items: Vec<MvUsageStats>,
+    /// Audit log records carrying MV rewrite info
+    pub analyzed_queries: usize,
+    /// Retained profiles scanned for MV rewrite info
+    pub analyzed_profiles: usize,
+    /// The audit log pull hit its row limit, so older queries of the window were not seen
+    pub audit_truncated: bool,
+    /// Every query of the window was observed; only then are unused MVs drop candidates
+    pub complete_coverage: bool,
+    pub hours: i32,
+}
+
+/// Statements pulled from the audit log for pattern mining
+const RECOMMENDATION_AUDIT_LIMIT: usize = 5000;
+
+/// Retained profiles fetched for pattern mining
+const RECOMMENDATION_PROFILE_LIMIT: usize = 50;
+
+/// Audit log records pulled for MV usage analysis
+const USAGE_AUDIT_LIMIT: usize = 20000;
+
+/// Retained profiles fetched for MV usage analysis
+const USAGE_PROFILE_LIMIT: usize = 50;
+
 /// GET /api/clusters/materialized_views - List all materialized views
 #[utoipa::path(
     get,
@@ -318,3 +396,388 @@ pub async fn alter_materialized_view(
 
     Ok((StatusCode::OK, Json(json!({ "message": "Materialized view altered successfully" }))))
 }
+
+/// GET /api/clusters/materialized_views/recommendations - Recommend MVs from workload
+#[utoipa::path(
+    get,
+    path = "/api/clusters/materialized_views/recommendations",
+    params(
+        ("hours" = Option<i32>, Query, description = "Audit log window in hours (default: 168)"),
+        ("min_count" = Option<usize>, Query, description = "Minimum executions of a pattern (default: 3)"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of candidates (default: 20)"),
+    ),
+    responses(
+        (status = 200, description = "Candidate materialized views", body = MVRecommendationResponse),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Materialized Views"
+)]
+pub async fn recommend_materialized_views(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<RecommendMVParams>,
+) -> ApiResult<Json<MVRecommendationResponse>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let adapter = create_adapter(cluster.clone(), state.mysql_pool_manager.clone());
+    let hours = params.hours.unwrap_or(168).clamp(1, 24 * 90);
+    let workload =
+        collect_recommendation_workload(&state, &cluster, adapter.as_ref(), hours).await?;
+    let candidates = build_recommender(&params).recommend(&workload);
+
+    Ok(Json(MVRecommendationResponse { candidates, analyzed_queries: workload.len(), hours }))
+}
+
+/// POST /api/clusters/materialized_views/recommendations/{pattern_id} - Create a recommended MV
+#[utoipa::path(
+    post,
+    path = "/api/clusters/materialized_views/recommendations/{pattern_id}",
+    params(
+        ("pattern_id" = String, Path, description = "Pattern id of the candidate"),
+        ("hours" = Option<i32>, Query, description = "Audit log window in hours (default: 168)"),
+        ("min_count" = Option<usize>, Query, description = "Minimum executions of a pattern (default: 3)"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of candidates (default: 20)"),
+    ),
+    responses(
+        (status = 201, description = "Materialized view created from the candidate", body = MvCandidate),
+        (status = 404, description = "No active cluster or candidate found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Materialized Views"
+)]
+pub async fn create_recommended_materialized_view(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(pattern_id): Path<String>,
+    Query(params): Query<RecommendMVParams>,
+) -> ApiResult<impl IntoResponse> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    // The DDL is rebuilt from the workload rather than taken from the client
+    let adapter = create_adapter(cluster.clone(), state.mysql_pool_manager.clone());
+    let hours = params.hours.unwrap_or(168).clamp(1, 24 * 90);
+    let workload =
+        collect_recommendation_workload(&state, &cluster, adapter.as_ref(), hours).await?;
+    let candidate = build_recommender(&params)
+        .recommend(&workload)
+        .into_iter()
+        .find(|c| c.pattern_id == pattern_id)
+        .ok_or_else(|| {
+            ApiError::not_found(format!(
+                "No materialized view candidate for pattern {}",
+                pattern_id
+            ))
+        })?;
+
+    adapter.create_materialized_view(&candidate.ddl).await?;
+
+    Ok((StatusCode::CREATED, Json(candidate)))
+}
+
+fn build_recommender(params: &RecommendMVParams) -> MvRecommender {
+    let defaults = MvRecommendationOptions::default();
+    MvRecommender::new(MvRecommendationOptions {
+        min_query_count: params.min_count.unwrap_or(defaults.min_query_count).max(2),
+        limit: params.limit.unwrap_or(defaults.limit).min(100),
+        ..defaults
+    })
+}
+
+/// Aggregation queries from the audit log plus retained profiles
+async fn collect_recommendation_workload(
+    state: &AppState,
+    cluster: &Cluster,
+    adapter: &dyn ClusterAdapter,
+    hours: i32,
+) -> ApiResult<Vec<WorkloadQuery>> {
+    let audit_service =
+        AuditLogService::new(state.mysql_pool_manager.clone(), state.audit_config.clone());
+    let mut workload: Vec<WorkloadQuery> = audit_service
+        .get_aggregation_queries(cluster, hours, RECOMMENDATION_AUDIT_LIMIT)
+        .await?
+        .into_iter()
+        .map(|q| WorkloadQuery {
+            query_id: Some(q.query_id),
+            database: Some(q.database),
+            sql: q.stmt,
+            duration_ms: q.duration_ms as f64,
+            scan_bytes: q.scan_bytes,
+        })
+        .collect();
+
+    // Retained profiles cover queries the audit log may have sampled out; the
+    // profile summary carries the session database the audit log records too
+    match adapter.list_profiles().await {
+        Ok(profiles) => {
+            for profile in profiles.into_iter().take(RECOMMENDATION_PROFILE_LIMIT) {
+                let summary = match adapter.get_profile(&profile.query_id).await {
+                    Ok(text) => match SectionParser::parse_summary(&text) {
+                        Ok(summary) => summary,
+                        Err(e) => {
+                            tracing::debug!("Skipping profile {}: {}", profile.query_id, e);
+                            continue;
+                        },
+                    },
+                    Err(e) => {
+                        tracing::debug!("Skipping profile {}: {}", profile.query_id, e);
+                        continue;
+                    },
+                };
+                let sql = if summary.sql_statement.trim().is_empty() {
+                    profile.statement
+                } else {
+                    summary.sql_statement
+                };
+                workload.push(WorkloadQuery {
+                    duration_ms: parse_duration_ms(&profile.time).unwrap_or(0.0),
+                    query_id: Some(profile.query_id),
+                    database: summary.default_db,
+                    sql,
+                    scan_bytes: None,
+                });
+            }
+        },
+        Err(e) => tracing::warn!("Skipping profiles for MV recommendation: {}", e),
+    }
+
+    Ok(workload)
+}
+
+/// GET /api/clusters/materialized_views/{mv_name}/refresh_history - Refresh task runs
+#[utoipa::path(
+    get,
+    path = "/api/clusters/materialized_views/{mv_name}/refresh_history",
+    params(
+        ("mv_name" = String, Path, description = "Materialized view name"),
+        ("database" = Option<String>, Query, description = "Database name"),
+        ("hours" = Option<i64>, Query, description = "Look-back window in hours (default: 168)"),
+    ),
+    responses(
+        (status = 200, description = "Refresh runs, oldest first", body = Vec<MvRefreshRun>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Materialized Views"
+)]
+pub async fn get_materialized_view_refresh_history(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(mv_name): Path<String>,
+    Query(params): Query<RefreshHistoryParams>,
+) -> ApiResult<Json<Vec<MvRefreshRun>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let runs = state
+        .mv_refresh_history_service
+        .get_history(
+            cluster.id,
+            params.database.as_deref().filter(|db| !db.is_empty()),
+            &mv_name,
+            params.hours.clamp(1, 24 * 90),
+        )
+        .await?;
+    Ok(Json(runs))
+}
+
+/// GET /api/clusters/materialized_views/refresh_alerts - MVs failing or slowing down
+#[utoipa::path(
+    get,
+    path = "/api/clusters/materialized_views/refresh_alerts",
+    params(
+        ("failures" = Option<usize>, Query, description = "Failed refreshes in a row before an MV is flagged (default: 3)"),
+    ),
+    responses(
+        (status = 200, description = "Materialized views needing attention", body = Vec<MvRefreshAlert>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Materialized Views"
+)]
+pub async fn get_materialized_view_refresh_alerts(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<RefreshAlertParams>,
+) -> ApiResult<Json<Vec<MvRefreshAlert>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let alerts = state
+        .mv_refresh_history_service
+        .get_alerts(cluster.id, params.failures.unwrap_or(DEFAULT_FAILURE_STREAK).max(1))
+        .await?;
+    Ok(Json(alerts))
+}
+
+/// GET /api/clusters/materialized_views/usage - Rewrite hit rate and refresh cost per MV
+#[utoipa::path(
+    get,
+    path = "/api/clusters/materialized_views/usage",
+    params(
+        ("hours" = Option<i32>, Query, description = "Analysis window in hours (default: 168)"),
+        ("database" = Option<String>, Query, description = "Database name"),
+    ),
+    responses(
+        (status = 200, description = "Materialized view usage, least useful first", body = MVUsageResponse),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Materialized Views"
+)]
+pub async fn get_materialized_view_usage(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<MVUsageParams>,
+) -> ApiResult<Json<MVUsageResponse>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let hours = params.hours.unwrap_or(168).clamp(1, 24 * 90);
+    let cluster_id = cluster.id;
+    let adapter = create_adapter(cluster.clone(), state.mysql_pool_manager.clone());
+    let mvs = adapter
+        .list_materialized_views(params.database.as_deref().filter(|db| !db.is_empty()))
+        .await?;
+
+    let audit_service =
+        AuditLogService::new(state.mysql_pool_manager.clone(), state.audit_config.clone());
+    // Doris audit logs carry no rewrite info, so its profile sample never covers the window
+    let mut complete_coverage = cluster.cluster_type == ClusterType::StarRocks;
+    let mut audit_truncated = false;
+    let mut observations: Vec<MvUsageObservation> = match audit_service
+        .get_mv_rewrite_records(&cluster, hours, USAGE_AUDIT_LIMIT)
+        .await
+    {
+        Ok((records, truncated)) => {
+            audit_truncated = truncated;
+            complete_coverage &= !truncated;
+            records
+                .iter()
+                .filter_map(|r| {
+                    MvUsageObservation::from_audit(
+                        &r.query_id,
+                        &r.database,
+                        &r.hit_mvs,
+                        &r.candidate_mvs,
+                    )
+                })
+                .collect()
+        },
+        Err(e) => {
+            tracing::warn!("Skipping audit log for MV usage: {}", e);
+            complete_coverage = false;
+            Vec::new()
+        },
+    };
+    let analyzed_queries = observations.len();
+
+    // Profiles carry the planner's MV rewrite info where the audit log has none
+    let mut analyzed_profiles = 0;
+    match adapter.list_profiles().await {
+        Ok(profiles) => {
+            for profile in profiles.iter().take(USAGE_PROFILE_LIMIT) {
+                match adapter.get_profile(&profile.query_id).await {
+                    Ok(text) => {
+                        analyzed_profiles += 1;
+                        observations
+                            .extend(MvUsageObservation::from_profile(&profile.query_id, &text));
+                    },
+                    Err(e) => tracing::debug!("Skipping profile {}: {}", profile.query_id, e),
+                }
+            }
+        },
+        Err(e) => tracing::warn!("Skipping profiles for MV usage: {}", e),
+    }
+
+    let costs = state
+        .mv_refresh_history_service
+        .refresh_costs(cluster_id, hours as i64)
+        .await?;
+    let items = analyze_mv_usage(&mvs, &observations, &costs, complete_coverage);
+
+    Ok(Json(MVUsageResponse {
+        items,
+        analyzed_queries,
+        analyzed_profiles,
+        audit_truncated,
+        complete_coverage,
+        hours,
+    }))
+}
+
+/// GET /api/clusters/materialized_views/dependencies - MV dependency DAG with staleness
+#[utoipa::path(
+    get,
+    path = "/api/clusters/materialized_views/dependencies",
+    responses(
+        (status = 200, description = "MVs and their base tables, inactive or stale nodes highlighted", body = MvDependencyGraph),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Materialized Views"
+)]
+pub async fn get_materialized_view_dependencies(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+) -> ApiResult<Json<MvDependencyGraph>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let adapter = create_adapter(cluster, state.mysql_pool_manager.clone());
+    let mvs = adapter.list_materialized_views(None).await?;
+    let dependencies = adapter.list_materialized_view_dependencies().await?;
+
+    let mut databases: Vec<String> = dependencies
+        .iter()
+        .filter(|d| d.base_catalog.is_none())
+        .map(|d| d.base_database.clone())
+        .collect();
+    databases.sort();
+    databases.dedup();
+    let update_times = match adapter.get_table_update_times(&databases).await {
+        Ok(times) => times,
+        Err(e) => {
+            tracing::warn!("Skipping table update times for MV staleness: {}", e);
+            Default::default()
+        },
+    };
+
+    Ok(Json(build_mv_dependency_graph(&mvs, &dependencies, &update_times)))
+}
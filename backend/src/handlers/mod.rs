@@ -1,18 +1,27 @@
 pub mod auth;
 pub mod backend;
 pub mod cluster;
+pub mod compaction;
+pub mod config_drift;
 pub mod frontend;
 pub mod llm;
+pub mod load;
 pub mod materialized_view;
+pub mod node_config;
 pub mod organization;
 pub mod overview;
+pub mod partition;
 pub mod permission;
 pub mod permission_request;
 pub mod profile;
 pub mod query;
+pub mod query_guard;
 pub mod query_history;
+pub mod resource_group;
 pub mod role;
+pub mod session_template;
 pub mod sessions;
+pub mod sql_blacklist;
 pub mod sql_diag;
 pub mod system;
 pub mod system_function;
@@ -0,0 +1,200 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::services::node_config_service::{
+    ConfigDivergence, NodeConfigChange, NodeConfigChangeRequest, NodeConfigInventory, NodeType,
+};
+use crate::utils::ApiResult;
+
+#[derive(Debug, Deserialize)]
+pub struct NodeConfigParams {
+    pub node_type: Option<NodeType>,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConfigChangeParams {
+    pub name: Option<String>,
+    #[serde(default = "default_change_limit")]
+    pub limit: i64,
+}
+
+fn default_change_limit() -> i64 {
+    100
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ConfigRollbackRequest {
+    pub change_id: i64,
+}
+
+/// GET /api/clusters/configs/nodes - Config values of every FE and BE
+#[utoipa::path(
+    get,
+    path = "/api/clusters/configs/nodes",
+    params(
+        ("node_type" = Option<String>, Query, description = "FE or BE (default: both)"),
+        ("name" = Option<String>, Query, description = "Config name filter (substring)"),
+    ),
+    responses(
+        (status = 200, description = "Per-node config inventory", body = NodeConfigInventory),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Node Configs"
+)]
+pub async fn get_node_configs(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<NodeConfigParams>,
+) -> ApiResult<Json<NodeConfigInventory>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let inventory = state
+        .node_config_service
+        .get_inventory(&cluster, params.node_type, params.name.as_deref())
+        .await?;
+    Ok(Json(inventory))
+}
+
+/// GET /api/clusters/configs/divergence - Configs whose values differ between nodes
+#[utoipa::path(
+    get,
+    path = "/api/clusters/configs/divergence",
+    responses(
+        (status = 200, description = "Diverging configs", body = Vec<ConfigDivergence>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Node Configs"
+)]
+pub async fn get_config_divergence(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+) -> ApiResult<Json<Vec<ConfigDivergence>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let divergences = state.node_config_service.get_divergence(&cluster).await?;
+    Ok(Json(divergences))
+}
+
+/// POST /api/clusters/configs/apply - Change a FE / BE config on selected nodes
+#[utoipa::path(
+    post,
+    path = "/api/clusters/configs/apply",
+    request_body = NodeConfigChangeRequest,
+    responses(
+        (status = 200, description = "Recorded change per node", body = Vec<NodeConfigChange>),
+        (status = 400, description = "Invalid config name or value")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Node Configs"
+)]
+pub async fn apply_node_config(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(req): Json<NodeConfigChangeRequest>,
+) -> ApiResult<Json<Vec<NodeConfigChange>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let changes = state
+        .node_config_service
+        .apply_change(&cluster, &req, &org_ctx.username)
+        .await?;
+    Ok(Json(changes))
+}
+
+/// GET /api/clusters/configs/changes - Config change history
+#[utoipa::path(
+    get,
+    path = "/api/clusters/configs/changes",
+    params(
+        ("name" = Option<String>, Query, description = "Config name"),
+        ("limit" = Option<i64>, Query, description = "Maximum records (default: 100)"),
+    ),
+    responses(
+        (status = 200, description = "Config changes, newest first", body = Vec<NodeConfigChange>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Node Configs"
+)]
+pub async fn list_config_changes(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<ConfigChangeParams>,
+) -> ApiResult<Json<Vec<NodeConfigChange>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let changes = state
+        .node_config_service
+        .list_changes(cluster.id, params.name.as_deref(), params.limit.clamp(1, 1000))
+        .await?;
+    Ok(Json(changes))
+}
+
+/// POST /api/clusters/configs/rollback - Restore the previous value of a config change
+#[utoipa::path(
+    post,
+    path = "/api/clusters/configs/rollback",
+    request_body = ConfigRollbackRequest,
+    responses(
+        (status = 200, description = "Rolled back change", body = NodeConfigChange),
+        (status = 400, description = "Change cannot be rolled back"),
+        (status = 404, description = "Change not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Node Configs"
+)]
+pub async fn rollback_config_change(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(req): Json<ConfigRollbackRequest>,
+) -> ApiResult<Json<NodeConfigChange>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let change = state
+        .node_config_service
+        .rollback_change(&cluster, req.change_id, &org_ctx.username)
+        .await?;
+    Ok(Json(change))
+}
@@ -0,0 +1,275 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::services::partition_service::{
+    DynamicPartitionSetting, PartitionDropPreview, PartitionDropRecord, PartitionDropRequest,
+    PartitionRequester, PartitionTtlCandidate,
+};
+use crate::utils::ApiResult;
+
+#[derive(Debug, Deserialize)]
+pub struct DynamicPartitionParams {
+    pub database: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TtlCandidateParams {
+    pub database: Option<String>,
+    #[serde(default = "default_ttl_days")]
+    pub days: i64,
+}
+
+fn default_ttl_days() -> i64 {
+    90
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DropRecordParams {
+    pub status: Option<String>,
+    #[serde(default = "default_record_limit")]
+    pub limit: i64,
+}
+
+fn default_record_limit() -> i64 {
+    100
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct PartitionDropDecision {
+    pub request_id: i64,
+    pub comment: Option<String>,
+}
+
+fn requester(org_ctx: &crate::middleware::OrgContext) -> PartitionRequester<'_> {
+    PartitionRequester {
+        user_id: org_ctx.user_id,
+        username: &org_ctx.username,
+        organization_id: org_ctx.organization_id,
+        is_super_admin: org_ctx.is_super_admin,
+    }
+}
+
+/// GET /api/clusters/partitions/dynamic - List dynamic partition settings
+#[utoipa::path(
+    get,
+    path = "/api/clusters/partitions/dynamic",
+    params(
+        ("database" = Option<String>, Query, description = "Database name filter (all user databases if omitted)"),
+    ),
+    responses(
+        (status = 200, description = "Dynamic partition settings", body = Vec<DynamicPartitionSetting>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Partitions"
+)]
+pub async fn list_dynamic_partitions(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<DynamicPartitionParams>,
+) -> ApiResult<Json<Vec<DynamicPartitionSetting>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let settings = state
+        .partition_service
+        .list_dynamic_partitions(&cluster, params.database.as_deref())
+        .await?;
+
+    Ok(Json(settings))
+}
+
+/// GET /api/clusters/partitions/ttl-candidates - Tables without TTL keeping old partitions
+#[utoipa::path(
+    get,
+    path = "/api/clusters/partitions/ttl-candidates",
+    params(
+        ("database" = Option<String>, Query, description = "Database name filter"),
+        ("days" = Option<i64>, Query, description = "Flag tables whose oldest partition is older than this (default: 90)"),
+    ),
+    responses(
+        (status = 200, description = "TTL candidates", body = Vec<PartitionTtlCandidate>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Partitions"
+)]
+pub async fn list_ttl_candidates(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<TtlCandidateParams>,
+) -> ApiResult<Json<Vec<PartitionTtlCandidate>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let candidates = state
+        .partition_service
+        .find_ttl_candidates(&cluster, params.database.as_deref(), params.days.max(1))
+        .await?;
+
+    Ok(Json(candidates))
+}
+
+/// POST /api/clusters/partitions/drop/preview - Preview DROP PARTITION batches
+#[utoipa::path(
+    post,
+    path = "/api/clusters/partitions/drop/preview",
+    request_body = PartitionDropRequest,
+    responses(
+        (status = 200, description = "Partitions and statements that would be executed", body = PartitionDropPreview),
+        (status = 400, description = "Invalid request")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Partitions"
+)]
+pub async fn preview_drop_partitions(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(req): Json<PartitionDropRequest>,
+) -> ApiResult<Json<PartitionDropPreview>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let preview = state.partition_service.preview_drop(&cluster, &req).await?;
+    Ok(Json(preview))
+}
+
+/// POST /api/clusters/partitions/drop - Drop partitions (executed directly for admins,
+/// queued for approval otherwise)
+#[utoipa::path(
+    post,
+    path = "/api/clusters/partitions/drop",
+    request_body = PartitionDropRequest,
+    responses(
+        (status = 200, description = "Audit record of the drop request", body = PartitionDropRecord),
+        (status = 400, description = "Invalid request")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Partitions"
+)]
+pub async fn drop_partitions(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(req): Json<PartitionDropRequest>,
+) -> ApiResult<Json<PartitionDropRecord>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let record = state
+        .partition_service
+        .submit_drop(&cluster, &requester(&org_ctx), &req)
+        .await?;
+    Ok(Json(record))
+}
+
+/// GET /api/clusters/partitions/drop/requests - Partition drop audit trail
+#[utoipa::path(
+    get,
+    path = "/api/clusters/partitions/drop/requests",
+    params(
+        ("status" = Option<String>, Query, description = "Filter by status: pending, rejected, executing, completed, failed"),
+        ("limit" = Option<i64>, Query, description = "Maximum records (default: 100)"),
+    ),
+    responses(
+        (status = 200, description = "Partition drop requests", body = Vec<PartitionDropRecord>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Partitions"
+)]
+pub async fn list_drop_requests(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<DropRecordParams>,
+) -> ApiResult<Json<Vec<PartitionDropRecord>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let records = state
+        .partition_service
+        .list_records(cluster.id, params.status.as_deref(), params.limit.clamp(1, 1000))
+        .await?;
+    Ok(Json(records))
+}
+
+/// POST /api/clusters/partitions/drop/approve - Approve and execute a pending drop request
+#[utoipa::path(
+    post,
+    path = "/api/clusters/partitions/drop/approve",
+    request_body = PartitionDropDecision,
+    responses(
+        (status = 200, description = "Updated audit record", body = PartitionDropRecord),
+        (status = 403, description = "Not an administrator")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Partitions"
+)]
+pub async fn approve_drop_request(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(decision): Json<PartitionDropDecision>,
+) -> ApiResult<Json<PartitionDropRecord>> {
+    let record = state
+        .partition_service
+        .approve_drop(decision.request_id, &requester(&org_ctx), decision.comment.as_deref())
+        .await?;
+    Ok(Json(record))
+}
+
+/// POST /api/clusters/partitions/drop/reject - Reject a pending drop request
+#[utoipa::path(
+    post,
+    path = "/api/clusters/partitions/drop/reject",
+    request_body = PartitionDropDecision,
+    responses(
+        (status = 200, description = "Updated audit record", body = PartitionDropRecord),
+        (status = 403, description = "Not an administrator")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Partitions"
+)]
+pub async fn reject_drop_request(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(decision): Json<PartitionDropDecision>,
+) -> ApiResult<Json<PartitionDropRecord>> {
+    let record = state
+        .partition_service
+        .reject_drop(decision.request_id, &requester(&org_ctx), decision.comment.as_deref())
+        .await?;
+    Ok(Json(record))
+}
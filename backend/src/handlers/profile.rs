@@ -1,20 +1,30 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    http::{HeaderMap, header},
+    response::IntoResponse,
 };
+use serde::Deserialize;
 use std::sync::Arc;
 
+use crate::models::cluster::ClusterType;
 use crate::models::{ProfileDetail, ProfileListItem};
 use crate::services::MySQLClient;
 use crate::services::cluster_adapter::create_adapter;
 use crate::services::llm::{
-    DiagnosticForLLM, ExecutionPlanForLLM, HotspotNodeForLLM, KeyMetricsForLLM, LLMService,
-    OperatorDetailForLLM, ProfileDataForLLM, QuerySummaryForLLM, RootCauseAnalysisRequest,
-    RootCauseAnalysisResponse, ScanDetailForLLM, determine_connector_type, determine_table_type,
+    CardinalityErrorForLLM, DiagnosticForLLM, ExecutionPlanForLLM, HotspotNodeForLLM,
+    KeyMetricsForLLM, LLMService, OperatorDetailForLLM, ProfileDataForLLM, QuerySummaryForLLM,
+    RootCauseAnalysisRequest, RootCauseAnalysisResponse, ScanDetailForLLM,
+    determine_connector_type, determine_table_type,
 };
 use crate::services::profile_analyzer::{
-    AnalysisContext, ClusterVariables, LLMEnhancedAnalysis, ProfileAnalysisResponse,
-    analyze_profile_with_context, analyzer::QueryComplexity,
+    AnalysisContext, ClusterVariables, LLMEnhancedAnalysis, Locale, ProfileAnalysisResponse,
+    analyze_profile_with_context,
+    analyzer::{
+        CardinalityInput, PlannerEstimates, QueryComplexity, TableStatistics, thresholds::QueryType,
+    },
+    export::{AnalysisBundle, ReportExporter, ReportFormat},
+    parser::core::SectionParser,
 };
 use crate::utils::{ApiResult, error::ApiError};
 
@@ -122,12 +132,20 @@ pub async fn get_profile(
     Ok(Json(ProfileDetail { query_id: safe_query_id, profile_content }))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct AnalyzeProfileParams {
+    /// Compare planner estimates with actual rows (runs `EXPLAIN COSTS` on the cluster)
+    #[serde(default)]
+    pub cardinality: bool,
+}
+
 /// Analyze a query profile and return structured visualization data
 #[utoipa::path(
     get,
     path = "/api/clusters/profiles/{query_id}/analyze",
     params(
-        ("query_id" = String, Path, description = "Query ID to analyze")
+        ("query_id" = String, Path, description = "Query ID to analyze"),
+        ("cardinality" = Option<bool>, Query, description = "Compare estimated and actual rows via EXPLAIN COSTS (default: false)")
     ),
     responses(
         (status = 200, description = "Profile analysis result with execution tree"),
@@ -142,7 +160,9 @@ pub async fn get_profile(
 pub async fn analyze_profile_handler(
     State(state): State<Arc<crate::AppState>>,
     axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    headers: HeaderMap,
     Path(query_id): Path<String>,
+    Query(params): Query<AnalyzeProfileParams>,
 ) -> ApiResult<Json<ProfileAnalysisResponse>> {
     let cluster = if org_ctx.is_super_admin {
         state.cluster_service.get_active_cluster().await?
@@ -174,8 +194,20 @@ pub async fn analyze_profile_handler(
     let pool = state.mysql_pool_manager.get_pool(&cluster).await?;
     let mysql_client = MySQLClient::from_pool(pool);
     let cluster_variables = fetch_cluster_variables(&mysql_client).await;
+    let cardinality_input = if params.cardinality {
+        fetch_cardinality_input(&mysql_client, &cluster.cluster_type, &profile_content).await
+    } else {
+        None
+    };
 
-    let context = AnalysisContext { cluster_variables, cluster_id: Some(cluster.id) };
+    let locale = resolve_locale(&state, org_ctx.user_id, &headers).await;
+
+    let context = AnalysisContext {
+        cluster_variables,
+        cluster_id: Some(cluster.id),
+        locale,
+        cardinality_input,
+    };
 
     let mut response = analyze_profile_with_context(&profile_content, &context)
         .map_err(|e| ApiError::internal_error(format!("Analysis failed: {}", e)))?;
@@ -191,6 +223,31 @@ pub async fn analyze_profile_handler(
     Ok(Json(response))
 }
 
+/// Pick the diagnostics language: the user's saved preference first, then the
+/// `Accept-Language` header, then the default (Chinese)
+pub(crate) async fn resolve_locale(
+    state: &crate::AppState,
+    user_id: i64,
+    headers: &HeaderMap,
+) -> Locale {
+    let preferred = state
+        .auth_service
+        .get_user_by_id(user_id)
+        .await
+        .ok()
+        .and_then(|user| user.language)
+        .and_then(|lang| Locale::parse(&lang));
+
+    preferred
+        .or_else(|| {
+            headers
+                .get(header::ACCEPT_LANGUAGE)
+                .and_then(|v| v.to_str().ok())
+                .and_then(Locale::from_accept_language)
+        })
+        .unwrap_or_default()
+}
+
 /// Request body for LLM enhancement
 #[derive(Debug, serde::Deserialize)]
 pub struct EnhanceProfileRequest {
@@ -253,6 +310,71 @@ pub async fn enhance_profile_handler(
     }
 }
 
+/// Request body for report export
+#[derive(Debug, serde::Deserialize)]
+pub struct ExportProfileRequest {
+    /// Analysis result as shown in the UI (including LLM results, if already loaded)
+    pub analysis_data: ProfileAnalysisResponse,
+    /// Report format: html (default), markdown or json (re-importable bundle)
+    #[serde(default)]
+    pub format: ReportFormat,
+}
+
+/// Export an analysis result as a shareable file
+#[utoipa::path(
+    post,
+    path = "/api/clusters/profiles/export",
+    responses(
+        (status = 200, description = "Self-contained HTML report, Markdown summary or JSON bundle"),
+        (status = 400, description = "Invalid request body")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Profiles"
+)]
+pub async fn export_profile_report_handler(
+    State(state): State<Arc<crate::AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    headers: HeaderMap,
+    Json(req): Json<ExportProfileRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let locale = resolve_locale(&state, org_ctx.user_id, &headers).await;
+    let body = ReportExporter::render(&req.analysis_data, req.format, locale)
+        .map_err(ApiError::internal_error)?;
+    let file_name = ReportExporter::file_name(&req.analysis_data, req.format);
+
+    tracing::info!("Exported profile report {} ({} bytes)", file_name, body.len());
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, req.format.content_type().to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", file_name)),
+        ],
+        body,
+    ))
+}
+
+/// Load a previously exported JSON bundle to reproduce the analysis view offline
+#[utoipa::path(
+    post,
+    path = "/api/clusters/profiles/import",
+    responses(
+        (status = 200, description = "Analysis result stored in the bundle"),
+        (status = 400, description = "Not a valid analysis bundle")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Profiles"
+)]
+pub async fn import_profile_bundle_handler(
+    body: String,
+) -> ApiResult<Json<ProfileAnalysisResponse>> {
+    let bundle = AnalysisBundle::from_json(&body).map_err(ApiError::invalid_data)?;
+    Ok(Json(bundle.analysis))
+}
+
 /// Enhance profile analysis with LLM-based root cause analysis
 ///
 /// Builds a request from the rule engine results and calls LLM for deeper analysis.
@@ -422,6 +544,29 @@ async fn enhance_with_llm(
         })
         .unwrap_or_default();
 
+    let cardinality = response.cardinality_analysis.as_ref();
+    let estimated_rows: std::collections::HashMap<i32, u64> = cardinality
+        .map(|c| {
+            c.errors
+                .iter()
+                .map(|e| (e.plan_node_id, e.estimated_rows))
+                .collect()
+        })
+        .unwrap_or_default();
+    let cardinality_errors: Vec<CardinalityErrorForLLM> = cardinality
+        .map(|c| {
+            c.errors
+                .iter()
+                .map(|e| CardinalityErrorForLLM {
+                    operator: format!("{} (plan_node_id={})", e.operator_name, e.plan_node_id),
+                    estimated_rows: e.estimated_rows,
+                    actual_rows: e.actual_rows,
+                    error_ratio: e.error_ratio,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
     let operators: Vec<OperatorDetailForLLM> = response
         .execution_tree
         .as_ref()
@@ -434,7 +579,9 @@ async fn enhance_with_llm(
                     plan_node_id: n.plan_node_id.unwrap_or(-1),
                     time_pct: n.time_percentage.unwrap_or(0.0),
                     rows: n.rows.unwrap_or(0),
-                    estimated_rows: None,
+                    estimated_rows: n
+                        .plan_node_id
+                        .and_then(|id| estimated_rows.get(&id).copied()),
                     memory_bytes: None,
                     metrics: n.unique_metrics.clone(),
                 })
@@ -455,7 +602,7 @@ async fn enhance_with_llm(
         .query_summary(query_summary)
         .execution_plan(execution_plan)
         .diagnostics(diagnostics)
-        .key_metrics(KeyMetricsForLLM::default())
+        .key_metrics(KeyMetricsForLLM { cardinality_errors, ..Default::default() })
         .profile_data(profile_data)
         .build()
         .map_err(|e| e.to_string())?;
@@ -691,3 +838,115 @@ async fn fetch_cluster_variables(mysql_client: &MySQLClient) -> Option<ClusterVa
         },
     }
 }
+
+/// The profiled SQL if it is a single SELECT that can be prefixed with `EXPLAIN COSTS`
+///
+/// Statement separators and comments are rejected instead of parsed, so nothing
+/// like `SELECT 1; DROP TABLE t` or `SELECT 1 /*...*/` reaches the cluster. Queries
+/// with such characters in string literals are skipped as well.
+pub(crate) fn explainable_statement(sql: &str) -> Option<&str> {
+    let sql = sql.trim().trim_end_matches(';').trim_end();
+    if sql.contains(';') || sql.contains("--") || sql.contains("/*") || sql.contains('#') {
+        return None;
+    }
+    (QueryType::from_sql(sql) == QueryType::Select).then_some(sql)
+}
+
+/// Fetch `EXPLAIN COSTS` of the profiled SQL and the statistics freshness of the
+/// scanned tables for cardinality analysis
+///
+/// Only single-statement StarRocks SELECT queries are explained, in the database
+/// the profiled query ran in. Returns `None` on any failure so the analysis
+/// continues without PL004 diagnostics.
+async fn fetch_cardinality_input(
+    mysql_client: &MySQLClient,
+    cluster_type: &ClusterType,
+    profile_content: &str,
+) -> Option<CardinalityInput> {
+    if *cluster_type != ClusterType::StarRocks {
+        return None;
+    }
+
+    let summary = SectionParser::parse_summary(profile_content).ok()?;
+    let Some(sql) = explainable_statement(&summary.sql_statement) else {
+        tracing::info!("Profiled SQL is not a single SELECT, skipping cardinality analysis");
+        return None;
+    };
+
+    let explain_costs = async {
+        let mut session = mysql_client.create_session().await?;
+        if let Some(db) = summary.default_db.as_deref() {
+            session.use_database(db).await?;
+        }
+        let (_, rows, _) = session.execute(&format!("EXPLAIN COSTS {}", sql)).await?;
+        Ok::<_, ApiError>(
+            rows.into_iter()
+                .filter_map(|row| row.into_iter().next())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+    .await
+    .map_err(|e| tracing::warn!("EXPLAIN COSTS failed, skipping cardinality analysis: {}", e))
+    .ok()?;
+
+    let tables: Vec<String> = PlannerEstimates::parse(&explain_costs)
+        .scan_tables()
+        .into_iter()
+        .map(|t| match summary.default_db.as_deref() {
+            Some(db) if !t.contains('.') && !db.is_empty() => format!("{}.{}", db, t),
+            _ => t,
+        })
+        .filter(|t| {
+            t.chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+        })
+        .collect();
+
+    let mut table_statistics = Vec::new();
+    if !tables.is_empty() {
+        let sql = format!(
+            "SELECT table_name, MAX(update_time), TIMESTAMPDIFF(HOUR, MAX(update_time), NOW()) \
+             FROM _statistics_.column_statistics WHERE table_name IN ({}) GROUP BY table_name",
+            tables
+                .iter()
+                .map(|t| format!("'{}'", t))
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+        match mysql_client.query_raw(&sql).await {
+            Ok((_, rows)) => {
+                for row in rows {
+                    if row.len() >= 3 {
+                        table_statistics.push(TableStatistics {
+                            table: row[0].clone(),
+                            updated_at: Some(row[1].clone()),
+                            age_hours: row[2].parse().ok(),
+                        });
+                    }
+                }
+            },
+            Err(e) => {
+                tracing::warn!("Failed to fetch statistics update times: {}", e);
+            },
+        }
+    }
+
+    Some(CardinalityInput { explain_costs, table_statistics })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explainable_statement() {
+        assert_eq!(explainable_statement("  select * from t;; "), Some("select * from t"));
+        assert_eq!(explainable_statement("SELECT 1; DROP TABLE t"), None);
+        assert_eq!(explainable_statement("SELECT 1 -- ;\nFROM t"), None);
+        assert_eq!(explainable_statement("SELECT /*+ x */ 1"), None);
+        assert_eq!(explainable_statement("SELECT 1 # comment"), None);
+        assert_eq!(explainable_statement("INSERT INTO t SELECT 1"), None);
+        assert_eq!(explainable_statement(""), None);
+    }
+}
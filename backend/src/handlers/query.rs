@@ -9,6 +9,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use crate::AppState;
+use crate::models::cluster::ClusterType;
 use crate::models::{
     AddSqlBlacklistRequest, CatalogWithDatabases, CatalogsWithDatabasesResponse, Query,
     QueryExecuteRequest, QueryExecuteResponse, SingleQueryResult, SqlBlacklistItem, TableMetadata,
@@ -16,7 +17,12 @@ use crate::models::{
 };
 use crate::services::create_adapter;
 use crate::services::mysql_client::MySQLClient;
-use crate::utils::{ApiError, ApiResult};
+use crate::services::profile_analyzer::ProfileComposer;
+use crate::services::running_query_service::{
+    RunningQueryDetail, build_running_query_detail, runtime_profile_hint,
+};
+use crate::services::session_template_service::SessionTemplateRequester;
+use crate::utils::{ApiError, ApiResult, RowView};
 
 // Get list of catalogs using MySQL client
 #[utoipa::path(
@@ -376,6 +382,81 @@ pub async fn list_queries(
     Ok(Json(queries))
 }
 
+// Get a running query with its in-flight profile, progress and hottest operators
+#[utoipa::path(
+    get,
+    path = "/api/clusters/queries/{query_id}/detail",
+    params(
+        ("query_id" = String, Path, description = "Query ID")
+    ),
+    responses(
+        (status = 200, description = "Running query, progress estimate and the operators that consumed the most time so far"),
+        (status = 404, description = "No active cluster found or query is not running")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Queries"
+)]
+pub async fn get_running_query_detail(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(query_id): Path<String>,
+) -> ApiResult<Json<RunningQueryDetail>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let valid_query_id = query_id.chars().all(|c| c.is_ascii_hexdigit() || c == '-');
+    if !valid_query_id || query_id.is_empty() || query_id.len() > 64 {
+        return Err(ApiError::validation_error("Invalid query ID format"));
+    }
+
+    let adapter = create_adapter(cluster.clone(), state.mysql_pool_manager.clone());
+    let query = adapter
+        .get_queries()
+        .await?
+        .into_iter()
+        .find(|q| q.query_id == query_id)
+        .ok_or_else(|| ApiError::not_found(format!("Query {} is not running", query_id)))?;
+
+    // Queued queries have no profile yet, the queue state tells why
+    let queue_state = match cluster.cluster_type {
+        ClusterType::StarRocks => {
+            let pool = state.mysql_pool_manager.get_pool(&cluster).await?;
+            running_query_state(&MySQLClient::from_pool(pool), &query_id).await
+        },
+        ClusterType::Doris => None,
+    };
+
+    let profile = match adapter.get_profile(&query_id).await {
+        Ok(content) => ProfileComposer::new()
+            .parse(&content)
+            .map_err(|e| format!("Failed to parse runtime profile: {:?}", e)),
+        Err(e) => Err(format!("{}. {}", e, runtime_profile_hint(&cluster.cluster_type))),
+    };
+
+    Ok(Json(build_running_query_detail(&cluster.cluster_type, query, queue_state, profile)))
+}
+
+/// State of a query in `SHOW RUNNING QUERIES`; older versions without a query queue have none
+async fn running_query_state(mysql_client: &MySQLClient, query_id: &str) -> Option<String> {
+    let (columns, rows) = mysql_client
+        .query_raw("SHOW RUNNING QUERIES")
+        .await
+        .map_err(|e| tracing::debug!("SHOW RUNNING QUERIES failed: {}", e))
+        .ok()?;
+    rows.iter()
+        .map(|row| RowView::new(&columns, row))
+        .find(|v| v.get(&["QueryId"]) == Some(query_id))
+        .and_then(|v| v.get(&["State"]).map(str::to_string))
+}
+
 // Kill a query
 #[utoipa::path(
     delete,
@@ -473,6 +554,19 @@ pub async fn execute_sql(
         session.use_database(db).await?;
     }
 
+    let requester = SessionTemplateRequester {
+        username: &org_ctx.username,
+        organization_id: org_ctx.organization_id,
+        is_super_admin: org_ctx.is_super_admin,
+    };
+    let set_statements = state
+        .session_template_service
+        .session_statements(request.template_id, request.session_variables.as_ref(), &requester)
+        .await?;
+    for statement in &set_statements {
+        session.execute(statement).await?;
+    }
+
     let total_start = Instant::now();
     let mut results = Vec::new();
 
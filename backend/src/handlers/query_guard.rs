@@ -0,0 +1,206 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::services::query_guard_service::{
+    QueryGuardAction, QueryGuardPolicy, QueryGuardPolicyRequest, QueryGuardRequester,
+    QueryGuardViolation,
+};
+use crate::utils::ApiResult;
+
+#[derive(Debug, Deserialize)]
+pub struct QueryGuardActionParams {
+    pub policy_id: Option<i64>,
+    #[serde(default = "default_action_limit")]
+    pub limit: i64,
+}
+
+fn default_action_limit() -> i64 {
+    200
+}
+
+fn requester(org_ctx: &crate::middleware::OrgContext) -> QueryGuardRequester<'_> {
+    QueryGuardRequester {
+        username: &org_ctx.username,
+        organization_id: org_ctx.organization_id,
+        is_super_admin: org_ctx.is_super_admin,
+    }
+}
+
+/// GET /api/clusters/query-guard/policies - Guard policies
+#[utoipa::path(
+    get,
+    path = "/api/clusters/query-guard/policies",
+    responses(
+        (status = 200, description = "Guard policies", body = Vec<QueryGuardPolicy>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Query Guard"
+)]
+pub async fn list_query_guard_policies(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+) -> ApiResult<Json<Vec<QueryGuardPolicy>>> {
+    let policies = state
+        .query_guard_service
+        .list_policies(&requester(&org_ctx))
+        .await?;
+    Ok(Json(policies))
+}
+
+/// POST /api/clusters/query-guard/policies - Create a guard policy
+#[utoipa::path(
+    post,
+    path = "/api/clusters/query-guard/policies",
+    request_body = QueryGuardPolicyRequest,
+    responses(
+        (status = 200, description = "Policy created", body = QueryGuardPolicy),
+        (status = 400, description = "Invalid limits, mode or webhook"),
+        (status = 403, description = "Cluster outside your organization")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Query Guard"
+)]
+pub async fn create_query_guard_policy(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(request): Json<QueryGuardPolicyRequest>,
+) -> ApiResult<Json<QueryGuardPolicy>> {
+    let policy = state
+        .query_guard_service
+        .create_policy(&request, &requester(&org_ctx))
+        .await?;
+    Ok(Json(policy))
+}
+
+/// GET /api/clusters/query-guard/policies/{id} - Get a guard policy
+#[utoipa::path(
+    get,
+    path = "/api/clusters/query-guard/policies/{id}",
+    params(("id" = i64, Path, description = "Policy ID")),
+    responses(
+        (status = 200, description = "Guard policy", body = QueryGuardPolicy),
+        (status = 404, description = "Policy not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Query Guard"
+)]
+pub async fn get_query_guard_policy(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<QueryGuardPolicy>> {
+    let policy = state
+        .query_guard_service
+        .get_policy(id, &requester(&org_ctx))
+        .await?;
+    Ok(Json(policy))
+}
+
+/// PUT /api/clusters/query-guard/policies/{id} - Update a guard policy
+#[utoipa::path(
+    put,
+    path = "/api/clusters/query-guard/policies/{id}",
+    params(("id" = i64, Path, description = "Policy ID")),
+    request_body = QueryGuardPolicyRequest,
+    responses(
+        (status = 200, description = "Policy updated", body = QueryGuardPolicy),
+        (status = 400, description = "Invalid limits, mode or webhook"),
+        (status = 404, description = "Policy not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Query Guard"
+)]
+pub async fn update_query_guard_policy(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(id): Path<i64>,
+    Json(request): Json<QueryGuardPolicyRequest>,
+) -> ApiResult<Json<QueryGuardPolicy>> {
+    let policy = state
+        .query_guard_service
+        .update_policy(id, &request, &requester(&org_ctx))
+        .await?;
+    Ok(Json(policy))
+}
+
+/// DELETE /api/clusters/query-guard/policies/{id} - Delete a guard policy
+#[utoipa::path(
+    delete,
+    path = "/api/clusters/query-guard/policies/{id}",
+    params(("id" = i64, Path, description = "Policy ID")),
+    responses(
+        (status = 200, description = "Policy deleted"),
+        (status = 404, description = "Policy not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Query Guard"
+)]
+pub async fn delete_query_guard_policy(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<impl IntoResponse> {
+    state
+        .query_guard_service
+        .delete_policy(id, &requester(&org_ctx))
+        .await?;
+    Ok((StatusCode::OK, Json(json!({ "message": "Query guard policy deleted successfully" }))))
+}
+
+/// POST /api/clusters/query-guard/preview - Running queries a policy would kill right now
+#[utoipa::path(
+    post,
+    path = "/api/clusters/query-guard/preview",
+    request_body = QueryGuardPolicyRequest,
+    responses(
+        (status = 200, description = "Violating queries, nothing is killed", body = Vec<QueryGuardViolation>),
+        (status = 400, description = "Invalid limits, mode or webhook")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Query Guard"
+)]
+pub async fn preview_query_guard_policy(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(request): Json<QueryGuardPolicyRequest>,
+) -> ApiResult<Json<Vec<QueryGuardViolation>>> {
+    let violations = state
+        .query_guard_service
+        .preview(&request, &requester(&org_ctx))
+        .await?;
+    Ok(Json(violations))
+}
+
+/// GET /api/clusters/query-guard/actions - Logged violations and kills
+#[utoipa::path(
+    get,
+    path = "/api/clusters/query-guard/actions",
+    params(
+        ("policy_id" = Option<i64>, Query, description = "Only this policy"),
+        ("limit" = Option<i64>, Query, description = "Max entries (default: 200)"),
+    ),
+    responses(
+        (status = 200, description = "Guard actions, newest first", body = Vec<QueryGuardAction>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Query Guard"
+)]
+pub async fn list_query_guard_actions(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<QueryGuardActionParams>,
+) -> ApiResult<Json<Vec<QueryGuardAction>>> {
+    let actions = state
+        .query_guard_service
+        .list_actions(params.policy_id, params.limit.clamp(1, 1000), &requester(&org_ctx))
+        .await?;
+    Ok(Json(actions))
+}
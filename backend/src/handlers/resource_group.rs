@@ -0,0 +1,200 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::models::{
+    AlterResourceGroupRequest, Cluster, CreateResourceGroupRequest, ResourceGroup,
+    ResourceGroupUsage, ResourceGroupUsageRecord,
+};
+use crate::services::create_adapter;
+use crate::utils::ApiResult;
+
+#[derive(Debug, Deserialize)]
+pub struct ResourceGroupHistoryParams {
+    /// Only this group; all groups when omitted
+    pub group: Option<String>,
+    #[serde(default = "default_history_hours")]
+    pub hours: i64,
+}
+
+fn default_history_hours() -> i64 {
+    24
+}
+
+async fn active_cluster(
+    state: &AppState,
+    org_ctx: &crate::middleware::OrgContext,
+) -> ApiResult<Cluster> {
+    if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await
+    }
+}
+
+/// GET /api/clusters/resource-groups - Resource groups (StarRocks) or workload groups (Doris)
+#[utoipa::path(
+    get,
+    path = "/api/clusters/resource-groups",
+    responses(
+        (status = 200, description = "Groups with properties and classifiers", body = Vec<ResourceGroup>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Resource Groups"
+)]
+pub async fn list_resource_groups(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+) -> ApiResult<Json<Vec<ResourceGroup>>> {
+    let cluster = active_cluster(&state, &org_ctx).await?;
+    let adapter = create_adapter(cluster, state.mysql_pool_manager.clone());
+    Ok(Json(adapter.list_resource_groups().await?))
+}
+
+/// POST /api/clusters/resource-groups - Create a resource group / workload group
+#[utoipa::path(
+    post,
+    path = "/api/clusters/resource-groups",
+    request_body = CreateResourceGroupRequest,
+    responses(
+        (status = 200, description = "Group created"),
+        (status = 400, description = "Invalid name, classifier or property"),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Resource Groups"
+)]
+pub async fn create_resource_group(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(request): Json<CreateResourceGroupRequest>,
+) -> ApiResult<Json<serde_json::Value>> {
+    let cluster = active_cluster(&state, &org_ctx).await?;
+    tracing::info!("Creating resource group {} on cluster {}", request.name, cluster.id);
+
+    let adapter = create_adapter(cluster, state.mysql_pool_manager.clone());
+    adapter.create_resource_group(&request).await?;
+
+    Ok(Json(serde_json::json!({
+        "message": format!("Resource group {} created successfully", request.name)
+    })))
+}
+
+/// PUT /api/clusters/resource-groups/{name} - Change classifiers or properties of a group
+#[utoipa::path(
+    put,
+    path = "/api/clusters/resource-groups/{name}",
+    params(("name" = String, Path, description = "Group name")),
+    request_body = AlterResourceGroupRequest,
+    responses(
+        (status = 200, description = "Group altered"),
+        (status = 400, description = "Invalid classifier or property"),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Resource Groups"
+)]
+pub async fn alter_resource_group(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(name): Path<String>,
+    Json(request): Json<AlterResourceGroupRequest>,
+) -> ApiResult<Json<serde_json::Value>> {
+    let cluster = active_cluster(&state, &org_ctx).await?;
+    tracing::info!("Altering resource group {} on cluster {}", name, cluster.id);
+
+    let adapter = create_adapter(cluster, state.mysql_pool_manager.clone());
+    adapter.alter_resource_group(&name, &request).await?;
+
+    Ok(Json(serde_json::json!({
+        "message": format!("Resource group {} altered successfully", name)
+    })))
+}
+
+/// DELETE /api/clusters/resource-groups/{name} - Drop a resource group / workload group
+#[utoipa::path(
+    delete,
+    path = "/api/clusters/resource-groups/{name}",
+    params(("name" = String, Path, description = "Group name")),
+    responses(
+        (status = 200, description = "Group dropped"),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Resource Groups"
+)]
+pub async fn drop_resource_group(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(name): Path<String>,
+) -> ApiResult<Json<serde_json::Value>> {
+    let cluster = active_cluster(&state, &org_ctx).await?;
+    tracing::info!("Dropping resource group {} on cluster {}", name, cluster.id);
+
+    let adapter = create_adapter(cluster, state.mysql_pool_manager.clone());
+    adapter.drop_resource_group(&name).await?;
+
+    Ok(Json(serde_json::json!({
+        "message": format!("Resource group {} dropped successfully", name)
+    })))
+}
+
+/// GET /api/clusters/resource-groups/usage - Running / queued queries and CPU / memory per group
+#[utoipa::path(
+    get,
+    path = "/api/clusters/resource-groups/usage",
+    responses(
+        (status = 200, description = "Current usage per group", body = Vec<ResourceGroupUsage>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Resource Groups"
+)]
+pub async fn get_resource_group_usage(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+) -> ApiResult<Json<Vec<ResourceGroupUsage>>> {
+    let cluster = active_cluster(&state, &org_ctx).await?;
+    let adapter = create_adapter(cluster, state.mysql_pool_manager.clone());
+    Ok(Json(adapter.get_resource_group_usage().await?))
+}
+
+/// GET /api/clusters/resource-groups/history - Usage recorded by the metrics collector
+#[utoipa::path(
+    get,
+    path = "/api/clusters/resource-groups/history",
+    params(
+        ("group" = Option<String>, Query, description = "Group name (default: all groups)"),
+        ("hours" = Option<i64>, Query, description = "Look-back window in hours (default: 24)"),
+    ),
+    responses(
+        (status = 200, description = "Usage samples, oldest first", body = Vec<ResourceGroupUsageRecord>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Resource Groups"
+)]
+pub async fn get_resource_group_history(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<ResourceGroupHistoryParams>,
+) -> ApiResult<Json<Vec<ResourceGroupUsageRecord>>> {
+    let cluster = active_cluster(&state, &org_ctx).await?;
+    let records = state
+        .metrics_collector_service
+        .get_resource_group_history(
+            cluster.id,
+            params.group.as_deref(),
+            params.hours.clamp(1, 24 * 30),
+        )
+        .await?;
+    Ok(Json(records))
+}
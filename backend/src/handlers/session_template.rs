@@ -0,0 +1,172 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::services::MySQLClient;
+use crate::services::session_template_service::{
+    AppliedSuggestion, ApplySuggestionRequest, SessionTemplateRequester, SessionVariableTemplate,
+    SessionVariableTemplateRequest, TARGET_USER, build_applied_suggestion,
+};
+use crate::utils::ApiResult;
+
+fn requester(org_ctx: &crate::middleware::OrgContext) -> SessionTemplateRequester<'_> {
+    SessionTemplateRequester {
+        username: &org_ctx.username,
+        organization_id: org_ctx.organization_id,
+        is_super_admin: org_ctx.is_super_admin,
+    }
+}
+
+/// GET /api/clusters/session-templates - Session variable templates of the organization
+#[utoipa::path(
+    get,
+    path = "/api/clusters/session-templates",
+    responses(
+        (status = 200, description = "Session variable templates", body = Vec<SessionVariableTemplate>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Session Variables"
+)]
+pub async fn list_session_templates(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+) -> ApiResult<Json<Vec<SessionVariableTemplate>>> {
+    let templates = state
+        .session_template_service
+        .list_templates(&requester(&org_ctx))
+        .await?;
+    Ok(Json(templates))
+}
+
+/// POST /api/clusters/session-templates - Create a session variable template
+#[utoipa::path(
+    post,
+    path = "/api/clusters/session-templates",
+    request_body = SessionVariableTemplateRequest,
+    responses(
+        (status = 200, description = "Template created", body = SessionVariableTemplate),
+        (status = 400, description = "Invalid name, variable or duplicate template")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Session Variables"
+)]
+pub async fn create_session_template(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(request): Json<SessionVariableTemplateRequest>,
+) -> ApiResult<Json<SessionVariableTemplate>> {
+    let template = state
+        .session_template_service
+        .create_template(&request, &requester(&org_ctx))
+        .await?;
+    Ok(Json(template))
+}
+
+/// PUT /api/clusters/session-templates/{id} - Update a session variable template
+#[utoipa::path(
+    put,
+    path = "/api/clusters/session-templates/{id}",
+    params(("id" = i64, Path, description = "Template ID")),
+    request_body = SessionVariableTemplateRequest,
+    responses(
+        (status = 200, description = "Template updated", body = SessionVariableTemplate),
+        (status = 400, description = "Invalid name, variable or duplicate template"),
+        (status = 404, description = "Template not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Session Variables"
+)]
+pub async fn update_session_template(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(id): Path<i64>,
+    Json(request): Json<SessionVariableTemplateRequest>,
+) -> ApiResult<Json<SessionVariableTemplate>> {
+    let template = state
+        .session_template_service
+        .update_template(id, &request, &requester(&org_ctx))
+        .await?;
+    Ok(Json(template))
+}
+
+/// DELETE /api/clusters/session-templates/{id} - Delete a session variable template
+#[utoipa::path(
+    delete,
+    path = "/api/clusters/session-templates/{id}",
+    params(("id" = i64, Path, description = "Template ID")),
+    responses(
+        (status = 200, description = "Template deleted"),
+        (status = 404, description = "Template not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Session Variables"
+)]
+pub async fn delete_session_template(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<impl IntoResponse> {
+    state
+        .session_template_service
+        .delete_template(id, &requester(&org_ctx))
+        .await?;
+    Ok((
+        StatusCode::OK,
+        Json(json!({ "message": "Session variable template deleted successfully" })),
+    ))
+}
+
+/// POST /api/clusters/session-variables/apply - Apply a parameter suggestion
+///
+/// The session target only validates the suggestion and returns the statement, the SQL editor
+/// sends it back as `session_variables` of `execute_sql`. The user target persists it on the
+/// cluster.
+#[utoipa::path(
+    post,
+    path = "/api/clusters/session-variables/apply",
+    request_body = ApplySuggestionRequest,
+    responses(
+        (status = 200, description = "Suggestion applied", body = AppliedSuggestion),
+        (status = 400, description = "Not a session SET command, invalid target or user"),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Session Variables"
+)]
+pub async fn apply_parameter_suggestion(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(request): Json<ApplySuggestionRequest>,
+) -> ApiResult<Json<AppliedSuggestion>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let applied = build_applied_suggestion(&cluster.cluster_type, &request)?;
+    if applied.target == TARGET_USER {
+        tracing::info!(
+            "{} sets {} = {} for user {:?} on cluster {}",
+            org_ctx.username,
+            applied.name,
+            applied.value,
+            applied.user,
+            cluster.id
+        );
+        let pool = state.mysql_pool_manager.get_pool(&cluster).await?;
+        MySQLClient::from_pool(pool)
+            .execute(&applied.statement)
+            .await?;
+    }
+    Ok(Json(applied))
+}
@@ -0,0 +1,230 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::AppState;
+use crate::services::AuditLogService;
+use crate::services::sql_blacklist_service::{
+    DEFAULT_SAMPLES_PER_USER, DEFAULT_TEST_DAYS, SqlBlacklistRequester, SqlBlacklistRuleDetail,
+    SqlBlacklistRuleRequest, SqlBlacklistTestRequest, SqlBlacklistTestResult,
+    compile_blacklist_pattern, preview_blacklist_pattern,
+};
+use crate::utils::ApiResult;
+
+/// Statements pulled from the audit log for a pattern test
+const TEST_STATEMENT_LIMIT: usize = 100_000;
+
+fn requester(org_ctx: &crate::middleware::OrgContext) -> SqlBlacklistRequester<'_> {
+    SqlBlacklistRequester {
+        user_id: org_ctx.user_id,
+        username: &org_ctx.username,
+        organization_id: org_ctx.organization_id,
+        is_super_admin: org_ctx.is_super_admin,
+    }
+}
+
+/// POST /api/clusters/sql-blacklist/test - Preview which recent SQL a pattern would block
+#[utoipa::path(
+    post,
+    path = "/api/clusters/sql-blacklist/test",
+    request_body = SqlBlacklistTestRequest,
+    responses(
+        (status = 200, description = "Matching statements per user", body = SqlBlacklistTestResult),
+        (status = 400, description = "Invalid pattern"),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "SQL Blacklist"
+)]
+pub async fn test_sql_blacklist_pattern(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(request): Json<SqlBlacklistTestRequest>,
+) -> ApiResult<Json<SqlBlacklistTestResult>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let pattern = request.pattern.trim().to_string();
+    let regex = compile_blacklist_pattern(&pattern)?;
+    let days = request.days.unwrap_or(DEFAULT_TEST_DAYS).clamp(1, 90);
+
+    let audit_service =
+        AuditLogService::new(state.mysql_pool_manager.clone(), state.audit_config.clone());
+    let statements = audit_service
+        .get_recent_statements(&cluster, (days * 24) as i32, TEST_STATEMENT_LIMIT)
+        .await?;
+    let (matched_statements, users) = preview_blacklist_pattern(
+        &regex,
+        &statements,
+        request
+            .samples_per_user
+            .unwrap_or(DEFAULT_SAMPLES_PER_USER)
+            .min(50),
+    );
+
+    Ok(Json(SqlBlacklistTestResult {
+        pattern,
+        days,
+        scanned_statements: statements.len(),
+        matched_statements,
+        truncated: statements.len() >= TEST_STATEMENT_LIMIT,
+        users,
+    }))
+}
+
+/// GET /api/clusters/sql-blacklist/rules - Blacklist rules managed by Stellar
+#[utoipa::path(
+    get,
+    path = "/api/clusters/sql-blacklist/rules",
+    responses(
+        (status = 200, description = "Managed rules with per-cluster state", body = Vec<SqlBlacklistRuleDetail>)
+    ),
+    security(("bearer_auth" = [])),
+    tag = "SQL Blacklist"
+)]
+pub async fn list_sql_blacklist_rules(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+) -> ApiResult<Json<Vec<SqlBlacklistRuleDetail>>> {
+    let rules = state
+        .sql_blacklist_service
+        .list_rules(&requester(&org_ctx))
+        .await?;
+    Ok(Json(rules))
+}
+
+/// POST /api/clusters/sql-blacklist/rules - Create a rule and apply it to its clusters
+#[utoipa::path(
+    post,
+    path = "/api/clusters/sql-blacklist/rules",
+    request_body = SqlBlacklistRuleRequest,
+    responses(
+        (status = 200, description = "Rule created", body = SqlBlacklistRuleDetail),
+        (status = 400, description = "Invalid pattern or no cluster"),
+        (status = 403, description = "Cluster outside your organization")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "SQL Blacklist"
+)]
+pub async fn create_sql_blacklist_rule(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Json(request): Json<SqlBlacklistRuleRequest>,
+) -> ApiResult<Json<SqlBlacklistRuleDetail>> {
+    let rule = state
+        .sql_blacklist_service
+        .create_rule(&request, &requester(&org_ctx))
+        .await?;
+    Ok(Json(rule))
+}
+
+/// GET /api/clusters/sql-blacklist/rules/{id} - Get a managed rule
+#[utoipa::path(
+    get,
+    path = "/api/clusters/sql-blacklist/rules/{id}",
+    params(("id" = i64, Path, description = "Rule ID")),
+    responses(
+        (status = 200, description = "Rule with per-cluster state", body = SqlBlacklistRuleDetail),
+        (status = 404, description = "Rule not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "SQL Blacklist"
+)]
+pub async fn get_sql_blacklist_rule(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<SqlBlacklistRuleDetail>> {
+    let rule = state
+        .sql_blacklist_service
+        .get_rule(id, &requester(&org_ctx))
+        .await?;
+    Ok(Json(rule))
+}
+
+/// PUT /api/clusters/sql-blacklist/rules/{id} - Update a rule and re-sync its clusters
+#[utoipa::path(
+    put,
+    path = "/api/clusters/sql-blacklist/rules/{id}",
+    params(("id" = i64, Path, description = "Rule ID")),
+    request_body = SqlBlacklistRuleRequest,
+    responses(
+        (status = 200, description = "Rule updated", body = SqlBlacklistRuleDetail),
+        (status = 400, description = "Invalid pattern or no cluster"),
+        (status = 404, description = "Rule not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "SQL Blacklist"
+)]
+pub async fn update_sql_blacklist_rule(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(id): Path<i64>,
+    Json(request): Json<SqlBlacklistRuleRequest>,
+) -> ApiResult<Json<SqlBlacklistRuleDetail>> {
+    let rule = state
+        .sql_blacklist_service
+        .update_rule(id, &request, &requester(&org_ctx))
+        .await?;
+    Ok(Json(rule))
+}
+
+/// DELETE /api/clusters/sql-blacklist/rules/{id} - Remove a rule from its clusters and delete it
+#[utoipa::path(
+    delete,
+    path = "/api/clusters/sql-blacklist/rules/{id}",
+    params(("id" = i64, Path, description = "Rule ID")),
+    responses(
+        (status = 200, description = "Rule deleted"),
+        (status = 404, description = "Rule not found"),
+        (status = 500, description = "Removal failed on some clusters")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "SQL Blacklist"
+)]
+pub async fn delete_sql_blacklist_rule(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<impl IntoResponse> {
+    state
+        .sql_blacklist_service
+        .delete_rule(id, &requester(&org_ctx))
+        .await?;
+    Ok((StatusCode::OK, Json(json!({ "message": "SQL blacklist rule deleted successfully" }))))
+}
+
+/// POST /api/clusters/sql-blacklist/rules/{id}/sync - Apply a rule to its clusters now
+#[utoipa::path(
+    post,
+    path = "/api/clusters/sql-blacklist/rules/{id}/sync",
+    params(("id" = i64, Path, description = "Rule ID")),
+    responses(
+        (status = 200, description = "Rule with refreshed per-cluster state", body = SqlBlacklistRuleDetail),
+        (status = 404, description = "Rule not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "SQL Blacklist"
+)]
+pub async fn sync_sql_blacklist_rule(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<SqlBlacklistRuleDetail>> {
+    let rule = state
+        .sql_blacklist_service
+        .sync_rule(id, &requester(&org_ctx))
+        .await?;
+    Ok(Json(rule))
+}
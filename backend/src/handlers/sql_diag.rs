@@ -1,6 +1,8 @@
-//! SQL Diagnosis Handler - LLM-enhanced SQL performance analysis
+//! SQL Diagnosis Handler - LLM-enhanced SQL performance analysis and rule-based
+//! EXPLAIN linting
 
-use axum::extract::{Json, Path, State};
+use axum::extract::{Extension, Json, Path, State};
+use axum::http::HeaderMap;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -9,7 +11,9 @@ use crate::AppState;
 use crate::LLMService;
 use crate::services::llm::{SqlDiagReq, SqlDiagResp};
 use crate::services::mysql_client::MySQLClient;
-use crate::utils::error::ApiResult;
+use crate::services::profile_analyzer::DiagnosticResult;
+use crate::services::profile_analyzer::analyzer::{ExplainPlan, rules::explain};
+use crate::utils::error::{ApiError, ApiResult};
 
 // ============================================================================
 // Request/Response
@@ -35,6 +39,23 @@ pub struct DiagResp {
     pub ms: u64,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct LintReq {
+    pub sql: String,
+    #[serde(default)]
+    pub database: Option<String>,
+    #[serde(default)]
+    pub catalog: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LintResp {
+    pub diagnostics: Vec<DiagnosticResult>,
+    /// `EXPLAIN VERBOSE` output the diagnostics refer to
+    pub explain: String,
+    pub ms: u64,
+}
+
 impl DiagResp {
     fn ok(data: SqlDiagResp, cached: bool, ms: u64) -> Self {
         Self { ok: true, data: Some(data), err: None, cached, ms }
@@ -121,7 +142,67 @@ pub async fn diagnose(
     }
 }
 
-/// Execute EXPLAIN VERBOSE
+/// POST /api/clusters/:cluster_id/sql/lint
+///
+/// Rule-based checks on the `EXPLAIN VERBOSE` plan before the query runs; works
+/// without an LLM provider.
+#[utoipa::path(
+    post,
+    path = "/api/clusters/{cluster_id}/sql/lint",
+    params(("cluster_id" = i64, Path, description = "Cluster ID")),
+    request_body = LintReq,
+    responses(
+        (status = 200, description = "Plan diagnostics", body = LintResp),
+        (status = 400, description = "Not a single SELECT statement, or EXPLAIN failed"),
+        (status = 403, description = "Cluster belongs to another organization"),
+        (status = 404, description = "Cluster not found"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "SQL Diagnosis"
+)]
+pub async fn lint(
+    State(s): State<Arc<AppState>>,
+    Extension(org_ctx): Extension<crate::middleware::OrgContext>,
+    headers: HeaderMap,
+    Path(cid): Path<i64>,
+    Json(req): Json<LintReq>,
+) -> ApiResult<Json<LintResp>> {
+    let t0 = std::time::Instant::now();
+
+    let cluster = s.cluster_service.get_cluster(cid).await?;
+    if !org_ctx.is_super_admin && cluster.organization_id != org_ctx.organization_id {
+        return Err(ApiError::forbidden(format!(
+            "Cluster {} does not belong to your organization",
+            cluster.name
+        )));
+    }
+    // The linter is read-only: only a single SELECT is ever sent to the cluster
+    let sql = crate::handlers::profile::explainable_statement(&req.sql).ok_or_else(|| {
+        ApiError::validation_error("Only a single SELECT statement without comments can be linted")
+    })?;
+
+    let pool = s.mysql_pool_manager.get_pool(&cluster).await?;
+    let client = MySQLClient::from_pool(pool);
+
+    let db = req.database.as_deref().unwrap_or("");
+    let cat = req.catalog.as_deref().unwrap_or("default_catalog");
+    let explain = run_explain(&client, cat, db, sql, &cluster.cluster_type)
+        .await
+        .map_err(|e| ApiError::invalid_data(format!("EXPLAIN failed: {}", e)))?;
+
+    let locale = crate::handlers::profile::resolve_locale(&s, org_ctx.user_id, &headers).await;
+    let diagnostics: Vec<DiagnosticResult> =
+        explain::evaluate_explain_rules(&ExplainPlan::parse(&explain))
+            .iter()
+            .map(|d| d.to_result(locale))
+            .collect();
+
+    tracing::info!("SQL lint: catalog={}, db={}, {} diagnostics", cat, db, diagnostics.len());
+
+    Ok(Json(LintResp { diagnostics, explain, ms: t0.elapsed().as_millis() as u64 }))
+}
+
+/// Execute EXPLAIN VERBOSE, truncated to fit the LLM prompt
 async fn exec_explain(
     client: &MySQLClient,
     cat: &str,
@@ -129,29 +210,9 @@ async fn exec_explain(
     sql: &str,
     cluster_type: &crate::models::cluster::ClusterType,
 ) -> Result<String, String> {
-    let mut sess = client.create_session().await.map_err(|e| e.to_string())?;
-
-    if !cat.is_empty() && cat != "default_catalog" {
-        sess.use_catalog(cat, cluster_type)
-            .await
-            .map_err(|e| format!("Failed to use catalog {}: {}", cat, e))?;
-    }
-
-    if !db.is_empty() {
-        sess.use_database(db)
-            .await
-            .map_err(|e| format!("Failed to use database {}: {}", db, e))?;
-    }
-
-    let explain_sql = format!("EXPLAIN VERBOSE {}", sql.trim().trim_end_matches(';'));
-    let (_, rows, _) = sess
-        .execute(&explain_sql)
-        .await
-        .map_err(|e| e.to_string())?;
-
-    let result: String = rows
-        .into_iter()
-        .flat_map(|r| r.into_iter())
+    let result = run_explain(client, cat, db, sql, cluster_type)
+        .await?
+        .lines()
         .take(1000)
         .collect::<Vec<_>>()
         .join("\n");
@@ -178,6 +239,41 @@ async fn exec_explain(
     }
 }
 
+/// Execute EXPLAIN VERBOSE and return the full plan text
+async fn run_explain(
+    client: &MySQLClient,
+    cat: &str,
+    db: &str,
+    sql: &str,
+    cluster_type: &crate::models::cluster::ClusterType,
+) -> Result<String, String> {
+    let mut sess = client.create_session().await.map_err(|e| e.to_string())?;
+
+    if !cat.is_empty() && cat != "default_catalog" {
+        sess.use_catalog(cat, cluster_type)
+            .await
+            .map_err(|e| format!("Failed to use catalog {}: {}", cat, e))?;
+    }
+
+    if !db.is_empty() {
+        sess.use_database(db)
+            .await
+            .map_err(|e| format!("Failed to use database {}: {}", db, e))?;
+    }
+
+    let explain_sql = format!("EXPLAIN VERBOSE {}", sql.trim().trim_end_matches(';'));
+    let (_, rows, _) = sess
+        .execute(&explain_sql)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(rows
+        .into_iter()
+        .flat_map(|r| r.into_iter())
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
 /// Fetch table schemas as JSON with table type info (internal/external)
 async fn fetch_schema(
     client: &MySQLClient,
@@ -10,6 +10,10 @@ use std::sync::Arc;
 
 use crate::{
     models::starrocks::{UpdateVariableRequest, Variable},
+    services::global_variable_history_service::{
+        GlobalVariableChange, GlobalVariableRollback, GlobalVariableRollbackRequest,
+        GlobalVariableSnapshot,
+    },
     services::mysql_client::MySQLClient,
     utils::error::{ApiError, ApiResult},
 };
@@ -25,12 +29,27 @@ pub struct VariableQueryParams {
 pub struct ConfigEntry {
     pub name: String,
     pub value: String,
+    /// Whether `ADMIN SET FRONTEND CONFIG` can change it at runtime, when reported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mutable: Option<bool>,
 }
 
 fn default_type() -> String {
     "global".to_string()
 }
 
+#[derive(Debug, Deserialize)]
+pub struct VariableHistoryParams {
+    /// Only this variable; all variables when omitted
+    pub variable: Option<String>,
+    #[serde(default = "default_history_limit")]
+    pub limit: i64,
+}
+
+fn default_history_limit() -> i64 {
+    200
+}
+
 /// Get system variables
 #[utoipa::path(
     get,
@@ -44,9 +63,7 @@ fn default_type() -> String {
         (status = 404, description = "No active cluster found"),
         (status = 500, description = "Internal server error")
     ),
-    security(
-        ("bearer" = [])
-    )
+    security(("bearer_auth" = []))
 )]
 pub async fn get_variables(
     State(state): State<Arc<crate::AppState>>,
@@ -98,9 +115,7 @@ pub async fn get_variables(
         (status = 404, description = "No active cluster found"),
         (status = 500, description = "Internal server error")
     ),
-    security(
-        ("bearer" = [])
-    )
+    security(("bearer_auth" = []))
 )]
 pub async fn get_configure_info(
     State(state): State<Arc<crate::AppState>>,
@@ -118,6 +133,208 @@ pub async fn get_configure_info(
     let pool = state.mysql_pool_manager.get_pool(&cluster).await?;
     let mysql_client = MySQLClient::from_pool(pool);
 
+    let configs = fetch_frontend_configs(&mysql_client).await?;
+
+    Ok(Json(configs))
+}
+
+/// Update a variable
+#[utoipa::path(
+    put,
+    path = "/api/clusters/variables/{variable_name}",
+    params(
+        ("variable_name" = String, Path, description = "Variable name")
+    ),
+    request_body = UpdateVariableRequest,
+    responses(
+        (status = 200, description = "Variable updated successfully"),
+        (status = 404, description = "No active cluster found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_variable(
+    State(state): State<Arc<crate::AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(variable_name): Path<String>,
+    Json(request): Json<UpdateVariableRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let pool = state.mysql_pool_manager.get_pool(&cluster).await?;
+    let mysql_client = MySQLClient::from_pool(pool);
+
+    let scope = match request.scope.to_uppercase().as_str() {
+        "GLOBAL" => "GLOBAL",
+        "SESSION" => "SESSION",
+        _ => return Err(ApiError::invalid_data("Invalid scope. Must be GLOBAL or SESSION")),
+    };
+
+    let sql = format!("SET {} {} = {}", scope, variable_name, request.value);
+
+    if scope == "GLOBAL" {
+        state
+            .global_variable_history_service
+            .apply_global_change(&cluster, &sql, &org_ctx.username)
+            .await?;
+    } else {
+        mysql_client.execute(&sql).await?;
+    }
+
+    Ok((StatusCode::OK, Json(json!({ "message": "Variable updated successfully" }))))
+}
+
+/// Global variable changes recorded by snapshots, newest first
+#[utoipa::path(
+    get,
+    path = "/api/clusters/variables/history",
+    params(
+        ("variable" = Option<String>, Query, description = "Variable name (default: all variables)"),
+        ("limit" = Option<i64>, Query, description = "Max entries (default: 200)")
+    ),
+    responses(
+        (status = 200, description = "Variable changes", body = Vec<GlobalVariableChange>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_variable_history(
+    State(state): State<Arc<crate::AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<VariableHistoryParams>,
+) -> ApiResult<Json<Vec<GlobalVariableChange>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let changes = state
+        .global_variable_history_service
+        .list_changes(
+            cluster.id,
+            params.variable.as_deref().filter(|v| !v.is_empty()),
+            None,
+            params.limit.clamp(1, 1000),
+        )
+        .await?;
+    Ok(Json(changes))
+}
+
+/// Global variable snapshots, newest first
+#[utoipa::path(
+    get,
+    path = "/api/clusters/variables/snapshots",
+    params(
+        ("limit" = Option<i64>, Query, description = "Max entries (default: 200)")
+    ),
+    responses(
+        (status = 200, description = "Snapshots without their variables", body = Vec<GlobalVariableSnapshot>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_variable_snapshots(
+    State(state): State<Arc<crate::AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Query(params): Query<VariableHistoryParams>,
+) -> ApiResult<Json<Vec<GlobalVariableSnapshot>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let snapshots = state
+        .global_variable_history_service
+        .list_snapshots(cluster.id, params.limit.clamp(1, 1000))
+        .await?;
+    Ok(Json(snapshots))
+}
+
+/// Snapshot global variables now and return the changes made outside Stellar
+#[utoipa::path(
+    post,
+    path = "/api/clusters/variables/snapshots",
+    responses(
+        (status = 200, description = "Out-of-band changes since the last snapshot", body = Vec<GlobalVariableChange>),
+        (status = 404, description = "No active cluster found")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_variable_snapshot(
+    State(state): State<Arc<crate::AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+) -> ApiResult<Json<Vec<GlobalVariableChange>>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let changes = state
+        .global_variable_history_service
+        .detect_changes(&cluster)
+        .await?;
+    Ok(Json(changes))
+}
+
+/// Generate (and optionally run) the SET GLOBAL statements restoring a snapshot
+#[utoipa::path(
+    post,
+    path = "/api/clusters/variables/snapshots/{snapshot_id}/rollback",
+    params(
+        ("snapshot_id" = i64, Path, description = "Snapshot ID")
+    ),
+    request_body = GlobalVariableRollbackRequest,
+    responses(
+        (status = 200, description = "Rollback statements", body = GlobalVariableRollback),
+        (status = 404, description = "Snapshot or active cluster not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn rollback_variable_snapshot(
+    State(state): State<Arc<crate::AppState>>,
+    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
+    Path(snapshot_id): Path<i64>,
+    Json(request): Json<GlobalVariableRollbackRequest>,
+) -> ApiResult<Json<GlobalVariableRollback>> {
+    let cluster = if org_ctx.is_super_admin {
+        state.cluster_service.get_active_cluster().await?
+    } else {
+        state
+            .cluster_service
+            .get_active_cluster_by_org(org_ctx.organization_id)
+            .await?
+    };
+
+    let rollback = state
+        .global_variable_history_service
+        .rollback(&cluster, snapshot_id, request.execute, &org_ctx.username)
+        .await?;
+    Ok(Json(rollback))
+}
+
+/// FE config of a cluster, trying the statements StarRocks and Doris versions understand
+pub(crate) async fn fetch_frontend_configs(
+    mysql_client: &MySQLClient,
+) -> ApiResult<Vec<ConfigEntry>> {
     let mut configs: Vec<ConfigEntry> = Vec::new();
     let mut errors: Vec<String> = Vec::new();
 
@@ -136,6 +353,9 @@ pub async fn get_configure_info(
                     let lc = c.to_lowercase();
                     lc == "value" || lc == "config_value" || lc == "configvalue"
                 });
+                let mutable_idx = columns
+                    .iter()
+                    .position(|c| c.eq_ignore_ascii_case("ismutable"));
 
                 tracing::info!("name_idx: {:?}, value_idx: {:?}", name_idx, value_idx);
 
@@ -147,7 +367,14 @@ pub async fn get_configure_info(
                             let value = value_idx
                                 .and_then(|v_idx| row.get(v_idx).cloned())
                                 .unwrap_or_default();
-                            if name.is_empty() { None } else { Some(ConfigEntry { name, value }) }
+                            let is_mutable = mutable_idx
+                                .and_then(|m_idx| row.get(m_idx))
+                                .map(|v| v.eq_ignore_ascii_case("true"));
+                            if name.is_empty() {
+                                None
+                            } else {
+                                Some(ConfigEntry { name, value, is_mutable })
+                            }
                         })
                         .collect();
 
@@ -169,53 +396,5 @@ pub async fn get_configure_info(
         )));
     }
 
-    Ok(Json(configs))
-}
-
-/// Update a variable
-#[utoipa::path(
-    put,
-    path = "/api/clusters/variables/{variable_name}",
-    params(
-        ("variable_name" = String, Path, description = "Variable name")
-    ),
-    request_body = UpdateVariableRequest,
-    responses(
-        (status = 200, description = "Variable updated successfully"),
-        (status = 404, description = "No active cluster found"),
-        (status = 500, description = "Internal server error")
-    ),
-    security(
-        ("bearer" = [])
-    )
-)]
-pub async fn update_variable(
-    State(state): State<Arc<crate::AppState>>,
-    axum::extract::Extension(org_ctx): axum::extract::Extension<crate::middleware::OrgContext>,
-    Path(variable_name): Path<String>,
-    Json(request): Json<UpdateVariableRequest>,
-) -> ApiResult<impl IntoResponse> {
-    let cluster = if org_ctx.is_super_admin {
-        state.cluster_service.get_active_cluster().await?
-    } else {
-        state
-            .cluster_service
-            .get_active_cluster_by_org(org_ctx.organization_id)
-            .await?
-    };
-
-    let pool = state.mysql_pool_manager.get_pool(&cluster).await?;
-    let mysql_client = MySQLClient::from_pool(pool);
-
-    let scope = match request.scope.to_uppercase().as_str() {
-        "GLOBAL" => "GLOBAL",
-        "SESSION" => "SESSION",
-        _ => return Err(ApiError::invalid_data("Invalid scope. Must be GLOBAL or SESSION")),
-    };
-
-    let sql = format!("SET {} {} = {}", scope, variable_name, request.value);
-
-    mysql_client.execute(&sql).await?;
-
-    Ok((StatusCode::OK, Json(json!({ "message": "Variable updated successfully" }))))
+    Ok(configs)
 }
@@ -18,10 +18,12 @@ pub mod utils;
 pub use config::Config;
 pub use services::llm::{LLMError, LLMProviderInfo, LLMService, LLMServiceImpl};
 pub use services::{
-    AuthService, CasbinService, ClusterService, DataStatisticsService, DbAuthQueryService,
-    MetricsCollectorService, MySQLPoolManager, OrganizationService, OverviewService,
-    PermissionRequestService, PermissionService, RoleService, SystemFunctionService, UserRoleService,
-    UserService,
+    AuthService, CasbinService, ClusterService, CompactionService, DataStatisticsService,
+    DbAuthQueryService, GlobalVariableHistoryService, LoadJobService, MetricsCollectorService,
+    MvRefreshHistoryService, MySQLPoolManager, NodeConfigService, OrganizationService,
+    OverviewService, PartitionService, PermissionRequestService, PermissionService,
+    QueryGuardService, RoleService, RoutineLoadHistoryService, SessionTemplateService,
+    SqlBlacklistService, SystemFunctionService, UserRoleService, UserService,
 };
 pub use utils::JwtUtil;
 
@@ -45,6 +47,16 @@ pub struct AppState {
     pub metrics_collector_service: Arc<MetricsCollectorService>,
     pub data_statistics_service: Arc<DataStatisticsService>,
     pub overview_service: Arc<OverviewService>,
+    pub partition_service: Arc<PartitionService>,
+    pub load_job_service: Arc<LoadJobService>,
+    pub routine_load_history_service: Arc<RoutineLoadHistoryService>,
+    pub mv_refresh_history_service: Arc<MvRefreshHistoryService>,
+    pub compaction_service: Arc<CompactionService>,
+    pub node_config_service: Arc<NodeConfigService>,
+    pub sql_blacklist_service: Arc<SqlBlacklistService>,
+    pub query_guard_service: Arc<QueryGuardService>,
+    pub session_template_service: Arc<SessionTemplateService>,
+    pub global_variable_history_service: Arc<GlobalVariableHistoryService>,
 
     pub casbin_service: Arc<CasbinService>,
     pub permission_service: Arc<PermissionService>,
@@ -16,10 +16,13 @@ use stellar::db;
 use stellar::embedded::WebAssets;
 use stellar::models;
 use stellar::services::{
-    AuthService, CasbinService, ClusterService, DataStatisticsService, DbAuthQueryService,
-    LLMServiceImpl, MetricsCollectorService, MySQLPoolManager, OrganizationService, OverviewService,
-    PermissionRequestService, PermissionService, RoleService, SystemFunctionService,
-    UserRoleService, UserService,
+    AuthService, CasbinService, ClusterService, CompactionService, DataStatisticsService,
+    DbAuthQueryService, GlobalVariableHistoryService, LLMServiceImpl, LoadJobService,
+    MetricsCollectorService, MvRefreshHistoryService, MySQLPoolManager, NodeConfigService,
+    OrganizationService, OverviewService, PartitionService, PermissionRequestService,
+    PermissionService, QueryGuardService, RoleService, RoutineLoadHistoryService,
+    SessionTemplateService, SqlBlacklistService, SystemFunctionService, UserRoleService,
+    UserService,
 };
 use stellar::utils::{JwtUtil, ScheduledExecutor};
 use stellar::{AppState, handlers, middleware, services};
@@ -59,26 +62,66 @@ use stellar::{AppState, handlers, middleware, services};
         handlers::materialized_view::refresh_materialized_view,
         handlers::materialized_view::cancel_refresh_materialized_view,
         handlers::materialized_view::alter_materialized_view,
+        handlers::materialized_view::recommend_materialized_views,
+        handlers::materialized_view::create_recommended_materialized_view,
+        handlers::materialized_view::get_materialized_view_refresh_history,
+        handlers::materialized_view::get_materialized_view_refresh_alerts,
+        handlers::materialized_view::get_materialized_view_usage,
+        handlers::materialized_view::get_materialized_view_dependencies,
 
         handlers::query::list_catalogs,
         handlers::query::list_databases,
         handlers::query::list_catalogs_with_databases,
         handlers::query::list_queries,
         handlers::query::kill_query,
+        handlers::query::get_running_query_detail,
         handlers::query::execute_sql,
+        handlers::sql_diag::lint,
         handlers::query::list_sql_blacklist,
         handlers::query::add_sql_blacklist,
         handlers::query::delete_sql_blacklist,
+        handlers::sql_blacklist::test_sql_blacklist_pattern,
+        handlers::sql_blacklist::list_sql_blacklist_rules,
+        handlers::sql_blacklist::create_sql_blacklist_rule,
+        handlers::sql_blacklist::get_sql_blacklist_rule,
+        handlers::sql_blacklist::update_sql_blacklist_rule,
+        handlers::sql_blacklist::delete_sql_blacklist_rule,
+        handlers::sql_blacklist::sync_sql_blacklist_rule,
+        handlers::resource_group::list_resource_groups,
+        handlers::resource_group::create_resource_group,
+        handlers::resource_group::alter_resource_group,
+        handlers::resource_group::drop_resource_group,
+        handlers::resource_group::get_resource_group_usage,
+        handlers::resource_group::get_resource_group_history,
+        handlers::query_guard::list_query_guard_policies,
+        handlers::query_guard::create_query_guard_policy,
+        handlers::query_guard::get_query_guard_policy,
+        handlers::query_guard::update_query_guard_policy,
+        handlers::query_guard::delete_query_guard_policy,
+        handlers::query_guard::preview_query_guard_policy,
+        handlers::query_guard::list_query_guard_actions,
+        handlers::session_template::list_session_templates,
+        handlers::session_template::create_session_template,
+        handlers::session_template::update_session_template,
+        handlers::session_template::delete_session_template,
+        handlers::session_template::apply_parameter_suggestion,
         handlers::query_history::list_query_history,
 
         handlers::sessions::get_sessions,
         handlers::sessions::kill_session,
         handlers::variables::get_variables,
         handlers::variables::update_variable,
+        handlers::variables::get_variable_history,
+        handlers::variables::list_variable_snapshots,
+        handlers::variables::create_variable_snapshot,
+        handlers::variables::rollback_variable_snapshot,
+        handlers::config_drift::compare_config_drift,
 
         handlers::profile::list_profiles,
         handlers::profile::get_profile,
         handlers::profile::analyze_profile_handler,
+        handlers::profile::export_profile_report_handler,
+        handlers::profile::import_profile_bundle_handler,
 
         handlers::system_management::get_system_functions,
         handlers::system_management::get_system_function_detail,
@@ -93,6 +136,33 @@ use stellar::{AppState, handlers, middleware, services};
         handlers::overview::get_extended_cluster_overview,
         handlers::cluster::test_cluster_connection,
 
+        handlers::partition::list_dynamic_partitions,
+        handlers::partition::list_ttl_candidates,
+        handlers::partition::preview_drop_partitions,
+        handlers::partition::drop_partitions,
+        handlers::partition::list_drop_requests,
+        handlers::partition::approve_drop_request,
+        handlers::partition::reject_drop_request,
+
+        handlers::load::list_load_jobs,
+        handlers::load::get_load_error_sample,
+        handlers::load::list_routine_loads,
+        handlers::load::control_routine_load,
+        handlers::load::get_load_history,
+        handlers::load::get_routine_load_history,
+        handlers::load::get_routine_load_alerts,
+
+        handlers::compaction::trigger_compaction,
+        handlers::compaction::list_backend_compaction_status,
+        handlers::compaction::update_compaction_config,
+        handlers::compaction::get_compaction_pressure,
+
+        handlers::node_config::get_node_configs,
+        handlers::node_config::get_config_divergence,
+        handlers::node_config::apply_node_config,
+        handlers::node_config::list_config_changes,
+        handlers::node_config::rollback_config_change,
+
         handlers::role::list_roles,
         handlers::role::get_role,
         handlers::role::create_role,
@@ -154,6 +224,20 @@ use stellar::{AppState, handlers, middleware, services};
             models::RefreshMaterializedViewRequest,
             models::AlterMaterializedViewRequest,
             models::MaterializedViewDDL,
+            services::MvCandidate,
+            handlers::materialized_view::MVRecommendationResponse,
+            services::mv_refresh_history_service::MvRefreshRun,
+            services::mv_refresh_history_service::MvRefreshAlert,
+            services::mv_refresh_history_service::MvRefreshAlertKind,
+            handlers::materialized_view::MVUsageResponse,
+            services::mv_usage_service::MvUsageStats,
+            services::mv_usage_service::MvUsageVerdict,
+            models::MaterializedViewDependency,
+            services::mv_dependency_service::MvDependencyGraph,
+            services::mv_dependency_service::MvDependencyNode,
+            services::mv_dependency_service::MvDependencyEdge,
+            services::mv_dependency_service::MvNodeKind,
+            services::mv_dependency_service::MvNodeStatus,
             models::Query,
             models::QueryExecuteRequest,
             models::QueryExecuteResponse,
@@ -208,6 +292,70 @@ use stellar::{AppState, handlers, middleware, services};
             services::TopTableBySize,
             services::TopTableByAccess,
             services::CapacityPrediction,
+            services::partition_service::DynamicPartitionSetting,
+            services::partition_service::PartitionInfo,
+            services::partition_service::PartitionTtlCandidate,
+            services::partition_service::PartitionDropPreview,
+            services::partition_service::PartitionDropRequest,
+            services::partition_service::PartitionDropRecord,
+            handlers::partition::PartitionDropDecision,
+            services::sql_blacklist_service::SqlBlacklistTestRequest,
+            services::sql_blacklist_service::SqlBlacklistTestResult,
+            services::sql_blacklist_service::SqlBlacklistUserImpact,
+            services::sql_blacklist_service::SqlBlacklistSample,
+            services::sql_blacklist_service::SqlBlacklistRule,
+            services::sql_blacklist_service::SqlBlacklistRuleTarget,
+            services::sql_blacklist_service::SqlBlacklistRuleDetail,
+            services::sql_blacklist_service::SqlBlacklistRuleRequest,
+            models::ResourceGroup,
+            models::ResourceGroupClassifier,
+            models::CreateResourceGroupRequest,
+            models::AlterResourceGroupRequest,
+            models::ResourceGroupUsage,
+            models::ResourceGroupUsageRecord,
+            services::query_guard_service::QueryGuardPolicy,
+            services::query_guard_service::QueryGuardPolicyRequest,
+            services::query_guard_service::QueryGuardViolation,
+            services::query_guard_service::QueryGuardAction,
+            services::session_template_service::SessionVariableTemplate,
+            services::session_template_service::SessionVariableTemplateRequest,
+            services::session_template_service::ApplySuggestionRequest,
+            services::session_template_service::AppliedSuggestion,
+            services::global_variable_history_service::GlobalVariableSnapshot,
+            services::global_variable_history_service::GlobalVariableChange,
+            services::global_variable_history_service::GlobalVariableRollbackRequest,
+            services::global_variable_history_service::GlobalVariableRollback,
+            services::global_variable_history_service::GlobalVariableRollbackStatement,
+            services::config_drift_service::ConfigDriftRequest,
+            services::config_drift_service::ConfigDriftReport,
+            services::config_drift_service::DriftCluster,
+            services::config_drift_service::DriftEntry,
+            services::config_drift_service::ConvergenceScript,
+            services::load_job_service::LoadJob,
+            services::load_job_service::LoadErrorSample,
+            services::load_job_service::RoutineLoadJob,
+            services::load_job_service::RoutineLoadPartition,
+            services::load_job_service::RoutineLoadAction,
+            services::load_job_service::LoadHistoryPoint,
+            handlers::load::RoutineLoadControlRequest,
+            services::routine_load_history_service::RoutineLoadSnapshot,
+            services::routine_load_history_service::RoutineLoadAlert,
+            services::routine_load_history_service::RoutineLoadAlertKind,
+            services::compaction_service::CompactionType,
+            services::compaction_service::ManualCompactionRequest,
+            services::compaction_service::CompactionTriggerResult,
+            services::compaction_service::BackendCompactionStatus,
+            services::compaction_service::CompactionConfigUpdate,
+            services::compaction_service::CompactionConfigResult,
+            services::compaction_service::CompactionPressurePoint,
+            services::node_config_service::NodeType,
+            services::node_config_service::NodeConfigEntry,
+            services::node_config_service::NodeConfigInventory,
+            services::node_config_service::ConfigValueGroup,
+            services::node_config_service::ConfigDivergence,
+            services::node_config_service::NodeConfigChangeRequest,
+            services::node_config_service::NodeConfigChange,
+            handlers::node_config::ConfigRollbackRequest,
             models::PermissionRequest,
             models::PermissionRequestResponse,
             models::SubmitRequestDto,
@@ -225,7 +373,15 @@ use stellar::{AppState, handlers, middleware, services};
         (name = "Backends", description = "Backend node management"),
         (name = "Frontends", description = "Frontend node management"),
         (name = "Materialized Views", description = "Materialized view management"),
+        (name = "Partitions", description = "Partition lifecycle management"),
+        (name = "Loads", description = "Load job monitoring"),
+        (name = "Compaction", description = "Compaction management and tuning"),
+        (name = "Node Configs", description = "Per-node FE / BE config inventory and changes"),
         (name = "Queries", description = "Query management"),
+        (name = "Resource Groups", description = "Resource group / workload group management"),
+        (name = "Query Guard", description = "Automatic kill policies for runaway queries"),
+        (name = "Session Variables", description = "Session variable templates and parameter suggestions"),
+        (name = "Config Drift", description = "Variable and FE config comparison across clusters"),
         (name = "Profiles", description = "Query profile management"),
         (name = "System", description = "System information"),
         (name = "Roles", description = "Role management"),
@@ -328,6 +484,59 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_data_statistics(Arc::clone(&data_statistics_service)),
     );
 
+    let partition_service = Arc::new(PartitionService::new(
+        pool.clone(),
+        Arc::clone(&cluster_service),
+        Arc::clone(&mysql_pool_manager),
+    ));
+
+    let load_job_service = Arc::new(LoadJobService::new(
+        pool.clone(),
+        Arc::clone(&cluster_service),
+        Arc::clone(&mysql_pool_manager),
+        config.metrics.retention_days,
+    ));
+
+    let routine_load_history_service = Arc::new(RoutineLoadHistoryService::new(
+        pool.clone(),
+        Arc::clone(&cluster_service),
+        Arc::clone(&load_job_service),
+        config.metrics.retention_days,
+    ));
+
+    let mv_refresh_history_service = Arc::new(MvRefreshHistoryService::new(
+        pool.clone(),
+        Arc::clone(&cluster_service),
+        Arc::clone(&mysql_pool_manager),
+        config.metrics.retention_days,
+    ));
+
+    let sql_blacklist_service = Arc::new(SqlBlacklistService::new(
+        pool.clone(),
+        Arc::clone(&cluster_service),
+        Arc::clone(&mysql_pool_manager),
+    ));
+
+    let query_guard_service = Arc::new(QueryGuardService::new(
+        pool.clone(),
+        Arc::clone(&cluster_service),
+        Arc::clone(&mysql_pool_manager),
+    ));
+
+    let session_template_service = Arc::new(SessionTemplateService::new(pool.clone()));
+
+    let global_variable_history_service = Arc::new(GlobalVariableHistoryService::new(
+        pool.clone(),
+        Arc::clone(&cluster_service),
+        Arc::clone(&mysql_pool_manager),
+    ));
+
+    let compaction_service =
+        Arc::new(CompactionService::new(pool.clone(), Arc::clone(&mysql_pool_manager)));
+
+    let node_config_service =
+        Arc::new(NodeConfigService::new(pool.clone(), Arc::clone(&mysql_pool_manager)));
+
     let casbin_service = Arc::new(
         CasbinService::new()
             .await
@@ -382,6 +591,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         metrics_collector_service: Arc::clone(&metrics_collector_service),
         data_statistics_service: Arc::clone(&data_statistics_service),
         overview_service: Arc::clone(&overview_service),
+        partition_service: Arc::clone(&partition_service),
+        load_job_service: Arc::clone(&load_job_service),
+        routine_load_history_service: Arc::clone(&routine_load_history_service),
+        mv_refresh_history_service: Arc::clone(&mv_refresh_history_service),
+        compaction_service: Arc::clone(&compaction_service),
+        node_config_service: Arc::clone(&node_config_service),
+        sql_blacklist_service: Arc::clone(&sql_blacklist_service),
+        query_guard_service: Arc::clone(&query_guard_service),
+        session_template_service: Arc::clone(&session_template_service),
+        global_variable_history_service: Arc::clone(&global_variable_history_service),
         casbin_service: Arc::clone(&casbin_service),
         permission_service: Arc::clone(&permission_service),
         role_service: Arc::clone(&role_service),
@@ -404,10 +623,50 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         tokio::spawn(async move {
             executor.start(service).await;
         });
+
+        let executor = ScheduledExecutor::new("load-history-collector", interval);
+        let service = Arc::clone(&load_job_service);
+        tokio::spawn(async move {
+            executor.start(service).await;
+        });
+
+        let executor = ScheduledExecutor::new("routine-load-collector", interval);
+        let service = Arc::clone(&routine_load_history_service);
+        tokio::spawn(async move {
+            executor.start(service).await;
+        });
+
+        let executor = ScheduledExecutor::new("mv-refresh-collector", interval);
+        let service = Arc::clone(&mv_refresh_history_service);
+        tokio::spawn(async move {
+            executor.start(service).await;
+        });
     } else {
         tracing::warn!("Metrics collector disabled by configuration");
     }
 
+    // Rules are re-applied independently of metrics collection: FE restarts can drop them
+    let executor = ScheduledExecutor::new("sql-blacklist-sync", std::time::Duration::from_secs(60));
+    let service = Arc::clone(&sql_blacklist_service);
+    tokio::spawn(async move {
+        executor.start(service).await;
+    });
+
+    // Runaway queries must be caught within seconds, whatever the metrics interval is
+    let executor = ScheduledExecutor::new("query-guard", std::time::Duration::from_secs(15));
+    let service = Arc::clone(&query_guard_service);
+    tokio::spawn(async move {
+        executor.start(service).await;
+    });
+
+    // Snapshots are only stored when a variable changed, so a short interval stays cheap
+    let executor =
+        ScheduledExecutor::new("global-variable-history", std::time::Duration::from_secs(300));
+    let service = Arc::clone(&global_variable_history_service);
+    tokio::spawn(async move {
+        executor.start(service).await;
+    });
+
     let _baseline_refresh_handle = services::start_baseline_refresh_task(
         Arc::clone(&mysql_pool_manager),
         Arc::clone(&cluster_service),
@@ -448,13 +707,88 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route("/api/clusters/queries", get(handlers::query::list_queries))
         .route("/api/clusters/queries/execute", post(handlers::query::execute_sql))
         .route("/api/clusters/queries/:query_id", delete(handlers::query::kill_query))
+        .route(
+            "/api/clusters/queries/:query_id/detail",
+            get(handlers::query::get_running_query_detail),
+        )
         .route("/api/clusters/queries/history", get(handlers::query_history::list_query_history))
         .route(
             "/api/clusters/sql-blacklist",
             get(handlers::query::list_sql_blacklist).post(handlers::query::add_sql_blacklist),
         )
         .route("/api/clusters/sql-blacklist/:id", delete(handlers::query::delete_sql_blacklist))
+        .route(
+            "/api/clusters/sql-blacklist/test",
+            post(handlers::sql_blacklist::test_sql_blacklist_pattern),
+        )
+        .route(
+            "/api/clusters/sql-blacklist/rules",
+            get(handlers::sql_blacklist::list_sql_blacklist_rules)
+                .post(handlers::sql_blacklist::create_sql_blacklist_rule),
+        )
+        .route(
+            "/api/clusters/sql-blacklist/rules/:id",
+            get(handlers::sql_blacklist::get_sql_blacklist_rule)
+                .put(handlers::sql_blacklist::update_sql_blacklist_rule)
+                .delete(handlers::sql_blacklist::delete_sql_blacklist_rule),
+        )
+        .route(
+            "/api/clusters/sql-blacklist/rules/:id/sync",
+            post(handlers::sql_blacklist::sync_sql_blacklist_rule),
+        )
+        .route(
+            "/api/clusters/query-guard/policies",
+            get(handlers::query_guard::list_query_guard_policies)
+                .post(handlers::query_guard::create_query_guard_policy),
+        )
+        .route(
+            "/api/clusters/query-guard/policies/:id",
+            get(handlers::query_guard::get_query_guard_policy)
+                .put(handlers::query_guard::update_query_guard_policy)
+                .delete(handlers::query_guard::delete_query_guard_policy),
+        )
+        .route(
+            "/api/clusters/query-guard/preview",
+            post(handlers::query_guard::preview_query_guard_policy),
+        )
+        .route(
+            "/api/clusters/query-guard/actions",
+            get(handlers::query_guard::list_query_guard_actions),
+        )
+        .route(
+            "/api/clusters/session-templates",
+            get(handlers::session_template::list_session_templates)
+                .post(handlers::session_template::create_session_template),
+        )
+        .route(
+            "/api/clusters/session-templates/:id",
+            put(handlers::session_template::update_session_template)
+                .delete(handlers::session_template::delete_session_template),
+        )
+        .route(
+            "/api/clusters/session-variables/apply",
+            post(handlers::session_template::apply_parameter_suggestion),
+        )
+        .route(
+            "/api/clusters/resource-groups",
+            get(handlers::resource_group::list_resource_groups)
+                .post(handlers::resource_group::create_resource_group),
+        )
+        .route(
+            "/api/clusters/resource-groups/usage",
+            get(handlers::resource_group::get_resource_group_usage),
+        )
+        .route(
+            "/api/clusters/resource-groups/history",
+            get(handlers::resource_group::get_resource_group_history),
+        )
+        .route(
+            "/api/clusters/resource-groups/:name",
+            put(handlers::resource_group::alter_resource_group)
+                .delete(handlers::resource_group::drop_resource_group),
+        )
         .route("/api/clusters/:cluster_id/sql/diagnose", post(handlers::sql_diag::diagnose))
+        .route("/api/clusters/:cluster_id/sql/lint", post(handlers::sql_diag::lint))
         .route("/api/clusters/:id", get(handlers::cluster::get_cluster))
         .route("/api/clusters/:id", put(handlers::cluster::update_cluster))
         .route("/api/clusters/:id", delete(handlers::cluster::delete_cluster))
@@ -479,6 +813,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             get(handlers::materialized_view::list_materialized_views)
                 .post(handlers::materialized_view::create_materialized_view),
         )
+        .route(
+            "/api/clusters/materialized_views/recommendations",
+            get(handlers::materialized_view::recommend_materialized_views),
+        )
+        .route(
+            "/api/clusters/materialized_views/recommendations/:pattern_id",
+            post(handlers::materialized_view::create_recommended_materialized_view),
+        )
+        .route(
+            "/api/clusters/materialized_views/refresh_alerts",
+            get(handlers::materialized_view::get_materialized_view_refresh_alerts),
+        )
+        .route(
+            "/api/clusters/materialized_views/usage",
+            get(handlers::materialized_view::get_materialized_view_usage),
+        )
+        .route(
+            "/api/clusters/materialized_views/dependencies",
+            get(handlers::materialized_view::get_materialized_view_dependencies),
+        )
         .route(
             "/api/clusters/materialized_views/:mv_name",
             get(handlers::materialized_view::get_materialized_view)
@@ -489,6 +843,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             "/api/clusters/materialized_views/:mv_name/ddl",
             get(handlers::materialized_view::get_materialized_view_ddl),
         )
+        .route(
+            "/api/clusters/materialized_views/:mv_name/refresh_history",
+            get(handlers::materialized_view::get_materialized_view_refresh_history),
+        )
         .route(
             "/api/clusters/materialized_views/:mv_name/refresh",
             post(handlers::materialized_view::refresh_materialized_view),
@@ -497,7 +855,60 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             "/api/clusters/materialized_views/:mv_name/cancel",
             post(handlers::materialized_view::cancel_refresh_materialized_view),
         )
+        .route(
+            "/api/clusters/partitions/dynamic",
+            get(handlers::partition::list_dynamic_partitions),
+        )
+        .route(
+            "/api/clusters/partitions/ttl-candidates",
+            get(handlers::partition::list_ttl_candidates),
+        )
+        .route(
+            "/api/clusters/partitions/drop/preview",
+            post(handlers::partition::preview_drop_partitions),
+        )
+        .route("/api/clusters/partitions/drop", post(handlers::partition::drop_partitions))
+        .route(
+            "/api/clusters/partitions/drop/requests",
+            get(handlers::partition::list_drop_requests),
+        )
+        .route(
+            "/api/clusters/partitions/drop/approve",
+            post(handlers::partition::approve_drop_request),
+        )
+        .route(
+            "/api/clusters/partitions/drop/reject",
+            post(handlers::partition::reject_drop_request),
+        )
+        .route("/api/clusters/loads", get(handlers::load::list_load_jobs))
+        .route("/api/clusters/loads/error-sample", get(handlers::load::get_load_error_sample))
+        .route("/api/clusters/loads/routine", get(handlers::load::list_routine_loads))
+        .route("/api/clusters/loads/routine/control", post(handlers::load::control_routine_load))
+        .route("/api/clusters/loads/history", get(handlers::load::get_load_history))
+        .route("/api/clusters/loads/routine/history", get(handlers::load::get_routine_load_history))
+        .route("/api/clusters/loads/routine/alerts", get(handlers::load::get_routine_load_alerts))
+        .route("/api/clusters/compaction/trigger", post(handlers::compaction::trigger_compaction))
+        .route(
+            "/api/clusters/compaction/backends",
+            get(handlers::compaction::list_backend_compaction_status),
+        )
+        .route(
+            "/api/clusters/compaction/config",
+            post(handlers::compaction::update_compaction_config),
+        )
+        .route(
+            "/api/clusters/compaction/pressure",
+            get(handlers::compaction::get_compaction_pressure),
+        )
         .route("/api/clusters/profiles", get(handlers::profile::list_profiles))
+        .route(
+            "/api/clusters/profiles/export",
+            post(handlers::profile::export_profile_report_handler),
+        )
+        .route(
+            "/api/clusters/profiles/import",
+            post(handlers::profile::import_profile_bundle_handler),
+        )
         .route("/api/clusters/profiles/:query_id", get(handlers::profile::get_profile))
         .route(
             "/api/clusters/profiles/:query_id/analyze",
@@ -511,7 +922,29 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route("/api/clusters/sessions/:session_id", delete(handlers::sessions::kill_session))
         .route("/api/clusters/variables", get(handlers::variables::get_variables))
         .route("/api/clusters/configs", get(handlers::variables::get_configure_info))
+        .route("/api/clusters/configs/nodes", get(handlers::node_config::get_node_configs))
+        .route(
+            "/api/clusters/configs/divergence",
+            get(handlers::node_config::get_config_divergence),
+        )
+        .route("/api/clusters/configs/apply", post(handlers::node_config::apply_node_config))
+        .route("/api/clusters/configs/changes", get(handlers::node_config::list_config_changes))
+        .route(
+            "/api/clusters/configs/rollback",
+            post(handlers::node_config::rollback_config_change),
+        )
         .route("/api/clusters/variables/:variable_name", put(handlers::variables::update_variable))
+        .route("/api/clusters/variables/history", get(handlers::variables::get_variable_history))
+        .route(
+            "/api/clusters/variables/snapshots",
+            get(handlers::variables::list_variable_snapshots)
+                .post(handlers::variables::create_variable_snapshot),
+        )
+        .route(
+            "/api/clusters/variables/snapshots/:snapshot_id/rollback",
+            post(handlers::variables::rollback_variable_snapshot),
+        )
+        .route("/api/clusters/config-drift", post(handlers::config_drift::compare_config_drift))
         .route("/api/clusters/system/runtime_info", get(handlers::system::get_runtime_info))
         .route("/api/clusters/system", get(handlers::system_management::get_system_functions))
         .route(
@@ -121,6 +121,8 @@ fn extract_clusters_id_action(segments: &[&str], method: &str) -> Option<String>
                 Some("health:post".to_string())
             } else if method == "POST" && *action == "sql" && segments.get(3) == Some(&"diagnose") {
                 Some("sql:diagnose".to_string())
+            } else if method == "POST" && *action == "sql" && segments.get(3) == Some(&"lint") {
+                Some("sql:lint".to_string())
             } else {
                 Some(action.to_string())
             }
@@ -172,14 +174,14 @@ fn extract_clusters_special_paths(segments: &[&str], method: &str) -> Option<Str
         Box::new(|seg, m| {
             if m == "GET" && seg.len() >= 4 && seg.get(1) == Some(&"queries") {
                 if let Some(last) = seg.last()
-                    && *last == "profile"
+                    && (*last == "profile" || *last == "detail")
                 {
                     if let Some(second) = seg.get(2)
                         && (*second == "history" || *second == "execute")
                     {
                         return None;
                     }
-                    return Some("queries:profile".to_string());
+                    return Some(format!("queries:{}", last));
                 }
                 None
             } else {
@@ -204,6 +206,9 @@ fn extract_clusters_special_paths(segments: &[&str], method: &str) -> Option<Str
         Box::new(extract_variables_action),
         Box::new(extract_system_functions_action),
         Box::new(extract_sql_blacklist_action),
+        Box::new(extract_resource_groups_action),
+        Box::new(extract_query_guard_action),
+        Box::new(extract_session_templates_action),
     ];
 
     for handler in handlers {
@@ -222,16 +227,38 @@ fn extract_materialized_views_action(segments: &[&str], method: &str) -> Option<
     }
 
     match segments.len() {
+        3 if segments.get(2) == Some(&"recommendations") => match method {
+            "GET" => Some("materialized_views:recommendations".to_string()),
+            _ => None,
+        },
+        3 if segments.get(2) == Some(&"refresh_alerts") => match method {
+            "GET" => Some("materialized_views:refresh_alerts".to_string()),
+            _ => None,
+        },
+        3 if segments.get(2) == Some(&"usage") => match method {
+            "GET" => Some("materialized_views:usage".to_string()),
+            _ => None,
+        },
+        3 if segments.get(2) == Some(&"dependencies") => match method {
+            "GET" => Some("materialized_views:dependencies".to_string()),
+            _ => None,
+        },
         3 => match method {
             "GET" => Some("materialized_views:get".to_string()),
             "PUT" => Some("materialized_views:update".to_string()),
             "DELETE" => Some("materialized_views:delete".to_string()),
             _ => None,
         },
+        4 if segments.get(2) == Some(&"recommendations") && method == "POST" => {
+            Some("materialized_views:recommendations:create".to_string())
+        },
         4 => {
             let action = segments.get(3)?;
             match (*action, method) {
                 ("ddl", "GET") => Some("materialized_views:ddl".to_string()),
+                ("refresh_history", "GET") => {
+                    Some("materialized_views:refresh_history".to_string())
+                },
                 ("refresh", "POST") => Some("materialized_views:refresh".to_string()),
                 ("cancel", "POST") => Some("materialized_views:cancel".to_string()),
                 _ => None,
@@ -243,12 +270,20 @@ fn extract_materialized_views_action(segments: &[&str], method: &str) -> Option<
 
 /// Extract action for variables paths
 fn extract_variables_action(segments: &[&str], method: &str) -> Option<String> {
-    if method == "PUT" && segments.len() == 3 && segments.get(1) == Some(&"variables") {
-        segments.get(2).and_then(|third| {
-            if third.parse::<i64>().is_err() { Some("variables:update".to_string()) } else { None }
-        })
-    } else {
-        None
+    if segments.get(1) != Some(&"variables") {
+        return None;
+    }
+    match (segments.len(), segments.get(2).copied(), method) {
+        (3, Some("history"), "GET") => Some("variables:history".to_string()),
+        (3, Some("snapshots"), "GET") => Some("variables:snapshots".to_string()),
+        (3, Some("snapshots"), "POST") => Some("variables:snapshots:create".to_string()),
+        (5, Some("snapshots"), "POST") if segments.get(4) == Some(&"rollback") => {
+            Some("variables:rollback".to_string())
+        },
+        (3, Some(third), "PUT") if third.parse::<i64>().is_err() => {
+            Some("variables:update".to_string())
+        },
+        _ => None,
     }
 }
 
@@ -290,10 +325,84 @@ fn extract_sql_blacklist_action(segments: &[&str], method: &str) -> Option<Strin
                 "POST" => Some("sql:blacklist:add".to_string()),
                 _ => None,
             },
+            3 if segments.get(2) == Some(&"test") => match method {
+                "POST" => Some("sql:blacklist:test".to_string()),
+                _ => None,
+            },
+            3 if segments.get(2) == Some(&"rules") => match method {
+                "GET" => Some("sql:blacklist:rules".to_string()),
+                "POST" => Some("sql:blacklist:rules:create".to_string()),
+                _ => None,
+            },
             3 => match method {
                 "DELETE" => Some("sql:blacklist:delete".to_string()),
                 _ => None,
             },
+            4 if segments.get(2) == Some(&"rules") => match method {
+                "GET" => Some("sql:blacklist:rules".to_string()),
+                "PUT" => Some("sql:blacklist:rules:update".to_string()),
+                "DELETE" => Some("sql:blacklist:rules:delete".to_string()),
+                _ => None,
+            },
+            5 if segments.get(2) == Some(&"rules") && segments.get(4) == Some(&"sync") => {
+                match method {
+                    "POST" => Some("sql:blacklist:rules:sync".to_string()),
+                    _ => None,
+                }
+            },
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// Resource group routes share paths across methods, e.g. PUT / DELETE /resource-groups/:name
+fn extract_resource_groups_action(segments: &[&str], method: &str) -> Option<String> {
+    if segments.len() >= 2 && segments.get(1) == Some(&"resource-groups") {
+        match segments.len() {
+            2 => match method {
+                "GET" => Some("resource:groups".to_string()),
+                "POST" => Some("resource:groups:create".to_string()),
+                _ => None,
+            },
+            3 if segments.get(2) == Some(&"usage") => match method {
+                "GET" => Some("resource:groups:usage".to_string()),
+                _ => None,
+            },
+            3 if segments.get(2) == Some(&"history") => match method {
+                "GET" => Some("resource:groups:history".to_string()),
+                _ => None,
+            },
+            3 => match method {
+                "PUT" => Some("resource:groups:alter".to_string()),
+                "DELETE" => Some("resource:groups:drop".to_string()),
+                _ => None,
+            },
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// Query guard policies are managed per method on the same paths
+fn extract_query_guard_action(segments: &[&str], method: &str) -> Option<String> {
+    if segments.len() >= 3 && segments.get(1) == Some(&"query-guard") {
+        match (segments.len(), segments.get(2).copied()) {
+            (3, Some("policies")) => match method {
+                "GET" => Some("query:guard:policies".to_string()),
+                "POST" => Some("query:guard:policies:create".to_string()),
+                _ => None,
+            },
+            (4, Some("policies")) => match method {
+                "GET" => Some("query:guard:policies".to_string()),
+                "PUT" => Some("query:guard:policies:update".to_string()),
+                "DELETE" => Some("query:guard:policies:delete".to_string()),
+                _ => None,
+            },
+            (3, Some("preview")) if method == "POST" => Some("query:guard:preview".to_string()),
+            (3, Some("actions")) if method == "GET" => Some("query:guard:actions".to_string()),
             _ => None,
         }
     } else {
@@ -301,6 +410,20 @@ fn extract_sql_blacklist_action(segments: &[&str], method: &str) -> Option<Strin
     }
 }
 
+/// Session variable templates share list / item paths across methods
+fn extract_session_templates_action(segments: &[&str], method: &str) -> Option<String> {
+    if segments.get(1) != Some(&"session-templates") {
+        return None;
+    }
+    match (segments.len(), method) {
+        (2, "GET") => Some("session:templates".to_string()),
+        (2, "POST") => Some("session:templates:create".to_string()),
+        (3, "PUT") => Some("session:templates:update".to_string()),
+        (3, "DELETE") => Some("session:templates:delete".to_string()),
+        _ => None,
+    }
+}
+
 /// Default action extraction for general cases
 /// This handles clusters non-ID paths and other generic routes
 fn extract_action_default(resource: &str, segments: &[&str], method: &str) -> Option<String> {
@@ -336,3 +459,35 @@ fn extract_action_default(resource: &str, segments: &[&str], method: &str) -> Op
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sql_diagnose_and_lint_actions() {
+        assert_eq!(
+            extract_permission("POST", "/api/clusters/1/sql/diagnose"),
+            Some(("clusters".to_string(), "sql:diagnose".to_string()))
+        );
+        assert_eq!(
+            extract_permission("POST", "/api/clusters/1/sql/lint"),
+            Some(("clusters".to_string(), "sql:lint".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_mv_recommendation_actions() {
+        assert_eq!(
+            extract_permission("GET", "/api/clusters/materialized_views/recommendations"),
+            Some(("clusters".to_string(), "materialized_views:recommendations".to_string()))
+        );
+        assert_eq!(
+            extract_permission(
+                "POST",
+                "/api/clusters/materialized_views/recommendations/0123456789abcdef"
+            ),
+            Some(("clusters".to_string(), "materialized_views:recommendations:create".to_string()))
+        );
+    }
+}
@@ -55,6 +55,30 @@ pub struct MaterializedView {
     pub text: String,
 }
 
+/// One base object read by a materialized view
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+pub struct MaterializedViewDependency {
+    /// Database of the materialized view
+    pub mv_database: String,
+
+    /// Materialized view name
+    pub mv_name: String,
+
+    /// Catalog of the base object, None for the internal catalog
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_catalog: Option<String>,
+
+    /// Database of the base object
+    pub base_database: String,
+
+    /// Base table / view / materialized view name
+    pub base_table: String,
+
+    /// Object type reported by the cluster (TABLE/VIEW/MATERIALIZED_VIEW), if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_type: Option<String>,
+}
+
 /// Request to create materialized view
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateMaterializedViewRequest {
@@ -3,6 +3,7 @@ pub mod materialized_view;
 pub mod organization;
 pub mod permission;
 pub mod permission_request;
+pub mod resource_group;
 pub mod role;
 pub mod starrocks;
 pub mod system_function;
@@ -13,6 +14,7 @@ pub use materialized_view::*;
 pub use organization::*;
 pub use permission::*;
 pub use permission_request::*;
+pub use resource_group::*;
 pub use role::*;
 pub use starrocks::*;
 pub use system_function::*;
@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/// Resource group (StarRocks) / workload group (Doris)
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+pub struct ResourceGroup {
+    /// Group name
+    pub name: String,
+
+    /// Group ID assigned by the FE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// Properties as reported by the cluster (cpu_weight, mem_limit, concurrency_limit, ...)
+    pub properties: BTreeMap<String, String>,
+
+    /// Classifiers routing queries to the group (StarRocks only)
+    pub classifiers: Vec<ResourceGroupClassifier>,
+}
+
+/// One classifier of a resource group
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, PartialEq)]
+pub struct ResourceGroupClassifier {
+    /// Classifier ID, used to drop it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+
+    /// Classifier as shown by the FE, e.g. `(id=3, weight=2.0, user=etl, db='ods')`
+    pub condition: String,
+}
+
+/// Request to create resource group
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateResourceGroupRequest {
+    pub name: String,
+
+    /// Classifier conditions without parentheses, e.g. `user='etl', query_type in ('insert')`
+    #[serde(default)]
+    pub classifiers: Vec<String>,
+
+    /// Group properties, e.g. `cpu_weight`, `mem_limit`, `concurrency_limit` (Doris: `cpu_share`, `memory_limit`, `max_concurrency`)
+    pub properties: BTreeMap<String, String>,
+}
+
+/// Request to alter resource group
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AlterResourceGroupRequest {
+    /// Classifier conditions to add
+    #[serde(default)]
+    pub add_classifiers: Vec<String>,
+
+    /// Classifier IDs to drop
+    #[serde(default)]
+    pub drop_classifier_ids: Vec<i64>,
+
+    /// Drop every classifier of the group
+    #[serde(default)]
+    pub drop_all_classifiers: bool,
+
+    /// Properties to change
+    #[serde(default)]
+    pub properties: BTreeMap<String, String>,
+}
+
+/// Current load of a resource group, summed over all BEs
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, Default, PartialEq)]
+pub struct ResourceGroupUsage {
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    pub running_queries: i64,
+
+    /// Queries waiting in the query queue for this group
+    pub queued_queries: i64,
+
+    /// CPU cores in use (StarRocks)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_cores_used: Option<f64>,
+
+    /// CPU usage percentage averaged over BEs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_usage_pct: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mem_used_bytes: Option<i64>,
+
+    /// Number of BEs reporting usage for the group
+    pub backend_count: usize,
+}
+
+/// Resource group usage recorded by the metrics collector
+#[derive(Debug, Serialize, Deserialize, ToSchema, FromRow, Clone)]
+pub struct ResourceGroupUsageRecord {
+    pub group_name: String,
+    pub running_queries: i64,
+    pub queued_queries: i64,
+    pub cpu_cores_used: Option<f64>,
+    pub cpu_usage_pct: Option<f64>,
+    pub mem_used_bytes: Option<i64>,
+    pub collected_at: DateTime<Utc>,
+}
@@ -366,6 +366,10 @@ pub struct QueryExecuteRequest {
     pub catalog: Option<String>, // Optional catalog name
     #[serde(default)]
     pub database: Option<String>, // Optional database name, will execute USE database before SQL
+    #[serde(default)]
+    pub template_id: Option<i64>, // Optional session variable template, applied before SQL
+    #[serde(default)]
+    pub session_variables: Option<std::collections::BTreeMap<String, String>>, // Override the template
 }
 
 fn default_limit() -> Option<i32> {
@@ -16,6 +16,8 @@ pub struct User {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub organization_id: Option<i64>,
+    /// Preferred language for diagnostics ("zh" / "en"); None follows Accept-Language
+    pub language: Option<String>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -53,6 +55,7 @@ pub struct UpdateUserRequest {
     pub username: Option<String>,
     pub email: Option<String>,
     pub avatar: Option<String>,
+    pub language: Option<String>,
     pub current_password: Option<String>,
     pub new_password: Option<String>,
 }
@@ -76,6 +79,7 @@ pub struct UserResponse {
     pub created_at: DateTime<Utc>,
     pub organization_id: Option<i64>,
     pub organization_name: Option<String>,
+    pub language: Option<String>,
     pub is_super_admin: bool,
     pub is_org_admin: bool,
 }
@@ -97,6 +101,7 @@ impl UserResponse {
             created_at: user.created_at,
             organization_id: user.organization_id,
             organization_name: None,
+            language: user.language,
             is_super_admin,
             is_org_admin,
         }
@@ -116,6 +121,7 @@ impl UserResponse {
             created_at: user.created_at,
             organization_id: user.organization_id,
             organization_name,
+            language: user.language,
             is_super_admin,
             is_org_admin,
         }
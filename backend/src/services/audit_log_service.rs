@@ -39,6 +39,35 @@ pub struct SlowQuery {
     pub query_preview: String, // First 200 characters
 }
 
+/// Aggregation statement from the audit log, input for MV recommendation
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct AggregationQuery {
+    pub query_id: String,
+    pub database: String,
+    pub stmt: String,
+    pub duration_ms: i64,
+    pub scan_bytes: Option<i64>,
+}
+
+/// Materialized view rewrite info of one query from the audit log
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct MvRewriteRecord {
+    pub query_id: String,
+    pub database: String,
+    pub hit_mvs: String,
+    pub candidate_mvs: String,
+}
+
+/// One audited statement with its submitter
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct AuditStatement {
+    pub query_id: String,
+    pub user: String,
+    pub database: String,
+    pub timestamp: String,
+    pub stmt: String,
+}
+
 pub struct AuditLogService {
     mysql_pool_manager: Arc<MySQLPoolManager>,
     audit_config: AuditLogConfig,
@@ -429,4 +458,244 @@ impl AuditLogService {
 
         Ok(slow_queries)
     }
+
+    /// Get finished SELECT statements with a GROUP BY clause
+    ///
+    /// Used to mine repeated aggregation patterns for materialized view
+    /// recommendation, slowest statements first.
+    ///
+    /// # Arguments
+    /// * `cluster` - The StarRocks cluster
+    /// * `hours` - Time window in hours
+    /// * `limit` - Maximum number of statements
+    pub async fn get_aggregation_queries(
+        &self,
+        cluster: &Cluster,
+        hours: i32,
+        limit: usize,
+    ) -> ApiResult<Vec<AggregationQuery>> {
+        let pool = self.mysql_pool_manager.get_pool(cluster).await?;
+        let mysql_client = MySQLClient::from_pool(pool);
+        let (audit_table, time_field, query_time_field, is_query_field, _stmt_type_field) =
+            self.get_audit_config(cluster);
+
+        use crate::models::cluster::ClusterType;
+
+        let (query_id_field, scan_bytes_field) = match cluster.cluster_type {
+            ClusterType::StarRocks => ("queryId", "scanBytes"),
+            ClusterType::Doris => ("query_id", "scan_bytes"),
+        };
+
+        let query = format!(
+            r#"
+            SELECT
+                `{query_id_field}` as query_id,
+                COALESCE(`db`, '') as `database`,
+                `stmt`,
+                `{query_time_field}` as duration_ms,
+                `{scan_bytes_field}` as scan_bytes
+            FROM {audit_table}
+            WHERE `{time_field}` >= DATE_SUB(NOW(), INTERVAL {hours} HOUR)
+                AND {is_query_field} = 1
+                AND `state` = 'EOF'
+                AND UPPER(`stmt`) LIKE 'SELECT%GROUP BY%'
+                AND (`db` NOT IN ('information_schema', '_statistics_', '__internal_schema') OR `db` IS NULL)
+            ORDER BY `{query_time_field}` DESC
+            LIMIT {limit}
+            "#,
+        );
+
+        tracing::debug!("Querying aggregation statements: hours={}, limit={}", hours, limit);
+
+        let (columns, rows) = mysql_client.query_raw(&query).await?;
+
+        let mut col_idx = std::collections::HashMap::new();
+        for (i, col) in columns.iter().enumerate() {
+            col_idx.insert(col.clone(), i);
+        }
+
+        let mut queries = Vec::new();
+        for row in rows {
+            let Some(stmt) = col_idx.get("stmt").and_then(|&i| row.get(i)) else {
+                continue;
+            };
+            let field = |name: &str| col_idx.get(name).and_then(|&i| row.get(i));
+
+            queries.push(AggregationQuery {
+                query_id: field("query_id").cloned().unwrap_or_default(),
+                database: field("database").cloned().unwrap_or_default(),
+                stmt: stmt.clone(),
+                duration_ms: field("duration_ms")
+                    .and_then(|s| s.parse::<i64>().ok())
+                    .unwrap_or(0),
+                scan_bytes: field("scan_bytes").and_then(|s| s.parse::<i64>().ok()),
+            });
+        }
+
+        tracing::info!("Found {} aggregation statements ({}h window)", queries.len(), hours);
+
+        Ok(queries)
+    }
+
+    /// Get queries whose audit record lists hit or candidate materialized views
+    ///
+    /// StarRocks writes `hitMvs` / `candidateMvs` into the audit log; Doris has no
+    /// equivalent columns and yields an empty list.
+    ///
+    /// Returns the newest records first, and whether more records than `limit` matched.
+    ///
+    /// # Arguments
+    /// * `cluster` - The StarRocks cluster
+    /// * `hours` - Time window in hours
+    /// * `limit` - Maximum number of records
+    pub async fn get_mv_rewrite_records(
+        &self,
+        cluster: &Cluster,
+        hours: i32,
+        limit: usize,
+    ) -> ApiResult<(Vec<MvRewriteRecord>, bool)> {
+        use crate::models::cluster::ClusterType;
+
+        if cluster.cluster_type == ClusterType::Doris {
+            return Ok((Vec::new(), false));
+        }
+
+        let pool = self.mysql_pool_manager.get_pool(cluster).await?;
+        let mysql_client = MySQLClient::from_pool(pool);
+        let (audit_table, time_field, _query_time_field, is_query_field, _stmt_type_field) =
+            self.get_audit_config(cluster);
+
+        let query = format!(
+            r#"
+            SELECT
+                queryId as query_id,
+                COALESCE(`db`, '') as `database`,
+                COALESCE(`hitMvs`, '') as hit_mvs,
+                COALESCE(`candidateMvs`, '') as candidate_mvs
+            FROM {audit_table}
+            WHERE `{time_field}` >= DATE_SUB(NOW(), INTERVAL {hours} HOUR)
+                AND {is_query_field} = 1
+                AND `state` = 'EOF'
+                AND (COALESCE(`hitMvs`, '') != '' OR COALESCE(`candidateMvs`, '') != '')
+            ORDER BY `{time_field}` DESC
+            LIMIT {fetch_limit}
+            "#,
+            fetch_limit = limit + 1,
+        );
+
+        tracing::debug!("Querying MV rewrite records: hours={}, limit={}", hours, limit);
+
+        let (columns, rows) = mysql_client.query_raw(&query).await?;
+
+        let mut col_idx = std::collections::HashMap::new();
+        for (i, col) in columns.iter().enumerate() {
+            col_idx.insert(col.clone(), i);
+        }
+
+        let truncated = rows.len() > limit;
+        let records: Vec<MvRewriteRecord> = rows
+            .iter()
+            .take(limit)
+            .map(|row| {
+                let field = |name: &str| {
+                    col_idx
+                        .get(name)
+                        .and_then(|&i| row.get(i))
+                        .cloned()
+                        .unwrap_or_default()
+                };
+                MvRewriteRecord {
+                    query_id: field("query_id"),
+                    database: field("database"),
+                    hit_mvs: field("hit_mvs"),
+                    candidate_mvs: field("candidate_mvs"),
+                }
+            })
+            .collect();
+
+        tracing::info!(
+            "Found {} MV rewrite records ({}h window, truncated: {})",
+            records.len(),
+            hours,
+            truncated
+        );
+
+        Ok((records, truncated))
+    }
+
+    /// Get the most recent audited statements of any type, newest first
+    ///
+    /// Used to preview which traffic a SQL blacklist pattern would block.
+    ///
+    /// # Arguments
+    /// * `cluster` - The StarRocks cluster
+    /// * `hours` - Time window in hours
+    /// * `limit` - Maximum number of statements
+    pub async fn get_recent_statements(
+        &self,
+        cluster: &Cluster,
+        hours: i32,
+        limit: usize,
+    ) -> ApiResult<Vec<AuditStatement>> {
+        let pool = self.mysql_pool_manager.get_pool(cluster).await?;
+        let mysql_client = MySQLClient::from_pool(pool);
+        let (audit_table, time_field, _query_time_field, _is_query_field, _stmt_type_field) =
+            self.get_audit_config(cluster);
+
+        use crate::models::cluster::ClusterType;
+
+        let query_id_field = match cluster.cluster_type {
+            ClusterType::StarRocks => "queryId",
+            ClusterType::Doris => "query_id",
+        };
+
+        let query = format!(
+            r#"
+            SELECT
+                `{query_id_field}` as query_id,
+                COALESCE(`user`, '') as `user`,
+                COALESCE(`db`, '') as `database`,
+                CAST(`{time_field}` AS CHAR) as `timestamp`,
+                `stmt`
+            FROM {audit_table}
+            WHERE `{time_field}` >= DATE_SUB(NOW(), INTERVAL {hours} HOUR)
+                AND `stmt` IS NOT NULL
+            ORDER BY `{time_field}` DESC
+            LIMIT {limit}
+            "#,
+        );
+
+        tracing::debug!("Querying recent statements: hours={}, limit={}", hours, limit);
+
+        let (columns, rows) = mysql_client.query_raw(&query).await?;
+
+        let mut col_idx = std::collections::HashMap::new();
+        for (i, col) in columns.iter().enumerate() {
+            col_idx.insert(col.clone(), i);
+        }
+
+        let statements: Vec<AuditStatement> = rows
+            .iter()
+            .map(|row| {
+                let field = |name: &str| {
+                    col_idx
+                        .get(name)
+                        .and_then(|&i| row.get(i))
+                        .cloned()
+                        .unwrap_or_default()
+                };
+                AuditStatement {
+                    query_id: field("query_id"),
+                    user: field("user"),
+                    database: field("database"),
+                    timestamp: field("timestamp"),
+                    stmt: field("stmt"),
+                }
+            })
+            .collect();
+
+        tracing::info!("Fetched {} recent statements ({}h window)", statements.len(), hours);
+
+        Ok(statements)
+    }
 }
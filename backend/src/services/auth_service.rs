@@ -1,4 +1,5 @@
 use crate::models::{CreateUserRequest, LoginRequest, UpdateUserRequest, User, UserResponse};
+use crate::services::profile_analyzer::Locale;
 use crate::utils::{ApiError, ApiResult, JwtUtil};
 use bcrypt::{DEFAULT_COST, hash, verify};
 use sqlx::SqlitePool;
@@ -160,6 +161,27 @@ impl AuthService {
                 .await?;
         }
 
+        if let Some(language) = &req.language {
+            // An empty value clears the preference and falls back to Accept-Language
+            let language = if language.trim().is_empty() {
+                None
+            } else {
+                let locale = Locale::parse(language).ok_or_else(|| {
+                    ApiError::validation_error(format!("Unsupported language: {}", language))
+                })?;
+                Some(locale.as_str())
+            };
+
+            tracing::debug!("Updating language for user_id: {}", user_id);
+            sqlx::query(
+                "UPDATE users SET language = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+            )
+            .bind(language)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+        }
+
         let updated_user = self.get_user_by_id(user_id).await?;
         tracing::info!(
             "User updated successfully: {} (ID: {})",
@@ -49,6 +49,27 @@ impl DorisAdapter {
         Ok(MySQLClient::from_pool(pool))
     }
 
+    /// Workload groups from information_schema (2.1+), falling back to SHOW WORKLOAD GROUPS
+    async fn query_workload_groups(
+        &self,
+        mysql_client: &MySQLClient,
+    ) -> ApiResult<(Vec<String>, Vec<Vec<String>>)> {
+        match mysql_client
+            .query_raw("SELECT * FROM information_schema.workload_groups")
+            .await
+        {
+            Ok(result) => Ok(result),
+            Err(e) => {
+                tracing::debug!(
+                    "[Doris] information_schema.workload_groups unavailable on cluster {}: {}",
+                    self.cluster.name,
+                    e
+                );
+                mysql_client.query_raw("SHOW WORKLOAD GROUPS").await
+            },
+        }
+    }
+
     /// 折中实现：聚合所有数据库的 Load 错误信息
     /// 替代 StarRocks 的 SHOW PROC '/load_error_hub'
     async fn get_load_errors_compromise(&self) -> ApiResult<Vec<Value>> {
@@ -971,6 +992,49 @@ impl ClusterAdapter for DorisAdapter {
         Ok(mvs)
     }
 
+    async fn list_materialized_view_dependencies(
+        &self,
+    ) -> ApiResult<Vec<crate::models::MaterializedViewDependency>> {
+        use crate::services::parse_mv_base_tables;
+
+        let mysql_client = self.mysql_client().await?;
+        let databases = <Self as ClusterAdapter>::list_databases(self, None).await?;
+        let mut dependencies = Vec::new();
+
+        for db in databases {
+            if db.starts_with("__") || db == "information_schema" || db == "mysql" || db == "sys" {
+                continue;
+            }
+
+            let sql = format!(
+                "SELECT Name, QuerySql FROM mv_infos('database'='{}')",
+                db.replace('\'', "''")
+            );
+            match mysql_client.query_raw(&sql).await {
+                Ok((_, rows)) => {
+                    for row in rows {
+                        if let [name, query_sql, ..] = row.as_slice() {
+                            dependencies.extend(parse_mv_base_tables(&db, name, query_sql));
+                        }
+                    }
+                },
+                Err(e) => {
+                    tracing::warn!("[Doris] Failed to read mv_infos of database {}: {}", db, e);
+                },
+            }
+        }
+
+        Ok(dependencies)
+    }
+
+    async fn get_table_update_times(
+        &self,
+        databases: &[String],
+    ) -> ApiResult<std::collections::HashMap<(String, String), String>> {
+        let mysql_client = self.mysql_client().await?;
+        super::query_table_update_times(&mysql_client, databases).await
+    }
+
     async fn get_materialized_view_ddl(&self, mv_name: &str) -> ApiResult<String> {
         tracing::debug!(
             "[Doris] Getting MV DDL for {} from cluster: {}",
@@ -1281,6 +1345,86 @@ impl ClusterAdapter for DorisAdapter {
         Ok(())
     }
 
+    async fn list_resource_groups(&self) -> ApiResult<Vec<crate::models::ResourceGroup>> {
+        use crate::services::resource_group_service::parse_doris_workload_groups;
+
+        let mysql_client = self.mysql_client().await?;
+        let (columns, rows) = self.query_workload_groups(&mysql_client).await?;
+        Ok(parse_doris_workload_groups(&columns, &rows))
+    }
+
+    async fn create_resource_group(
+        &self,
+        request: &crate::models::CreateResourceGroupRequest,
+    ) -> ApiResult<()> {
+        use crate::services::resource_group_service::build_doris_create_sql;
+
+        let sql = build_doris_create_sql(request)?;
+        let mysql_client = self.mysql_client().await?;
+        mysql_client.execute(&sql).await?;
+        tracing::info!(
+            "[Doris] Created workload group {} on cluster {}",
+            request.name,
+            self.cluster.name
+        );
+        Ok(())
+    }
+
+    async fn alter_resource_group(
+        &self,
+        name: &str,
+        request: &crate::models::AlterResourceGroupRequest,
+    ) -> ApiResult<()> {
+        use crate::services::resource_group_service::build_doris_alter_sql;
+
+        let sql = build_doris_alter_sql(name, request)?;
+        let mysql_client = self.mysql_client().await?;
+        mysql_client.execute(&sql).await?;
+        tracing::info!("[Doris] Altered workload group {} on cluster {}", name, self.cluster.name);
+        Ok(())
+    }
+
+    async fn drop_resource_group(&self, name: &str) -> ApiResult<()> {
+        use crate::services::resource_group_service::validate_group_name;
+
+        validate_group_name(name)?;
+        let mysql_client = self.mysql_client().await?;
+        mysql_client
+            .execute(&format!("DROP WORKLOAD GROUP {}", name))
+            .await?;
+        tracing::info!("[Doris] Dropped workload group {} on cluster {}", name, self.cluster.name);
+        Ok(())
+    }
+
+    async fn get_resource_group_usage(&self) -> ApiResult<Vec<crate::models::ResourceGroupUsage>> {
+        use crate::services::resource_group_service::{
+            aggregate_group_usage, parse_doris_queue_counts, parse_doris_usage_rows,
+            parse_doris_workload_groups,
+        };
+
+        let mysql_client = self.mysql_client().await?;
+        let (columns, rows) = self.query_workload_groups(&mysql_client).await?;
+        let groups = parse_doris_workload_groups(&columns, &rows);
+        let queue = parse_doris_queue_counts(&columns, &rows);
+
+        let samples = match mysql_client
+            .query_raw("SELECT * FROM information_schema.workload_group_resource_usage")
+            .await
+        {
+            Ok((columns, rows)) => parse_doris_usage_rows(&columns, &rows, &groups),
+            Err(e) => {
+                tracing::warn!(
+                    "[Doris] Failed to get workload group usage for cluster {}: {}",
+                    self.cluster.name,
+                    e
+                );
+                Vec::new()
+            },
+        };
+
+        Ok(aggregate_group_usage(&groups, &samples, &queue))
+    }
+
     async fn show_proc_raw(&self, path: &str) -> ApiResult<Vec<Value>> {
         let normalized_path =
             if path.starts_with('/') { path.to_string() } else { format!("/{}", path) };
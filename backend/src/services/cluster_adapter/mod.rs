@@ -65,6 +65,18 @@ pub trait ClusterAdapter: Send + Sync {
         database: Option<&str>,
     ) -> ApiResult<Vec<crate::models::MaterializedView>>;
 
+    /// List base tables / views / MVs read by each async materialized view
+    async fn list_materialized_view_dependencies(
+        &self,
+    ) -> ApiResult<Vec<crate::models::MaterializedViewDependency>>;
+
+    /// Last data change (UPDATE_TIME) of tables in the given databases,
+    /// keyed by lower-cased (database, table)
+    async fn get_table_update_times(
+        &self,
+        databases: &[String],
+    ) -> ApiResult<std::collections::HashMap<(String, String), String>>;
+
     /// Get materialized view DDL
     async fn get_materialized_view_ddl(&self, mv_name: &str) -> ApiResult<String>;
 
@@ -103,6 +115,28 @@ pub trait ClusterAdapter: Send + Sync {
     /// Delete SQL blacklist rule
     async fn delete_sql_blacklist(&self, id: &str) -> ApiResult<()>;
 
+    /// List resource groups (StarRocks) or workload groups (Doris)
+    async fn list_resource_groups(&self) -> ApiResult<Vec<crate::models::ResourceGroup>>;
+
+    /// Create a resource group / workload group
+    async fn create_resource_group(
+        &self,
+        request: &crate::models::CreateResourceGroupRequest,
+    ) -> ApiResult<()>;
+
+    /// Alter classifiers or properties of a resource group / workload group
+    async fn alter_resource_group(
+        &self,
+        name: &str,
+        request: &crate::models::AlterResourceGroupRequest,
+    ) -> ApiResult<()>;
+
+    /// Drop a resource group / workload group
+    async fn drop_resource_group(&self, name: &str) -> ApiResult<()>;
+
+    /// Current running / queued queries and CPU / memory usage per group
+    async fn get_resource_group_usage(&self) -> ApiResult<Vec<crate::models::ResourceGroupUsage>>;
+
     /// Execute SQL command via HTTP API
     async fn execute_sql(&self, sql: &str) -> ApiResult<()>;
 
@@ -186,3 +220,35 @@ pub fn create_starrocks_adapter(
 pub fn create_doris_adapter(cluster: Cluster, pool_manager: Arc<MySQLPoolManager>) -> DorisAdapter {
     DorisAdapter::new(cluster, pool_manager)
 }
+
+/// UPDATE_TIME of tables in the given databases from information_schema.tables
+/// (same layout on StarRocks and Doris)
+async fn query_table_update_times(
+    mysql_client: &crate::services::MySQLClient,
+    databases: &[String],
+) -> ApiResult<std::collections::HashMap<(String, String), String>> {
+    let mut update_times = std::collections::HashMap::new();
+    if databases.is_empty() {
+        return Ok(update_times);
+    }
+
+    let in_list = databases
+        .iter()
+        .map(|db| format!("'{}'", db.replace('\'', "''")))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let sql = format!(
+        "SELECT TABLE_SCHEMA, TABLE_NAME, UPDATE_TIME FROM information_schema.tables \
+         WHERE TABLE_SCHEMA IN ({}) AND UPDATE_TIME IS NOT NULL",
+        in_list
+    );
+    let (_, rows) = mysql_client.query_raw(&sql).await?;
+    for row in rows {
+        if let [db, table, time, ..] = row.as_slice()
+            && !time.is_empty()
+        {
+            update_times.insert((db.to_lowercase(), table.to_lowercase()), time.clone());
+        }
+    }
+    Ok(update_times)
+}
@@ -447,6 +447,61 @@ impl ClusterAdapter for StarRocksAdapter {
         mv_service.list_materialized_views(database).await
     }
 
+    async fn list_materialized_view_dependencies(
+        &self,
+    ) -> ApiResult<Vec<crate::models::MaterializedViewDependency>> {
+        use crate::models::MaterializedViewDependency;
+        use crate::services::parse_mv_base_tables;
+
+        let mysql_client = self.mysql_client().await?;
+
+        // sys.object_dependencies (3.2+) resolves bases across catalogs exactly
+        let sql = "SELECT object_database, object_name, ref_object_catalog, ref_object_database, \
+                   ref_object_name, ref_object_type FROM sys.object_dependencies \
+                   WHERE object_type = 'MATERIALIZED_VIEW'";
+        match mysql_client.query_raw(sql).await {
+            Ok((_, rows)) => {
+                return Ok(rows
+                    .into_iter()
+                    .filter_map(|row| {
+                        let [mv_db, mv_name, catalog, db, table, kind, ..] = row.as_slice() else {
+                            return None;
+                        };
+                        let internal =
+                            catalog.is_empty() || catalog.eq_ignore_ascii_case("default_catalog");
+                        Some(MaterializedViewDependency {
+                            mv_database: mv_db.clone(),
+                            mv_name: mv_name.clone(),
+                            base_catalog: (!internal).then(|| catalog.clone()),
+                            base_database: db.clone(),
+                            base_table: table.clone(),
+                            base_type: Some(kind.clone()).filter(|k| !k.is_empty()),
+                        })
+                    })
+                    .collect());
+            },
+            Err(e) => tracing::debug!(
+                "sys.object_dependencies unavailable, parsing MV definitions: {}",
+                e
+            ),
+        }
+
+        let mvs = self.list_materialized_views(None).await?;
+        Ok(mvs
+            .iter()
+            .filter(|mv| mv.refresh_type != "ROLLUP")
+            .flat_map(|mv| parse_mv_base_tables(&mv.database_name, &mv.name, &mv.text))
+            .collect())
+    }
+
+    async fn get_table_update_times(
+        &self,
+        databases: &[String],
+    ) -> ApiResult<std::collections::HashMap<(String, String), String>> {
+        let mysql_client = self.mysql_client().await?;
+        super::query_table_update_times(&mysql_client, databases).await
+    }
+
     async fn get_materialized_view_ddl(&self, mv_name: &str) -> ApiResult<String> {
         use crate::services::MaterializedViewService;
 
@@ -513,9 +568,10 @@ impl ClusterAdapter for StarRocksAdapter {
     }
 
     async fn add_sql_blacklist(&self, pattern: &str) -> ApiResult<()> {
+        use crate::services::sql_blacklist_service::quote_blacklist_pattern;
+
         let mysql_client = self.mysql_client().await?;
-        let escaped_pattern = pattern.replace('"', "\\\"");
-        let sql = format!("ADD SQLBLACKLIST \"{}\"", escaped_pattern);
+        let sql = format!("ADD SQLBLACKLIST {}", quote_blacklist_pattern(pattern));
         mysql_client.execute(&sql).await?;
         Ok(())
     }
@@ -527,6 +583,106 @@ impl ClusterAdapter for StarRocksAdapter {
         Ok(())
     }
 
+    async fn list_resource_groups(&self) -> ApiResult<Vec<crate::models::ResourceGroup>> {
+        use crate::services::resource_group_service::parse_starrocks_resource_groups;
+
+        let mysql_client = self.mysql_client().await?;
+        let (columns, rows) = mysql_client.query_raw("SHOW RESOURCE GROUPS ALL").await?;
+        Ok(parse_starrocks_resource_groups(&columns, &rows))
+    }
+
+    async fn create_resource_group(
+        &self,
+        request: &crate::models::CreateResourceGroupRequest,
+    ) -> ApiResult<()> {
+        use crate::services::resource_group_service::build_starrocks_create_sql;
+
+        let sql = build_starrocks_create_sql(request)?;
+        let mysql_client = self.mysql_client().await?;
+        mysql_client.execute(&sql).await?;
+        tracing::info!("Created resource group {} on cluster {}", request.name, self.cluster.name);
+        Ok(())
+    }
+
+    async fn alter_resource_group(
+        &self,
+        name: &str,
+        request: &crate::models::AlterResourceGroupRequest,
+    ) -> ApiResult<()> {
+        use crate::services::resource_group_service::build_starrocks_alter_sqls;
+
+        let statements = build_starrocks_alter_sqls(name, request)?;
+        let mysql_client = self.mysql_client().await?;
+        for sql in &statements {
+            mysql_client.execute(sql).await?;
+        }
+        tracing::info!("Altered resource group {} on cluster {}", name, self.cluster.name);
+        Ok(())
+    }
+
+    async fn drop_resource_group(&self, name: &str) -> ApiResult<()> {
+        use crate::services::resource_group_service::validate_group_name;
+
+        validate_group_name(name)?;
+        let mysql_client = self.mysql_client().await?;
+        mysql_client
+            .execute(&format!("DROP RESOURCE GROUP {}", name))
+            .await?;
+        tracing::info!("Dropped resource group {} on cluster {}", name, self.cluster.name);
+        Ok(())
+    }
+
+    async fn get_resource_group_usage(&self) -> ApiResult<Vec<crate::models::ResourceGroupUsage>> {
+        use crate::services::resource_group_service::{
+            aggregate_group_usage, parse_starrocks_be_metrics, parse_starrocks_resource_groups,
+            parse_starrocks_running_queries, parse_starrocks_usage_rows,
+        };
+
+        let mysql_client = self.mysql_client().await?;
+        let (columns, rows) = mysql_client.query_raw("SHOW RESOURCE GROUPS ALL").await?;
+        let groups = parse_starrocks_resource_groups(&columns, &rows);
+
+        // be_metrics carries CPU ratio as well, older versions only have SHOW USAGE
+        let mut samples = match mysql_client
+            .query_raw(
+                "SELECT BE_ID, NAME, LABELS, VALUE FROM information_schema.be_metrics \
+                 WHERE NAME LIKE 'resource_group%'",
+            )
+            .await
+        {
+            Ok((columns, rows)) => parse_starrocks_be_metrics(&columns, &rows),
+            Err(e) => {
+                tracing::debug!("be_metrics unavailable on cluster {}: {}", self.cluster.name, e);
+                Vec::new()
+            },
+        };
+        if samples.is_empty() {
+            match mysql_client.query_raw("SHOW USAGE RESOURCE GROUPS").await {
+                Ok((columns, rows)) => samples = parse_starrocks_usage_rows(&columns, &rows),
+                Err(e) => tracing::warn!(
+                    "Failed to get resource group usage for cluster {}: {}",
+                    self.cluster.name,
+                    e
+                ),
+            }
+        }
+
+        // Query queue state lives on the FE; without it running counts come from the BEs
+        let queue = match mysql_client.query_raw("SHOW RUNNING QUERIES").await {
+            Ok((columns, rows)) => parse_starrocks_running_queries(&columns, &rows, &groups),
+            Err(e) => {
+                tracing::debug!(
+                    "SHOW RUNNING QUERIES unavailable on cluster {}: {}",
+                    self.cluster.name,
+                    e
+                );
+                Default::default()
+            },
+        };
+
+        Ok(aggregate_group_usage(&groups, &samples, &queue))
+    }
+
     async fn show_proc_raw(&self, path: &str) -> ApiResult<Vec<Value>> {
         let sql = Self::build_show_proc_sql(path);
         let mysql_client = self.mysql_client().await?;
@@ -0,0 +1,591 @@
+// Compaction Service
+// Purpose: Act on compaction pressure - trigger manual compaction through the BE HTTP API, show
+// per-BE compaction queues / thread settings, tune compaction configs and chart the score history.
+
+use crate::models::{Backend, Cluster, ClusterType};
+use crate::services::overview_service::TimeRange;
+use crate::services::{MySQLClient, MySQLPoolManager, create_adapter};
+use crate::utils::{ApiError, ApiResult, RowView};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+/// Maximum tablets compacted by one manual request
+const MAX_MANUAL_TABLETS: usize = 200;
+
+/// BE configs that may be changed through Stellar (StarRocks and Doris names)
+const TUNABLE_CONFIGS: [&str; 6] = [
+    "compact_threads",
+    "max_cumulative_compaction_num_singleton_deltas",
+    "base_compaction_num_threads_per_disk",
+    "cumulative_compaction_num_threads_per_disk",
+    "max_base_compaction_threads",
+    "max_cumulative_compaction_threads",
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CompactionType {
+    Base,
+    Cumulative,
+}
+
+impl CompactionType {
+    fn as_str(self) -> &'static str {
+        match self {
+            CompactionType::Base => "base",
+            CompactionType::Cumulative => "cumulative",
+        }
+    }
+}
+
+/// Manual compaction target: either a single tablet or every tablet of a partition
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct ManualCompactionRequest {
+    pub tablet_id: Option<i64>,
+    pub database: Option<String>,
+    pub table: Option<String>,
+    pub partition: Option<String>,
+    pub compaction_type: CompactionType,
+}
+
+/// Result of one `/api/compact` call on a replica
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct CompactionTriggerResult {
+    pub tablet_id: i64,
+    pub backend_id: String,
+    pub host: String,
+    pub success: bool,
+    pub message: String,
+}
+
+/// Compaction queue and thread settings of one BE
+#[derive(Debug, Serialize, Deserialize, Clone, Default, ToSchema)]
+pub struct BackendCompactionStatus {
+    pub backend_id: String,
+    pub host: String,
+    pub alive: bool,
+    pub candidates_num: Option<i64>,
+    pub base_running: Option<i64>,
+    pub cumulative_running: Option<i64>,
+    pub manual_running: Option<i64>,
+    pub latest_score: Option<f64>,
+    pub candidate_max_score: Option<f64>,
+    /// Current values of the tunable compaction configs
+    pub configs: HashMap<String, String>,
+    pub error: Option<String>,
+}
+
+/// Change a compaction config on all (or selected) BEs via `update_config`
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct CompactionConfigUpdate {
+    pub name: String,
+    pub value: String,
+    /// Target BE ids; all alive BEs when empty
+    #[serde(default)]
+    pub backend_ids: Vec<String>,
+    /// Persist to be.conf (Doris only)
+    #[serde(default)]
+    pub persist: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct CompactionConfigResult {
+    pub backend_id: String,
+    pub host: String,
+    pub success: bool,
+    pub message: String,
+}
+
+/// One point of the compaction pressure chart
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct CompactionPressurePoint {
+    pub collected_at: DateTime<Utc>,
+    pub max_compaction_score: f64,
+    pub tablet_count: i64,
+    pub load_running: i64,
+}
+
+#[derive(Clone)]
+pub struct CompactionService {
+    db: SqlitePool,
+    mysql_pool_manager: Arc<MySQLPoolManager>,
+    http_client: reqwest::Client,
+}
+
+impl CompactionService {
+    pub fn new(db: SqlitePool, mysql_pool_manager: Arc<MySQLPoolManager>) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+        Self { db, mysql_pool_manager, http_client }
+    }
+
+    /// Trigger manual compaction on every replica of a tablet or partition
+    pub async fn trigger_compaction(
+        &self,
+        cluster: &Cluster,
+        req: &ManualCompactionRequest,
+    ) -> ApiResult<Vec<CompactionTriggerResult>> {
+        let client = self.mysql_client(cluster).await?;
+
+        let (database, table, partition) = match req.tablet_id {
+            Some(tablet_id) => locate_tablet(&client, tablet_id).await?,
+            None => match (&req.database, &req.table, &req.partition) {
+                (Some(db), Some(tbl), Some(p)) => (db.clone(), tbl.clone(), p.clone()),
+                _ => {
+                    return Err(ApiError::validation_error(
+                        "Either tablet_id or database, table and partition are required",
+                    ));
+                },
+            },
+        };
+        for ident in [&database, &table, &partition] {
+            if ident.is_empty() || ident.contains('`') {
+                return Err(ApiError::validation_error(format!("Invalid identifier: {}", ident)));
+            }
+        }
+
+        let sql =
+            format!("SHOW TABLETS FROM `{}`.`{}` PARTITION (`{}`)", database, table, partition);
+        let (columns, rows) = client.query_raw(&sql).await?;
+        let mut replicas: Vec<(i64, String)> = rows
+            .iter()
+            .filter_map(|row| {
+                let v = RowView::new(&columns, row);
+                Some((v.int(&["TabletId"])?, v.string(&["BackendId"])))
+            })
+            .filter(|(tablet_id, _)| req.tablet_id.is_none_or(|t| t == *tablet_id))
+            .collect();
+        if replicas.is_empty() {
+            return Err(ApiError::not_found(format!(
+                "No tablets found in {}.{} partition {}",
+                database, table, partition
+            )));
+        }
+
+        replicas.sort();
+        let mut tablet_ids: Vec<i64> = replicas.iter().map(|(t, _)| *t).collect();
+        tablet_ids.dedup();
+        if tablet_ids.len() > MAX_MANUAL_TABLETS {
+            return Err(ApiError::validation_error(format!(
+                "Partition has {} tablets, manual compaction is limited to {}",
+                tablet_ids.len(),
+                MAX_MANUAL_TABLETS
+            )));
+        }
+
+        let backends = self.backends_by_id(cluster).await?;
+        let mut results = Vec::with_capacity(replicas.len());
+        for (tablet_id, backend_id) in replicas {
+            let Some(be) = backends.get(&backend_id) else {
+                results.push(CompactionTriggerResult {
+                    tablet_id,
+                    backend_id,
+                    host: String::new(),
+                    success: false,
+                    message: "Backend not found".to_string(),
+                });
+                continue;
+            };
+            let url = compact_url(cluster, be, tablet_id, req.compaction_type);
+            let (success, message) = self.post_be(cluster, &url).await;
+            results.push(CompactionTriggerResult {
+                tablet_id,
+                backend_id,
+                host: be.host.clone(),
+                success,
+                message,
+            });
+        }
+
+        tracing::info!(
+            "Manual {} compaction on cluster {} for {}.{} partition {}: {}/{} replicas accepted",
+            req.compaction_type.as_str(),
+            cluster.name,
+            database,
+            table,
+            partition,
+            results.iter().filter(|r| r.success).count(),
+            results.len()
+        );
+        Ok(results)
+    }
+
+    /// Per-BE compaction queues, running tasks and thread configs
+    pub async fn list_backend_status(
+        &self,
+        cluster: &Cluster,
+    ) -> ApiResult<Vec<BackendCompactionStatus>> {
+        let backends = self.backends_by_id(cluster).await?;
+        let mut statuses: Vec<BackendCompactionStatus> = backends
+            .values()
+            .map(|be| BackendCompactionStatus {
+                backend_id: be.backend_id.clone(),
+                host: be.host.clone(),
+                alive: be.alive.eq_ignore_ascii_case("true"),
+                ..Default::default()
+            })
+            .collect();
+        statuses.sort_by(|a, b| a.backend_id.cmp(&b.backend_id));
+
+        match cluster.cluster_type {
+            ClusterType::StarRocks => self.fill_starrocks_status(cluster, &mut statuses).await?,
+            ClusterType::Doris => {
+                for status in &mut statuses {
+                    if let Some(be) = backends.get(&status.backend_id) {
+                        self.fill_doris_status(cluster, be, status).await;
+                    }
+                }
+            },
+        }
+        Ok(statuses)
+    }
+
+    async fn fill_starrocks_status(
+        &self,
+        cluster: &Cluster,
+        statuses: &mut [BackendCompactionStatus],
+    ) -> ApiResult<()> {
+        let client = self.mysql_client(cluster).await?;
+
+        match client
+            .query_raw("SELECT * FROM information_schema.be_compactions")
+            .await
+        {
+            Ok((columns, rows)) => {
+                for row in &rows {
+                    let v = RowView::new(&columns, row);
+                    let be_id = v.string(&["BE_ID"]);
+                    if let Some(s) = statuses.iter_mut().find(|s| s.backend_id == be_id) {
+                        s.candidates_num = v.int(&["CANDIDATES_NUM"]);
+                        s.base_running = v.int(&["BASE_COMPACTION_CONCURRENCY"]);
+                        s.cumulative_running = v.int(&["CUMULATIVE_COMPACTION_CONCURRENCY"]);
+                        s.manual_running = v.int(&["MANUAL_COMPACTION_CONCURRENCY"]);
+                        s.latest_score = v.float(&["LATEST_COMPACTION_SCORE"]);
+                        s.candidate_max_score = v.float(&["CANDIDATE_MAX_SCORE"]);
+                    }
+                }
+            },
+            Err(e) => tracing::debug!("be_compactions not available: {}", e),
+        }
+
+        let names = TUNABLE_CONFIGS.map(|n| format!("'{}'", n)).join(", ");
+        let sql = format!(
+            "SELECT BE_ID, NAME, VALUE FROM information_schema.be_configs WHERE NAME IN ({})",
+            names
+        );
+        match client.query_raw(&sql).await {
+            Ok((columns, rows)) => {
+                for row in &rows {
+                    let v = RowView::new(&columns, row);
+                    let be_id = v.string(&["BE_ID"]);
+                    if let Some(s) = statuses.iter_mut().find(|s| s.backend_id == be_id) {
+                        s.configs.insert(v.string(&["NAME"]), v.string(&["VALUE"]));
+                    }
+                }
+            },
+            Err(e) => tracing::debug!("be_configs not available: {}", e),
+        }
+        Ok(())
+    }
+
+    async fn fill_doris_status(
+        &self,
+        cluster: &Cluster,
+        be: &Backend,
+        status: &mut BackendCompactionStatus,
+    ) {
+        let base = be_base_url(cluster, be);
+
+        match self
+            .get_be_json(cluster, &format!("{}/api/compaction/run_status", base))
+            .await
+        {
+            Ok(body) => {
+                let (base_running, cumulative_running) = count_doris_running(&body);
+                status.base_running = Some(base_running);
+                status.cumulative_running = Some(cumulative_running);
+            },
+            Err(e) => status.error = Some(e),
+        }
+
+        match self
+            .get_be_json(cluster, &format!("{}/api/show_config", base))
+            .await
+        {
+            Ok(body) => status.configs = parse_show_config(&body),
+            Err(e) => {
+                status.error.get_or_insert(e);
+            },
+        }
+    }
+
+    /// Change a tunable compaction config through the BE `update_config` API
+    pub async fn update_config(
+        &self,
+        cluster: &Cluster,
+        update: &CompactionConfigUpdate,
+    ) -> ApiResult<Vec<CompactionConfigResult>> {
+        if !TUNABLE_CONFIGS.contains(&update.name.as_str()) {
+            return Err(ApiError::validation_error(format!(
+                "Config {} is not tunable, allowed: {}",
+                update.name,
+                TUNABLE_CONFIGS.join(", ")
+            )));
+        }
+        if update.value.parse::<u32>().map_or(true, |v| v == 0) {
+            return Err(ApiError::validation_error("Config value must be a positive integer"));
+        }
+
+        let backends = self.backends_by_id(cluster).await?;
+        let mut targets: Vec<&Backend> = backends
+            .values()
+            .filter(|be| {
+                if update.backend_ids.is_empty() {
+                    be.alive.eq_ignore_ascii_case("true")
+                } else {
+                    update.backend_ids.contains(&be.backend_id)
+                }
+            })
+            .collect();
+        if targets.is_empty() {
+            return Err(ApiError::not_found("No matching backend"));
+        }
+        targets.sort_by(|a, b| a.backend_id.cmp(&b.backend_id));
+
+        let mut results = Vec::with_capacity(targets.len());
+        for be in targets {
+            let mut url = format!(
+                "{}/api/update_config?{}={}",
+                be_base_url(cluster, be),
+                update.name,
+                update.value
+            );
+            if update.persist && cluster.cluster_type == ClusterType::Doris {
+                url.push_str("&persist=true");
+            }
+            let (success, message) = self.post_be(cluster, &url).await;
+            results.push(CompactionConfigResult {
+                backend_id: be.backend_id.clone(),
+                host: be.host.clone(),
+                success,
+                message,
+            });
+        }
+
+        tracing::info!(
+            "Set {}={} on cluster {}: {}/{} backends updated",
+            update.name,
+            update.value,
+            cluster.name,
+            results.iter().filter(|r| r.success).count(),
+            results.len()
+        );
+        Ok(results)
+    }
+
+    /// Compaction score history from the collected metrics snapshots
+    pub async fn get_pressure_history(
+        &self,
+        cluster_id: i64,
+        time_range: &TimeRange,
+    ) -> ApiResult<Vec<CompactionPressurePoint>> {
+        let rows: Vec<(chrono::NaiveDateTime, f64, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT collected_at, max_compaction_score, tablet_count, load_running
+            FROM metrics_snapshots
+            WHERE cluster_id = ? AND collected_at BETWEEN ? AND ?
+            ORDER BY collected_at ASC
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(time_range.start_time())
+        .bind(time_range.end_time())
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(collected_at, max_compaction_score, tablet_count, load_running)| {
+                CompactionPressurePoint {
+                    collected_at: collected_at.and_utc(),
+                    max_compaction_score,
+                    tablet_count,
+                    load_running,
+                }
+            })
+            .collect())
+    }
+
+    async fn backends_by_id(&self, cluster: &Cluster) -> ApiResult<HashMap<String, Backend>> {
+        let adapter = create_adapter(cluster.clone(), self.mysql_pool_manager.clone());
+        Ok(adapter
+            .get_backends()
+            .await?
+            .into_iter()
+            .map(|be| (be.backend_id.clone(), be))
+            .collect())
+    }
+
+    /// POST to a BE endpoint, returning (success, message)
+    async fn post_be(&self, cluster: &Cluster, url: &str) -> (bool, String) {
+        let response = self
+            .http_client
+            .post(url)
+            .basic_auth(&cluster.username, Some(&cluster.password_encrypted))
+            .send()
+            .await;
+        match response {
+            Ok(resp) => {
+                let status = resp.status();
+                let body = resp.text().await.unwrap_or_default();
+                (status.is_success() && be_reports_success(&body), body.trim().to_string())
+            },
+            Err(e) => (false, format!("Request failed: {}", e)),
+        }
+    }
+
+    async fn get_be_json(&self, cluster: &Cluster, url: &str) -> Result<serde_json::Value, String> {
+        let resp = self
+            .http_client
+            .get(url)
+            .basic_auth(&cluster.username, Some(&cluster.password_encrypted))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+        if !resp.status().is_success() {
+            return Err(format!("{} returned {}", url, resp.status()));
+        }
+        resp.json()
+            .await
+            .map_err(|e| format!("Invalid response: {}", e))
+    }
+
+    async fn mysql_client(&self, cluster: &Cluster) -> ApiResult<MySQLClient> {
+        let pool = self.mysql_pool_manager.get_pool(cluster).await?;
+        Ok(MySQLClient::from_pool(pool))
+    }
+}
+
+/// Resolve database / table / partition of a tablet via `SHOW TABLET <id>`
+async fn locate_tablet(
+    client: &MySQLClient,
+    tablet_id: i64,
+) -> ApiResult<(String, String, String)> {
+    let (columns, rows) = client
+        .query_raw(&format!("SHOW TABLET {}", tablet_id))
+        .await?;
+    let row = rows
+        .first()
+        .ok_or_else(|| ApiError::not_found(format!("Tablet {} not found", tablet_id)))?;
+    let v = RowView::new(&columns, row);
+    Ok((v.string(&["DbName"]), v.string(&["TableName"]), v.string(&["PartitionName"])))
+}
+
+pub(crate) fn be_base_url(cluster: &Cluster, be: &Backend) -> String {
+    let protocol = if cluster.enable_ssl { "https" } else { "http" };
+    format!("{}://{}:{}", protocol, be.host, be.http_port)
+}
+
+fn compact_url(cluster: &Cluster, be: &Backend, tablet_id: i64, kind: CompactionType) -> String {
+    match cluster.cluster_type {
+        ClusterType::StarRocks => format!(
+            "{}/api/compact?tablet_id={}&compaction_type={}",
+            be_base_url(cluster, be),
+            tablet_id,
+            kind.as_str()
+        ),
+        ClusterType::Doris => format!(
+            "{}/api/compaction/run?tablet_id={}&compact_type={}",
+            be_base_url(cluster, be),
+            tablet_id,
+            kind.as_str()
+        ),
+    }
+}
+
+/// BE HTTP APIs answer 200 with `{"status": "Fail", ...}` on logical errors
+pub(crate) fn be_reports_success(body: &str) -> bool {
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(json) => json
+            .get("status")
+            .and_then(|s| s.as_str())
+            .is_none_or(|s| s.eq_ignore_ascii_case("success") || s.eq_ignore_ascii_case("ok")),
+        Err(_) => !body.to_lowercase().contains("fail"),
+    }
+}
+
+/// Count running tablets in Doris `/api/compaction/run_status`
+/// (`{"BaseCompaction": {"/data": [..]}, "CumulativeCompaction": {...}}`)
+fn count_doris_running(body: &serde_json::Value) -> (i64, i64) {
+    let count = |key: &str| {
+        body.get(key)
+            .and_then(|v| v.as_object())
+            .map(|dirs| {
+                dirs.values()
+                    .filter_map(|t| t.as_array())
+                    .map(|t| t.len() as i64)
+                    .sum()
+            })
+            .unwrap_or(0)
+    };
+    (count("BaseCompaction"), count("CumulativeCompaction"))
+}
+
+/// Pick the tunable configs from Doris `/api/show_config` (`[[name, type, value, mutable], ...]`)
+fn parse_show_config(body: &serde_json::Value) -> HashMap<String, String> {
+    body.as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.as_array()?;
+            let name = entry.first()?.as_str()?;
+            let value = entry.get(2)?.as_str()?;
+            TUNABLE_CONFIGS
+                .contains(&name)
+                .then(|| (name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_be_reports_success() {
+        assert!(be_reports_success(
+            r#"{"status": "Success", "msg": "compaction task is successfully triggered"}"#
+        ));
+        assert!(!be_reports_success(r#"{"status": "Fail", "msg": "Tablet not found"}"#));
+        assert!(be_reports_success("OK"));
+        assert!(!be_reports_success("failed to update config"));
+    }
+
+    #[test]
+    fn test_count_doris_running() {
+        let body = json!({
+            "BaseCompaction": {"/data1": [1001], "/data2": []},
+            "CumulativeCompaction": {"/data1": [1002, 1003], "/data2": [1004]}
+        });
+        assert_eq!(count_doris_running(&body), (1, 3));
+        assert_eq!(count_doris_running(&json!({})), (0, 0));
+    }
+
+    #[test]
+    fn test_parse_show_config_keeps_tunables() {
+        let body = json!([
+            ["max_cumulative_compaction_num_singleton_deltas", "int64", "1000", "true"],
+            ["be_port", "int32", "9060", "false"]
+        ]);
+        let configs = parse_show_config(&body);
+        assert_eq!(configs.len(), 1);
+        assert_eq!(configs["max_cumulative_compaction_num_singleton_deltas"], "1000");
+    }
+}
@@ -0,0 +1,426 @@
+// Config Drift Service
+// Purpose: Compare global variables and FE config of clusters that should be configured alike
+// (staging / production) and generate the script converging one cluster onto a reference.
+
+use crate::models::ClusterType;
+use crate::services::global_variable_history_service::set_global_statement;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use utoipa::ToSchema;
+
+/// Clusters compared in one request
+pub const MAX_DRIFT_CLUSTERS: usize = 10;
+
+/// Variables that differ per node, version or connection and never converge
+const VOLATILE_VARIABLES: &[&str] = &[
+    "version",
+    "version_comment",
+    "hostname",
+    "server_id",
+    "server_uuid",
+    "timestamp",
+    "last_insert_id",
+    "identity",
+    "warning_count",
+    "error_count",
+    "system_time_zone",
+    "license",
+];
+
+/// FE configs bound to the host; keys ending in `_dir` / `_path` are volatile too
+const VOLATILE_CONFIGS: &[&str] = &[
+    "priority_networks",
+    "frontend_address",
+    "meta_dir",
+    "sys_log_dir",
+    "audit_log_dir",
+    "tmp_dir",
+    "cluster_id",
+    "mysql_server_version",
+];
+
+pub const KIND_VARIABLE: &str = "variable";
+pub const KIND_CONFIG: &str = "config";
+
+/// Drift comparison request
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ConfigDriftRequest {
+    pub cluster_ids: Vec<i64>,
+    /// Cluster the others converge onto (default: the first one)
+    pub reference_cluster_id: Option<i64>,
+    /// Also compare volatile keys such as version, host names and directories
+    #[serde(default)]
+    pub include_volatile: bool,
+    /// Extra keys to skip; `prefix*` skips every key starting with prefix
+    #[serde(default)]
+    pub ignore_keys: Vec<String>,
+}
+
+/// Variables and FE config fetched from one cluster
+#[derive(Debug, Clone, Default)]
+pub struct ClusterSettings {
+    pub cluster_id: i64,
+    pub cluster_name: String,
+    pub cluster_type: ClusterType,
+    pub variables: BTreeMap<String, String>,
+    pub configs: BTreeMap<String, String>,
+    /// FE configs that `ADMIN SET FRONTEND CONFIG` cannot change
+    pub immutable_configs: HashSet<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq)]
+pub struct DriftCluster {
+    pub id: i64,
+    pub name: String,
+    pub cluster_type: ClusterType,
+}
+
+/// A key whose normalized value is not the same on every cluster
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq)]
+pub struct DriftEntry {
+    /// variable | config
+    pub kind: String,
+    pub key: String,
+    /// Value per cluster, in the order of `clusters`; missing keys are null
+    pub values: Vec<Option<String>>,
+}
+
+/// Statements converging one cluster onto the reference
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq)]
+pub struct ConvergenceScript {
+    pub cluster_id: i64,
+    pub cluster_name: String,
+    pub statements: Vec<String>,
+    /// Differences that need a manual change (immutable config, key missing on one side)
+    pub manual_steps: Vec<String>,
+    /// Statements and manual steps as one SQL script
+    pub script: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct ConfigDriftReport {
+    pub reference_cluster_id: i64,
+    pub clusters: Vec<DriftCluster>,
+    pub variables: Vec<DriftEntry>,
+    pub configs: Vec<DriftEntry>,
+    /// Keys left out as volatile or ignored
+    pub skipped_keys: usize,
+    pub scripts: Vec<ConvergenceScript>,
+}
+
+/// Key filter built from the volatile lists and the request
+pub struct DriftFilter {
+    include_volatile: bool,
+    ignore_exact: HashSet<String>,
+    ignore_prefixes: Vec<String>,
+}
+
+impl DriftFilter {
+    pub fn new(include_volatile: bool, ignore_keys: &[String]) -> Self {
+        let mut ignore_exact = HashSet::new();
+        let mut ignore_prefixes = Vec::new();
+        for key in ignore_keys.iter().map(|k| normalize_key(k)) {
+            match key.strip_suffix('*') {
+                Some(prefix) => ignore_prefixes.push(prefix.to_string()),
+                None => {
+                    ignore_exact.insert(key);
+                },
+            }
+        }
+        Self { include_volatile, ignore_exact, ignore_prefixes }
+    }
+
+    pub fn skips(&self, kind: &str, key: &str) -> bool {
+        if self.ignore_exact.contains(key)
+            || self.ignore_prefixes.iter().any(|p| key.starts_with(p))
+        {
+            return true;
+        }
+        if self.include_volatile {
+            return false;
+        }
+        match kind {
+            KIND_VARIABLE => VOLATILE_VARIABLES.contains(&key),
+            _ => VOLATILE_CONFIGS.contains(&key) || key.ends_with("_dir") || key.ends_with("_path"),
+        }
+    }
+}
+
+fn normalize_key(key: &str) -> String {
+    key.trim().to_lowercase()
+}
+
+/// Trimmed value; booleans compare case-insensitively
+fn normalize_value(value: &str) -> String {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+        value.to_lowercase()
+    } else {
+        value.to_string()
+    }
+}
+
+fn normalize_map(map: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+    map.iter()
+        .map(|(k, v)| (normalize_key(k), normalize_value(v)))
+        .collect()
+}
+
+/// Keys whose value differs between the maps, with the values in map order
+fn diff_maps(
+    kind: &str,
+    maps: &[BTreeMap<String, String>],
+    filter: &DriftFilter,
+    skipped: &mut BTreeSet<String>,
+) -> Vec<DriftEntry> {
+    let keys: BTreeSet<&String> = maps.iter().flat_map(|m| m.keys()).collect();
+    keys.into_iter()
+        .filter(|key| {
+            let skip = filter.skips(kind, key);
+            if skip {
+                skipped.insert(format!("{}:{}", kind, key));
+            }
+            !skip
+        })
+        .filter_map(|key| {
+            let values: Vec<Option<String>> = maps.iter().map(|m| m.get(key).cloned()).collect();
+            let drifted = values.iter().any(|v| *v != values[0]);
+            drifted.then(|| DriftEntry { kind: kind.to_string(), key: key.clone(), values })
+        })
+        .collect()
+}
+
+fn set_frontend_config_statement(key: &str, value: &str) -> Option<String> {
+    let valid_key = key
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+    let valid_value = !value.contains(['"', '\\', '\n', '\r']);
+    (valid_key && !key.is_empty() && valid_value)
+        .then(|| format!("ADMIN SET FRONTEND CONFIG (\"{}\" = \"{}\")", key, value))
+}
+
+/// Statements making `target` match `reference` for the drifted keys
+pub fn convergence_script(
+    reference: &ClusterSettings,
+    reference_index: usize,
+    target: &ClusterSettings,
+    target_index: usize,
+    variables: &[DriftEntry],
+    configs: &[DriftEntry],
+) -> ConvergenceScript {
+    let mut statements = Vec::new();
+    let mut manual_steps = Vec::new();
+    let immutable: HashSet<String> = target
+        .immutable_configs
+        .iter()
+        .chain(reference.immutable_configs.iter())
+        .map(|k| normalize_key(k))
+        .collect();
+
+    for entry in variables {
+        let (wanted, current) = (&entry.values[reference_index], &entry.values[target_index]);
+        if wanted == current {
+            continue;
+        }
+        match wanted {
+            Some(value) => match set_global_statement(&entry.key, value) {
+                Ok(statement) => statements.push(statement),
+                Err(e) => manual_steps.push(format!("variable {}: {}", entry.key, e)),
+            },
+            None => manual_steps.push(format!(
+                "variable {} does not exist on {}, current value {}",
+                entry.key,
+                reference.cluster_name,
+                current.as_deref().unwrap_or_default()
+            )),
+        }
+    }
+
+    for entry in configs {
+        let (wanted, current) = (&entry.values[reference_index], &entry.values[target_index]);
+        if wanted == current {
+            continue;
+        }
+        let Some(value) = wanted else {
+            manual_steps
+                .push(format!("config {} does not exist on {}", entry.key, reference.cluster_name));
+            continue;
+        };
+        if immutable.contains(&entry.key) {
+            manual_steps.push(format!(
+                "config {} = {} is not mutable, set it in fe.conf and restart the FE",
+                entry.key, value
+            ));
+            continue;
+        }
+        match set_frontend_config_statement(&entry.key, value) {
+            Some(statement) => statements.push(statement),
+            None => manual_steps
+                .push(format!("config {} = {} cannot be set by statement", entry.key, value)),
+        }
+    }
+
+    let mut script = format!(
+        "-- Converge {} onto {}\n\
+         -- FE config changes apply to the connected FE and are not written to fe.conf\n",
+        target.cluster_name, reference.cluster_name
+    );
+    for step in &manual_steps {
+        script.push_str(&format!("-- MANUAL: {}\n", step));
+    }
+    for statement in &statements {
+        script.push_str(statement);
+        script.push_str(";\n");
+    }
+
+    ConvergenceScript {
+        cluster_id: target.cluster_id,
+        cluster_name: target.cluster_name.clone(),
+        statements,
+        manual_steps,
+        script,
+    }
+}
+
+/// Normalized diff of all clusters and the scripts converging them onto the reference
+pub fn build_drift_report(
+    settings: &[ClusterSettings],
+    reference_cluster_id: i64,
+    filter: &DriftFilter,
+) -> ConfigDriftReport {
+    let reference_index = settings
+        .iter()
+        .position(|s| s.cluster_id == reference_cluster_id)
+        .unwrap_or(0);
+    let mut skipped = BTreeSet::new();
+
+    let variable_maps: Vec<_> = settings
+        .iter()
+        .map(|s| normalize_map(&s.variables))
+        .collect();
+    let config_maps: Vec<_> = settings.iter().map(|s| normalize_map(&s.configs)).collect();
+    let variables = diff_maps(KIND_VARIABLE, &variable_maps, filter, &mut skipped);
+    let configs = diff_maps(KIND_CONFIG, &config_maps, filter, &mut skipped);
+
+    let reference = &settings[reference_index];
+    let scripts = settings
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != reference_index)
+        .map(|(index, target)| {
+            convergence_script(reference, reference_index, target, index, &variables, &configs)
+        })
+        .collect();
+
+    ConfigDriftReport {
+        reference_cluster_id: reference.cluster_id,
+        clusters: settings
+            .iter()
+            .map(|s| DriftCluster {
+                id: s.cluster_id,
+                name: s.cluster_name.clone(),
+                cluster_type: s.cluster_type,
+            })
+            .collect(),
+        variables,
+        configs,
+        skipped_keys: skipped.len(),
+        scripts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(
+        id: i64,
+        name: &str,
+        vars: &[(&str, &str)],
+        configs: &[(&str, &str)],
+    ) -> ClusterSettings {
+        let map = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+        ClusterSettings {
+            cluster_id: id,
+            cluster_name: name.to_string(),
+            variables: map(vars),
+            configs: map(configs),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_drift_normalization_and_filters() {
+        let staging = settings(
+            1,
+            "staging",
+            &[("query_timeout", "300"), ("enable_profile", "TRUE"), ("version", "3.2.1")],
+            &[("max_routine_load_task_num_per_be", "16"), ("meta_dir", "/data/meta")],
+        );
+        let production = settings(
+            2,
+            "production",
+            &[("query_timeout", "600"), ("enable_profile", "true"), ("version", "3.2.4")],
+            &[("max_routine_load_task_num_per_be", "16"), ("meta_dir", "/fe/meta")],
+        );
+        let report = build_drift_report(
+            &[staging.clone(), production.clone()],
+            1,
+            &DriftFilter::new(false, &[]),
+        );
+        assert_eq!(report.variables.len(), 1);
+        assert_eq!(report.variables[0].key, "query_timeout");
+        assert_eq!(
+            report.variables[0].values,
+            vec![Some("300".to_string()), Some("600".to_string())]
+        );
+        assert!(report.configs.is_empty());
+        assert_eq!(report.skipped_keys, 2);
+
+        let report = build_drift_report(
+            &[staging, production],
+            1,
+            &DriftFilter::new(true, &["query_*".to_string()]),
+        );
+        let keys: Vec<&str> = report.variables.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, vec!["version"]);
+        assert_eq!(report.configs[0].key, "meta_dir");
+    }
+
+    #[test]
+    fn test_convergence_script() {
+        let reference = settings(
+            1,
+            "staging",
+            &[("query_timeout", "300"), ("time_zone", "Asia/Shanghai")],
+            &[("tablet_sched_max_scheduling_tablets", "10000"), ("http_port", "8030")],
+        );
+        let mut target = settings(
+            2,
+            "production",
+            &[("query_timeout", "600"), ("time_zone", "Asia/Shanghai"), ("new_var", "1")],
+            &[("tablet_sched_max_scheduling_tablets", "2000"), ("http_port", "8040")],
+        );
+        target.immutable_configs.insert("http_port".to_string());
+
+        let report = build_drift_report(&[reference, target], 1, &DriftFilter::new(false, &[]));
+        let script = &report.scripts[0];
+        assert_eq!(script.cluster_id, 2);
+        assert_eq!(
+            script.statements,
+            vec![
+                "SET GLOBAL query_timeout = 300",
+                "ADMIN SET FRONTEND CONFIG (\"tablet_sched_max_scheduling_tablets\" = \"10000\")",
+            ]
+        );
+        assert_eq!(script.manual_steps.len(), 2);
+        assert!(script.manual_steps[0].starts_with("variable new_var does not exist"));
+        assert!(script.manual_steps[1].contains("http_port = 8030 is not mutable"));
+        assert!(script.script.ends_with("= \"10000\");\n"));
+    }
+}
@@ -0,0 +1,471 @@
+// Global Variable History Service
+// Purpose: Snapshot global variables periodically and around every change made through Stellar,
+// keep per-variable diffs with the user who changed them, flag changes made outside Stellar and
+// generate the SET GLOBAL statements that roll back to an earlier snapshot.
+
+use crate::models::Cluster;
+use crate::services::{ClusterService, MySQLClient, MySQLPoolManager};
+use crate::utils::{ApiError, ApiResult, ScheduledTask};
+use chrono::{NaiveDateTime, Utc};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use utoipa::ToSchema;
+
+/// Change made through the variables page
+pub const SOURCE_STELLAR: &str = "stellar";
+/// Change made through a snapshot rollback
+pub const SOURCE_ROLLBACK: &str = "rollback";
+/// Change found by a snapshot that Stellar did not make
+pub const SOURCE_OUT_OF_BAND: &str = "out_of_band";
+
+/// Snapshot of all global variables, only stored when something changed
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, sqlx::FromRow)]
+pub struct GlobalVariableSnapshot {
+    pub id: i64,
+    pub cluster_id: i64,
+    /// Variables that differ from the previous snapshot (0 for the first one)
+    pub changed_count: i64,
+    pub created_by: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+/// One variable change between two snapshots
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, sqlx::FromRow)]
+pub struct GlobalVariableChange {
+    pub id: i64,
+    pub cluster_id: i64,
+    pub snapshot_id: i64,
+    pub variable_name: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    /// stellar | rollback | out_of_band
+    pub source: String,
+    /// Stellar user; empty for out-of-band changes
+    pub changed_by: Option<String>,
+    pub changed_at: NaiveDateTime,
+}
+
+/// Rollback request of a snapshot
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct GlobalVariableRollbackRequest {
+    /// Run the statements; otherwise they are only generated
+    #[serde(default)]
+    pub execute: bool,
+}
+
+/// Statement restoring one variable
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq)]
+pub struct GlobalVariableRollbackStatement {
+    pub variable_name: String,
+    pub current_value: Option<String>,
+    pub target_value: String,
+    pub statement: String,
+    pub error: Option<String>,
+}
+
+/// Statements that bring the cluster back to a snapshot
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct GlobalVariableRollback {
+    pub snapshot_id: i64,
+    pub executed: bool,
+    pub statements: Vec<GlobalVariableRollbackStatement>,
+}
+
+/// Variable changes between two snapshots as (name, old, new), in name order
+pub fn diff_variables(
+    previous: &BTreeMap<String, String>,
+    current: &BTreeMap<String, String>,
+) -> Vec<(String, Option<String>, Option<String>)> {
+    let mut changes: Vec<(String, Option<String>, Option<String>)> = current
+        .iter()
+        .filter(|(name, value)| previous.get(*name) != Some(*value))
+        .map(|(name, value)| (name.clone(), previous.get(name).cloned(), Some(value.clone())))
+        .collect();
+    changes.extend(
+        previous
+            .iter()
+            .filter(|(name, _)| !current.contains_key(*name))
+            .map(|(name, value)| (name.clone(), Some(value.clone()), None)),
+    );
+    changes.sort_by(|a, b| a.0.cmp(&b.0));
+    changes
+}
+
+/// `SET GLOBAL name = value` restoring a recorded value
+pub fn set_global_statement(name: &str, value: &str) -> ApiResult<String> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+    if !valid {
+        return Err(ApiError::validation_error(format!("Invalid variable name: {}", name)));
+    }
+    let is_number = value.parse::<f64>().is_ok()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '-' || c == '.');
+    let is_literal =
+        is_number || value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false");
+    let value = if is_literal {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+    };
+    Ok(format!("SET GLOBAL {} = {}", name, value))
+}
+
+/// Rollback statements for every variable whose live value differs from the snapshot
+pub fn rollback_statements(
+    snapshot: &BTreeMap<String, String>,
+    current: &BTreeMap<String, String>,
+) -> Vec<GlobalVariableRollbackStatement> {
+    snapshot
+        .iter()
+        .filter(|(name, value)| current.get(*name) != Some(*value))
+        .map(|(name, value)| {
+            let (statement, error) = match set_global_statement(name, value) {
+                Ok(statement) => (statement, None),
+                Err(e) => (String::new(), Some(e.to_string())),
+            };
+            GlobalVariableRollbackStatement {
+                variable_name: name.clone(),
+                current_value: current.get(name).cloned(),
+                target_value: value.clone(),
+                statement,
+                error,
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct GlobalVariableHistoryService {
+    db: SqlitePool,
+    cluster_service: Arc<ClusterService>,
+    mysql_pool_manager: Arc<MySQLPoolManager>,
+    /// Per-cluster locks keeping the scheduled snapshot from attributing a Stellar change to
+    /// out-of-band
+    change_locks: Arc<DashMap<i64, Arc<Mutex<()>>>>,
+}
+
+impl GlobalVariableHistoryService {
+    pub fn new(
+        db: SqlitePool,
+        cluster_service: Arc<ClusterService>,
+        mysql_pool_manager: Arc<MySQLPoolManager>,
+    ) -> Self {
+        Self { db, cluster_service, mysql_pool_manager, change_locks: Arc::new(DashMap::new()) }
+    }
+
+    fn change_lock(&self, cluster_id: i64) -> Arc<Mutex<()>> {
+        Arc::clone(self.change_locks.entry(cluster_id).or_default().value())
+    }
+
+    /// Run a `SET GLOBAL` statement and record its diff under the user
+    ///
+    /// Changes made since the last snapshot are recorded as out-of-band first.
+    pub async fn apply_global_change(
+        &self,
+        cluster: &Cluster,
+        statement: &str,
+        username: &str,
+    ) -> ApiResult<()> {
+        let lock = self.change_lock(cluster.id);
+        let _guard = lock.lock().await;
+        if let Err(e) = self.snapshot(cluster, SOURCE_OUT_OF_BAND, None).await {
+            tracing::warn!("Global variable snapshot before change failed: {}", e);
+        }
+        self.client(cluster).await?.execute(statement).await?;
+        if let Err(e) = self.snapshot(cluster, SOURCE_STELLAR, Some(username)).await {
+            tracing::warn!("Global variable snapshot after change failed: {}", e);
+        }
+        Ok(())
+    }
+
+    /// Snapshot now and return the out-of-band changes found
+    pub async fn detect_changes(&self, cluster: &Cluster) -> ApiResult<Vec<GlobalVariableChange>> {
+        let lock = self.change_lock(cluster.id);
+        let _guard = lock.lock().await;
+        match self.snapshot(cluster, SOURCE_OUT_OF_BAND, None).await? {
+            Some(snapshot_id) => {
+                self.list_changes(cluster.id, None, Some(snapshot_id), 1000)
+                    .await
+            },
+            None => Ok(Vec::new()),
+        }
+    }
+
+    pub async fn list_snapshots(
+        &self,
+        cluster_id: i64,
+        limit: i64,
+    ) -> ApiResult<Vec<GlobalVariableSnapshot>> {
+        let snapshots = sqlx::query_as(
+            "SELECT id, cluster_id, changed_count, created_by, created_at \
+             FROM global_variable_snapshots WHERE cluster_id = ? \
+             ORDER BY created_at DESC, id DESC LIMIT ?",
+        )
+        .bind(cluster_id)
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+        Ok(snapshots)
+    }
+
+    /// Changes newest first, optionally of one variable or one snapshot
+    pub async fn list_changes(
+        &self,
+        cluster_id: i64,
+        variable: Option<&str>,
+        snapshot_id: Option<i64>,
+        limit: i64,
+    ) -> ApiResult<Vec<GlobalVariableChange>> {
+        let changes = sqlx::query_as(
+            r#"
+            SELECT id, cluster_id, snapshot_id, variable_name, old_value, new_value, source,
+                   changed_by, changed_at
+            FROM global_variable_changes
+            WHERE cluster_id = ? AND (? IS NULL OR variable_name = ?)
+              AND (? IS NULL OR snapshot_id = ?)
+            ORDER BY changed_at DESC, id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(variable)
+        .bind(variable)
+        .bind(snapshot_id)
+        .bind(snapshot_id)
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+        Ok(changes)
+    }
+
+    /// Generate, and optionally run, the statements restoring a snapshot
+    pub async fn rollback(
+        &self,
+        cluster: &Cluster,
+        snapshot_id: i64,
+        execute: bool,
+        username: &str,
+    ) -> ApiResult<GlobalVariableRollback> {
+        let variables: String = sqlx::query_scalar(
+            "SELECT variables FROM global_variable_snapshots WHERE id = ? AND cluster_id = ?",
+        )
+        .bind(snapshot_id)
+        .bind(cluster.id)
+        .fetch_optional(&self.db)
+        .await?
+        .ok_or_else(|| {
+            ApiError::not_found(format!("Global variable snapshot {} not found", snapshot_id))
+        })?;
+        let snapshot: BTreeMap<String, String> =
+            serde_json::from_str(&variables).unwrap_or_default();
+
+        let lock = self.change_lock(cluster.id);
+        let _guard = lock.lock().await;
+        let client = self.client(cluster).await?;
+        let mut statements =
+            rollback_statements(&snapshot, &fetch_global_variables(&client).await?);
+        if execute && !statements.is_empty() {
+            if let Err(e) = self.snapshot(cluster, SOURCE_OUT_OF_BAND, None).await {
+                tracing::warn!("Global variable snapshot before rollback failed: {}", e);
+            }
+            for statement in statements.iter_mut().filter(|s| s.error.is_none()) {
+                if let Err(e) = client.execute(&statement.statement).await {
+                    statement.error = Some(e.to_string());
+                }
+            }
+            tracing::info!(
+                "{} rolled back global variables of cluster {} to snapshot {}",
+                username,
+                cluster.name,
+                snapshot_id
+            );
+            self.snapshot(cluster, SOURCE_ROLLBACK, Some(username))
+                .await?;
+        }
+        Ok(GlobalVariableRollback { snapshot_id, executed: execute, statements })
+    }
+
+    /// Snapshot every cluster and record what changed outside Stellar
+    pub async fn snapshot_all(&self) -> Result<(), anyhow::Error> {
+        let clusters = self.cluster_service.list_clusters().await?;
+        for cluster in clusters {
+            let lock = self.change_lock(cluster.id);
+            let _guard = lock.lock().await;
+            if let Err(e) = self.snapshot(&cluster, SOURCE_OUT_OF_BAND, None).await {
+                tracing::warn!(
+                    "Global variable snapshot failed for cluster {}: {}",
+                    cluster.name,
+                    e
+                );
+            }
+        }
+        Ok(())
+    }
+
+    async fn client(&self, cluster: &Cluster) -> ApiResult<MySQLClient> {
+        let pool = self.mysql_pool_manager.get_pool(cluster).await?;
+        Ok(MySQLClient::from_pool(pool))
+    }
+
+    /// Store a snapshot when the variables differ from the latest one; returns its ID
+    ///
+    /// The first snapshot of a cluster is the baseline and records no changes.
+    async fn snapshot(
+        &self,
+        cluster: &Cluster,
+        source: &str,
+        changed_by: Option<&str>,
+    ) -> ApiResult<Option<i64>> {
+        let current = fetch_global_variables(&self.client(cluster).await?).await?;
+        let previous: Option<String> = sqlx::query_scalar(
+            "SELECT variables FROM global_variable_snapshots WHERE cluster_id = ? \
+             ORDER BY created_at DESC, id DESC LIMIT 1",
+        )
+        .bind(cluster.id)
+        .fetch_optional(&self.db)
+        .await?;
+        let changes = match &previous {
+            Some(previous) => {
+                let previous: BTreeMap<String, String> =
+                    serde_json::from_str(previous).unwrap_or_default();
+                let changes = diff_variables(&previous, &current);
+                if changes.is_empty() {
+                    return Ok(None);
+                }
+                changes
+            },
+            None => Vec::new(),
+        };
+
+        let now = Utc::now().naive_utc();
+        let mut tx = self.db.begin().await?;
+        let snapshot_id = sqlx::query(
+            "INSERT INTO global_variable_snapshots \
+             (cluster_id, variables, changed_count, created_by, created_at) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(cluster.id)
+        .bind(serde_json::to_string(&current).unwrap_or_default())
+        .bind(changes.len() as i64)
+        .bind(changed_by)
+        .bind(now)
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+
+        for (name, old_value, new_value) in &changes {
+            sqlx::query(
+                "INSERT INTO global_variable_changes (cluster_id, snapshot_id, variable_name, \
+                 old_value, new_value, source, changed_by, changed_at) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(cluster.id)
+            .bind(snapshot_id)
+            .bind(name)
+            .bind(old_value)
+            .bind(new_value)
+            .bind(source)
+            .bind(changed_by)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        if source == SOURCE_OUT_OF_BAND && !changes.is_empty() {
+            tracing::warn!(
+                "{} global variables of cluster {} changed outside Stellar: {}",
+                changes.len(),
+                cluster.name,
+                changes
+                    .iter()
+                    .map(|c| c.0.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+        Ok(Some(snapshot_id))
+    }
+}
+
+/// `SHOW GLOBAL VARIABLES` as name -> value
+pub async fn fetch_global_variables(client: &MySQLClient) -> ApiResult<BTreeMap<String, String>> {
+    let (_, rows) = client.query_raw("SHOW GLOBAL VARIABLES").await?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|row| {
+            let mut row = row.into_iter();
+            Some((row.next()?, row.next().unwrap_or_default()))
+        })
+        .collect())
+}
+
+impl ScheduledTask for GlobalVariableHistoryService {
+    fn run(&self) -> Pin<Box<dyn Future<Output = Result<(), anyhow::Error>> + Send + '_>> {
+        Box::pin(async move { self.snapshot_all().await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff_variables() {
+        let previous = vars(&[("query_timeout", "300"), ("pipeline_dop", "0"), ("old_var", "1")]);
+        let current = vars(&[("query_timeout", "600"), ("pipeline_dop", "0"), ("new_var", "x")]);
+        assert_eq!(
+            diff_variables(&previous, &current),
+            vec![
+                ("new_var".to_string(), None, Some("x".to_string())),
+                ("old_var".to_string(), Some("1".to_string()), None),
+                ("query_timeout".to_string(), Some("300".to_string()), Some("600".to_string())),
+            ]
+        );
+        assert!(diff_variables(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn test_rollback_statements() {
+        let snapshot = vars(&[
+            ("query_timeout", "300"),
+            ("sql_mode", "ONLY_FULL_GROUP_BY"),
+            ("time_zone", "Asia/Shanghai"),
+            ("init_connect", "it's"),
+        ]);
+        let current = vars(&[
+            ("query_timeout", "600"),
+            ("sql_mode", "ONLY_FULL_GROUP_BY"),
+            ("time_zone", "UTC"),
+        ]);
+        let statements: Vec<String> = rollback_statements(&snapshot, &current)
+            .into_iter()
+            .map(|s| s.statement)
+            .collect();
+        assert_eq!(
+            statements,
+            vec![
+                "SET GLOBAL init_connect = 'it\\'s'",
+                "SET GLOBAL query_timeout = 300",
+                "SET GLOBAL time_zone = 'Asia/Shanghai'",
+            ]
+        );
+        assert!(set_global_statement("a; DROP", "1").is_err());
+    }
+}
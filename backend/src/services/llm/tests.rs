@@ -864,7 +864,8 @@ mod llm_integration_tests {
         println!("🦴 Step 1: Rule Engine Analysis (骨架)");
         println!("{}\n", sep);
 
-        let context = AnalysisContext { cluster_variables: None, cluster_id: None };
+        let context =
+            AnalysisContext { cluster_variables: None, cluster_id: None, ..Default::default() };
         let response = analyze_profile_with_context(&profile_content, &context)
             .expect("Failed to analyze profile");
 
@@ -0,0 +1,1043 @@
+// Load Job Service
+// Purpose: Monitor Stream / Routine / Broker / INSERT load jobs, control Routine Load jobs and
+// keep an hourly throughput / failure-rate history in SQLite.
+
+use crate::models::{Cluster, ClusterType};
+use crate::services::{ClusterService, MySQLClient, MySQLPoolManager, create_adapter};
+use crate::utils::system_databases::list_user_databases;
+use crate::utils::{ApiError, ApiResult, RowView, ScheduledTask};
+use chrono::{DateTime, NaiveDateTime, Timelike, Utc};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+/// Maximum load jobs fetched per listing
+const MAX_LOAD_JOBS: usize = 5000;
+
+/// Number of recent hourly buckets recomputed on every collection
+const HISTORY_BUCKETS_REFRESHED: i64 = 2;
+
+/// Bytes of a load error log read at most; BE error logs can be hundreds of MB
+const MAX_ERROR_LOG_BYTES: usize = 1024 * 1024;
+
+/// A Broker / INSERT / Stream / Spark load job
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct LoadJob {
+    pub job_id: String,
+    pub label: String,
+    pub database: String,
+    /// BROKER, INSERT, STREAM_LOAD, SPARK, ...
+    pub job_type: String,
+    pub state: String,
+    pub progress: String,
+    pub scan_rows: Option<i64>,
+    pub filtered_rows: Option<i64>,
+    pub unselected_rows: Option<i64>,
+    pub sink_rows: Option<i64>,
+    pub create_time: String,
+    pub load_start_time: String,
+    pub load_finish_time: String,
+    pub error_msg: String,
+    /// URL of the rejected-rows log on the BE, if any
+    pub error_url: Option<String>,
+    pub tracking_sql: Option<String>,
+}
+
+/// Filter for listing load jobs
+#[derive(Debug, Default, Deserialize, Clone)]
+pub struct LoadJobFilter {
+    pub state: Option<String>,
+    pub job_type: Option<String>,
+    pub database: Option<String>,
+    pub limit: Option<usize>,
+}
+
+/// Rejected-row sample fetched from a load error URL
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct LoadErrorSample {
+    pub url: String,
+    pub lines: Vec<String>,
+    pub truncated: bool,
+}
+
+/// Per Kafka partition progress of a Routine Load job
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct RoutineLoadPartition {
+    pub partition: String,
+    pub current_offset: Option<i64>,
+    pub latest_offset: Option<i64>,
+    pub lag: Option<i64>,
+    /// Messages consumed per second since the previous observation
+    pub consume_rate: Option<f64>,
+}
+
+/// Routine Load job with per-partition lag
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct RoutineLoadJob {
+    pub id: String,
+    pub name: String,
+    pub database: String,
+    pub table: String,
+    pub state: String,
+    pub data_source_type: String,
+    pub create_time: String,
+    pub pause_time: String,
+    pub current_task_num: Option<i64>,
+    pub loaded_rows: Option<i64>,
+    pub error_rows: Option<i64>,
+    pub received_bytes: Option<i64>,
+    pub load_rows_rate: Option<f64>,
+    pub total_lag: Option<i64>,
+    pub partitions: Vec<RoutineLoadPartition>,
+    pub reason_of_state_changed: String,
+    pub error_log_urls: String,
+    pub other_msg: String,
+}
+
+/// Routine Load control operations
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RoutineLoadAction {
+    Pause,
+    Resume,
+    Stop,
+}
+
+impl RoutineLoadAction {
+    fn keyword(self) -> &'static str {
+        match self {
+            RoutineLoadAction::Pause => "PAUSE",
+            RoutineLoadAction::Resume => "RESUME",
+            RoutineLoadAction::Stop => "STOP",
+        }
+    }
+}
+
+/// Hourly load throughput / failure bucket
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, sqlx::FromRow)]
+pub struct LoadHistoryPoint {
+    /// Bucket start in cluster local time (yyyy-MM-dd HH:00:00)
+    pub bucket_start: String,
+    pub job_type: String,
+    pub finished_count: i64,
+    pub failed_count: i64,
+    pub loaded_rows: i64,
+    pub filtered_rows: i64,
+    pub failure_rate: f64,
+}
+
+/// Last seen offsets of a Routine Load job, used for consumption rate
+struct OffsetSample {
+    at: DateTime<Utc>,
+    offsets: HashMap<String, i64>,
+}
+
+#[derive(Clone)]
+pub struct LoadJobService {
+    db: SqlitePool,
+    cluster_service: Arc<ClusterService>,
+    mysql_pool_manager: Arc<MySQLPoolManager>,
+    retention_days: i64,
+    http_client: reqwest::Client,
+    offset_samples: Arc<DashMap<(i64, String), OffsetSample>>,
+}
+
+impl LoadJobService {
+    pub fn new(
+        db: SqlitePool,
+        cluster_service: Arc<ClusterService>,
+        mysql_pool_manager: Arc<MySQLPoolManager>,
+        retention_days: i64,
+    ) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+        Self {
+            db,
+            cluster_service,
+            mysql_pool_manager,
+            retention_days,
+            http_client,
+            offset_samples: Arc::new(DashMap::new()),
+        }
+    }
+
+    // ========================================================================
+    // Load jobs
+    // ========================================================================
+
+    /// List load jobs (newest first)
+    pub async fn list_jobs(
+        &self,
+        cluster: &Cluster,
+        filter: &LoadJobFilter,
+    ) -> ApiResult<Vec<LoadJob>> {
+        if let Some(db) = &filter.database {
+            validate_identifier(db)?;
+        }
+        let client = self.mysql_client(cluster).await?;
+        let limit = filter.limit.unwrap_or(500).min(MAX_LOAD_JOBS);
+
+        let mut jobs = match cluster.cluster_type {
+            ClusterType::StarRocks => Self::list_starrocks_jobs(&client, filter, limit).await?,
+            ClusterType::Doris => Self::list_doris_jobs(&client, filter).await?,
+        };
+
+        jobs.retain(|job| {
+            filter
+                .state
+                .as_ref()
+                .is_none_or(|s| job.state.eq_ignore_ascii_case(s))
+                && filter
+                    .job_type
+                    .as_ref()
+                    .is_none_or(|t| job.job_type.eq_ignore_ascii_case(t))
+                && filter
+                    .database
+                    .as_ref()
+                    .is_none_or(|d| job.database.eq_ignore_ascii_case(d))
+        });
+        jobs.sort_by(|a, b| b.create_time.cmp(&a.create_time));
+        jobs.truncate(limit);
+        Ok(jobs)
+    }
+
+    /// Filters are pushed into the queries so that LIMIT applies to matching jobs only
+    async fn list_starrocks_jobs(
+        client: &MySQLClient,
+        filter: &LoadJobFilter,
+        limit: usize,
+    ) -> ApiResult<Vec<LoadJob>> {
+        let loads_sql = |db_column: &str| {
+            format!(
+                "SELECT * FROM information_schema.loads {} ORDER BY CREATE_TIME DESC LIMIT {}",
+                load_where_clause(filter, db_column, true),
+                limit
+            )
+        };
+        let (columns, rows) = match client.query_raw(&loads_sql("DATABASE_NAME")).await {
+            Ok(result) => result,
+            // Older versions name the column DB_NAME
+            Err(e) if filter.database.is_some() => {
+                tracing::debug!("Retrying information_schema.loads with DB_NAME: {}", e);
+                client.query_raw(&loads_sql("DB_NAME")).await?
+            },
+            Err(e) => return Err(e),
+        };
+        let mut jobs: Vec<LoadJob> = rows
+            .iter()
+            .map(|row| map_load_row(&columns, row, "", ""))
+            .collect();
+
+        // Transactional Stream Load records (StarRocks 3.1+)
+        if filter
+            .job_type
+            .as_ref()
+            .is_none_or(|t| t.eq_ignore_ascii_case("STREAM_LOAD"))
+        {
+            let sql = format!(
+                "SELECT * FROM information_schema.stream_loads {} \
+                 ORDER BY CREATE_TIME_MS DESC LIMIT {}",
+                load_where_clause(filter, "DB_NAME", false),
+                limit
+            );
+            match client.query_raw(&sql).await {
+                Ok((columns, rows)) => jobs.extend(
+                    rows.iter()
+                        .map(|row| map_load_row(&columns, row, "STREAM_LOAD", "")),
+                ),
+                Err(e) => tracing::debug!("information_schema.stream_loads unavailable: {}", e),
+            }
+        }
+
+        Ok(jobs)
+    }
+
+    async fn list_doris_jobs(
+        client: &MySQLClient,
+        filter: &LoadJobFilter,
+    ) -> ApiResult<Vec<LoadJob>> {
+        let databases = match &filter.database {
+            Some(db) => vec![db.clone()],
+            None => list_user_databases(client).await?,
+        };
+
+        let mut jobs = Vec::new();
+        for db in databases {
+            let sql = format!("SHOW LOAD FROM `{}`", db);
+            match client.query_raw(&sql).await {
+                Ok((columns, rows)) => {
+                    jobs.extend(rows.iter().map(|row| map_load_row(&columns, row, "", &db)))
+                },
+                Err(e) => tracing::debug!("SHOW LOAD failed for {}: {}", db, e),
+            }
+
+            // Requires enable_stream_load_record on the BE
+            let sql = format!("SHOW STREAM LOAD FROM `{}`", db);
+            if let Ok((columns, rows)) = client.query_raw(&sql).await {
+                jobs.extend(
+                    rows.iter()
+                        .map(|row| map_load_row(&columns, row, "STREAM_LOAD", &db)),
+                );
+            }
+        }
+        Ok(jobs)
+    }
+
+    /// Fetch the first rejected rows from a load error URL.
+    /// Only URLs pointing at nodes of the cluster are allowed.
+    pub async fn fetch_error_sample(
+        &self,
+        cluster: &Cluster,
+        url: &str,
+        max_lines: usize,
+    ) -> ApiResult<LoadErrorSample> {
+        let parsed = reqwest::Url::parse(url)
+            .map_err(|e| ApiError::validation_error(format!("Invalid error URL: {}", e)))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(ApiError::validation_error("Only http(s) error URLs are supported"));
+        }
+        let host = parsed
+            .host_str()
+            .ok_or_else(|| ApiError::validation_error("Error URL has no host"))?;
+
+        let adapter = create_adapter(cluster.clone(), self.mysql_pool_manager.clone());
+        let mut hosts: Vec<String> = adapter
+            .get_backends()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|b| b.host)
+            .collect();
+        hosts.extend(
+            adapter
+                .get_frontends()
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .map(|f| f.host),
+        );
+        hosts.push(cluster.fe_host.clone());
+        if !hosts.iter().any(|h| h.eq_ignore_ascii_case(host)) {
+            return Err(ApiError::forbidden(format!(
+                "Host {} does not belong to cluster {}",
+                host, cluster.name
+            )));
+        }
+
+        let mut response =
+            self.http_client.get(parsed).send().await.map_err(|e| {
+                ApiError::cluster_connection_failed(format!("Request failed: {}", e))
+            })?;
+        if !response.status().is_success() {
+            return Err(ApiError::cluster_connection_failed(format!(
+                "Error log request returned {}",
+                response.status()
+            )));
+        }
+
+        // Read incrementally and stop once enough lines (or bytes) have been seen
+        let mut sample = ErrorLineSample::new(max_lines);
+        let mut read_bytes = 0;
+        let mut truncated = false;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| ApiError::cluster_connection_failed(format!("Read failed: {}", e)))?
+        {
+            read_bytes += chunk.len();
+            if sample.push(&chunk) {
+                truncated = true;
+                break;
+            }
+            if read_bytes >= MAX_ERROR_LOG_BYTES {
+                truncated = true;
+                break;
+            }
+        }
+        let mut lines = sample.finish(truncated);
+        let truncated = truncated || lines.len() > max_lines;
+        lines.truncate(max_lines);
+
+        Ok(LoadErrorSample { url: url.to_string(), lines, truncated })
+    }
+
+    // ========================================================================
+    // Routine Load
+    // ========================================================================
+
+    /// List Routine Load jobs with per-partition lag and consumption rate
+    ///
+    /// The consumption rate is derived from the previous call for the same job,
+    /// so every call also records the current offsets.
+    pub async fn list_routine_loads(
+        &self,
+        cluster: &Cluster,
+        database: Option<&str>,
+        include_stopped: bool,
+    ) -> ApiResult<Vec<RoutineLoadJob>> {
+        let mut jobs = self
+            .fetch_routine_loads(cluster, database, include_stopped)
+            .await?;
+
+        let now = Utc::now();
+        for job in &mut jobs {
+            self.apply_consume_rate(cluster.id, job, now);
+        }
+
+        Ok(jobs)
+    }
+
+    /// Fetch Routine Load jobs with per-partition lag, without consumption rate
+    ///
+    /// Unlike `list_routine_loads` this leaves the offset samples untouched, so
+    /// background collectors do not skew the rates seen by API callers.
+    pub async fn fetch_routine_loads(
+        &self,
+        cluster: &Cluster,
+        database: Option<&str>,
+        include_stopped: bool,
+    ) -> ApiResult<Vec<RoutineLoadJob>> {
+        if let Some(db) = database {
+            validate_identifier(db)?;
+        }
+        let client = self.mysql_client(cluster).await?;
+        let databases = match database {
+            Some(db) => vec![db.to_string()],
+            None => list_user_databases(&client).await?,
+        };
+
+        let mut jobs = Vec::new();
+        for db in databases {
+            let mut session = client.create_session().await?;
+            if let Err(e) = session.use_database(&db).await {
+                tracing::debug!("Skip database {}: {}", db, e);
+                continue;
+            }
+            let (columns, rows, _) = match session.execute("SHOW ALL ROUTINE LOAD").await {
+                Ok(result) => result,
+                Err(e) => {
+                    tracing::debug!("SHOW ALL ROUTINE LOAD failed for {}: {}", db, e);
+                    continue;
+                },
+            };
+            for row in &rows {
+                let job = parse_routine_load_row(&columns, row, &db);
+                if include_stopped || !matches!(job.state.as_str(), "STOPPED" | "CANCELLED") {
+                    jobs.push(job);
+                }
+            }
+        }
+
+        Ok(jobs)
+    }
+
+    /// Fill consume_rate from the previous offset observation of the same job
+    fn apply_consume_rate(&self, cluster_id: i64, job: &mut RoutineLoadJob, now: DateTime<Utc>) {
+        let current: HashMap<String, i64> = job
+            .partitions
+            .iter()
+            .filter_map(|p| p.current_offset.map(|o| (p.partition.clone(), o)))
+            .collect();
+
+        let key = (cluster_id, job.id.clone());
+        if let Some(prev) = self.offset_samples.get(&key) {
+            let elapsed = (now - prev.at).num_milliseconds() as f64 / 1000.0;
+            if elapsed > 0.0 {
+                for partition in &mut job.partitions {
+                    if let (Some(cur), Some(old)) =
+                        (partition.current_offset, prev.offsets.get(&partition.partition))
+                        && cur >= *old
+                    {
+                        partition.consume_rate = Some((cur - old) as f64 / elapsed);
+                    }
+                }
+            }
+        }
+        self.offset_samples
+            .insert(key, OffsetSample { at: now, offsets: current });
+    }
+
+    /// Pause, resume or stop a Routine Load job
+    pub async fn control_routine_load(
+        &self,
+        cluster: &Cluster,
+        database: &str,
+        name: &str,
+        action: RoutineLoadAction,
+    ) -> ApiResult<()> {
+        validate_identifier(database)?;
+        validate_identifier(name)?;
+        let sql = format!("{} ROUTINE LOAD FOR `{}`.`{}`", action.keyword(), database, name);
+        let client = self.mysql_client(cluster).await?;
+        client.execute(&sql).await?;
+        tracing::info!("Executed on cluster {}: {}", cluster.name, sql);
+        Ok(())
+    }
+
+    // ========================================================================
+    // History
+    // ========================================================================
+
+    /// Hourly load history of a cluster.
+    /// Buckets are keyed by cluster local time, so the window is anchored at the newest bucket.
+    pub async fn get_history(
+        &self,
+        cluster_id: i64,
+        hours: i64,
+        job_type: Option<&str>,
+    ) -> ApiResult<Vec<LoadHistoryPoint>> {
+        let offset = format!("-{} hours", hours.max(0));
+        let points = sqlx::query_as::<_, LoadHistoryPoint>(
+            r#"
+            SELECT bucket_start, job_type, finished_count, failed_count, loaded_rows, filtered_rows,
+                   CASE WHEN finished_count + failed_count = 0 THEN 0.0
+                        ELSE CAST(failed_count AS REAL) / (finished_count + failed_count) END AS failure_rate
+            FROM load_job_history
+            WHERE cluster_id = ?
+              AND bucket_start >= datetime(
+                  (SELECT MAX(bucket_start) FROM load_job_history WHERE cluster_id = ?), ?)
+              AND (? IS NULL OR job_type = ?)
+            ORDER BY bucket_start ASC, job_type ASC
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(cluster_id)
+        .bind(offset)
+        .bind(job_type)
+        .bind(job_type)
+        .fetch_all(&self.db)
+        .await?;
+        Ok(points)
+    }
+
+    /// Execute one collection cycle (called by the ScheduledExecutor)
+    pub async fn collect_once(&self) -> Result<(), anyhow::Error> {
+        let clusters = self.cluster_service.list_clusters().await?;
+        for cluster in clusters {
+            if let Err(e) = self.collect_cluster_history(&cluster).await {
+                tracing::warn!(
+                    "Failed to collect load history for cluster {} ({}): {}",
+                    cluster.id,
+                    cluster.name,
+                    e
+                );
+            }
+        }
+
+        let cutoff = (Utc::now() - chrono::Duration::days(self.retention_days)).naive_utc();
+        sqlx::query("DELETE FROM load_job_history WHERE updated_at < ?")
+            .bind(cutoff)
+            .execute(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    /// Recompute the most recent hourly buckets from the cluster's load jobs.
+    /// Buckets use the cluster clock so results are stable regardless of timezone.
+    async fn collect_cluster_history(&self, cluster: &Cluster) -> ApiResult<()> {
+        let client = self.mysql_client(cluster).await?;
+        let (_, rows) = client.query_raw("SELECT NOW()").await?;
+        let cluster_now = rows
+            .first()
+            .and_then(|r| r.first())
+            .and_then(|s| parse_load_time(s))
+            .ok_or_else(|| ApiError::internal_error("Failed to read cluster time"))?;
+        let current_bucket = truncate_to_hour(cluster_now);
+        let since = current_bucket - chrono::Duration::hours(HISTORY_BUCKETS_REFRESHED - 1);
+
+        let filter = LoadJobFilter { limit: Some(MAX_LOAD_JOBS), ..Default::default() };
+        let jobs = self.list_jobs(cluster, &filter).await?;
+        let buckets = aggregate_history(&jobs, since);
+
+        for ((bucket, job_type), agg) in buckets {
+            sqlx::query(
+                r#"
+                INSERT INTO load_job_history (
+                    cluster_id, bucket_start, job_type, finished_count, failed_count,
+                    loaded_rows, filtered_rows, updated_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
+                ON CONFLICT(cluster_id, bucket_start, job_type) DO UPDATE SET
+                    finished_count = excluded.finished_count,
+                    failed_count = excluded.failed_count,
+                    loaded_rows = excluded.loaded_rows,
+                    filtered_rows = excluded.filtered_rows,
+                    updated_at = excluded.updated_at
+                "#,
+            )
+            .bind(cluster.id)
+            .bind(bucket.format("%Y-%m-%d %H:00:00").to_string())
+            .bind(job_type)
+            .bind(agg.finished)
+            .bind(agg.failed)
+            .bind(agg.loaded_rows)
+            .bind(agg.filtered_rows)
+            .execute(&self.db)
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn mysql_client(&self, cluster: &Cluster) -> ApiResult<MySQLClient> {
+        let pool = self.mysql_pool_manager.get_pool(cluster).await?;
+        Ok(MySQLClient::from_pool(pool))
+    }
+}
+
+impl ScheduledTask for LoadJobService {
+    fn run(&self) -> Pin<Box<dyn Future<Output = Result<(), anyhow::Error>> + Send + '_>> {
+        Box::pin(async move { self.collect_once().await })
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct HistoryAggregate {
+    finished: i64,
+    failed: i64,
+    loaded_rows: i64,
+    filtered_rows: i64,
+}
+
+/// Group finished / cancelled jobs into hourly buckets by finish time
+fn aggregate_history(
+    jobs: &[LoadJob],
+    since: NaiveDateTime,
+) -> BTreeMap<(NaiveDateTime, String), HistoryAggregate> {
+    let mut buckets: BTreeMap<(NaiveDateTime, String), HistoryAggregate> = BTreeMap::new();
+    for job in jobs {
+        let Some(finish) = parse_load_time(&job.load_finish_time) else {
+            continue;
+        };
+        if finish < since {
+            continue;
+        }
+        let agg = buckets
+            .entry((truncate_to_hour(finish), job.job_type.to_uppercase()))
+            .or_default();
+        match job.state.to_uppercase().as_str() {
+            "FINISHED" | "SUCCESS" | "VISIBLE" | "COMMITTED" => {
+                agg.finished += 1;
+                agg.loaded_rows += job.sink_rows.unwrap_or(0);
+            },
+            "CANCELLED" | "FAILED" | "ABORTED" | "FAIL" => agg.failed += 1,
+            _ => continue,
+        }
+        agg.filtered_rows += job.filtered_rows.unwrap_or(0);
+    }
+    buckets
+}
+
+fn truncate_to_hour(t: NaiveDateTime) -> NaiveDateTime {
+    t.with_minute(0)
+        .and_then(|t| t.with_second(0))
+        .and_then(|t| t.with_nanosecond(0))
+        .unwrap_or(t)
+}
+
+fn parse_load_time(s: &str) -> Option<NaiveDateTime> {
+    let s = s.trim();
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f"))
+        .ok()
+        .or_else(|| {
+            // Epoch milliseconds (information_schema.stream_loads)
+            s.parse::<i64>()
+                .ok()
+                .and_then(DateTime::from_timestamp_millis)
+                .map(|t| t.naive_utc())
+        })
+}
+
+/// Reject identifiers that could break out of backtick quoting
+fn validate_identifier(ident: &str) -> ApiResult<()> {
+    if ident.is_empty() || ident.contains('`') {
+        return Err(ApiError::validation_error(format!("Invalid identifier: {}", ident)));
+    }
+    Ok(())
+}
+
+fn escape_literal(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\'', "''")
+}
+
+/// Parse `key=value; key2=value2` style EtlInfo from Doris SHOW LOAD
+fn parse_etl_info(etl_info: &str) -> HashMap<String, i64> {
+    etl_info
+        .split(';')
+        .filter_map(|kv| {
+            let (k, v) = kv.split_once('=')?;
+            Some((k.trim().to_string(), v.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+/// First non-empty lines of a load error log, fed chunk by chunk
+struct ErrorLineSample {
+    max_lines: usize,
+    lines: Vec<String>,
+    pending: Vec<u8>,
+}
+
+impl ErrorLineSample {
+    fn new(max_lines: usize) -> Self {
+        Self { max_lines, lines: Vec::new(), pending: Vec::new() }
+    }
+
+    /// Add a chunk; returns true once more than `max_lines` lines were collected
+    fn push(&mut self, chunk: &[u8]) -> bool {
+        self.pending.extend_from_slice(chunk);
+        while let Some(pos) = self.pending.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=pos).collect();
+            self.push_line(&line);
+            if self.lines.len() > self.max_lines {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Collected lines; an unterminated last line only counts when the log was read to the end
+    fn finish(mut self, truncated: bool) -> Vec<String> {
+        if !truncated {
+            let rest = std::mem::take(&mut self.pending);
+            self.push_line(&rest);
+        }
+        self.lines
+    }
+
+    fn push_line(&mut self, line: &[u8]) {
+        let line = String::from_utf8_lossy(line);
+        let line = line.trim_end_matches(['\r', '\n']);
+        if !line.trim().is_empty() {
+            self.lines.push(line.to_string());
+        }
+    }
+}
+
+/// WHERE clause of the StarRocks load queries; `db_column` names the database column
+/// and `with_type` adds the job type, which stream_loads does not have
+fn load_where_clause(filter: &LoadJobFilter, db_column: &str, with_type: bool) -> String {
+    let mut conditions = Vec::new();
+    if let Some(state) = &filter.state {
+        conditions.push(format!("STATE = '{}'", escape_literal(state)));
+    }
+    if with_type && let Some(job_type) = &filter.job_type {
+        conditions.push(format!("TYPE = '{}'", escape_literal(job_type)));
+    }
+    if let Some(db) = &filter.database {
+        conditions.push(format!("{} = '{}'", db_column, escape_literal(db)));
+    }
+    if conditions.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", conditions.join(" AND "))
+    }
+}
+
+/// Map a load row from information_schema.loads / stream_loads or SHOW [STREAM] LOAD
+fn map_load_row(
+    columns: &[String],
+    row: &[String],
+    default_type: &str,
+    default_db: &str,
+) -> LoadJob {
+    let v = RowView::new(columns, row);
+    let etl = v.get(&["EtlInfo"]).map(parse_etl_info).unwrap_or_default();
+    let details: Option<serde_json::Value> = v
+        .get(&["JOB_DETAILS", "JobDetails"])
+        .and_then(|s| serde_json::from_str(s).ok());
+    let detail_int = |key: &str| {
+        details
+            .as_ref()
+            .and_then(|d| d.get(key))
+            .and_then(|n| n.as_i64())
+    };
+
+    // SHOW STREAM LOAD / stream_loads rows carry no (or a transport-level) TYPE column
+    let job_type = if default_type.is_empty() {
+        v.get(&["TYPE", "Type"])
+            .map(|t| t.to_uppercase())
+            .unwrap_or_default()
+    } else {
+        default_type.to_string()
+    };
+
+    LoadJob {
+        job_id: v.string(&["JOB_ID", "JobId", "ID", "TXN_ID", "Label"]),
+        label: v.string(&["LABEL", "Label"]),
+        database: v
+            .get(&["DATABASE_NAME", "DB_NAME", "Db"])
+            .unwrap_or(default_db)
+            .to_string(),
+        job_type,
+        state: v.string(&["STATE", "State", "Status"]).to_uppercase(),
+        progress: v.string(&["PROGRESS", "Progress"]),
+        scan_rows: v
+            .int(&["SCAN_ROWS", "TotalRows"])
+            .or_else(|| detail_int("ScannedRows")),
+        filtered_rows: v
+            .int(&["FILTERED_ROWS", "FilteredRows", "NUM_ROWS_AB_NORMAL"])
+            .or_else(|| etl.get("dpp.abnorm.ALL").copied()),
+        unselected_rows: v
+            .int(&["UNSELECTED_ROWS", "UnselectedRows", "NUM_ROWS_UNSELECTED"])
+            .or_else(|| etl.get("unselected.rows").copied()),
+        sink_rows: v
+            .int(&["SINK_ROWS", "LoadedRows", "NUM_ROWS_NORMAL"])
+            .or_else(|| etl.get("dpp.norm.ALL").copied())
+            .or_else(|| detail_int("LoadRows")),
+        create_time: v.string(&["CREATE_TIME", "CreateTime", "StartTime", "CREATE_TIME_MS"]),
+        load_start_time: v.string(&[
+            "LOAD_START_TIME",
+            "LoadStartTime",
+            "StartTime",
+            "START_LOADING_TIME_MS",
+        ]),
+        load_finish_time: v.string(&[
+            "LOAD_FINISH_TIME",
+            "LoadFinishTime",
+            "FinishTime",
+            "END_TIME_MS",
+        ]),
+        error_msg: v.string(&["ERROR_MSG", "ErrorMsg", "Message"]),
+        error_url: v
+            .get(&["TRACKING_URL", "URL", "Url", "ErrorURL"])
+            .map(String::from),
+        tracking_sql: v.get(&["TRACKING_SQL"]).map(String::from),
+    }
+}
+
+/// Parse an offset map such as `{"0":"1234","1":"OFFSET_END"}`
+fn parse_offset_map(json: &str) -> BTreeMap<String, Option<i64>> {
+    serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(json)
+        .map(|m| {
+            m.into_iter()
+                .map(|(k, v)| {
+                    let offset = v
+                        .as_i64()
+                        .or_else(|| v.as_str().and_then(|s| s.parse::<i64>().ok()));
+                    (k, offset)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Map a SHOW ROUTINE LOAD row, computing per-partition lag
+fn parse_routine_load_row(columns: &[String], row: &[String], database: &str) -> RoutineLoadJob {
+    let v = RowView::new(columns, row);
+    let statistic: serde_json::Value = v
+        .get(&["Statistic"])
+        .and_then(|s| serde_json::from_str(s).ok())
+        .unwrap_or_default();
+    let stat_int = |key: &str| statistic.get(key).and_then(|n| n.as_i64());
+
+    let progress = v
+        .get(&["Progress"])
+        .map(parse_offset_map)
+        .unwrap_or_default();
+    // StarRocks exposes latest offsets, Doris exposes the lag directly
+    let latest = v
+        .get(&["LatestSourcePosition"])
+        .map(parse_offset_map)
+        .unwrap_or_default();
+    let reported_lag = v.get(&["Lag"]).map(parse_offset_map).unwrap_or_default();
+
+    let partitions: Vec<RoutineLoadPartition> = progress
+        .iter()
+        .map(|(partition, current)| {
+            let latest_offset = latest.get(partition).copied().flatten();
+            // Progress records the last consumed offset, so the next one is current + 1
+            let lag = match (current, latest_offset) {
+                (Some(cur), Some(end)) => Some((end - cur - 1).max(0)),
+                _ => reported_lag.get(partition).copied().flatten(),
+            };
+            RoutineLoadPartition {
+                partition: partition.clone(),
+                current_offset: *current,
+                latest_offset,
+                lag,
+                consume_rate: None,
+            }
+        })
+        .collect();
+    let total_lag = partitions.iter().filter_map(|p| p.lag).reduce(|a, b| a + b);
+
+    RoutineLoadJob {
+        id: v.string(&["Id"]),
+        name: v.string(&["Name"]),
+        database: v.get(&["DbName"]).unwrap_or(database).to_string(),
+        table: v.string(&["TableName"]),
+        state: v.string(&["State"]).to_uppercase(),
+        data_source_type: v.string(&["DataSourceType"]),
+        create_time: v.string(&["CreateTime"]),
+        pause_time: v.string(&["PauseTime"]),
+        current_task_num: v.int(&["CurrentTaskNum"]),
+        loaded_rows: stat_int("loadedRows"),
+        error_rows: stat_int("errorRows"),
+        received_bytes: stat_int("receivedBytes"),
+        load_rows_rate: statistic.get("loadRowsRate").and_then(|n| n.as_f64()),
+        total_lag,
+        partitions,
+        reason_of_state_changed: v.string(&["ReasonOfStateChanged"]),
+        error_log_urls: v.string(&["ErrorLogUrls"]),
+        other_msg: v.string(&["OtherMsg"]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_map_doris_show_load_row() {
+        let columns = strings(&[
+            "JobId",
+            "Label",
+            "State",
+            "Progress",
+            "Type",
+            "EtlInfo",
+            "ErrorMsg",
+            "CreateTime",
+            "LoadFinishTime",
+            "URL",
+        ]);
+        let row = strings(&[
+            "10086",
+            "label_1",
+            "CANCELLED",
+            "ETL:100%; LOAD:0%",
+            "BROKER",
+            "unselected.rows=3; dpp.abnorm.ALL=12; dpp.norm.ALL=1000",
+            "quality not good enough",
+            "2024-05-01 10:00:00",
+            "2024-05-01 10:05:00",
+            "http://be1:8040/api/_load_error_log?file=abc",
+        ]);
+        let job = map_load_row(&columns, &row, "", "db1");
+        assert_eq!(job.database, "db1");
+        assert_eq!(job.job_type, "BROKER");
+        assert_eq!(job.filtered_rows, Some(12));
+        assert_eq!(job.sink_rows, Some(1000));
+        assert_eq!(job.unselected_rows, Some(3));
+        assert_eq!(job.error_url.as_deref(), Some("http://be1:8040/api/_load_error_log?file=abc"));
+    }
+
+    #[test]
+    fn test_parse_routine_load_lag_from_latest_position() {
+        let columns =
+            strings(&["Id", "Name", "State", "Progress", "LatestSourcePosition", "Statistic"]);
+        let row = strings(&[
+            "1",
+            "job1",
+            "RUNNING",
+            r#"{"0":"99","1":"OFFSET_BEGINNING"}"#,
+            r#"{"0":"150","1":"20"}"#,
+            r#"{"loadedRows":100,"errorRows":2,"loadRowsRate":10}"#,
+        ]);
+        let job = parse_routine_load_row(&columns, &row, "db");
+        assert_eq!(job.partitions.len(), 2);
+        assert_eq!(job.partitions[0].lag, Some(50));
+        assert_eq!(job.partitions[1].current_offset, None);
+        assert_eq!(job.total_lag, Some(50));
+        assert_eq!(job.error_rows, Some(2));
+    }
+
+    #[test]
+    fn test_parse_routine_load_lag_doris() {
+        let columns = strings(&["Id", "Name", "State", "Progress", "Lag"]);
+        let row = strings(&["1", "job1", "PAUSED", r#"{"0":"10"}"#, r#"{"0":7}"#]);
+        let job = parse_routine_load_row(&columns, &row, "db");
+        assert_eq!(job.partitions[0].lag, Some(7));
+        assert_eq!(job.state, "PAUSED");
+    }
+
+    #[test]
+    fn test_validate_identifier_rejects_backticks() {
+        assert!(validate_identifier("sales_db").is_ok());
+        assert!(validate_identifier("").is_err());
+        assert!(validate_identifier("db`; DROP DATABASE x; --").is_err());
+    }
+
+    #[test]
+    fn test_aggregate_history_buckets() {
+        let job = |state: &str, finish: &str, rows: i64| LoadJob {
+            job_id: String::new(),
+            label: String::new(),
+            database: String::new(),
+            job_type: "BROKER".to_string(),
+            state: state.to_string(),
+            progress: String::new(),
+            scan_rows: None,
+            filtered_rows: Some(1),
+            unselected_rows: None,
+            sink_rows: Some(rows),
+            create_time: String::new(),
+            load_start_time: String::new(),
+            load_finish_time: finish.to_string(),
+            error_msg: String::new(),
+            error_url: None,
+            tracking_sql: None,
+        };
+        let jobs = vec![
+            job("FINISHED", "2024-05-01 10:05:00", 100),
+            job("FINISHED", "2024-05-01 10:55:00", 50),
+            job("CANCELLED", "2024-05-01 11:01:00", 0),
+            job("FINISHED", "2024-05-01 08:00:00", 999),
+            job("LOADING", "", 0),
+        ];
+        let since = parse_load_time("2024-05-01 10:00:00").unwrap();
+        let buckets = aggregate_history(&jobs, since);
+        assert_eq!(buckets.len(), 2);
+        let first = &buckets[&(since, "BROKER".to_string())];
+        assert_eq!(
+            first,
+            &HistoryAggregate { finished: 2, failed: 0, loaded_rows: 150, filtered_rows: 2 }
+        );
+    }
+
+    #[test]
+    fn test_error_line_sample_stops_at_max_lines() {
+        let mut sample = ErrorLineSample::new(2);
+        assert!(!sample.push(b"Reason: bad int\r\n\nReason: "));
+        assert!(!sample.push(b"too long\n"));
+        assert!(sample.push(b"Reason: null\nReason: more\n"));
+        assert_eq!(
+            sample.finish(true),
+            vec!["Reason: bad int", "Reason: too long", "Reason: null"]
+        );
+
+        let mut sample = ErrorLineSample::new(5);
+        assert!(!sample.push(b"first\nlast without newline"));
+        assert_eq!(sample.finish(false), vec!["first", "last without newline"]);
+    }
+
+    #[test]
+    fn test_load_where_clause_pushes_filters() {
+        let filter = LoadJobFilter {
+            database: Some("sales".to_string()),
+            state: Some("FINISHED".to_string()),
+            job_type: Some("BROKER".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            load_where_clause(&filter, "DATABASE_NAME", true),
+            "WHERE STATE = 'FINISHED' AND TYPE = 'BROKER' AND DATABASE_NAME = 'sales'"
+        );
+        assert_eq!(
+            load_where_clause(&filter, "DB_NAME", false),
+            "WHERE STATE = 'FINISHED' AND DB_NAME = 'sales'"
+        );
+        assert_eq!(load_where_clause(&LoadJobFilter::default(), "DB_NAME", false), "");
+    }
+}
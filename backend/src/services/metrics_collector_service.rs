@@ -2,9 +2,9 @@
 // Purpose: Periodically collect metrics from StarRocks clusters and store them in SQLite
 // Design Ref: ARCHITECTURE_ANALYSIS_AND_INTEGRATION.md
 
-use crate::models::Cluster;
+use crate::models::{Cluster, ResourceGroupUsageRecord};
 use crate::services::mysql_pool_manager::MySQLPoolManager;
-use crate::services::{ClusterService, StarRocksClient};
+use crate::services::{ClusterService, StarRocksClient, create_adapter};
 use crate::utils::{ApiResult, ScheduledTask};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
@@ -409,6 +409,15 @@ impl MetricsCollectorService {
 
         self.save_snapshot(&snapshot).await?;
 
+        if let Err(e) = self.collect_resource_group_usage(cluster).await {
+            tracing::warn!(
+                "Failed to collect resource group usage for cluster {} ({}): {}",
+                cluster.id,
+                cluster.name,
+                e
+            );
+        }
+
         tracing::debug!(
             "Metrics collected for cluster {} ({}): QPS={:.2}, CPU={:.1}%, Disk={:.1}%",
             cluster.id,
@@ -504,6 +513,63 @@ impl MetricsCollectorService {
         Ok(())
     }
 
+    /// Record the current usage of every resource group / workload group
+    async fn collect_resource_group_usage(&self, cluster: &Cluster) -> ApiResult<()> {
+        let adapter = create_adapter(cluster.clone(), self.mysql_pool_manager.clone());
+        let usage = adapter.get_resource_group_usage().await?;
+        let collected_at = Utc::now();
+
+        for group in &usage {
+            sqlx::query(
+                r#"
+                INSERT INTO resource_group_usage_history (
+                    cluster_id, group_name, running_queries, queued_queries,
+                    cpu_cores_used, cpu_usage_pct, mem_used_bytes, collected_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(cluster.id)
+            .bind(&group.name)
+            .bind(group.running_queries)
+            .bind(group.queued_queries)
+            .bind(group.cpu_cores_used)
+            .bind(group.cpu_usage_pct)
+            .bind(group.mem_used_bytes)
+            .bind(collected_at)
+            .execute(&self.db)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Recorded resource group usage of a cluster, oldest first
+    pub async fn get_resource_group_history(
+        &self,
+        cluster_id: i64,
+        group_name: Option<&str>,
+        hours: i64,
+    ) -> ApiResult<Vec<ResourceGroupUsageRecord>> {
+        let since = Utc::now() - chrono::Duration::hours(hours);
+        let records = sqlx::query_as::<_, ResourceGroupUsageRecord>(
+            r#"
+            SELECT group_name, running_queries, queued_queries,
+                   cpu_cores_used, cpu_usage_pct, mem_used_bytes, collected_at
+            FROM resource_group_usage_history
+            WHERE cluster_id = ? AND collected_at >= ? AND (? IS NULL OR group_name = ?)
+            ORDER BY collected_at ASC, group_name ASC
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(since)
+        .bind(group_name)
+        .bind(group_name)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(records)
+    }
+
     /// Cleanup old metrics data based on retention policy
     async fn cleanup_old_metrics(&self) -> Result<(), sqlx::Error> {
         let cutoff_date = Utc::now() - chrono::Duration::days(self.retention_days);
@@ -521,6 +587,11 @@ impl MetricsCollectorService {
             );
         }
 
+        sqlx::query("DELETE FROM resource_group_usage_history WHERE collected_at < ?")
+            .bind(cutoff_date)
+            .execute(&self.db)
+            .await?;
+
         Ok(())
     }
 
@@ -5,40 +5,70 @@ pub mod baseline_service;
 pub mod casbin_service;
 pub mod cluster_adapter;
 pub mod cluster_service;
+pub mod compaction_service;
+pub mod config_drift_service;
 pub mod data_statistics_service;
 pub mod db_auth_query_service;
+pub mod global_variable_history_service;
 pub mod llm;
+pub mod load_job_service;
 pub mod materialized_view_service;
 pub mod metrics_collector_service;
+pub mod mv_dependency_service;
+pub mod mv_recommendation_service;
+pub mod mv_refresh_history_service;
+pub mod mv_usage_service;
 pub mod mysql_client;
 pub mod mysql_pool_manager;
+pub mod node_config_service;
 pub mod organization_service;
 pub mod overview_service;
+pub mod partition_service;
 pub mod permission_service;
 pub mod permission_request_service;
 pub mod profile_analyzer;
+pub mod query_guard_service;
+pub mod resource_group_service;
 pub mod role_service;
+pub mod routine_load_history_service;
+pub mod running_query_service;
+pub mod session_template_service;
+pub mod sql_blacklist_service;
 pub mod starrocks_client;
 pub mod system_function_service;
 pub mod user_role_service;
 pub mod user_service;
 
-pub use audit_log_service::{AuditLogService, SlowQuery, TopTableByAccess};
+pub use audit_log_service::{
+    AggregationQuery, AuditLogService, AuditStatement, SlowQuery, TopTableByAccess,
+};
 pub use auth_service::AuthService;
 pub use baseline_refresh_task::start_baseline_refresh_task;
 pub use casbin_service::CasbinService;
 pub use cluster_service::ClusterService;
+pub use compaction_service::CompactionService;
 pub use data_statistics_service::{DataStatistics, DataStatisticsService, TopTableBySize};
 pub use db_auth_query_service::DbAuthQueryService;
+pub use global_variable_history_service::GlobalVariableHistoryService;
 pub use llm::{
     LLMAnalysisResult, LLMError, LLMProvider, LLMProviderInfo, LLMServiceImpl, LLMUsageStats,
     RootCauseAnalysisRequest as LLMAnalysisRequest,
     RootCauseAnalysisResponse as LLMAnalysisResponse,
 };
+pub use load_job_service::LoadJobService;
 pub use materialized_view_service::MaterializedViewService;
 pub use metrics_collector_service::{MetricsCollectorService, MetricsSnapshot};
+pub use mv_dependency_service::{
+    MvDependencyGraph, build_mv_dependency_graph, parse_mv_base_tables,
+};
+pub use mv_recommendation_service::{
+    MvCandidate, MvRecommendationOptions, MvRecommender, WorkloadQuery,
+};
+pub use mv_refresh_history_service::MvRefreshHistoryService;
+pub use mv_usage_service::{MvUsageObservation, MvUsageStats, MvUsageVerdict, analyze_mv_usage};
 pub use mysql_client::MySQLClient;
 pub use mysql_pool_manager::MySQLPoolManager;
+pub use node_config_service::NodeConfigService;
 pub use organization_service::OrganizationService;
 pub use overview_service::{
     Alert, AlertLevel, BECompactionScore, CapacityPrediction, ClusterHealth, ClusterOverview,
@@ -48,9 +78,14 @@ pub use overview_service::{
     ResourceTrends, RunningQuery, SchemaChangeStats, SessionStats, TimeRange, TopPartitionByScore,
     TransactionStats,
 };
+pub use partition_service::PartitionService;
 pub use permission_service::PermissionService;
 pub use permission_request_service::PermissionRequestService;
+pub use query_guard_service::QueryGuardService;
 pub use role_service::RoleService;
+pub use routine_load_history_service::RoutineLoadHistoryService;
+pub use session_template_service::SessionTemplateService;
+pub use sql_blacklist_service::SqlBlacklistService;
 pub use starrocks_client::StarRocksClient;
 pub use system_function_service::SystemFunctionService;
 pub use user_role_service::UserRoleService;
@@ -0,0 +1,423 @@
+// Materialized View Dependency Service
+// Purpose: Build a cross-database DAG of async MVs and their base tables (internal and external
+// catalogs), and flag MVs that are inactive, failing, stale against their bases, or fed by such MVs.
+
+use chrono::NaiveDateTime;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use utoipa::ToSchema;
+
+use crate::models::{MaterializedView, MaterializedViewDependency};
+
+/// `FROM` / `JOIN` followed by a 1-3 part object name
+static TABLE_REF_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(?:FROM|JOIN)\s+((?:`[^`]+`|[A-Za-z_][\w$]*)(?:\s*\.\s*(?:`[^`]+`|[A-Za-z_][\w$]*)){0,2})")
+        .unwrap()
+});
+
+/// Further comma-separated object names after a `FROM` item (`FROM a x, b y`)
+static COMMA_REF_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*(?:(?i:AS)\s+)?(?:`[^`]+`|[A-Za-z_]\w*)?\s*,\s*((?:`[^`]+`|[A-Za-z_][\w$]*)(?:\s*\.\s*(?:`[^`]+`|[A-Za-z_][\w$]*)){0,2})")
+        .unwrap()
+});
+
+/// CTE names, which are not base tables
+static CTE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(?:\bWITH(?:\s+RECURSIVE)?|,)\s*(`[^`]+`|[A-Za-z_]\w*)\s+AS\s*\(").unwrap()
+});
+
+/// Functions whose arguments use `FROM` (`EXTRACT(YEAR FROM dt)`)
+static FROM_FUNCTION_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(EXTRACT|TRIM|SUBSTRING|SUBSTR|POSITION)\s*\(([^()]*?)\bFROM\b").unwrap()
+});
+
+static COMMENT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?s)/\*.*?\*/|--[^\n]*").unwrap());
+
+static STRING_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"'(?:[^'\\]|\\.)*'").unwrap());
+
+/// Catalog names that mean the cluster's own tables
+const INTERNAL_CATALOGS: &[&str] = &["default_catalog", "internal"];
+
+/// Base objects read by one MV definition (the SELECT part of `CREATE MATERIALIZED VIEW`)
+pub fn parse_mv_base_tables(
+    mv_database: &str,
+    mv_name: &str,
+    definition: &str,
+) -> Vec<MaterializedViewDependency> {
+    let sql = COMMENT_REGEX.replace_all(definition, " ");
+    let sql = STRING_REGEX.replace_all(&sql, "''");
+    let sql = FROM_FUNCTION_REGEX.replace_all(&sql, "$1($2,");
+    let ctes: HashSet<String> = CTE_REGEX
+        .captures_iter(&sql)
+        .map(|c| unquote(&c[1]).to_lowercase())
+        .collect();
+
+    let mut refs: Vec<(&str, &str)> = Vec::new();
+    for cap in TABLE_REF_REGEX.captures_iter(&sql) {
+        let m = cap.get(1).unwrap();
+        let mut rest = &sql[m.end()..];
+        refs.push((m.as_str(), rest));
+        while let Some(more) = COMMA_REF_REGEX.captures(rest) {
+            let m = more.get(1).unwrap();
+            rest = &rest[m.end()..];
+            refs.push((m.as_str(), rest));
+        }
+    }
+
+    let mut dependencies: Vec<MaterializedViewDependency> = Vec::new();
+    for (reference, after) in refs {
+        // Table functions (`FROM unnest(...)`) are followed by a parenthesis
+        if after.trim_start().starts_with('(') {
+            continue;
+        }
+
+        let parts: Vec<String> = reference.split('.').map(|p| unquote(p.trim())).collect();
+        let (catalog, database, table) = match parts.as_slice() {
+            [table] => {
+                let upper = table.to_uppercase();
+                if ctes.contains(&table.to_lowercase())
+                    || matches!(upper.as_str(), "SELECT" | "LATERAL" | "UNNEST" | "TABLE" | "DUAL")
+                {
+                    continue;
+                }
+                (None, mv_database.to_string(), table.clone())
+            },
+            [database, table] => (None, database.clone(), table.clone()),
+            [catalog, database, table] => {
+                let internal = INTERNAL_CATALOGS
+                    .iter()
+                    .any(|c| catalog.eq_ignore_ascii_case(c));
+                ((!internal).then(|| catalog.clone()), database.clone(), table.clone())
+            },
+            _ => continue,
+        };
+
+        let dependency = MaterializedViewDependency {
+            mv_database: mv_database.to_string(),
+            mv_name: mv_name.to_string(),
+            base_catalog: catalog,
+            base_database: database,
+            base_table: table,
+            base_type: None,
+        };
+        if !dependencies.contains(&dependency) {
+            dependencies.push(dependency);
+        }
+    }
+    dependencies
+}
+
+fn unquote(identifier: &str) -> String {
+    identifier.trim_matches('`').to_string()
+}
+
+/// Kind of a node in the dependency graph
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MvNodeKind {
+    MaterializedView,
+    /// Table or view of the internal catalog
+    Table,
+    /// Table of an external catalog (Hive, Iceberg, JDBC, ...)
+    External,
+}
+
+/// Health of a node in the dependency graph
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MvNodeStatus {
+    Ok,
+    /// A base table changed after the last refresh
+    Stale,
+    Inactive,
+    /// Last refresh failed
+    RefreshFailed,
+    /// Fresh itself, but built on an MV that is inactive, failing or stale
+    UpstreamBroken,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct MvDependencyNode {
+    /// `[catalog.]database.name`, lower-cased
+    pub id: String,
+    pub kind: MvNodeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog: Option<String>,
+    pub database: String,
+    pub name: String,
+    pub is_active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_refresh_state: Option<String>,
+    /// Last refresh finish time (MVs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_refresh_time: Option<String>,
+    /// Last data change: refresh time for MVs, UPDATE_TIME for tables
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_update_time: Option<String>,
+    /// How far the newest base change is ahead of the last refresh
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stale_seconds: Option<i64>,
+    /// Longest path from a source table, for layered layout
+    pub level: usize,
+    pub status: MvNodeStatus,
+    pub highlighted: bool,
+}
+
+/// Edge from a base object to an MV reading it
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct MvDependencyEdge {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, ToSchema)]
+pub struct MvDependencyGraph {
+    pub nodes: Vec<MvDependencyNode>,
+    pub edges: Vec<MvDependencyEdge>,
+}
+
+fn node_id(catalog: Option<&str>, database: &str, name: &str) -> String {
+    match catalog {
+        Some(catalog) => format!("{}.{}.{}", catalog, database, name).to_lowercase(),
+        None => format!("{}.{}", database, name).to_lowercase(),
+    }
+}
+
+fn parse_time(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f"))
+        .ok()
+}
+
+/// Build the DAG and evaluate every MV against its bases
+///
+/// `update_times` maps lower-cased `(database, table)` of internal tables to their UPDATE_TIME.
+pub fn build_mv_dependency_graph(
+    mvs: &[MaterializedView],
+    dependencies: &[MaterializedViewDependency],
+    update_times: &HashMap<(String, String), String>,
+) -> MvDependencyGraph {
+    let mut nodes: Vec<MvDependencyNode> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+
+    for mv in mvs.iter().filter(|mv| mv.refresh_type != "ROLLUP") {
+        let id = node_id(None, &mv.database_name, &mv.name);
+        if index.contains_key(&id) {
+            continue;
+        }
+        index.insert(id.clone(), nodes.len());
+        nodes.push(MvDependencyNode {
+            id,
+            kind: MvNodeKind::MaterializedView,
+            catalog: None,
+            database: mv.database_name.clone(),
+            name: mv.name.clone(),
+            is_active: mv.is_active,
+            last_refresh_state: mv.last_refresh_state.clone(),
+            last_refresh_time: mv.last_refresh_finished_time.clone(),
+            last_update_time: mv.last_refresh_finished_time.clone(),
+            stale_seconds: None,
+            level: 0,
+            status: MvNodeStatus::Ok,
+            highlighted: false,
+        });
+    }
+
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    for dep in dependencies {
+        let Some(&to) = index.get(&node_id(None, &dep.mv_database, &dep.mv_name)) else {
+            continue;
+        };
+        let base_id = node_id(dep.base_catalog.as_deref(), &dep.base_database, &dep.base_table);
+        let from = match index.get(&base_id) {
+            Some(&i) => i,
+            None => {
+                let last_update_time = match dep.base_catalog {
+                    Some(_) => None,
+                    None => update_times
+                        .get(&(dep.base_database.to_lowercase(), dep.base_table.to_lowercase()))
+                        .cloned(),
+                };
+                index.insert(base_id.clone(), nodes.len());
+                nodes.push(MvDependencyNode {
+                    id: base_id,
+                    kind: if dep.base_catalog.is_some() {
+                        MvNodeKind::External
+                    } else {
+                        MvNodeKind::Table
+                    },
+                    catalog: dep.base_catalog.clone(),
+                    database: dep.base_database.clone(),
+                    name: dep.base_table.clone(),
+                    is_active: true,
+                    last_refresh_state: None,
+                    last_refresh_time: None,
+                    last_update_time,
+                    stale_seconds: None,
+                    level: 0,
+                    status: MvNodeStatus::Ok,
+                    highlighted: false,
+                });
+                nodes.len() - 1
+            },
+        };
+        if from != to && !edges.contains(&(from, to)) {
+            edges.push((from, to));
+        }
+    }
+
+    let mut upstream: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    let mut downstream: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    for &(from, to) in &edges {
+        upstream[to].push(from);
+        downstream[from].push(to);
+    }
+
+    // Kahn's order so every MV is evaluated after the MVs it reads; nodes on a cycle keep level 0
+    let mut pending: Vec<usize> = upstream.iter().map(|u| u.len()).collect();
+    let mut queue: VecDeque<usize> = (0..nodes.len()).filter(|&i| pending[i] == 0).collect();
+    while let Some(i) = queue.pop_front() {
+        if nodes[i].kind == MvNodeKind::MaterializedView {
+            let refreshed = nodes[i].last_refresh_time.as_deref().and_then(parse_time);
+            let newest_base = upstream[i]
+                .iter()
+                .filter_map(|&u| nodes[u].last_update_time.as_deref().and_then(parse_time))
+                .max();
+            nodes[i].stale_seconds = match (refreshed, newest_base) {
+                (Some(refreshed), Some(base)) if base > refreshed => {
+                    Some((base - refreshed).num_seconds())
+                },
+                _ => None,
+            };
+
+            let upstream_broken = upstream[i]
+                .iter()
+                .any(|&u| nodes[u].status != MvNodeStatus::Ok);
+            nodes[i].status = if !nodes[i].is_active {
+                MvNodeStatus::Inactive
+            } else if nodes[i]
+                .last_refresh_state
+                .as_deref()
+                .is_some_and(|s| s.eq_ignore_ascii_case("FAILED"))
+            {
+                MvNodeStatus::RefreshFailed
+            } else if nodes[i].stale_seconds.is_some() {
+                MvNodeStatus::Stale
+            } else if upstream_broken {
+                MvNodeStatus::UpstreamBroken
+            } else {
+                MvNodeStatus::Ok
+            };
+            nodes[i].highlighted = nodes[i].status != MvNodeStatus::Ok;
+        }
+
+        for &d in &downstream[i] {
+            nodes[d].level = nodes[d].level.max(nodes[i].level + 1);
+            pending[d] -= 1;
+            if pending[d] == 0 {
+                queue.push_back(d);
+            }
+        }
+    }
+
+    let mut edges: Vec<MvDependencyEdge> = edges
+        .into_iter()
+        .map(|(from, to)| MvDependencyEdge {
+            from: nodes[from].id.clone(),
+            to: nodes[to].id.clone(),
+        })
+        .collect();
+    edges.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+    nodes.sort_by(|a, b| a.level.cmp(&b.level).then_with(|| a.id.cmp(&b.id)));
+
+    MvDependencyGraph { nodes, edges }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mv(db: &str, name: &str, refreshed: &str, active: bool, state: &str) -> MaterializedView {
+        MaterializedView {
+            id: format!("{}.{}", db, name),
+            name: name.to_string(),
+            database_name: db.to_string(),
+            refresh_type: "ASYNC".to_string(),
+            is_active: active,
+            partition_type: None,
+            task_id: None,
+            task_name: None,
+            last_refresh_start_time: None,
+            last_refresh_finished_time: Some(refreshed.to_string()),
+            last_refresh_duration: None,
+            last_refresh_state: Some(state.to_string()),
+            rows: None,
+            text: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_mv_base_tables() {
+        let sql = r#"
+            WITH recent AS (SELECT * FROM orders WHERE dt >= '2024-01-01')
+            SELECT EXTRACT(YEAR FROM r.dt) AS y, c.region, SUM(r.amount)
+            FROM recent r, default_catalog.dim.dates d
+            JOIN `crm`.`customers` c ON r.cid = c.id
+            LEFT JOIN hive_prod.ods.events e ON e.cid = c.id
+            -- FROM ignored_table
+            GROUP BY 1, 2
+        "#;
+        let deps = parse_mv_base_tables("sales", "mv_yearly", sql);
+        let names: Vec<(Option<&str>, &str, &str)> = deps
+            .iter()
+            .map(|d| (d.base_catalog.as_deref(), d.base_database.as_str(), d.base_table.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                (None, "sales", "orders"),
+                (None, "dim", "dates"),
+                (None, "crm", "customers"),
+                (Some("hive_prod"), "ods", "events"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_staleness_propagates_down_the_chain() {
+        let mvs = vec![
+            mv("sales", "mv_daily", "2024-05-01 10:00:00", true, "SUCCESS"),
+            mv("report", "mv_monthly", "2024-05-01 11:00:00", true, "SUCCESS"),
+            mv("report", "mv_broken", "2024-05-01 09:00:00", false, "SUCCESS"),
+        ];
+        let mut deps = parse_mv_base_tables("sales", "mv_daily", "SELECT * FROM orders");
+        deps.extend(parse_mv_base_tables("report", "mv_monthly", "SELECT * FROM sales.mv_daily"));
+        deps.extend(parse_mv_base_tables("report", "mv_broken", "SELECT * FROM sales.orders"));
+        let update_times = HashMap::from([(
+            ("sales".to_string(), "orders".to_string()),
+            "2024-05-01 10:30:00".to_string(),
+        )]);
+
+        let graph = build_mv_dependency_graph(&mvs, &deps, &update_times);
+        let node = |id: &str| graph.nodes.iter().find(|n| n.id == id).unwrap();
+
+        assert_eq!(graph.edges.len(), 3);
+        assert_eq!(node("sales.orders").kind, MvNodeKind::Table);
+        assert_eq!(node("sales.orders").level, 0);
+        assert_eq!(node("sales.mv_daily").status, MvNodeStatus::Stale);
+        assert_eq!(node("sales.mv_daily").stale_seconds, Some(1800));
+        assert_eq!(node("report.mv_monthly").status, MvNodeStatus::UpstreamBroken);
+        assert_eq!(node("report.mv_monthly").level, 2);
+        assert_eq!(node("report.mv_broken").status, MvNodeStatus::Inactive);
+        assert!(
+            graph
+                .nodes
+                .iter()
+                .filter(|n| n.kind != MvNodeKind::MaterializedView)
+                .all(|n| !n.highlighted)
+        );
+    }
+}
@@ -0,0 +1,556 @@
+// Materialized View Recommendation Service
+// Purpose: Mine repeated aggregation patterns from the workload and propose async MVs
+// Input: audit log rows and retained profiles, reduced to `WorkloadQuery`
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use utoipa::ToSchema;
+
+use crate::services::profile_analyzer::analyzer::QueryFingerprint;
+
+/// Share of the original query time an MV rewrite is assumed to save
+const ESTIMATED_SPEEDUP: f64 = 0.9;
+
+/// Aggregate functions an async MV can pre-compute and rewrite against
+const REWRITABLE_AGGREGATES: &[&str] = &[
+    "SUM",
+    "COUNT",
+    "MIN",
+    "MAX",
+    "AVG",
+    "APPROX_COUNT_DISTINCT",
+    "BITMAP_UNION",
+    "BITMAP_UNION_COUNT",
+    "HLL_UNION",
+    "HLL_UNION_AGG",
+    "PERCENTILE_UNION",
+];
+
+/// One executed query taken from the audit log or a stored profile
+#[derive(Debug, Clone, Default)]
+pub struct WorkloadQuery {
+    pub query_id: Option<String>,
+    pub database: Option<String>,
+    pub sql: String,
+    pub duration_ms: f64,
+    pub scan_bytes: Option<i64>,
+}
+
+/// Candidate async materialized view for a repeated aggregation pattern
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MvCandidate {
+    /// Stable id of the aggregation pattern
+    pub pattern_id: String,
+    pub database: Option<String>,
+    pub tables: Vec<String>,
+    pub group_by: Vec<String>,
+    /// Filter predicates with literals replaced by `?`
+    pub filters: Vec<String>,
+    pub aggregates: Vec<String>,
+    pub query_count: usize,
+    pub avg_duration_ms: f64,
+    /// Benefit: query count × estimated time saved per query
+    pub estimated_saved_ms: f64,
+    /// Cost: bytes scanned by one full refresh, from the largest observed scan
+    pub estimated_refresh_bytes: Option<i64>,
+    pub sample_sql: String,
+    /// Proposed `CREATE MATERIALIZED VIEW` statement
+    pub ddl: String,
+}
+
+/// Tuning knobs for `MvRecommender`
+#[derive(Debug, Clone)]
+pub struct MvRecommendationOptions {
+    /// Minimum executions of a pattern before it is proposed
+    pub min_query_count: usize,
+    /// Maximum number of candidates returned
+    pub limit: usize,
+    /// Refresh interval written into the generated DDL
+    pub refresh_interval: String,
+}
+
+impl Default for MvRecommendationOptions {
+    fn default() -> Self {
+        Self { min_query_count: 3, limit: 20, refresh_interval: "1 HOUR".to_string() }
+    }
+}
+
+/// SELECT statement split into the clauses the recommender looks at
+#[derive(Debug, Clone, PartialEq)]
+struct AggregateQuery {
+    select: Vec<String>,
+    from: String,
+    predicates: Vec<String>,
+    group_by: Vec<String>,
+}
+
+impl AggregateQuery {
+    /// Split a single-block aggregation query; subqueries, unions and CTEs are skipped
+    fn parse(sql: &str) -> Option<Self> {
+        let sql = collapse_whitespace(sql.trim().trim_end_matches(';'));
+        let upper = sql.to_ascii_uppercase();
+        if !upper.starts_with("SELECT ") || upper.matches("SELECT ").count() > 1 {
+            return None;
+        }
+
+        let from = keyword_pos(&upper, " FROM ")?;
+        let where_ = keyword_pos(&upper, " WHERE ");
+        let group = keyword_pos(&upper, " GROUP BY ")?;
+        let tail = [" HAVING ", " ORDER BY ", " LIMIT "]
+            .iter()
+            .filter_map(|k| keyword_pos(&upper, k))
+            .filter(|&p| p > group)
+            .min()
+            .unwrap_or(sql.len());
+        if where_.is_some_and(|w| w < from || w > group) || from > group {
+            return None;
+        }
+
+        let select = split_top_level(&sql["SELECT ".len()..from], ",");
+        let from_end = where_.unwrap_or(group);
+        let from_clause = sql[from + " FROM ".len()..from_end].trim().to_string();
+        // AND binds tighter than OR, so a top-level OR keeps the WHERE clause whole
+        let predicates = where_
+            .map(|w| {
+                let clause = &sql[w + " WHERE ".len()..group];
+                if keyword_pos(&upper[w + " WHERE ".len()..group], " OR ").is_some() {
+                    vec![clause.trim().to_string()]
+                } else {
+                    split_top_level(clause, " AND ")
+                }
+            })
+            .unwrap_or_default();
+        let group_by = split_top_level(&sql[group + " GROUP BY ".len()..tail], ",");
+
+        if group_by.is_empty()
+            || group_by
+                .iter()
+                .any(|k| k.chars().all(|c| c.is_ascii_digit()))
+        {
+            return None;
+        }
+        Some(Self { select, from: from_clause, predicates, group_by })
+    }
+
+    fn aggregates(&self) -> Vec<String> {
+        self.select
+            .iter()
+            .map(|item| strip_alias(item))
+            .filter(|item| is_rewritable_aggregate(item))
+            .collect()
+    }
+}
+
+/// Groups workload queries by aggregation pattern and ranks MV candidates
+pub struct MvRecommender {
+    options: MvRecommendationOptions,
+}
+
+impl MvRecommender {
+    pub fn new(options: MvRecommendationOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn recommend(&self, queries: &[WorkloadQuery]) -> Vec<MvCandidate> {
+        let mut seen_ids = HashSet::new();
+        let mut patterns: HashMap<String, Vec<(&WorkloadQuery, AggregateQuery)>> = HashMap::new();
+
+        for query in queries {
+            if let Some(id) = query.query_id.as_deref().filter(|id| !id.is_empty())
+                && !seen_ids.insert(id.to_string())
+            {
+                continue;
+            }
+            let Some(parsed) = AggregateQuery::parse(&query.sql) else {
+                continue;
+            };
+            if parsed.aggregates().is_empty() {
+                continue;
+            }
+            patterns
+                .entry(pattern_key(query, &parsed))
+                .or_default()
+                .push((query, parsed));
+        }
+
+        let mut candidates: Vec<MvCandidate> = patterns
+            .into_iter()
+            .filter(|(_, members)| members.len() >= self.options.min_query_count)
+            .filter_map(|(key, members)| self.build_candidate(&key, &members))
+            .collect();
+
+        candidates.sort_by(|a, b| {
+            b.estimated_saved_ms
+                .partial_cmp(&a.estimated_saved_ms)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.pattern_id.cmp(&b.pattern_id))
+        });
+        candidates.truncate(self.options.limit);
+        candidates
+    }
+
+    fn build_candidate(
+        &self,
+        key: &str,
+        members: &[(&WorkloadQuery, AggregateQuery)],
+    ) -> Option<MvCandidate> {
+        let (sample, parsed) = &members[0];
+        let total_ms: f64 = members.iter().map(|(q, _)| q.duration_ms).sum();
+        let tables = QueryFingerprint::extract_tables(&sample.sql);
+        let pattern_id = format!("{:016x}", stable_hash(key));
+        let ddl = self.build_ddl(sample.database.as_deref(), &tables, &pattern_id, parsed)?;
+
+        Some(MvCandidate {
+            database: sample.database.clone().filter(|db| !db.is_empty()),
+            group_by: parsed.group_by.clone(),
+            filters: parsed
+                .predicates
+                .iter()
+                .map(|p| QueryFingerprint::normalize_sql(p))
+                .collect(),
+            aggregates: parsed.aggregates(),
+            query_count: members.len(),
+            avg_duration_ms: total_ms / members.len() as f64,
+            estimated_saved_ms: total_ms * ESTIMATED_SPEEDUP,
+            estimated_refresh_bytes: members.iter().filter_map(|(q, _)| q.scan_bytes).max(),
+            sample_sql: sample.sql.trim().to_string(),
+            ddl,
+            tables,
+            pattern_id,
+        })
+    }
+
+    /// Build the MV definition: literal filters become grouping columns so one MV
+    /// serves every parameter value, join-style predicates stay in the WHERE clause
+    ///
+    /// Returns `None` when a literal filter cannot become a grouping column (OR
+    /// predicates, expressions), since the MV would then only serve one value.
+    fn build_ddl(
+        &self,
+        database: Option<&str>,
+        tables: &[String],
+        pattern_id: &str,
+        parsed: &AggregateQuery,
+    ) -> Option<String> {
+        let mut keys: Vec<String> = parsed.group_by.clone();
+        let mut static_predicates = Vec::new();
+        for predicate in &parsed.predicates {
+            if QueryFingerprint::normalize_sql(predicate).contains('?') {
+                let column = filter_column(predicate)?;
+                if !keys.iter().any(|k| k.eq_ignore_ascii_case(&column)) {
+                    keys.push(column);
+                }
+            } else {
+                static_predicates.push(predicate.clone());
+            }
+        }
+
+        let mut used_names = HashSet::new();
+        let mut columns: Vec<String> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                let name = column_name(key).unwrap_or_else(|| format!("key_{}", i + 1));
+                let name = unique_name(name, &mut used_names);
+                format!("{} AS `{}`", key, name)
+            })
+            .collect();
+        columns.extend(
+            parsed
+                .aggregates()
+                .iter()
+                .enumerate()
+                .map(|(i, agg)| format!("{} AS `agg_{}`", agg, i + 1)),
+        );
+
+        let table_part = tables
+            .iter()
+            .take(3)
+            .map(|t| t.to_ascii_lowercase())
+            .collect::<Vec<_>>()
+            .join("_");
+        let mv_name = sanitize_mv_name(&format!("mv_{}_{}", table_part, &pattern_id[..8]));
+        let qualified = match database.filter(|db| !db.is_empty()) {
+            Some(db) => format!("`{}`.`{}`", db, mv_name),
+            None => format!("`{}`", mv_name),
+        };
+
+        let mut ddl = format!(
+            "CREATE MATERIALIZED VIEW {}\nREFRESH ASYNC EVERY (INTERVAL {})\nAS\nSELECT {}\nFROM {}",
+            qualified,
+            self.options.refresh_interval,
+            columns.join(",\n       "),
+            parsed.from
+        );
+        if !static_predicates.is_empty() {
+            ddl.push_str(&format!("\nWHERE {}", static_predicates.join(" AND ")));
+        }
+        ddl.push_str(&format!("\nGROUP BY {}", keys.join(", ")));
+        Some(ddl)
+    }
+}
+
+/// Same tables, group keys, filter shape and aggregates map to the same pattern
+fn pattern_key(query: &WorkloadQuery, parsed: &AggregateQuery) -> String {
+    let mut filters: Vec<String> = parsed
+        .predicates
+        .iter()
+        .map(|p| QueryFingerprint::normalize_sql(p))
+        .collect();
+    filters.sort();
+    let aggregates: BTreeSet<String> = parsed
+        .aggregates()
+        .iter()
+        .map(|a| QueryFingerprint::normalize_sql(a))
+        .collect();
+    let group_by: Vec<String> = parsed
+        .group_by
+        .iter()
+        .map(|k| QueryFingerprint::normalize_sql(k))
+        .collect();
+
+    format!(
+        "{}|{}|{}|{}|{}|{}",
+        query.database.as_deref().unwrap_or("").to_ascii_lowercase(),
+        QueryFingerprint::extract_tables(&query.sql).join(","),
+        QueryFingerprint::normalize_sql(&parsed.from),
+        group_by.join(","),
+        filters.join(" AND "),
+        aggregates.into_iter().collect::<Vec<_>>().join(",")
+    )
+}
+
+fn stable_hash(key: &str) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn collapse_whitespace(sql: &str) -> String {
+    sql.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Byte position of a keyword outside parentheses and quotes
+fn keyword_pos(upper: &str, keyword: &str) -> Option<usize> {
+    let bytes = upper.as_bytes();
+    let mut depth = 0i32;
+    let mut quote: Option<u8> = None;
+    for i in 0..bytes.len() {
+        let b = bytes[i];
+        match quote {
+            Some(q) if b == q => quote = None,
+            Some(_) => {},
+            None => match b {
+                b'\'' | b'"' | b'`' => quote = Some(b),
+                b'(' => depth += 1,
+                b')' => depth -= 1,
+                _ if depth == 0 && bytes[i..].starts_with(keyword.as_bytes()) => return Some(i),
+                _ => {},
+            },
+        }
+    }
+    None
+}
+
+/// Split on a separator that appears outside parentheses and quotes
+fn split_top_level(text: &str, separator: &str) -> Vec<String> {
+    let upper = text.to_ascii_uppercase();
+    let mut parts = Vec::new();
+    let mut rest = 0;
+    while let Some(pos) = keyword_pos(&upper[rest..], separator) {
+        parts.push(text[rest..rest + pos].trim().to_string());
+        rest += pos + separator.len();
+    }
+    parts.push(text[rest..].trim().to_string());
+    parts.retain(|p| !p.is_empty());
+    parts
+}
+
+fn strip_alias(item: &str) -> String {
+    let upper = item.to_ascii_uppercase();
+    match keyword_pos(&upper, " AS ") {
+        Some(pos) => item[..pos].trim().to_string(),
+        None => item.trim().to_string(),
+    }
+}
+
+fn is_rewritable_aggregate(item: &str) -> bool {
+    let upper = item.to_ascii_uppercase();
+    let Some(open) = upper.find('(') else {
+        return false;
+    };
+    let name = upper[..open].trim();
+    REWRITABLE_AGGREGATES.contains(&name) && upper.ends_with(')')
+}
+
+/// Column on the left-hand side of a simple filter such as `dt >= '2024-01-01'`
+///
+/// OR predicates are not simple filters: `dt = '2024' OR region = 'EU'` does not
+/// restrict `dt` alone.
+fn filter_column(predicate: &str) -> Option<String> {
+    if keyword_pos(&predicate.to_ascii_uppercase(), " OR ").is_some() {
+        return None;
+    }
+    let end = predicate
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.' || c == '`'))
+        .unwrap_or(predicate.len());
+    let column = predicate[..end].trim();
+    let rest = predicate[end..].trim_start().to_ascii_uppercase();
+    let is_filter = ["=", "<", ">", "!=", "IN ", "IN(", "BETWEEN ", "LIKE ", "NOT "]
+        .iter()
+        .any(|op| rest.starts_with(op));
+    (!column.is_empty() && is_filter).then(|| column.to_string())
+}
+
+/// Restrict a generated MV name to `[a-z0-9_]`
+fn sanitize_mv_name(name: &str) -> String {
+    name.chars()
+        .map(|c| c.to_ascii_lowercase())
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect()
+}
+
+/// Output name for a plain (optionally qualified) column reference
+fn column_name(key: &str) -> Option<String> {
+    let name = key.rsplit('.').next()?.trim_matches('`');
+    (!name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_'))
+        .then(|| name.to_string())
+}
+
+fn unique_name(name: String, used: &mut HashSet<String>) -> String {
+    let mut candidate = name.clone();
+    let mut n = 2;
+    while !used.insert(candidate.to_ascii_lowercase()) {
+        candidate = format!("{}_{}", name, n);
+        n += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(id: &str, sql: &str, duration_ms: f64) -> WorkloadQuery {
+        WorkloadQuery {
+            query_id: Some(id.to_string()),
+            database: Some("sales".to_string()),
+            sql: sql.to_string(),
+            duration_ms,
+            scan_bytes: Some(1_000_000),
+        }
+    }
+
+    #[test]
+    fn test_parse_aggregate_query() {
+        let parsed = AggregateQuery::parse(
+            "SELECT o.region, SUM(o.amount) AS total FROM orders o JOIN users u ON o.uid = u.id \
+             WHERE o.dt >= '2024-01-01' AND o.uid = u.id GROUP BY o.region ORDER BY total DESC LIMIT 10",
+        )
+        .unwrap();
+        assert_eq!(parsed.from, "orders o JOIN users u ON o.uid = u.id");
+        assert_eq!(parsed.group_by, vec!["o.region"]);
+        assert_eq!(parsed.predicates.len(), 2);
+        assert_eq!(parsed.aggregates(), vec!["SUM(o.amount)"]);
+
+        assert!(AggregateQuery::parse("SELECT * FROM (SELECT 1) t GROUP BY a").is_none());
+        assert!(AggregateQuery::parse("SELECT a FROM t").is_none());
+    }
+
+    #[test]
+    fn test_recommend_groups_repeated_patterns() {
+        let recommender = MvRecommender::new(MvRecommendationOptions::default());
+        let queries = vec![
+            query(
+                "q1",
+                "SELECT region, SUM(amount) FROM orders WHERE dt = '2024-01-01' GROUP BY region",
+                4000.0,
+            ),
+            query(
+                "q2",
+                "select region, sum(amount) from orders where dt = '2024-01-02' group by region",
+                6000.0,
+            ),
+            query(
+                "q3",
+                "SELECT region, SUM(amount) FROM orders WHERE dt = '2024-01-03' GROUP BY region",
+                5000.0,
+            ),
+            // duplicate from the profile list must not be counted twice
+            query(
+                "q3",
+                "SELECT region, SUM(amount) FROM orders WHERE dt = '2024-01-03' GROUP BY region",
+                5000.0,
+            ),
+            query("q4", "SELECT city, COUNT(*) FROM users GROUP BY city", 100.0),
+        ];
+
+        let candidates = recommender.recommend(&queries);
+        assert_eq!(candidates.len(), 1);
+        let mv = &candidates[0];
+        assert_eq!(mv.query_count, 3);
+        assert_eq!(mv.tables, vec!["ORDERS"]);
+        assert_eq!(mv.filters, vec!["DT = ?"]);
+        assert!((mv.estimated_saved_ms - 15000.0 * ESTIMATED_SPEEDUP).abs() < 1e-6);
+        assert_eq!(mv.estimated_refresh_bytes, Some(1_000_000));
+        assert!(
+            mv.ddl
+                .starts_with("CREATE MATERIALIZED VIEW `sales`.`mv_orders_")
+        );
+        assert!(mv.ddl.contains("REFRESH ASYNC EVERY (INTERVAL 1 HOUR)"));
+        assert!(mv.ddl.contains("SUM(amount) AS `agg_1`"));
+        assert!(mv.ddl.ends_with("GROUP BY region, dt"));
+        assert!(!mv.ddl.contains("WHERE"));
+    }
+
+    #[test]
+    fn test_ddl_keeps_join_predicates_and_dedupes_names() {
+        let parsed = AggregateQuery::parse(
+            "SELECT a.id, b.id, MAX(a.v) FROM a, b WHERE a.k = b.k AND a.dt > '2024' GROUP BY a.id, b.id",
+        )
+        .unwrap();
+        let ddl = MvRecommender::new(MvRecommendationOptions::default())
+            .build_ddl(None, &["A".to_string(), "B".to_string()], "0123456789abcdef", &parsed)
+            .unwrap();
+        assert!(ddl.starts_with("CREATE MATERIALIZED VIEW `mv_a_b_01234567`"));
+        assert!(ddl.contains("a.id AS `id`"));
+        assert!(ddl.contains("b.id AS `id_2`"));
+        assert!(ddl.contains("a.dt AS `dt`"));
+        assert!(ddl.contains("\nWHERE a.k = b.k\n"));
+        assert!(ddl.ends_with("GROUP BY a.id, b.id, a.dt"));
+    }
+
+    #[test]
+    fn test_or_predicates_are_not_grouping_columns() {
+        assert_eq!(filter_column("dt = '2024-01-01'"), Some("dt".to_string()));
+        assert_eq!(filter_column("dt = '2024-01-01' OR region = 'EU'"), None);
+
+        let parsed = AggregateQuery::parse(
+            "SELECT region, SUM(v) FROM t WHERE k = 1 AND dt = '2024' OR region = 'EU' GROUP BY region",
+        )
+        .unwrap();
+        assert_eq!(parsed.predicates.len(), 1);
+
+        let recommender = MvRecommender::new(MvRecommendationOptions::default());
+        let queries: Vec<WorkloadQuery> = (1..=3)
+            .map(|i| {
+                query(
+                    &format!("q{}", i),
+                    &format!(
+                        "SELECT region, SUM(v) FROM t WHERE dt = '2024-0{}' OR region = 'EU' \
+                         GROUP BY region",
+                        i
+                    ),
+                    1000.0,
+                )
+            })
+            .collect();
+        assert!(recommender.recommend(&queries).is_empty());
+    }
+
+    #[test]
+    fn test_sanitize_mv_name() {
+        assert_eq!(sanitize_mv_name("mv_Orders-2024_表_0123abcd"), "mv_orders_2024___0123abcd");
+    }
+}
@@ -0,0 +1,703 @@
+// Materialized View Refresh History Service
+// Purpose: Keep every MV refresh task run (state, duration, error, refreshed partitions / rows) in
+// SQLite beyond the FE's task run retention, and flag MVs whose refreshes keep failing or slowing down.
+
+use crate::models::Cluster;
+use crate::services::mv_usage_service::MvRefreshCost;
+use crate::services::{ClusterService, MySQLClient, MySQLPoolManager};
+use crate::utils::{ApiResult, RowView, ScheduledTask};
+use chrono::{NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+/// Default number of failed refreshes in a row before an MV is flagged
+pub const DEFAULT_FAILURE_STREAK: usize = 3;
+
+/// Successful runs needed before a duration trend is evaluated
+const TREND_MIN_RUNS: usize = 5;
+
+/// Most recent runs per MV considered for alerts
+const ALERT_WINDOW_RUNS: i64 = 20;
+
+/// Fitted refresh duration must grow by this factor over the window to be flagged
+const GROWTH_RATIO: f64 = 1.5;
+
+/// Refreshes shorter than this are not worth a growth alert
+const TREND_MIN_DURATION_MS: f64 = 10_000.0;
+
+/// Re-read task runs created this long before the newest stored one, to pick up late state changes
+const RESCAN_MARGIN_HOURS: i64 = 1;
+
+/// Labels per `information_schema.loads` lookup
+const LOAD_LABEL_BATCH: usize = 200;
+
+/// One refresh task run of a materialized view
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MvRefreshRun {
+    pub task_run_id: String,
+    pub task_name: String,
+    pub database: String,
+    pub mv_name: String,
+    /// PENDING, RUNNING, SUCCESS, FAILED, SKIPPED, MERGED (StarRocks) / CANCELED (Doris)
+    pub state: String,
+    pub create_time: Option<NaiveDateTime>,
+    pub finish_time: Option<NaiveDateTime>,
+    pub duration_ms: Option<i64>,
+    pub error_message: String,
+    pub refreshed_partitions: Vec<String>,
+    pub refreshed_rows: Option<i64>,
+}
+
+/// Why a materialized view needs attention
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MvRefreshAlertKind {
+    /// The latest refreshes all failed
+    ConsecutiveFailures,
+    /// Successful refreshes keep taking longer
+    GrowingDuration,
+}
+
+/// Materialized view flagged from its refresh history
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct MvRefreshAlert {
+    pub kind: MvRefreshAlertKind,
+    pub database: String,
+    pub mv_name: String,
+    /// Failed refreshes in a row (latest first)
+    pub consecutive_failures: usize,
+    /// Fitted last / first refresh duration over the recent successful runs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub growth_ratio: Option<f64>,
+    pub latest_duration_ms: Option<i64>,
+    pub last_error: String,
+    pub last_run_at: Option<NaiveDateTime>,
+}
+
+#[derive(sqlx::FromRow)]
+struct RunRow {
+    task_run_id: String,
+    task_name: String,
+    database_name: String,
+    mv_name: String,
+    state: String,
+    create_time: Option<NaiveDateTime>,
+    finish_time: Option<NaiveDateTime>,
+    duration_ms: Option<i64>,
+    error_message: Option<String>,
+    refreshed_partitions: String,
+    refreshed_rows: Option<i64>,
+}
+
+impl From<RunRow> for MvRefreshRun {
+    fn from(r: RunRow) -> Self {
+        Self {
+            task_run_id: r.task_run_id,
+            task_name: r.task_name,
+            database: r.database_name,
+            mv_name: r.mv_name,
+            state: r.state,
+            create_time: r.create_time,
+            finish_time: r.finish_time,
+            duration_ms: r.duration_ms,
+            error_message: r.error_message.unwrap_or_default(),
+            refreshed_partitions: serde_json::from_str(&r.refreshed_partitions).unwrap_or_default(),
+            refreshed_rows: r.refreshed_rows,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MvRefreshHistoryService {
+    db: SqlitePool,
+    cluster_service: Arc<ClusterService>,
+    mysql_pool_manager: Arc<MySQLPoolManager>,
+    retention_days: i64,
+}
+
+impl MvRefreshHistoryService {
+    pub fn new(
+        db: SqlitePool,
+        cluster_service: Arc<ClusterService>,
+        mysql_pool_manager: Arc<MySQLPoolManager>,
+        retention_days: i64,
+    ) -> Self {
+        Self { db, cluster_service, mysql_pool_manager, retention_days }
+    }
+
+    /// Execute one collection cycle (called by the ScheduledExecutor)
+    pub async fn collect_once(&self) -> Result<(), anyhow::Error> {
+        let clusters = self.cluster_service.list_clusters().await?;
+        for cluster in clusters {
+            if let Err(e) = self.collect_cluster(&cluster).await {
+                tracing::warn!(
+                    "Failed to collect MV refresh history for cluster {} ({}): {}",
+                    cluster.id,
+                    cluster.name,
+                    e
+                );
+            }
+        }
+
+        // Task times use the cluster clock, so retention counts back from each cluster's newest run
+        let cutoff = (Utc::now() - chrono::Duration::days(self.retention_days)).naive_utc();
+        sqlx::query(
+            r#"
+            DELETE FROM mv_refresh_runs
+            WHERE COALESCE(finish_time, create_time) < datetime(
+                    (SELECT MAX(COALESCE(r.finish_time, r.create_time)) FROM mv_refresh_runs r
+                     WHERE r.cluster_id = mv_refresh_runs.cluster_id), ?)
+                OR (finish_time IS NULL AND create_time IS NULL AND collected_at < ?)
+            "#,
+        )
+        .bind(format!("-{} days", self.retention_days))
+        .bind(cutoff)
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    async fn collect_cluster(&self, cluster: &Cluster) -> ApiResult<()> {
+        use crate::models::cluster::ClusterType;
+
+        let pool = self.mysql_pool_manager.get_pool(cluster).await?;
+        let client = MySQLClient::from_pool(pool);
+        let since = self.rescan_since(cluster.id).await?;
+        let time_filter = |column: &str| {
+            since
+                .map(|t| format!("WHERE {} >= '{}'", column, t.format("%Y-%m-%d %H:%M:%S")))
+                .unwrap_or_default()
+        };
+
+        let mut runs = match cluster.cluster_type {
+            ClusterType::StarRocks => {
+                let (columns, rows) = client
+                    .query_raw(
+                        "SELECT TABLE_SCHEMA, TABLE_NAME, TASK_NAME FROM information_schema.materialized_views",
+                    )
+                    .await?;
+                let mv_by_task: HashMap<String, (String, String)> = rows
+                    .iter()
+                    .filter_map(|row| {
+                        let v = RowView::new(&columns, row);
+                        Some((
+                            v.get(&["TASK_NAME"])?.to_string(),
+                            (v.string(&["TABLE_SCHEMA"]), v.string(&["TABLE_NAME"])),
+                        ))
+                    })
+                    .collect();
+
+                let filter = match time_filter("CREATE_TIME") {
+                    f if f.is_empty() => "WHERE TASK_NAME LIKE 'mv-%'".to_string(),
+                    f => format!("{} AND TASK_NAME LIKE 'mv-%'", f),
+                };
+                let (columns, rows) = client
+                    .query_raw(&format!(
+                        "SELECT * FROM information_schema.task_runs {} ORDER BY CREATE_TIME",
+                        filter
+                    ))
+                    .await?;
+                let mut runs: Vec<MvRefreshRun> = rows
+                    .iter()
+                    .filter_map(|row| parse_task_run_row(&columns, row, &mv_by_task))
+                    .collect();
+                if let Err(e) = Self::fill_refreshed_rows(&client, &mut runs).await {
+                    tracing::debug!(
+                        "Refreshed rows unavailable for cluster {}: {}",
+                        cluster.name,
+                        e
+                    );
+                }
+                runs
+            },
+            ClusterType::Doris => {
+                let (columns, rows) = client
+                    .query_raw(&format!(
+                        "SELECT * FROM tasks(\"type\"=\"mv\") {} ORDER BY CreateTime",
+                        time_filter("CreateTime")
+                    ))
+                    .await?;
+                rows.iter()
+                    .filter_map(|row| parse_task_run_row(&columns, row, &HashMap::new()))
+                    .collect()
+            },
+        };
+
+        runs.retain(|r| !r.task_run_id.is_empty());
+        for run in &runs {
+            self.save_run(cluster.id, run).await?;
+        }
+        tracing::debug!("Collected {} MV refresh runs for cluster {}", runs.len(), cluster.name);
+        Ok(())
+    }
+
+    /// Oldest create time to re-read: unfinished runs may still change state
+    async fn rescan_since(&self, cluster_id: i64) -> ApiResult<Option<NaiveDateTime>> {
+        let (unfinished, newest): (Option<NaiveDateTime>, Option<NaiveDateTime>) = sqlx::query_as(
+            r#"
+            SELECT
+                (SELECT MIN(create_time) FROM mv_refresh_runs
+                 WHERE cluster_id = ? AND state IN ('PENDING', 'RUNNING')),
+                (SELECT MAX(create_time) FROM mv_refresh_runs WHERE cluster_id = ?)
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(cluster_id)
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(match (unfinished, newest) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+        .map(|t| t - chrono::Duration::hours(RESCAN_MARGIN_HOURS)))
+    }
+
+    /// StarRocks labels the INSERT OVERWRITE of a refresh `insert_<query id>`
+    async fn fill_refreshed_rows(client: &MySQLClient, runs: &mut [MvRefreshRun]) -> ApiResult<()> {
+        let mut rows_by_label = HashMap::new();
+        let labels: Vec<String> = runs
+            .iter()
+            .filter(|r| r.state == "SUCCESS")
+            .map(|r| format!("'insert_{}'", r.task_run_id.replace('\'', "")))
+            .collect();
+        for batch in labels.chunks(LOAD_LABEL_BATCH) {
+            let (columns, rows) = client
+                .query_raw(&format!(
+                    "SELECT * FROM information_schema.loads WHERE LABEL IN ({})",
+                    batch.join(",")
+                ))
+                .await?;
+            for row in &rows {
+                let v = RowView::new(&columns, row);
+                if let (Some(label), Some(sink_rows)) = (v.get(&["LABEL"]), v.int(&["SINK_ROWS"])) {
+                    rows_by_label.insert(label.to_string(), sink_rows);
+                }
+            }
+        }
+
+        for run in runs.iter_mut() {
+            if let Some(rows) = rows_by_label.get(&format!("insert_{}", run.task_run_id)) {
+                run.refreshed_rows = Some(*rows);
+            }
+        }
+        Ok(())
+    }
+
+    async fn save_run(&self, cluster_id: i64, run: &MvRefreshRun) -> ApiResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO mv_refresh_runs (
+                cluster_id, task_run_id, task_name, database_name, mv_name, state, create_time,
+                finish_time, duration_ms, error_message, refreshed_partitions, refreshed_rows,
+                collected_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(cluster_id, task_run_id) DO UPDATE SET
+                state = excluded.state,
+                finish_time = excluded.finish_time,
+                duration_ms = excluded.duration_ms,
+                error_message = excluded.error_message,
+                refreshed_partitions = excluded.refreshed_partitions,
+                refreshed_rows = COALESCE(excluded.refreshed_rows, mv_refresh_runs.refreshed_rows)
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(&run.task_run_id)
+        .bind(&run.task_name)
+        .bind(&run.database)
+        .bind(&run.mv_name)
+        .bind(&run.state)
+        .bind(run.create_time)
+        .bind(run.finish_time)
+        .bind(run.duration_ms)
+        .bind(&run.error_message)
+        .bind(serde_json::to_string(&run.refreshed_partitions)?)
+        .bind(run.refreshed_rows)
+        .bind(Utc::now().naive_utc())
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    /// Refresh runs of one materialized view, oldest first.
+    /// Task times use the cluster clock, so the window counts back from the cluster's newest run.
+    pub async fn get_history(
+        &self,
+        cluster_id: i64,
+        database: Option<&str>,
+        mv_name: &str,
+        hours: i64,
+    ) -> ApiResult<Vec<MvRefreshRun>> {
+        let rows = sqlx::query_as::<_, RunRow>(
+            r#"
+            SELECT task_run_id, task_name, database_name, mv_name, state, create_time, finish_time,
+                   duration_ms, error_message, refreshed_partitions, refreshed_rows
+            FROM mv_refresh_runs
+            WHERE cluster_id = ? AND mv_name = ? AND (? IS NULL OR database_name = ?)
+                AND COALESCE(finish_time, create_time) >= datetime(
+                    (SELECT MAX(COALESCE(finish_time, create_time)) FROM mv_refresh_runs
+                     WHERE cluster_id = ?), ?)
+            ORDER BY create_time ASC, id ASC
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(mv_name)
+        .bind(database)
+        .bind(database)
+        .bind(cluster_id)
+        .bind(format!("-{} hours", hours.max(0)))
+        .fetch_all(&self.db)
+        .await?;
+        Ok(rows.into_iter().map(MvRefreshRun::from).collect())
+    }
+
+    /// Number of refreshes and total refresh time per materialized view, for runs that
+    /// finished (or started, if unfinished) within `hours` of the cluster's newest run
+    pub async fn refresh_costs(
+        &self,
+        cluster_id: i64,
+        hours: i64,
+    ) -> ApiResult<Vec<MvRefreshCost>> {
+        let rows: Vec<(String, String, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT database_name, mv_name, COUNT(*), COALESCE(SUM(duration_ms), 0)
+            FROM mv_refresh_runs
+            WHERE cluster_id = ?
+                AND COALESCE(finish_time, create_time) >= datetime(
+                    (SELECT MAX(COALESCE(finish_time, create_time)) FROM mv_refresh_runs
+                     WHERE cluster_id = ?), ?)
+            GROUP BY database_name, mv_name
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(cluster_id)
+        .bind(format!("-{} hours", hours.max(0)))
+        .fetch_all(&self.db)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(database, mv_name, runs, total_duration_ms)| MvRefreshCost {
+                database,
+                mv_name,
+                runs,
+                total_duration_ms,
+            })
+            .collect())
+    }
+
+    /// MVs whose latest refreshes failed `failure_streak` times in a row or keep slowing down
+    pub async fn get_alerts(
+        &self,
+        cluster_id: i64,
+        failure_streak: usize,
+    ) -> ApiResult<Vec<MvRefreshAlert>> {
+        let rows = sqlx::query_as::<_, RunRow>(
+            r#"
+            SELECT task_run_id, task_name, database_name, mv_name, state, create_time, finish_time,
+                   duration_ms, error_message, refreshed_partitions, refreshed_rows
+            FROM (
+                SELECT *, ROW_NUMBER() OVER (
+                    PARTITION BY database_name, mv_name ORDER BY create_time DESC, id DESC
+                ) AS rn
+                FROM mv_refresh_runs
+                WHERE cluster_id = ? AND state IN ('SUCCESS', 'FAILED')
+            )
+            WHERE rn <= ?
+            ORDER BY database_name, mv_name, create_time DESC
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(ALERT_WINDOW_RUNS)
+        .fetch_all(&self.db)
+        .await?;
+
+        let mut by_mv: BTreeMap<(String, String), Vec<MvRefreshRun>> = BTreeMap::new();
+        for row in rows {
+            let run = MvRefreshRun::from(row);
+            by_mv
+                .entry((run.database.clone(), run.mv_name.clone()))
+                .or_default()
+                .push(run);
+        }
+
+        Ok(by_mv
+            .values()
+            .flat_map(|runs| evaluate_alerts(runs, failure_streak))
+            .collect())
+    }
+}
+
+impl ScheduledTask for MvRefreshHistoryService {
+    fn run(&self) -> Pin<Box<dyn Future<Output = Result<(), anyhow::Error>> + Send + '_>> {
+        Box::pin(async move { self.collect_once().await })
+    }
+}
+
+/// Map a row of StarRocks `information_schema.task_runs` or Doris `tasks("type"="mv")`
+fn parse_task_run_row(
+    columns: &[String],
+    row: &[String],
+    mv_by_task: &HashMap<String, (String, String)>,
+) -> Option<MvRefreshRun> {
+    let v = RowView::new(columns, row);
+    let task_name = v.string(&["TASK_NAME", "JobName"]);
+    let (database, mv_name) = match mv_by_task.get(&task_name) {
+        Some((db, name)) => (db.clone(), name.clone()),
+        None => (
+            v.string(&["MvDatabaseName", "DATABASE"]),
+            v.get(&["MvName"]).unwrap_or(&task_name).to_string(),
+        ),
+    };
+    if mv_name.is_empty() {
+        return None;
+    }
+
+    let create_time = v.get(&["CREATE_TIME", "CreateTime"]).and_then(parse_time);
+    let start_time = v
+        .get(&["PROCESS_TIME", "StartTime"])
+        .and_then(parse_time)
+        .or(create_time);
+    let finish_time = v.get(&["FINISH_TIME", "FinishTime"]).and_then(parse_time);
+    let duration_ms = v.int(&["DurationMs"]).or_else(|| {
+        let (start, finish) = (start_time?, finish_time?);
+        Some((finish - start).num_milliseconds().max(0))
+    });
+
+    // StarRocks keeps the partitions in the EXTRA_MESSAGE json, Doris in its own column
+    let refreshed_partitions = v
+        .get(&["EXTRA_MESSAGE"])
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(s).ok())
+        .and_then(|m| {
+            m.get("mvPartitionsToRefresh")?.as_array().map(|a| {
+                a.iter()
+                    .filter_map(|p| p.as_str().map(String::from))
+                    .collect()
+            })
+        })
+        .or_else(|| v.get(&["CompletedPartitions"]).map(parse_partition_list))
+        .unwrap_or_default();
+
+    Some(MvRefreshRun {
+        task_run_id: v.string(&["QUERY_ID", "TaskId"]),
+        task_name,
+        database,
+        mv_name,
+        state: v.string(&["STATE", "Status"]).to_uppercase(),
+        create_time,
+        finish_time,
+        duration_ms,
+        error_message: v.string(&["ERROR_MESSAGE", "ErrorMsg"]),
+        refreshed_partitions,
+        refreshed_rows: None,
+    })
+}
+
+fn parse_time(s: &str) -> Option<NaiveDateTime> {
+    let s = s.trim();
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f"))
+        .ok()
+}
+
+/// `["p1","p2"]` or `[p1, p2]`
+fn parse_partition_list(s: &str) -> Vec<String> {
+    serde_json::from_str::<Vec<String>>(s).unwrap_or_else(|_| {
+        s.trim_matches(|c| c == '[' || c == ']')
+            .split(',')
+            .map(|p| p.trim().trim_matches('"').to_string())
+            .filter(|p| !p.is_empty())
+            .collect()
+    })
+}
+
+/// Evaluate the finished runs of one MV, newest first
+fn evaluate_alerts(runs: &[MvRefreshRun], failure_streak: usize) -> Vec<MvRefreshAlert> {
+    let Some(latest) = runs.first() else {
+        return Vec::new();
+    };
+    let failures = runs.iter().take_while(|r| r.state == "FAILED").count();
+    let successes: Vec<&MvRefreshRun> = runs.iter().filter(|r| r.state == "SUCCESS").collect();
+    let durations: Vec<f64> = successes
+        .iter()
+        .rev()
+        .filter_map(|r| r.duration_ms.map(|d| d as f64))
+        .collect();
+    let growth = duration_growth(&durations);
+
+    let alert = |kind| MvRefreshAlert {
+        kind,
+        database: latest.database.clone(),
+        mv_name: latest.mv_name.clone(),
+        consecutive_failures: failures,
+        growth_ratio: growth,
+        latest_duration_ms: successes.first().and_then(|r| r.duration_ms),
+        last_error: runs
+            .iter()
+            .find(|r| !r.error_message.is_empty())
+            .map(|r| r.error_message.clone())
+            .unwrap_or_default(),
+        last_run_at: latest.create_time,
+    };
+
+    let mut alerts = Vec::new();
+    if failures >= failure_streak.max(1) {
+        alerts.push(alert(MvRefreshAlertKind::ConsecutiveFailures));
+    }
+    if growth.is_some() {
+        alerts.push(alert(MvRefreshAlertKind::GrowingDuration));
+    }
+    alerts
+}
+
+/// Least-squares trend over refresh durations (oldest first); returns fitted last / first when the
+/// refresh time keeps growing by at least `GROWTH_RATIO`
+fn duration_growth(durations: &[f64]) -> Option<f64> {
+    if durations.len() < TREND_MIN_RUNS {
+        return None;
+    }
+    let n = durations.len() as f64;
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = durations.iter().sum::<f64>() / n;
+    let (mut cov, mut var) = (0.0, 0.0);
+    for (i, y) in durations.iter().enumerate() {
+        let dx = i as f64 - mean_x;
+        cov += dx * (y - mean_y);
+        var += dx * dx;
+    }
+    let slope = cov / var;
+    let first = mean_y - slope * mean_x;
+    let last = mean_y + slope * mean_x;
+
+    (slope > 0.0 && first > 0.0 && last >= TREND_MIN_DURATION_MS && last / first >= GROWTH_RATIO)
+        .then_some(last / first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(state: &str, duration_ms: i64) -> MvRefreshRun {
+        MvRefreshRun {
+            task_run_id: "q".to_string(),
+            task_name: "mv-1".to_string(),
+            database: "db".to_string(),
+            mv_name: "mv".to_string(),
+            state: state.to_string(),
+            create_time: None,
+            finish_time: None,
+            duration_ms: Some(duration_ms),
+            error_message: if state == "FAILED" { "oom".to_string() } else { String::new() },
+            refreshed_partitions: Vec::new(),
+            refreshed_rows: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_starrocks_task_run() {
+        let columns: Vec<String> = [
+            "QUERY_ID",
+            "TASK_NAME",
+            "CREATE_TIME",
+            "FINISH_TIME",
+            "STATE",
+            "DATABASE",
+            "ERROR_MESSAGE",
+            "EXTRA_MESSAGE",
+            "PROCESS_TIME",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        let row: Vec<String> = [
+            "a-b",
+            "mv-10",
+            "2026-10-18 10:00:00",
+            "2026-10-18 10:02:30",
+            "SUCCESS",
+            "db",
+            "NULL",
+            r#"{"forceRefresh":false,"mvPartitionsToRefresh":["p20261017","p20261018"]}"#,
+            "2026-10-18 10:00:30",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        let mv_by_task =
+            HashMap::from([("mv-10".to_string(), ("sales".to_string(), "mv_orders".to_string()))]);
+
+        let run = parse_task_run_row(&columns, &row, &mv_by_task).unwrap();
+        assert_eq!(run.database, "sales");
+        assert_eq!(run.mv_name, "mv_orders");
+        assert_eq!(run.duration_ms, Some(120_000));
+        assert_eq!(run.refreshed_partitions, vec!["p20261017", "p20261018"]);
+        assert!(run.error_message.is_empty());
+    }
+
+    #[test]
+    fn test_parse_doris_task_row() {
+        let columns: Vec<String> = [
+            "TaskId",
+            "JobName",
+            "MvName",
+            "MvDatabaseName",
+            "Status",
+            "DurationMs",
+            "CompletedPartitions",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        let row: Vec<String> =
+            ["42", "inner_mtmv_1", "mv_orders", "sales", "failed", "1500", "[p1, p2]"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect();
+
+        let run = parse_task_run_row(&columns, &row, &HashMap::new()).unwrap();
+        assert_eq!(run.task_run_id, "42");
+        assert_eq!(run.mv_name, "mv_orders");
+        assert_eq!(run.state, "FAILED");
+        assert_eq!(run.duration_ms, Some(1500));
+        assert_eq!(run.refreshed_partitions, vec!["p1", "p2"]);
+    }
+
+    #[test]
+    fn test_consecutive_failures_flagged() {
+        let runs = vec![run("FAILED", 0), run("FAILED", 0), run("FAILED", 0), run("SUCCESS", 1000)];
+        let alerts = evaluate_alerts(&runs, 3);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, MvRefreshAlertKind::ConsecutiveFailures);
+        assert_eq!(alerts[0].consecutive_failures, 3);
+        assert_eq!(alerts[0].last_error, "oom");
+
+        assert!(evaluate_alerts(&runs[1..], 3).is_empty());
+    }
+
+    #[test]
+    fn test_duration_growth() {
+        let growing = [20_000.0, 26_000.0, 31_000.0, 38_000.0, 45_000.0, 52_000.0];
+        let ratio = duration_growth(&growing).unwrap();
+        assert!(ratio > 2.0, "ratio={}", ratio);
+
+        // Noisy but flat, too few runs, or too short to matter
+        assert!(duration_growth(&[30_000.0, 28_000.0, 31_000.0, 29_000.0, 30_500.0]).is_none());
+        assert!(duration_growth(&growing[..4]).is_none());
+        assert!(duration_growth(&[100.0, 200.0, 300.0, 400.0, 500.0]).is_none());
+
+        // newest first, as evaluate_alerts receives them
+        let runs: Vec<MvRefreshRun> = growing
+            .iter()
+            .rev()
+            .map(|d| run("SUCCESS", *d as i64))
+            .collect();
+        let alerts = evaluate_alerts(&runs, 3);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, MvRefreshAlertKind::GrowingDuration);
+        assert_eq!(alerts[0].latest_duration_ms, Some(52_000));
+    }
+}
@@ -0,0 +1,386 @@
+// Materialized View Usage Service
+// Purpose: Measure how often query rewrite actually used each async MV (audit log hit / candidate
+// MVs and profile planner info) and rank MVs by refresh cost vs benefit to surface drop candidates.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use utoipa::ToSchema;
+
+use crate::models::MaterializedView;
+use crate::services::profile_analyzer::parser::core::SectionParser;
+
+/// Candidate appearances needed before a low hit rate is trusted
+const MIN_CANDIDATES_FOR_RATE: usize = 10;
+
+/// Hit rate (hits / times considered by the optimizer) below which an MV is underused
+const UNDERUSED_HIT_RATE: f64 = 0.1;
+
+/// Refresh time spent per rewritten query above which an MV is underused
+const UNDERUSED_REFRESH_MS_PER_HIT: f64 = 60_000.0;
+
+/// Normalized `key: value` names that list MVs used by the rewrite
+const HIT_KEYS: &[&str] = &[
+    "hitmv",
+    "hitmvs",
+    "mvrewrite",
+    "mvrewrites",
+    "rewrittenmvs",
+    "materializedviewrewrite",
+    "materializedviewrewrites",
+];
+
+/// Normalized `key: value` names that list MVs the optimizer considered
+const CANDIDATE_KEYS: &[&str] = &["candidatemv", "candidatemvs", "mvcandidates"];
+
+/// MVs one query was rewritten to, and the ones the optimizer considered
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MvUsageObservation {
+    pub query_id: Option<String>,
+    pub database: Option<String>,
+    pub hit_mvs: Vec<String>,
+    pub candidate_mvs: Vec<String>,
+}
+
+impl MvUsageObservation {
+    /// From the audit log `hitMvs` / `candidateMvs` columns
+    pub fn from_audit(
+        query_id: &str,
+        database: &str,
+        hit_mvs: &str,
+        candidate_mvs: &str,
+    ) -> Option<Self> {
+        Self {
+            query_id: Some(query_id.to_string()).filter(|id| !id.is_empty()),
+            database: Some(database.to_string()).filter(|db| !db.is_empty()),
+            hit_mvs: split_mv_names(hit_mvs),
+            candidate_mvs: split_mv_names(candidate_mvs),
+        }
+        .non_empty()
+    }
+
+    /// From a query profile: planner info first, then any MV rewrite line of the profile
+    pub fn from_profile(query_id: &str, text: &str) -> Option<Self> {
+        let mut observation = Self {
+            query_id: Some(query_id.to_string()).filter(|id| !id.is_empty()),
+            ..Default::default()
+        };
+
+        if let Ok(planner) = SectionParser::parse_planner(text) {
+            let mut details: Vec<_> = planner.details.iter().collect();
+            details.sort();
+            for (key, value) in details {
+                observation.record(key, value);
+            }
+        }
+        if observation.hit_mvs.is_empty() && observation.candidate_mvs.is_empty() {
+            for line in text.lines() {
+                let line = line.trim().trim_start_matches('-').trim();
+                if let Some((key, value)) = line.split_once(':') {
+                    observation.record(key, value);
+                }
+            }
+        }
+        observation.non_empty()
+    }
+
+    fn record(&mut self, key: &str, value: &str) {
+        let key: String = key
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        let target = if HIT_KEYS.contains(&key.as_str()) {
+            &mut self.hit_mvs
+        } else if CANDIDATE_KEYS.contains(&key.as_str()) {
+            &mut self.candidate_mvs
+        } else {
+            return;
+        };
+        for name in split_mv_names(value) {
+            if !target.contains(&name) {
+                target.push(name);
+            }
+        }
+    }
+
+    fn non_empty(self) -> Option<Self> {
+        (!self.hit_mvs.is_empty() || !self.candidate_mvs.is_empty()).then_some(self)
+    }
+}
+
+/// Refresh effort of one MV over the analysis window
+#[derive(Debug, Clone, Default)]
+pub struct MvRefreshCost {
+    pub database: String,
+    pub mv_name: String,
+    pub runs: i64,
+    pub total_duration_ms: i64,
+}
+
+/// How useful an MV turned out to be
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MvUsageVerdict {
+    /// Never used by query rewrite in a fully observed window
+    DropCandidate,
+    /// Rarely picked, or refresh costs far more than it serves
+    Underused,
+    Useful,
+    /// No rewrite information was available, or coverage was too partial to rule out use
+    Unknown,
+}
+
+/// Usage and cost of one async materialized view
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct MvUsageStats {
+    pub database: String,
+    pub mv_name: String,
+    pub refresh_type: String,
+    pub is_active: bool,
+    /// Queries rewritten to this MV
+    pub hit_count: usize,
+    /// Queries for which the optimizer considered this MV
+    pub candidate_count: usize,
+    /// hit_count / candidate_count, when candidates were reported
+    pub hit_rate: Option<f64>,
+    pub refresh_runs: i64,
+    pub refresh_time_ms: i64,
+    /// Refresh time spent per rewritten query
+    pub refresh_ms_per_hit: Option<f64>,
+    pub verdict: MvUsageVerdict,
+}
+
+/// Attribute observations to MVs and rank them, least useful first.
+///
+/// `complete_coverage` states that the observations cover every query of the window
+/// (untruncated audit log). Without it an MV that was never seen may simply have been
+/// missed, so it is reported as `Unknown` rather than `DropCandidate`.
+pub fn analyze_mv_usage(
+    mvs: &[MaterializedView],
+    observations: &[MvUsageObservation],
+    costs: &[MvRefreshCost],
+    complete_coverage: bool,
+) -> Vec<MvUsageStats> {
+    let mvs: Vec<&MaterializedView> = mvs
+        .iter()
+        .filter(|mv| mv.refresh_type != "ROLLUP")
+        .collect();
+    let mut by_name: HashMap<String, Vec<usize>> = HashMap::new();
+    for (i, mv) in mvs.iter().enumerate() {
+        by_name.entry(mv.name.to_lowercase()).or_default().push(i);
+    }
+    let resolve = |name: &str, database: Option<&str>| -> Option<usize> {
+        let parts: Vec<&str> = name.split('.').collect();
+        let (db, mv_name) = match parts.as_slice() {
+            [.., db, mv] => (Some(*db), *mv),
+            [mv] => (database, *mv),
+            [] => return None,
+        };
+        let matches = by_name.get(&mv_name.to_lowercase())?;
+        match db {
+            Some(db) => matches
+                .iter()
+                .copied()
+                .find(|&i| mvs[i].database_name.eq_ignore_ascii_case(db))
+                .or_else(|| (matches.len() == 1).then_some(matches[0])),
+            None => (matches.len() == 1).then_some(matches[0]),
+        }
+    };
+
+    let mut hits = vec![0usize; mvs.len()];
+    let mut candidates = vec![0usize; mvs.len()];
+    let mut seen = HashSet::new();
+    for observation in observations {
+        if let Some(id) = &observation.query_id
+            && !seen.insert(id.clone())
+        {
+            continue;
+        }
+        let db = observation.database.as_deref();
+        let hit: HashSet<usize> = observation
+            .hit_mvs
+            .iter()
+            .filter_map(|n| resolve(n, db))
+            .collect();
+        let mut considered: HashSet<usize> = observation
+            .candidate_mvs
+            .iter()
+            .filter_map(|n| resolve(n, db))
+            .collect();
+        // An MV that was used was also considered, even if only hits are reported
+        considered.extend(hit.iter().copied());
+        for i in hit {
+            hits[i] += 1;
+        }
+        for i in considered {
+            candidates[i] += 1;
+        }
+    }
+
+    let cost_of = |mv: &MaterializedView| {
+        costs.iter().find(|c| {
+            c.mv_name.eq_ignore_ascii_case(&mv.name)
+                && c.database.eq_ignore_ascii_case(&mv.database_name)
+        })
+    };
+
+    let have_observations = !observations.is_empty();
+    let mut stats: Vec<MvUsageStats> = mvs
+        .iter()
+        .enumerate()
+        .map(|(i, mv)| {
+            let cost = cost_of(mv);
+            let refresh_runs = cost.map(|c| c.runs).unwrap_or(0);
+            let refresh_time_ms = cost.map(|c| c.total_duration_ms).unwrap_or(0);
+            let hit_rate = (candidates[i] > 0).then(|| hits[i] as f64 / candidates[i] as f64);
+            let refresh_ms_per_hit = (hits[i] > 0).then(|| refresh_time_ms as f64 / hits[i] as f64);
+
+            let verdict = if !have_observations {
+                MvUsageVerdict::Unknown
+            } else if hits[i] == 0 {
+                if complete_coverage {
+                    MvUsageVerdict::DropCandidate
+                } else {
+                    MvUsageVerdict::Unknown
+                }
+            } else if (candidates[i] >= MIN_CANDIDATES_FOR_RATE
+                && hit_rate.unwrap_or(1.0) < UNDERUSED_HIT_RATE)
+                || refresh_ms_per_hit.unwrap_or(0.0) > UNDERUSED_REFRESH_MS_PER_HIT
+            {
+                MvUsageVerdict::Underused
+            } else {
+                MvUsageVerdict::Useful
+            };
+
+            MvUsageStats {
+                database: mv.database_name.clone(),
+                mv_name: mv.name.clone(),
+                refresh_type: mv.refresh_type.clone(),
+                is_active: mv.is_active,
+                hit_count: hits[i],
+                candidate_count: candidates[i],
+                hit_rate,
+                refresh_runs,
+                refresh_time_ms,
+                refresh_ms_per_hit,
+                verdict,
+            }
+        })
+        .collect();
+
+    stats.sort_by(|a, b| {
+        a.verdict
+            .cmp(&b.verdict)
+            .then_with(|| b.refresh_time_ms.cmp(&a.refresh_time_ms))
+            .then_with(|| a.hit_count.cmp(&b.hit_count))
+            .then_with(|| (&a.database, &a.mv_name).cmp(&(&b.database, &b.mv_name)))
+    });
+    stats
+}
+
+/// `mv1,db.mv2`, `[mv1, mv2]` or `` `mv1` `` style lists
+fn split_mv_names(value: &str) -> Vec<String> {
+    value
+        .split([',', ';', ' '])
+        .map(|name| {
+            name.trim()
+                .trim_matches(|c| matches!(c, '[' | ']' | '"' | '\'' | '`'))
+                .replace('`', "")
+        })
+        .filter(|name| {
+            !name.is_empty()
+                && !name.eq_ignore_ascii_case("null")
+                && !name.eq_ignore_ascii_case("none")
+                && name.chars().any(|c| c.is_alphabetic())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mv(database: &str, name: &str) -> MaterializedView {
+        MaterializedView {
+            id: name.to_string(),
+            name: name.to_string(),
+            database_name: database.to_string(),
+            refresh_type: "ASYNC".to_string(),
+            is_active: true,
+            partition_type: None,
+            task_id: None,
+            task_name: None,
+            last_refresh_start_time: None,
+            last_refresh_finished_time: None,
+            last_refresh_duration: None,
+            last_refresh_state: None,
+            rows: None,
+            text: String::new(),
+        }
+    }
+
+    fn audit(id: &str, hit: &str, candidates: &str) -> MvUsageObservation {
+        MvUsageObservation::from_audit(id, "sales", hit, candidates).unwrap()
+    }
+
+    #[test]
+    fn test_observation_from_profile_planner() {
+        let text = "Query:\n  Summary:\n     - Query ID: q1\n  Planner:\n     - Total[1] 12ms\n     \
+                    - MV Rewrite: `sales`.`mv_daily`, mv_region\n     - Candidate MVs: [mv_daily, mv_region, mv_city]\n  \
+                    Execution:\n     - Topology: {}\n";
+        let observation = MvUsageObservation::from_profile("q1", text).unwrap();
+        assert_eq!(observation.hit_mvs, vec!["sales.mv_daily", "mv_region"]);
+        assert_eq!(observation.candidate_mvs, vec!["mv_daily", "mv_region", "mv_city"]);
+
+        assert!(
+            MvUsageObservation::from_profile("q2", "Query:\n  Planner:\n     - Total[1] 1ms\n")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_analyze_ranks_drop_candidates_first() {
+        let mvs = vec![mv("sales", "mv_daily"), mv("sales", "mv_city"), mv("sales", "mv_unused")];
+        let mut observations: Vec<MvUsageObservation> = (0..12)
+            .map(|i| audit(&format!("q{}", i), "", "mv_city,mv_daily"))
+            .collect();
+        observations.push(audit("h1", "mv_daily", ""));
+        observations.push(audit("h2", "sales.mv_daily", ""));
+        observations.push(audit("h2", "sales.mv_daily", ""));
+        observations.push(audit("h3", "mv_city", "mv_city"));
+        let costs = vec![MvRefreshCost {
+            database: "sales".to_string(),
+            mv_name: "mv_unused".to_string(),
+            runs: 24,
+            total_duration_ms: 480_000,
+        }];
+
+        let stats = analyze_mv_usage(&mvs, &observations, &costs, true);
+        let names: Vec<&str> = stats.iter().map(|s| s.mv_name.as_str()).collect();
+        assert_eq!(names, vec!["mv_unused", "mv_city", "mv_daily"]);
+
+        assert_eq!(stats[0].verdict, MvUsageVerdict::DropCandidate);
+        assert_eq!(stats[0].refresh_time_ms, 480_000);
+        assert_eq!(stats[1].verdict, MvUsageVerdict::Underused);
+        assert_eq!((stats[1].hit_count, stats[1].candidate_count), (1, 13));
+        assert_eq!(stats[2].verdict, MvUsageVerdict::Useful);
+        assert_eq!(stats[2].hit_count, 2);
+    }
+
+    #[test]
+    fn test_unknown_without_observations() {
+        let stats = analyze_mv_usage(&[mv("sales", "mv_daily")], &[], &[], true);
+        assert_eq!(stats[0].verdict, MvUsageVerdict::Unknown);
+    }
+
+    #[test]
+    fn test_unseen_mv_unknown_with_partial_coverage() {
+        let mvs = vec![mv("sales", "mv_daily"), mv("sales", "mv_unused")];
+        let observations = vec![audit("q1", "mv_daily", "")];
+        let stats = analyze_mv_usage(&mvs, &observations, &[], false);
+        let unused = stats.iter().find(|s| s.mv_name == "mv_unused").unwrap();
+        assert_eq!(unused.verdict, MvUsageVerdict::Unknown);
+        let daily = stats.iter().find(|s| s.mv_name == "mv_daily").unwrap();
+        assert_eq!(daily.verdict, MvUsageVerdict::Useful);
+    }
+}
@@ -74,6 +74,30 @@ impl MySQLPoolManager {
         self.pools.len()
     }
 
+    /// Create a short-lived pool against one specific FE of a cluster
+    ///
+    /// Needed for statements that only act on the connected FE, such as
+    /// `ADMIN SHOW/SET FRONTEND CONFIG`. The pool is not cached; call `disconnect()` when done.
+    pub fn create_node_pool(&self, cluster: &Cluster, host: &str, port: u16) -> ApiResult<Pool> {
+        let opts = OptsBuilder::default()
+            .ip_or_hostname(host)
+            .tcp_port(port)
+            .user(Some(&cluster.username))
+            .pass(cluster.get_auth_password())
+            .db_name(None::<String>)
+            .prefer_socket(false)
+            .ssl_opts(None::<SslOpts>)
+            .pool_opts(mysql_async::PoolOpts::default().with_constraints(
+                mysql_async::PoolConstraints::new(0, 2).ok_or_else(|| {
+                    crate::utils::ApiError::internal_error(
+                        "Failed to create pool constraints: invalid min/max values",
+                    )
+                })?,
+            ));
+
+        Ok(Pool::new(opts))
+    }
+
     /// Create a new MySQL connection pool for a cluster
     async fn create_pool(&self, cluster: &Cluster) -> ApiResult<Pool> {
         let opts = OptsBuilder::default()
@@ -0,0 +1,711 @@
+// Node Config Service
+// Purpose: Per-node FE / BE configuration inventory, in-cluster divergence detection and
+// tracked config changes (with rollback) through ADMIN SET FRONTEND CONFIG / BE update_config.
+
+use crate::models::{Backend, Cluster, ClusterType, Frontend};
+use crate::services::compaction_service::{be_base_url, be_reports_success};
+use crate::services::{MySQLClient, MySQLPoolManager, create_adapter};
+use crate::utils::{ApiError, ApiResult, RowView};
+use chrono::{NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ToSchema)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum NodeType {
+    Fe,
+    Be,
+}
+
+impl NodeType {
+    fn as_str(self) -> &'static str {
+        match self {
+            NodeType::Fe => "FE",
+            NodeType::Be => "BE",
+        }
+    }
+}
+
+/// One config value on one node
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct NodeConfigEntry {
+    pub node_type: NodeType,
+    /// `host:port` (FE query port / BE HTTP port)
+    pub node: String,
+    pub name: String,
+    pub value: String,
+    pub mutable: Option<bool>,
+}
+
+/// Normalized config table of all nodes of a cluster
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct NodeConfigInventory {
+    pub entries: Vec<NodeConfigEntry>,
+    /// Nodes whose config could not be fetched
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct ConfigValueGroup {
+    pub value: String,
+    pub nodes: Vec<String>,
+}
+
+/// A config whose value differs between nodes of the same type
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct ConfigDivergence {
+    pub node_type: NodeType,
+    pub name: String,
+    /// Value shared by most nodes
+    pub majority_value: String,
+    /// Value groups, most common first
+    pub groups: Vec<ConfigValueGroup>,
+}
+
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct NodeConfigChangeRequest {
+    pub node_type: NodeType,
+    pub name: String,
+    pub value: String,
+    /// Target nodes (`host:port`); all alive nodes of the type when empty
+    #[serde(default)]
+    pub nodes: Vec<String>,
+    /// Persist to be.conf (Doris BE only). FE changes are runtime-only.
+    #[serde(default)]
+    pub persist: bool,
+}
+
+/// Tracked config change of one node
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, sqlx::FromRow)]
+pub struct NodeConfigChange {
+    pub id: i64,
+    pub cluster_id: i64,
+    pub node_type: String,
+    pub node: String,
+    pub config_name: String,
+    pub old_value: Option<String>,
+    pub new_value: String,
+    /// Whether the change survives a node restart
+    pub persisted: bool,
+    /// applied, failed, rolled_back
+    pub status: String,
+    pub error_message: Option<String>,
+    pub changed_by: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub rolled_back_at: Option<NaiveDateTime>,
+}
+
+/// A node addressed by config operations
+enum ConfigNode {
+    Fe(Box<Frontend>),
+    Be(Box<Backend>),
+}
+
+impl ConfigNode {
+    fn node_type(&self) -> NodeType {
+        match self {
+            ConfigNode::Fe(_) => NodeType::Fe,
+            ConfigNode::Be(_) => NodeType::Be,
+        }
+    }
+
+    fn address(&self) -> String {
+        match self {
+            ConfigNode::Fe(fe) => format!("{}:{}", fe.host, fe.query_port),
+            ConfigNode::Be(be) => format!("{}:{}", be.host, be.http_port),
+        }
+    }
+
+    fn alive(&self) -> bool {
+        match self {
+            ConfigNode::Fe(fe) => fe.alive.eq_ignore_ascii_case("true"),
+            ConfigNode::Be(be) => be.alive.eq_ignore_ascii_case("true"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct NodeConfigService {
+    db: SqlitePool,
+    mysql_pool_manager: Arc<MySQLPoolManager>,
+    http_client: reqwest::Client,
+}
+
+impl NodeConfigService {
+    pub fn new(db: SqlitePool, mysql_pool_manager: Arc<MySQLPoolManager>) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+        Self { db, mysql_pool_manager, http_client }
+    }
+
+    /// Collect the configs of every FE and BE of a cluster
+    pub async fn get_inventory(
+        &self,
+        cluster: &Cluster,
+        node_type: Option<NodeType>,
+        name_filter: Option<&str>,
+    ) -> ApiResult<NodeConfigInventory> {
+        let mut entries = Vec::new();
+        let mut errors = Vec::new();
+
+        if node_type.is_none_or(|t| t == NodeType::Fe) {
+            for node in self.list_nodes(cluster, NodeType::Fe).await? {
+                if !node.alive() {
+                    errors.push(format!("FE {} is not alive", node.address()));
+                    continue;
+                }
+                match self.fetch_node_configs(cluster, &node).await {
+                    Ok(mut configs) => entries.append(&mut configs),
+                    Err(e) => errors.push(format!("FE {}: {}", node.address(), e)),
+                }
+            }
+        }
+
+        if node_type.is_none_or(|t| t == NodeType::Be) {
+            match cluster.cluster_type {
+                ClusterType::StarRocks => match self.fetch_starrocks_be_configs(cluster).await {
+                    Ok(mut configs) => entries.append(&mut configs),
+                    Err(e) => errors.push(format!("BE: {}", e)),
+                },
+                ClusterType::Doris => {
+                    for node in self.list_nodes(cluster, NodeType::Be).await? {
+                        if !node.alive() {
+                            errors.push(format!("BE {} is not alive", node.address()));
+                            continue;
+                        }
+                        match self.fetch_node_configs(cluster, &node).await {
+                            Ok(mut configs) => entries.append(&mut configs),
+                            Err(e) => errors.push(format!("BE {}: {}", node.address(), e)),
+                        }
+                    }
+                },
+            }
+        }
+
+        if let Some(filter) = name_filter.filter(|f| !f.is_empty()) {
+            let filter = filter.to_lowercase();
+            entries.retain(|e| e.name.to_lowercase().contains(&filter));
+        }
+        entries
+            .sort_by(|a, b| (a.node_type, &a.name, &a.node).cmp(&(b.node_type, &b.name, &b.node)));
+
+        Ok(NodeConfigInventory { entries, errors })
+    }
+
+    /// Configs whose values differ between nodes of the same type
+    pub async fn get_divergence(&self, cluster: &Cluster) -> ApiResult<Vec<ConfigDivergence>> {
+        let inventory = self.get_inventory(cluster, None, None).await?;
+        Ok(detect_divergence(&inventory.entries))
+    }
+
+    /// Apply a config change to the target nodes and record one change per node
+    pub async fn apply_change(
+        &self,
+        cluster: &Cluster,
+        req: &NodeConfigChangeRequest,
+        changed_by: &str,
+    ) -> ApiResult<Vec<NodeConfigChange>> {
+        validate_config(&req.name, &req.value)?;
+
+        let mut targets: Vec<ConfigNode> =
+            self.list_nodes(cluster, req.node_type)
+                .await?
+                .into_iter()
+                .filter(|n| {
+                    if req.nodes.is_empty() { n.alive() } else { req.nodes.contains(&n.address()) }
+                })
+                .collect();
+        if targets.is_empty() {
+            return Err(ApiError::not_found("No matching node"));
+        }
+        targets.sort_by_key(|n| n.address());
+
+        let persist = req.persist
+            && req.node_type == NodeType::Be
+            && cluster.cluster_type == ClusterType::Doris;
+
+        let mut changes = Vec::with_capacity(targets.len());
+        for node in &targets {
+            let old_value = self
+                .fetch_node_configs(cluster, node)
+                .await
+                .ok()
+                .and_then(|configs| configs.into_iter().find(|c| c.name == req.name))
+                .map(|c| c.value);
+            let result = self
+                .set_node_config(cluster, node, &req.name, &req.value, persist)
+                .await;
+            let id = self
+                .record_change(
+                    cluster.id,
+                    node,
+                    &req.name,
+                    old_value.as_deref(),
+                    &req.value,
+                    persist,
+                    result.as_ref().err().map(|e| e.to_string()),
+                    changed_by,
+                )
+                .await?;
+            changes.push(self.get_change(cluster.id, id).await?);
+        }
+
+        tracing::info!(
+            "User {} set {} {}={} on cluster {}: {}/{} nodes applied",
+            changed_by,
+            req.node_type.as_str(),
+            req.name,
+            req.value,
+            cluster.name,
+            changes.iter().filter(|c| c.status == "applied").count(),
+            changes.len()
+        );
+        Ok(changes)
+    }
+
+    /// Restore the previous value of an applied change
+    pub async fn rollback_change(
+        &self,
+        cluster: &Cluster,
+        change_id: i64,
+        changed_by: &str,
+    ) -> ApiResult<NodeConfigChange> {
+        let change = self.get_change(cluster.id, change_id).await?;
+        if change.status != "applied" {
+            return Err(ApiError::validation_error(format!(
+                "Change {} is {}, only applied changes can be rolled back",
+                change_id, change.status
+            )));
+        }
+        let old_value = change.old_value.clone().ok_or_else(|| {
+            ApiError::validation_error("Previous value is unknown, cannot roll back")
+        })?;
+
+        let node_type = if change.node_type == "FE" { NodeType::Fe } else { NodeType::Be };
+        let node = self
+            .list_nodes(cluster, node_type)
+            .await?
+            .into_iter()
+            .find(|n| n.address() == change.node)
+            .ok_or_else(|| ApiError::not_found(format!("Node {} not found", change.node)))?;
+
+        self.set_node_config(cluster, &node, &change.config_name, &old_value, change.persisted)
+            .await?;
+
+        sqlx::query(
+            "UPDATE node_config_changes SET status = 'rolled_back', rolled_back_at = ? WHERE id = ?",
+        )
+        .bind(Utc::now().naive_utc())
+        .bind(change_id)
+        .execute(&self.db)
+        .await?;
+
+        tracing::info!(
+            "User {} rolled back {} {} on {} to {}",
+            changed_by,
+            change.node_type,
+            change.config_name,
+            change.node,
+            old_value
+        );
+        self.get_change(cluster.id, change_id).await
+    }
+
+    /// Change history of a cluster (newest first)
+    pub async fn list_changes(
+        &self,
+        cluster_id: i64,
+        name: Option<&str>,
+        limit: i64,
+    ) -> ApiResult<Vec<NodeConfigChange>> {
+        let changes = sqlx::query_as::<_, NodeConfigChange>(
+            r#"
+            SELECT * FROM node_config_changes
+            WHERE cluster_id = ? AND (? IS NULL OR config_name = ?)
+            ORDER BY created_at DESC, id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(name)
+        .bind(name)
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+        Ok(changes)
+    }
+
+    async fn get_change(&self, cluster_id: i64, id: i64) -> ApiResult<NodeConfigChange> {
+        sqlx::query_as::<_, NodeConfigChange>(
+            "SELECT * FROM node_config_changes WHERE id = ? AND cluster_id = ?",
+        )
+        .bind(id)
+        .bind(cluster_id)
+        .fetch_optional(&self.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found(format!("Config change {} not found", id)))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn record_change(
+        &self,
+        cluster_id: i64,
+        node: &ConfigNode,
+        name: &str,
+        old_value: Option<&str>,
+        new_value: &str,
+        persisted: bool,
+        error: Option<String>,
+        changed_by: &str,
+    ) -> ApiResult<i64> {
+        let status = if error.is_some() { "failed" } else { "applied" };
+        let result = sqlx::query(
+            r#"
+            INSERT INTO node_config_changes (
+                cluster_id, node_type, node, config_name, old_value, new_value, persisted,
+                status, error_message, changed_by, created_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(node.node_type().as_str())
+        .bind(node.address())
+        .bind(name)
+        .bind(old_value)
+        .bind(new_value)
+        .bind(persisted && error.is_none())
+        .bind(status)
+        .bind(error)
+        .bind(changed_by)
+        .bind(Utc::now().naive_utc())
+        .execute(&self.db)
+        .await?;
+        Ok(result.last_insert_rowid())
+    }
+
+    async fn list_nodes(
+        &self,
+        cluster: &Cluster,
+        node_type: NodeType,
+    ) -> ApiResult<Vec<ConfigNode>> {
+        let adapter = create_adapter(cluster.clone(), self.mysql_pool_manager.clone());
+        Ok(match node_type {
+            NodeType::Fe => adapter
+                .get_frontends()
+                .await?
+                .into_iter()
+                .map(|fe| ConfigNode::Fe(Box::new(fe)))
+                .collect(),
+            NodeType::Be => adapter
+                .get_backends()
+                .await?
+                .into_iter()
+                .map(|be| ConfigNode::Be(Box::new(be)))
+                .collect(),
+        })
+    }
+
+    async fn fetch_node_configs(
+        &self,
+        cluster: &Cluster,
+        node: &ConfigNode,
+    ) -> ApiResult<Vec<NodeConfigEntry>> {
+        match node {
+            ConfigNode::Fe(fe) => {
+                let (columns, rows) = self
+                    .query_fe(cluster, fe, "ADMIN SHOW FRONTEND CONFIG")
+                    .await?;
+                Ok(rows
+                    .iter()
+                    .filter_map(|row| {
+                        let v = RowView::new(&columns, row);
+                        Some(NodeConfigEntry {
+                            node_type: NodeType::Fe,
+                            node: node.address(),
+                            name: v.get(&["Key", "Name"])?.to_string(),
+                            value: v.string(&["Value"]),
+                            mutable: v.get(&["IsMutable", "Mutable"]).map(parse_bool),
+                        })
+                    })
+                    .collect())
+            },
+            ConfigNode::Be(be) => {
+                // StarRocks BE exposes `name=value` lines on /varz, Doris BE a JSON table
+                let path = match cluster.cluster_type {
+                    ClusterType::StarRocks => "varz",
+                    ClusterType::Doris => "api/show_config",
+                };
+                let url = format!("{}/{}", be_base_url(cluster, be), path);
+                let resp = self
+                    .http_client
+                    .get(&url)
+                    .basic_auth(&cluster.username, Some(&cluster.password_encrypted))
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        ApiError::cluster_connection_failed(format!("Request failed: {}", e))
+                    })?;
+                let body = resp.text().await.map_err(|e| {
+                    ApiError::cluster_connection_failed(format!("Read failed: {}", e))
+                })?;
+                Ok(match cluster.cluster_type {
+                    ClusterType::StarRocks => parse_varz(&body, &node.address()),
+                    ClusterType::Doris => serde_json::from_str(&body)
+                        .map(|json| parse_be_show_config(&json, &node.address()))
+                        .map_err(|e| {
+                            ApiError::cluster_connection_failed(format!("Invalid response: {}", e))
+                        })?,
+                })
+            },
+        }
+    }
+
+    async fn fetch_starrocks_be_configs(
+        &self,
+        cluster: &Cluster,
+    ) -> ApiResult<Vec<NodeConfigEntry>> {
+        let addresses: HashMap<String, String> = self
+            .list_nodes(cluster, NodeType::Be)
+            .await?
+            .into_iter()
+            .filter_map(|n| match &n {
+                ConfigNode::Be(be) => Some((be.backend_id.clone(), n.address())),
+                ConfigNode::Fe(_) => None,
+            })
+            .collect();
+
+        let pool = self.mysql_pool_manager.get_pool(cluster).await?;
+        let client = MySQLClient::from_pool(pool);
+        let (columns, rows) = client
+            .query_raw("SELECT BE_ID, NAME, VALUE, MUTABLE FROM information_schema.be_configs")
+            .await?;
+        Ok(rows
+            .iter()
+            .filter_map(|row| {
+                let v = RowView::new(&columns, row);
+                let be_id = v.string(&["BE_ID"]);
+                Some(NodeConfigEntry {
+                    node_type: NodeType::Be,
+                    node: addresses.get(&be_id).cloned().unwrap_or(be_id),
+                    name: v.get(&["NAME"])?.to_string(),
+                    value: v.string(&["VALUE"]),
+                    mutable: v.get(&["MUTABLE"]).map(parse_bool),
+                })
+            })
+            .collect())
+    }
+
+    async fn set_node_config(
+        &self,
+        cluster: &Cluster,
+        node: &ConfigNode,
+        name: &str,
+        value: &str,
+        persist: bool,
+    ) -> ApiResult<()> {
+        match node {
+            ConfigNode::Fe(fe) => {
+                let sql = format!("ADMIN SET FRONTEND CONFIG (\"{}\" = \"{}\")", name, value);
+                self.query_fe(cluster, fe, &sql).await?;
+                Ok(())
+            },
+            ConfigNode::Be(be) => {
+                let mut url =
+                    reqwest::Url::parse(&format!("{}/api/update_config", be_base_url(cluster, be)))
+                        .map_err(|e| {
+                            ApiError::internal_error(format!("Invalid BE address: {}", e))
+                        })?;
+                url.query_pairs_mut().append_pair(name, value);
+                if persist {
+                    url.query_pairs_mut().append_pair("persist", "true");
+                }
+                let resp = self
+                    .http_client
+                    .post(url)
+                    .basic_auth(&cluster.username, Some(&cluster.password_encrypted))
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        ApiError::cluster_connection_failed(format!("Request failed: {}", e))
+                    })?;
+                let status = resp.status();
+                let body = resp.text().await.unwrap_or_default();
+                if status.is_success() && be_reports_success(&body) {
+                    Ok(())
+                } else {
+                    Err(ApiError::cluster_connection_failed(format!(
+                        "update_config failed on {}: {}",
+                        node.address(),
+                        body.trim()
+                    )))
+                }
+            },
+        }
+    }
+
+    /// Run a statement on one specific FE (config statements only affect the connected FE)
+    async fn query_fe(
+        &self,
+        cluster: &Cluster,
+        fe: &Frontend,
+        sql: &str,
+    ) -> ApiResult<(Vec<String>, Vec<Vec<String>>)> {
+        let port: u16 = fe.query_port.parse().map_err(|_| {
+            ApiError::invalid_data(format!("Invalid FE query port: {}", fe.query_port))
+        })?;
+        let pool = self
+            .mysql_pool_manager
+            .create_node_pool(cluster, &fe.host, port)?;
+        let result = MySQLClient::from_pool(pool.clone()).query_raw(sql).await;
+        if let Err(e) = pool.disconnect().await {
+            tracing::debug!("Failed to disconnect FE pool {}:{}: {}", fe.host, port, e);
+        }
+        result
+    }
+}
+
+fn parse_bool(s: &str) -> bool {
+    s.eq_ignore_ascii_case("true") || s == "1"
+}
+
+/// Config names are plain identifiers; values must not break out of the quoted SQL literal
+fn validate_config(name: &str, value: &str) -> ApiResult<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(ApiError::validation_error(format!("Invalid config name: {}", name)));
+    }
+    if value.is_empty() || value.contains(['"', '\\', '\n', '\r']) {
+        return Err(ApiError::validation_error("Config value is empty or contains quotes"));
+    }
+    Ok(())
+}
+
+/// Parse BE `/api/show_config` (`[[name, type, value, mutable], ...]`)
+fn parse_be_show_config(body: &serde_json::Value, node: &str) -> Vec<NodeConfigEntry> {
+    body.as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.as_array()?;
+            Some(NodeConfigEntry {
+                node_type: NodeType::Be,
+                node: node.to_string(),
+                name: entry.first()?.as_str()?.to_string(),
+                value: entry.get(2)?.as_str()?.to_string(),
+                mutable: entry.get(3).and_then(|m| m.as_str()).map(parse_bool),
+            })
+        })
+        .collect()
+}
+
+/// Parse StarRocks BE `/varz` (`name=value` per line)
+fn parse_varz(body: &str, node: &str) -> Vec<NodeConfigEntry> {
+    body.lines()
+        .filter_map(|line| {
+            let (name, value) = line.split_once('=')?;
+            let name = name.trim();
+            (!name.is_empty() && !name.starts_with('#')).then(|| NodeConfigEntry {
+                node_type: NodeType::Be,
+                node: node.to_string(),
+                name: name.to_string(),
+                value: value.trim().to_string(),
+                mutable: None,
+            })
+        })
+        .collect()
+}
+
+/// Group values per (node type, config) and report configs with more than one distinct value
+fn detect_divergence(entries: &[NodeConfigEntry]) -> Vec<ConfigDivergence> {
+    let mut by_config: BTreeMap<(NodeType, &str), BTreeMap<&str, Vec<String>>> = BTreeMap::new();
+    for e in entries {
+        by_config
+            .entry((e.node_type, e.name.as_str()))
+            .or_default()
+            .entry(e.value.as_str())
+            .or_default()
+            .push(e.node.clone());
+    }
+
+    by_config
+        .into_iter()
+        .filter(|(_, values)| values.len() > 1)
+        .map(|((node_type, name), values)| {
+            let mut groups: Vec<ConfigValueGroup> = values
+                .into_iter()
+                .map(|(value, nodes)| ConfigValueGroup { value: value.to_string(), nodes })
+                .collect();
+            groups.sort_by(|a, b| b.nodes.len().cmp(&a.nodes.len()));
+            ConfigDivergence {
+                node_type,
+                name: name.to_string(),
+                majority_value: groups[0].value.clone(),
+                groups,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(node: &str, name: &str, value: &str) -> NodeConfigEntry {
+        NodeConfigEntry {
+            node_type: NodeType::Be,
+            node: node.to_string(),
+            name: name.to_string(),
+            value: value.to_string(),
+            mutable: Some(true),
+        }
+    }
+
+    #[test]
+    fn test_detect_divergence_reports_minority_nodes() {
+        let entries = vec![
+            entry("be1:8040", "compact_threads", "4"),
+            entry("be2:8040", "compact_threads", "4"),
+            entry("be3:8040", "compact_threads", "16"),
+            entry("be1:8040", "be_port", "9060"),
+            entry("be2:8040", "be_port", "9060"),
+            entry("be3:8040", "be_port", "9060"),
+        ];
+        let divergences = detect_divergence(&entries);
+        assert_eq!(divergences.len(), 1);
+        assert_eq!(divergences[0].name, "compact_threads");
+        assert_eq!(divergences[0].majority_value, "4");
+        assert_eq!(divergences[0].groups[1].nodes, vec!["be3:8040".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_be_show_config() {
+        let body = serde_json::json!([
+            ["compact_threads", "int32", "4", "true"],
+            ["be_port", "int32", "9060", "false"]
+        ]);
+        let entries = parse_be_show_config(&body, "be1:8040");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].value, "4");
+        assert_eq!(entries[1].mutable, Some(false));
+    }
+
+    #[test]
+    fn test_parse_varz() {
+        let entries =
+            parse_varz("compact_threads=4\nstorage_root_path=/data1;/data2\n\n", "be1:8040");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].name, "storage_root_path");
+        assert_eq!(entries[1].value, "/data1;/data2");
+    }
+
+    #[test]
+    fn test_validate_config() {
+        assert!(validate_config("max_routine_load_task_num_per_be", "16").is_ok());
+        assert!(validate_config("bad name", "1").is_err());
+        assert!(validate_config("qe_max_connection", "1\") OR (\"").is_err());
+    }
+}
@@ -0,0 +1,822 @@
+// Partition Lifecycle Service
+// Purpose: Inspect dynamic partition / TTL settings, find tables that keep old partitions
+// forever, and drop expired partitions through an audited (and optionally approved) workflow.
+
+use crate::models::{Cluster, ClusterType};
+use crate::services::{ClusterService, MySQLClient, MySQLPoolManager, create_adapter};
+use crate::utils::system_databases::{SYSTEM_DATABASES, list_user_databases};
+use crate::utils::{ApiError, ApiResult};
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+/// Dynamic partition `start` value meaning "never drop history partitions"
+const DYNAMIC_PARTITION_NO_START: i64 = i32::MIN as i64;
+
+/// Default number of DROP PARTITION statements per batch
+const DEFAULT_DROP_BATCH_SIZE: usize = 20;
+
+/// Upper bound of tables inspected by a single TTL candidate scan
+const MAX_TABLES_PER_SCAN: usize = 500;
+
+/// Dynamic partition settings of a table (from SHOW DYNAMIC PARTITION TABLES)
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct DynamicPartitionSetting {
+    pub database: String,
+    pub table: String,
+    pub enabled: bool,
+    pub time_unit: String,
+    /// Offset of the oldest partition kept, None means history is never dropped
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+    pub prefix: String,
+    pub buckets: Option<i64>,
+    pub state: String,
+    pub last_drop_partition_msg: Option<String>,
+}
+
+/// A single partition with its time range resolved
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct PartitionInfo {
+    pub name: String,
+    /// Lower bound of the range (or first list value)
+    pub lower_bound: Option<String>,
+    /// Upper bound of the range (exclusive)
+    pub upper_bound: Option<String>,
+    pub data_size: String,
+    pub row_count: Option<i64>,
+}
+
+/// A table without partition TTL whose oldest partition exceeds the threshold
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct PartitionTtlCandidate {
+    pub database: String,
+    pub table: String,
+    pub partition_count: usize,
+    pub oldest_partition: String,
+    pub oldest_partition_date: String,
+    pub oldest_age_days: i64,
+    /// Partitions entirely older than the threshold
+    pub expired_partition_count: usize,
+    pub data_size_bytes: i64,
+}
+
+/// Preview of a DROP PARTITION operation
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct PartitionDropPreview {
+    pub database: String,
+    pub table: String,
+    pub partitions: Vec<PartitionInfo>,
+    /// Statements grouped into execution batches
+    pub batches: Vec<Vec<String>>,
+}
+
+/// Request to preview or drop partitions
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct PartitionDropRequest {
+    pub database: String,
+    pub table: String,
+    /// Drop partitions whose upper bound is older than this many days
+    pub older_than_days: Option<i64>,
+    /// Explicit partition names (takes precedence over older_than_days)
+    pub partitions: Option<Vec<String>>,
+    pub batch_size: Option<usize>,
+    pub reason: Option<String>,
+}
+
+/// Audit record of a partition drop request
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, sqlx::FromRow)]
+pub struct PartitionDropRecord {
+    pub id: i64,
+    pub cluster_id: i64,
+    pub organization_id: Option<i64>,
+    pub database_name: String,
+    pub table_name: String,
+    /// JSON array of partition names
+    pub partitions: String,
+    /// JSON array of statement batches
+    pub statements: String,
+    pub reason: Option<String>,
+    /// pending | rejected | executing | completed | failed
+    pub status: String,
+    pub requested_by: i64,
+    pub requested_by_name: String,
+    pub approver_id: Option<i64>,
+    pub approver_name: Option<String>,
+    pub approval_comment: Option<String>,
+    pub execution_result: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub executed_at: Option<NaiveDateTime>,
+}
+
+/// Requester identity passed down from the handler
+pub struct PartitionRequester<'a> {
+    pub user_id: i64,
+    pub username: &'a str,
+    pub organization_id: Option<i64>,
+    pub is_super_admin: bool,
+}
+
+#[derive(Clone)]
+pub struct PartitionService {
+    db: SqlitePool,
+    cluster_service: Arc<ClusterService>,
+    mysql_pool_manager: Arc<MySQLPoolManager>,
+}
+
+impl PartitionService {
+    pub fn new(
+        db: SqlitePool,
+        cluster_service: Arc<ClusterService>,
+        mysql_pool_manager: Arc<MySQLPoolManager>,
+    ) -> Self {
+        Self { db, cluster_service, mysql_pool_manager }
+    }
+
+    /// List dynamic partition settings of all tables in the given (or every user) database
+    pub async fn list_dynamic_partitions(
+        &self,
+        cluster: &Cluster,
+        database: Option<&str>,
+    ) -> ApiResult<Vec<DynamicPartitionSetting>> {
+        if let Some(db) = database {
+            validate_identifier(db)?;
+        }
+        let client = self.mysql_client(cluster).await?;
+        let databases = match database {
+            Some(db) => vec![db.to_string()],
+            None => list_user_databases(&client).await?,
+        };
+
+        let mut settings = Vec::new();
+        for db in databases {
+            let sql = format!("SHOW DYNAMIC PARTITION TABLES FROM `{}`", db);
+            let (columns, rows) = match client.query_raw(&sql).await {
+                Ok(result) => result,
+                Err(e) => {
+                    tracing::warn!("SHOW DYNAMIC PARTITION TABLES failed for {}: {}", db, e);
+                    continue;
+                },
+            };
+            settings.extend(Self::parse_dynamic_partition_rows(&db, &columns, &rows));
+        }
+
+        Ok(settings)
+    }
+
+    /// Find tables without partition TTL whose oldest partition is older than `days`
+    pub async fn find_ttl_candidates(
+        &self,
+        cluster: &Cluster,
+        database: Option<&str>,
+        days: i64,
+    ) -> ApiResult<Vec<PartitionTtlCandidate>> {
+        let client = self.mysql_client(cluster).await?;
+
+        let dynamic = self.list_dynamic_partitions(cluster, database).await?;
+        let mut ttl_tables: std::collections::HashSet<(String, String)> = dynamic
+            .iter()
+            .filter(|s| s.enabled && s.start.is_some())
+            .map(|s| (s.database.clone(), s.table.clone()))
+            .collect();
+        if cluster.cluster_type == ClusterType::StarRocks {
+            ttl_tables.extend(Self::list_starrocks_ttl_tables(&client, database).await);
+        }
+
+        let tables = Self::list_tables_by_size(&client, database).await?;
+        let cutoff = Utc::now().date_naive() - chrono::Duration::days(days);
+
+        let mut candidates = Vec::new();
+        for (db, table, size) in tables {
+            if ttl_tables.contains(&(db.clone(), table.clone())) {
+                continue;
+            }
+
+            let partitions = match Self::show_partitions(&client, &db, &table).await {
+                Ok(p) => p,
+                Err(e) => {
+                    tracing::debug!("SHOW PARTITIONS failed for {}.{}: {}", db, table, e);
+                    continue;
+                },
+            };
+            // Unpartitioned tables expose a single implicit partition
+            if partitions.len() < 2 {
+                continue;
+            }
+
+            let oldest = partitions
+                .iter()
+                .filter_map(|p| {
+                    p.lower_bound
+                        .as_deref()
+                        .and_then(parse_partition_date)
+                        .map(|d| (p, d))
+                })
+                .min_by_key(|(_, d)| *d);
+
+            if let Some((partition, oldest_date)) = oldest
+                && oldest_date < cutoff
+            {
+                let expired = partitions
+                    .iter()
+                    .filter(|p| is_partition_expired(p, cutoff))
+                    .count();
+                candidates.push(PartitionTtlCandidate {
+                    database: db.clone(),
+                    table: table.clone(),
+                    partition_count: partitions.len(),
+                    oldest_partition: partition.name.clone(),
+                    oldest_partition_date: oldest_date.to_string(),
+                    oldest_age_days: (Utc::now().date_naive() - oldest_date).num_days(),
+                    expired_partition_count: expired,
+                    data_size_bytes: size,
+                });
+            }
+        }
+
+        candidates.sort_by(|a, b| b.oldest_age_days.cmp(&a.oldest_age_days));
+        Ok(candidates)
+    }
+
+    /// Resolve the partitions to drop and build the batched statements
+    pub async fn preview_drop(
+        &self,
+        cluster: &Cluster,
+        req: &PartitionDropRequest,
+    ) -> ApiResult<PartitionDropPreview> {
+        validate_identifier(&req.database)?;
+        validate_identifier(&req.table)?;
+
+        let client = self.mysql_client(cluster).await?;
+        let all_partitions = Self::show_partitions(&client, &req.database, &req.table).await?;
+
+        let partitions: Vec<PartitionInfo> = if let Some(names) = &req.partitions {
+            let missing: Vec<&String> = names
+                .iter()
+                .filter(|n| !all_partitions.iter().any(|p| &p.name == *n))
+                .collect();
+            if !missing.is_empty() {
+                return Err(ApiError::validation_error(format!(
+                    "Partitions not found in {}.{}: {:?}",
+                    req.database, req.table, missing
+                )));
+            }
+            all_partitions
+                .into_iter()
+                .filter(|p| names.contains(&p.name))
+                .collect()
+        } else if let Some(days) = req.older_than_days {
+            if days <= 0 {
+                return Err(ApiError::validation_error("older_than_days must be positive"));
+            }
+            let cutoff = Utc::now().date_naive() - chrono::Duration::days(days);
+            all_partitions
+                .into_iter()
+                .filter(|p| is_partition_expired(p, cutoff))
+                .collect()
+        } else {
+            return Err(ApiError::validation_error(
+                "Either partitions or older_than_days must be provided",
+            ));
+        };
+
+        let names: Vec<String> = partitions.iter().map(|p| p.name.clone()).collect();
+        let batches = build_drop_batches(
+            &req.database,
+            &req.table,
+            &names,
+            req.batch_size.unwrap_or(DEFAULT_DROP_BATCH_SIZE),
+        );
+
+        Ok(PartitionDropPreview {
+            database: req.database.clone(),
+            table: req.table.clone(),
+            partitions,
+            batches,
+        })
+    }
+
+    /// Submit a drop request. Admins execute immediately, other users wait for approval.
+    pub async fn submit_drop(
+        &self,
+        cluster: &Cluster,
+        requester: &PartitionRequester<'_>,
+        req: &PartitionDropRequest,
+    ) -> ApiResult<PartitionDropRecord> {
+        let preview = self.preview_drop(cluster, req).await?;
+        if preview.partitions.is_empty() {
+            return Err(ApiError::validation_error("No partitions match the request"));
+        }
+
+        let names: Vec<&str> = preview.partitions.iter().map(|p| p.name.as_str()).collect();
+        let record_id: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO partition_drop_requests (
+                cluster_id, organization_id, database_name, table_name, partitions, statements,
+                reason, status, requested_by, requested_by_name
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, 'pending', ?, ?)
+            RETURNING id
+            "#,
+        )
+        .bind(cluster.id)
+        .bind(requester.organization_id)
+        .bind(&preview.database)
+        .bind(&preview.table)
+        .bind(serde_json::to_string(&names)?)
+        .bind(serde_json::to_string(&preview.batches)?)
+        .bind(&req.reason)
+        .bind(requester.user_id)
+        .bind(requester.username)
+        .fetch_one(&self.db)
+        .await?;
+
+        tracing::info!(
+            "Partition drop request {} submitted by {} for {}.{} ({} partitions)",
+            record_id,
+            requester.username,
+            preview.database,
+            preview.table,
+            names.len()
+        );
+
+        if self.is_admin(requester).await? {
+            self.mark_approved(record_id, requester, Some("auto-approved (admin)"))
+                .await?;
+            self.execute_record(record_id).await?;
+        }
+
+        self.get_record(record_id).await
+    }
+
+    /// Approve a pending request and execute it
+    pub async fn approve_drop(
+        &self,
+        record_id: i64,
+        approver: &PartitionRequester<'_>,
+        comment: Option<&str>,
+    ) -> ApiResult<PartitionDropRecord> {
+        self.check_approver(record_id, approver).await?;
+        self.mark_approved(record_id, approver, comment).await?;
+        self.execute_record(record_id).await?;
+        self.get_record(record_id).await
+    }
+
+    /// Reject a pending request
+    pub async fn reject_drop(
+        &self,
+        record_id: i64,
+        approver: &PartitionRequester<'_>,
+        comment: Option<&str>,
+    ) -> ApiResult<PartitionDropRecord> {
+        self.check_approver(record_id, approver).await?;
+        sqlx::query(
+            "UPDATE partition_drop_requests SET status = 'rejected', approver_id = ?,
+             approver_name = ?, approval_comment = ?, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND status = 'pending'",
+        )
+        .bind(approver.user_id)
+        .bind(approver.username)
+        .bind(comment)
+        .bind(record_id)
+        .execute(&self.db)
+        .await?;
+        self.get_record(record_id).await
+    }
+
+    /// List drop requests of a cluster, newest first
+    pub async fn list_records(
+        &self,
+        cluster_id: i64,
+        status: Option<&str>,
+        limit: i64,
+    ) -> ApiResult<Vec<PartitionDropRecord>> {
+        let records = sqlx::query_as::<_, PartitionDropRecord>(
+            r#"
+            SELECT id, cluster_id, organization_id, database_name, table_name, partitions,
+                   statements, reason, status, requested_by, requested_by_name, approver_id,
+                   approver_name, approval_comment, execution_result, created_at, executed_at
+            FROM partition_drop_requests
+            WHERE cluster_id = ? AND (? IS NULL OR status = ?)
+            ORDER BY created_at DESC, id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(status)
+        .bind(status)
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+        Ok(records)
+    }
+
+    async fn get_record(&self, record_id: i64) -> ApiResult<PartitionDropRecord> {
+        sqlx::query_as::<_, PartitionDropRecord>(
+            r#"
+            SELECT id, cluster_id, organization_id, database_name, table_name, partitions,
+                   statements, reason, status, requested_by, requested_by_name, approver_id,
+                   approver_name, approval_comment, execution_result, created_at, executed_at
+            FROM partition_drop_requests WHERE id = ?
+            "#,
+        )
+        .bind(record_id)
+        .fetch_optional(&self.db)
+        .await?
+        .ok_or_else(|| {
+            ApiError::not_found(format!("Partition drop request {} not found", record_id))
+        })
+    }
+
+    async fn mark_approved(
+        &self,
+        record_id: i64,
+        approver: &PartitionRequester<'_>,
+        comment: Option<&str>,
+    ) -> ApiResult<()> {
+        let updated = sqlx::query(
+            "UPDATE partition_drop_requests SET status = 'executing', approver_id = ?,
+             approver_name = ?, approval_comment = ?, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND status = 'pending'",
+        )
+        .bind(approver.user_id)
+        .bind(approver.username)
+        .bind(comment)
+        .bind(record_id)
+        .execute(&self.db)
+        .await?;
+
+        if updated.rows_affected() == 0 {
+            return Err(ApiError::validation_error("Only pending requests can be approved"));
+        }
+        Ok(())
+    }
+
+    /// Execute an approved request, marking it failed if it cannot run to the end
+    ///
+    /// The record is already 'executing' at this point; without this any error
+    /// before or after the statements would leave it stuck in that state.
+    async fn execute_record(&self, record_id: i64) -> ApiResult<()> {
+        let result = self.run_record(record_id).await;
+        if let Err(e) = &result {
+            tracing::error!("Partition drop request {} aborted: {}", record_id, e);
+            sqlx::query(
+                "UPDATE partition_drop_requests SET status = 'failed', execution_result = ?,
+                 executed_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+            )
+            .bind(serde_json::json!([{ "ok": false, "error": e.to_string() }]).to_string())
+            .bind(record_id)
+            .execute(&self.db)
+            .await?;
+        }
+        result
+    }
+
+    /// Execute all statement batches of an approved request, stopping at the first failure
+    async fn run_record(&self, record_id: i64) -> ApiResult<()> {
+        let record = self.get_record(record_id).await?;
+        let cluster = self.cluster_service.get_cluster(record.cluster_id).await?;
+        let adapter = create_adapter(cluster, self.mysql_pool_manager.clone());
+        let batches: Vec<Vec<String>> = serde_json::from_str(&record.statements)?;
+
+        let mut results = Vec::new();
+        let mut failed = false;
+        'outer: for (batch_idx, batch) in batches.iter().enumerate() {
+            for sql in batch {
+                match adapter.execute_sql(sql).await {
+                    Ok(()) => results
+                        .push(serde_json::json!({"batch": batch_idx, "sql": sql, "ok": true})),
+                    Err(e) => {
+                        tracing::error!("Partition drop request {} failed: {}", record_id, e);
+                        results.push(serde_json::json!({
+                            "batch": batch_idx, "sql": sql, "ok": false, "error": e.to_string()
+                        }));
+                        failed = true;
+                        break 'outer;
+                    },
+                }
+            }
+        }
+
+        sqlx::query(
+            "UPDATE partition_drop_requests SET status = ?, execution_result = ?,
+             executed_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+        )
+        .bind(if failed { "failed" } else { "completed" })
+        .bind(serde_json::to_string(&results)?)
+        .bind(record_id)
+        .execute(&self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Approvers must be admins of the requester's organization (or super admin)
+    async fn check_approver(
+        &self,
+        record_id: i64,
+        approver: &PartitionRequester<'_>,
+    ) -> ApiResult<()> {
+        let record = self.get_record(record_id).await?;
+        if !self.is_admin(approver).await? {
+            return Err(ApiError::forbidden("Only administrators can approve partition drops"));
+        }
+        if !approver.is_super_admin && record.organization_id != approver.organization_id {
+            return Err(ApiError::forbidden(
+                "You can only approve requests from users in your organization",
+            ));
+        }
+        Ok(())
+    }
+
+    async fn is_admin(&self, user: &PartitionRequester<'_>) -> ApiResult<bool> {
+        if user.is_super_admin {
+            return Ok(true);
+        }
+        let exists: Option<(i64,)> = sqlx::query_as(
+            "SELECT 1 FROM user_roles ur
+             JOIN roles r ON ur.role_id = r.id
+             WHERE ur.user_id = ?
+               AND (r.code IN ('admin', 'super_admin') OR r.code LIKE 'org_admin_%')
+             LIMIT 1",
+        )
+        .bind(user.user_id)
+        .fetch_optional(&self.db)
+        .await?;
+        Ok(exists.is_some())
+    }
+
+    async fn mysql_client(&self, cluster: &Cluster) -> ApiResult<MySQLClient> {
+        let pool = self.mysql_pool_manager.get_pool(cluster).await?;
+        Ok(MySQLClient::from_pool(pool))
+    }
+
+    /// Tables ordered by size (largest first), limited to MAX_TABLES_PER_SCAN
+    async fn list_tables_by_size(
+        client: &MySQLClient,
+        database: Option<&str>,
+    ) -> ApiResult<Vec<(String, String, i64)>> {
+        let db_filter = match database {
+            Some(db) => {
+                validate_identifier(db)?;
+                format!("AND TABLE_SCHEMA = '{}'", db)
+            },
+            None => format!(
+                "AND TABLE_SCHEMA NOT IN ({})",
+                SYSTEM_DATABASES
+                    .iter()
+                    .map(|d| format!("'{}'", d))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        let sql = format!(
+            "SELECT TABLE_SCHEMA, TABLE_NAME, COALESCE(DATA_LENGTH, 0) \
+             FROM information_schema.tables WHERE TABLE_TYPE = 'BASE TABLE' {} \
+             ORDER BY 3 DESC LIMIT {}",
+            db_filter, MAX_TABLES_PER_SCAN
+        );
+        let (_, rows) = client.query_raw(&sql).await?;
+        Ok(rows
+            .into_iter()
+            .filter(|r| r.len() >= 3)
+            .map(|r| (r[0].clone(), r[1].clone(), r[2].parse::<i64>().unwrap_or(0)))
+            .collect())
+    }
+
+    /// StarRocks expression partitions may carry partition_ttl / partition_live_number
+    async fn list_starrocks_ttl_tables(
+        client: &MySQLClient,
+        database: Option<&str>,
+    ) -> Vec<(String, String)> {
+        let mut sql =
+            "SELECT TABLE_SCHEMA, TABLE_NAME, PROPERTIES FROM information_schema.tables_config"
+                .to_string();
+        if let Some(db) = database {
+            sql.push_str(&format!(" WHERE TABLE_SCHEMA = '{}'", db.replace('\'', "''")));
+        }
+        match client.query_raw(&sql).await {
+            Ok((_, rows)) => rows
+                .into_iter()
+                .filter(|r| {
+                    r.get(2).is_some_and(|props| {
+                        props.contains("partition_ttl") || props.contains("partition_live_number")
+                    })
+                })
+                .map(|r| (r[0].clone(), r[1].clone()))
+                .collect(),
+            Err(e) => {
+                tracing::debug!("Failed to read tables_config: {}", e);
+                Vec::new()
+            },
+        }
+    }
+
+    async fn show_partitions(
+        client: &MySQLClient,
+        database: &str,
+        table: &str,
+    ) -> ApiResult<Vec<PartitionInfo>> {
+        let sql = format!("SHOW PARTITIONS FROM `{}`.`{}`", database, table);
+        let (columns, rows) = client.query_raw(&sql).await?;
+        Ok(Self::parse_partition_rows(&columns, &rows))
+    }
+
+    fn parse_partition_rows(columns: &[String], rows: &[Vec<String>]) -> Vec<PartitionInfo> {
+        let idx = column_index(columns);
+        rows.iter()
+            .filter_map(|row| {
+                let get = |name: &str| idx.get(name).and_then(|i| row.get(*i)).cloned();
+                let name = get("partitionname")?;
+                let (lower_bound, upper_bound) = get("range")
+                    .filter(|r| !r.is_empty())
+                    .map(|r| parse_range_bounds(&r))
+                    .or_else(|| get("list").map(|l| (parse_list_first_value(&l), None)))
+                    .unwrap_or((None, None));
+                Some(PartitionInfo {
+                    name,
+                    lower_bound,
+                    upper_bound,
+                    data_size: get("datasize").unwrap_or_default(),
+                    row_count: get("rowcount").and_then(|v| v.parse().ok()),
+                })
+            })
+            .collect()
+    }
+
+    fn parse_dynamic_partition_rows(
+        database: &str,
+        columns: &[String],
+        rows: &[Vec<String>],
+    ) -> Vec<DynamicPartitionSetting> {
+        let idx = column_index(columns);
+        rows.iter()
+            .filter_map(|row| {
+                let get = |name: &str| idx.get(name).and_then(|i| row.get(*i)).cloned();
+                let start = get("start")
+                    .and_then(|v| v.parse::<i64>().ok())
+                    .filter(|v| *v != DYNAMIC_PARTITION_NO_START);
+                Some(DynamicPartitionSetting {
+                    database: database.to_string(),
+                    table: get("tablename")?,
+                    enabled: get("enable").is_some_and(|v| v.eq_ignore_ascii_case("true")),
+                    time_unit: get("timeunit").unwrap_or_default(),
+                    start,
+                    end: get("end").and_then(|v| v.parse().ok()),
+                    prefix: get("prefix").unwrap_or_default(),
+                    buckets: get("buckets").and_then(|v| v.parse().ok()),
+                    state: get("state").unwrap_or_default(),
+                    last_drop_partition_msg: get("lastdroppartitionmsg")
+                        .filter(|v| !v.is_empty() && v != "N/A"),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Map lower-cased column names to their index
+fn column_index(columns: &[String]) -> HashMap<String, usize> {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (c.to_lowercase(), i))
+        .collect()
+}
+
+/// Reject identifiers that could break out of backtick quoting
+fn validate_identifier(name: &str) -> ApiResult<()> {
+    if name.is_empty() || name.contains('`') || name.contains('\'') || name.contains(';') {
+        return Err(ApiError::validation_error(format!("Invalid identifier: {}", name)));
+    }
+    Ok(())
+}
+
+/// Extract lower/upper keys from a range description such as
+/// `[types: [DATE]; keys: [2024-01-01]; ..types: [DATE]; keys: [2024-01-02]; )`
+fn parse_range_bounds(range: &str) -> (Option<String>, Option<String>) {
+    let mut keys = range.split("keys: [").skip(1).filter_map(|part| {
+        part.split(']')
+            .next()
+            .map(|k| k.split(',').next().unwrap_or(k).trim().to_string())
+    });
+    (keys.next(), keys.next())
+}
+
+/// First value of a list partition description such as `(('2024-01-01'), ('2024-01-02'))`
+fn parse_list_first_value(list: &str) -> Option<String> {
+    let value = list
+        .trim_matches(|c| c == '(' || c == ')' || c == '[' || c == ']')
+        .split(',')
+        .next()?
+        .trim_matches(|c: char| c == '(' || c == ')' || c == '\'' || c == '"' || c.is_whitespace())
+        .to_string();
+    if value.is_empty() { None } else { Some(value) }
+}
+
+/// Parse a partition key into a date (DATE, DATETIME or yyyyMMdd integer keys)
+fn parse_partition_date(value: &str) -> Option<NaiveDate> {
+    let v = value.trim();
+    NaiveDate::parse_from_str(v.get(..10).unwrap_or(v), "%Y-%m-%d")
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(v, "%Y%m%d").ok())
+        .filter(|d| *d > NaiveDate::from_ymd_opt(1900, 1, 1).unwrap_or_default())
+}
+
+/// A partition is expired when all of its data is older than the cutoff
+fn is_partition_expired(partition: &PartitionInfo, cutoff: NaiveDate) -> bool {
+    match (&partition.upper_bound, &partition.lower_bound) {
+        (Some(upper), _) => parse_partition_date(upper).is_some_and(|d| d <= cutoff),
+        (None, Some(lower)) => parse_partition_date(lower).is_some_and(|d| d < cutoff),
+        _ => false,
+    }
+}
+
+/// Build `ALTER TABLE ... DROP PARTITION` statements grouped into batches
+fn build_drop_batches(
+    database: &str,
+    table: &str,
+    partitions: &[String],
+    batch_size: usize,
+) -> Vec<Vec<String>> {
+    partitions
+        .chunks(batch_size.max(1))
+        .map(|chunk| {
+            chunk
+                .iter()
+                .map(|p| {
+                    format!(
+                        "ALTER TABLE `{}`.`{}` DROP PARTITION IF EXISTS `{}`",
+                        database, table, p
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_range_bounds() {
+        let range = "[types: [DATE]; keys: [2024-01-01]; ..types: [DATE]; keys: [2024-01-02]; )";
+        let (lower, upper) = parse_range_bounds(range);
+        assert_eq!(lower.as_deref(), Some("2024-01-01"));
+        assert_eq!(upper.as_deref(), Some("2024-01-02"));
+    }
+
+    #[test]
+    fn test_parse_partition_date_formats() {
+        let expected = NaiveDate::from_ymd_opt(2024, 3, 5);
+        assert_eq!(parse_partition_date("2024-03-05"), expected);
+        assert_eq!(parse_partition_date("2024-03-05 00:00:00"), expected);
+        assert_eq!(parse_partition_date("20240305"), expected);
+        assert_eq!(parse_partition_date("0000-01-01"), None);
+        assert_eq!(parse_partition_date("MAXVALUE"), None);
+    }
+
+    #[test]
+    fn test_is_partition_expired_uses_upper_bound() {
+        let cutoff = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        let partition = |lower: &str, upper: &str| PartitionInfo {
+            name: "p".to_string(),
+            lower_bound: Some(lower.to_string()),
+            upper_bound: Some(upper.to_string()),
+            data_size: String::new(),
+            row_count: None,
+        };
+        assert!(is_partition_expired(&partition("2024-01-01", "2024-01-02"), cutoff));
+        assert!(!is_partition_expired(&partition("2024-01-01", "2024-01-03"), cutoff));
+    }
+
+    #[test]
+    fn test_build_drop_batches() {
+        let parts: Vec<String> = (1..=5).map(|i| format!("p{}", i)).collect();
+        let batches = build_drop_batches("db", "tbl", &parts, 2);
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[2], vec!["ALTER TABLE `db`.`tbl` DROP PARTITION IF EXISTS `p5`"]);
+    }
+
+    #[test]
+    fn test_parse_dynamic_partition_rows_without_ttl() {
+        let columns: Vec<String> = ["TableName", "Enable", "TimeUnit", "Start", "End", "Prefix"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let rows = vec![
+            vec!["t1", "true", "DAY", "-2147483648", "3", "p"],
+            vec!["t2", "true", "DAY", "-30", "3", "p"],
+        ]
+        .into_iter()
+        .map(|r| r.into_iter().map(String::from).collect())
+        .collect::<Vec<Vec<String>>>();
+
+        let settings = PartitionService::parse_dynamic_partition_rows("db", &columns, &rows);
+        assert_eq!(settings.len(), 2);
+        assert_eq!(settings[0].start, None);
+        assert_eq!(settings[1].start, Some(-30));
+    }
+}
@@ -0,0 +1,509 @@
+//! Cardinality Misestimation Analysis
+//!
+//! Pairs the optimizer's estimated rows from `EXPLAIN COSTS` with the actual
+//! rows recorded in the profile. Large estimation errors usually come from stale
+//! or missing statistics and lead the optimizer to bad join orders, wrong join
+//! distribution and undersized aggregation hash tables.
+//!
+//! - Estimates are matched by plan node ID, so the plan must come from the same
+//!   SQL as the profile.
+//! - Actual rows are the operator output (`PullRowNum`, falling back to
+//!   `PushRowNum` for sinks), summed over all instances.
+//! - Every node whose error exceeds [`ERROR_RATIO_THRESHOLD`] becomes a PL004
+//!   diagnostic; tables below it whose statistics are stale get an
+//!   `ANALYZE TABLE` suggestion.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use super::rules::{Diagnostic, RuleSeverity};
+use crate::services::profile_analyzer::i18n::{Message, msg};
+use crate::services::profile_analyzer::models::{ExecutionTree, ExecutionTreeNode};
+
+/// Estimated/actual ratio (either direction) that counts as a misestimation
+pub const ERROR_RATIO_THRESHOLD: f64 = 10.0;
+/// Ratio above which the misestimation is reported as a warning
+const SEVERE_ERROR_RATIO: f64 = 100.0;
+/// Ignore errors where both sides are tiny, e.g. 1 vs 20 rows
+const MIN_ROWS: u64 = 1000;
+/// Statistics older than this are considered stale
+pub const STALE_STATISTICS_HOURS: i64 = 7 * 24;
+
+/// Plan node header, e.g. `5:HASH JOIN`, `|----4:EXCHANGE`, `0:OlapScanNode`
+static NODE_HEADER_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[\s|]*-*\s*(\d+):([A-Za-z][A-Za-z_ ]*)").unwrap());
+static CARDINALITY_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[\s|]*cardinality\s*[:=]\s*(\d+)").unwrap());
+static TABLE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^[\s|]*table\s*:\s*([^,\s]+)").unwrap());
+/// Column statistics entry without statistics, e.g. `* id-->[-Infinity, ...] UNKNOWN`
+static UNKNOWN_COLUMN_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[\s|]*\*\s*(\S+)-->.*\bUNKNOWN\s*$").unwrap());
+
+// ============================================================================
+// Input Types
+// ============================================================================
+
+/// Planner information fetched from the live cluster for a profiled query
+#[derive(Debug, Clone, Default)]
+pub struct CardinalityInput {
+    /// Raw `EXPLAIN COSTS` output of the profiled SQL
+    pub explain_costs: String,
+    /// Statistics freshness of the scanned tables
+    pub table_statistics: Vec<TableStatistics>,
+}
+
+/// Freshness of the column statistics of one table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableStatistics {
+    /// Table name as it appears in the plan (`db.table` when known)
+    pub table: String,
+    /// Latest `update_time` in `_statistics_.column_statistics`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// Hours since the last update, None if the table was never analyzed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age_hours: Option<i64>,
+}
+
+impl TableStatistics {
+    pub fn is_stale(&self) -> bool {
+        self.age_hours
+            .is_none_or(|hours| hours > STALE_STATISTICS_HOURS)
+    }
+}
+
+/// A plan node parsed from `EXPLAIN COSTS`
+#[derive(Debug, Clone, PartialEq)]
+pub struct EstimatedNode {
+    pub plan_node_id: i32,
+    pub operator: String,
+    pub cardinality: Option<u64>,
+    /// Scanned table for scan nodes
+    pub table: Option<String>,
+    /// Columns reported without statistics
+    pub unknown_columns: Vec<String>,
+}
+
+/// Estimates of all plan nodes, keyed by plan node ID
+#[derive(Debug, Clone, Default)]
+pub struct PlannerEstimates {
+    pub nodes: BTreeMap<i32, EstimatedNode>,
+}
+
+impl PlannerEstimates {
+    /// Parse the text output of `EXPLAIN COSTS`.
+    ///
+    /// Node attributes directly follow their header, so every attribute line
+    /// belongs to the most recently seen node.
+    pub fn parse(explain: &str) -> Self {
+        let mut nodes = BTreeMap::new();
+        let mut current: Option<i32> = None;
+
+        for line in explain.lines() {
+            if let Some(caps) = NODE_HEADER_REGEX.captures(line) {
+                let Ok(id) = caps[1].parse::<i32>() else { continue };
+                nodes.entry(id).or_insert_with(|| EstimatedNode {
+                    plan_node_id: id,
+                    operator: caps[2].trim().to_string(),
+                    cardinality: None,
+                    table: None,
+                    unknown_columns: vec![],
+                });
+                current = Some(id);
+                continue;
+            }
+
+            let Some(node) = current.and_then(|id| nodes.get_mut(&id)) else { continue };
+            if let Some(caps) = CARDINALITY_REGEX.captures(line) {
+                if node.cardinality.is_none() {
+                    node.cardinality = caps[1].parse().ok();
+                }
+            } else if let Some(caps) = TABLE_REGEX.captures(line) {
+                if node.table.is_none() {
+                    node.table = Some(caps[1].trim_matches('`').to_string());
+                }
+            } else if let Some(caps) = UNKNOWN_COLUMN_REGEX.captures(line) {
+                node.unknown_columns.push(caps[1].to_string());
+            }
+        }
+
+        Self { nodes }
+    }
+
+    /// Tables scanned by the plan
+    pub fn scan_tables(&self) -> Vec<String> {
+        let tables: BTreeSet<&String> = self
+            .nodes
+            .values()
+            .filter_map(|n| n.table.as_ref())
+            .collect();
+        tables.into_iter().cloned().collect()
+    }
+}
+
+// ============================================================================
+// Result Types
+// ============================================================================
+
+/// Estimated vs actual rows across the plan
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CardinalityAnalysis {
+    /// Nodes whose estimate is off by more than the threshold, worst first
+    pub errors: Vec<CardinalityError>,
+    /// Tables below misestimated nodes whose statistics are stale or missing
+    pub stale_tables: Vec<TableStatistics>,
+    /// `ANALYZE TABLE` statements for the stale tables
+    pub analyze_statements: Vec<String>,
+    /// Number of plan nodes with both an estimate and actual rows
+    pub compared_nodes: usize,
+}
+
+/// A single misestimated plan node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardinalityError {
+    pub plan_node_id: i32,
+    pub operator_name: String,
+    pub estimated_rows: u64,
+    pub actual_rows: u64,
+    /// max(estimated, actual) / min(estimated, actual)
+    pub error_ratio: f64,
+    /// True when the optimizer expected fewer rows than were produced
+    pub underestimated: bool,
+    /// Tables scanned below this node
+    pub tables: Vec<String>,
+}
+
+// ============================================================================
+// Cardinality Analyzer
+// ============================================================================
+
+/// Compares planner estimates with the actual rows of an execution tree
+pub struct CardinalityAnalyzer;
+
+impl CardinalityAnalyzer {
+    /// Analyze an execution tree against the planner information
+    pub fn analyze(
+        tree: &ExecutionTree,
+        input: &CardinalityInput,
+        default_db: Option<&str>,
+    ) -> Option<CardinalityAnalysis> {
+        let estimates = PlannerEstimates::parse(&input.explain_costs);
+        if estimates.nodes.is_empty() {
+            return None;
+        }
+
+        let index: HashMap<&str, &ExecutionTreeNode> =
+            tree.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+
+        let mut compared_nodes = 0;
+        let mut seen = HashSet::new();
+        let mut errors = Vec::new();
+        for node in &tree.nodes {
+            let Some(plan_node_id) = node.plan_node_id else { continue };
+            if !seen.insert(plan_node_id) {
+                continue;
+            }
+            let Some(estimated) = estimates
+                .nodes
+                .get(&plan_node_id)
+                .and_then(|e| e.cardinality)
+            else {
+                continue;
+            };
+            let Some(actual) = Self::actual_rows(node) else { continue };
+            compared_nodes += 1;
+
+            let (low, high) = (estimated.min(actual), estimated.max(actual));
+            let error_ratio = high as f64 / low.max(1) as f64;
+            if error_ratio <= ERROR_RATIO_THRESHOLD || high < MIN_ROWS {
+                continue;
+            }
+
+            let mut tables = BTreeSet::new();
+            Self::collect_tables(node, &index, &estimates, &mut HashSet::new(), &mut tables);
+            errors.push(CardinalityError {
+                plan_node_id,
+                operator_name: node.operator_name.clone(),
+                estimated_rows: estimated,
+                actual_rows: actual,
+                error_ratio,
+                underestimated: estimated < actual,
+                tables: tables
+                    .into_iter()
+                    .map(|t| Self::qualify(&t, default_db))
+                    .collect(),
+            });
+        }
+        errors.sort_by(|a, b| b.error_ratio.total_cmp(&a.error_ratio));
+
+        let statistics: HashMap<&str, &TableStatistics> = input
+            .table_statistics
+            .iter()
+            .map(|s| (s.table.as_str(), s))
+            .collect();
+        let involved: BTreeSet<&String> = errors.iter().flat_map(|e| &e.tables).collect();
+        let stale_tables: Vec<TableStatistics> = involved
+            .into_iter()
+            .map(|table| {
+                statistics
+                    .get(table.as_str())
+                    .map(|s| (*s).clone())
+                    .unwrap_or(TableStatistics {
+                        table: table.clone(),
+                        updated_at: None,
+                        age_hours: None,
+                    })
+            })
+            .filter(|s| s.is_stale())
+            .collect();
+        let analyze_statements = stale_tables
+            .iter()
+            .map(|s| format!("ANALYZE TABLE {};", s.table))
+            .collect();
+
+        Some(CardinalityAnalysis { errors, stale_tables, analyze_statements, compared_nodes })
+    }
+
+    /// PL004 diagnostics, one per misestimated node
+    pub fn to_diagnostics(analysis: &CardinalityAnalysis) -> Vec<Diagnostic> {
+        let stale: HashSet<&str> = analysis
+            .stale_tables
+            .iter()
+            .map(|s| s.table.as_str())
+            .collect();
+
+        analysis
+            .errors
+            .iter()
+            .map(|error| {
+                let severity = if error.error_ratio > SEVERE_ERROR_RATIO {
+                    RuleSeverity::Warning
+                } else {
+                    RuleSeverity::Info
+                };
+                let mut suggestions: Vec<Message> = error
+                    .tables
+                    .iter()
+                    .filter(|t| stale.contains(t.as_str()))
+                    .map(|t| msg!("PL004.suggestion.1", t))
+                    .collect();
+                if suggestions.is_empty() {
+                    suggestions.push(msg!("PL004.suggestion.2"));
+                }
+                suggestions.push(msg!("PL004.suggestion.3"));
+
+                Diagnostic {
+                    rule_id: "PL004".to_string(),
+                    severity,
+                    node_path: format!(
+                        "{} (plan_node_id={})",
+                        error.operator_name, error.plan_node_id
+                    ),
+                    plan_node_id: Some(error.plan_node_id),
+                    message: msg!(
+                        "PL004.message",
+                        error.estimated_rows,
+                        error.actual_rows,
+                        format!("{:.1}", error.error_ratio)
+                    ),
+                    reason: msg!("PL004.reason"),
+                    suggestions,
+                    parameter_suggestions: vec![],
+                    threshold_metadata: None,
+                }
+            })
+            .collect()
+    }
+
+    fn actual_rows(node: &ExecutionTreeNode) -> Option<u64> {
+        node.metrics
+            .pull_row_num
+            .or(node.metrics.push_row_num)
+            .or(node.rows)
+    }
+
+    fn collect_tables(
+        node: &ExecutionTreeNode,
+        index: &HashMap<&str, &ExecutionTreeNode>,
+        estimates: &PlannerEstimates,
+        visiting: &mut HashSet<String>,
+        tables: &mut BTreeSet<String>,
+    ) {
+        if !visiting.insert(node.id.clone()) {
+            return;
+        }
+        if let Some(table) = node
+            .plan_node_id
+            .and_then(|id| estimates.nodes.get(&id))
+            .and_then(|e| e.table.clone())
+        {
+            tables.insert(table);
+        }
+        for child in &node.children {
+            if let Some(child) = index.get(child.as_str()) {
+                Self::collect_tables(child, index, estimates, visiting, tables);
+            }
+        }
+    }
+
+    fn qualify(table: &str, default_db: Option<&str>) -> String {
+        match default_db {
+            Some(db) if !table.contains('.') && !db.is_empty() => format!("{}.{}", db, table),
+            _ => table.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::profile_analyzer::i18n::Locale;
+    use crate::services::profile_analyzer::models::{HotSeverity, NodeType, OperatorMetrics};
+
+    const EXPLAIN: &str = "PLAN FRAGMENT 0(F02)
+  Output Exprs:1: id | 5: cnt
+  Input Partition: UNPARTITIONED
+  RESULT SINK
+
+  4:EXCHANGE
+     distribution type: GATHER
+     cardinality: 10
+
+PLAN FRAGMENT 1(F00)
+  3:AGGREGATE (update finalize)
+  |  aggregate: count[([2: v, INT, true]); args: INT; result: BIGINT]
+  |  group by: [1: id, INT, true]
+  |  cardinality: 10
+  |  column statistics:
+  |  * id-->[1.0, 10.0, 0.0, 4.0, 10.0] ESTIMATE
+  |
+  2:HASH JOIN
+  |  join op: INNER JOIN (BROADCAST)
+  |  cardinality: 100
+  |
+  |----1:EXCHANGE
+  |       distribution type: BROADCAST
+  |       cardinality: 5
+  |
+  0:OlapScanNode
+     table: orders, rollup: orders
+     preAggregation: on
+     cardinality: 2000
+     column statistics:
+     * id-->[-Infinity, Infinity, 0.0, 4.0, 1.0] UNKNOWN
+";
+
+    fn node(id: &str, name: &str, plan_id: i32, rows: u64, children: &[&str]) -> ExecutionTreeNode {
+        ExecutionTreeNode {
+            id: id.to_string(),
+            operator_name: name.to_string(),
+            node_type: NodeType::Unknown,
+            plan_node_id: Some(plan_id),
+            parent_plan_node_id: None,
+            metrics: OperatorMetrics { pull_row_num: Some(rows), ..Default::default() },
+            children: children.iter().map(|c| c.to_string()).collect(),
+            depth: 0,
+            is_hotspot: false,
+            hotspot_severity: HotSeverity::Normal,
+            fragment_id: None,
+            pipeline_id: None,
+            time_percentage: None,
+            rows: None,
+            is_most_consuming: false,
+            is_second_most_consuming: false,
+            unique_metrics: HashMap::new(),
+            has_diagnostic: false,
+            diagnostic_ids: vec![],
+        }
+    }
+
+    fn tree() -> ExecutionTree {
+        let nodes = vec![
+            node("agg", "AGGREGATE", 3, 50_000, &["join"]),
+            node("join", "HASH_JOIN", 2, 2_000_000, &["scan", "exchange"]),
+            node("exchange", "EXCHANGE", 1, 5, &[]),
+            node("scan", "OLAP_SCAN", 0, 2_000_000, &[]),
+        ];
+        ExecutionTree { root: nodes[0].clone(), nodes }
+    }
+
+    #[test]
+    fn test_parse_explain_costs() {
+        let estimates = PlannerEstimates::parse(EXPLAIN);
+        assert_eq!(estimates.nodes.len(), 5);
+        assert_eq!(estimates.nodes[&2].operator, "HASH JOIN");
+        assert_eq!(estimates.nodes[&2].cardinality, Some(100));
+        assert_eq!(estimates.nodes[&1].cardinality, Some(5));
+        assert_eq!(estimates.nodes[&0].table.as_deref(), Some("orders"));
+        assert_eq!(estimates.nodes[&0].unknown_columns, vec!["id".to_string()]);
+        assert!(estimates.nodes[&3].unknown_columns.is_empty());
+        assert_eq!(estimates.scan_tables(), vec!["orders".to_string()]);
+    }
+
+    #[test]
+    fn test_misestimated_nodes_and_stale_tables() {
+        let input = CardinalityInput {
+            explain_costs: EXPLAIN.to_string(),
+            table_statistics: vec![TableStatistics {
+                table: "sales.orders".to_string(),
+                updated_at: Some("2026-01-01 00:00:00".to_string()),
+                age_hours: Some(24 * 30),
+            }],
+        };
+        let analysis = CardinalityAnalyzer::analyze(&tree(), &input, Some("sales")).unwrap();
+
+        assert_eq!(analysis.compared_nodes, 4);
+        let ids: Vec<i32> = analysis.errors.iter().map(|e| e.plan_node_id).collect();
+        // join 20000x, agg 5000x, scan 1000x; the 5-row exchange is exact
+        assert_eq!(ids, vec![2, 3, 0]);
+        assert!(analysis.errors[0].underestimated);
+        assert_eq!(analysis.errors[0].tables, vec!["sales.orders".to_string()]);
+        assert_eq!(analysis.analyze_statements, vec!["ANALYZE TABLE sales.orders;".to_string()]);
+
+        let diagnostics = CardinalityAnalyzer::to_diagnostics(&analysis);
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(diagnostics[0].rule_id, "PL004");
+        assert_eq!(diagnostics[0].plan_node_id, Some(2));
+        assert_eq!(diagnostics[0].severity, RuleSeverity::Warning);
+        assert!(
+            diagnostics[0].suggestions[0]
+                .render(Locale::En)
+                .contains("ANALYZE TABLE sales.orders;")
+        );
+    }
+
+    #[test]
+    fn test_fresh_statistics_are_not_reported() {
+        let input = CardinalityInput {
+            explain_costs: EXPLAIN.to_string(),
+            table_statistics: vec![TableStatistics {
+                table: "sales.orders".to_string(),
+                updated_at: Some("2026-10-18 00:00:00".to_string()),
+                age_hours: Some(2),
+            }],
+        };
+        let analysis = CardinalityAnalyzer::analyze(&tree(), &input, Some("sales")).unwrap();
+        assert!(!analysis.errors.is_empty());
+        assert!(analysis.stale_tables.is_empty());
+        assert!(analysis.analyze_statements.is_empty());
+    }
+
+    #[test]
+    fn test_missing_statistics_are_stale() {
+        let input =
+            CardinalityInput { explain_costs: EXPLAIN.to_string(), table_statistics: vec![] };
+        let analysis = CardinalityAnalyzer::analyze(&tree(), &input, None).unwrap();
+        assert_eq!(analysis.stale_tables.len(), 1);
+        assert_eq!(analysis.stale_tables[0].table, "orders");
+        assert!(analysis.stale_tables[0].age_hours.is_none());
+    }
+
+    #[test]
+    fn test_empty_explain() {
+        let input = CardinalityInput::default();
+        assert!(CardinalityAnalyzer::analyze(&tree(), &input, None).is_none());
+    }
+}
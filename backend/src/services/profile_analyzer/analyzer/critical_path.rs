@@ -0,0 +1,351 @@
+//! Critical Path Analysis
+//!
+//! Hotspots rank operators by their share of cumulative operator time, but in a
+//! pipelined DAG the wall-clock time is decided by the longest chain of dependent
+//! work, not by the single most expensive operator. This module walks the
+//! execution tree and extracts that chain:
+//!
+//! - Node cost is the node's `operator_total_time` as set by
+//!   `TreeBuilder::calculate_time_percentages`: `__MAX_OF_OperatorTotalTime` summed
+//!   over the operators of a plan node, plus `NetworkTime` for exchanges and
+//!   `ScanTime` for scans, i.e. the slowest instance of each operator.
+//! - Streaming children overlap with their parent, so only the slowest child
+//!   contributes (`max`).
+//! - A hash join cannot probe before its build side is finished, so the build
+//!   chain and the probe chain are sequential (`build + probe`).
+//! - Exchange edges cross fragments; the receiving side waits for the sender,
+//!   so the exchange's own network time is added on top of the sender chain.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::services::profile_analyzer::models::{
+    ExecutionTree, ExecutionTreeNode, NodeType, Profile,
+};
+
+// ============================================================================
+// Critical Path Result Types
+// ============================================================================
+
+/// The chain of operators that determined the query wall-clock time
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CriticalPath {
+    /// Operators on the path in execution order (leaf first, sink last).
+    /// For joins the build side chain precedes the probe side chain.
+    pub nodes: Vec<CriticalPathNode>,
+    /// Sum of per-instance max times along the path
+    pub path_time_ms: f64,
+    /// QueryExecutionWallTime used as the reference, if available
+    pub wall_time_ms: Option<f64>,
+    /// path_time_ms / wall_time_ms in percent (>100 means operators overlapped
+    /// more than the model assumes)
+    pub coverage_percentage: Option<f64>,
+}
+
+/// A single operator on the critical path
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriticalPathNode {
+    /// Execution tree node ID
+    pub node_id: String,
+    pub operator_name: String,
+    pub plan_node_id: Option<i32>,
+    pub fragment_id: Option<String>,
+    /// Per-instance max time of this operator
+    pub self_time_ms: f64,
+    /// Share of the wall-clock time attributed to this operator
+    pub contribution_ms: f64,
+    /// contribution_ms as a percentage of the wall-clock time (or path time)
+    pub contribution_percentage: f64,
+    /// How this operator is reached from its parent on the path
+    pub dependency: PathDependency,
+}
+
+/// Kind of dependency between an operator and its parent on the path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PathDependency {
+    /// The final sink of the query
+    Root,
+    /// Streams into its parent within the same pipeline
+    Pipelined,
+    /// Hash join build side, blocks the probe side until finished
+    BuildSide,
+    /// Hash join probe side, starts after the build side completes
+    ProbeSide,
+    /// Sends data to its parent across an exchange
+    Exchange,
+}
+
+impl CriticalPath {
+    /// Plan node IDs on the path
+    pub fn plan_node_ids(&self) -> HashSet<i32> {
+        self.nodes.iter().filter_map(|n| n.plan_node_id).collect()
+    }
+}
+
+// ============================================================================
+// Critical Path Analyzer
+// ============================================================================
+
+/// Partial chain collected while walking the tree
+#[derive(Default)]
+struct Span<'a> {
+    time_ns: u64,
+    steps: Vec<(&'a ExecutionTreeNode, PathDependency)>,
+}
+
+/// Extracts the critical path from an execution tree
+pub struct CriticalPathAnalyzer;
+
+impl CriticalPathAnalyzer {
+    /// Analyze a parsed profile
+    pub fn analyze(profile: &Profile) -> Option<CriticalPath> {
+        let tree = profile.execution_tree.as_ref()?;
+        let wall_time_ms = profile
+            .summary
+            .query_execution_wall_time_ms
+            .or(profile.summary.total_time_ms)
+            .filter(|ms| *ms > 0.0);
+        Self::analyze_tree(tree, wall_time_ms)
+    }
+
+    /// Analyze an execution tree against an optional wall-clock reference
+    pub fn analyze_tree(tree: &ExecutionTree, wall_time_ms: Option<f64>) -> Option<CriticalPath> {
+        let index: HashMap<&str, &ExecutionTreeNode> =
+            tree.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+        let root = index
+            .get(tree.root.id.as_str())
+            .copied()
+            .unwrap_or(&tree.root);
+
+        let mut visiting = HashSet::new();
+        let span = Self::longest_span(root, PathDependency::Root, &index, &mut visiting);
+        if span.steps.is_empty() || span.time_ns == 0 {
+            return None;
+        }
+
+        let path_time_ms = span.time_ns as f64 / 1_000_000.0;
+        // When the modelled path is longer than the measured wall time, operators
+        // overlapped more than assumed; scale contributions down so they sum to it
+        let scale = match wall_time_ms {
+            Some(wall) if path_time_ms > wall => wall / path_time_ms,
+            _ => 1.0,
+        };
+        let reference_ms = wall_time_ms.unwrap_or(path_time_ms);
+
+        let nodes = span
+            .steps
+            .into_iter()
+            .map(|(node, dependency)| {
+                let self_time_ms = Self::self_time_ns(node) as f64 / 1_000_000.0;
+                let contribution_ms = self_time_ms * scale;
+                CriticalPathNode {
+                    node_id: node.id.clone(),
+                    operator_name: node.operator_name.clone(),
+                    plan_node_id: node.plan_node_id,
+                    fragment_id: node.fragment_id.clone(),
+                    self_time_ms,
+                    contribution_ms,
+                    contribution_percentage: round2(contribution_ms / reference_ms * 100.0),
+                    dependency,
+                }
+            })
+            .collect();
+
+        Some(CriticalPath {
+            nodes,
+            path_time_ms,
+            wall_time_ms,
+            coverage_percentage: wall_time_ms.map(|wall| round2(path_time_ms / wall * 100.0)),
+        })
+    }
+
+    /// Longest chain of dependent work ending at `node`
+    fn longest_span<'a>(
+        node: &'a ExecutionTreeNode,
+        dependency: PathDependency,
+        index: &HashMap<&str, &'a ExecutionTreeNode>,
+        visiting: &mut HashSet<&'a str>,
+    ) -> Span<'a> {
+        // Guard against malformed topologies that reference an ancestor
+        if !visiting.insert(node.id.as_str()) {
+            return Span::default();
+        }
+
+        let children: Vec<&ExecutionTreeNode> = node
+            .children
+            .iter()
+            .filter_map(|id| index.get(id.as_str()).copied())
+            .collect();
+
+        let mut span = if Self::is_join(node) && children.len() == 2 {
+            // Topology lists join children as [probe, build]
+            let mut build =
+                Self::longest_span(children[1], PathDependency::BuildSide, index, visiting);
+            let probe = Self::longest_span(children[0], PathDependency::ProbeSide, index, visiting);
+            build.time_ns += probe.time_ns;
+            build.steps.extend(probe.steps);
+            build
+        } else {
+            let child_dependency = if node.operator_name.contains("EXCHANGE") {
+                PathDependency::Exchange
+            } else {
+                PathDependency::Pipelined
+            };
+            children
+                .into_iter()
+                .map(|child| Self::longest_span(child, child_dependency, index, visiting))
+                .max_by_key(|s| s.time_ns)
+                .unwrap_or_default()
+        };
+
+        visiting.remove(node.id.as_str());
+
+        span.time_ns += Self::self_time_ns(node);
+        span.steps.push((node, dependency));
+        span
+    }
+
+    fn is_join(node: &ExecutionTreeNode) -> bool {
+        node.node_type == NodeType::HashJoin || node.operator_name.contains("JOIN")
+    }
+
+    /// Per-instance max time; TreeBuilder already folds exchange network time and
+    /// scan time into operator_total_time, so they are not added again here
+    fn self_time_ns(node: &ExecutionTreeNode) -> u64 {
+        node.metrics.operator_total_time.unwrap_or(0)
+    }
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::profile_analyzer::models::{HotSeverity, OperatorMetrics};
+
+    fn node(
+        id: &str,
+        name: &str,
+        plan_id: i32,
+        time_ms: u64,
+        children: &[&str],
+    ) -> ExecutionTreeNode {
+        ExecutionTreeNode {
+            id: id.to_string(),
+            operator_name: name.to_string(),
+            node_type: if name.contains("JOIN") { NodeType::HashJoin } else { NodeType::Unknown },
+            plan_node_id: Some(plan_id),
+            parent_plan_node_id: None,
+            metrics: OperatorMetrics {
+                operator_total_time: Some(time_ms * 1_000_000),
+                ..Default::default()
+            },
+            children: children.iter().map(|c| c.to_string()).collect(),
+            depth: 0,
+            is_hotspot: false,
+            hotspot_severity: HotSeverity::Normal,
+            fragment_id: None,
+            pipeline_id: None,
+            time_percentage: None,
+            rows: None,
+            is_most_consuming: false,
+            is_second_most_consuming: false,
+            unique_metrics: HashMap::new(),
+            has_diagnostic: false,
+            diagnostic_ids: vec![],
+        }
+    }
+
+    fn tree(nodes: Vec<ExecutionTreeNode>) -> ExecutionTree {
+        ExecutionTree { root: nodes[0].clone(), nodes }
+    }
+
+    fn ids(path: &CriticalPath) -> Vec<&str> {
+        path.nodes.iter().map(|n| n.node_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_streaming_children_take_slowest_branch() {
+        let t = tree(vec![
+            node("sink", "RESULT_SINK", 100, 1, &["union"]),
+            node("union", "UNION", 3, 2, &["scan_a", "scan_b"]),
+            node("scan_a", "OLAP_SCAN", 1, 50, &[]),
+            node("scan_b", "OLAP_SCAN", 2, 10, &[]),
+        ]);
+
+        let path = CriticalPathAnalyzer::analyze_tree(&t, None).unwrap();
+        assert_eq!(ids(&path), vec!["scan_a", "union", "sink"]);
+        assert_eq!(path.path_time_ms, 53.0);
+        assert_eq!(path.nodes[0].dependency, PathDependency::Pipelined);
+        assert_eq!(path.nodes[2].dependency, PathDependency::Root);
+    }
+
+    #[test]
+    fn test_join_build_blocks_probe() {
+        // The slowest single operator (probe scan) is on the path, but the build
+        // chain adds to it instead of overlapping
+        let t = tree(vec![
+            node("sink", "RESULT_SINK", 100, 1, &["join"]),
+            node("join", "HASH_JOIN", 5, 4, &["probe_scan", "exchange"]),
+            node("probe_scan", "OLAP_SCAN", 1, 60, &[]),
+            node("exchange", "EXCHANGE", 4, 5, &["build_scan"]),
+            node("build_scan", "OLAP_SCAN", 3, 30, &[]),
+        ]);
+
+        let path = CriticalPathAnalyzer::analyze_tree(&t, Some(200.0)).unwrap();
+        assert_eq!(ids(&path), vec!["build_scan", "exchange", "probe_scan", "join", "sink"]);
+        assert_eq!(path.path_time_ms, 100.0);
+        assert_eq!(path.coverage_percentage, Some(50.0));
+        assert_eq!(path.nodes[0].dependency, PathDependency::Exchange);
+        assert_eq!(path.nodes[1].dependency, PathDependency::BuildSide);
+        assert_eq!(path.nodes[2].dependency, PathDependency::ProbeSide);
+        assert_eq!(path.nodes[2].contribution_percentage, 30.0);
+    }
+
+    #[test]
+    fn test_contributions_scaled_to_wall_time() {
+        let t = tree(vec![
+            node("sink", "RESULT_SINK", 100, 20, &["scan"]),
+            node("scan", "OLAP_SCAN", 1, 180, &[]),
+        ]);
+
+        let path = CriticalPathAnalyzer::analyze_tree(&t, Some(100.0)).unwrap();
+        let total: f64 = path.nodes.iter().map(|n| n.contribution_percentage).sum();
+        assert!((total - 100.0).abs() < 0.01);
+        assert_eq!(path.nodes[0].contribution_ms, 90.0);
+        assert_eq!(path.coverage_percentage, Some(200.0));
+    }
+
+    #[test]
+    fn test_fixture_profile_with_joins() {
+        use crate::services::profile_analyzer::parser::ProfileComposer;
+
+        let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/profiles/profile11.txt");
+        let text = std::fs::read_to_string(path).unwrap();
+        let profile = ProfileComposer::new().parse(&text).unwrap();
+
+        let path = CriticalPathAnalyzer::analyze(&profile).unwrap();
+        assert_eq!(path.nodes.last().unwrap().dependency, PathDependency::Root);
+        assert!(
+            path.nodes
+                .iter()
+                .any(|n| n.dependency == PathDependency::BuildSide)
+        );
+        let total: f64 = path.nodes.iter().map(|n| n.contribution_percentage).sum();
+        assert!(total <= 100.5, "contributions exceed wall time: {}", total);
+    }
+
+    #[test]
+    fn test_cycle_and_empty_tree() {
+        let t = tree(vec![node("a", "PROJECT", 1, 5, &["b"]), node("b", "PROJECT", 2, 5, &["a"])]);
+        let path = CriticalPathAnalyzer::analyze_tree(&t, None).unwrap();
+        assert_eq!(ids(&path), vec!["b", "a"]);
+
+        let idle = tree(vec![node("a", "PROJECT", 1, 0, &[])]);
+        assert!(CriticalPathAnalyzer::analyze_tree(&idle, None).is_none());
+    }
+}
@@ -0,0 +1,443 @@
+//! EXPLAIN Plan Parsing
+//!
+//! Builds a plan tree from the text output of `EXPLAIN`, `EXPLAIN VERBOSE` or
+//! `EXPLAIN COSTS` (StarRocks and Doris), so the plan can be checked by
+//! `rules::explain` before the query runs.
+//!
+//! Layout of a text plan:
+//! - Every `PLAN FRAGMENT n` starts with its sink. `EXCHANGE ID: m` in the sink
+//!   makes the fragment's top node the child of exchange node `m`.
+//! - Inside a fragment the first (probe) child of a node is printed below it in
+//!   the same column; further children are printed before it in `|----`
+//!   branches, five columns to the right.
+//! - Attribute lines directly follow their node header.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::{BTreeMap, HashSet};
+
+static FRAGMENT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*PLAN FRAGMENT (\d+)").unwrap());
+/// Node header, e.g. `  4:HASH JOIN`, `  |----3:EXCHANGE`, `  7:AGGREGATE (update finalize)`,
+/// `  0:VOlapScanNode(103)`
+static HEADER_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^(?P<prefix>[\s|]*?)(?P<branch>----)?(?P<id>\d+):(?P<name>[A-Za-z][A-Za-z0-9_\- ]*?)\s*(?:\((?P<detail>[^)]*)\))?\s*$",
+    )
+    .unwrap()
+});
+static EXCHANGE_ID_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*EXCHANGE ID:\s*(\d+)").unwrap());
+static CARDINALITY_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\bcardinality\s*[:=]\s*(\d+)").unwrap());
+/// `partitions=3/10` (EXPLAIN), `partitionsRatio=3/10` (VERBOSE), `partition=3/10` (Doris Hive)
+static PARTITIONS_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\bpartitions?(?:Ratio)?\s*=\s*(\d+)/(\d+)").unwrap());
+
+/// Column offset of a `|----` branch relative to its parent
+const BRANCH_INDENT: usize = 5;
+
+/// A node of the text plan
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanNode {
+    pub id: i32,
+    /// Operator name without the Doris `V` prefix, e.g. `HASH JOIN`, `OlapScanNode`
+    pub operator: String,
+    /// Parenthesized part of the header, e.g. `update finalize`
+    pub detail: Option<String>,
+    pub fragment_id: Option<i32>,
+    pub parent: Option<i32>,
+    /// Children in plan order; the first one is the probe side of a join
+    pub children: Vec<i32>,
+    /// Attribute lines with the tree drawing stripped, e.g. `join op: INNER JOIN (BROADCAST)`
+    pub attributes: Vec<String>,
+}
+
+impl PlanNode {
+    /// Node path in the same format as profile diagnostics
+    pub fn label(&self) -> String {
+        format!("{} (plan_node_id={})", self.operator, self.id)
+    }
+
+    /// Value of a `key: value` or `key=value` attribute (key is case-insensitive)
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.iter().find_map(|line| {
+            let head = line.get(..key.len())?;
+            if !head.eq_ignore_ascii_case(key) {
+                return None;
+            }
+            let rest = line[key.len()..].trim_start();
+            rest.strip_prefix(':')
+                .or_else(|| rest.strip_prefix('='))
+                .map(str::trim)
+        })
+    }
+
+    /// Estimated output rows
+    pub fn cardinality(&self) -> Option<u64> {
+        self.attributes
+            .iter()
+            .find_map(|line| CARDINALITY_REGEX.captures(line))
+            .and_then(|caps| caps[1].parse().ok())
+    }
+
+    /// Selected and total partitions of a scan
+    pub fn partitions(&self) -> Option<(u64, u64)> {
+        let caps = self
+            .attributes
+            .iter()
+            .find_map(|line| PARTITIONS_REGEX.captures(line))?;
+        Some((caps[1].parse().ok()?, caps[2].parse().ok()?))
+    }
+
+    /// Scanned table, e.g. `lineitem` or `hive_db.orders`
+    pub fn table(&self) -> Option<String> {
+        let value = self.attribute("table")?;
+        let name = value.split(',').next()?.trim();
+        let name = name
+            .split('(')
+            .next()
+            .unwrap_or(name)
+            .trim_matches('`')
+            .trim();
+        (!name.is_empty()).then(|| name.to_string())
+    }
+
+    pub fn is_scan(&self) -> bool {
+        self.operator.to_uppercase().contains("SCAN")
+    }
+
+    pub fn is_olap_scan(&self) -> bool {
+        self.is_scan() && self.operator.to_uppercase().contains("OLAP")
+    }
+
+    /// Scan of a lake/catalog table (Hive, Iceberg, Hudi, files, ...)
+    pub fn is_external_scan(&self) -> bool {
+        self.is_scan() && !self.is_olap_scan()
+    }
+
+    pub fn is_join(&self) -> bool {
+        self.operator.to_uppercase().contains("JOIN")
+    }
+
+    /// Operator name matches one of the given names (case-insensitive)
+    pub fn is(&self, names: &[&str]) -> bool {
+        names.iter().any(|n| self.operator.eq_ignore_ascii_case(n))
+    }
+}
+
+/// Plan tree parsed from EXPLAIN output
+#[derive(Debug, Clone, Default)]
+pub struct ExplainPlan {
+    pub nodes: BTreeMap<i32, PlanNode>,
+    /// Top node of fragment 0
+    pub root: Option<i32>,
+}
+
+impl ExplainPlan {
+    /// Parse the text output of `EXPLAIN [VERBOSE | COSTS]`
+    pub fn parse(explain: &str) -> Self {
+        let mut nodes: BTreeMap<i32, PlanNode> = BTreeMap::new();
+        let mut root = None;
+
+        let mut fragment_id: Option<i32> = None;
+        let mut fragment_top: Option<i32> = None;
+        // Exchange node IDs fed by the current fragment
+        let mut fragment_exchanges: Vec<i32> = Vec::new();
+        // (exchange node, top node of the feeding fragment)
+        let mut exchange_links: Vec<(i32, i32)> = Vec::new();
+        // Last node seen in each column of the current fragment
+        let mut columns: BTreeMap<usize, i32> = BTreeMap::new();
+        let mut current: Option<i32> = None;
+
+        for line in explain.lines() {
+            if let Some(caps) = FRAGMENT_REGEX.captures(line) {
+                fragment_id = caps[1].parse().ok();
+                fragment_top = None;
+                fragment_exchanges.clear();
+                columns.clear();
+                current = None;
+                continue;
+            }
+
+            if let Some(caps) = HEADER_REGEX.captures(line) {
+                let Ok(id) = caps["id"].parse::<i32>() else { continue };
+                let is_branch = caps.name("branch").is_some();
+                let column = caps.name("id").map_or(0, |m| m.start());
+
+                let parent = if is_branch {
+                    column
+                        .checked_sub(BRANCH_INDENT)
+                        .and_then(|c| columns.get(&c).copied())
+                        .or_else(|| columns.range(..column).next_back().map(|(_, id)| *id))
+                } else {
+                    columns.get(&column).copied()
+                };
+
+                nodes.insert(
+                    id,
+                    PlanNode {
+                        id,
+                        operator: normalize_operator(caps["name"].trim()),
+                        detail: caps.name("detail").map(|m| m.as_str().trim().to_string()),
+                        fragment_id,
+                        parent,
+                        children: vec![],
+                        attributes: vec![],
+                    },
+                );
+                if let Some(parent) = parent.and_then(|p| nodes.get_mut(&p)) {
+                    if is_branch {
+                        parent.children.push(id);
+                    } else {
+                        parent.children.insert(0, id);
+                    }
+                }
+
+                if fragment_top.is_none() {
+                    fragment_top = Some(id);
+                    root = root.or(Some(id));
+                    exchange_links.extend(fragment_exchanges.iter().map(|ex| (*ex, id)));
+                }
+                // Subtrees to the right of this column are complete
+                columns.retain(|c, _| *c < column);
+                columns.insert(column, id);
+                current = Some(id);
+                continue;
+            }
+
+            if fragment_top.is_none() {
+                if let Some(caps) = EXCHANGE_ID_REGEX.captures(line)
+                    && let Ok(id) = caps[1].parse::<i32>()
+                {
+                    fragment_exchanges.push(id);
+                }
+                continue;
+            }
+
+            let text = line
+                .trim_start_matches(|c: char| c.is_whitespace() || c == '|')
+                .trim_end();
+            if text.is_empty() {
+                continue;
+            }
+            if let Some(node) = current.and_then(|id| nodes.get_mut(&id)) {
+                node.attributes.push(text.to_string());
+            }
+        }
+
+        for (exchange, child) in exchange_links {
+            let linkable = exchange != child
+                && nodes.contains_key(&exchange)
+                && nodes.get(&child).is_some_and(|n| n.parent.is_none());
+            if !linkable {
+                continue;
+            }
+            if let Some(node) = nodes.get_mut(&child) {
+                node.parent = Some(exchange);
+            }
+            if let Some(node) = nodes.get_mut(&exchange) {
+                node.children.push(child);
+            }
+        }
+
+        Self { nodes, root }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn get(&self, id: i32) -> Option<&PlanNode> {
+        self.nodes.get(&id)
+    }
+
+    pub fn parent(&self, node: &PlanNode) -> Option<&PlanNode> {
+        node.parent.and_then(|id| self.nodes.get(&id))
+    }
+
+    /// Estimated rows of a node; nodes without an estimate (e.g. exchanges in
+    /// plain EXPLAIN) take it from their only child
+    pub fn estimated_rows(&self, id: i32) -> Option<u64> {
+        let mut node = self.nodes.get(&id)?;
+        for _ in 0..self.nodes.len() {
+            if let Some(rows) = node.cardinality() {
+                return Some(rows);
+            }
+            match node.children.as_slice() {
+                [child] => node = self.nodes.get(child)?,
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// Nodes in depth-first order from the root, followed by any unreachable nodes
+    pub fn nodes_in_order(&self) -> Vec<&PlanNode> {
+        let mut visited: HashSet<i32> = HashSet::new();
+        let mut ordered = Vec::with_capacity(self.nodes.len());
+        let mut stack: Vec<i32> = self.root.into_iter().collect();
+        while let Some(id) = stack.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            let Some(node) = self.nodes.get(&id) else { continue };
+            ordered.push(node);
+            stack.extend(node.children.iter().rev());
+        }
+        ordered.extend(self.nodes.values().filter(|n| !visited.contains(&n.id)));
+        ordered
+    }
+}
+
+/// Doris prefixes vectorized operators with `V` (`VHASH JOIN`, `VOlapScanNode`)
+fn normalize_operator(name: &str) -> String {
+    match name.strip_prefix('V') {
+        Some(rest)
+            if rest.starts_with(|c: char| c.is_ascii_uppercase())
+                && !name.starts_with("VALUES") =>
+        {
+            rest.to_string()
+        },
+        _ => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STARROCKS_PLAN: &str = r#"PLAN FRAGMENT 0
+ OUTPUT EXPRS:18: count
+  PARTITION: UNPARTITIONED
+
+  RESULT SINK
+
+  9:AGGREGATE (merge finalize)
+  |  output: count(18: count)
+  |  group by:
+  |  cardinality: 1
+  |
+  8:EXCHANGE
+     cardinality: 1
+
+PLAN FRAGMENT 1
+ OUTPUT EXPRS:
+  PARTITION: RANDOM
+
+  STREAM DATA SINK
+    EXCHANGE ID: 08
+    UNPARTITIONED
+
+  7:AGGREGATE (update serialize)
+  |  output: count(*)
+  |  cardinality: 1
+  |
+  6:HASH JOIN
+  |  join op: INNER JOIN (BROADCAST)
+  |  equal join conjunct: 1: o_orderkey = 10: l_orderkey
+  |  cardinality: 6000000
+  |
+  |----5:EXCHANGE
+  |       cardinality: 5000000
+  |
+  1:SELECT
+  |  predicates: abs(2: o_custkey) > 10
+  |  cardinality: 1500000
+  |
+  0:OlapScanNode
+     TABLE: orders
+     PREAGGREGATION: ON
+     partitions=12/12
+     rollup: orders
+     tabletRatio=96/96
+     cardinality=1500000
+
+PLAN FRAGMENT 2
+ OUTPUT EXPRS:
+  PARTITION: RANDOM
+
+  STREAM DATA SINK
+    EXCHANGE ID: 05
+    UNPARTITIONED
+
+  4:Project
+  |  cardinality: 5000000
+  |
+  3:HdfsScanNode
+     TABLE: lineitem
+     partitions=30/30
+     cardinality=5000000
+"#;
+
+    #[test]
+    fn test_parse_tree_across_fragments() {
+        let plan = ExplainPlan::parse(STARROCKS_PLAN);
+        assert_eq!(plan.root, Some(9));
+        assert_eq!(plan.nodes.len(), 9);
+
+        let join = plan.get(6).unwrap();
+        assert_eq!(join.operator, "HASH JOIN");
+        assert_eq!(join.children, vec![1, 5]);
+        assert_eq!(join.attribute("join op"), Some("INNER JOIN (BROADCAST)"));
+
+        // Exchanges are linked to the top node of the feeding fragment
+        assert_eq!(plan.get(8).unwrap().children, vec![7]);
+        assert_eq!(plan.get(5).unwrap().children, vec![4]);
+        assert_eq!(plan.get(4).unwrap().parent, Some(5));
+        assert_eq!(plan.get(3).unwrap().parent, Some(4));
+
+        let scan = plan.get(0).unwrap();
+        assert_eq!(scan.parent, Some(1));
+        assert_eq!(scan.table().as_deref(), Some("orders"));
+        assert_eq!(scan.partitions(), Some((12, 12)));
+        assert_eq!(scan.cardinality(), Some(1_500_000));
+        assert!(scan.is_olap_scan());
+        assert!(plan.get(3).unwrap().is_external_scan());
+
+        let agg = plan.get(9).unwrap();
+        assert_eq!(agg.detail.as_deref(), Some("merge finalize"));
+        assert_eq!(plan.nodes_in_order().first().map(|n| n.id), Some(9));
+    }
+
+    #[test]
+    fn test_parse_doris_plan() {
+        let explain = r#"PLAN FRAGMENT 0
+  OUTPUT EXPRS:
+    o_orderkey[#10]
+  PARTITION: HASH_PARTITIONED: o_orderkey[#0]
+
+  VRESULT SINK
+     MYSQL_PROTOCAL
+
+  2:VNESTED LOOP JOIN(95)
+  |  join op: CROSS JOIN()
+  |  cardinality=100
+  |
+  |----1:VEXCHANGE
+  |       offset: 0
+  |
+  0:VOlapScanNode(87)
+     TABLE: tpch.orders(orders), PREAGGREGATION: ON
+     partitions=1/1 (orders)
+     cardinality=10, avgRowSize=0.0, numNodes=1
+
+PLAN FRAGMENT 1
+  STREAM DATA SINK
+    EXCHANGE ID: 01
+    UNPARTITIONED
+
+  3:VOlapScanNode(88)
+     TABLE: tpch.nation(nation), PREAGGREGATION: ON
+     cardinality=10
+"#;
+        let plan = ExplainPlan::parse(explain);
+        assert_eq!(plan.root, Some(2));
+        let join = plan.get(2).unwrap();
+        assert_eq!(join.operator, "NESTED LOOP JOIN");
+        assert_eq!(join.detail.as_deref(), Some("95"));
+        assert_eq!(join.children, vec![0, 1]);
+        assert_eq!(plan.get(0).unwrap().table().as_deref(), Some("tpch.orders"));
+        assert_eq!(plan.get(0).unwrap().partitions(), Some((1, 1)));
+        assert_eq!(plan.estimated_rows(1), Some(10));
+    }
+}
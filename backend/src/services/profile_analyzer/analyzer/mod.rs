@@ -4,17 +4,36 @@
 
 pub mod baseline;
 pub mod baseline_cache;
+pub mod cardinality;
+pub mod critical_path;
+pub mod explain_plan;
 pub mod query_history;
 pub mod root_cause;
 pub mod rule_engine;
 pub mod rules;
+pub mod runtime_filter;
 pub mod thresholds;
+pub mod timeline;
 
 pub use baseline::{AuditLogRecord, BaselineCalculator, PerformanceBaseline, QueryComplexity};
 pub use baseline_cache::{
     BaselineCacheManager, BaselineDriftResult, BaselineProvider, BaselineRefreshConfig,
     BaselineSource, DriftDetail, DriftDirection,
 };
+pub use cardinality::{
+    CardinalityAnalysis, CardinalityAnalyzer, CardinalityError, CardinalityInput, PlannerEstimates,
+    TableStatistics,
+};
+pub use critical_path::{CriticalPath, CriticalPathAnalyzer, CriticalPathNode, PathDependency};
+pub use explain_plan::{ExplainPlan, PlanNode};
 pub use query_history::{QUERY_HISTORY, QueryFingerprint, QueryHistoryService};
 pub use root_cause::{RootCauseAnalysis, RootCauseAnalyzer};
 pub use rule_engine::RuleEngine;
+pub use runtime_filter::{
+    RuntimeFilterAnalyzer, RuntimeFilterEntry, RuntimeFilterProbe, RuntimeFilterReport,
+    RuntimeFilterType, RuntimeFilterVerdict,
+};
+pub use timeline::{
+    BackendTimeline, ExecutionTimeline, FragmentTimeline, InstanceSpread, PipelineTimeline,
+    TimelineAnalyzer,
+};
@@ -36,6 +36,7 @@ use std::time::Instant;
 
 use super::rules::{Diagnostic, RuleSeverity};
 use super::thresholds::QueryType;
+use crate::services::profile_analyzer::i18n::{Arg, msg};
 use crate::services::profile_analyzer::models::Profile;
 
 // ============================================================================
@@ -122,7 +123,7 @@ impl QueryFingerprint {
     }
 
     /// Normalize SQL: replace literals with placeholders
-    fn normalize_sql(sql: &str) -> String {
+    pub fn normalize_sql(sql: &str) -> String {
         let mut result = sql.to_uppercase();
 
         result = regex::Regex::new(r"\b\d+\.?\d*\b")
@@ -141,7 +142,7 @@ impl QueryFingerprint {
     }
 
     /// Extract table names from SQL (simplified)
-    fn extract_tables(sql: &str) -> Vec<String> {
+    pub fn extract_tables(sql: &str) -> Vec<String> {
         let upper = sql.to_uppercase();
         let mut tables = Vec::new();
 
@@ -390,45 +391,29 @@ impl QueryHistoryService {
                 RuleSeverity::Warning
             };
 
-            let time_display = if current_time_ms >= 60000.0 {
-                format!("{:.1}分钟", current_time_ms / 60000.0)
-            } else if current_time_ms >= 1000.0 {
-                format!("{:.1}秒", current_time_ms / 1000.0)
-            } else {
-                format!("{:.0}ms", current_time_ms)
-            };
+            let time_display = format_duration_ms(current_time_ms);
 
-            let p90_display = if p90 >= 60000.0 {
-                format!("{:.1}分钟", p90 / 60000.0)
-            } else if p90 >= 1000.0 {
-                format!("{:.1}秒", p90 / 1000.0)
-            } else {
-                format!("{:.0}ms", p90)
-            };
+            let p90_display = format_duration_ms(p90);
 
             Some(Diagnostic {
                 rule_id: "REG001".to_string(),
-                rule_name: "性能回归".to_string(),
                 severity,
                 node_path: "Query".to_string(),
                 plan_node_id: None,
-                message: format!(
-                    "查询执行时间 {} 是历史 P90 ({}) 的 {:.1} 倍",
-                    time_display, p90_display, ratio
-                ),
-                reason: format!(
-                    "同类查询（{}）历史执行 {} 次，P50={:.0}ms P90={:.0}ms P99={:.0}ms，当前执行显著慢于历史表现。",
+                message: msg!("REG001.message", time_display, p90_display, format!("{:.1}", ratio)),
+                reason: msg!(
+                    "REG001.reason",
                     fingerprint.tables.join(", "),
                     baseline.time_stats.count(),
-                    baseline.time_stats.p50(),
-                    p90,
-                    baseline.time_stats.p99()
+                    format!("{:.0}", baseline.time_stats.p50()),
+                    format!("{:.0}", p90),
+                    format!("{:.0}", baseline.time_stats.p99())
                 ),
                 suggestions: vec![
-                    "检查是否有数据分布变化导致执行计划改变".to_string(),
-                    "检查是否有并发查询导致资源竞争".to_string(),
-                    "检查相关表的统计信息是否过期".to_string(),
-                    "对比历史执行计划是否有差异".to_string(),
+                    msg!("REG001.suggestion.1"),
+                    msg!("REG001.suggestion.2"),
+                    msg!("REG001.suggestion.3"),
+                    msg!("REG001.suggestion.4"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -480,6 +465,17 @@ pub struct HistoryStats {
     pub total_samples: usize,
 }
 
+/// Format a millisecond duration for display in REG001 messages
+fn format_duration_ms(ms: f64) -> Arg {
+    if ms >= 60000.0 {
+        msg!("common.minutes", format!("{:.1}", ms / 60000.0)).into()
+    } else if ms >= 1000.0 {
+        msg!("common.seconds", format!("{:.1}", ms / 1000.0)).into()
+    } else {
+        format!("{:.0}ms", ms).into()
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
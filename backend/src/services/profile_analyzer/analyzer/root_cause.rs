@@ -7,9 +7,10 @@
 //! 4. Causal Graph - build and visualize causal relationships
 
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use super::rules::Diagnostic;
+use crate::services::profile_analyzer::i18n::{Arg, Locale, Message, msg};
 
 // ============================================================================
 // Root Cause Analysis Result Types
@@ -72,7 +73,7 @@ struct IntraNodeRule {
     causes: &'static [&'static str],
     /// Effect diagnostic ID
     effect: &'static str,
-    /// Description of the causal relationship
+    /// Catalog key describing the causal relationship
     description: &'static str,
 }
 
@@ -82,273 +83,274 @@ struct IntraNodeRule {
 /// - S: SCAN node rules (S001-S016)
 /// - J: JOIN node rules (J001-J011)
 /// - A: AGGREGATE node rules (A001-A006)
-/// - Q: Query-level rules (Q001-Q009)
+/// - Q: Query-level rules (Q001-Q010)
 /// - G: General rules (G001-G003)
 /// - E: EXCHANGE node rules (E001-E003)
 /// - T: SORT node rules (T001-T005)
 /// - W: WINDOW node rules (W001)
 /// - I: SINK/Insert rules (I001-I003)
 /// - F: Fragment rules (F001-F003)
+/// - PL: Planner rules (PL001-PL004)
 const INTRA_NODE_RULES: &[IntraNodeRule] = &[
     IntraNodeRule {
         causes: &["S016", "S006"], // Small files (S016), Rowset fragmentation (S006)
         effect: "S007",            // IO bottleneck
-        description: "小文件/碎片化导致IO瓶颈",
+        description: "root_cause.intra.S016_S006_S007",
     },
     IntraNodeRule {
         causes: &["S017"], // ORC Stripe fragmentation
         effect: "S007",    // IO bottleneck
-        description: "Stripe碎片化导致IO瓶颈",
+        description: "root_cause.intra.S017_S007",
     },
     IntraNodeRule {
         causes: &["S017"], // ORC Stripe fragmentation
         effect: "S018",    // IO wait time
-        description: "Stripe碎片化导致IO等待时间长",
+        description: "root_cause.intra.S017_S018",
     },
     IntraNodeRule {
         causes: &["S017"], // ORC Stripe fragmentation
         effect: "G001",    // Time consuming node
-        description: "Stripe碎片化导致SCAN耗时长",
+        description: "root_cause.intra.S017_G001",
     },
     IntraNodeRule {
         causes: &["S018"], // IO wait time
         effect: "G001",    // Time consuming node
-        description: "IO等待时间长导致SCAN耗时长",
+        description: "root_cause.intra.S018_G001",
     },
     IntraNodeRule {
         causes: &["S018"], // IO wait time
         effect: "Q004",    // Low CPU utilization
-        description: "IO等待导致CPU空闲",
+        description: "root_cause.intra.S018_Q004",
     },
     IntraNodeRule {
         causes: &["S009"], // Low cache hit ratio
         effect: "S007",    // IO bottleneck
-        description: "缓存命中率低导致IO瓶颈",
+        description: "root_cause.intra.S009_S007",
     },
     IntraNodeRule {
         causes: &["S009"], // Low cache hit ratio
         effect: "G001",    // Time consuming node (SCAN)
-        description: "缓存命中率低导致SCAN耗时长",
+        description: "root_cause.intra.S009_G001",
     },
     IntraNodeRule {
         causes: &["S008", "S012", "S013"], // ZoneMap/Bitmap/BloomFilter ineffective
         effect: "S003",                    // Poor filter effectiveness
-        description: "索引未生效导致过滤效果差",
+        description: "root_cause.intra.S008_S012_S013_S003",
     },
     IntraNodeRule {
         causes: &["S001"], // Data skew in SCAN
         effect: "G003",    // Execution time skew
-        description: "数据倾斜导致执行时间倾斜",
+        description: "root_cause.intra.S001_G003",
     },
     IntraNodeRule {
         causes: &["S003"], // Poor filter effectiveness
         effect: "S002",    // Full table scan
-        description: "过滤效果差导致全表扫描",
+        description: "root_cause.intra.S003_S002",
     },
     IntraNodeRule {
         causes: &["S010"], // Large compressed ratio
         effect: "S007",    // IO bottleneck (decompression overhead)
-        description: "高压缩率导致解压开销大",
+        description: "root_cause.intra.S010_S007",
     },
     IntraNodeRule {
         causes: &["S014"], // Too many segments
         effect: "S007",    // IO bottleneck
-        description: "Segment过多导致IO瓶颈",
+        description: "root_cause.intra.S014_S007",
     },
     IntraNodeRule {
         causes: &["J002"], // Suboptimal join order
         effect: "J001",    // Hash table too large
-        description: "Join顺序不优导致Hash表过大",
+        description: "root_cause.intra.J002_J001",
     },
     IntraNodeRule {
         causes: &["J005"], // Broadcast table too large
         effect: "E002",    // Network bottleneck
-        description: "Broadcast表过大导致网络瓶颈",
+        description: "root_cause.intra.J005_E002",
     },
     IntraNodeRule {
         causes: &["J003"], // Join probe rows skew
         effect: "G003",    // Execution time skew
-        description: "Join探测端数据倾斜导致时间倾斜",
+        description: "root_cause.intra.J003_G003",
     },
     IntraNodeRule {
         causes: &["J001"], // Hash table too large
         effect: "Q003",    // Spill to disk
-        description: "Hash表过大导致内存溢出到磁盘",
+        description: "root_cause.intra.J001_Q003",
     },
     IntraNodeRule {
         causes: &["J006"], // Missing runtime filter
         effect: "S003",    // Poor filter effectiveness on probe side
-        description: "缺少Runtime Filter导致探测端过滤差",
+        description: "root_cause.intra.J006_S003",
     },
     IntraNodeRule {
         causes: &["J007"], // Runtime filter not pushed down
         effect: "S003",    // Poor filter effectiveness
-        description: "Runtime Filter未下推导致过滤效果差",
+        description: "root_cause.intra.J007_S003",
     },
     IntraNodeRule {
         causes: &["J008"], // Join condition not optimal
         effect: "J001",    // Large hash table
-        description: "Join条件不优导致Hash表过大",
+        description: "root_cause.intra.J008_J001",
     },
     IntraNodeRule {
         causes: &["J009"], // Cross join detected
         effect: "G002",    // High CPU utilization
-        description: "笛卡尔积导致CPU使用率高",
+        description: "root_cause.intra.J009_G002",
     },
     IntraNodeRule {
         causes: &["J010"], // Join type not optimal
         effect: "E002",    // Network bottleneck (wrong distribution)
-        description: "Join类型不优导致数据传输过多",
+        description: "root_cause.intra.J010_E002",
     },
     IntraNodeRule {
         causes: &["A001"], // Aggregation skew
         effect: "Q003",    // Spill occurred
-        description: "聚合倾斜导致内存溢出",
+        description: "root_cause.intra.A001_Q003",
     },
     IntraNodeRule {
         causes: &["A001"], // Aggregation skew
         effect: "G003",    // Execution time skew
-        description: "聚合倾斜导致执行时间倾斜",
+        description: "root_cause.intra.A001_G003",
     },
     IntraNodeRule {
         causes: &["A003"], // Too many distinct keys
         effect: "A002",    // Large hash table
-        description: "Distinct键过多导致Hash表过大",
+        description: "root_cause.intra.A003_A002",
     },
     IntraNodeRule {
         causes: &["A002"], // Large hash table
         effect: "Q003",    // Spill to disk
-        description: "聚合Hash表过大导致溢出",
+        description: "root_cause.intra.A002_Q003",
     },
     IntraNodeRule {
         causes: &["A004"], // Missing streaming aggregation
         effect: "A002",    // Large hash table
-        description: "未使用流式聚合导致内存占用高",
+        description: "root_cause.intra.A004_A002",
     },
     IntraNodeRule {
         causes: &["A005"], // High aggregation cardinality
         effect: "G002",    // High CPU utilization
-        description: "聚合基数过高导致CPU占用高",
+        description: "root_cause.intra.A005_G002",
     },
     IntraNodeRule {
         causes: &["T001"], // Sort data too large
         effect: "Q003",    // Spill to disk
-        description: "排序数据量过大导致溢出",
+        description: "root_cause.intra.T001_Q003",
     },
     IntraNodeRule {
         causes: &["T002"], // TopN not optimized
         effect: "T001",    // Large sort data
-        description: "TopN未优化导致排序数据量大",
+        description: "root_cause.intra.T002_T001",
     },
     IntraNodeRule {
         causes: &["T003"], // Sort without limit
         effect: "T001",    // Large sort data
-        description: "全量排序导致数据量过大",
+        description: "root_cause.intra.T003_T001",
     },
     IntraNodeRule {
         causes: &["T004"], // Multiple sort keys
         effect: "G002",    // High CPU utilization
-        description: "多排序键导致CPU占用高",
+        description: "root_cause.intra.T004_G002",
     },
     IntraNodeRule {
         causes: &["E001"], // Large data shuffle
         effect: "E002",    // Network bottleneck
-        description: "Shuffle数据量大导致网络瓶颈",
+        description: "root_cause.intra.E001_E002",
     },
     IntraNodeRule {
         causes: &["E003"], // Partition skew
         effect: "G003",    // Execution time skew
-        description: "分区倾斜导致执行时间倾斜",
+        description: "root_cause.intra.E003_G003",
     },
     IntraNodeRule {
         causes: &["Q003"], // Spill to disk
         effect: "Q001",    // Query timeout
-        description: "磁盘溢出导致查询变慢可能超时",
+        description: "root_cause.intra.Q003_Q001",
     },
     IntraNodeRule {
         causes: &["Q006"], // Resource queue waiting
         effect: "Q001",    // Query timeout
-        description: "资源队列等待可能导致超时",
+        description: "root_cause.intra.Q006_Q001",
     },
     IntraNodeRule {
         causes: &["S007"], // IO bottleneck
         effect: "Q004",    // Low CPU utilization
-        description: "IO瓶颈导致CPU利用率低",
+        description: "root_cause.intra.S007_Q004",
     },
     IntraNodeRule {
         causes: &["S009"], // Low cache hit
         effect: "Q004",    // Low CPU (waiting for remote IO)
-        description: "缓存未命中导致等待远程IO，CPU空闲",
+        description: "root_cause.intra.S009_Q004",
     },
     IntraNodeRule {
         causes: &["E002"], // Network bottleneck
         effect: "Q004",    // Low CPU (waiting for network)
-        description: "网络等待导致CPU利用率低",
+        description: "root_cause.intra.E002_Q004",
     },
     IntraNodeRule {
         causes: &["G001", "G001b"], // Most/Second consuming node (usually SCAN)
         effect: "Q005",             // Scan time ratio high
-        description: "扫描算子耗时长导致扫描时间占比高",
+        description: "root_cause.intra.G001_G001b_Q005",
     },
     IntraNodeRule {
         causes: &["G002"], // High memory usage node
         effect: "Q002",    // Query peak memory high
-        description: "算子内存高导致查询峰值内存高",
+        description: "root_cause.intra.G002_Q002",
     },
     IntraNodeRule {
         causes: &["G001"], // Most consuming node
         effect: "Q001",    // Query timeout
-        description: "耗时算子导致查询超时",
+        description: "root_cause.intra.G001_Q001",
     },
     IntraNodeRule {
         causes: &["J003"], // Join HashTable memory high
         effect: "G002",    // Node memory high
-        description: "Join HashTable内存高导致节点内存高",
+        description: "root_cause.intra.J003_G002",
     },
     IntraNodeRule {
         causes: &["A002"], // Aggregation HashTable memory high
         effect: "G002",    // Node memory high
-        description: "聚合HashTable内存高导致节点内存高",
+        description: "root_cause.intra.A002_G002",
     },
     IntraNodeRule {
         causes: &["J005"], // Hash collision
         effect: "J003",    // HashTable memory high
-        description: "Hash碰撞导致HashTable内存增大",
+        description: "root_cause.intra.J005_J003",
     },
     IntraNodeRule {
         causes: &["J011"], // Broadcast build side too large
         effect: "J003",    // HashTable memory high
-        description: "Broadcast端数据量大导致HashTable内存高",
+        description: "root_cause.intra.J011_J003",
     },
     IntraNodeRule {
         causes: &["J011"], // Broadcast build side too large
         effect: "G002",    // Node memory high
-        description: "Broadcast端数据量大导致节点内存高",
+        description: "root_cause.intra.J011_G002",
     },
     IntraNodeRule {
         causes: &["E001"], // Large shuffle data
         effect: "Q008",    // Scheduling overhead high
-        description: "Shuffle数据量大导致调度开销增加",
+        description: "root_cause.intra.E001_Q008",
     },
     IntraNodeRule {
         causes: &["Q008"], // Scheduling overhead
         effect: "Q001",    // Query timeout
-        description: "调度开销大导致查询变慢",
+        description: "root_cause.intra.Q008_Q001",
     },
     IntraNodeRule {
         causes: &["P001"], // Complex expression in project
         effect: "G002",    // High CPU utilization
-        description: "复杂表达式计算导致CPU占用高",
+        description: "root_cause.intra.P001_G002",
     },
     IntraNodeRule {
         causes: &["E002"], // Network bottleneck
         effect: "I001",    // Insert slow
-        description: "网络瓶颈导致数据导入慢",
+        description: "root_cause.intra.E002_I001",
     },
     IntraNodeRule {
         causes: &["A001"], // Aggregation skew
         effect: "I002",    // Insert skew
-        description: "聚合倾斜导致导入数据倾斜",
+        description: "root_cause.intra.A001_I002",
     },
 ];
 
@@ -378,7 +380,7 @@ struct InterNodeRule {
     /// Propagation mode (reserved for future use)
     #[allow(dead_code)]
     mode: PropagationMode,
-    /// Description
+    /// Catalog key describing the propagation
     description: &'static str,
 }
 
@@ -394,265 +396,295 @@ const INTER_NODE_RULES: &[InterNodeRule] = &[
         upstream: "S001",   // SCAN data skew
         downstream: "G003", // Execution time skew
         mode: PropagationMode::Skew,
-        description: "SCAN数据倾斜传导到下游执行时间倾斜",
+        description: "root_cause.inter.S001_G003",
     },
     InterNodeRule {
         upstream: "S001",   // SCAN data skew
         downstream: "J003", // Join probe rows skew
         mode: PropagationMode::Skew,
-        description: "SCAN数据倾斜传导到Join探测端",
+        description: "root_cause.inter.S001_J003",
     },
     InterNodeRule {
         upstream: "S001",   // SCAN data skew
         downstream: "A001", // Aggregation skew
         mode: PropagationMode::Skew,
-        description: "SCAN数据倾斜传导到聚合倾斜",
+        description: "root_cause.inter.S001_A001",
     },
     InterNodeRule {
         upstream: "S001",   // SCAN data skew
         downstream: "E003", // Exchange partition skew
         mode: PropagationMode::Skew,
-        description: "SCAN数据倾斜传导到Shuffle分区倾斜",
+        description: "root_cause.inter.S001_E003",
     },
     InterNodeRule {
         upstream: "J003",   // Join probe skew
         downstream: "A001", // Aggregation skew
         mode: PropagationMode::Skew,
-        description: "Join倾斜传导到聚合倾斜",
+        description: "root_cause.inter.J003_A001",
     },
     InterNodeRule {
         upstream: "E003",   // Exchange partition skew
         downstream: "G003", // Execution time skew
         mode: PropagationMode::Skew,
-        description: "Shuffle倾斜传导到执行时间倾斜",
+        description: "root_cause.inter.E003_G003",
     },
     InterNodeRule {
         upstream: "S003",   // Poor filter effectiveness
         downstream: "J001", // Hash table too large
         mode: PropagationMode::DataVolume,
-        description: "过滤效果差导致下游Join数据量大",
+        description: "root_cause.inter.S003_J001",
     },
     InterNodeRule {
         upstream: "S003",   // Poor filter effectiveness
         downstream: "A002", // Aggregation hash table large
         mode: PropagationMode::DataVolume,
-        description: "过滤效果差导致聚合处理数据量大",
+        description: "root_cause.inter.S003_A002",
     },
     InterNodeRule {
         upstream: "S003",   // Poor filter effectiveness
         downstream: "E001", // Large shuffle data
         mode: PropagationMode::DataVolume,
-        description: "过滤效果差导致Shuffle数据量大",
+        description: "root_cause.inter.S003_E001",
     },
     InterNodeRule {
         upstream: "S003",   // Poor filter effectiveness
         downstream: "T001", // Sort data too large
         mode: PropagationMode::DataVolume,
-        description: "过滤效果差导致排序数据量大",
+        description: "root_cause.inter.S003_T001",
     },
     InterNodeRule {
         upstream: "S002",   // Full table scan
         downstream: "J001", // Hash table too large
         mode: PropagationMode::DataVolume,
-        description: "全表扫描导致Join数据量大",
+        description: "root_cause.inter.S002_J001",
     },
     InterNodeRule {
         upstream: "S002",   // Full table scan
         downstream: "E001", // Large shuffle data
         mode: PropagationMode::DataVolume,
-        description: "全表扫描导致Shuffle数据量大",
+        description: "root_cause.inter.S002_E001",
     },
     InterNodeRule {
         upstream: "J001",   // Large join hash table
         downstream: "A002", // Large aggregation hash table
         mode: PropagationMode::DataVolume,
-        description: "Join输出数据量大导致聚合数据量大",
+        description: "root_cause.inter.J001_A002",
     },
     InterNodeRule {
         upstream: "J009",   // Cross join (cartesian product)
         downstream: "A002", // Large aggregation hash table
         mode: PropagationMode::DataVolume,
-        description: "笛卡尔积导致下游数据爆炸",
+        description: "root_cause.inter.J009_A002",
     },
     InterNodeRule {
         upstream: "J009",   // Cross join
         downstream: "T001", // Large sort data
         mode: PropagationMode::DataVolume,
-        description: "笛卡尔积导致排序数据量爆炸",
+        description: "root_cause.inter.J009_T001",
     },
     InterNodeRule {
         upstream: "J001",   // Hash table large
         downstream: "Q003", // Spill
         mode: PropagationMode::Memory,
-        description: "Join内存占用高导致触发Spill",
+        description: "root_cause.inter.J001_Q003",
     },
     InterNodeRule {
         upstream: "A002",   // Aggregation hash table large
         downstream: "Q003", // Spill
         mode: PropagationMode::Memory,
-        description: "聚合内存占用高导致触发Spill",
+        description: "root_cause.inter.A002_Q003",
     },
     InterNodeRule {
         upstream: "T001",   // Large sort data
         downstream: "Q003", // Spill
         mode: PropagationMode::Memory,
-        description: "排序数据量大导致触发Spill",
+        description: "root_cause.inter.T001_Q003",
     },
     InterNodeRule {
         upstream: "W001",   // Window function memory
         downstream: "Q003", // Spill
         mode: PropagationMode::Memory,
-        description: "窗口函数内存占用导致Spill",
+        description: "root_cause.inter.W001_Q003",
     },
     InterNodeRule {
         upstream: "S007",   // IO bottleneck (SCAN level)
         downstream: "G001", // Time consuming node (if same SCAN node)
         mode: PropagationMode::IoWait,
-        description: "IO瓶颈导致SCAN节点耗时长",
+        description: "root_cause.inter.S007_G001",
     },
     InterNodeRule {
         upstream: "S017",   // ORC Stripe fragmentation
         downstream: "G001", // Time consuming node
         mode: PropagationMode::IoWait,
-        description: "Stripe碎片化导致SCAN节点耗时长",
+        description: "root_cause.inter.S017_G001",
     },
     InterNodeRule {
         upstream: "S017",   // Stripe fragmentation
         downstream: "S018", // IO wait time
         mode: PropagationMode::IoWait,
-        description: "Stripe碎片化导致IO等待",
+        description: "root_cause.inter.S017_S018",
     },
     InterNodeRule {
         upstream: "S018",   // IO wait time
         downstream: "G001", // Time consuming node
         mode: PropagationMode::IoWait,
-        description: "IO等待导致节点耗时长",
+        description: "root_cause.inter.S018_G001",
     },
     InterNodeRule {
         upstream: "S018",   // IO wait time
         downstream: "Q004", // Low CPU
         mode: PropagationMode::IoWait,
-        description: "IO等待导致CPU利用率低",
+        description: "root_cause.inter.S018_Q004",
     },
     InterNodeRule {
         upstream: "Q003",   // Spill to disk
         downstream: "G001", // Time consuming node
         mode: PropagationMode::IoWait,
-        description: "磁盘溢出导致节点耗时长",
+        description: "root_cause.inter.Q003_G001",
     },
     InterNodeRule {
         upstream: "G001",   // Most consuming node (SCAN)
         downstream: "Q005", // Scan time ratio high (Query level)
         mode: PropagationMode::DataVolume,
-        description: "SCAN耗时长导致扫描时间占比高",
+        description: "root_cause.inter.G001_Q005",
     },
     InterNodeRule {
         upstream: "G001b",  // Second consuming node
         downstream: "Q005", // Scan time ratio high
         mode: PropagationMode::DataVolume,
-        description: "次耗时SCAN导致扫描时间占比高",
+        description: "root_cause.inter.G001b_Q005",
     },
     InterNodeRule {
         upstream: "G002",   // High memory node
         downstream: "Q002", // Query peak memory high
         mode: PropagationMode::Memory,
-        description: "节点内存高导致查询峰值内存高",
+        description: "root_cause.inter.G002_Q002",
     },
     InterNodeRule {
         upstream: "G001",   // Most consuming node
         downstream: "Q001", // Query timeout
         mode: PropagationMode::IoWait,
-        description: "耗时算子导致查询超时",
+        description: "root_cause.intra.G001_Q001",
     },
     InterNodeRule {
         upstream: "J003",   // Join HashTable memory
         downstream: "Q002", // Query peak memory
         mode: PropagationMode::Memory,
-        description: "Join内存高导致查询峰值内存高",
+        description: "root_cause.inter.J003_Q002",
     },
     InterNodeRule {
         upstream: "A002",   // Aggregation memory
         downstream: "Q002", // Query peak memory
         mode: PropagationMode::Memory,
-        description: "聚合内存高导致查询峰值内存高",
+        description: "root_cause.inter.A002_Q002",
     },
     InterNodeRule {
         upstream: "E001",   // Large shuffle
         downstream: "Q008", // Scheduling overhead
         mode: PropagationMode::DataVolume,
-        description: "Shuffle数据量大导致调度开销增加",
+        description: "root_cause.intra.E001_Q008",
     },
     InterNodeRule {
         upstream: "Q008",   // Scheduling overhead
         downstream: "Q001", // Query timeout
         mode: PropagationMode::IoWait,
-        description: "调度开销大导致查询超时",
+        description: "root_cause.inter.Q008_Q001",
     },
     InterNodeRule {
         upstream: "J011",   // Broadcast too large
         downstream: "G002", // High memory node
         mode: PropagationMode::Memory,
-        description: "Broadcast数据大导致节点内存高",
+        description: "root_cause.inter.J011_G002",
     },
     InterNodeRule {
         upstream: "J005",   // Hash collision
         downstream: "G002", // High memory node
         mode: PropagationMode::Memory,
-        description: "Hash碰撞导致节点内存增大",
+        description: "root_cause.inter.J005_G002",
     },
     InterNodeRule {
         upstream: "Q002",   // Query peak memory high
         downstream: "Q001", // Query timeout
         mode: PropagationMode::Memory,
-        description: "内存压力过大可能导致查询超时",
+        description: "root_cause.inter.Q002_Q001",
     },
     InterNodeRule {
         upstream: "A002",   // Aggregation HashTable large
         downstream: "G001", // Most consuming node (AGG)
         mode: PropagationMode::Memory,
-        description: "聚合HashTable过大导致聚合算子耗时长",
+        description: "root_cause.inter.A002_G001",
     },
     InterNodeRule {
         upstream: "J003",   // Join HashTable memory
         downstream: "G001", // Most consuming node (JOIN)
         mode: PropagationMode::Memory,
-        description: "Join HashTable过大导致Join算子耗时长",
+        description: "root_cause.inter.J003_G001",
     },
     InterNodeRule {
         upstream: "J001",   // Join hash table too large
         downstream: "G001", // Most consuming node
         mode: PropagationMode::Memory,
-        description: "Join数据量大导致Join算子耗时长",
+        description: "root_cause.inter.J001_G001",
     },
     InterNodeRule {
         upstream: "E001",   // Large shuffle data
         downstream: "G001", // Most consuming node (EXCHANGE)
         mode: PropagationMode::DataVolume,
-        description: "Shuffle数据量大导致EXCHANGE算子耗时长",
+        description: "root_cause.inter.E001_G001",
     },
     InterNodeRule {
         upstream: "E001",   // Large shuffle (cause)
         downstream: "E002", // Network ratio high (effect)
         mode: PropagationMode::IoWait,
-        description: "大Shuffle导致网络时间占比高",
+        description: "root_cause.inter.E001_E002",
     },
     InterNodeRule {
         upstream: "S009",   // Low cache hit
         downstream: "Q004", // Low CPU utilization
         mode: PropagationMode::IoWait,
-        description: "缓存未命中导致等待远程IO，CPU空闲",
+        description: "root_cause.intra.S009_Q004",
     },
     InterNodeRule {
         upstream: "S007",   // IO bottleneck
         downstream: "Q004", // Low CPU utilization
         mode: PropagationMode::IoWait,
-        description: "IO瓶颈导致CPU利用率低",
+        description: "root_cause.intra.S007_Q004",
     },
     InterNodeRule {
         upstream: "E002",   // Network time ratio high
         downstream: "Q004", // Low CPU (waiting for network)
         mode: PropagationMode::IoWait,
-        description: "网络等待导致CPU利用率低",
+        description: "root_cause.intra.E002_Q004",
+    },
+    InterNodeRule {
+        upstream: "PL004",  // Cardinality misestimation
+        downstream: "J002", // Join build side too large
+        mode: PropagationMode::DataVolume,
+        description: "root_cause.inter.PL004_J002",
+    },
+    InterNodeRule {
+        upstream: "PL004",  // Cardinality misestimation
+        downstream: "J011", // Broadcast used wrongly
+        mode: PropagationMode::DataVolume,
+        description: "root_cause.inter.PL004_J011",
+    },
+    InterNodeRule {
+        upstream: "PL004",  // Cardinality misestimation
+        downstream: "J003", // HashTable memory high
+        mode: PropagationMode::DataVolume,
+        description: "root_cause.inter.PL004_J003",
+    },
+    InterNodeRule {
+        upstream: "PL004",  // Cardinality misestimation
+        downstream: "A002", // Aggregation HashTable large
+        mode: PropagationMode::DataVolume,
+        description: "root_cause.inter.PL004_A002",
+    },
+    InterNodeRule {
+        upstream: "PL004",  // Cardinality misestimation
+        downstream: "A006", // Low local aggregation ratio
+        mode: PropagationMode::DataVolume,
+        description: "root_cause.inter.PL004_A006",
     },
 ];
 
@@ -664,8 +696,8 @@ const INTER_NODE_RULES: &[InterNodeRule] = &[
 pub struct RootCauseAnalyzer;
 
 impl RootCauseAnalyzer {
-    /// Analyze diagnostics and identify root causes
-    pub fn analyze(diagnostics: &[Diagnostic]) -> RootCauseAnalysis {
+    /// Analyze diagnostics and identify root causes, rendering text for `locale`
+    pub fn analyze(diagnostics: &[Diagnostic], locale: Locale) -> RootCauseAnalysis {
         if diagnostics.is_empty() {
             return RootCauseAnalysis::default();
         }
@@ -679,11 +711,11 @@ impl RootCauseAnalyzer {
         let all_edges: Vec<(String, String, String)> =
             intra_edges.into_iter().chain(inter_edges).collect();
 
-        let root_causes = Self::identify_root_causes(diagnostics, &all_edges, &diag_map);
+        let root_causes = Self::identify_root_causes(diagnostics, &all_edges, &diag_map, locale);
 
-        let causal_chains = Self::build_causal_chains(&root_causes, &all_edges, &diag_map);
+        let causal_chains = Self::build_causal_chains(&root_causes, &all_edges, &diag_map, locale);
 
-        let summary = Self::generate_summary(&root_causes);
+        let summary = Self::generate_summary(&root_causes).render(locale);
 
         RootCauseAnalysis {
             root_causes,
@@ -759,6 +791,7 @@ impl RootCauseAnalyzer {
         diagnostics: &[Diagnostic],
         edges: &[(String, String, String)],
         diag_map: &HashMap<String, Vec<&Diagnostic>>,
+        locale: Locale,
     ) -> Vec<RootCause> {
         let effects: HashSet<&str> = edges.iter().map(|(_, effect, _)| effect.as_str()).collect();
 
@@ -802,16 +835,19 @@ impl RootCauseAnalyzer {
                 let symptom_bonus = symptoms.len() as f64 * 10.0;
                 let impact = (base_impact + symptom_bonus).min(100.0);
 
-                let suggestions = Self::merge_suggestions_for_root_cause(diags);
+                let suggestions = Self::merge_suggestions(diags)
+                    .iter()
+                    .map(|s| s.render(locale))
+                    .collect();
 
                 root_causes.push(RootCause {
                     id: format!("RC{:03}", rc_counter),
                     diagnostic_ids: vec![rule_id.to_string()],
-                    description: first_diag.message.clone(),
+                    description: first_diag.message.render(locale),
                     impact_percentage: impact,
                     confidence: 1.0,
                     affected_nodes: diags.iter().map(|d| d.node_path.clone()).collect(),
-                    evidence: vec![first_diag.reason.clone()],
+                    evidence: vec![first_diag.reason.render(locale)],
                     symptoms,
                     suggestions,
                 });
@@ -829,70 +865,58 @@ impl RootCauseAnalyzer {
 
     /// Merge suggestions from multiple diagnostics of the same rule
     /// Consolidates similar suggestions (e.g., different table names) into one
-    fn merge_suggestions_for_root_cause(diags: &[&Diagnostic]) -> Vec<String> {
+    pub fn merge_suggestions(diags: &[&Diagnostic]) -> Vec<Message> {
         if diags.len() == 1 {
             return diags[0].suggestions.clone();
         }
 
-        let tables: Vec<String> = diags
-            .iter()
-            .filter_map(|d| Self::extract_table_name(&d.reason))
-            .collect::<HashSet<_>>()
-            .into_iter()
-            .collect();
+        let merged_key = match diags[0].suggestions.first().map(Message::key) {
+            Some("S017.suggestion.1") => Some("merge.small_files"),
+            Some("S017.suggestion.2") => Some("merge.compaction"),
+            _ => None,
+        };
 
-        let first_sug = diags[0]
-            .suggestions
-            .first()
-            .map(|s| s.as_str())
-            .unwrap_or("");
+        if let Some(merged_key) = merged_key {
+            // The table name is the first parameter of both S017 suggestions
+            let tables: Vec<&str> = diags
+                .iter()
+                .filter_map(|d| d.suggestions.first())
+                .filter_map(|s| match s.args().first() {
+                    Some(Arg::Text(table)) => Some(table.as_str()),
+                    _ => None,
+                })
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
 
-        if first_sug.contains("外表小文件合并方案") || first_sug.contains("Compaction 合并碎片")
-        {
-            let tables_str = if tables.is_empty() {
-                format!("{} 个表", diags.len())
+            let tables_arg: Arg = if tables.is_empty() {
+                msg!("merge.table_count", diags.len()).into()
             } else if tables.len() <= 3 {
-                tables.join(", ")
+                tables.join(", ").into()
             } else {
-                format!("{} 等 {} 个表", tables[0], tables.len())
+                msg!("merge.tables_more", tables[0], tables.len()).into()
             };
 
-            return if first_sug.contains("外表小文件合并方案") {
-                vec![format!(
-                    "外表小文件合并 (涉及: {}): ①ALTER TABLE <table> PARTITION(...) CONCATENATE; \
-                     ②INSERT OVERWRITE TABLE <table> SELECT * FROM <table>; \
-                     ③Spark: df.repartition(N).saveAsTable('<table>'); \
-                     ④SET connector_io_tasks_per_scan_operator=64",
-                    tables_str
-                )]
-            } else {
-                vec![format!("执行 Compaction: ALTER TABLE <{}> COMPACT", tables_str)]
-            };
+            return vec![msg!(merged_key, tables_arg)];
         }
 
         let mut seen = HashSet::new();
         diags
             .iter()
             .flat_map(|d| d.suggestions.iter())
-            .filter(|s| seen.insert(s.as_str()))
+            .filter(|s| seen.insert(*s))
             .take(3)
             .cloned()
             .collect()
     }
 
-    /// Extract table name from reason like "外表「table_name」的 ORC..."
-    fn extract_table_name(reason: &str) -> Option<String> {
-        let start = reason.find('「')?;
-        let end = reason.find('」')?;
-        (end > start).then(|| reason[start + 3..end].to_string())
-    }
-
     /// Build causal chains from root causes to symptoms
     /// Deduplicates by rule_name chain (not rule_id) to avoid visual duplicates
     fn build_causal_chains(
         root_causes: &[RootCause],
         edges: &[(String, String, String)],
         diag_map: &HashMap<String, Vec<&Diagnostic>>,
+        locale: Locale,
     ) -> Vec<CausalChain> {
         let mut chains = Vec::new();
         let mut seen_rule_id_chains: HashSet<String> = HashSet::new();
@@ -919,7 +943,7 @@ impl RootCauseAnalyzer {
                             diag_map
                                 .get(id)
                                 .and_then(|d| d.first())
-                                .map(|d| d.rule_name.clone())
+                                .map(|d| d.rule_name().render(locale))
                                 .unwrap_or_else(|| id.clone())
                         })
                         .collect();
@@ -941,17 +965,18 @@ impl RootCauseAnalyzer {
                                 .iter()
                                 .find(|(c, e, _)| c == node_id && e == &path[i + 1])
                             {
-                                explanations.push(desc.clone());
+                                explanations.push(Message::new(desc.clone()).render(locale));
                             }
                         }
                     }
 
                     let explanation = if explanations.is_empty() {
-                        format!(
-                            "{} 导致 {}",
+                        msg!(
+                            "root_cause.chain",
                             names.first().unwrap_or(&path[0]),
                             names.last().unwrap_or(&path[0])
                         )
+                        .render(locale)
                     } else {
                         explanations.join("; ")
                     };
@@ -1010,21 +1035,17 @@ impl RootCauseAnalyzer {
     }
 
     /// Generate a natural language summary
-    fn generate_summary(root_causes: &[RootCause]) -> String {
+    fn generate_summary(root_causes: &[RootCause]) -> Message {
         if root_causes.is_empty() {
-            return "未发现明显的性能问题根因".to_string();
+            return msg!("root_cause.summary_none");
         }
 
         if root_causes.len() == 1 {
             let rc = &root_causes[0];
             if rc.symptoms.is_empty() {
-                format!("发现 1 个根因: {}", rc.description)
+                msg!("root_cause.summary_single", rc.description)
             } else {
-                format!(
-                    "发现 1 个根因: {}，导致了 {} 个下游问题",
-                    rc.description,
-                    rc.symptoms.len()
-                )
+                msg!("root_cause.summary_single_with_symptoms", rc.description, rc.symptoms.len())
             }
         } else {
             let top_causes: Vec<&str> = root_causes
@@ -1033,11 +1054,7 @@ impl RootCauseAnalyzer {
                 .map(|rc| rc.diagnostic_ids.first().map(|s| s.as_str()).unwrap_or(""))
                 .collect();
 
-            format!(
-                "发现 {} 个独立根因，主要包括: {}。建议按优先级依次解决",
-                root_causes.len(),
-                top_causes.join(", ")
-            )
+            msg!("root_cause.summary_multiple", root_causes.len(), top_causes.join(", "))
         }
     }
 }
@@ -1050,13 +1067,12 @@ mod tests {
     fn make_diag(rule_id: &str, node_path: &str, severity: RuleSeverity) -> Diagnostic {
         Diagnostic {
             rule_id: rule_id.to_string(),
-            rule_name: format!("Rule {}", rule_id),
             severity,
             node_path: node_path.to_string(),
             plan_node_id: None,
-            message: format!("Message for {}", rule_id),
-            reason: format!("Reason for {}", rule_id),
-            suggestions: vec![format!("Fix {}", rule_id)],
+            message: Message::new(format!("{}.message", rule_id)),
+            reason: Message::new(format!("{}.reason", rule_id)),
+            suggestions: vec![Message::new(format!("{}.suggestion", rule_id))],
             parameter_suggestions: vec![],
             threshold_metadata: None,
         }
@@ -1069,7 +1085,7 @@ mod tests {
             make_diag("S007", "Fragment_1/Pipeline_0/SCAN", RuleSeverity::Error),
         ];
 
-        let result = RootCauseAnalyzer::analyze(&diagnostics);
+        let result = RootCauseAnalyzer::analyze(&diagnostics, Locale::Zh);
 
         assert!(!result.root_causes.is_empty());
         assert!(
@@ -1092,7 +1108,7 @@ mod tests {
             make_diag("G003", "Fragment_1/Pipeline_1/JOIN", RuleSeverity::Warning),
         ];
 
-        let result = RootCauseAnalyzer::analyze(&diagnostics);
+        let result = RootCauseAnalyzer::analyze(&diagnostics, Locale::Zh);
 
         assert!(!result.root_causes.is_empty());
         assert!(
@@ -1110,8 +1126,31 @@ mod tests {
             make_diag("J002", "Fragment_1/Pipeline_1/JOIN", RuleSeverity::Warning),
         ];
 
-        let result = RootCauseAnalyzer::analyze(&diagnostics);
+        let result = RootCauseAnalyzer::analyze(&diagnostics, Locale::Zh);
 
         assert_eq!(result.root_causes.len(), 2);
     }
+
+    #[test]
+    fn test_merge_table_suggestions() {
+        let diagnostics: Vec<Diagnostic> = ["hive.a", "hive.b"]
+            .iter()
+            .map(|table| {
+                let mut diag =
+                    make_diag("S017", "Fragment_1/Pipeline_0/SCAN", RuleSeverity::Warning);
+                diag.suggestions = vec![msg!("S017.suggestion.1", table, table, table, table)];
+                diag
+            })
+            .collect();
+        let diags: Vec<&Diagnostic> = diagnostics.iter().collect();
+
+        let merged = RootCauseAnalyzer::merge_suggestions(&diags);
+
+        assert_eq!(merged, vec![msg!("merge.small_files", "hive.a, hive.b")]);
+        assert!(
+            merged[0]
+                .render(Locale::En)
+                .starts_with("Merge small files of external tables")
+        );
+    }
 }
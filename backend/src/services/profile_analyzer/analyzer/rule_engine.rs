@@ -4,13 +4,19 @@
 //! conclusion and performance score calculation.
 
 use super::baseline::QueryComplexity;
+use super::cardinality::{CardinalityAnalysis, CardinalityAnalyzer};
+use super::critical_path::{CriticalPath, CriticalPathAnalyzer};
 use super::rules::{
     Diagnostic, DiagnosticRule, RuleContext, RuleSeverity, get_all_rules, get_query_rules,
 };
 use super::thresholds::{DynamicThresholds, QueryType};
+use crate::services::profile_analyzer::i18n::{Message, msg};
 use crate::services::profile_analyzer::models::*;
 use std::collections::HashSet;
 
+/// Score penalty multiplier for diagnostics on the critical path
+const CRITICAL_PATH_PENALTY_WEIGHT: f64 = 1.5;
+
 /// Rule engine configuration
 #[derive(Debug, Clone)]
 pub struct RuleEngineConfig {
@@ -69,6 +75,27 @@ impl RuleEngine {
         profile: &Profile,
         cluster_variables: Option<&std::collections::HashMap<String, String>>,
         cluster_id: Option<i64>,
+    ) -> Vec<Diagnostic> {
+        let critical_path = CriticalPathAnalyzer::analyze(profile);
+        self.analyze_with_cardinality(
+            profile,
+            cluster_variables,
+            cluster_id,
+            None,
+            critical_path.as_ref(),
+        )
+    }
+
+    /// Analyze a profile with baseline support and planner cardinality errors
+    /// cardinality: estimated vs actual rows, each error becomes a PL004 diagnostic
+    /// critical_path: the profile's critical path, used to rank diagnostics
+    pub fn analyze_with_cardinality(
+        &self,
+        profile: &Profile,
+        cluster_variables: Option<&std::collections::HashMap<String, String>>,
+        cluster_id: Option<i64>,
+        cardinality: Option<&CardinalityAnalysis>,
+        critical_path: Option<&CriticalPath>,
     ) -> Vec<Diagnostic> {
         let query_type = QueryType::from_sql(&profile.summary.sql_statement);
 
@@ -104,7 +131,6 @@ impl RuleEngine {
             {
                 diagnostics.push(Diagnostic {
                     rule_id: diag.rule_id,
-                    rule_name: diag.rule_name,
                     severity: diag.severity,
                     node_path: "Query".to_string(),
                     plan_node_id: None,
@@ -171,11 +197,27 @@ impl RuleEngine {
             }
         }
 
+        if let Some(analysis) = cardinality {
+            diagnostics.extend(
+                CardinalityAnalyzer::to_diagnostics(analysis)
+                    .into_iter()
+                    .filter(|d| d.severity >= self.config.min_severity),
+            );
+        }
+
         if let Some(regression) = super::query_history::QUERY_HISTORY.record_and_detect(profile) {
             diagnostics.push(regression);
         }
 
-        diagnostics.sort_by(|a, b| b.severity.cmp(&a.severity));
+        // Within the same severity, issues on the critical path come first: they are
+        // the ones that actually determined the wall-clock time
+        let critical_nodes = Self::critical_plan_nodes(critical_path);
+        diagnostics.sort_by(|a, b| {
+            b.severity.cmp(&a.severity).then_with(|| {
+                Self::on_critical_path(b, &critical_nodes)
+                    .cmp(&Self::on_critical_path(a, &critical_nodes))
+            })
+        });
 
         diagnostics = self.deduplicate(diagnostics);
 
@@ -186,6 +228,16 @@ impl RuleEngine {
         diagnostics
     }
 
+    /// Plan node IDs on the critical path of the profile
+    fn critical_plan_nodes(critical_path: Option<&CriticalPath>) -> HashSet<i32> {
+        critical_path.map(|p| p.plan_node_ids()).unwrap_or_default()
+    }
+
+    fn on_critical_path(diag: &Diagnostic, critical_nodes: &HashSet<i32>) -> bool {
+        diag.plan_node_id
+            .is_some_and(|id| critical_nodes.contains(&id))
+    }
+
     /// Deduplicate diagnostics by rule_id and node
     fn deduplicate(&self, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
         let mut seen = std::collections::HashSet::new();
@@ -214,9 +266,9 @@ impl Default for RuleEngine {
 
 impl RuleEngine {
     /// Generate a conclusion based on diagnostics and profile
-    pub fn generate_conclusion(diagnostics: &[Diagnostic], profile: &Profile) -> String {
+    pub fn generate_conclusion(diagnostics: &[Diagnostic], profile: &Profile) -> Message {
         if diagnostics.is_empty() {
-            return "查询执行良好，未发现明显性能问题。".to_string();
+            return msg!("conclusion.healthy");
         }
 
         let error_count = diagnostics
@@ -231,30 +283,26 @@ impl RuleEngine {
         let total_time = Self::parse_total_time(&profile.summary.total_time).unwrap_or(0.0);
 
         if error_count > 0 {
-            format!(
-                "查询存在{}个严重性能问题，执行时间较长（{}）。主要问题是{}。建议优先解决严重问题。",
+            msg!(
+                "conclusion.severe",
                 error_count,
                 Self::format_duration(total_time),
                 diagnostics
                     .first()
-                    .map(|d| d.rule_name.as_str())
-                    .unwrap_or("未知")
+                    .map(|d| d.rule_name())
+                    .unwrap_or_else(|| msg!("common.unknown"))
             )
         } else if warning_count > 2 {
-            format!(
-                "查询存在{}个中等程度性能问题，整体性能需优化。执行时间{}。",
-                warning_count,
-                Self::format_duration(total_time)
-            )
+            msg!("conclusion.moderate", warning_count, Self::format_duration(total_time))
         } else if total_time > 300.0 {
-            format!("查询执行时间较长（{}），建议关注性能热点。", Self::format_duration(total_time))
+            msg!("conclusion.slow", Self::format_duration(total_time))
         } else {
-            format!("查询发现{}个小问题，整体性能可接受。", diagnostics.len())
+            msg!("conclusion.minor", diagnostics.len())
         }
     }
 
     /// Generate aggregated suggestions from diagnostics
-    pub fn generate_suggestions(diagnostics: &[Diagnostic]) -> Vec<String> {
+    pub fn generate_suggestions(diagnostics: &[Diagnostic]) -> Vec<Message> {
         let mut suggestions = Vec::new();
         let mut unique_suggestions = HashSet::new();
 
@@ -270,8 +318,13 @@ impl RuleEngine {
     }
 
     /// Calculate performance score (0-100) based on diagnostics
-    pub fn calculate_performance_score(diagnostics: &[Diagnostic], profile: &Profile) -> f64 {
+    pub fn calculate_performance_score(
+        diagnostics: &[Diagnostic],
+        profile: &Profile,
+        critical_path: Option<&CriticalPath>,
+    ) -> f64 {
         let mut score: f64 = 100.0;
+        let critical_nodes = Self::critical_plan_nodes(critical_path);
 
         for diag in diagnostics {
             let penalty = match diag.severity {
@@ -279,7 +332,13 @@ impl RuleEngine {
                 RuleSeverity::Warning => 10.0,
                 RuleSeverity::Info => 3.0,
             };
-            score -= penalty;
+            // Issues on the critical path directly cost wall-clock time
+            let weight = if Self::on_critical_path(diag, &critical_nodes) {
+                CRITICAL_PATH_PENALTY_WEIGHT
+            } else {
+                1.0
+            };
+            score -= penalty * weight;
         }
 
         if let Ok(total_seconds) = Self::parse_total_time(&profile.summary.total_time) {
@@ -344,13 +403,13 @@ impl RuleEngine {
     }
 
     /// Format duration to human-readable string
-    fn format_duration(seconds: f64) -> String {
+    fn format_duration(seconds: f64) -> Message {
         if seconds >= 3600.0 {
-            format!("{:.1}小时", seconds / 3600.0)
+            msg!("common.hours", format!("{:.1}", seconds / 3600.0))
         } else if seconds >= 60.0 {
-            format!("{:.0}分钟", seconds / 60.0)
+            msg!("common.minutes", format!("{:.0}", seconds / 60.0))
         } else {
-            format!("{:.1}秒", seconds)
+            msg!("common.seconds", format!("{:.1}", seconds))
         }
     }
 }
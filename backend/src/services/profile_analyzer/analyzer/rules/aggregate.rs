@@ -14,9 +14,6 @@ impl DiagnosticRule for A001AggregationSkew {
     fn id(&self) -> &str {
         "A001"
     }
-    fn name(&self) -> &str {
-        "聚合数据倾斜"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("AGG")
@@ -44,21 +41,23 @@ impl DiagnosticRule for A001AggregationSkew {
         if ratio > skew_threshold {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})",
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "聚合存在数据倾斜，max/avg 比率为 {:.2} (阈值: {:.1})",
-                    ratio, skew_threshold
+                message: msg!(
+                    "A001.message",
+                    format!("{:.2}", ratio),
+                    format!("{:.1}", skew_threshold)
                 ),
-                reason: "聚合算子多个实例处理的数据量存在明显差异，部分实例成为瓶颈。通常是 GROUP BY 键的数据分布不均匀导致。".to_string(),
+                reason: msg!("A001.reason"),
                 suggestions: vec![
-                    "检查 GROUP BY 键的数据分布".to_string(),
-                    "考虑使用两阶段聚合".to_string(),
-                    "检查是否存在热点键".to_string(),
+                    msg!("A001.suggestion.1"),
+                    msg!("A001.suggestion.2"),
+                    msg!("A001.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -78,9 +77,6 @@ impl DiagnosticRule for A002HashTableTooLarge {
     fn id(&self) -> &str {
         "A002"
     }
-    fn name(&self) -> &str {
-        "聚合 HashTable 过大"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("AGG")
@@ -94,22 +90,19 @@ impl DiagnosticRule for A002HashTableTooLarge {
         if memory > memory_threshold {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
-                plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "聚合 HashTable 内存使用 {} (阈值: {})",
-                    format_bytes(memory),
-                    format_bytes(memory_threshold)
+                    context.node.plan_node_id.unwrap_or(-1)
                 ),
-                reason: "HashTable 占用内存过大，可能导致内存压力或触发 Spill。通常是 GROUP BY 键基数过高或聚合函数状态过大。".to_string(),
+                plan_node_id: context.node.plan_node_id,
+                message: msg!("A002.message", format_bytes(memory), format_bytes(memory_threshold)),
+                reason: msg!("A002.reason"),
                 suggestions: vec![
-                    "检查 GROUP BY 基数是否过高".to_string(),
-                    "考虑使用物化视图预聚合".to_string(),
-                    "启用 Spill 功能避免 OOM".to_string(),
+                    msg!("A002.suggestion.1"),
+                    msg!("A002.suggestion.2"),
+                    msg!("A002.suggestion.3"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
@@ -134,9 +127,6 @@ impl DiagnosticRule for A004HighCardinality {
     fn id(&self) -> &str {
         "A004"
     }
-    fn name(&self) -> &str {
-        "高基数 GROUP BY"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("AGG")
@@ -150,12 +140,12 @@ impl DiagnosticRule for A004HighCardinality {
         if hash_size > 10_000_000.0 {
             let group_keys = context
                 .get_group_by_keys()
-                .unwrap_or_else(|| "未知".to_string());
+                .map(Arg::from)
+                .unwrap_or_else(|| msg!("common.unknown").into());
             let memory = context.get_memory_usage().unwrap_or(0);
 
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -163,18 +153,17 @@ impl DiagnosticRule for A004HighCardinality {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("GROUP BY 基数过高 ({:.0} 个分组)", hash_size),
-                reason: format!(
-                    "GROUP BY 键「{}」的基数过高（{:.0} 个唯一值），导致 HashTable 占用 {} 内存，聚合效率下降。",
+                message: msg!("A004.message", format!("{:.0}", hash_size)),
+                reason: msg!(
+                    "A004.reason",
                     group_keys,
-                    hash_size,
+                    format!("{:.0}", hash_size),
                     format_bytes(memory)
                 ),
                 suggestions: vec![
-                    format!("检查 GROUP BY 键「{}」是否都必要，减少不必要的分组列", group_keys),
-                    "考虑使用流式聚合: SET streaming_preaggregation_mode = 'force_streaming'"
-                        .to_string(),
-                    "考虑创建物化视图预聚合常用分组".to_string(),
+                    msg!("A004.suggestion.1", group_keys),
+                    msg!("A004.suggestion.2"),
+                    msg!("A004.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -192,9 +181,6 @@ impl DiagnosticRule for A003DataSkew {
     fn id(&self) -> &str {
         "A003"
     }
-    fn name(&self) -> &str {
-        "聚合数据倾斜"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("AGGREGATE")
@@ -210,7 +196,6 @@ impl DiagnosticRule for A003DataSkew {
         if ratio > 2.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -218,10 +203,9 @@ impl DiagnosticRule for A003DataSkew {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("聚合存在数据倾斜，max/avg 比率为 {:.2}", ratio),
-                reason: "聚合算子的输入数据在各个实例间分布不均匀，导致部分实例处理更多数据。"
-                    .to_string(),
-                suggestions: vec!["优化分组键选择".to_string(), "考虑对热点键单独处理".to_string()],
+                message: msg!("A003.message", format!("{:.2}", ratio)),
+                reason: msg!("A003.reason"),
+                suggestions: vec![msg!("A003.suggestion.1"), msg!("A003.suggestion.2")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -238,9 +222,6 @@ impl DiagnosticRule for A005ExpensiveKeyExpr {
     fn id(&self) -> &str {
         "A005"
     }
-    fn name(&self) -> &str {
-        "GROUP BY 键表达式计算开销高"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("AGGREGATE")
@@ -256,16 +237,19 @@ impl DiagnosticRule for A005ExpensiveKeyExpr {
         if ratio > 0.5 && expr_time > 100_000_000.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Info,
-                node_path: format!("{} (plan_node_id={})", context.node.operator_name, context.node.plan_node_id.unwrap_or(-1)),
+                node_path: format!(
+                    "{} (plan_node_id={})",
+                    context.node.operator_name,
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("GROUP BY 键表达式计算占比过高 ({:.1}%)", ratio * 100.0),
-                reason: "GROUP BY 键包含复杂表达式，每行数据都需要计算表达式，增加 CPU 开销。建议将表达式提前计算或使用生成列。".to_string(),
+                message: msg!("A005.message", format!("{:.1}", ratio * 100.0)),
+                reason: msg!("A005.reason"),
                 suggestions: vec![
-                    "在子查询中物化复杂表达式".to_string(),
-                    "将表达式提升为生成列".to_string(),
-                    "避免在 GROUP BY 中使用复杂函数".to_string(),
+                    msg!("A005.suggestion.1"),
+                    msg!("A005.suggestion.2"),
+                    msg!("A005.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -284,9 +268,6 @@ impl DiagnosticRule for A006LowLocalAggregation {
     fn id(&self) -> &str {
         "A006"
     }
-    fn name(&self) -> &str {
-        "Aggregate 本地聚合度低"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         let name = node.operator_name.to_uppercase();
@@ -311,7 +292,6 @@ impl DiagnosticRule for A006LowLocalAggregation {
         if agg_ratio < 2.0 && input_rows > 10_000.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -319,31 +299,34 @@ impl DiagnosticRule for A006LowLocalAggregation {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "本地聚合效果差，聚合比 {:.2}:1 (输入 {:.0} 行 → 输出 {:.0} 行)",
-                    agg_ratio, input_rows, output_rows
+                message: msg!(
+                    "A006.message",
+                    format!("{:.2}", agg_ratio),
+                    format!("{:.0}", input_rows),
+                    format!("{:.0}", output_rows)
                 ),
                 reason: {
                     let group_keys = context
                         .get_group_by_keys()
-                        .unwrap_or_else(|| "未知".to_string());
-                    format!(
-                        "GROUP BY「{}」在本地聚合时，输入 {:.0} 行仅聚合为 {:.0} 行（缩减比 {:.2}:1），\
-                        未能有效减少数据量。这会增加网络传输和后续计算开销。",
-                        group_keys, input_rows, output_rows, agg_ratio
+                        .map(Arg::from)
+                        .unwrap_or_else(|| msg!("common.unknown").into());
+                    msg!(
+                        "A006.reason",
+                        group_keys,
+                        format!("{:.0}", input_rows),
+                        format!("{:.0}", output_rows),
+                        format!("{:.2}", agg_ratio)
                     )
                 },
                 suggestions: {
                     let group_keys = context
                         .get_group_by_keys()
-                        .unwrap_or_else(|| "未知".to_string());
+                        .map(Arg::from)
+                        .unwrap_or_else(|| msg!("common.unknown").into());
                     vec![
-                        format!(
-                            "GROUP BY「{}」基数可能过高，考虑关闭二阶段聚合: SET new_planner_agg_stage = 1",
-                            group_keys
-                        ),
-                        "检查 GROUP BY 键是否包含高基数列（如 ID、时间戳）".to_string(),
-                        "考虑在数据写入时预聚合或使用物化视图".to_string(),
+                        msg!("A006.suggestion.1", group_keys),
+                        msg!("A006.suggestion.2"),
+                        msg!("A006.suggestion.3"),
                     ]
                 },
                 parameter_suggestions: vec![ParameterSuggestion::new(
@@ -351,7 +334,7 @@ impl DiagnosticRule for A006LowLocalAggregation {
                     ParameterType::Session,
                     None,
                     "1",
-                    "SET new_planner_agg_stage = 1; -- 关闭二阶段聚合",
+                    "SET new_planner_agg_stage = 1;",
                 )],
                 threshold_metadata: None,
             })
@@ -16,9 +16,6 @@ impl DiagnosticRule for G001MostConsuming {
     fn id(&self) -> &str {
         "G001"
     }
-    fn name(&self) -> &str {
-        "算子时间占比过高"
-    }
 
     fn applicable_to(&self, _node: &ExecutionTreeNode) -> bool {
         true
@@ -31,18 +28,20 @@ impl DiagnosticRule for G001MostConsuming {
         if percentage > MOST_CONSUMING_PERCENTAGE && operator_time_ms > MIN_OPERATOR_TIME_MS {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Error,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "🔴 算子 {} 占用 {:.1}% 的执行时间（最耗时节点）",
-                    context.node.operator_name, percentage
+                message: msg!(
+                    "G001.message",
+                    context.node.operator_name,
+                    format!("{:.1}", percentage)
                 ),
                 suggestions: get_operator_suggestions(&context.node.operator_name),
-                reason: "算子执行时间占整体查询时间比例过高，是查询的主要瓶颈。优化该算子可获得最大收益。".to_string(),
+                reason: msg!("G001.reason"),
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -61,9 +60,6 @@ impl DiagnosticRule for G001bSecondConsuming {
     fn id(&self) -> &str {
         "G001b"
     }
-    fn name(&self) -> &str {
-        "算子时间占比较高"
-    }
 
     fn applicable_to(&self, _node: &ExecutionTreeNode) -> bool {
         true
@@ -79,18 +75,20 @@ impl DiagnosticRule for G001bSecondConsuming {
         {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "🟠 算子 {} 占用 {:.1}% 的执行时间（次耗时节点）",
-                    context.node.operator_name, percentage
+                message: msg!(
+                    "G001b.message",
+                    context.node.operator_name,
+                    format!("{:.1}", percentage)
                 ),
                 suggestions: get_operator_suggestions(&context.node.operator_name),
-                reason: "算子执行时间占整体查询时间比例过高，是查询的主要瓶颈。优化该算子可获得最大收益。".to_string(),
+                reason: msg!("G001.reason"),
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -109,9 +107,6 @@ impl DiagnosticRule for G002HighMemory {
     fn id(&self) -> &str {
         "G002"
     }
-    fn name(&self) -> &str {
-        "算子内存使用过高"
-    }
 
     fn applicable_to(&self, _node: &ExecutionTreeNode) -> bool {
         true
@@ -125,21 +120,24 @@ impl DiagnosticRule for G002HighMemory {
         if memory > memory_threshold {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "算子 {} 内存使用过高: {} (阈值: {})",
-                    context.node.operator_name, format_bytes(memory), format_bytes(memory_threshold)
+                message: msg!(
+                    "G002.message",
+                    context.node.operator_name,
+                    format_bytes(memory),
+                    format_bytes(memory_threshold)
                 ),
-                reason: "算子内存使用过高，可能导致查询失败或触发 Spill。检查是否存在数据膨胀或中间结果过大。".to_string(),
+                reason: msg!("G002.reason"),
                 suggestions: vec![
-                    "检查是否存在数据膨胀".to_string(),
-                    "考虑分批处理".to_string(),
-                    "检查 HashTable 或中间结果是否过大".to_string(),
+                    msg!("G002.suggestion.1"),
+                    msg!("G002.suggestion.2"),
+                    msg!("G002.suggestion.3"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
@@ -166,9 +164,6 @@ impl DiagnosticRule for G003ExecutionSkew {
     fn id(&self) -> &str {
         "G003"
     }
-    fn name(&self) -> &str {
-        "算子执行时间倾斜"
-    }
 
     fn applicable_to(&self, _node: &ExecutionTreeNode) -> bool {
         true
@@ -195,7 +190,6 @@ impl DiagnosticRule for G003ExecutionSkew {
         if ratio > skew_threshold {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -203,17 +197,17 @@ impl DiagnosticRule for G003ExecutionSkew {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "算子 {} 存在执行时间倾斜，max/avg 比率为 {:.2} (阈值: {:.1})",
-                    context.node.operator_name, ratio, skew_threshold
+                message: msg!(
+                    "G003.message",
+                    context.node.operator_name,
+                    format!("{:.2}", ratio),
+                    format!("{:.1}", skew_threshold)
                 ),
-                reason:
-                    "算子在多个实例间执行时间差异大，部分实例成为瓶颈。通常是数据分布不均匀导致。"
-                        .to_string(),
+                reason: msg!("G003.reason"),
                 suggestions: vec![
-                    "检查数据分布是否均匀".to_string(),
-                    "检查数据分区或分桶是否合理".to_string(),
-                    "考虑增加并行度".to_string(),
+                    msg!("G003.suggestion.1"),
+                    msg!("G003.suggestion.2"),
+                    msg!("G003.suggestion.3"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
@@ -231,42 +225,26 @@ impl DiagnosticRule for G003ExecutionSkew {
 }
 
 /// Get operator-specific suggestions based on operator name
-fn get_operator_suggestions(operator_name: &str) -> Vec<String> {
+fn get_operator_suggestions(operator_name: &str) -> Vec<Message> {
     let name = operator_name.to_uppercase();
 
     if name.contains("SCAN") {
-        vec![
-            "检查是否可以添加过滤条件减少扫描数据量".to_string(),
-            "检查分区裁剪是否生效".to_string(),
-            "执行 ANALYZE TABLE 更新统计信息".to_string(),
-        ]
+        vec![msg!("G001.suggestion.1"), msg!("G001.suggestion.2"), msg!("G001.suggestion.3")]
     } else if name.contains("JOIN") {
         vec![
-            "检查 JOIN 顺序是否最优".to_string(),
-            "考虑使用 Runtime Filter".to_string(),
-            "检查是否存在数据倾斜".to_string(),
-            "执行 ANALYZE TABLE 更新统计信息".to_string(),
+            msg!("G001.suggestion.4"),
+            msg!("G001.suggestion.5"),
+            msg!("G001.suggestion.6"),
+            msg!("G001.suggestion.3"),
         ]
     } else if name.contains("AGGREGATE") || name.contains("AGG") {
-        vec![
-            "检查聚合模式是否合适".to_string(),
-            "考虑使用预聚合或物化视图".to_string(),
-            "检查 GROUP BY 键的选择".to_string(),
-        ]
+        vec![msg!("G001.suggestion.7"), msg!("G001.suggestion.8"), msg!("G001.suggestion.9")]
     } else if name.contains("EXCHANGE") {
-        vec![
-            "检查数据分布是否均匀".to_string(),
-            "考虑调整并行度".to_string(),
-            "检查网络带宽是否充足".to_string(),
-        ]
+        vec![msg!("G003.suggestion.1"), msg!("G001.suggestion.10"), msg!("G001.suggestion.11")]
     } else if name.contains("SORT") {
-        vec![
-            "添加 LIMIT 限制结果集大小".to_string(),
-            "检查是否可以使用 Top-N 优化".to_string(),
-            "考虑使用物化视图预排序".to_string(),
-        ]
+        vec![msg!("G001.suggestion.12"), msg!("G001.suggestion.13"), msg!("G001.suggestion.14")]
     } else {
-        vec!["检查该算子是否处理数据量过大".to_string(), "考虑优化查询计划".to_string()]
+        vec![msg!("G001.suggestion.15"), msg!("G001.suggestion.16")]
     }
 }
 
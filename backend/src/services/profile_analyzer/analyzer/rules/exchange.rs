@@ -12,9 +12,6 @@ impl DiagnosticRule for E001NetworkTransferLarge {
     fn id(&self) -> &str {
         "E001"
     }
-    fn name(&self) -> &str {
-        "网络传输数据量大"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("EXCHANGE")
@@ -30,26 +27,26 @@ impl DiagnosticRule for E001NetworkTransferLarge {
         if bytes_sent > ONE_GB {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
-                plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "网络传输数据量 {}，可能存在网络瓶颈",
-                    format_bytes(bytes_sent as u64)
+                    context.node.plan_node_id.unwrap_or(-1)
                 ),
-                reason: "网络传输数据量过大，占用大量网络带宽和时间。可能是 Shuffle 数据量大或缺少有效的数据裁剪。".to_string(),
+                plan_node_id: context.node.plan_node_id,
+                message: msg!("E001.message", format_bytes(bytes_sent as u64)),
+                reason: msg!("E001.reason"),
                 suggestions: vec![
-                    "检查是否可以减少 Shuffle 数据量".to_string(),
-                    "考虑使用 Colocate Join 避免 Shuffle".to_string(),
-                    "检查网络带宽是否充足".to_string(),
+                    msg!("E001.suggestion.1"),
+                    msg!("E001.suggestion.2"),
+                    msg!("G001.suggestion.11"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
 
-                    if let Some(s) = context.suggest_parameter_smart("parallel_fragment_exec_instance_num") {
+                    if let Some(s) =
+                        context.suggest_parameter_smart("parallel_fragment_exec_instance_num")
+                    {
                         suggestions.push(s);
                     }
 
@@ -74,9 +71,6 @@ impl DiagnosticRule for E002NetworkTimeHigh {
     fn id(&self) -> &str {
         "E002"
     }
-    fn name(&self) -> &str {
-        "网络时间占比高"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("EXCHANGE")
@@ -97,7 +91,6 @@ impl DiagnosticRule for E002NetworkTimeHigh {
         if ratio > 0.5 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -105,13 +98,12 @@ impl DiagnosticRule for E002NetworkTimeHigh {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("网络时间占比 {:.1}%，可能存在网络瓶颈", ratio * 100.0),
-                reason: "网络传输时间占比过高，查询瓶颈在网络。可能是网络带宽不足或跨机房传输。"
-                    .to_string(),
+                message: msg!("E002.message", format!("{:.1}", ratio * 100.0)),
+                reason: msg!("E002.reason"),
                 suggestions: vec![
-                    "检查网络带宽和延迟".to_string(),
-                    "考虑使用 Colocate Join".to_string(),
-                    "减少跨节点数据传输".to_string(),
+                    msg!("E002.suggestion.1"),
+                    msg!("E002.suggestion.2"),
+                    msg!("E002.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -130,9 +122,6 @@ impl DiagnosticRule for E003ShuffleSkew {
     fn id(&self) -> &str {
         "E003"
     }
-    fn name(&self) -> &str {
-        "Shuffle 数据倾斜"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("EXCHANGE")
@@ -152,21 +141,19 @@ impl DiagnosticRule for E003ShuffleSkew {
         if ratio > 2.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
-                plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "Shuffle 存在数据倾斜，max/avg 比率为 {:.2}",
-                    ratio
+                    context.node.plan_node_id.unwrap_or(-1)
                 ),
-                reason: "Shuffle 数据在各节点间分布不均匀，部分节点接收更多数据。通常是 Shuffle 键存在热点值。".to_string(),
+                plan_node_id: context.node.plan_node_id,
+                message: msg!("E003.message", format!("{:.2}", ratio)),
+                reason: msg!("E003.reason"),
                 suggestions: vec![
-                    "检查分区键选择是否合理".to_string(),
-                    "考虑使用 Skew Join 优化".to_string(),
-                    "检查是否存在热点数据".to_string(),
+                    msg!("E003.suggestion.1"),
+                    msg!("E003.suggestion.2"),
+                    msg!("E003.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
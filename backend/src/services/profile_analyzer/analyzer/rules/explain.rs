@@ -0,0 +1,444 @@
+//! EXPLAIN plan lint rules
+//!
+//! Static checks on the plan returned by `EXPLAIN [VERBOSE | COSTS]`, evaluated
+//! before the query runs and without any LLM provider:
+//! - EX001: Cross join
+//! - EX002: Large table broadcast
+//! - EX003: No partition pruning on an internal table
+//! - EX004: Filter not pushed down to the scan
+//! - EX005: ORDER BY without LIMIT
+//! - EX006: External table scanned without a partition filter
+
+use super::*;
+use crate::services::profile_analyzer::analyzer::explain_plan::{ExplainPlan, PlanNode};
+
+/// Estimated rows of a broadcast build side that make a shuffle join preferable
+/// (same threshold as J011 on the profile)
+const BROADCAST_ROWS_THRESHOLD: u64 = 1_000_000;
+/// Estimated rows above which an unbounded sort is a warning instead of info
+const LARGE_SORT_ROWS: u64 = 1_000_000;
+
+/// Context for EXPLAIN rule evaluation
+pub struct ExplainRuleContext<'a> {
+    pub plan: &'a ExplainPlan,
+    pub node: &'a PlanNode,
+}
+
+impl ExplainRuleContext<'_> {
+    fn diagnostic(
+        &self,
+        rule: &dyn ExplainDiagnosticRule,
+        severity: RuleSeverity,
+        message: Message,
+        reason: Message,
+        suggestions: Vec<Message>,
+    ) -> Diagnostic {
+        Diagnostic {
+            rule_id: rule.id().to_string(),
+            severity,
+            node_path: self.node.label(),
+            plan_node_id: Some(self.node.id),
+            message,
+            reason,
+            suggestions,
+            parameter_suggestions: vec![],
+            threshold_metadata: None,
+        }
+    }
+
+    fn scan_name(&self) -> String {
+        self.node.table().unwrap_or_else(|| self.node.label())
+    }
+}
+
+/// Trait for EXPLAIN diagnostic rules
+pub trait ExplainDiagnosticRule: Send + Sync {
+    fn id(&self) -> &str;
+    fn evaluate(&self, context: &ExplainRuleContext) -> Option<Diagnostic>;
+}
+
+fn format_rows(rows: Option<u64>) -> Arg {
+    rows.map(Arg::from)
+        .unwrap_or_else(|| msg!("common.unknown").into())
+}
+
+// ============================================================================
+// EX001: Cross Join
+// ============================================================================
+
+/// EX001: Join without any join condition
+/// Condition: join op is CROSS JOIN
+pub struct EX001CrossJoin;
+
+impl ExplainDiagnosticRule for EX001CrossJoin {
+    fn id(&self) -> &str {
+        "EX001"
+    }
+
+    fn evaluate(&self, context: &ExplainRuleContext) -> Option<Diagnostic> {
+        let node = context.node;
+        if !node.is_join() {
+            return None;
+        }
+        let join_op = node.attribute("join op").unwrap_or_default().to_uppercase();
+        if !node.operator.to_uppercase().contains("CROSS") && !join_op.contains("CROSS JOIN") {
+            return None;
+        }
+
+        Some(context.diagnostic(
+            self,
+            RuleSeverity::Warning,
+            msg!("EX001.message", node.label(), format_rows(context.plan.estimated_rows(node.id))),
+            msg!("EX001.reason"),
+            vec![msg!("EX001.suggestion.1"), msg!("EX001.suggestion.2")],
+        ))
+    }
+}
+
+// ============================================================================
+// EX002: Large Table Broadcast
+// ============================================================================
+
+/// EX002: Broadcast join with a large build side
+/// Condition: join op is BROADCAST and build side estimate > 1M rows
+pub struct EX002LargeBroadcast;
+
+impl ExplainDiagnosticRule for EX002LargeBroadcast {
+    fn id(&self) -> &str {
+        "EX002"
+    }
+
+    fn evaluate(&self, context: &ExplainRuleContext) -> Option<Diagnostic> {
+        let node = context.node;
+        if !node.is_join() {
+            return None;
+        }
+        let join_op = node.attribute("join op")?.to_uppercase();
+        if !join_op.contains("BROADCAST") {
+            return None;
+        }
+        let build_side = *node.children.get(1)?;
+        let build_rows = context.plan.estimated_rows(build_side)?;
+        if build_rows <= BROADCAST_ROWS_THRESHOLD {
+            return None;
+        }
+
+        Some(context.diagnostic(
+            self,
+            RuleSeverity::Warning,
+            msg!("EX002.message", build_rows, BROADCAST_ROWS_THRESHOLD),
+            msg!("EX002.reason"),
+            vec![msg!("EX002.suggestion.1"), msg!("EX002.suggestion.2")],
+        ))
+    }
+}
+
+// ============================================================================
+// EX003: No Partition Pruning
+// ============================================================================
+
+/// EX003: Internal table scan reads every partition
+/// Condition: OLAP scan with partitions=N/N and N > 1
+pub struct EX003NoPartitionPruning;
+
+impl ExplainDiagnosticRule for EX003NoPartitionPruning {
+    fn id(&self) -> &str {
+        "EX003"
+    }
+
+    fn evaluate(&self, context: &ExplainRuleContext) -> Option<Diagnostic> {
+        if !context.node.is_olap_scan() {
+            return None;
+        }
+        let (selected, total) = context.node.partitions()?;
+        if total <= 1 || selected < total {
+            return None;
+        }
+
+        Some(context.diagnostic(
+            self,
+            RuleSeverity::Warning,
+            msg!("EX003.message", context.scan_name(), total),
+            msg!("EX003.reason"),
+            vec![msg!("EX003.suggestion.1"), msg!("EX003.suggestion.2")],
+        ))
+    }
+}
+
+// ============================================================================
+// EX004: Predicate Not Pushed Down
+// ============================================================================
+
+/// EX004: Filter evaluated in a separate SELECT node above the scan
+/// Condition: SELECT node whose input (through projections) is a scan
+pub struct EX004PredicateNotPushedDown;
+
+impl ExplainDiagnosticRule for EX004PredicateNotPushedDown {
+    fn id(&self) -> &str {
+        "EX004"
+    }
+
+    fn evaluate(&self, context: &ExplainRuleContext) -> Option<Diagnostic> {
+        let node = context.node;
+        if !node.is(&["SELECT"]) {
+            return None;
+        }
+        let mut input = context.plan.get(*node.children.first()?)?;
+        while input.is(&["Project"]) {
+            input = context.plan.get(*input.children.first()?)?;
+        }
+        if !input.is_scan() {
+            return None;
+        }
+
+        Some(context.diagnostic(
+            self,
+            RuleSeverity::Warning,
+            msg!("EX004.message", node.label(), input.label()),
+            msg!("EX004.reason"),
+            vec![msg!("EX004.suggestion.1"), msg!("EX004.suggestion.2")],
+        ))
+    }
+}
+
+// ============================================================================
+// EX005: ORDER BY Without LIMIT
+// ============================================================================
+
+/// EX005: Full sort of the result without LIMIT
+/// Condition: SORT/TOP-N node with order by and no limit, not feeding a window function
+pub struct EX005UnboundedOrderBy;
+
+impl ExplainDiagnosticRule for EX005UnboundedOrderBy {
+    fn id(&self) -> &str {
+        "EX005"
+    }
+
+    fn evaluate(&self, context: &ExplainRuleContext) -> Option<Diagnostic> {
+        let node = context.node;
+        if !node.is(&["SORT", "TOP-N"]) || node.attribute("order by").is_none() {
+            return None;
+        }
+        if node.attribute("limit").is_some() {
+            return None;
+        }
+        // Sorts below ANALYTIC order window partitions, not the result
+        if context
+            .plan
+            .parent(node)
+            .is_some_and(|p| p.is(&["ANALYTIC"]))
+        {
+            return None;
+        }
+
+        let rows = context.plan.estimated_rows(node.id);
+        let severity = if rows.is_some_and(|r| r > LARGE_SORT_ROWS) {
+            RuleSeverity::Warning
+        } else {
+            RuleSeverity::Info
+        };
+
+        Some(context.diagnostic(
+            self,
+            severity,
+            msg!("EX005.message", node.label(), format_rows(rows)),
+            msg!("EX005.reason"),
+            vec![msg!("EX005.suggestion.1"), msg!("EX005.suggestion.2")],
+        ))
+    }
+}
+
+// ============================================================================
+// EX006: External Table Without Partition Filter
+// ============================================================================
+
+/// EX006: External table scan reads every partition
+/// Condition: non-OLAP scan with partitions=N/N and N > 1
+pub struct EX006ExternalScanWithoutPartitionFilter;
+
+impl ExplainDiagnosticRule for EX006ExternalScanWithoutPartitionFilter {
+    fn id(&self) -> &str {
+        "EX006"
+    }
+
+    fn evaluate(&self, context: &ExplainRuleContext) -> Option<Diagnostic> {
+        if !context.node.is_external_scan() {
+            return None;
+        }
+        let (selected, total) = context.node.partitions()?;
+        if total <= 1 || selected < total {
+            return None;
+        }
+
+        Some(context.diagnostic(
+            self,
+            RuleSeverity::Warning,
+            msg!("EX006.message", context.scan_name(), total),
+            msg!("EX006.reason"),
+            vec![msg!("EX006.suggestion.1"), msg!("EX006.suggestion.2")],
+        ))
+    }
+}
+
+/// Get all EXPLAIN rules
+pub fn get_rules() -> Vec<Box<dyn ExplainDiagnosticRule>> {
+    vec![
+        Box::new(EX001CrossJoin),
+        Box::new(EX002LargeBroadcast),
+        Box::new(EX003NoPartitionPruning),
+        Box::new(EX004PredicateNotPushedDown),
+        Box::new(EX005UnboundedOrderBy),
+        Box::new(EX006ExternalScanWithoutPartitionFilter),
+    ]
+}
+
+/// Evaluate all EXPLAIN rules on every node, root first
+pub fn evaluate_explain_rules(plan: &ExplainPlan) -> Vec<Diagnostic> {
+    let rules = get_rules();
+    plan.nodes_in_order()
+        .into_iter()
+        .flat_map(|node| {
+            let context = ExplainRuleContext { plan, node };
+            rules
+                .iter()
+                .filter_map(|rule| rule.evaluate(&context))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::profile_analyzer::i18n;
+
+    fn lint(explain: &str) -> Vec<Diagnostic> {
+        evaluate_explain_rules(&ExplainPlan::parse(explain))
+    }
+
+    fn rule_ids(diagnostics: &[Diagnostic]) -> Vec<&str> {
+        diagnostics.iter().map(|d| d.rule_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_lint_flags_plan_problems() {
+        let explain = r#"PLAN FRAGMENT 0
+  RESULT SINK
+
+  8:SORT
+  |  order by: <slot 3> 3: o_totalprice DESC
+  |  offset: 0
+  |  cardinality: 2000000
+  |
+  7:HASH JOIN
+  |  join op: INNER JOIN (BROADCAST)
+  |  equal join conjunct: 1: o_orderkey = 10: l_orderkey
+  |  cardinality: 2000000
+  |
+  |----6:EXCHANGE
+  |       cardinality: 5000000
+  |
+  5:NESTLOOP JOIN
+  |  join op: CROSS JOIN
+  |  cardinality: 2000000
+  |
+  |----4:EXCHANGE
+  |       cardinality: 20
+  |
+  1:SELECT
+  |  predicates: abs(2: o_custkey) > 10
+  |
+  0:OlapScanNode
+     TABLE: orders
+     partitions=12/12
+     cardinality=100000
+
+PLAN FRAGMENT 1
+  STREAM DATA SINK
+    EXCHANGE ID: 06
+    UNPARTITIONED
+
+  3:HdfsScanNode
+     TABLE: lineitem
+     partitions=30/30
+     cardinality=5000000
+
+PLAN FRAGMENT 2
+  STREAM DATA SINK
+    EXCHANGE ID: 04
+    UNPARTITIONED
+
+  2:OlapScanNode
+     TABLE: nation
+     partitions=1/1
+     cardinality=20
+"#;
+        let diagnostics = lint(explain);
+        let ids = rule_ids(&diagnostics);
+        for id in ["EX001", "EX002", "EX003", "EX004", "EX005", "EX006"] {
+            assert!(ids.contains(&id), "missing {id}: {ids:?}");
+        }
+        assert_eq!(ids.len(), 6, "{ids:?}");
+
+        let sort = diagnostics.iter().find(|d| d.rule_id == "EX005").unwrap();
+        assert_eq!(sort.severity, RuleSeverity::Warning);
+        assert_eq!(sort.plan_node_id, Some(8));
+        let pruning = diagnostics.iter().find(|d| d.rule_id == "EX003").unwrap();
+        assert!(pruning.message.render(i18n::Locale::Zh).contains("orders"));
+
+        for diagnostic in &diagnostics {
+            let result = diagnostic.to_result(i18n::Locale::En);
+            let text = format!(
+                "{} {} {} {:?}",
+                result.rule_name, result.message, result.reason, result.suggestions
+            );
+            assert!(text.is_ascii(), "untranslated {}: {}", result.rule_id, text);
+        }
+    }
+
+    #[test]
+    fn test_lint_clean_plan() {
+        let explain = r#"PLAN FRAGMENT 0
+  RESULT SINK
+
+  4:TOP-N
+  |  order by: <slot 3> 3: o_totalprice DESC
+  |  offset: 0
+  |  limit: 10
+  |
+  3:HASH JOIN
+  |  join op: INNER JOIN (BROADCAST)
+  |  equal join conjunct: 1: o_orderkey = 10: l_orderkey
+  |
+  |----2:EXCHANGE
+  |       cardinality: 25
+  |
+  0:OlapScanNode
+     TABLE: orders
+     PREDICATES: 4: o_orderdate >= '2024-01-01'
+     partitions=3/12
+     cardinality=100000
+"#;
+        assert!(lint(explain).is_empty());
+    }
+
+    #[test]
+    fn test_sort_for_window_function_is_ignored() {
+        let explain = r#"PLAN FRAGMENT 0
+  RESULT SINK
+
+  2:ANALYTIC
+  |  functions: [, row_number(), ]
+  |  partition by: 1: k
+  |
+  1:SORT
+  |  order by: <slot 1> 1: k ASC
+  |  offset: 0
+  |
+  0:OlapScanNode
+     TABLE: t
+     partitions=1/1
+"#;
+        assert!(lint(explain).is_empty());
+    }
+}
@@ -9,9 +9,6 @@ impl DiagnosticRule for F001ExecutionTimeSkew {
     fn id(&self) -> &str {
         "F001"
     }
-    fn name(&self) -> &str {
-        "实例执行时间倾斜"
-    }
 
     fn applicable_to(&self, _node: &ExecutionTreeNode) -> bool {
         true
@@ -27,13 +24,16 @@ impl DiagnosticRule for F001ExecutionTimeSkew {
         if ratio > 2.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", context.node.operator_name, context.node.plan_node_id.unwrap_or(-1)),
+                node_path: format!(
+                    "{} (plan_node_id={})",
+                    context.node.operator_name,
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("实例执行时间存在倾斜，max/avg 比率为 {:.2}", ratio),
-                reason: "Fragment 执行时间过长，是查询的主要瓶颈。需要分析 Fragment 内的算子找出具体问题。".to_string(),
-                suggestions: vec!["检查数据分布".to_string(), "优化分桶策略".to_string()],
+                message: msg!("F001.message", format!("{:.2}", ratio)),
+                reason: msg!("F001.reason"),
+                suggestions: vec![msg!("F001.suggestion.1"), msg!("F001.suggestion.2")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -50,9 +50,6 @@ impl DiagnosticRule for F002MemorySkew {
     fn id(&self) -> &str {
         "F002"
     }
-    fn name(&self) -> &str {
-        "实例内存分配不均"
-    }
 
     fn applicable_to(&self, _node: &ExecutionTreeNode) -> bool {
         true
@@ -70,7 +67,6 @@ impl DiagnosticRule for F002MemorySkew {
         if ratio > 2.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -78,9 +74,9 @@ impl DiagnosticRule for F002MemorySkew {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("实例内存分配不均，max/avg 比率为 {:.2}", ratio),
-                reason: "Fragment 内存使用过高，可能导致查询失败。".to_string(),
-                suggestions: vec!["检查数据倾斜".to_string()],
+                message: msg!("F002.message", format!("{:.2}", ratio)),
+                reason: msg!("F002.reason"),
+                suggestions: vec![msg!("F002.suggestion")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -97,9 +93,6 @@ impl DiagnosticRule for F003PrepareTimeLong {
     fn id(&self) -> &str {
         "F003"
     }
-    fn name(&self) -> &str {
-        "Fragment 准备时间过长"
-    }
 
     fn applicable_to(&self, _node: &ExecutionTreeNode) -> bool {
         true
@@ -110,7 +103,6 @@ impl DiagnosticRule for F003PrepareTimeLong {
         if prepare_time > 1_000_000_000.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Info,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -118,9 +110,9 @@ impl DiagnosticRule for F003PrepareTimeLong {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("Fragment 准备时间 {:.1}s", prepare_time / 1_000_000_000.0),
-                reason: "Fragment 各实例执行时间差异大，存在数据倾斜或资源不均。".to_string(),
-                suggestions: vec!["检查元数据加载".to_string()],
+                message: msg!("F003.message", format!("{:.1}", prepare_time / 1_000_000_000.0)),
+                reason: msg!("F003.reason"),
+                suggestions: vec![msg!("F003.suggestion")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -13,9 +13,6 @@ impl DiagnosticRule for J001ResultExplosion {
     fn id(&self) -> &str {
         "J001"
     }
-    fn name(&self) -> &str {
-        "Join 结果膨胀"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("JOIN")
@@ -39,21 +36,24 @@ impl DiagnosticRule for J001ResultExplosion {
         if ratio > 10.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Error,
-                node_path: format!("{} (plan_node_id={})",
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "Join 结果膨胀 {:.1} 倍 (输出 {:.0} 行 / 探测 {:.0} 行)",
-                    ratio, output_rows, probe_rows
+                message: msg!(
+                    "J001.message",
+                    format!("{:.1}", ratio),
+                    format!("{:.0}", output_rows),
+                    format!("{:.0}", probe_rows)
                 ),
-                reason: "Join 输出结果显著大于输入，通常是缺少 Join 条件导致 Cross Join，或 Join 条件错误导致 1:N 匹配。".to_string(),
+                reason: msg!("J001.reason"),
                 suggestions: vec![
-                    "检查 JOIN 条件是否缺失或不完整".to_string(),
-                    "检查是否存在多对多关系".to_string(),
-                    "考虑添加更多过滤条件".to_string(),
+                    msg!("J001.suggestion.1"),
+                    msg!("J001.suggestion.2"),
+                    msg!("J001.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -72,9 +72,6 @@ impl DiagnosticRule for J002BuildLargerThanProbe {
     fn id(&self) -> &str {
         "J002"
     }
-    fn name(&self) -> &str {
-        "Join Build 端过大"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("HASH")
@@ -88,21 +85,23 @@ impl DiagnosticRule for J002BuildLargerThanProbe {
         if build_rows > probe_rows && build_rows > 100_000.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "Build 端行数 ({:.0}) 大于 Probe 端 ({:.0})，Join 顺序可能不优",
-                    build_rows, probe_rows
+                message: msg!(
+                    "J002.message",
+                    format!("{:.0}", build_rows),
+                    format!("{:.0}", probe_rows)
                 ),
-                reason: "在 Hash Join 中，Build 端数据量大于 Probe 端，导致 HashTable 过大。优化器可能因统计信息不准确选择了错误的 Build 端。".to_string(),
+                reason: msg!("J002.reason"),
                 suggestions: vec![
-                    "执行 ANALYZE TABLE 更新统计信息".to_string(),
-                    "检查优化器是否选择了正确的 Join 顺序".to_string(),
-                    "考虑使用 Hint 指定 Join 顺序".to_string(),
+                    msg!("G001.suggestion.3"),
+                    msg!("J002.suggestion.1"),
+                    msg!("J002.suggestion.2"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -122,9 +121,6 @@ impl DiagnosticRule for J003HashTableTooLarge {
     fn id(&self) -> &str {
         "J003"
     }
-    fn name(&self) -> &str {
-        "HashTable 内存过大"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("HASH")
@@ -141,7 +137,6 @@ impl DiagnosticRule for J003HashTableTooLarge {
         if hash_memory > memory_threshold {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -149,17 +144,16 @@ impl DiagnosticRule for J003HashTableTooLarge {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "HashTable 内存使用 {}，可能导致内存压力 (阈值: {})",
+                message: msg!(
+                    "J003.message",
                     format_bytes(hash_memory as u64),
                     format_bytes(memory_threshold as u64)
                 ),
-                reason: "Join 的 HashTable 占用内存过大，可能导致内存压力或触发 Spill。"
-                    .to_string(),
+                reason: msg!("J003.reason"),
                 suggestions: vec![
-                    "检查 Build 端数据量是否过大".to_string(),
-                    "考虑使用 Runtime Filter 减少数据量".to_string(),
-                    "启用 Spill 功能避免 OOM".to_string(),
+                    msg!("J003.suggestion.1"),
+                    msg!("J003.suggestion.2"),
+                    msg!("A002.suggestion.3"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
@@ -184,9 +178,6 @@ impl DiagnosticRule for J004NoRuntimeFilter {
     fn id(&self) -> &str {
         "J004"
     }
-    fn name(&self) -> &str {
-        "未生成 Runtime Filter"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("JOIN")
@@ -199,28 +190,29 @@ impl DiagnosticRule for J004NoRuntimeFilter {
         if rf_num == 0.0 && build_rows > 10_000.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Info,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
-                plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "Join 未生成 Runtime Filter，Build 端有 {:.0} 行",
-                    build_rows
+                    context.node.plan_node_id.unwrap_or(-1)
                 ),
-                reason: "Runtime Filter 未生效或效果差，Scan 端未能有效过滤数据。可能是 Filter 构建失败或选择性差。".to_string(),
+                plan_node_id: context.node.plan_node_id,
+                message: msg!("J004.message", format!("{:.0}", build_rows)),
+                reason: msg!("J004.reason"),
                 suggestions: vec![
-                    "检查 enable_global_runtime_filter 是否启用".to_string(),
-                    "检查 Join 条件是否适合生成 RF".to_string(),
-                    "检查 Build 端行数是否超过阈值".to_string(),
+                    msg!("J004.suggestion.1"),
+                    msg!("J004.suggestion.2"),
+                    msg!("J004.suggestion.3"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
-                    if let Some(s) = context.suggest_parameter_smart("enable_global_runtime_filter") {
+                    if let Some(s) = context.suggest_parameter_smart("enable_global_runtime_filter")
+                    {
                         suggestions.push(s);
                     }
-                    if let Some(s) = context.suggest_parameter_smart("runtime_join_filter_push_down_limit") {
+                    if let Some(s) =
+                        context.suggest_parameter_smart("runtime_join_filter_push_down_limit")
+                    {
                         suggestions.push(s);
                     }
                     suggestions
@@ -241,9 +233,6 @@ impl DiagnosticRule for J009NonEquiJoin {
     fn id(&self) -> &str {
         "J009"
     }
-    fn name(&self) -> &str {
-        "非等式 Join 回退"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         let name = node.operator_name.to_uppercase();
@@ -257,21 +246,19 @@ impl DiagnosticRule for J009NonEquiJoin {
         if probe_rows > 1000.0 || build_rows > 1000.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
-                plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "使用了 {} 算子，可能是非等式 Join 导致",
-                    context.node.operator_name
+                    context.node.plan_node_id.unwrap_or(-1)
                 ),
-                reason: "Join 条件包含非等式条件，无法使用 Hash Join，退化为 Nested Loop Join，性能较差。".to_string(),
+                plan_node_id: context.node.plan_node_id,
+                message: msg!("J009.message", context.node.operator_name),
+                reason: msg!("J009.reason"),
                 suggestions: vec![
-                    "检查 JOIN 条件是否包含等式条件".to_string(),
-                    "尝试将非等式条件转换为等式条件".to_string(),
-                    "考虑重构查询逻辑".to_string(),
+                    msg!("J009.suggestion.1"),
+                    msg!("J009.suggestion.2"),
+                    msg!("J009.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -290,9 +277,6 @@ impl DiagnosticRule for J010ProbeCacheUnfriendly {
     fn id(&self) -> &str {
         "J010"
     }
-    fn name(&self) -> &str {
-        "探测缓存不友好"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("HASH")
@@ -311,21 +295,24 @@ impl DiagnosticRule for J010ProbeCacheUnfriendly {
         if hash_memory > L3_CACHE && probe_rows > build_rows * 100.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Info,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "HashTable ({}) 超过 L3 缓存，探测行数 ({:.0}) 远大于构建行数 ({:.0})，可能存在缓存不友好",
-                    format_bytes(hash_memory as u64), probe_rows, build_rows
+                message: msg!(
+                    "J010.message",
+                    format_bytes(hash_memory as u64),
+                    format!("{:.0}", probe_rows),
+                    format!("{:.0}", build_rows)
                 ),
-                reason: "Hash 表探测时缓存命中率低，可能是 HashTable 过大超出 CPU 缓存或探测数据访问模式不友好。".to_string(),
+                reason: msg!("J010.reason"),
                 suggestions: vec![
-                    "考虑交换 Join 左右表顺序".to_string(),
-                    "使用 Hint 指定 Join 顺序".to_string(),
-                    "检查统计信息是否准确".to_string(),
+                    msg!("J010.suggestion.1"),
+                    msg!("J010.suggestion.2"),
+                    msg!("J010.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -343,9 +330,6 @@ impl DiagnosticRule for J005HashCollision {
     fn id(&self) -> &str {
         "J005"
     }
-    fn name(&self) -> &str {
-        "Hash 碰撞严重"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("JOIN")
@@ -356,12 +340,12 @@ impl DiagnosticRule for J005HashCollision {
         if keys_per_bucket > 10.0 {
             let join_pred = context
                 .get_join_predicates()
-                .unwrap_or_else(|| "未知".to_string());
+                .map(Arg::from)
+                .unwrap_or_else(|| msg!("common.unknown").into());
             let build_rows = context.get_metric("BuildRows").unwrap_or(0.0);
 
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -369,15 +353,12 @@ impl DiagnosticRule for J005HashCollision {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("Hash 表碰撞严重，平均每桶 {:.0} 个键", keys_per_bucket),
-                reason: format!(
-                    "Join 条件「{}」的 Hash 表存在大量冲突（Build 端 {:.0} 行），导致探测效率下降。可能是 Join 键分布不均匀或 Hash 函数效果差。",
-                    join_pred, build_rows
-                ),
+                message: msg!("J005.message", format!("{:.0}", keys_per_bucket)),
+                reason: msg!("J005.reason", join_pred, format!("{:.0}", build_rows)),
                 suggestions: vec![
-                    format!("检查 Join 键「{}」是否存在大量重复值或 NULL 值", join_pred),
-                    "考虑添加更多等值 Join 条件分散 Hash 分布".to_string(),
-                    "检查 Build 表的数据分布，必要时添加预过滤".to_string(),
+                    msg!("J005.suggestion.1", join_pred),
+                    msg!("J005.suggestion.2"),
+                    msg!("J005.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -396,9 +377,6 @@ impl DiagnosticRule for J006ShuffleSkew {
     fn id(&self) -> &str {
         "J006"
     }
-    fn name(&self) -> &str {
-        "Join Shuffle 倾斜"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("JOIN")
@@ -417,11 +395,11 @@ impl DiagnosticRule for J006ShuffleSkew {
         if ratio > skew_threshold {
             let join_pred = context
                 .get_join_predicates()
-                .unwrap_or_else(|| "未知".to_string());
+                .map(Arg::from)
+                .unwrap_or_else(|| msg!("common.unknown").into());
 
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -429,18 +407,21 @@ impl DiagnosticRule for J006ShuffleSkew {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "Join 数据分布倾斜，max/avg 比率为 {:.2} (阈值: {:.1})",
-                    ratio, skew_threshold
+                message: msg!(
+                    "J006.message",
+                    format!("{:.2}", ratio),
+                    format!("{:.1}", skew_threshold)
                 ),
-                reason: format!(
-                    "Shuffle Join 按「{}」分发数据时分布不均匀，max 实例处理 {:.0} 行，min 实例仅 {:.0} 行。通常是 Join 键存在热点值导致。",
-                    join_pred, max_probe, min_probe
+                reason: msg!(
+                    "J006.reason",
+                    join_pred,
+                    format!("{:.0}", max_probe),
+                    format!("{:.0}", min_probe)
                 ),
                 suggestions: vec![
-                    format!("检查 Join 键「{}」中是否存在热点值（如 NULL 或高频值）", join_pred),
-                    "考虑添加更多 Join 条件分散数据分布".to_string(),
-                    "对热点键值单独处理或添加盐值打散".to_string(),
+                    msg!("J006.suggestion.1", join_pred),
+                    msg!("J006.suggestion.2"),
+                    msg!("J006.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -458,9 +439,6 @@ impl DiagnosticRule for J007PartitionProbeOverhead {
     fn id(&self) -> &str {
         "J007"
     }
-    fn name(&self) -> &str {
-        "分区 Join 探测开销高"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("JOIN")
@@ -473,7 +451,6 @@ impl DiagnosticRule for J007PartitionProbeOverhead {
         if partition_nums > 1.0 && search_time > 0.0 && probe_overhead / search_time > 0.5 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -481,16 +458,13 @@ impl DiagnosticRule for J007PartitionProbeOverhead {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "分区探测开销占比 {:.1}%，分区数为 {:.0}",
-                    probe_overhead / search_time * 100.0,
-                    partition_nums
+                message: msg!(
+                    "J007.message",
+                    format!("{:.1}", probe_overhead / search_time * 100.0),
+                    format!("{:.0}", partition_nums)
                 ),
-                reason: "分区探测开销过高，可能是分区数过多或分区策略不当。".to_string(),
-                suggestions: vec![
-                    "检查分区数是否合理".to_string(),
-                    "考虑增加内存限制避免过度分区".to_string(),
-                ],
+                reason: msg!("J007.reason"),
+                suggestions: vec![msg!("J007.suggestion.1"), msg!("J007.suggestion.2")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -507,9 +481,6 @@ impl DiagnosticRule for J008RFMemoryHigh {
     fn id(&self) -> &str {
         "J008"
     }
-    fn name(&self) -> &str {
-        "Runtime Filter 内存占用高"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("JOIN")
@@ -521,7 +492,6 @@ impl DiagnosticRule for J008RFMemoryHigh {
         if rf_bytes > HUNDRED_MB {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Info,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -529,13 +499,9 @@ impl DiagnosticRule for J008RFMemoryHigh {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("Runtime Filter 内存占用 {}", format_bytes(rf_bytes as u64)),
-                reason: "Runtime Filter 占用内存过高，可能是 Filter 数量过多或单个 Filter 过大。"
-                    .to_string(),
-                suggestions: vec![
-                    "降低 runtime_filter_max_size 配置".to_string(),
-                    "检查 Join 键基数是否过高".to_string(),
-                ],
+                message: msg!("J008.message", format_bytes(rf_bytes as u64)),
+                reason: msg!("J008.reason"),
+                suggestions: vec![msg!("J008.suggestion.1"), msg!("J008.suggestion.2")],
                 parameter_suggestions: vec![ParameterSuggestion::new(
                     "runtime_filter_max_size",
                     ParameterType::Session,
@@ -559,9 +525,6 @@ impl DiagnosticRule for J011BroadcastNotRecommended {
     fn id(&self) -> &str {
         "J011"
     }
-    fn name(&self) -> &str {
-        "Join 不应使用 Broadcast"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("JOIN")
@@ -582,12 +545,12 @@ impl DiagnosticRule for J011BroadcastNotRecommended {
         if build_rows > rows_threshold || hash_table_memory > memory_threshold {
             let join_pred = context
                 .get_join_predicates()
-                .unwrap_or_else(|| "未知".to_string());
+                .map(Arg::from)
+                .unwrap_or_else(|| msg!("common.unknown").into());
             let probe_rows = context.get_metric("ProbeRows").unwrap_or(0.0);
 
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -595,25 +558,21 @@ impl DiagnosticRule for J011BroadcastNotRecommended {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "Broadcast Join Build 端数据量过大 ({:.0} 行, {})",
-                    build_rows,
+                message: msg!(
+                    "J011.message",
+                    format!("{:.0}", build_rows),
                     format_bytes(hash_table_memory as u64)
                 ),
-                reason: format!(
-                    "当小表与大表 Join 时，Broadcast Join 会将小表广播到所有节点。\
-                    但当 Build 端数据量过大时，会增加网络和计算成本。\
-                    当前 Join 条件「{}」的 Build 端有 {:.0} 行，Probe 端有 {:.0} 行，\
-                    可能是统计信息不准确导致优化器错误估计了表大小。",
-                    join_pred, build_rows, probe_rows
+                reason: msg!(
+                    "J011.reason",
+                    join_pred,
+                    format!("{:.0}", build_rows),
+                    format!("{:.0}", probe_rows)
                 ),
                 suggestions: vec![
-                    format!(
-                        "在 JOIN 关键字后添加 [shuffle] Hint: SELECT ... FROM a JOIN [shuffle] b ON {}",
-                        join_pred
-                    ),
-                    "执行 ANALYZE TABLE <build_table> 更新统计信息".to_string(),
-                    "检查 Join 顺序，确保小表在 Build 端".to_string(),
+                    msg!("J011.suggestion.1", join_pred),
+                    msg!("J011.suggestion.2"),
+                    msg!("J011.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
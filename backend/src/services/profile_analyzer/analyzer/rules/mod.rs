@@ -6,6 +6,7 @@
 pub mod aggregate;
 pub mod common;
 pub mod exchange;
+pub mod explain;
 pub mod fragment;
 pub mod join;
 pub mod planner;
@@ -16,6 +17,7 @@ pub mod sink;
 pub mod sort;
 
 use super::thresholds::DynamicThresholds;
+use crate::services::profile_analyzer::i18n::{Arg, Locale, Message, msg, render_all};
 use crate::services::profile_analyzer::models::*;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -52,7 +54,7 @@ impl From<RuleSeverity> for HotSeverity {
 }
 
 /// Parameter suggestion for tuning
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone)]
 pub struct ParameterSuggestion {
     /// Parameter name (e.g., "enable_scan_datacache")
     pub name: String,
@@ -65,9 +67,9 @@ pub struct ParameterSuggestion {
     /// SQL command to set the parameter
     pub command: String,
     /// Human-readable description of what this parameter does
-    pub description: String,
+    pub description: Message,
     /// Expected impact of changing this parameter
-    pub impact: String,
+    pub impact: Message,
 }
 
 /// Parameter type classification
@@ -77,119 +79,53 @@ pub enum ParameterType {
     BE,
 }
 
+/// Parameters with a dedicated description and impact in the message catalog
+/// (`param.<name>.description` / `param.<name>.impact`)
+pub const DOCUMENTED_PARAMETERS: &[&str] = &[
+    "enable_scan_datacache",
+    "enable_populate_datacache",
+    "datacache_evict_probability",
+    "enable_query_cache",
+    "enable_adaptive_sink_dop",
+    "enable_runtime_adaptive_dop",
+    "enable_spill",
+    "enable_connector_adaptive_io_tasks",
+    "io_tasks_per_scan_operator",
+    "connector_io_tasks_per_scan_operator",
+    "hash_join_push_down_right_table",
+    "enable_local_shuffle_agg",
+    "runtime_filter_on_exchange_node",
+    "global_runtime_filter_build_max_size",
+    "parallel_fragment_exec_instance_num",
+    "pipeline_dop",
+    "query_mem_limit",
+    "query_timeout",
+    "streaming_preaggregation_mode",
+    "enable_sort_aggregate",
+    "pipeline_profile_level",
+    "storage_page_cache_limit",
+];
+
 /// Get parameter metadata (description and impact) for common StarRocks parameters
 pub fn get_parameter_metadata(name: &str) -> ParameterMetadata {
-    match name {
-        "enable_scan_datacache" => ParameterMetadata {
-            description: "启用 DataCache 读取缓存，允许从本地缓存读取数据而非远程存储".to_string(),
-            impact: "提升存算分离架构下的查询性能，减少网络 IO".to_string(),
-        },
-        "enable_populate_datacache" => ParameterMetadata {
-            description: "启用 DataCache 写入填充，将远程读取的数据缓存到本地".to_string(),
-            impact: "后续查询可命中本地缓存，但首次查询会有额外写入开销".to_string(),
-        },
-        "datacache_evict_probability" => ParameterMetadata {
-            description: "DataCache 淘汰概率 (0-100)，控制缓存数据被淘汰的可能性".to_string(),
-            impact: "降低该值可减少缓存抖动，但可能导致缓存空间不足".to_string(),
-        },
-
-        "enable_query_cache" => ParameterMetadata {
-            description: "启用查询结果缓存，相同查询可直接返回缓存结果".to_string(),
-            impact: "对重复查询有显著加速，但会占用额外内存".to_string(),
-        },
-        "enable_adaptive_sink_dop" => ParameterMetadata {
-            description: "启用自适应 Sink 并行度，根据数据量动态调整写入并行度".to_string(),
-            impact: "可优化数据写入性能，减少小文件产生".to_string(),
-        },
-        "enable_runtime_adaptive_dop" => ParameterMetadata {
-            description: "启用运行时自适应并行度，根据实际数据量动态调整执行并行度".to_string(),
-            impact: "可优化资源利用率，避免小数据量查询占用过多资源".to_string(),
-        },
-        "enable_spill" => ParameterMetadata {
-            description: "启用中间结果落盘，当内存不足时将数据写入磁盘".to_string(),
-            impact: "可处理超大数据量查询，但会降低查询性能".to_string(),
-        },
-
-        "enable_connector_adaptive_io_tasks" => ParameterMetadata {
-            description: "启用连接器自适应 IO 任务数，根据数据量动态调整 IO 并行度".to_string(),
-            impact: "可优化外部表扫描性能，平衡 IO 和 CPU 资源".to_string(),
-        },
-        "io_tasks_per_scan_operator" => ParameterMetadata {
-            description: "每个扫描算子的 IO 任务数，控制本地表扫描并行度".to_string(),
-            impact: "增大可提升扫描吞吐，但会增加 IO 压力".to_string(),
-        },
-        "connector_io_tasks_per_scan_operator" => ParameterMetadata {
-            description: "每个连接器扫描算子的 IO 任务数，控制外部表扫描并行度".to_string(),
-            impact: "增大可提升外部表扫描吞吐，但会增加远程存储压力".to_string(),
-        },
-
-        "hash_join_push_down_right_table" => ParameterMetadata {
-            description: "启用 Hash Join 右表下推，将小表广播到各节点".to_string(),
-            impact: "可减少数据 Shuffle，提升 Join 性能".to_string(),
-        },
-        "enable_local_shuffle_agg" => ParameterMetadata {
-            description: "启用本地 Shuffle 聚合，在本地先进行预聚合".to_string(),
-            impact: "可减少网络传输数据量，提升聚合性能".to_string(),
-        },
-
-        "runtime_filter_on_exchange_node" => ParameterMetadata {
-            description: "在 Exchange 节点启用 Runtime Filter，跨节点传递过滤条件".to_string(),
-            impact: "可提前过滤数据减少 Shuffle，但会增加 Filter 构建开销".to_string(),
-        },
-        "global_runtime_filter_build_max_size" => ParameterMetadata {
-            description: "全局 Runtime Filter 最大构建大小 (字节)".to_string(),
-            impact: "增大可支持更大的 Filter，但会占用更多内存".to_string(),
-        },
-
-        "parallel_fragment_exec_instance_num" => ParameterMetadata {
-            description: "每个 Fragment 的并行执行实例数".to_string(),
-            impact: "增大可提升并行度，但会占用更多资源".to_string(),
-        },
-        "pipeline_dop" => ParameterMetadata {
-            description: "Pipeline 执行并行度，0 表示自动".to_string(),
-            impact: "手动设置可控制资源使用，自动模式根据 CPU 核数调整".to_string(),
-        },
-
-        "query_mem_limit" => ParameterMetadata {
-            description: "单个查询的内存限制 (字节)".to_string(),
-            impact: "增大可处理更大数据量，但可能影响其他查询".to_string(),
-        },
-        "query_timeout" => ParameterMetadata {
-            description: "查询超时时间 (秒)".to_string(),
-            impact: "增大可允许长时间运行的查询，但可能占用资源过久".to_string(),
-        },
-
-        "streaming_preaggregation_mode" => ParameterMetadata {
-            description: "流式预聚合模式 (auto/force_streaming/force_preaggregation)".to_string(),
-            impact: "auto 模式自动选择最优策略，force 模式强制使用指定策略".to_string(),
-        },
-        "enable_sort_aggregate" => ParameterMetadata {
-            description: "启用排序聚合，适用于高基数 GROUP BY".to_string(),
-            impact: "可减少内存使用，但需要额外排序开销".to_string(),
-        },
-
-        "pipeline_profile_level" => ParameterMetadata {
-            description: "Pipeline Profile 详细级别 (0-2)".to_string(),
-            impact: "级别越高信息越详细，但收集开销也越大".to_string(),
-        },
-
-        "storage_page_cache_limit" => ParameterMetadata {
-            description: "BE 存储页缓存大小限制".to_string(),
-            impact: "增大可提升热数据读取性能，但会占用更多内存".to_string(),
-        },
-
-        _ => ParameterMetadata {
-            description: format!("StarRocks 参数 {}", name),
-            impact: "请参考 StarRocks 官方文档了解详情".to_string(),
-        },
+    if DOCUMENTED_PARAMETERS.contains(&name) {
+        ParameterMetadata {
+            description: msg!(format!("param.{}.description", name)),
+            impact: msg!(format!("param.{}.impact", name)),
+        }
+    } else {
+        ParameterMetadata {
+            description: msg!("param.default.description", name),
+            impact: msg!("param.default.impact"),
+        }
     }
 }
 
 /// Metadata for a parameter
 #[derive(Debug, Clone)]
 pub struct ParameterMetadata {
-    pub description: String,
-    pub impact: String,
+    pub description: Message,
+    pub impact: Message,
 }
 
 impl ParameterSuggestion {
@@ -269,17 +205,16 @@ impl ThresholdMetadata {
 #[derive(Debug, Clone)]
 pub struct Diagnostic {
     pub rule_id: String,
-    pub rule_name: String,
     pub severity: RuleSeverity,
     pub node_path: String,
     /// Plan node ID for associating diagnostic with execution tree node
     pub plan_node_id: Option<i32>,
     /// Summary of the diagnostic issue (诊断结果概要)
-    pub message: String,
+    pub message: Message,
     /// Detailed explanation of why this issue occurs (详细诊断原因)
-    pub reason: String,
+    pub reason: Message,
     /// Recommended actions to fix the issue (建议措施)
-    pub suggestions: Vec<String>,
+    pub suggestions: Vec<Message>,
     pub parameter_suggestions: Vec<ParameterSuggestion>,
     /// Threshold metadata for traceability (what threshold triggered this diagnostic)
     pub threshold_metadata: Option<ThresholdMetadata>,
@@ -294,23 +229,68 @@ impl Diagnostic {
 }
 
 impl Diagnostic {
-    /// Convert to HotSpot for backward compatibility
-    pub fn to_hotspot(&self) -> HotSpot {
-        let mut all_suggestions = self.suggestions.clone();
+    /// Localized rule name, shared by every diagnostic of the rule
+    pub fn rule_name(&self) -> Message {
+        msg!(format!("{}.name", self.rule_id))
+    }
 
+    /// Suggestions followed by one line per parameter suggestion
+    fn suggestions_with_parameters(&self) -> Vec<Message> {
+        let mut all_suggestions = self.suggestions.clone();
         for param in &self.parameter_suggestions {
-            all_suggestions.push(format!(
-                "调整参数: {} → {} (命令: {})",
-                param.name, param.recommended, param.command
+            all_suggestions.push(msg!(
+                "hotspot.parameter",
+                param.name,
+                param.recommended,
+                param.command
             ));
         }
+        all_suggestions
+    }
 
+    /// Convert to HotSpot for backward compatibility
+    pub fn to_hotspot(&self, locale: Locale) -> HotSpot {
         HotSpot {
             node_path: self.node_path.clone(),
             severity: self.severity.into(),
             issue_type: self.rule_id.clone(),
-            description: self.message.clone(),
-            suggestions: all_suggestions,
+            description: self.message.render(locale),
+            suggestions: render_all(&self.suggestions_with_parameters(), locale),
+        }
+    }
+
+    /// Convert to the API result type, rendering all text in the given locale
+    pub fn to_result(&self, locale: Locale) -> DiagnosticResult {
+        DiagnosticResult {
+            rule_id: self.rule_id.clone(),
+            rule_name: self.rule_name().render(locale),
+            severity: format!("{:?}", self.severity),
+            node_path: self.node_path.clone(),
+            plan_node_id: self.plan_node_id,
+            message: self.message.render(locale),
+            reason: self.reason.render(locale),
+            suggestions: render_all(&self.suggestions, locale),
+            parameter_suggestions: self
+                .parameter_suggestions
+                .iter()
+                .map(|p| ParameterTuningSuggestion {
+                    name: p.name.clone(),
+                    param_type: format!("{:?}", p.param_type),
+                    current: p.current.clone(),
+                    recommended: p.recommended.clone(),
+                    command: p.command.clone(),
+                    description: p.description.render(locale),
+                    impact: p.impact.render(locale),
+                })
+                .collect(),
+            threshold_metadata: self.threshold_metadata.as_ref().map(|tm| {
+                ThresholdMetadataResult {
+                    threshold_value: tm.threshold_value,
+                    threshold_source: tm.threshold_source.clone(),
+                    baseline_p95_ms: tm.baseline_p95_ms,
+                    baseline_sample_count: tm.baseline_sample_count,
+                }
+            }),
         }
     }
 }
@@ -590,7 +570,7 @@ impl<'a> RuleContext<'a> {
 
                 (
                     recommended.to_string(),
-                    format!("根据集群 {} 个 BE 节点推荐", be_count),
+                    msg!("param.parallel_fragment_exec_instance_num.reason", be_count),
                     ParameterType::Session,
                 )
             },
@@ -600,11 +580,7 @@ impl<'a> RuleContext<'a> {
                 if current == 0 {
                     return None;
                 }
-                (
-                    "0".to_string(),
-                    "推荐使用自动模式，系统会根据 CPU 核数自动调整".to_string(),
-                    ParameterType::Session,
-                )
+                ("0".to_string(), msg!("param.pipeline_dop.reason.1"), ParameterType::Session)
             },
 
             "io_tasks_per_scan_operator" => {
@@ -619,7 +595,7 @@ impl<'a> RuleContext<'a> {
 
                 (
                     recommended.to_string(),
-                    "大数据量扫描，建议增加 IO 并行度".to_string(),
+                    msg!("param.io_tasks_per_scan_operator.reason"),
                     ParameterType::Session,
                 )
             },
@@ -641,7 +617,7 @@ impl<'a> RuleContext<'a> {
                 let recommended_gb = recommended / (1024 * 1024 * 1024);
                 (
                     recommended.to_string(),
-                    format!("根据数据量推荐 {}GB 内存限制", recommended_gb),
+                    msg!("param.query_mem_limit.reason", recommended_gb),
                     ParameterType::Session,
                 )
             },
@@ -651,7 +627,7 @@ impl<'a> RuleContext<'a> {
                 if current {
                     return None;
                 }
-                ("true".to_string(), "启用后可避免大查询 OOM".to_string(), ParameterType::Session)
+                ("true".to_string(), msg!("param.enable_spill.reason"), ParameterType::Session)
             },
 
             "query_timeout" => {
@@ -660,11 +636,7 @@ impl<'a> RuleContext<'a> {
                 if current >= 600 {
                     return None;
                 }
-                (
-                    "600".to_string(),
-                    "延长超时时间以支持复杂查询".to_string(),
-                    ParameterType::Session,
-                )
+                ("600".to_string(), msg!("param.query_timeout.reason"), ParameterType::Session)
             },
 
             "enable_query_cache" => {
@@ -674,7 +646,7 @@ impl<'a> RuleContext<'a> {
                 }
                 (
                     "true".to_string(),
-                    "启用查询缓存可加速重复查询".to_string(),
+                    msg!("param.enable_query_cache.reason"),
                     ParameterType::Session,
                 )
             },
@@ -686,7 +658,7 @@ impl<'a> RuleContext<'a> {
                 }
                 (
                     "true".to_string(),
-                    "启用全局 Runtime Filter 提升 Join 性能".to_string(),
+                    msg!("param.enable_global_runtime_filter.reason"),
                     ParameterType::Session,
                 )
             },
@@ -698,7 +670,7 @@ impl<'a> RuleContext<'a> {
                 }
                 (
                     "10000000".to_string(),
-                    "增大 RF 下推阈值以支持更大的 Build 端".to_string(),
+                    msg!("param.runtime_join_filter_push_down_limit.reason"),
                     ParameterType::Session,
                 )
             },
@@ -710,7 +682,7 @@ impl<'a> RuleContext<'a> {
                 }
                 (
                     "true".to_string(),
-                    "启用 DataCache 提升存算分离性能".to_string(),
+                    msg!("param.enable_scan_datacache.reason"),
                     ParameterType::Session,
                 )
             },
@@ -720,7 +692,11 @@ impl<'a> RuleContext<'a> {
                 if current {
                     return None;
                 }
-                ("true".to_string(), "启用缓存填充以预热缓存".to_string(), ParameterType::Session)
+                (
+                    "true".to_string(),
+                    msg!("param.enable_populate_datacache.reason"),
+                    ParameterType::Session,
+                )
             },
 
             "pipeline_profile_level" => {
@@ -730,14 +706,16 @@ impl<'a> RuleContext<'a> {
                 }
                 (
                     "1".to_string(),
-                    "降低 Profile 级别减少收集开销".to_string(),
+                    msg!("param.pipeline_profile_level.reason"),
                     ParameterType::Session,
                 )
             },
 
-            "storage_page_cache_limit" => {
-                ("30%".to_string(), "增大页缓存提升热数据读取性能".to_string(), ParameterType::BE)
-            },
+            "storage_page_cache_limit" => (
+                "30%".to_string(),
+                msg!("param.storage_page_cache_limit.reason"),
+                ParameterType::BE,
+            ),
 
             _ => return None,
         };
@@ -759,7 +737,7 @@ impl<'a> RuleContext<'a> {
             recommended,
             command,
             description: metadata.description,
-            impact: format!("{} ({})", metadata.impact, reason),
+            impact: msg!("param.impact", metadata.impact, reason),
         })
     }
 }
@@ -799,9 +777,6 @@ pub trait DiagnosticRule: Send + Sync {
     /// Rule ID (e.g., "S001", "J001")
     fn id(&self) -> &str;
 
-    /// Rule name
-    fn name(&self) -> &str;
-
     /// Check if rule applies to this node
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool;
 
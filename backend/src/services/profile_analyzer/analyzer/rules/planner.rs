@@ -4,6 +4,8 @@
 //! - PL001: HMS metadata retrieval slow
 //! - PL002: Optimizer timeout or slow
 //! - PL003: Too many partitions to process
+//! - PL004: Cardinality misestimation, produced by `analyzer::cardinality`
+//!   because it needs `EXPLAIN COSTS` from the live cluster
 
 use super::*;
 use crate::services::profile_analyzer::models::PlannerInfo;
@@ -17,7 +19,6 @@ pub struct PlannerRuleContext<'a> {
 /// Trait for planner diagnostic rules
 pub trait PlannerDiagnosticRule: Send + Sync {
     fn id(&self) -> &str;
-    fn name(&self) -> &str;
     fn evaluate(&self, context: &PlannerRuleContext) -> Option<Diagnostic>;
 }
 
@@ -33,9 +34,6 @@ impl PlannerDiagnosticRule for PL001HMSMetadataSlow {
     fn id(&self) -> &str {
         "PL001"
     }
-    fn name(&self) -> &str {
-        "HMS 元数据获取慢"
-    }
 
     fn evaluate(&self, context: &PlannerRuleContext) -> Option<Diagnostic> {
         let hms = &context.planner.hms_metrics;
@@ -81,11 +79,11 @@ impl PlannerDiagnosticRule for PL001HMSMetadataSlow {
         }
 
         let (ratio_base, ratio_label) = if context.planner.total_time_ms > 0.0 {
-            (context.planner.total_time_ms, "Planner")
+            (context.planner.total_time_ms, Arg::from("Planner"))
         } else if context.query_time_ms > 0.0 {
-            (context.query_time_ms, "查询总")
+            (context.query_time_ms, msg!("PL001.ratio_base.query").into())
         } else {
-            (0.0, "")
+            (0.0, Arg::from(""))
         };
 
         let hms_ratio =
@@ -97,41 +95,29 @@ impl PlannerDiagnosticRule for PL001HMSMetadataSlow {
             RuleSeverity::Warning
         };
 
-        let ratio_info = if ratio_base > 0.0 {
-            format!("（占{}时间 {:.1}%）", ratio_label, hms_ratio)
-        } else {
-            String::new()
-        };
-
-        let message = if slow_calls.is_empty() {
-            format!(
-                "HMS 元数据获取总耗时 {}{}",
-                format_duration_ms(hms.total_hms_time_ms),
-                ratio_info
-            )
-        } else {
-            format!(
-                "HMS 元数据获取总耗时 {}{}，慢调用: {}",
-                format_duration_ms(hms.total_hms_time_ms),
-                ratio_info,
-                slow_calls.join(", ")
-            )
+        let total = format_duration_ms(hms.total_hms_time_ms);
+        let ratio = format!("{:.1}", hms_ratio);
+        let message = match (ratio_base > 0.0, slow_calls.is_empty()) {
+            (false, true) => msg!("PL001.message.1", total),
+            (false, false) => msg!("PL001.message.2", total, slow_calls.join(", ")),
+            (true, true) => msg!("PL001.message.3", total, ratio_label, ratio),
+            (true, false) => {
+                msg!("PL001.message.4", total, ratio_label, ratio, slow_calls.join(", "))
+            },
         };
 
         Some(Diagnostic {
             rule_id: self.id().to_string(),
-            rule_name: self.name().to_string(),
             severity,
             node_path: "Planner".to_string(),
             plan_node_id: None,
             message,
-            reason: "Hive MetaStore 响应慢会阻塞查询规划，可能是 HMS 服务负载高或网络延迟"
-                .to_string(),
+            reason: msg!("PL001.reason"),
             suggestions: vec![
-                "检查 HMS 服务状态和负载".to_string(),
-                "减少查询涉及的分区数量".to_string(),
-                "考虑启用元数据缓存".to_string(),
-                "检查网络延迟".to_string(),
+                msg!("PL001.suggestion.1"),
+                msg!("PL001.suggestion.2"),
+                msg!("PL001.suggestion.3"),
+                msg!("PL001.suggestion.4"),
             ],
             parameter_suggestions: vec![],
             threshold_metadata: None,
@@ -151,9 +137,6 @@ impl PlannerDiagnosticRule for PL002OptimizerSlow {
     fn id(&self) -> &str {
         "PL002"
     }
-    fn name(&self) -> &str {
-        "优化器耗时过长"
-    }
 
     fn evaluate(&self, context: &PlannerRuleContext) -> Option<Diagnostic> {
         let optimizer_ms = context.planner.optimizer_time_ms;
@@ -173,21 +156,20 @@ impl PlannerDiagnosticRule for PL002OptimizerSlow {
 
         Some(Diagnostic {
             rule_id: self.id().to_string(),
-            rule_name: self.name().to_string(),
             severity,
             node_path: "Planner".to_string(),
             plan_node_id: None,
-            message: format!(
-                "优化器耗时 {}（占 Planner 时间 {:.1}%）",
+            message: msg!(
+                "PL002.message",
                 format_duration_ms(optimizer_ms),
-                ratio
+                format!("{:.1}", ratio)
             ),
-            reason: "查询优化器花费过长时间，可能是查询过于复杂或统计信息不准确".to_string(),
+            reason: msg!("PL002.reason"),
             suggestions: vec![
-                "简化查询结构，减少 JOIN 和子查询数量".to_string(),
-                "更新表的统计信息: ANALYZE TABLE".to_string(),
-                "检查 new_planner_optimize_timeout 参数设置".to_string(),
-                "考虑拆分为多个简单查询".to_string(),
+                msg!("PL002.suggestion.1"),
+                msg!("PL002.suggestion.2"),
+                msg!("PL002.suggestion.3"),
+                msg!("PL002.suggestion.4"),
             ],
             parameter_suggestions: vec![],
             threshold_metadata: None,
@@ -207,9 +189,6 @@ impl PlannerDiagnosticRule for PL003HighPlannerRatio {
     fn id(&self) -> &str {
         "PL003"
     }
-    fn name(&self) -> &str {
-        "规划时间占比过高"
-    }
 
     fn evaluate(&self, context: &PlannerRuleContext) -> Option<Diagnostic> {
         let planner_ms = context.planner.total_time_ms;
@@ -229,20 +208,12 @@ impl PlannerDiagnosticRule for PL003HighPlannerRatio {
 
         Some(Diagnostic {
             rule_id: self.id().to_string(),
-            rule_name: self.name().to_string(),
             severity,
             node_path: "Planner".to_string(),
             plan_node_id: None,
-            message: format!(
-                "规划时间 {} 占查询总时间的 {:.1}%",
-                format_duration_ms(planner_ms),
-                ratio
-            ),
-            reason: "查询规划占用了大量时间，通常是元数据获取或优化器导致".to_string(),
-            suggestions: vec![
-                "参见 PL001/PL002 获取具体原因".to_string(),
-                "减少查询涉及的表和分区数量".to_string(),
-            ],
+            message: msg!("PL003.message", format_duration_ms(planner_ms), format!("{:.1}", ratio)),
+            reason: msg!("PL003.reason"),
+            suggestions: vec![msg!("PL003.suggestion.1"), msg!("PL003.suggestion.2")],
             parameter_suggestions: vec![],
             threshold_metadata: None,
         })
@@ -13,9 +13,6 @@ impl DiagnosticRule for P001ProjectExprHigh {
     fn id(&self) -> &str {
         "P001"
     }
-    fn name(&self) -> &str {
-        "Project 表达式计算耗时高"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("PROJECT")
@@ -31,7 +28,6 @@ impl DiagnosticRule for P001ProjectExprHigh {
         if ratio > 0.5 && expr_time > 100_000_000.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -39,12 +35,12 @@ impl DiagnosticRule for P001ProjectExprHigh {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("Project 表达式计算占比过高 ({:.1}%)", ratio * 100.0),
-                reason: "Project 算子执行时间过长，可能是表达式计算复杂或数据量大。".to_string(),
+                message: msg!("P001.message", format!("{:.1}", ratio * 100.0)),
+                reason: msg!("P001.reason"),
                 suggestions: vec![
-                    "简化 SELECT 中的复杂表达式".to_string(),
-                    "将复杂计算移到物化视图中预计算".to_string(),
-                    "检查是否有不必要的类型转换".to_string(),
+                    msg!("P001.suggestion.1"),
+                    msg!("P001.suggestion.2"),
+                    msg!("P001.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -63,9 +59,6 @@ impl DiagnosticRule for P002CommonSubExprHigh {
     fn id(&self) -> &str {
         "P002"
     }
-    fn name(&self) -> &str {
-        "公共子表达式计算耗时高"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("PROJECT")
@@ -101,7 +94,6 @@ impl DiagnosticRule for P002CommonSubExprHigh {
 
         Some(Diagnostic {
             rule_id: self.id().to_string(),
-            rule_name: self.name().to_string(),
             severity,
             node_path: format!(
                 "{} (plan_node_id={})",
@@ -109,19 +101,20 @@ impl DiagnosticRule for P002CommonSubExprHigh {
                 context.node.plan_node_id.unwrap_or(-1)
             ),
             plan_node_id: context.node.plan_node_id,
-            message: format!(
-                "公共子表达式计算耗时 {}（占表达式总时间 {:.1}%）",
-                format_duration_ms(time_ms), common_ratio
+            message: msg!(
+                "P002.message",
+                format_duration_ms(time_ms),
+                format!("{:.1}", common_ratio)
             ),
-            reason: "复杂 CASE WHEN 表达式或重复子表达式导致计算开销高。StarRocks 会尝试提取公共子表达式以避免重复计算，但提取本身也有开销。".to_string(),
+            reason: msg!("P002.reason"),
             suggestions: vec![
-                "简化 CASE WHEN 表达式，减少分支数量".to_string(),
-                "将复杂条件判断移到物化视图预计算".to_string(),
-                "检查是否存在大量重复的表达式计算".to_string(),
-                "考虑使用 IF() 替代简单的 CASE WHEN".to_string(),
+                msg!("P002.suggestion.1"),
+                msg!("P002.suggestion.2"),
+                msg!("P002.suggestion.3"),
+                msg!("P002.suggestion.4"),
             ],
             parameter_suggestions: vec![],
-                threshold_metadata: None,
+            threshold_metadata: None,
         })
     }
 }
@@ -133,9 +126,6 @@ impl DiagnosticRule for L001LocalExchangeMemory {
     fn id(&self) -> &str {
         "L001"
     }
-    fn name(&self) -> &str {
-        "LocalExchange 内存使用过高"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("LOCAL")
@@ -150,7 +140,6 @@ impl DiagnosticRule for L001LocalExchangeMemory {
         if memory > ONE_GB {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -158,12 +147,9 @@ impl DiagnosticRule for L001LocalExchangeMemory {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("LocalExchange 内存使用 {}", format_bytes(memory as u64)),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
-                suggestions: vec![
-                    "检查上下游算子的数据流是否平衡".to_string(),
-                    "调整 pipeline_dop 参数".to_string(),
-                ],
+                message: msg!("L001.message", format_bytes(memory as u64)),
+                reason: msg!("L001.reason"),
+                suggestions: vec![msg!("L001.suggestion.1"), msg!("L001.suggestion.2")],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
                     if let Some(s) = context.suggest_parameter_smart("pipeline_dop") {
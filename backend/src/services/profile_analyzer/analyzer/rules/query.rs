@@ -1,4 +1,4 @@
-//! Query-level diagnostic rules (Q001-Q009)
+//! Query-level diagnostic rules (Q001-Q010)
 //!
 //! Rules that evaluate the entire query profile.
 
@@ -7,6 +7,8 @@ use super::{
     get_parameter_metadata, parse_duration_ms,
 };
 use crate::services::profile_analyzer::analyzer::thresholds::DynamicThresholds;
+use crate::services::profile_analyzer::analyzer::timeline::TimelineAnalyzer;
+use crate::services::profile_analyzer::i18n::{Message, msg};
 use crate::services::profile_analyzer::models::*;
 
 /// Known default values for common StarRocks session parameters
@@ -95,7 +97,7 @@ impl<'a> QueryRuleContext<'a> {
                 if current >= 600 {
                     return None;
                 }
-                ("600".to_string(), "延长超时时间以支持复杂查询".to_string())
+                ("600".to_string(), msg!("param.query_timeout.reason"))
             },
 
             "query_mem_limit" => {
@@ -110,14 +112,14 @@ impl<'a> QueryRuleContext<'a> {
                     return None;
                 }
                 let gb = recommended / (1024 * 1024 * 1024);
-                (recommended.to_string(), format!("根据数据量推荐 {}GB 内存限制", gb))
+                (recommended.to_string(), msg!("param.query_mem_limit.reason", gb))
             },
 
             "enable_spill" => {
                 if current_bool.unwrap_or(false) {
                     return None;
                 }
-                ("true".to_string(), "启用后可避免大查询 OOM".to_string())
+                ("true".to_string(), msg!("param.enable_spill.reason"))
             },
 
             "pipeline_profile_level" => {
@@ -125,7 +127,7 @@ impl<'a> QueryRuleContext<'a> {
                 if current <= 1 {
                     return None;
                 }
-                ("1".to_string(), "降低 Profile 级别减少收集开销".to_string())
+                ("1".to_string(), msg!("param.pipeline_profile_level.reason"))
             },
 
             "pipeline_dop" => {
@@ -133,14 +135,14 @@ impl<'a> QueryRuleContext<'a> {
                 if current == 0 {
                     return None;
                 }
-                ("0".to_string(), "推荐使用自动模式".to_string())
+                ("0".to_string(), msg!("param.pipeline_dop.reason.2"))
             },
 
             "enable_scan_datacache" => {
                 if current_bool.unwrap_or(true) {
                     return None;
                 }
-                ("true".to_string(), "启用 DataCache 提升存算分离性能".to_string())
+                ("true".to_string(), msg!("param.enable_scan_datacache.reason"))
             },
 
             _ => return None,
@@ -155,7 +157,7 @@ impl<'a> QueryRuleContext<'a> {
             recommended,
             command,
             description: metadata.description,
-            impact: format!("{} ({})", metadata.impact, reason),
+            impact: msg!("param.impact", metadata.impact, reason),
         })
     }
 }
@@ -163,7 +165,6 @@ impl<'a> QueryRuleContext<'a> {
 /// Query-level rule trait
 pub trait QueryRule: Send + Sync {
     fn id(&self) -> &str;
-    fn name(&self) -> &str;
     /// Evaluate the rule with full context including cluster variables
     fn evaluate(&self, ctx: &QueryRuleContext) -> Option<QueryDiagnostic>;
 }
@@ -172,11 +173,10 @@ pub trait QueryRule: Send + Sync {
 #[derive(Debug, Clone)]
 pub struct QueryDiagnostic {
     pub rule_id: String,
-    pub rule_name: String,
     pub severity: RuleSeverity,
-    pub message: String,
-    pub reason: String,
-    pub suggestions: Vec<String>,
+    pub message: Message,
+    pub reason: Message,
+    pub suggestions: Vec<Message>,
     pub parameter_suggestions: Vec<ParameterSuggestion>,
     /// Threshold metadata for traceability
     pub threshold_metadata: Option<super::ThresholdMetadata>,
@@ -196,9 +196,6 @@ impl QueryRule for Q001LongRunning {
     fn id(&self) -> &str {
         "Q001"
     }
-    fn name(&self) -> &str {
-        "查询执行时间过长"
-    }
 
     fn evaluate(&self, ctx: &QueryRuleContext) -> Option<QueryDiagnostic> {
         use crate::services::profile_analyzer::analyzer::thresholds::QueryType;
@@ -213,50 +210,49 @@ impl QueryRule for Q001LongRunning {
         let has_baseline = ctx.thresholds.baseline.is_some();
 
         let threshold_display = if time_threshold_ms >= 60_000.0 {
-            format!("{:.0}分钟", time_threshold_ms / 60_000.0)
+            msg!("common.minutes", format!("{:.0}", time_threshold_ms / 60_000.0))
         } else {
-            format!("{:.0}秒", time_threshold_ms / 1000.0)
+            msg!("common.seconds", format!("{:.0}", time_threshold_ms / 1000.0))
         };
 
-        let threshold_source = if has_baseline { "自适应基线" } else { "默认" };
+        let threshold_source = if has_baseline {
+            msg!("Q001.threshold_source.baseline")
+        } else {
+            msg!("Q001.threshold_source.default")
+        };
 
         let query_type = QueryType::from_sql(&ctx.profile.summary.sql_statement);
-        let query_type_name = match query_type {
-            QueryType::Select => "OLAP 查询",
-            QueryType::Insert => "INSERT 导入",
-            QueryType::Export => "EXPORT 导出",
-            QueryType::Analyze => "ANALYZE 分析",
-            QueryType::Ctas => "CTAS 建表",
-            QueryType::Load => "LOAD 导入",
-            QueryType::Unknown => "查询",
-        };
+        let query_type_name = msg!(match query_type {
+            QueryType::Select => "Q001.query_type.select",
+            QueryType::Insert => "Q001.query_type.insert",
+            QueryType::Export => "Q001.query_type.export",
+            QueryType::Analyze => "Q001.query_type.analyze",
+            QueryType::Ctas => "Q001.query_type.ctas",
+            QueryType::Load => "Q001.query_type.load",
+            QueryType::Unknown => "Q001.query_type.unknown",
+        });
 
         if total_time_ms > time_threshold_ms {
             Some(QueryDiagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                message: format!(
-                    "{}执行时间 {}，超过{}阈值 ({}, {})",
-                    query_type_name,
+                message: msg!(
+                    "Q001.message",
+                    query_type_name.clone(),
                     format_duration_ms(total_time_ms),
-                    query_type_name,
+                    query_type_name.clone(),
                     threshold_display,
                     threshold_source
                 ),
                 reason: if has_baseline {
-                    "阈值基于历史基线 P95 + 2σ 计算。当前查询显著慢于同类查询的历史表现。"
-                        .to_string()
+                    msg!("Q001.reason.1")
                 } else {
-                    format!(
-                        "根据查询类型 ({}) 使用默认阈值。OLAP 查询期望快速响应 (10s)，而 ETL 任务允许更长时间 (5-30min)。",
-                        query_type_name
-                    )
+                    msg!("Q001.reason.2", query_type_name)
                 },
                 suggestions: vec![
-                    "检查是否存在性能瓶颈算子".to_string(),
-                    "考虑优化查询计划".to_string(),
-                    "检查是否存在数据倾斜".to_string(),
+                    msg!("Q001.suggestion"),
+                    msg!("G001.suggestion.16"),
+                    msg!("G001.suggestion.6"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
@@ -290,9 +286,6 @@ impl QueryRule for Q002HighMemory {
     fn id(&self) -> &str {
         "Q002"
     }
-    fn name(&self) -> &str {
-        "查询内存使用过高"
-    }
 
     fn evaluate(&self, ctx: &QueryRuleContext) -> Option<QueryDiagnostic> {
         let peak_memory = ctx.profile.summary.query_peak_memory?;
@@ -301,14 +294,13 @@ impl QueryRule for Q002HighMemory {
         if peak_memory > TEN_GB {
             Some(QueryDiagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                message: format!("查询峰值内存 {}，超过 10GB 阈值", format_bytes(peak_memory)),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
+                message: msg!("Q002.message", format_bytes(peak_memory)),
+                reason: msg!("L001.reason"),
                 suggestions: vec![
-                    "检查是否存在大表 Join".to_string(),
-                    "考虑启用 Spill 功能".to_string(),
-                    "优化查询减少中间结果".to_string(),
+                    msg!("Q002.suggestion.1"),
+                    msg!("Q002.suggestion.2"),
+                    msg!("Q002.suggestion.3"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
@@ -336,9 +328,6 @@ impl QueryRule for Q003QuerySpill {
     fn id(&self) -> &str {
         "Q003"
     }
-    fn name(&self) -> &str {
-        "查询发生落盘"
-    }
 
     fn evaluate(&self, ctx: &QueryRuleContext) -> Option<QueryDiagnostic> {
         let spill_bytes_str = ctx.profile.summary.query_spill_bytes.as_ref()?;
@@ -348,14 +337,13 @@ impl QueryRule for Q003QuerySpill {
         if spill_bytes > 0 {
             Some(QueryDiagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Info,
-                message: format!("查询发生磁盘溢写，溢写数据量 {}", format_bytes(spill_bytes)),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
+                message: msg!("Q003.message", format_bytes(spill_bytes)),
+                reason: msg!("L001.reason"),
                 suggestions: vec![
-                    "增加内存限制以减少 Spill".to_string(),
-                    "优化查询减少中间结果".to_string(),
-                    "检查 Spill 是否影响性能".to_string(),
+                    msg!("Q003.suggestion.1"),
+                    msg!("Q002.suggestion.3"),
+                    msg!("Q003.suggestion.2"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
@@ -380,9 +368,6 @@ impl QueryRule for Q005ScanDominates {
     fn id(&self) -> &str {
         "Q005"
     }
-    fn name(&self) -> &str {
-        "扫描时间占比过高"
-    }
 
     fn evaluate(&self, ctx: &QueryRuleContext) -> Option<QueryDiagnostic> {
         let scan_time_ms = ctx
@@ -411,15 +396,14 @@ impl QueryRule for Q005ScanDominates {
         if ratio > 0.8 {
             Some(QueryDiagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                message: format!("扫描时间占比 {:.1}%，查询瓶颈在数据扫描", ratio * 100.0),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
+                message: msg!("Q005.message", format!("{:.1}", ratio * 100.0)),
+                reason: msg!("L001.reason"),
                 suggestions: vec![
-                    "添加过滤条件减少扫描数据量".to_string(),
-                    "检查分区裁剪是否生效".to_string(),
-                    "考虑创建物化视图".to_string(),
-                    "检查存储性能".to_string(),
+                    msg!("Q005.suggestion.1"),
+                    msg!("G001.suggestion.2"),
+                    msg!("Q005.suggestion.2"),
+                    msg!("Q005.suggestion.3"),
                 ],
 
                 parameter_suggestions: ctx
@@ -442,9 +426,6 @@ impl QueryRule for Q006NetworkDominates {
     fn id(&self) -> &str {
         "Q006"
     }
-    fn name(&self) -> &str {
-        "网络时间占比过高"
-    }
 
     fn evaluate(&self, ctx: &QueryRuleContext) -> Option<QueryDiagnostic> {
         let network_time_ms = ctx
@@ -473,14 +454,13 @@ impl QueryRule for Q006NetworkDominates {
         if ratio > 0.5 {
             Some(QueryDiagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                message: format!("网络时间占比 {:.1}%，查询瓶颈在网络传输", ratio * 100.0),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
+                message: msg!("Q006.message", format!("{:.1}", ratio * 100.0)),
+                reason: msg!("L001.reason"),
                 suggestions: vec![
-                    "考虑使用 Colocate Join 减少 Shuffle".to_string(),
-                    "检查网络带宽".to_string(),
-                    "减少跨节点数据传输".to_string(),
+                    msg!("Q006.suggestion.1"),
+                    msg!("Q006.suggestion.2"),
+                    msg!("E002.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -498,9 +478,6 @@ impl QueryRule for Q004LowCPU {
     fn id(&self) -> &str {
         "Q004"
     }
-    fn name(&self) -> &str {
-        "CPU 利用率低"
-    }
 
     fn evaluate(&self, ctx: &QueryRuleContext) -> Option<QueryDiagnostic> {
         let cpu_time = ctx.profile.summary.query_cumulative_cpu_time_ms?;
@@ -512,11 +489,10 @@ impl QueryRule for Q004LowCPU {
         if ratio < 0.3 {
             Some(QueryDiagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: super::RuleSeverity::Warning,
-                message: format!("CPU 利用率仅 {:.1}%，可能存在等待或 IO 瓶颈", ratio * 100.0),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
-                suggestions: vec!["检查是否存在等待".to_string(), "增加并行度".to_string()],
+                message: msg!("Q004.message", format!("{:.1}", ratio * 100.0)),
+                reason: msg!("L001.reason"),
+                suggestions: vec![msg!("Q004.suggestion.1"), msg!("Q004.suggestion.2")],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
                     if let Some(s) = ctx.suggest_parameter("pipeline_dop") {
@@ -539,9 +515,6 @@ impl QueryRule for Q007ProfileCollectSlow {
     fn id(&self) -> &str {
         "Q007"
     }
-    fn name(&self) -> &str {
-        "Profile 收集慢"
-    }
 
     fn evaluate(&self, ctx: &QueryRuleContext) -> Option<QueryDiagnostic> {
         let collect_time = ctx
@@ -553,11 +526,10 @@ impl QueryRule for Q007ProfileCollectSlow {
         if collect_time > 100_000_000.0 {
             Some(QueryDiagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: super::RuleSeverity::Info,
-                message: format!("Profile 收集时间 {:.1}ms", collect_time / 1_000_000.0),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
-                suggestions: vec!["降低 pipeline_profile_level".to_string()],
+                message: msg!("Q007.message", format!("{:.1}", collect_time / 1_000_000.0)),
+                reason: msg!("L001.reason"),
+                suggestions: vec![msg!("Q007.suggestion")],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
                     if let Some(s) = ctx.suggest_parameter("pipeline_profile_level") {
@@ -580,9 +552,6 @@ impl QueryRule for Q008ScheduleTimeLong {
     fn id(&self) -> &str {
         "Q008"
     }
-    fn name(&self) -> &str {
-        "调度时间过长"
-    }
 
     fn evaluate(&self, ctx: &QueryRuleContext) -> Option<QueryDiagnostic> {
         let schedule_time = ctx.profile.summary.query_peak_schedule_time_ms?;
@@ -594,11 +563,10 @@ impl QueryRule for Q008ScheduleTimeLong {
         if ratio > 0.3 {
             Some(QueryDiagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: super::RuleSeverity::Warning,
-                message: format!("调度时间占比 {:.1}%，Pipeline 调度可能存在瓶颈", ratio * 100.0),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
-                suggestions: vec!["检查 Pipeline 调度瓶颈".to_string(), "增加并行度".to_string()],
+                message: msg!("Q008.message", format!("{:.1}", ratio * 100.0)),
+                reason: msg!("L001.reason"),
+                suggestions: vec![msg!("Q008.suggestion"), msg!("Q004.suggestion.2")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -615,9 +583,6 @@ impl QueryRule for Q009ResultDeliverySlow {
     fn id(&self) -> &str {
         "Q009"
     }
-    fn name(&self) -> &str {
-        "结果传输慢"
-    }
 
     fn evaluate(&self, ctx: &QueryRuleContext) -> Option<QueryDiagnostic> {
         let deliver_time = ctx
@@ -634,11 +599,10 @@ impl QueryRule for Q009ResultDeliverySlow {
         if ratio > 0.2 {
             Some(QueryDiagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: super::RuleSeverity::Info,
-                message: format!("结果传输时间占比 {:.1}%", ratio * 100.0),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
-                suggestions: vec!["检查网络带宽".to_string(), "减少结果集大小".to_string()],
+                message: msg!("Q009.message", format!("{:.1}", ratio * 100.0)),
+                reason: msg!("L001.reason"),
+                suggestions: vec![msg!("Q006.suggestion.2"), msg!("Q009.suggestion")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -648,6 +612,59 @@ impl QueryRule for Q009ResultDeliverySlow {
     }
 }
 
+/// Q010: Pipeline drivers wait too long in the ready queue
+/// Condition: avg ScheduleTime > 1s and > 20% of DriverTotalTime for a pipeline
+pub struct Q010DriverQueueingLong;
+
+impl QueryRule for Q010DriverQueueingLong {
+    fn id(&self) -> &str {
+        "Q010"
+    }
+
+    fn evaluate(&self, ctx: &QueryRuleContext) -> Option<QueryDiagnostic> {
+        const MIN_QUEUE_TIME_MS: f64 = 1000.0;
+        const MIN_QUEUE_PERCENTAGE: f64 = 20.0;
+
+        let timeline = TimelineAnalyzer::analyze(ctx.profile)?;
+        let (fragment, pipeline) = timeline
+            .fragments
+            .iter()
+            .flat_map(|f| f.pipelines.iter().map(move |p| (f, p)))
+            .filter(|(_, p)| p.queue_time_ms.avg > MIN_QUEUE_TIME_MS)
+            .max_by(|(_, a), (_, b)| a.queue_percentage.total_cmp(&b.queue_percentage))?;
+        if pipeline.queue_percentage <= MIN_QUEUE_PERCENTAGE {
+            return None;
+        }
+
+        let severity = if pipeline.queue_percentage > 50.0 {
+            super::RuleSeverity::Error
+        } else {
+            super::RuleSeverity::Warning
+        };
+        let parameter_suggestions = ctx.suggest_parameter("pipeline_dop").into_iter().collect();
+
+        Some(QueryDiagnostic {
+            rule_id: self.id().to_string(),
+            severity,
+            message: msg!(
+                "Q010.message",
+                fragment.fragment_id,
+                pipeline.pipeline_id,
+                format_duration_ms(pipeline.queue_time_ms.avg),
+                format!("{:.1}", pipeline.queue_percentage)
+            ),
+            reason: msg!("Q010.reason"),
+            suggestions: vec![
+                msg!("Q010.suggestion.1"),
+                msg!("Q010.suggestion.2"),
+                msg!("Q010.suggestion.3"),
+            ],
+            parameter_suggestions,
+            threshold_metadata: None,
+        })
+    }
+}
+
 /// Parse spill bytes string (e.g., "1.5 GB", "0.000 B")
 fn parse_spill_bytes(s: &str) -> Option<u64> {
     let s = s.trim();
@@ -684,5 +701,6 @@ pub fn get_rules() -> Vec<Box<dyn QueryRule>> {
         Box::new(Q007ProfileCollectSlow),
         Box::new(Q008ScheduleTimeLong),
         Box::new(Q009ResultDeliverySlow),
+        Box::new(Q010DriverQueueingLong),
     ]
 }
@@ -29,9 +29,6 @@ impl DiagnosticRule for S001DataSkew {
     fn id(&self) -> &str {
         "S001"
     }
-    fn name(&self) -> &str {
-        "Scan 数据倾斜"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SCAN")
@@ -62,39 +59,36 @@ impl DiagnosticRule for S001DataSkew {
 
             let (reason, suggestions) = if is_internal {
                 (
-                    format!(
-                        "内表「{}」数据在各节点分布不均（max {:.0} 行，min {:.0} 行）。通常是分桶键选择不当导致数据倾斜。",
-                        table, max_rows, min_rows
+                    msg!(
+                        "S001.reason.1",
+                        table,
+                        format!("{:.0}", max_rows),
+                        format!("{:.0}", min_rows)
                     ),
                     vec![
-                        format!("检查表「{}」的分桶键是否选择了高基数列", table),
-                        format!(
-                            "查看数据分布: SELECT COUNT(*) FROM {} GROUP BY <bucket_key> ORDER BY 1 DESC",
-                            table
-                        ),
-                        format!(
-                            "必要时重建分桶: ALTER TABLE {} DISTRIBUTED BY HASH(<high_cardinality_column>) BUCKETS N",
-                            table
-                        ),
+                        msg!("S001.suggestion.1", table),
+                        msg!("S001.suggestion.2", table),
+                        msg!("S001.suggestion.3", table),
                     ],
                 )
             } else {
                 (
-                    format!(
-                        "外表「{}」数据在各节点分布不均（max {:.0} 行，min {:.0} 行）。可能是 Hive 分区大小不均或文件分布不均。",
-                        table, max_rows, min_rows
+                    msg!(
+                        "S001.reason.2",
+                        table,
+                        format!("{:.0}", max_rows),
+                        format!("{:.0}", min_rows)
                     ),
                     vec![
-                        format!("检查外表「{}」的分区数据量是否均衡", table),
-                        "检查是否存在热点分区或超大文件".to_string(),
-                        "考虑在 Hive 侧重新分区或合并小文件".to_string(),
+                        msg!("S001.suggestion.4", table),
+                        msg!("S001.suggestion.5"),
+                        msg!("S001.suggestion.6"),
                     ],
                 )
             };
 
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -102,9 +96,10 @@ impl DiagnosticRule for S001DataSkew {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "Scan 存在数据倾斜，max/avg 比率为 {:.2} (阈值: {:.1})",
-                    ratio, skew_threshold
+                message: msg!(
+                    "S001.message",
+                    format!("{:.2}", ratio),
+                    format!("{:.1}", skew_threshold)
                 ),
                 reason,
                 suggestions,
@@ -126,9 +121,6 @@ impl DiagnosticRule for S003PoorFilter {
     fn id(&self) -> &str {
         "S003"
     }
-    fn name(&self) -> &str {
-        "过滤效果差"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SCAN")
@@ -150,42 +142,37 @@ impl DiagnosticRule for S003PoorFilter {
 
             let (reason, suggestions) = if is_internal {
                 (
-                    format!(
-                        "内表「{}」扫描了 {:.0} 行但仅过滤掉 {:.1}%。可通过 ZoneMap、BloomFilter 索引或谓词下推提前过滤。",
+                    msg!(
+                        "S003.reason.1",
                         table,
-                        raw_rows_read,
-                        (1.0 - ratio) * 100.0
+                        format!("{:.0}", raw_rows_read),
+                        format!("{:.1}", (1.0 - ratio) * 100.0)
                     ),
                     vec![
-                        format!("为表「{}」的过滤列添加 ZoneMap 或 BloomFilter 索引", table),
-                        "检查 WHERE 条件是否支持下推（避免函数包裹、类型转换）".to_string(),
-                        format!("检查表「{}」的分区是否能裁剪", table),
-                        "通过 EXPLAIN 查看谓词下推情况".to_string(),
+                        msg!("S003.suggestion.1", table),
+                        msg!("S003.suggestion.2"),
+                        msg!("S003.suggestion.3", table),
+                        msg!("S003.suggestion.4"),
                     ],
                 )
             } else {
                 (
-                    format!(
-                        "外表「{}」扫描了 {:.0} 行但仅过滤掉 {:.1}%。外表过滤依赖 Hive 分区裁剪和文件格式的统计信息。",
+                    msg!(
+                        "S003.reason.2",
                         table,
-                        raw_rows_read,
-                        (1.0 - ratio) * 100.0
+                        format!("{:.0}", raw_rows_read),
+                        format!("{:.1}", (1.0 - ratio) * 100.0)
                     ),
                     vec![
-                        format!(
-                            "检查外表「{}」的 Hive 分区是否能裁剪（WHERE 条件包含分区列）",
-                            table
-                        ),
-                        "ORC/Parquet 文件利用 min/max 统计信息过滤，确保文件有 statistics"
-                            .to_string(),
-                        "检查 WHERE 条件是否支持下推到外部存储".to_string(),
+                        msg!("S003.suggestion.5", table),
+                        msg!("S003.suggestion.6"),
+                        msg!("S003.suggestion.7"),
                     ],
                 )
             };
 
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -193,11 +180,11 @@ impl DiagnosticRule for S003PoorFilter {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "过滤效果差，仅过滤了 {:.1}% 的数据 (读取 {:.0} 行 / 原始 {:.0} 行)",
-                    (1.0 - ratio) * 100.0,
-                    rows_read,
-                    raw_rows_read
+                message: msg!(
+                    "S003.message",
+                    format!("{:.1}", (1.0 - ratio) * 100.0),
+                    format!("{:.0}", rows_read),
+                    format!("{:.0}", raw_rows_read)
                 ),
                 reason,
                 suggestions,
@@ -218,9 +205,6 @@ impl DiagnosticRule for S007ColdStorage {
     fn id(&self) -> &str {
         "S007"
     }
-    fn name(&self) -> &str {
-        "冷存储访问"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SCAN")
@@ -246,21 +230,23 @@ impl DiagnosticRule for S007ColdStorage {
         if ratio > 0.8 && bytes_read > ONE_GB {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "IO 时间占比 {:.1}%，读取数据量 {}，可能存在存储瓶颈",
-                    ratio * 100.0, format_bytes(bytes_read as u64)
+                message: msg!(
+                    "S007.message",
+                    format!("{:.1}", ratio * 100.0),
+                    format_bytes(bytes_read as u64)
                 ),
-                reason: "数据存储在冷存储（如对象存储）上，IO 延迟较高。冷存储的 IOPS 和吞吐量通常低于本地 SSD。".to_string(),
+                reason: msg!("S007.reason"),
                 suggestions: vec![
-                    "检查存储性能，考虑使用 SSD".to_string(),
-                    "增大 PageCache 缓存".to_string(),
-                    "检查网络带宽（如果是远程存储）".to_string(),
+                    msg!("S007.suggestion.1"),
+                    msg!("S007.suggestion.2"),
+                    msg!("S007.suggestion.3"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
@@ -291,9 +277,6 @@ impl DiagnosticRule for S009LowCacheHit {
     fn id(&self) -> &str {
         "S009"
     }
-    fn name(&self) -> &str {
-        "缓存命中率低"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SCAN")
@@ -320,39 +303,47 @@ impl DiagnosticRule for S009LowCacheHit {
                 let miss_rate = (1.0 - hit_rate) * 100.0;
                 return Some(Diagnostic {
                     rule_id: self.id().to_string(),
-                    rule_name: self.name().to_string(),
-                    severity: if hit_rate < error_threshold { RuleSeverity::Error } else { RuleSeverity::Warning },
-                    node_path: format!("{} (plan_node_id={})", 
+                    severity: if hit_rate < error_threshold {
+                        RuleSeverity::Error
+                    } else {
+                        RuleSeverity::Warning
+                    },
+                    node_path: format!(
+                        "{} (plan_node_id={})",
                         context.node.operator_name,
-                        context.node.plan_node_id.unwrap_or(-1)),
+                        context.node.plan_node_id.unwrap_or(-1)
+                    ),
                     plan_node_id: context.node.plan_node_id,
-                    message: format!(
-                        "DataCache 命中率 {:.1}%，{:.1}% 数据从远程存储读取 (本地: {}, 远程: {})",
-                        hit_rate * 100.0,
-                        miss_rate,
+                    message: msg!(
+                        "S009.message.1",
+                        format!("{:.1}", hit_rate * 100.0),
+                        format!("{:.1}", miss_rate),
                         format_bytes(bytes_local as u64),
                         format_bytes(bytes_remote as u64)
                     ),
-                    reason: "存算分离架构下，DataCache 是提升查询性能的关键。当大量数据需要从远程存储（如 S3/OSS）读取时，网络延迟会显著影响查询性能。".to_string(),
+                    reason: msg!("S009.reason.1"),
                     suggestions: vec![
-                        "增大 DataCache 容量 (datacache_disk_size)".to_string(),
-                        "检查 DataCache 磁盘空间是否充足".to_string(),
-                        "对热点数据执行缓存预热 (CACHE SELECT)".to_string(),
-                        "检查是否有其他查询竞争缓存资源".to_string(),
+                        msg!("S009.suggestion.1"),
+                        msg!("S009.suggestion.2"),
+                        msg!("S009.suggestion.3"),
+                        msg!("S009.suggestion.4"),
                     ],
 
                     parameter_suggestions: [
                         context.suggest_parameter(
                             "enable_scan_datacache",
                             "true",
-                            "SET enable_scan_datacache = true;"
+                            "SET enable_scan_datacache = true;",
                         ),
                         context.suggest_parameter(
                             "enable_populate_datacache",
                             "true",
-                            "SET enable_populate_datacache = true;"
+                            "SET enable_populate_datacache = true;",
                         ),
-                    ].into_iter().flatten().collect(),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect(),
                     threshold_metadata: None,
                 });
             }
@@ -368,30 +359,35 @@ impl DiagnosticRule for S009LowCacheHit {
             if hit_rate < cache_threshold {
                 return Some(Diagnostic {
                     rule_id: self.id().to_string(),
-                    rule_name: self.name().to_string(),
-                    severity: if hit_rate < error_threshold { RuleSeverity::Error } else { RuleSeverity::Warning },
-                    node_path: format!("{} (plan_node_id={})", 
+                    severity: if hit_rate < error_threshold {
+                        RuleSeverity::Error
+                    } else {
+                        RuleSeverity::Warning
+                    },
+                    node_path: format!(
+                        "{} (plan_node_id={})",
                         context.node.operator_name,
-                        context.node.plan_node_id.unwrap_or(-1)),
+                        context.node.plan_node_id.unwrap_or(-1)
+                    ),
                     plan_node_id: context.node.plan_node_id,
-                    message: format!(
-                        "DataCache IO 命中率 {:.1}%，{:.1}% IO 访问远程存储 (本地: {:.0}, 远程: {:.0})",
-                        hit_rate * 100.0,
-                        (1.0 - hit_rate) * 100.0,
-                        io_local,
-                        io_remote
+                    message: msg!(
+                        "S009.message.2",
+                        format!("{:.1}", hit_rate * 100.0),
+                        format!("{:.1}", (1.0 - hit_rate) * 100.0),
+                        format!("{:.0}", io_local),
+                        format!("{:.0}", io_remote)
                     ),
-                    reason: "存算分离架构下，DataCache 是提升查询性能的关键。当大量 IO 请求需要访问远程存储时，网络延迟会显著影响查询性能。".to_string(),
-                    suggestions: vec![
-                        "增大 DataCache 容量 (datacache_disk_size)".to_string(),
-                        "对热点数据执行缓存预热 (CACHE SELECT)".to_string(),
-                    ],
+                    reason: msg!("S009.reason.2"),
+                    suggestions: vec![msg!("S009.suggestion.1"), msg!("S009.suggestion.3")],
 
-                    parameter_suggestions: context.suggest_parameter(
-                        "enable_scan_datacache",
-                        "true",
-                        "SET enable_scan_datacache = true;"
-                    ).into_iter().collect(),
+                    parameter_suggestions: context
+                        .suggest_parameter(
+                            "enable_scan_datacache",
+                            "true",
+                            "SET enable_scan_datacache = true;",
+                        )
+                        .into_iter()
+                        .collect(),
                     threshold_metadata: None,
                 });
             }
@@ -407,24 +403,24 @@ impl DiagnosticRule for S009LowCacheHit {
 
             if hit_rate < 0.3 {
                 return Some(Diagnostic {
-                        rule_id: self.id().to_string(),
-                        rule_name: self.name().to_string(),
-                        severity: RuleSeverity::Info,
-                        node_path: format!("{} (plan_node_id={})", 
-                            context.node.operator_name,
-                            context.node.plan_node_id.unwrap_or(-1)),
-                        plan_node_id: context.node.plan_node_id,
-                        message: format!(
-                            "PageCache 命中率仅 {:.1}% ({:.0}/{:.0} pages)",
-                            hit_rate * 100.0, cached, total
-                        ),
-                        reason: "PageCache 命中率低，大量数据需要从磁盘读取。可能是缓存容量不足或数据访问模式不适合缓存。".to_string(),
-                        suggestions: vec![
-                            "增大 PageCache 容量 (storage_page_cache_limit)".to_string(),
-                            "检查是否有其他查询竞争缓存".to_string(),
-                        ],
+                    rule_id: self.id().to_string(),
+                    severity: RuleSeverity::Info,
+                    node_path: format!(
+                        "{} (plan_node_id={})",
+                        context.node.operator_name,
+                        context.node.plan_node_id.unwrap_or(-1)
+                    ),
+                    plan_node_id: context.node.plan_node_id,
+                    message: msg!(
+                        "S009.message.3",
+                        format!("{:.1}", hit_rate * 100.0),
+                        format!("{:.0}", cached),
+                        format!("{:.0}", total)
+                    ),
+                    reason: msg!("S009.reason.3"),
+                    suggestions: vec![msg!("S009.suggestion.5"), msg!("S009.suggestion.6")],
                     parameter_suggestions: vec![],
-                threshold_metadata: None,
+                    threshold_metadata: None,
                 });
             }
         }
@@ -442,9 +438,6 @@ impl DiagnosticRule for S010RFNotEffective {
     fn id(&self) -> &str {
         "S010"
     }
-    fn name(&self) -> &str {
-        "Scan Runtime Filter 未生效"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("OLAP_SCAN")
@@ -457,7 +450,6 @@ impl DiagnosticRule for S010RFNotEffective {
         if rf_rows == 0.0 && raw_rows > 100_000.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Info,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -465,20 +457,17 @@ impl DiagnosticRule for S010RFNotEffective {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("Runtime Filter 未过滤任何行，扫描了 {:.0} 行", raw_rows),
+                message: msg!("S010.message", format!("{:.0}", raw_rows)),
                 reason: {
                     let table = context.get_full_table_name();
-                    format!(
-                        "表「{}」扫描了 {:.0} 行但 Runtime Filter 未过滤任何数据。可能是 RF 构建失败、超时或选择性差。",
-                        table, raw_rows
-                    )
+                    msg!("S010.reason", table, format!("{:.0}", raw_rows))
                 },
                 suggestions: {
                     let table = context.get_full_table_name();
                     vec![
-                        format!("检查 Join 侧是否生成了针对「{}」的 Runtime Filter", table),
-                        "确认 enable_global_runtime_filter = true".to_string(),
-                        "检查 RF 是否因 Build 端数据量过大而被跳过".to_string(),
+                        msg!("S010.suggestion.1", table),
+                        msg!("S010.suggestion.2"),
+                        msg!("S010.suggestion.3"),
                     ]
                 },
                 parameter_suggestions: {
@@ -505,9 +494,6 @@ impl DiagnosticRule for S011SoftDeletes {
     fn id(&self) -> &str {
         "S011"
     }
-    fn name(&self) -> &str {
-        "累积软删除过多"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("OLAP_SCAN")
@@ -547,7 +533,6 @@ impl DiagnosticRule for S011SoftDeletes {
 
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -555,19 +540,12 @@ impl DiagnosticRule for S011SoftDeletes {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "表 {} 软删除行占比 {:.1}%，建议执行 Compaction",
-                    full_table_name,
-                    ratio * 100.0
-                ),
-                reason: format!(
-                    "表 {} 中存在大量软删除记录 ({:.0} 行)，扫描时需要过滤这些已删除的行，影响查询性能。建议执行 Compaction 清理删除标记。",
-                    full_table_name, del_vec_rows
-                ),
+                message: msg!("S011.message", full_table_name, format!("{:.1}", ratio * 100.0)),
+                reason: msg!("S011.reason", full_table_name, format!("{:.0}", del_vec_rows)),
                 suggestions: vec![
-                    format!("执行 Compaction: {}", compaction_cmd),
-                    "检查 Compaction 状态: SHOW PROC '/compactions';".to_string(),
-                    format!("查看表 Tablet 状态: SHOW TABLET FROM {};", full_table_name),
+                    msg!("S011.suggestion.1", compaction_cmd),
+                    msg!("S011.suggestion.2"),
+                    msg!("S011.suggestion.3", full_table_name),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -588,9 +566,6 @@ impl DiagnosticRule for S002IOSkew {
     fn id(&self) -> &str {
         "S002"
     }
-    fn name(&self) -> &str {
-        "Scan IO 倾斜"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SCAN")
@@ -617,13 +592,20 @@ impl DiagnosticRule for S002IOSkew {
         if ratio > skew_threshold {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", context.node.operator_name, context.node.plan_node_id.unwrap_or(-1)),
+                node_path: format!(
+                    "{} (plan_node_id={})",
+                    context.node.operator_name,
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("Scan IO 耗时存在倾斜，max/avg 比率为 {:.2} (阈值: {:.1})", ratio, skew_threshold),
-                reason: "Scan 算子多个实例在读取数据时，部分实例花费的时间显著大于其它实例。可能是节点 IO 使用率不均或数据在节点上分布不均。".to_string(),
-                suggestions: vec!["检查节点 IO 使用率是否不均".to_string(), "检查存储设备是否存在性能问题".to_string()],
+                message: msg!(
+                    "S002.message",
+                    format!("{:.2}", ratio),
+                    format!("{:.1}", skew_threshold)
+                ),
+                reason: msg!("S002.reason"),
+                suggestions: vec![msg!("S002.suggestion.1"), msg!("S002.suggestion.2")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -641,9 +623,6 @@ impl DiagnosticRule for S004PredicateNotPushed {
     fn id(&self) -> &str {
         "S004"
     }
-    fn name(&self) -> &str {
-        "谓词未下推"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SCAN")
@@ -658,26 +637,33 @@ impl DiagnosticRule for S004PredicateNotPushed {
             let is_internal = context.is_internal_table();
             let suggestions = if is_internal {
                 vec![
-                    "将谓词重写为简单比较（避免函数包裹）".to_string(),
-                    "为过滤列添加 ZoneMap/Bloom 索引".to_string(),
-                    "检查列类型是否匹配（避免隐式转换）".to_string(),
+                    msg!("S004.suggestion.1"),
+                    msg!("S004.suggestion.2"),
+                    msg!("S004.suggestion.3"),
                 ]
             } else {
                 vec![
-                    "将谓词重写为简单比较（避免函数包裹）".to_string(),
-                    "确保过滤列在 Hive 分区列中".to_string(),
-                    "检查 ORC/Parquet 文件是否有统计信息".to_string(),
+                    msg!("S004.suggestion.1"),
+                    msg!("S004.suggestion.4"),
+                    msg!("S004.suggestion.5"),
                 ]
             };
 
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", context.node.operator_name, context.node.plan_node_id.unwrap_or(-1)),
+                node_path: format!(
+                    "{} (plan_node_id={})",
+                    context.node.operator_name,
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("谓词未能下推到存储层，{:.0} 行 ({:.1}%) 在表达式层过滤", pred_filter, pred_filter / raw_rows * 100.0),
-                reason: "查询条件未能下推到存储层执行，导致需要在计算层过滤大量数据。可能是查询条件包含函数、类型不匹配或不支持下推的表达式。".to_string(),
+                message: msg!(
+                    "S004.message",
+                    format!("{:.0}", pred_filter),
+                    format!("{:.1}", pred_filter / raw_rows * 100.0)
+                ),
+                reason: msg!("S004.reason"),
                 suggestions,
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -695,9 +681,6 @@ impl DiagnosticRule for S005IOThreadPoolSaturation {
     fn id(&self) -> &str {
         "S005"
     }
-    fn name(&self) -> &str {
-        "IO 线程池饱和"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SCAN")
@@ -709,13 +692,16 @@ impl DiagnosticRule for S005IOThreadPoolSaturation {
         if wait_time > 1_000_000_000.0 && peak_tasks < 10.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", context.node.operator_name, context.node.plan_node_id.unwrap_or(-1)),
+                node_path: format!(
+                    "{} (plan_node_id={})",
+                    context.node.operator_name,
+                    context.node.plan_node_id.unwrap_or(-1)
+                ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("IO 线程池可能已饱和，等待时间 {:.1}s", wait_time / 1_000_000_000.0),
-                reason: "IO 线程池使用率过高，导致 IO 任务等待时间过长。可能是并发查询过多或存储性能不足。".to_string(),
-                suggestions: vec!["增加 BE 上的 max_io_threads 配置".to_string()],
+                message: msg!("S005.message", format!("{:.1}", wait_time / 1_000_000_000.0)),
+                reason: msg!("S005.reason"),
+                suggestions: vec![msg!("S005.suggestion")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -732,9 +718,6 @@ impl DiagnosticRule for S006RowsetFragmentation {
     fn id(&self) -> &str {
         "S006"
     }
-    fn name(&self) -> &str {
-        "Rowset 碎片化"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("OLAP_SCAN")
@@ -747,7 +730,6 @@ impl DiagnosticRule for S006RowsetFragmentation {
             let table = context.get_full_table_name();
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -755,21 +737,21 @@ impl DiagnosticRule for S006RowsetFragmentation {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "Rowset 数量过多 ({:.0})，初始化耗时 {:.1}ms",
-                    rowsets,
-                    init_time / 1_000_000.0
+                message: msg!(
+                    "S006.message",
+                    format!("{:.0}", rowsets),
+                    format!("{:.1}", init_time / 1_000_000.0)
                 ),
-                reason: format!(
-                    "表「{}」的 Rowset 数量过多（{:.0} 个），导致 Segment 初始化耗时 {:.1}ms。通常是频繁小批量导入或 Compaction 不及时导致。",
+                reason: msg!(
+                    "S006.reason",
                     table,
-                    rowsets,
-                    init_time / 1_000_000.0
+                    format!("{:.0}", rowsets),
+                    format!("{:.1}", init_time / 1_000_000.0)
                 ),
                 suggestions: vec![
-                    format!("触发手动 Compaction: ALTER TABLE {} COMPACT", table),
-                    format!("查看 Compaction 状态: SHOW TABLET FROM {}", table),
-                    "批量合并小型导入任务，减少导入频率".to_string(),
+                    msg!("S006.suggestion.1", table),
+                    msg!("S006.suggestion.2", table),
+                    msg!("S006.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -788,9 +770,6 @@ impl DiagnosticRule for S008ZoneMapNotEffective {
     fn id(&self) -> &str {
         "S008"
     }
-    fn name(&self) -> &str {
-        "ZoneMap 索引未生效"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("OLAP_SCAN")
@@ -803,7 +782,6 @@ impl DiagnosticRule for S008ZoneMapNotEffective {
             let table = context.get_full_table_name();
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Info,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -811,15 +789,12 @@ impl DiagnosticRule for S008ZoneMapNotEffective {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: "ZoneMap 索引未能过滤数据".to_string(),
-                reason: format!(
-                    "表「{}」扫描了 {:.0} 行但 ZoneMap 未过滤任何数据。ZoneMap 基于排序键的 min/max 值过滤，需要 WHERE 条件包含排序键前缀列。",
-                    table, raw_rows
-                ),
+                message: msg!("S008.message"),
+                reason: msg!("S008.reason", table, format!("{:.0}", raw_rows)),
                 suggestions: vec![
-                    format!("查看表「{}」的排序键: SHOW CREATE TABLE {}", table, table),
-                    "确保 WHERE 条件包含排序键的前缀列（如 WHERE dt = '2024-01-01'）".to_string(),
-                    "避免在排序键上使用函数（如 WHERE DATE(dt) = ...）".to_string(),
+                    msg!("S008.suggestion.1", table, table),
+                    msg!("S008.suggestion.2"),
+                    msg!("S008.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -844,9 +819,6 @@ impl DiagnosticRule for S012BitmapIndexNotEffective {
     fn id(&self) -> &str {
         "S012"
     }
-    fn name(&self) -> &str {
-        "Bitmap 索引未生效"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("OLAP_SCAN")
@@ -861,24 +833,22 @@ impl DiagnosticRule for S012BitmapIndexNotEffective {
             if expr_filter > raw_rows * 0.1 {
                 return Some(Diagnostic {
                     rule_id: self.id().to_string(),
-                    rule_name: self.name().to_string(),
                     severity: RuleSeverity::Info,
-                    node_path: format!("{} (plan_node_id={})", 
+                    node_path: format!(
+                        "{} (plan_node_id={})",
                         context.node.operator_name,
-                        context.node.plan_node_id.unwrap_or(-1)),
-                    plan_node_id: context.node.plan_node_id,
-                    message: format!(
-                        "Bitmap 索引未过滤数据，表达式过滤了 {:.0} 行",
-                        expr_filter
+                        context.node.plan_node_id.unwrap_or(-1)
                     ),
-                    reason: "Bitmap 索引适用于基数较低且大量重复的字段（如性别、状态）。如果查询条件包含这类字段但未命中索引，可能是未创建索引或查询条件不支持。".to_string(),
-                suggestions: vec![
-                        "对低基数列（如状态、类型）创建 Bitmap 索引".to_string(),
-                        "确保查询条件使用等值匹配 (=, IN)".to_string(),
-                        "检查 Profile 中 BitmapIndexFilterRows 指标".to_string(),
+                    plan_node_id: context.node.plan_node_id,
+                    message: msg!("S012.message", format!("{:.0}", expr_filter)),
+                    reason: msg!("S012.reason"),
+                    suggestions: vec![
+                        msg!("S012.suggestion.1"),
+                        msg!("S012.suggestion.2"),
+                        msg!("S012.suggestion.3"),
                     ],
                     parameter_suggestions: vec![],
-                threshold_metadata: None,
+                    threshold_metadata: None,
                 });
             }
         }
@@ -900,9 +870,6 @@ impl DiagnosticRule for S013BloomFilterNotEffective {
     fn id(&self) -> &str {
         "S013"
     }
-    fn name(&self) -> &str {
-        "Bloom Filter 索引未生效"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("OLAP_SCAN")
@@ -917,25 +884,23 @@ impl DiagnosticRule for S013BloomFilterNotEffective {
             if expr_filter > raw_rows * 0.5 {
                 return Some(Diagnostic {
                     rule_id: self.id().to_string(),
-                    rule_name: self.name().to_string(),
                     severity: RuleSeverity::Info,
-                    node_path: format!("{} (plan_node_id={})", 
+                    node_path: format!(
+                        "{} (plan_node_id={})",
                         context.node.operator_name,
-                        context.node.plan_node_id.unwrap_or(-1)),
-                    plan_node_id: context.node.plan_node_id,
-                    message: format!(
-                        "Bloom Filter 索引未过滤数据，表达式过滤了 {:.0} 行",
-                        expr_filter
+                        context.node.plan_node_id.unwrap_or(-1)
                     ),
-                    reason: "Bloom Filter 索引适用于高基数列（如 ID 列）的等值查询。仅支持 = 和 IN 条件，且 TINYINT/FLOAT/DOUBLE/DECIMAL 类型不支持。".to_string(),
-                suggestions: vec![
-                        "对高基数列（如 ID 列）创建 Bloom Filter 索引".to_string(),
-                        "确保查询条件使用等值匹配 (=, IN)".to_string(),
-                        "注意: TINYINT/FLOAT/DOUBLE/DECIMAL 类型不支持 Bloom Filter".to_string(),
-                        "检查 Profile 中 BloomFilterFilterRows 指标".to_string(),
+                    plan_node_id: context.node.plan_node_id,
+                    message: msg!("S013.message", format!("{:.0}", expr_filter)),
+                    reason: msg!("S013.reason"),
+                    suggestions: vec![
+                        msg!("S013.suggestion.1"),
+                        msg!("S012.suggestion.2"),
+                        msg!("S013.suggestion.2"),
+                        msg!("S013.suggestion.3"),
                     ],
                     parameter_suggestions: vec![],
-                threshold_metadata: None,
+                    threshold_metadata: None,
                 });
             }
         }
@@ -954,9 +919,6 @@ impl DiagnosticRule for S014ColocateJoinOpportunity {
     fn id(&self) -> &str {
         "S014"
     }
-    fn name(&self) -> &str {
-        "可优化为 Colocate Join"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         let name = node.operator_name.to_uppercase();
@@ -974,22 +936,20 @@ impl DiagnosticRule for S014ColocateJoinOpportunity {
         if bytes_sent > HUNDRED_MB {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Info,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
-                plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "Shuffle Join 网络传输 {}，考虑使用 Colocate Join 优化",
-                    format_bytes(bytes_sent as u64)
+                    context.node.plan_node_id.unwrap_or(-1)
                 ),
-                reason: "Colocate Join 可以避免数据网络传输，显著提升 Join 性能。当两个表的分桶键相同且分桶数相同时，可以使用 Colocate Join。".to_string(),
+                plan_node_id: context.node.plan_node_id,
+                message: msg!("S014.message", format_bytes(bytes_sent as u64)),
+                reason: msg!("S014.reason"),
                 suggestions: vec![
-                    "将频繁 Join 的表设置为同一 Colocation Group".to_string(),
-                    "确保两表的分桶键和分桶数相同".to_string(),
-                    "使用 SHOW COLOCATION GROUP 查看现有分组".to_string(),
-                    "Colocate Join 可避免数据网络传输，显著提升性能".to_string(),
+                    msg!("S014.suggestion.1"),
+                    msg!("S014.suggestion.2"),
+                    msg!("S014.suggestion.3"),
+                    msg!("S014.suggestion.4"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -1009,9 +969,6 @@ impl DiagnosticRule for S016SmallFiles {
     fn id(&self) -> &str {
         "S016"
     }
-    fn name(&self) -> &str {
-        "外表小文件过多"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         use crate::services::profile_analyzer::analyzer::thresholds::ExternalScanType;
@@ -1066,7 +1023,6 @@ impl DiagnosticRule for S016SmallFiles {
 
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -1074,17 +1030,13 @@ impl DiagnosticRule for S016SmallFiles {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "扫描了 {:.0} 个文件，平均大小仅 {}（建议 > {}）",
-                    file_count,
+                message: msg!(
+                    "S016.message",
+                    format!("{:.0}", file_count),
                     format_bytes(avg_file_size as u64),
                     format_bytes(small_file_threshold as u64)
                 ),
-                reason: format!(
-                    "{} 外表 {} 存在大量小文件，导致元数据开销大、IO 效率低。",
-                    scan_type.display_name(),
-                    table_name
-                ),
+                reason: msg!("S016.reason", scan_type.display_name(), table_name),
                 suggestions,
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -1104,9 +1056,6 @@ impl DiagnosticRule for S017FileFragmentation {
     fn id(&self) -> &str {
         "S017"
     }
-    fn name(&self) -> &str {
-        "文件格式碎片化"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SCAN")
@@ -1126,17 +1075,11 @@ impl DiagnosticRule for S017FileFragmentation {
 impl S017FileFragmentation {
     /// Get suggestions based on table type (internal vs external)
     /// For external tables, provide a single consolidated suggestion to avoid repetition
-    fn get_suggestions(is_external: bool, _format: &str, table: &str) -> Vec<String> {
+    fn get_suggestions(is_external: bool, _format: &str, table: &str) -> Vec<Message> {
         if is_external {
-            vec![format!(
-                "外表小文件合并方案: ①Hive简单合并: ALTER TABLE {} PARTITION(...) CONCATENATE; \
-                 ②推荐重写: INSERT OVERWRITE TABLE {} PARTITION(...) SELECT * FROM {}; \
-                 ③大数据量用Spark: df.repartition(N).saveAsTable('{}'); \
-                 ④StarRocks临时优化: SET connector_io_tasks_per_scan_operator=64",
-                table, table, table, table
-            )]
+            vec![msg!("S017.suggestion.1", table, table, table, table)]
         } else {
-            vec![format!("执行 Compaction 合并碎片: ALTER TABLE {} COMPACT", table)]
+            vec![msg!("S017.suggestion.2", table)]
         }
     }
 
@@ -1171,11 +1114,14 @@ impl S017FileFragmentation {
         } else {
             RuleSeverity::Warning
         };
-        let table_type = if is_external { "外表" } else { "内表" };
+        let table_type = if is_external {
+            msg!("S017.table_kind.external")
+        } else {
+            msg!("S017.table_kind.internal")
+        };
 
         Some(Diagnostic {
             rule_id: self.id().to_string(),
-            rule_name: self.name().to_string(),
             severity,
             node_path: format!(
                 "{} (plan_node_id={})",
@@ -1183,16 +1129,13 @@ impl S017FileFragmentation {
                 context.node.plan_node_id.unwrap_or(-1)
             ),
             plan_node_id: context.node.plan_node_id,
-            message: format!(
-                "ORC 文件存在 {:.0} 个 Stripe（平均 {}），TinyStripe 占比 {:.1}%",
-                stripe_count,
+            message: msg!(
+                "S017.message.1",
+                format!("{:.0}", stripe_count),
                 format_bytes(avg_stripe_size as u64),
-                tiny_ratio
-            ),
-            reason: format!(
-                "{}「{}」的 ORC 文件 Stripe 碎片化严重（共 {:.0} 个），导致大量 IO 请求和文件打开开销。",
-                table_type, table, stripe_count
+                format!("{:.1}", tiny_ratio)
             ),
+            reason: msg!("S017.reason.1", table_type, table, format!("{:.0}", stripe_count)),
             suggestions: Self::get_suggestions(is_external, "ORC", table),
             parameter_suggestions: vec![],
             threshold_metadata: None,
@@ -1214,11 +1157,14 @@ impl S017FileFragmentation {
         let is_external = context.is_external_table();
         let severity =
             if total_rowgroups > 100000.0 { RuleSeverity::Error } else { RuleSeverity::Warning };
-        let table_type = if is_external { "外表" } else { "内表" };
+        let table_type = if is_external {
+            msg!("S017.table_kind.external")
+        } else {
+            msg!("S017.table_kind.internal")
+        };
 
         Some(Diagnostic {
             rule_id: self.id().to_string(),
-            rule_name: self.name().to_string(),
             severity,
             node_path: format!(
                 "{} (plan_node_id={})",
@@ -1226,11 +1172,8 @@ impl S017FileFragmentation {
                 context.node.plan_node_id.unwrap_or(-1)
             ),
             plan_node_id: context.node.plan_node_id,
-            message: format!("Parquet 文件存在 {:.0} 个 RowGroup，文件碎片化严重", total_rowgroups),
-            reason: format!(
-                "{}「{}」的 Parquet 文件 RowGroup 过多（共 {:.0} 个），导致元数据开销和 IO 效率低。",
-                table_type, table, total_rowgroups
-            ),
+            message: msg!("S017.message.2", format!("{:.0}", total_rowgroups)),
+            reason: msg!("S017.reason.2", table_type, table, format!("{:.0}", total_rowgroups)),
             suggestions: Self::get_suggestions(is_external, "Parquet", table),
             parameter_suggestions: vec![],
             threshold_metadata: None,
@@ -1246,9 +1189,6 @@ impl DiagnosticRule for S018IOWaitTime {
     fn id(&self) -> &str {
         "S018"
     }
-    fn name(&self) -> &str {
-        "IO 等待时间过长"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         let op = node.operator_name.to_uppercase();
@@ -1277,7 +1217,6 @@ impl DiagnosticRule for S018IOWaitTime {
 
         Some(Diagnostic {
             rule_id: self.id().to_string(),
-            rule_name: self.name().to_string(),
             severity,
             node_path: format!(
                 "{} (plan_node_id={})",
@@ -1285,17 +1224,17 @@ impl DiagnosticRule for S018IOWaitTime {
                 context.node.plan_node_id.unwrap_or(-1)
             ),
             plan_node_id: context.node.plan_node_id,
-            message: format!(
-                "IO 等待时间 {}（占 IO 总时间 {:.1}%），存在 IO 排队瓶颈",
+            message: msg!(
+                "S018.message",
                 format_duration_ms(io_wait),
-                wait_ratio
+                format!("{:.1}", wait_ratio)
             ),
-            reason: "大量并发 IO 请求在队列中等待，可能是文件碎片化或 IO 资源不足导致".to_string(),
+            reason: msg!("S018.reason"),
             suggestions: vec![
-                "合并小文件减少 IO 请求数".to_string(),
-                "增加 io_tasks_per_scan_operator 参数".to_string(),
-                "检查存储系统 IO 性能".to_string(),
-                "考虑启用 Data Cache 缓存热点数据".to_string(),
+                msg!("S018.suggestion.1"),
+                msg!("S018.suggestion.2"),
+                msg!("S018.suggestion.3"),
+                msg!("S018.suggestion.4"),
             ],
             parameter_suggestions: vec![],
             threshold_metadata: None,
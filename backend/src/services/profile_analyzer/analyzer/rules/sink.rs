@@ -9,9 +9,6 @@ impl DiagnosticRule for I001ImportDataSkew {
     fn id(&self) -> &str {
         "I001"
     }
-    fn name(&self) -> &str {
-        "导入数据倾斜"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         let name = node.operator_name.to_uppercase();
@@ -28,7 +25,6 @@ impl DiagnosticRule for I001ImportDataSkew {
         if ratio > 3.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -36,12 +32,9 @@ impl DiagnosticRule for I001ImportDataSkew {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("导入存在数据倾斜，PushChunkNum max/min 比率为 {:.2}", ratio),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
-                suggestions: vec![
-                    "检查上游算子是否存在数据倾斜".to_string(),
-                    "优化分桶键选择".to_string(),
-                ],
+                message: msg!("I001.message", format!("{:.2}", ratio)),
+                reason: msg!("L001.reason"),
+                suggestions: vec![msg!("I001.suggestion.1"), msg!("I001.suggestion.2")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -58,9 +51,6 @@ impl DiagnosticRule for I002ImportRPCLatency {
     fn id(&self) -> &str {
         "I002"
     }
-    fn name(&self) -> &str {
-        "导入 RPC 延迟高"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         let name = node.operator_name.to_uppercase();
@@ -77,7 +67,6 @@ impl DiagnosticRule for I002ImportRPCLatency {
         if ratio > 2.0 && client_time > 1_000_000_000.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -85,15 +74,9 @@ impl DiagnosticRule for I002ImportRPCLatency {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "导入 RPC 客户端耗时是服务端的 {:.1} 倍，网络传输可能是瓶颈",
-                    ratio
-                ),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
-                suggestions: vec![
-                    "启用数据压缩减少网络传输量".to_string(),
-                    "检查网络带宽和延迟".to_string(),
-                ],
+                message: msg!("I002.message", format!("{:.1}", ratio)),
+                reason: msg!("L001.reason"),
+                suggestions: vec![msg!("I002.suggestion"), msg!("E002.suggestion.1")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -110,9 +93,6 @@ impl DiagnosticRule for I003ImportFilteredRows {
     fn id(&self) -> &str {
         "I003"
     }
-    fn name(&self) -> &str {
-        "导入过滤行数过多"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         let name = node.operator_name.to_uppercase();
@@ -131,7 +111,6 @@ impl DiagnosticRule for I003ImportFilteredRows {
         if ratio > 0.1 && filtered > 1000.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -139,16 +118,16 @@ impl DiagnosticRule for I003ImportFilteredRows {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "导入过滤了 {:.0} 行 ({:.1}%)，可能存在数据质量问题",
-                    filtered,
-                    ratio * 100.0
+                message: msg!(
+                    "I003.message",
+                    format!("{:.0}", filtered),
+                    format!("{:.1}", ratio * 100.0)
                 ),
-                reason: "请参考 StarRocks 官方文档了解更多信息。".to_string(),
+                reason: msg!("L001.reason"),
                 suggestions: vec![
-                    "检查数据格式是否符合表结构".to_string(),
-                    "检查是否有空值或类型不匹配".to_string(),
-                    "查看 BE 日志获取详细过滤原因".to_string(),
+                    msg!("I003.suggestion.1"),
+                    msg!("I003.suggestion.2"),
+                    msg!("I003.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -12,9 +12,6 @@ impl DiagnosticRule for T001SortRowsTooLarge {
     fn id(&self) -> &str {
         "T001"
     }
-    fn name(&self) -> &str {
-        "排序行数过多"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SORT")
@@ -26,21 +23,19 @@ impl DiagnosticRule for T001SortRowsTooLarge {
         if input_rows > 10_000_000.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
-                node_path: format!("{} (plan_node_id={})", 
+                node_path: format!(
+                    "{} (plan_node_id={})",
                     context.node.operator_name,
-                    context.node.plan_node_id.unwrap_or(-1)),
-                plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "排序行数过多 ({:.0} 行)，可能导致性能问题",
-                    input_rows
+                    context.node.plan_node_id.unwrap_or(-1)
                 ),
-                reason: "排序数据量过大，消耗大量 CPU 和内存资源。考虑添加过滤条件减少排序数据量或使用 TopN 优化。".to_string(),
+                plan_node_id: context.node.plan_node_id,
+                message: msg!("T001.message", format!("{:.0}", input_rows)),
+                reason: msg!("T001.reason"),
                 suggestions: vec![
-                    "添加 LIMIT 限制结果集大小".to_string(),
-                    "检查是否可以使用 Top-N 优化".to_string(),
-                    "考虑使用物化视图预排序".to_string(),
+                    msg!("G001.suggestion.12"),
+                    msg!("G001.suggestion.13"),
+                    msg!("G001.suggestion.14"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -59,9 +54,6 @@ impl DiagnosticRule for T002SortSpill {
     fn id(&self) -> &str {
         "T002"
     }
-    fn name(&self) -> &str {
-        "排序发生落盘"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SORT")
@@ -75,7 +67,6 @@ impl DiagnosticRule for T002SortSpill {
         if spill_bytes > 0.0 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -83,16 +74,12 @@ impl DiagnosticRule for T002SortSpill {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!(
-                    "Sort 发生磁盘溢写，溢写数据量 {}",
-                    format_bytes(spill_bytes as u64)
-                ),
-                reason: "排序数据量超出内存限制，触发磁盘溢写。Spill 会显著降低排序性能。"
-                    .to_string(),
+                message: msg!("T002.message", format_bytes(spill_bytes as u64)),
+                reason: msg!("T002.reason"),
                 suggestions: vec![
-                    "增加内存限制以避免 Spill".to_string(),
-                    "添加 LIMIT 减少排序数据量".to_string(),
-                    "检查是否可以优化查询减少排序数据".to_string(),
+                    msg!("T002.suggestion.1"),
+                    msg!("T002.suggestion.2"),
+                    msg!("T002.suggestion.3"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
@@ -120,9 +107,6 @@ impl DiagnosticRule for T003SortMemoryHigh {
     fn id(&self) -> &str {
         "T003"
     }
-    fn name(&self) -> &str {
-        "排序内存过高"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SORT")
@@ -138,7 +122,6 @@ impl DiagnosticRule for T003SortMemoryHigh {
         if memory > ONE_GB {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -146,12 +129,12 @@ impl DiagnosticRule for T003SortMemoryHigh {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("排序内存使用 {}", format_bytes(memory as u64)),
-                reason: "排序占用内存过高，可能导致内存压力或影响其他算子。".to_string(),
+                message: msg!("T003.message", format_bytes(memory as u64)),
+                reason: msg!("T003.reason"),
                 suggestions: vec![
-                    "添加 LIMIT 减少排序数据量".to_string(),
-                    "启用 Spill 功能避免 OOM".to_string(),
-                    "考虑分批处理".to_string(),
+                    msg!("T002.suggestion.2"),
+                    msg!("A002.suggestion.3"),
+                    msg!("G002.suggestion.2"),
                 ],
                 parameter_suggestions: {
                     let mut suggestions = Vec::new();
@@ -176,9 +159,6 @@ impl DiagnosticRule for W001WindowMemoryHigh {
     fn id(&self) -> &str {
         "W001"
     }
-    fn name(&self) -> &str {
-        "窗口函数内存过高"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         let name = node.operator_name.to_uppercase();
@@ -192,7 +172,6 @@ impl DiagnosticRule for W001WindowMemoryHigh {
         if memory > THRESHOLD {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Warning,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -200,12 +179,12 @@ impl DiagnosticRule for W001WindowMemoryHigh {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("窗口函数内存使用 {}", format_bytes(memory)),
-                reason: "窗口函数占用内存过高，可能是窗口分区过大或窗口函数状态过大。".to_string(),
+                message: msg!("W001.message", format_bytes(memory)),
+                reason: msg!("W001.reason"),
                 suggestions: vec![
-                    "检查 PARTITION BY 基数是否过高".to_string(),
-                    "考虑减少窗口大小".to_string(),
-                    "检查是否可以使用聚合函数替代".to_string(),
+                    msg!("W001.suggestion.1"),
+                    msg!("W001.suggestion.2"),
+                    msg!("W001.suggestion.3"),
                 ],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
@@ -223,9 +202,6 @@ impl DiagnosticRule for T004SortMergingTimeLong {
     fn id(&self) -> &str {
         "T004"
     }
-    fn name(&self) -> &str {
-        "Sort 合并时间过长"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("SORT")
@@ -241,7 +217,6 @@ impl DiagnosticRule for T004SortMergingTimeLong {
         if ratio > 0.3 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Info,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -249,12 +224,9 @@ impl DiagnosticRule for T004SortMergingTimeLong {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("Sort 合并阶段占比过高 ({:.1}%)", ratio * 100.0),
-                reason: "多路归并排序时间过长，可能是归并路数过多或单路数据量大。".to_string(),
-                suggestions: vec![
-                    "检查并行度设置是否合理".to_string(),
-                    "考虑减少分区数量".to_string(),
-                ],
+                message: msg!("T004.message", format!("{:.1}", ratio * 100.0)),
+                reason: msg!("T004.reason"),
+                suggestions: vec![msg!("T004.suggestion.1"), msg!("T004.suggestion.2")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -271,9 +243,6 @@ impl DiagnosticRule for T005MergeWaitingLong {
     fn id(&self) -> &str {
         "T005"
     }
-    fn name(&self) -> &str {
-        "Merge 等待上游过长"
-    }
 
     fn applicable_to(&self, node: &ExecutionTreeNode) -> bool {
         node.operator_name.to_uppercase().contains("MERGE")
@@ -289,7 +258,6 @@ impl DiagnosticRule for T005MergeWaitingLong {
         if ratio > 0.3 {
             Some(Diagnostic {
                 rule_id: self.id().to_string(),
-                rule_name: self.name().to_string(),
                 severity: RuleSeverity::Info,
                 node_path: format!(
                     "{} (plan_node_id={})",
@@ -297,12 +265,9 @@ impl DiagnosticRule for T005MergeWaitingLong {
                     context.node.plan_node_id.unwrap_or(-1)
                 ),
                 plan_node_id: context.node.plan_node_id,
-                message: format!("Merge 等待上游时间占比 {:.1}%", ratio * 100.0),
-                reason: "Merge 算子等待上游数据时间过长，上游算子可能存在性能瓶颈。".to_string(),
-                suggestions: vec![
-                    "首先优化生产者 operator".to_string(),
-                    "扩大管道缓冲区".to_string(),
-                ],
+                message: msg!("T005.message", format!("{:.1}", ratio * 100.0)),
+                reason: msg!("T005.reason"),
+                suggestions: vec![msg!("T005.suggestion.1"), msg!("T005.suggestion.2")],
                 parameter_suggestions: vec![],
                 threshold_metadata: None,
             })
@@ -0,0 +1,556 @@
+//! Runtime Filter Effectiveness Analysis
+//!
+//! Reconstructs every join runtime filter from the raw operator counters, which
+//! the execution tree drops for CommonMetrics:
+//!
+//! - The join build operator reports how many filters it built
+//!   (`RuntimeFilterNum`), how long that took (`RuntimeFilterBuildTime`) and the
+//!   memory of the bloom/membership part (`Partial*FilterBytes`).
+//! - Every operator that evaluates filters reports `JoinRuntimeFilterInputRows`
+//!   and `JoinRuntimeFilterOutputRows` plus the number of IN/bloom filters it
+//!   received (`RuntimeInFilterNum`, `RuntimeBloomFilterNum`).
+//! - A pipeline with `LocalRfWaitingSet` blocks on `PreconditionBlockTime` until
+//!   its local filters arrive.
+//!
+//! Profiles do not record filter IDs, so each probe operator is attributed to
+//! the nearest enclosing join whose probe side it belongs to.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use super::timeline::InstanceSpread;
+use crate::services::profile_analyzer::models::{ExecutionTree, Pipeline, Profile};
+use crate::services::profile_analyzer::parser::core::ValueParser;
+
+/// Share of evaluated rows a filter must drop to count as effective
+const EFFECTIVE_FILTER_RATIO: f64 = 0.1;
+/// Below this share of rows evaluated against the filter it arrived late
+const LATE_COVERAGE_RATIO: f64 = 0.9;
+
+// ============================================================================
+// Runtime Filter Result Types
+// ============================================================================
+
+/// Runtime filters of a query with their effect on the probe side
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RuntimeFilterReport {
+    pub filters: Vec<RuntimeFilterEntry>,
+    /// Bloom/membership memory of all filters
+    pub total_size_bytes: u64,
+    /// Memory of filters that were not applied or filtered almost nothing
+    pub wasted_size_bytes: u64,
+    pub total_rows_filtered: u64,
+    /// Operators evaluating filters that could not be matched to a join
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub unattributed_probes: Vec<RuntimeFilterProbe>,
+}
+
+/// Filters built by one join node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeFilterEntry {
+    pub build_plan_node_id: i32,
+    pub join_operator: String,
+    pub filter_types: Vec<RuntimeFilterType>,
+    /// Filters built, summed over all instances
+    pub filter_num: u64,
+    pub size_bytes: u64,
+    pub build_time_ms: InstanceSpread,
+    pub probes: Vec<RuntimeFilterProbe>,
+    pub rows_filtered: u64,
+    /// Share of evaluated probe rows dropped by the filters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_ratio: Option<f64>,
+    pub verdict: RuntimeFilterVerdict,
+}
+
+/// An operator that evaluated runtime filters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeFilterProbe {
+    pub plan_node_id: i32,
+    pub operator_name: String,
+    pub input_rows: u64,
+    pub output_rows: u64,
+    pub rows_filtered: u64,
+    pub filter_time_ms: f64,
+    pub in_filter_num: u64,
+    pub bloom_filter_num: u64,
+    /// Share of the operator's rows that were evaluated against the filters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coverage: Option<f64>,
+    /// Time the pipeline was blocked waiting for local filters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_time_ms: Option<f64>,
+    /// Whether the filters were in place before the operator produced data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arrived_before_start: Option<bool>,
+}
+
+/// Kind of runtime filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimeFilterType {
+    In,
+    Bloom,
+    MinMax,
+}
+
+/// Outcome of a join's runtime filters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimeFilterVerdict {
+    /// Dropped a meaningful share of probe rows
+    Effective,
+    /// Evaluated but filtered almost nothing, the memory was wasted
+    Ineffective,
+    /// Part of the probe data was produced before the filters arrived
+    Late,
+    /// Built but never evaluated by any probe operator
+    NotApplied,
+}
+
+// ============================================================================
+// Runtime Filter Analyzer
+// ============================================================================
+
+/// Counters of one operator gathered from the raw fragments
+struct OperatorCounters<'a> {
+    plan_node_id: i32,
+    name: &'a str,
+    common: &'a HashMap<String, String>,
+    unique: &'a HashMap<String, String>,
+    pipeline: &'a Pipeline,
+}
+
+/// Builds the runtime filter report of a profile
+pub struct RuntimeFilterAnalyzer;
+
+impl RuntimeFilterAnalyzer {
+    /// Analyze a parsed profile
+    pub fn analyze(profile: &Profile) -> Option<RuntimeFilterReport> {
+        let counters: Vec<OperatorCounters> = profile
+            .fragments
+            .iter()
+            .flat_map(|f| &f.pipelines)
+            .flat_map(|p| p.operators.iter().map(move |op| (p, op)))
+            .filter_map(|(pipeline, op)| {
+                Some(OperatorCounters {
+                    plan_node_id: op.plan_node_id.as_deref()?.trim().parse().ok()?,
+                    name: &op.name,
+                    common: &op.common_metrics,
+                    unique: &op.unique_metrics,
+                    pipeline,
+                })
+            })
+            .collect();
+
+        let mut builds: BTreeMap<i32, RuntimeFilterEntry> = counters
+            .iter()
+            .filter(|op| op.name.contains("JOIN_BUILD"))
+            .filter_map(Self::build_entry)
+            .map(|entry| (entry.build_plan_node_id, entry))
+            .collect();
+
+        let probes: Vec<RuntimeFilterProbe> = counters.iter().filter_map(Self::probe).collect();
+        if builds.is_empty() && probes.is_empty() {
+            return None;
+        }
+
+        let mut unattributed_probes = Vec::new();
+        for probe in probes {
+            let owner = profile
+                .execution_tree
+                .as_ref()
+                .and_then(|tree| Self::owning_join(tree, probe.plan_node_id, &builds));
+            match owner.and_then(|id| builds.get_mut(&id)) {
+                Some(entry) => entry.probes.push(probe),
+                None => unattributed_probes.push(probe),
+            }
+        }
+
+        let mut report = RuntimeFilterReport { unattributed_probes, ..Default::default() };
+        for mut entry in builds.into_values() {
+            Self::finish_entry(&mut entry);
+            report.total_size_bytes += entry.size_bytes;
+            if matches!(
+                entry.verdict,
+                RuntimeFilterVerdict::Ineffective | RuntimeFilterVerdict::NotApplied
+            ) {
+                report.wasted_size_bytes += entry.size_bytes;
+            }
+            report.total_rows_filtered += entry.rows_filtered;
+            report.filters.push(entry);
+        }
+        Some(report)
+    }
+
+    /// Filters built by a join build operator, if it built any
+    fn build_entry(op: &OperatorCounters) -> Option<RuntimeFilterEntry> {
+        let filter_num = Self::count(op.unique, "RuntimeFilterNum");
+        let size_bytes = Self::bytes(op.unique, "PartialRuntimeBloomFilterBytes")
+            + Self::bytes(op.unique, "PartialRuntimeMembershipFilterBytes");
+        if filter_num == 0 && size_bytes == 0 {
+            return None;
+        }
+
+        // Bloom filters carry min/max bounds as well; without bloom memory only
+        // the bounds (or IN lists reported on the probe side) were built
+        let filter_types = if size_bytes > 0 {
+            vec![RuntimeFilterType::Bloom]
+        } else {
+            vec![RuntimeFilterType::MinMax]
+        };
+
+        Some(RuntimeFilterEntry {
+            build_plan_node_id: op.plan_node_id,
+            join_operator: op.name.to_string(),
+            filter_types,
+            filter_num,
+            size_bytes,
+            build_time_ms: Self::spread(op.unique, "RuntimeFilterBuildTime"),
+            probes: vec![],
+            rows_filtered: 0,
+            filter_ratio: None,
+            verdict: RuntimeFilterVerdict::NotApplied,
+        })
+    }
+
+    /// Filter evaluation counters of an operator, if it received any filter
+    fn probe(op: &OperatorCounters) -> Option<RuntimeFilterProbe> {
+        let input_rows = Self::count(op.common, "JoinRuntimeFilterInputRows");
+        let in_filter_num = Self::count(op.common, "RuntimeInFilterNum");
+        let bloom_filter_num = Self::count(op.common, "RuntimeBloomFilterNum");
+        if input_rows == 0 && in_filter_num == 0 && bloom_filter_num == 0 {
+            return None;
+        }
+
+        let output_rows = Self::count(op.common, "JoinRuntimeFilterOutputRows").min(input_rows);
+        let rows_filtered = input_rows - output_rows;
+
+        // Operator output is counted after filtering, add back what was dropped
+        let produced_rows = Self::count(op.common, "PullRowNum") + rows_filtered;
+        let coverage = (input_rows > 0 && produced_rows > 0)
+            .then(|| (input_rows as f64 / produced_rows as f64).min(1.0));
+
+        let pipeline = &op.pipeline.metrics;
+        let wait_time_ms = (Self::count(pipeline, "LocalRfWaitingSet") > 0)
+            .then(|| Self::spread(pipeline, "PreconditionBlockTime").avg);
+
+        Some(RuntimeFilterProbe {
+            plan_node_id: op.plan_node_id,
+            operator_name: op.name.to_string(),
+            input_rows,
+            output_rows,
+            rows_filtered,
+            filter_time_ms: Self::spread(op.common, "JoinRuntimeFilterTime").avg,
+            in_filter_num,
+            bloom_filter_num,
+            coverage,
+            wait_time_ms,
+            arrived_before_start: coverage.map(|c| c >= LATE_COVERAGE_RATIO),
+        })
+    }
+
+    /// Nearest join with filters whose probe side contains the plan node.
+    ///
+    /// Topology lists join children as [probe, build].
+    fn owning_join(
+        tree: &ExecutionTree,
+        plan_node_id: i32,
+        builds: &BTreeMap<i32, RuntimeFilterEntry>,
+    ) -> Option<i32> {
+        let parents: HashMap<&str, &str> = tree
+            .nodes
+            .iter()
+            .flat_map(|n| n.children.iter().map(move |c| (c.as_str(), n.id.as_str())))
+            .collect();
+        let by_id: HashMap<&str, _> = tree.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+
+        let mut current = tree
+            .nodes
+            .iter()
+            .find(|n| n.plan_node_id == Some(plan_node_id))?
+            .id
+            .as_str();
+        // Bounded by the node count to survive malformed topologies
+        for _ in 0..tree.nodes.len() {
+            let parent = by_id.get(parents.get(current)?)?;
+            if let Some(id) = parent.plan_node_id
+                && builds.contains_key(&id)
+                && parent.children.first().map(String::as_str) == Some(current)
+            {
+                return Some(id);
+            }
+            current = parent.id.as_str();
+        }
+        None
+    }
+
+    fn finish_entry(entry: &mut RuntimeFilterEntry) {
+        for probe in &entry.probes {
+            if probe.in_filter_num > 0 {
+                entry.filter_types.push(RuntimeFilterType::In);
+            }
+            if probe.bloom_filter_num > 0 {
+                entry.filter_types.push(RuntimeFilterType::Bloom);
+            }
+        }
+        entry.filter_types.sort();
+        entry.filter_types.dedup();
+
+        let input_rows: u64 = entry.probes.iter().map(|p| p.input_rows).sum();
+        entry.rows_filtered = entry.probes.iter().map(|p| p.rows_filtered).sum();
+        entry.filter_ratio =
+            (input_rows > 0).then(|| entry.rows_filtered as f64 / input_rows as f64);
+
+        entry.verdict = match entry.filter_ratio {
+            None => RuntimeFilterVerdict::NotApplied,
+            Some(_)
+                if entry
+                    .probes
+                    .iter()
+                    .any(|p| p.arrived_before_start == Some(false)) =>
+            {
+                RuntimeFilterVerdict::Late
+            },
+            Some(ratio) if ratio >= EFFECTIVE_FILTER_RATIO => RuntimeFilterVerdict::Effective,
+            Some(_) => RuntimeFilterVerdict::Ineffective,
+        };
+    }
+
+    fn spread(metrics: &HashMap<String, String>, key: &str) -> InstanceSpread {
+        let time = |k: &str| {
+            metrics
+                .get(k)
+                .and_then(|v| ValueParser::parse_time_to_ms(v).ok())
+        };
+        InstanceSpread {
+            avg: time(key).unwrap_or(0.0),
+            min: time(&format!("__MIN_OF_{}", key)),
+            max: time(&format!("__MAX_OF_{}", key)),
+        }
+    }
+
+    fn count(metrics: &HashMap<String, String>, key: &str) -> u64 {
+        metrics
+            .get(key)
+            .and_then(|v| ValueParser::parse_number::<u64>(v).ok())
+            .unwrap_or(0)
+    }
+
+    fn bytes(metrics: &HashMap<String, String>, key: &str) -> u64 {
+        metrics
+            .get(key)
+            .and_then(|v| ValueParser::parse_bytes(v).ok())
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::profile_analyzer::models::{
+        ExecutionInfo, ExecutionTreeNode, Fragment, HotSeverity, NodeType, Operator,
+        OperatorMetrics, PlannerInfo, ProfileSummary,
+    };
+    use crate::services::profile_analyzer::parser::ProfileComposer;
+
+    fn metrics(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn operator(
+        name: &str,
+        plan_id: i32,
+        common: &[(&str, &str)],
+        unique: &[(&str, &str)],
+    ) -> Operator {
+        Operator {
+            name: name.to_string(),
+            plan_node_id: Some(plan_id.to_string()),
+            operator_id: None,
+            common_metrics: metrics(common),
+            unique_metrics: metrics(unique),
+            children: vec![],
+        }
+    }
+
+    fn node(plan_id: i32, name: &str, children: &[i32]) -> ExecutionTreeNode {
+        ExecutionTreeNode {
+            id: format!("node_{}", plan_id),
+            operator_name: name.to_string(),
+            node_type: if name.contains("JOIN") { NodeType::HashJoin } else { NodeType::Unknown },
+            plan_node_id: Some(plan_id),
+            parent_plan_node_id: None,
+            metrics: OperatorMetrics::default(),
+            children: children.iter().map(|c| format!("node_{}", c)).collect(),
+            depth: 0,
+            is_hotspot: false,
+            hotspot_severity: HotSeverity::Normal,
+            fragment_id: None,
+            pipeline_id: None,
+            time_percentage: None,
+            rows: None,
+            is_most_consuming: false,
+            is_second_most_consuming: false,
+            unique_metrics: HashMap::new(),
+            has_diagnostic: false,
+            diagnostic_ids: vec![],
+        }
+    }
+
+    /// Join 1 probes scan 2 and builds from scan 3
+    fn profile(scan_common: &[(&str, &str)], scan_pipeline: &[(&str, &str)]) -> Profile {
+        let build = operator(
+            "HASH_JOIN_BUILD",
+            1,
+            &[],
+            &[
+                ("RuntimeFilterNum", "2"),
+                ("PartialRuntimeBloomFilterBytes", "1.000 MB"),
+                ("RuntimeFilterBuildTime", "3ms"),
+            ],
+        );
+        let scan = operator("OLAP_SCAN", 2, scan_common, &[]);
+        let fragment = Fragment {
+            id: "0".to_string(),
+            backend_addresses: vec![],
+            instance_ids: vec![],
+            metrics: HashMap::new(),
+            pipelines: vec![
+                Pipeline { id: "0".to_string(), metrics: metrics(&[]), operators: vec![build] },
+                Pipeline {
+                    id: "1".to_string(),
+                    metrics: metrics(scan_pipeline),
+                    operators: vec![scan],
+                },
+            ],
+        };
+
+        let nodes = vec![
+            node(1, "HASH_JOIN", &[2, 3]),
+            node(2, "OLAP_SCAN", &[]),
+            node(3, "OLAP_SCAN", &[]),
+        ];
+        Profile {
+            summary: ProfileSummary::default(),
+            planner: PlannerInfo::default(),
+            execution: ExecutionInfo::default(),
+            fragments: vec![fragment],
+            execution_tree: Some(ExecutionTree { root: nodes[0].clone(), nodes }),
+        }
+    }
+
+    #[test]
+    fn test_effective_filter() {
+        let profile = profile(
+            &[
+                ("JoinRuntimeFilterInputRows", "1000000"),
+                ("JoinRuntimeFilterOutputRows", "50000"),
+                ("JoinRuntimeFilterTime", "12ms"),
+                ("RuntimeBloomFilterNum", "1"),
+                ("RuntimeInFilterNum", "1"),
+                ("PullRowNum", "50000"),
+            ],
+            &[("LocalRfWaitingSet", "1"), ("PreconditionBlockTime", "20ms")],
+        );
+        let report = RuntimeFilterAnalyzer::analyze(&profile).unwrap();
+
+        assert_eq!(report.filters.len(), 1);
+        let entry = &report.filters[0];
+        assert_eq!(entry.build_plan_node_id, 1);
+        assert_eq!(entry.filter_num, 2);
+        assert_eq!(entry.size_bytes, 1024 * 1024);
+        assert_eq!(entry.build_time_ms.avg, 3.0);
+        assert_eq!(entry.filter_types, vec![RuntimeFilterType::In, RuntimeFilterType::Bloom]);
+        assert_eq!(entry.rows_filtered, 950_000);
+        assert_eq!(entry.verdict, RuntimeFilterVerdict::Effective);
+
+        let probe = &entry.probes[0];
+        assert_eq!(probe.plan_node_id, 2);
+        assert_eq!(probe.wait_time_ms, Some(20.0));
+        assert_eq!(probe.arrived_before_start, Some(true));
+        assert_eq!(report.wasted_size_bytes, 0);
+        assert!(report.unattributed_probes.is_empty());
+    }
+
+    #[test]
+    fn test_late_filter() {
+        // Only 100K of the 1.05M produced rows were evaluated against the filter
+        let profile = profile(
+            &[
+                ("JoinRuntimeFilterInputRows", "100000"),
+                ("JoinRuntimeFilterOutputRows", "10000"),
+                ("PullRowNum", "960000"),
+            ],
+            &[],
+        );
+        let report = RuntimeFilterAnalyzer::analyze(&profile).unwrap();
+
+        let entry = &report.filters[0];
+        assert_eq!(entry.verdict, RuntimeFilterVerdict::Late);
+        assert_eq!(entry.probes[0].arrived_before_start, Some(false));
+        assert_eq!(entry.probes[0].wait_time_ms, None);
+    }
+
+    #[test]
+    fn test_ineffective_and_unapplied_filters_waste_memory() {
+        let ineffective = profile(
+            &[
+                ("JoinRuntimeFilterInputRows", "1000000"),
+                ("JoinRuntimeFilterOutputRows", "990000"),
+                ("PullRowNum", "990000"),
+            ],
+            &[],
+        );
+        let report = RuntimeFilterAnalyzer::analyze(&ineffective).unwrap();
+        assert_eq!(report.filters[0].verdict, RuntimeFilterVerdict::Ineffective);
+        assert_eq!(report.wasted_size_bytes, 1024 * 1024);
+
+        let unapplied = profile(&[("PullRowNum", "990000")], &[]);
+        let report = RuntimeFilterAnalyzer::analyze(&unapplied).unwrap();
+        assert_eq!(report.filters[0].verdict, RuntimeFilterVerdict::NotApplied);
+        assert!(report.filters[0].probes.is_empty());
+        assert_eq!(report.wasted_size_bytes, 1024 * 1024);
+    }
+
+    #[test]
+    fn test_build_side_probe_is_unattributed() {
+        let mut profile = profile(&[], &[]);
+        let scan = operator(
+            "OLAP_SCAN",
+            3,
+            &[("JoinRuntimeFilterInputRows", "10"), ("JoinRuntimeFilterOutputRows", "5")],
+            &[],
+        );
+        profile.fragments[0].pipelines[1].operators.push(scan);
+
+        let report = RuntimeFilterAnalyzer::analyze(&profile).unwrap();
+        assert!(report.filters[0].probes.is_empty());
+        assert_eq!(report.unattributed_probes.len(), 1);
+        assert_eq!(report.unattributed_probes[0].plan_node_id, 3);
+    }
+
+    #[test]
+    fn test_fixture_profile_runtime_filters() {
+        let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/profiles/profile14.txt");
+        let text = std::fs::read_to_string(path).unwrap();
+        let profile = ProfileComposer::new().parse(&text).unwrap();
+
+        let report = RuntimeFilterAnalyzer::analyze(&profile).unwrap();
+        let entry = report
+            .filters
+            .iter()
+            .find(|f| f.build_plan_node_id == 35)
+            .expect("join 35 builds runtime filters");
+        assert_eq!(entry.filter_num, 31);
+        assert_eq!(entry.size_bytes, 576);
+        assert!(entry.filter_types.contains(&RuntimeFilterType::Bloom));
+        assert_eq!(entry.probes[0].operator_name, "EXCHANGE_SOURCE");
+        assert_eq!(entry.verdict, RuntimeFilterVerdict::Effective);
+        assert!(report.total_rows_filtered >= 350);
+    }
+}
@@ -10,6 +10,7 @@
 //! Reference: profile-diagnostic-system-review.md Section 4
 
 use super::baseline::{PerformanceBaseline, QueryComplexity};
+use crate::services::profile_analyzer::i18n::{Arg, Message, msg};
 use crate::services::profile_analyzer::models::ClusterInfo;
 
 // ============================================================================
@@ -561,8 +562,8 @@ impl ExternalScanType {
     }
 
     /// Get display name for this scan type
-    pub fn display_name(&self) -> &'static str {
-        match self {
+    pub fn display_name(&self) -> Arg {
+        let name = match self {
             Self::Hive => "Hive",
             Self::Iceberg => "Iceberg",
             Self::Hudi => "Hudi",
@@ -570,61 +571,62 @@ impl ExternalScanType {
             Self::Paimon => "Paimon",
             Self::Hdfs => "HDFS",
             Self::File => "File",
-            Self::S3 => "S3/对象存储",
+            Self::S3 => return msg!("S016.scan_type.s3").into(),
             Self::Jdbc => "JDBC",
             Self::Mysql => "MySQL",
             Self::Elasticsearch => "Elasticsearch",
             Self::Connector => "Connector",
-        }
+        };
+        name.into()
     }
 }
 
 /// Generate type-specific suggestions for small file issues
-pub fn generate_small_file_suggestions(scan_type: &ExternalScanType, table: &str) -> Vec<String> {
+pub fn generate_small_file_suggestions(scan_type: &ExternalScanType, table: &str) -> Vec<Message> {
     match scan_type {
         ExternalScanType::Hive => vec![
-            format!("合并小文件: INSERT OVERWRITE {} SELECT * FROM {}", table, table),
-            "调整 Hive 表的 mapreduce.input.fileinputformat.split.minsize".to_string(),
-            "使用 ALTER TABLE CONCATENATE 合并小文件".to_string(),
+            msg!("S016.suggestion.hive.1", table),
+            msg!("S016.suggestion.hive.2"),
+            msg!("S016.suggestion.hive.3"),
         ],
         ExternalScanType::Iceberg => vec![
-            format!("执行 Compaction: CALL rewrite_data_files(table => '{}')", table),
-            "调整 write.target-file-size-bytes 参数（建议 128MB-256MB）".to_string(),
-            "启用 Iceberg 自动 Compaction".to_string(),
+            msg!("S016.suggestion.iceberg.1", table),
+            msg!("S016.suggestion.iceberg.2"),
+            msg!("S016.suggestion.iceberg.3"),
         ],
         ExternalScanType::Hudi => vec![
-            "执行 Hudi Compaction 合并小文件".to_string(),
-            "调整 hoodie.parquet.small.file.limit 参数".to_string(),
-            "检查 Hudi 表的 Compaction 策略配置".to_string(),
+            msg!("S016.suggestion.hudi.1"),
+            msg!("S016.suggestion.hudi.2"),
+            msg!("S016.suggestion.hudi.3"),
         ],
         ExternalScanType::DeltaLake => vec![
-            format!("执行 OPTIMIZE {} ZORDER BY ...", table),
-            "启用 Delta Lake Auto Compaction".to_string(),
-            "调整 spark.databricks.delta.autoCompact.minNumFiles".to_string(),
+            msg!("S016.suggestion.delta.1", table),
+            msg!("S016.suggestion.delta.2"),
+            msg!("S016.suggestion.delta.3"),
         ],
         ExternalScanType::Paimon => vec![
-            "执行 Paimon Compaction 合并小文件".to_string(),
-            "调整 write.target-file-size 参数".to_string(),
-            "检查 Paimon 表的 Compaction 配置".to_string(),
+            msg!("S016.suggestion.paimon.1"),
+            msg!("S016.suggestion.paimon.2"),
+            msg!("S016.suggestion.paimon.3"),
         ],
         ExternalScanType::Hdfs => vec![
-            "使用 Hadoop Archive (HAR) 合并小文件".to_string(),
-            "调整上游 ETL 输出文件大小（建议 128MB-256MB）".to_string(),
-            "使用 Spark coalesce/repartition 合并输出文件".to_string(),
+            msg!("S016.suggestion.hdfs.1"),
+            msg!("S016.suggestion.hdfs.2"),
+            msg!("S016.suggestion.hdfs.3"),
         ],
         ExternalScanType::S3 => vec![
-            "使用 Spark/Flink 合并小文件".to_string(),
-            "调整写入任务的并行度减少小文件产生".to_string(),
-            "考虑使用更大的 Parquet row group size".to_string(),
-            "对于 Iceberg/Hudi 表，执行 OPTIMIZE 或 Compaction".to_string(),
+            msg!("S016.suggestion.s3.1"),
+            msg!("S016.suggestion.s3.2"),
+            msg!("S016.suggestion.s3.3"),
+            msg!("S016.suggestion.s3.4"),
         ],
         ExternalScanType::File => {
-            vec!["合并本地小文件".to_string(), "调整写入程序的输出文件大小".to_string()]
+            vec![msg!("S016.suggestion.file.1"), msg!("S016.suggestion.file.2")]
         },
         ExternalScanType::Connector => vec![
-            "合并小文件以提升查询性能".to_string(),
-            "考虑将热点数据导入 StarRocks 内表".to_string(),
-            "调整上游数据写入的文件大小配置".to_string(),
+            msg!("S016.suggestion.connector.1"),
+            msg!("S016.suggestion.connector.2"),
+            msg!("S016.suggestion.connector.3"),
         ],
 
         ExternalScanType::Jdbc | ExternalScanType::Mysql | ExternalScanType::Elasticsearch => {
@@ -802,6 +804,7 @@ mod dynamic_thresholds_tests {
 #[cfg(test)]
 mod external_scan_type_tests {
     use super::*;
+    use crate::services::profile_analyzer::i18n::Locale;
 
     #[test]
     fn test_external_scan_type_detection() {
@@ -875,14 +878,18 @@ mod external_scan_type_tests {
         let hive_suggestions =
             generate_small_file_suggestions(&ExternalScanType::Hive, "test_table");
         assert!(!hive_suggestions.is_empty());
-        assert!(hive_suggestions[0].contains("test_table"));
+        assert!(
+            hive_suggestions[0]
+                .render(Locale::Zh)
+                .contains("test_table")
+        );
 
         let iceberg_suggestions =
             generate_small_file_suggestions(&ExternalScanType::Iceberg, "iceberg_table");
         assert!(
             iceberg_suggestions
                 .iter()
-                .any(|s| s.contains("rewrite_data_files"))
+                .any(|s| s.render(Locale::En).contains("rewrite_data_files"))
         );
 
         let jdbc_suggestions =
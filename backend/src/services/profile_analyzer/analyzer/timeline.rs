@@ -0,0 +1,483 @@
+//! Execution Timeline Analysis
+//!
+//! Builds Gantt-style timeline data for fragments and pipeline drivers from the
+//! counters that StarRocks records per pipeline. Merged profiles only keep the
+//! average and the `__MAX_OF_`/`__MIN_OF_` instance extremes, so every span is
+//! reported as an [`InstanceSpread`] rather than a per-instance list:
+//!
+//! - A driver lives for `DriverTotalTime`, which splits into running
+//!   (`ActiveTime`), blocked (`PendingTime`: input empty, output full,
+//!   precondition) and queued in the ready queue waiting for an execution
+//!   thread (`ScheduleTime`).
+//! - `FirstInputEmptyTime` is the wait before the first chunk arrived, i.e. the
+//!   offset at which a pipeline actually starts working.
+//! - Backends are attributed from `BackendAddresses`; a backend whose fragments
+//!   finish far earlier than the busiest backend is reported as idle.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::services::profile_analyzer::models::{Fragment, Pipeline, Profile};
+use crate::services::profile_analyzer::parser::core::ValueParser;
+
+/// ActiveTime max/avg ratio above which the slowest instance is a straggler
+const STRAGGLER_RATIO: f64 = 2.0;
+/// Minimum ActiveTime of the slowest instance for straggler detection
+const STRAGGLER_MIN_ACTIVE_MS: f64 = 100.0;
+/// Backends busy for less than this share of the busiest backend are idle
+const IDLE_BACKEND_RATIO: f64 = 0.5;
+/// Profile durations such as `9m41s` are rounded, allow this much slack when
+/// checking that the driver time components add up
+const ROUNDING_SLACK_MS: f64 = 1000.0;
+
+// ============================================================================
+// Timeline Result Types
+// ============================================================================
+
+/// Fragment and pipeline timeline of a query, grouped per BE
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ExecutionTimeline {
+    /// QueryExecutionWallTime used as the time axis, if available
+    pub wall_time_ms: Option<f64>,
+    pub fragments: Vec<FragmentTimeline>,
+    pub backends: Vec<BackendTimeline>,
+    /// Backends that finished their work far earlier than the busiest one
+    pub idle_backends: Vec<String>,
+    /// Longest average ready-queue time of any pipeline driver
+    pub max_queue_time_ms: f64,
+}
+
+/// Average and instance extremes of a merged counter
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq)]
+pub struct InstanceSpread {
+    pub avg: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+}
+
+impl InstanceSpread {
+    /// Value of the slowest instance, falling back to the average
+    pub fn max_or_avg(&self) -> f64 {
+        self.max.unwrap_or(self.avg)
+    }
+
+    /// Slowest instance compared to the average (1.0 without extremes)
+    pub fn skew_ratio(&self) -> Option<f64> {
+        if self.avg > 0.0 { Some(self.max_or_avg() / self.avg) } else { None }
+    }
+}
+
+/// Timeline of a single fragment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FragmentTimeline {
+    pub fragment_id: String,
+    pub backends: Vec<String>,
+    pub instance_num: usize,
+    /// Earliest pipeline start offset (first input received)
+    pub start_ms: f64,
+    /// Latest pipeline finish offset (slowest driver)
+    pub end_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_driver_count: Option<u64>,
+    pub pipelines: Vec<PipelineTimeline>,
+}
+
+/// Timeline of the drivers of one pipeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineTimeline {
+    pub pipeline_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub degree_of_parallelism: Option<u64>,
+    /// Offset at which the drivers received their first input
+    pub start_ms: f64,
+    /// Offset at which the slowest driver finished
+    pub end_ms: f64,
+    /// Driver lifetime, from creation to finish
+    pub driver_total_time_ms: InstanceSpread,
+    /// Time spent running on an execution thread
+    pub active_time_ms: InstanceSpread,
+    /// Time spent blocked on input, output or preconditions
+    pub pending_time_ms: InstanceSpread,
+    /// Wait before the first chunk arrived
+    pub first_input_wait_ms: InstanceSpread,
+    /// Time spent ready but waiting in the driver queue for a thread
+    pub queue_time_ms: InstanceSpread,
+    /// queue_time_ms.avg as a percentage of driver_total_time_ms.avg
+    pub queue_percentage: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peak_driver_queue_size: Option<u64>,
+    pub block_by_input_empty: u64,
+    pub block_by_output_full: u64,
+    pub block_by_precondition: u64,
+    /// ActiveTime of the slowest instance divided by the average
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub straggler_ratio: Option<f64>,
+    pub is_straggler: bool,
+}
+
+/// Work assigned to a single BE
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackendTimeline {
+    pub address: String,
+    pub fragment_ids: Vec<String>,
+    /// Instances assuming an even spread of each fragment over its backends
+    pub estimated_instances: f64,
+    /// Latest finish offset of the fragments running on this backend
+    pub busy_until_ms: f64,
+    pub is_idle: bool,
+}
+
+// ============================================================================
+// Timeline Analyzer
+// ============================================================================
+
+/// Builds the execution timeline from parsed fragments
+pub struct TimelineAnalyzer;
+
+impl TimelineAnalyzer {
+    /// Analyze a parsed profile
+    pub fn analyze(profile: &Profile) -> Option<ExecutionTimeline> {
+        let wall_time_ms = profile
+            .summary
+            .query_execution_wall_time_ms
+            .or(profile.summary.total_time_ms)
+            .filter(|ms| *ms > 0.0);
+        Self::analyze_fragments(&profile.fragments, wall_time_ms)
+    }
+
+    /// Analyze fragments against an optional wall-clock reference
+    pub fn analyze_fragments(
+        fragments: &[Fragment],
+        wall_time_ms: Option<f64>,
+    ) -> Option<ExecutionTimeline> {
+        let fragments: Vec<FragmentTimeline> = fragments
+            .iter()
+            .map(Self::fragment_timeline)
+            .filter(|f| !f.pipelines.is_empty())
+            .collect();
+        if fragments.is_empty() {
+            return None;
+        }
+
+        let backends = Self::backend_timelines(&fragments);
+        let idle_backends = backends
+            .iter()
+            .filter(|b| b.is_idle)
+            .map(|b| b.address.clone())
+            .collect();
+        let max_queue_time_ms = fragments
+            .iter()
+            .flat_map(|f| &f.pipelines)
+            .map(|p| p.queue_time_ms.avg)
+            .fold(0.0, f64::max);
+
+        Some(ExecutionTimeline {
+            wall_time_ms,
+            fragments,
+            backends,
+            idle_backends,
+            max_queue_time_ms,
+        })
+    }
+
+    fn fragment_timeline(fragment: &Fragment) -> FragmentTimeline {
+        let pipelines: Vec<PipelineTimeline> = fragment
+            .pipelines
+            .iter()
+            .filter(|p| p.metrics.contains_key("DriverTotalTime"))
+            .map(Self::pipeline_timeline)
+            .collect();
+
+        let start_ms = pipelines
+            .iter()
+            .map(|p| p.start_ms)
+            .reduce(f64::min)
+            .unwrap_or(0.0);
+        let end_ms = pipelines.iter().map(|p| p.end_ms).fold(0.0, f64::max);
+        let backends: Vec<String> = fragment
+            .backend_addresses
+            .iter()
+            .filter(|a| !a.is_empty())
+            .cloned()
+            .collect();
+        let instance_num = Self::count(&fragment.metrics, "InstanceNum")
+            .map(|n| n as usize)
+            .unwrap_or_else(|| {
+                fragment
+                    .instance_ids
+                    .iter()
+                    .filter(|i| !i.is_empty())
+                    .count()
+            });
+
+        FragmentTimeline {
+            fragment_id: fragment.id.clone(),
+            backends,
+            instance_num,
+            start_ms,
+            end_ms,
+            initial_driver_count: Self::count(&fragment.metrics, "InitialProcessDriverCount"),
+            pipelines,
+        }
+    }
+
+    fn pipeline_timeline(pipeline: &Pipeline) -> PipelineTimeline {
+        let metrics = &pipeline.metrics;
+        let driver_total = Self::spread(metrics, "DriverTotalTime");
+        let active = Self::spread(metrics, "ActiveTime");
+        let first_input_wait = Self::spread(metrics, "FirstInputEmptyTime");
+
+        // Older versions only report the PendingTime breakdown
+        let mut pending = Self::spread(metrics, "PendingTime");
+        if pending.avg == 0.0 {
+            pending.avg = ["InputEmptyTime", "OutputFullTime", "PreconditionBlockTime"]
+                .iter()
+                .map(|key| Self::spread(metrics, key).avg)
+                .sum();
+        }
+
+        let schedule = Self::spread(metrics, "ScheduleTime");
+        let queue = Self::queue_time(driver_total, active, pending, schedule);
+        let queue_percentage = if driver_total.avg > 0.0 {
+            (queue.avg / driver_total.avg * 100.0).min(100.0)
+        } else {
+            0.0
+        };
+
+        let straggler_ratio = active.skew_ratio();
+        let is_straggler = straggler_ratio.is_some_and(|r| r > STRAGGLER_RATIO)
+            && active.max_or_avg() >= STRAGGLER_MIN_ACTIVE_MS;
+
+        PipelineTimeline {
+            pipeline_id: pipeline.id.clone(),
+            degree_of_parallelism: Self::count(metrics, "DegreeOfParallelism"),
+            start_ms: first_input_wait.avg,
+            end_ms: driver_total.max_or_avg(),
+            driver_total_time_ms: driver_total,
+            active_time_ms: active,
+            pending_time_ms: pending,
+            first_input_wait_ms: first_input_wait,
+            queue_time_ms: queue,
+            queue_percentage,
+            schedule_count: Self::count(metrics, "ScheduleCount"),
+            peak_driver_queue_size: Self::count(metrics, "PeakDriverQueueSize"),
+            block_by_input_empty: Self::count(metrics, "BlockByInputEmpty").unwrap_or(0),
+            block_by_output_full: Self::count(metrics, "BlockByOutputFull").unwrap_or(0),
+            block_by_precondition: Self::count(metrics, "BlockByPrecondition").unwrap_or(0),
+            straggler_ratio,
+            is_straggler,
+        }
+    }
+
+    /// Ready-queue time of the drivers.
+    ///
+    /// `ScheduleTime` is the ready-queue time on most versions, but some versions
+    /// report the whole driver lifetime there. When active + pending + schedule
+    /// clearly exceeds the driver time, fall back to the unaccounted remainder.
+    fn queue_time(
+        driver_total: InstanceSpread,
+        active: InstanceSpread,
+        pending: InstanceSpread,
+        schedule: InstanceSpread,
+    ) -> InstanceSpread {
+        let accounted = active.avg + pending.avg + schedule.avg;
+        if accounted <= driver_total.avg * 1.01 + ROUNDING_SLACK_MS {
+            return schedule;
+        }
+        InstanceSpread {
+            avg: (driver_total.avg - active.avg - pending.avg).max(0.0),
+            min: None,
+            max: None,
+        }
+    }
+
+    fn backend_timelines(fragments: &[FragmentTimeline]) -> Vec<BackendTimeline> {
+        let mut by_address: BTreeMap<&str, BackendTimeline> = BTreeMap::new();
+        for fragment in fragments {
+            if fragment.backends.is_empty() {
+                continue;
+            }
+            let per_backend = fragment.instance_num as f64 / fragment.backends.len() as f64;
+            for address in &fragment.backends {
+                let backend = by_address
+                    .entry(address)
+                    .or_insert_with(|| BackendTimeline {
+                        address: address.clone(),
+                        fragment_ids: vec![],
+                        estimated_instances: 0.0,
+                        busy_until_ms: 0.0,
+                        is_idle: false,
+                    });
+                backend.fragment_ids.push(fragment.fragment_id.clone());
+                backend.estimated_instances += per_backend;
+                backend.busy_until_ms = backend.busy_until_ms.max(fragment.end_ms);
+            }
+        }
+
+        let mut backends: Vec<BackendTimeline> = by_address.into_values().collect();
+        let busiest = backends.iter().map(|b| b.busy_until_ms).fold(0.0, f64::max);
+        if backends.len() > 1 && busiest > 0.0 {
+            for backend in &mut backends {
+                backend.is_idle = backend.busy_until_ms < busiest * IDLE_BACKEND_RATIO;
+            }
+        }
+        backends
+    }
+
+    fn spread(metrics: &HashMap<String, String>, key: &str) -> InstanceSpread {
+        let time = |k: &str| {
+            metrics
+                .get(k)
+                .and_then(|v| ValueParser::parse_time_to_ms(v).ok())
+        };
+        InstanceSpread {
+            avg: time(key).unwrap_or(0.0),
+            min: time(&format!("__MIN_OF_{}", key)),
+            max: time(&format!("__MAX_OF_{}", key)),
+        }
+    }
+
+    fn count(metrics: &HashMap<String, String>, key: &str) -> Option<u64> {
+        metrics
+            .get(key)
+            .and_then(|v| ValueParser::parse_number::<u64>(v).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pipeline(id: &str, metrics: &[(&str, &str)]) -> Pipeline {
+        Pipeline {
+            id: id.to_string(),
+            metrics: metrics
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            operators: vec![],
+        }
+    }
+
+    fn fragment(id: &str, backends: &[&str], pipelines: Vec<Pipeline>) -> Fragment {
+        Fragment {
+            id: id.to_string(),
+            backend_addresses: backends.iter().map(|b| b.to_string()).collect(),
+            instance_ids: backends.iter().map(|b| format!("instance-{}", b)).collect(),
+            metrics: HashMap::new(),
+            pipelines,
+        }
+    }
+
+    #[test]
+    fn test_schedule_time_is_queue_time() {
+        let fragments = vec![fragment(
+            "1",
+            &["be1:9060"],
+            vec![pipeline(
+                "0",
+                &[
+                    ("DriverTotalTime", "10s"),
+                    ("ActiveTime", "2s"),
+                    ("PendingTime", "3s"),
+                    ("ScheduleTime", "5s"),
+                    ("__MAX_OF_ScheduleTime", "6s"),
+                    ("FirstInputEmptyTime", "1s"),
+                ],
+            )],
+        )];
+        let timeline = TimelineAnalyzer::analyze_fragments(&fragments, Some(10_000.0)).unwrap();
+        let p = &timeline.fragments[0].pipelines[0];
+        assert_eq!(p.queue_time_ms.avg, 5_000.0);
+        assert_eq!(p.queue_time_ms.max, Some(6_000.0));
+        assert_eq!(p.queue_percentage, 50.0);
+        assert_eq!(p.start_ms, 1_000.0);
+        assert_eq!(timeline.max_queue_time_ms, 5_000.0);
+    }
+
+    #[test]
+    fn test_lifetime_schedule_time_falls_back_to_remainder() {
+        let fragments = vec![fragment(
+            "1",
+            &["be1:9060"],
+            vec![pipeline(
+                "0",
+                &[
+                    ("DriverTotalTime", "4m33s"),
+                    ("ActiveTime", "10.503ms"),
+                    ("PendingTime", "0ns"),
+                    ("InputEmptyTime", "4m33s"),
+                    ("ScheduleTime", "4m33s"),
+                ],
+            )],
+        )];
+        let timeline = TimelineAnalyzer::analyze_fragments(&fragments, None).unwrap();
+        let p = &timeline.fragments[0].pipelines[0];
+        assert!(p.queue_time_ms.avg < 1.0, "queue time {}", p.queue_time_ms.avg);
+        assert_eq!(p.pending_time_ms.avg, 273_000.0);
+    }
+
+    #[test]
+    fn test_stragglers_and_idle_backends() {
+        let slow = pipeline(
+            "0",
+            &[
+                ("DriverTotalTime", "10s"),
+                ("__MAX_OF_DriverTotalTime", "20s"),
+                ("ActiveTime", "1s"),
+                ("__MAX_OF_ActiveTime", "5s"),
+            ],
+        );
+        let fast = pipeline("0", &[("DriverTotalTime", "1s"), ("ActiveTime", "500ms")]);
+        let fragments = vec![
+            fragment("1", &["be1:9060", "be2:9060"], vec![slow]),
+            fragment("2", &["be3:9060"], vec![fast]),
+        ];
+        let timeline = TimelineAnalyzer::analyze_fragments(&fragments, None).unwrap();
+
+        let p = &timeline.fragments[0].pipelines[0];
+        assert!(p.is_straggler);
+        assert_eq!(p.straggler_ratio, Some(5.0));
+        assert_eq!(timeline.fragments[0].end_ms, 20_000.0);
+        assert!(!timeline.fragments[1].pipelines[0].is_straggler);
+
+        assert_eq!(timeline.backends.len(), 3);
+        assert_eq!(timeline.idle_backends, vec!["be3:9060".to_string()]);
+        assert_eq!(timeline.backends[0].estimated_instances, 1.0);
+    }
+
+    #[test]
+    fn test_fixture_profile_timeline() {
+        use crate::services::profile_analyzer::parser::ProfileComposer;
+
+        let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/profiles/profile11.txt");
+        let text = std::fs::read_to_string(path).unwrap();
+        let profile = ProfileComposer::new().parse(&text).unwrap();
+
+        let timeline = TimelineAnalyzer::analyze(&profile).unwrap();
+        let fragment = timeline
+            .fragments
+            .iter()
+            .find(|f| f.fragment_id == "1")
+            .unwrap();
+        assert_eq!(fragment.instance_num, 32);
+        assert_eq!(fragment.backends.len(), 32);
+        assert_eq!(fragment.initial_driver_count, Some(5954));
+        let pipeline = &fragment.pipelines[0];
+        assert_eq!(pipeline.degree_of_parallelism, Some(16));
+        assert_eq!(pipeline.active_time_ms.max, Some(451.908));
+        assert!(pipeline.end_ms >= pipeline.start_ms);
+        assert_eq!(timeline.backends.len(), 32);
+    }
+
+    #[test]
+    fn test_no_pipeline_metrics() {
+        let fragments = vec![fragment("0", &[], vec![pipeline("0", &[])])];
+        assert!(TimelineAnalyzer::analyze_fragments(&fragments, None).is_none());
+    }
+}
@@ -0,0 +1,775 @@
+//! Shareable analysis reports
+//!
+//! Renders a finished `ProfileAnalysisResponse` for sharing outside the UI:
+//! - HTML: a single self-contained page (inline CSS and an inline SVG of the execution
+//!   tree, no external assets) with diagnostics, root causes and parameter suggestions
+//! - Markdown: a compact summary to paste into tickets
+//! - JSON bundle: the full response wrapped with format/version metadata, which the UI
+//!   can import to reproduce the analysis view offline
+//!
+//! The analysis text is expected to be localized already; only the section labels are
+//! looked up from the i18n catalog.
+
+use super::analyzer::CriticalPath;
+use super::i18n::{self, Locale};
+use super::models::{
+    DiagnosticResult, ExecutionTree, ExecutionTreeNode, HotSeverity, ParameterTuningSuggestion,
+    ProfileAnalysisResponse,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// Identifier stored in every JSON bundle
+pub const BUNDLE_FORMAT: &str = "stellar-profile-analysis";
+/// Current bundle schema version; bundles with a newer version are rejected on import
+pub const BUNDLE_VERSION: u32 = 1;
+
+// SVG layout of the execution tree
+const NODE_WIDTH: f64 = 180.0;
+const NODE_HEIGHT: f64 = 56.0;
+const H_GAP: f64 = 24.0;
+const V_GAP: f64 = 48.0;
+const MARGIN: f64 = 16.0;
+
+/// Output format of an exported report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    #[default]
+    Html,
+    Markdown,
+    Json,
+}
+
+impl ReportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ReportFormat::Html => "text/html; charset=utf-8",
+            ReportFormat::Markdown => "text/markdown; charset=utf-8",
+            ReportFormat::Json => "application/json",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Html => "html",
+            ReportFormat::Markdown => "md",
+            ReportFormat::Json => "json",
+        }
+    }
+}
+
+/// Re-importable snapshot of an analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisBundle {
+    /// Always `BUNDLE_FORMAT`
+    pub format: String,
+    pub version: u32,
+    /// RFC 3339 export timestamp
+    pub exported_at: String,
+    /// Language the analysis text was rendered in
+    pub locale: String,
+    pub analysis: ProfileAnalysisResponse,
+}
+
+impl AnalysisBundle {
+    pub fn new(analysis: ProfileAnalysisResponse, locale: Locale) -> Self {
+        Self {
+            format: BUNDLE_FORMAT.to_string(),
+            version: BUNDLE_VERSION,
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            locale: locale.as_str().to_string(),
+            analysis,
+        }
+    }
+
+    /// Parse and validate an exported bundle
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        let bundle: AnalysisBundle =
+            serde_json::from_str(text).map_err(|e| format!("invalid analysis bundle: {}", e))?;
+        bundle.validate()?;
+        Ok(bundle)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.format != BUNDLE_FORMAT {
+            return Err(format!("unsupported bundle format '{}'", self.format));
+        }
+        if self.version == 0 || self.version > BUNDLE_VERSION {
+            return Err(format!(
+                "unsupported bundle version {} (supported: 1..={})",
+                self.version, BUNDLE_VERSION
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Renders analysis results into shareable documents
+pub struct ReportExporter;
+
+impl ReportExporter {
+    /// Render a response in the requested format
+    pub fn render(
+        response: &ProfileAnalysisResponse,
+        format: ReportFormat,
+        locale: Locale,
+    ) -> Result<String, String> {
+        match format {
+            ReportFormat::Html => Ok(Self::to_html(response, locale)),
+            ReportFormat::Markdown => Ok(Self::to_markdown(response, locale)),
+            ReportFormat::Json => {
+                serde_json::to_string_pretty(&AnalysisBundle::new(response.clone(), locale))
+                    .map_err(|e| format!("failed to serialize analysis bundle: {}", e))
+            },
+        }
+    }
+
+    /// File name for a downloaded report, e.g. `profile-<query_id>.html`
+    pub fn file_name(response: &ProfileAnalysisResponse, format: ReportFormat) -> String {
+        let query_id: String = response
+            .summary
+            .as_ref()
+            .map(|s| s.query_id.as_str())
+            .unwrap_or("analysis")
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .collect();
+        let query_id = if query_id.is_empty() { "analysis".to_string() } else { query_id };
+        format!("profile-{}.{}", query_id, format.extension())
+    }
+
+    // ========================================================================
+    // HTML
+    // ========================================================================
+
+    pub fn to_html(response: &ProfileAnalysisResponse, locale: Locale) -> String {
+        let label = |key| i18n::message(key, locale);
+        let mut out = String::new();
+
+        let _ = write!(
+            out,
+            "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n",
+            locale.as_str(),
+            escape_html(&title(response, locale)),
+            HTML_STYLE
+        );
+        let _ = writeln!(out, "<h1>{}</h1>", escape_html(label("report.title")));
+        let _ = writeln!(
+            out,
+            "<p class=\"muted\">{}</p>",
+            escape_html(
+                &label("report.generated_at").replace(
+                    "{0}",
+                    &chrono::Utc::now()
+                        .format("%Y-%m-%d %H:%M:%S UTC")
+                        .to_string()
+                )
+            )
+        );
+
+        // Summary
+        let _ = writeln!(
+            out,
+            "<h2>{}</h2>\n<table class=\"kv\">",
+            escape_html(label("report.summary"))
+        );
+        for (key, value) in summary_rows(response, locale) {
+            let _ = writeln!(
+                out,
+                "<tr><th>{}</th><td>{}</td></tr>",
+                escape_html(&key),
+                escape_html(&value)
+            );
+        }
+        out.push_str("</table>\n");
+        if let Some(sql) = response
+            .summary
+            .as_ref()
+            .map(|s| s.sql_statement.trim())
+            .filter(|sql| !sql.is_empty())
+        {
+            let _ = writeln!(out, "<pre class=\"sql\">{}</pre>", escape_html(sql));
+        }
+        if !response.conclusion.is_empty() {
+            let _ = writeln!(
+                out,
+                "<h2>{}</h2>\n<p>{}</p>",
+                escape_html(label("report.conclusion")),
+                escape_html(&response.conclusion)
+            );
+        }
+
+        // Execution tree
+        if let Some(tree) = &response.execution_tree {
+            let _ = writeln!(out, "<h2>{}</h2>", escape_html(label("report.execution_tree")));
+            out.push_str("<div class=\"tree\">\n");
+            out.push_str(&render_tree_svg(tree, response.critical_path.as_ref()));
+            out.push_str("</div>\n");
+        }
+
+        // Diagnostics
+        let _ = writeln!(out, "<h2>{}</h2>", escape_html(label("report.diagnostics")));
+        if response.diagnostics.is_empty() {
+            let _ = writeln!(out, "<p>{}</p>", escape_html(label("report.no_diagnostics")));
+        } else {
+            let _ = writeln!(
+                out,
+                "<table>\n<tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>",
+                escape_html(label("report.severity")),
+                escape_html(label("report.rule")),
+                escape_html(label("report.node")),
+                escape_html(label("report.message")),
+                escape_html(label("report.suggestions"))
+            );
+            for diag in &response.diagnostics {
+                let _ = writeln!(
+                    out,
+                    "<tr><td><span class=\"sev sev-{}\">{}</span></td><td>{} {}</td><td>{}</td><td>{}<div class=\"muted\">{}</div></td><td>{}</td></tr>",
+                    escape_html(&diag.severity.to_lowercase()),
+                    escape_html(&diag.severity),
+                    escape_html(&diag.rule_id),
+                    escape_html(&diag.rule_name),
+                    escape_html(&diag.node_path),
+                    escape_html(&diag.message),
+                    escape_html(&diag.reason),
+                    html_list(&diag.suggestions)
+                );
+            }
+            out.push_str("</table>\n");
+        }
+
+        // Root causes
+        if let Some(rca) = response
+            .root_cause_analysis
+            .as_ref()
+            .filter(|r| !r.root_causes.is_empty())
+        {
+            let _ = writeln!(
+                out,
+                "<h2>{}</h2>\n<p>{}</p>",
+                escape_html(label("report.root_causes")),
+                escape_html(&rca.summary)
+            );
+            for root_cause in &rca.root_causes {
+                let _ = writeln!(
+                    out,
+                    "<div class=\"card\"><h3>{} {}</h3><p class=\"muted\">{}: {:.0}% · {}: {}</p>",
+                    escape_html(&root_cause.id),
+                    escape_html(&root_cause.description),
+                    escape_html(label("report.impact")),
+                    root_cause.impact_percentage,
+                    escape_html(label("report.confidence")),
+                    format_args!("{:.0}%", root_cause.confidence * 100.0)
+                );
+                if !root_cause.evidence.is_empty() {
+                    let _ = writeln!(
+                        out,
+                        "<h4>{}</h4>{}",
+                        escape_html(label("report.evidence")),
+                        html_list(&root_cause.evidence)
+                    );
+                }
+                if !root_cause.suggestions.is_empty() {
+                    let _ = writeln!(
+                        out,
+                        "<h4>{}</h4>{}",
+                        escape_html(label("report.suggestions")),
+                        html_list(&root_cause.suggestions)
+                    );
+                }
+                out.push_str("</div>\n");
+            }
+            if !rca.causal_chains.is_empty() {
+                let _ =
+                    writeln!(out, "<h3>{}</h3>\n<ul>", escape_html(label("report.causal_chains")));
+                for chain in &rca.causal_chains {
+                    let _ = writeln!(
+                        out,
+                        "<li><strong>{}</strong> — {}</li>",
+                        escape_html(&chain.chain.join(" ")),
+                        escape_html(&chain.explanation)
+                    );
+                }
+                out.push_str("</ul>\n");
+            }
+        }
+
+        // Parameter suggestions
+        let parameters = collect_parameters(&response.diagnostics);
+        if !parameters.is_empty() {
+            let _ = writeln!(
+                out,
+                "<h2>{}</h2>\n<table>\n<tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>",
+                escape_html(label("report.parameters")),
+                escape_html(label("report.parameter")),
+                escape_html(label("report.current")),
+                escape_html(label("report.recommended")),
+                escape_html(label("report.command")),
+                escape_html(label("report.impact"))
+            );
+            for param in parameters {
+                let _ = writeln!(
+                    out,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td><code>{}</code></td><td>{}</td></tr>",
+                    escape_html(&param.name),
+                    escape_html(param.current.as_deref().unwrap_or("-")),
+                    escape_html(&param.recommended),
+                    escape_html(&param.command),
+                    escape_html(&param.impact)
+                );
+            }
+            out.push_str("</table>\n");
+        }
+
+        // General suggestions
+        if !response.suggestions.is_empty() {
+            let _ = writeln!(
+                out,
+                "<h2>{}</h2>\n{}",
+                escape_html(label("report.suggestions")),
+                html_list(&response.suggestions)
+            );
+        }
+
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    // ========================================================================
+    // Markdown
+    // ========================================================================
+
+    pub fn to_markdown(response: &ProfileAnalysisResponse, locale: Locale) -> String {
+        let label = |key| i18n::message(key, locale);
+        let mut out = String::new();
+
+        let _ = writeln!(out, "# {}\n", label("report.title"));
+        let _ = writeln!(out, "## {}\n", label("report.summary"));
+        for (key, value) in summary_rows(response, locale) {
+            let _ = writeln!(out, "- **{}**: {}", key, value);
+        }
+        out.push('\n');
+        if let Some(sql) = response
+            .summary
+            .as_ref()
+            .map(|s| s.sql_statement.trim())
+            .filter(|sql| !sql.is_empty())
+        {
+            let _ = writeln!(out, "```sql\n{}\n```\n", sql);
+        }
+        if !response.conclusion.is_empty() {
+            let _ = writeln!(out, "## {}\n\n{}\n", label("report.conclusion"), response.conclusion);
+        }
+
+        let _ = writeln!(out, "## {}\n", label("report.diagnostics"));
+        if response.aggregated_diagnostics.is_empty() && response.diagnostics.is_empty() {
+            let _ = writeln!(out, "{}\n", label("report.no_diagnostics"));
+        } else {
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} | {} |\n|---|---|---|---|",
+                label("report.severity"),
+                label("report.rule"),
+                label("report.node"),
+                label("report.message")
+            );
+            if response.aggregated_diagnostics.is_empty() {
+                for diag in &response.diagnostics {
+                    let _ = writeln!(
+                        out,
+                        "| {} | {} {} | {} | {} |",
+                        diag.severity,
+                        diag.rule_id,
+                        markdown_cell(&diag.rule_name),
+                        markdown_cell(&diag.node_path),
+                        markdown_cell(&diag.message)
+                    );
+                }
+            } else {
+                for agg in &response.aggregated_diagnostics {
+                    let _ = writeln!(
+                        out,
+                        "| {} | {} {} | {} | {} |",
+                        agg.severity,
+                        agg.rule_id,
+                        markdown_cell(&agg.rule_name),
+                        markdown_cell(&agg.affected_nodes.join(", ")),
+                        markdown_cell(&agg.message)
+                    );
+                }
+            }
+            out.push('\n');
+        }
+
+        if let Some(rca) = response
+            .root_cause_analysis
+            .as_ref()
+            .filter(|r| !r.root_causes.is_empty())
+        {
+            let _ = writeln!(out, "## {}\n\n{}\n", label("report.root_causes"), rca.summary);
+            for root_cause in &rca.root_causes {
+                let _ = writeln!(
+                    out,
+                    "- **{}** {} ({}: {:.0}%)",
+                    root_cause.id,
+                    root_cause.description,
+                    label("report.impact"),
+                    root_cause.impact_percentage
+                );
+                for suggestion in &root_cause.suggestions {
+                    let _ = writeln!(out, "  - {}", suggestion);
+                }
+            }
+            out.push('\n');
+        }
+
+        let parameters = collect_parameters(&response.diagnostics);
+        if !parameters.is_empty() {
+            let _ = writeln!(out, "## {}\n\n```sql", label("report.parameters"));
+            for param in parameters {
+                let _ = writeln!(out, "{}", param.command);
+            }
+            out.push_str("```\n\n");
+        }
+
+        if !response.suggestions.is_empty() {
+            let _ = writeln!(out, "## {}\n", label("report.suggestions"));
+            for suggestion in &response.suggestions {
+                let _ = writeln!(out, "- {}", suggestion);
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn title(response: &ProfileAnalysisResponse, locale: Locale) -> String {
+    match response.summary.as_ref().map(|s| s.query_id.as_str()) {
+        Some(query_id) if !query_id.is_empty() => {
+            format!("{} - {}", i18n::message("report.title", locale), query_id)
+        },
+        _ => i18n::message("report.title", locale).to_string(),
+    }
+}
+
+fn summary_rows(response: &ProfileAnalysisResponse, locale: Locale) -> Vec<(String, String)> {
+    let label = |key| i18n::message(key, locale).to_string();
+    let mut rows = Vec::new();
+    if let Some(summary) = &response.summary {
+        rows.push((label("report.query_id"), summary.query_id.clone()));
+        rows.push((label("report.total_time"), summary.total_time.clone()));
+        if !summary.query_state.is_empty() {
+            rows.push((label("report.state"), summary.query_state.clone()));
+        }
+    }
+    rows.push((label("report.score"), format!("{:.1}", response.performance_score)));
+    rows
+}
+
+/// Parameter suggestions of all diagnostics, first occurrence per parameter
+fn collect_parameters(diagnostics: &[DiagnosticResult]) -> Vec<&ParameterTuningSuggestion> {
+    let mut seen = HashSet::new();
+    diagnostics
+        .iter()
+        .flat_map(|d| d.parameter_suggestions.iter())
+        .filter(|p| seen.insert(p.name.as_str()))
+        .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn html_list(items: &[String]) -> String {
+    if items.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("<ul>");
+    for item in items {
+        let _ = write!(out, "<li>{}</li>", escape_html(item));
+    }
+    out.push_str("</ul>");
+    out
+}
+
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+// ============================================================================
+// Execution tree SVG
+// ============================================================================
+
+/// Lay out the tree top-down: leaves take consecutive columns and each parent is
+/// centered above its children. Returns node id -> (column, depth).
+fn layout_tree(tree: &ExecutionTree) -> HashMap<String, (f64, usize)> {
+    let index: HashMap<&str, &ExecutionTreeNode> =
+        tree.nodes.iter().map(|n| (n.id.as_str(), n)).collect();
+    let mut positions = HashMap::new();
+    let mut next_column = 0.0;
+    let mut visiting = HashSet::new();
+    place_node(&tree.root.id, 0, &index, &mut positions, &mut next_column, &mut visiting);
+    positions
+}
+
+fn place_node(
+    id: &str,
+    depth: usize,
+    index: &HashMap<&str, &ExecutionTreeNode>,
+    positions: &mut HashMap<String, (f64, usize)>,
+    next_column: &mut f64,
+    visiting: &mut HashSet<String>,
+) -> Option<f64> {
+    // Guard against malformed (cyclic or shared) child references
+    if positions.contains_key(id) || !visiting.insert(id.to_string()) {
+        return None;
+    }
+    let node = index.get(id)?;
+    let columns: Vec<f64> = node
+        .children
+        .iter()
+        .filter_map(|child| place_node(child, depth + 1, index, positions, next_column, visiting))
+        .collect();
+    let column = if columns.is_empty() {
+        let column = *next_column;
+        *next_column += 1.0;
+        column
+    } else {
+        columns.iter().sum::<f64>() / columns.len() as f64
+    };
+    positions.insert(id.to_string(), (column, depth));
+    Some(column)
+}
+
+fn severity_color(node: &ExecutionTreeNode) -> &'static str {
+    match node.hotspot_severity {
+        HotSeverity::Critical | HotSeverity::Severe => "#fde2e1",
+        HotSeverity::High | HotSeverity::Moderate => "#fff1d6",
+        HotSeverity::Mild => "#fffbe6",
+        HotSeverity::Normal if node.has_diagnostic => "#fffbe6",
+        HotSeverity::Normal => "#f5f7fa",
+    }
+}
+
+fn render_tree_svg(tree: &ExecutionTree, critical_path: Option<&CriticalPath>) -> String {
+    let positions = layout_tree(tree);
+    let critical: HashSet<&str> = critical_path
+        .map(|path| path.nodes.iter().map(|n| n.node_id.as_str()).collect())
+        .unwrap_or_default();
+
+    let columns = positions.values().map(|(c, _)| *c).fold(0.0_f64, f64::max) + 1.0;
+    let depth = positions.values().map(|(_, d)| *d).max().unwrap_or(0) + 1;
+    let width = MARGIN * 2.0 + columns * NODE_WIDTH + (columns - 1.0).max(0.0) * H_GAP;
+    let height = MARGIN * 2.0 + depth as f64 * NODE_HEIGHT + (depth - 1) as f64 * V_GAP;
+    let origin = |(column, depth): (f64, usize)| {
+        (MARGIN + column * (NODE_WIDTH + H_GAP), MARGIN + depth as f64 * (NODE_HEIGHT + V_GAP))
+    };
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w:.0}\" height=\"{h:.0}\" viewBox=\"0 0 {w:.0} {h:.0}\" font-family=\"sans-serif\" font-size=\"12\">",
+        w = width,
+        h = height
+    );
+
+    // Edges first so boxes are drawn on top
+    for node in &tree.nodes {
+        let Some(&parent) = positions.get(&node.id) else {
+            continue;
+        };
+        let (px, py) = origin(parent);
+        for child in &node.children {
+            let Some(&position) = positions.get(child) else {
+                continue;
+            };
+            let (cx, cy) = origin(position);
+            let on_path = critical.contains(node.id.as_str()) && critical.contains(child.as_str());
+            let _ = writeln!(
+                out,
+                "<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"{}\" stroke-width=\"{}\"/>",
+                px + NODE_WIDTH / 2.0,
+                py + NODE_HEIGHT,
+                cx + NODE_WIDTH / 2.0,
+                cy,
+                if on_path { "#d4380d" } else { "#b0b7c3" },
+                if on_path { 2.5 } else { 1.2 }
+            );
+        }
+    }
+
+    for node in &tree.nodes {
+        let Some(&position) = positions.get(&node.id) else {
+            continue;
+        };
+        let (x, y) = origin(position);
+        let on_path = critical.contains(node.id.as_str());
+        let name = match node.plan_node_id {
+            Some(id) => format!("{} (#{})", node.operator_name, id),
+            None => node.operator_name.clone(),
+        };
+        let mut detail = Vec::new();
+        if let Some(pct) = node.time_percentage {
+            detail.push(format!("{:.1}%", pct));
+        }
+        if let Some(rows) = node.rows {
+            detail.push(format!("{} rows", rows));
+        }
+        let _ = writeln!(
+            out,
+            "<g><title>{}</title><rect x=\"{:.1}\" y=\"{:.1}\" width=\"{}\" height=\"{}\" rx=\"6\" fill=\"{}\" stroke=\"{}\" stroke-width=\"{}\"/>",
+            escape_html(&name),
+            x,
+            y,
+            NODE_WIDTH,
+            NODE_HEIGHT,
+            severity_color(node),
+            if on_path { "#d4380d" } else { "#8c96a5" },
+            if on_path { 2.5 } else { 1.0 }
+        );
+        let _ = writeln!(
+            out,
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\" font-weight=\"bold\">{}</text>",
+            x + NODE_WIDTH / 2.0,
+            y + 22.0,
+            escape_html(&truncate(&name, 24))
+        );
+        let _ = writeln!(
+            out,
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\" fill=\"#555\">{}</text></g>",
+            x + NODE_WIDTH / 2.0,
+            y + 40.0,
+            escape_html(&detail.join(" · "))
+        );
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max_chars - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+const HTML_STYLE: &str = "body{font-family:-apple-system,'Segoe UI',sans-serif;margin:24px auto;max-width:1200px;color:#1f2329;padding:0 16px}\
+h1{font-size:22px}h2{font-size:18px;margin-top:28px;border-bottom:1px solid #e5e6eb;padding-bottom:4px}\
+table{border-collapse:collapse;width:100%;font-size:13px}th,td{border:1px solid #e5e6eb;padding:6px 8px;text-align:left;vertical-align:top}\
+th{background:#f5f7fa}table.kv{width:auto}table.kv th{width:160px}ul{margin:4px 0;padding-left:20px}\
+pre.sql{background:#f5f7fa;padding:12px;overflow-x:auto;white-space:pre-wrap}\
+.muted{color:#86909c;font-size:12px}.tree{overflow-x:auto;border:1px solid #e5e6eb;padding:8px}\
+.card{border:1px solid #e5e6eb;border-radius:6px;padding:8px 12px;margin:8px 0}.card h3{font-size:15px;margin:4px 0}.card h4{font-size:13px;margin:8px 0 2px}\
+.sev{padding:1px 6px;border-radius:4px;font-size:12px}.sev-error{background:#fde2e1;color:#c9302c}\
+.sev-warning{background:#fff1d6;color:#b36b00}.sev-info{background:#e8f3ff;color:#165dff}";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::profile_analyzer::{AnalysisContext, analyze_profile_with_context};
+
+    fn fixture_response(locale: Locale) -> ProfileAnalysisResponse {
+        let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/profiles/profile1.txt");
+        let text = std::fs::read_to_string(path).unwrap();
+        let context = AnalysisContext { locale, ..Default::default() };
+        analyze_profile_with_context(&text, &context).unwrap()
+    }
+
+    #[test]
+    fn test_html_report_is_self_contained() {
+        let response = fixture_response(Locale::En);
+        let html = ReportExporter::to_html(&response, Locale::En);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<svg"));
+        assert!(html.contains("Query Profile Analysis Report"));
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("src=\"http"));
+        assert!(!html.contains("href=\"http"));
+
+        let tree = response.execution_tree.as_ref().unwrap();
+        assert_eq!(html.matches("<rect").count(), tree.nodes.len());
+        for diag in &response.diagnostics {
+            assert!(html.contains(&escape_html(&diag.message)), "missing {}", diag.rule_id);
+        }
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let response = fixture_response(Locale::Zh);
+        let markdown = ReportExporter::to_markdown(&response, Locale::Zh);
+
+        assert!(markdown.starts_with("# 查询 Profile 分析报告"));
+        assert!(markdown.contains(&response.summary.as_ref().unwrap().query_id));
+        for agg in &response.aggregated_diagnostics {
+            assert!(markdown.contains(&agg.rule_id));
+        }
+    }
+
+    #[test]
+    fn test_bundle_round_trip() {
+        let response = fixture_response(Locale::En);
+        let json = ReportExporter::render(&response, ReportFormat::Json, Locale::En).unwrap();
+
+        let bundle = AnalysisBundle::from_json(&json).unwrap();
+        assert_eq!(bundle.version, BUNDLE_VERSION);
+        assert_eq!(bundle.locale, "en");
+        // Floats may differ in the last digit, so compare the structure of the view
+        let imported = &bundle.analysis;
+        assert_eq!(imported.diagnostics.len(), response.diagnostics.len());
+        assert_eq!(imported.aggregated_diagnostics.len(), response.aggregated_diagnostics.len());
+        assert_eq!(imported.node_diagnostics.len(), response.node_diagnostics.len());
+        assert_eq!(
+            imported.execution_tree.as_ref().map(|t| t.nodes.len()),
+            response.execution_tree.as_ref().map(|t| t.nodes.len())
+        );
+        assert_eq!(imported.profile_content, response.profile_content);
+        assert_eq!(imported.conclusion, response.conclusion);
+    }
+
+    #[test]
+    fn test_bundle_validation() {
+        let mut bundle = AnalysisBundle::new(fixture_response(Locale::Zh), Locale::Zh);
+        bundle.version = BUNDLE_VERSION + 1;
+        let json = serde_json::to_string(&bundle).unwrap();
+        assert!(AnalysisBundle::from_json(&json).is_err());
+
+        bundle.version = BUNDLE_VERSION;
+        bundle.format = "other".to_string();
+        let json = serde_json::to_string(&bundle).unwrap();
+        assert!(AnalysisBundle::from_json(&json).is_err());
+
+        assert!(AnalysisBundle::from_json("{\"format\": 1}").is_err());
+    }
+
+    #[test]
+    fn test_escape_and_file_name() {
+        assert_eq!(escape_html("<a href=\"x\">&'"), "&lt;a href=&quot;x&quot;&gt;&amp;&#39;");
+
+        let response = fixture_response(Locale::Zh);
+        let name = ReportExporter::file_name(&response, ReportFormat::Markdown);
+        assert!(name.starts_with("profile-") && name.ends_with(".md"));
+        assert!(!name.contains('/'));
+    }
+}
@@ -0,0 +1,1171 @@
+//! English message catalog. Every key must also exist in `zh.rs` with the same
+//! placeholders.
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    ("A001.message", "Aggregation data is skewed, max/avg ratio {0} (threshold: {1})"),
+    ("A001.name", "Aggregation data skew"),
+    (
+        "A001.reason",
+        "Aggregation instances process very different amounts of data, so some instances become the bottleneck. This is usually caused by an uneven distribution of GROUP BY keys.",
+    ),
+    ("A001.suggestion.1", "Check the data distribution of the GROUP BY keys"),
+    ("A001.suggestion.2", "Consider two-phase aggregation"),
+    ("A001.suggestion.3", "Check for hot keys"),
+    ("A002.message", "Aggregation HashTable uses {0} of memory (threshold: {1})"),
+    ("A002.name", "Aggregation HashTable too large"),
+    (
+        "A002.reason",
+        "The HashTable uses too much memory, which may cause memory pressure or trigger spilling. This is usually caused by high GROUP BY cardinality or large aggregate function states.",
+    ),
+    ("A002.suggestion.1", "Check whether the GROUP BY cardinality is too high"),
+    ("A002.suggestion.2", "Consider pre-aggregating with a materialized view"),
+    ("A002.suggestion.3", "Enable spilling to avoid OOM"),
+    ("A003.message", "Aggregation data is skewed, max/avg ratio {0}"),
+    ("A003.name", "Aggregation data skew"),
+    (
+        "A003.reason",
+        "Aggregation input is unevenly distributed across instances, so some instances process more data than others.",
+    ),
+    ("A003.suggestion.1", "Choose better grouping keys"),
+    ("A003.suggestion.2", "Consider handling hot keys separately"),
+    ("A004.message", "GROUP BY cardinality is too high ({0} groups)"),
+    ("A004.name", "High-cardinality GROUP BY"),
+    (
+        "A004.reason",
+        "GROUP BY key `{0}` has very high cardinality ({1} distinct values), so the HashTable takes {2} of memory and aggregation becomes inefficient.",
+    ),
+    (
+        "A004.suggestion.1",
+        "Check whether every GROUP BY key in `{0}` is needed and drop unnecessary grouping columns",
+    ),
+    (
+        "A004.suggestion.2",
+        "Consider streaming aggregation: SET streaming_preaggregation_mode = 'force_streaming'",
+    ),
+    ("A004.suggestion.3", "Consider a materialized view that pre-aggregates common groupings"),
+    ("A005.message", "GROUP BY key expressions take too much time ({0}%)"),
+    ("A005.name", "Expensive GROUP BY key expressions"),
+    (
+        "A005.reason",
+        "GROUP BY keys contain complex expressions that are evaluated for every row, adding CPU cost. Compute the expressions earlier or use generated columns.",
+    ),
+    ("A005.suggestion.1", "Materialize complex expressions in a subquery"),
+    ("A005.suggestion.2", "Turn the expressions into generated columns"),
+    ("A005.suggestion.3", "Avoid complex functions in GROUP BY"),
+    (
+        "A006.message",
+        "Local aggregation is ineffective, ratio {0}:1 (input {1} rows → output {2} rows)",
+    ),
+    ("A006.name", "Low local aggregation ratio"),
+    (
+        "A006.reason",
+        "During local aggregation, GROUP BY `{0}` only reduced {1} input rows to {2} rows (reduction {3}:1), so little data was eliminated. This adds network transfer and downstream computation.",
+    ),
+    (
+        "A006.suggestion.1",
+        "GROUP BY `{0}` may have very high cardinality; consider disabling two-stage aggregation: SET new_planner_agg_stage = 1",
+    ),
+    (
+        "A006.suggestion.2",
+        "Check whether the GROUP BY keys include high-cardinality columns (such as IDs or timestamps)",
+    ),
+    ("A006.suggestion.3", "Consider pre-aggregating at write time or using a materialized view"),
+    ("E001.message", "Network transfer volume is {0}; the network may be a bottleneck"),
+    ("E001.name", "Large network transfer"),
+    (
+        "E001.reason",
+        "Too much data is sent over the network, consuming bandwidth and time. The shuffle may be large or data pruning may be missing.",
+    ),
+    ("E001.suggestion.1", "Check whether the shuffle volume can be reduced"),
+    ("E001.suggestion.2", "Consider a Colocate Join to avoid the shuffle"),
+    ("E002.message", "Network time takes {0}%; the network may be a bottleneck"),
+    ("E002.name", "High network time ratio"),
+    (
+        "E002.reason",
+        "Network transfer takes a large share of the time, so the query is network-bound. Bandwidth may be insufficient or data may cross data centers.",
+    ),
+    ("E002.suggestion.1", "Check network bandwidth and latency"),
+    ("E002.suggestion.2", "Consider a Colocate Join"),
+    ("E002.suggestion.3", "Reduce cross-node data transfer"),
+    ("E003.message", "Shuffle data is skewed, max/avg ratio {0}"),
+    ("E003.name", "Shuffle data skew"),
+    (
+        "E003.reason",
+        "Shuffled data is unevenly distributed, so some nodes receive more data. The shuffle key usually has hot values.",
+    ),
+    ("E003.suggestion.1", "Check whether the partition key is a good choice"),
+    ("E003.suggestion.2", "Consider Skew Join optimization"),
+    ("E003.suggestion.3", "Check for hot data"),
+    (
+        "EX001.message",
+        "{0} has no join condition and produces a Cartesian product (estimated output rows: {1})",
+    ),
+    ("EX001.name", "Cross join"),
+    (
+        "EX001.reason",
+        "CROSS JOIN combines every row of one side with every row of the other, so the output is the product of both row counts; even moderately sized inputs cause timeouts or out-of-memory errors.",
+    ),
+    ("EX001.suggestion.1", "Check whether a JOIN condition is missing"),
+    ("EX001.suggestion.2", "Rewrite the join condition as an equality so a hash join can be used"),
+    ("EX002.message", "Broadcast join is estimated to broadcast {0} rows (threshold: {1} rows)"),
+    ("EX002.name", "Large table broadcast"),
+    (
+        "EX002.reason",
+        "A broadcast join copies the right table to every BE and builds a hash table on each, so a large right table multiplies network and memory cost. The optimizer usually picks broadcast because missing or stale statistics underestimate the right table.",
+    ),
+    ("EX002.suggestion.1", "Use the JOIN [shuffle] hint to switch to a shuffle join"),
+    ("EX002.suggestion.2", "Run ANALYZE TABLE to refresh the statistics of the right table"),
+    ("EX003.message", "Table {0} scans all {1} partitions; no partition pruning"),
+    ("EX003.name", "No partition pruning"),
+    (
+        "EX003.reason",
+        "The query conditions do not hit the partition column, so data of all partitions is read. Filtering on the partition column greatly reduces the scanned data.",
+    ),
+    ("EX003.suggestion.1", "Add a filter on the partition column to the WHERE clause"),
+    (
+        "EX003.suggestion.2",
+        "Avoid functions or type casts on the partition column; they prevent partition pruning",
+    ),
+    ("EX004.message", "The filter of {0} is not pushed down to scan node {1}"),
+    ("EX004.name", "Predicate not pushed down"),
+    (
+        "EX004.reason",
+        "The filter runs after the scan, so the storage layer cannot use partitions, indexes or zone maps to skip data, and more data is scanned and transferred. Common causes are functions, UDFs, non-deterministic expressions or OR conditions across columns in the predicate.",
+    ),
+    ("EX004.suggestion.1", "Avoid functions or implicit type conversion on filtered columns"),
+    (
+        "EX004.suggestion.2",
+        "Rewrite complex expressions as simple comparisons that can be pushed down",
+    ),
+    ("EX005.message", "{0} fully sorts its input without LIMIT (estimated rows: {1})"),
+    ("EX005.name", "ORDER BY without LIMIT"),
+    (
+        "EX005.reason",
+        "ORDER BY without LIMIT sorts the entire result and returns all of it to the client; large results use a lot of memory and may spill to disk.",
+    ),
+    (
+        "EX005.suggestion.1",
+        "If only the first N rows are needed, add LIMIT to use the TopN optimization",
+    ),
+    ("EX005.suggestion.2", "Remove unnecessary ORDER BY and sort on the client"),
+    ("EX006.message", "External table {0} scans all {1} partitions without a partition filter"),
+    ("EX006.name", "External table scan without partition filter"),
+    (
+        "EX006.reason",
+        "Without a partition filter, external tables (Hive/Iceberg/Hudi, etc.) must fetch every partition from the metastore and read all their files, so both metadata retrieval and remote scanning are slow.",
+    ),
+    ("EX006.suggestion.1", "Add a filter on the partition column to the WHERE clause"),
+    ("EX006.suggestion.2", "Make sure the filter value type matches the partition column type"),
+    ("F001.message", "Instance execution time is skewed, max/avg ratio {0}"),
+    ("F001.name", "Instance execution time skew"),
+    (
+        "F001.reason",
+        "The fragment runs too long and is the main bottleneck of the query. Analyze the operators inside the fragment to find the concrete problem.",
+    ),
+    ("F001.suggestion.1", "Check the data distribution"),
+    ("F001.suggestion.2", "Improve the bucketing strategy"),
+    ("F002.message", "Instance memory is unevenly allocated, max/avg ratio {0}"),
+    ("F002.name", "Uneven instance memory"),
+    ("F002.reason", "Fragment memory usage is too high and may fail the query."),
+    ("F002.suggestion", "Check for data skew"),
+    ("F003.message", "Fragment preparation time {0}s"),
+    ("F003.name", "Fragment preparation too slow"),
+    (
+        "F003.reason",
+        "Execution time differs a lot between fragment instances; there is data skew or uneven resources.",
+    ),
+    ("F003.suggestion", "Check metadata loading"),
+    ("G001.message", "🔴 Operator {0} takes {1}% of the execution time (most time-consuming node)"),
+    ("G001.name", "Operator takes most of the time"),
+    (
+        "G001.reason",
+        "This operator takes a large share of the total query time and is the main bottleneck. Optimizing it gives the biggest gain.",
+    ),
+    ("G001.suggestion.1", "Check whether filters can be added to reduce scanned data"),
+    ("G001.suggestion.2", "Check whether partition pruning takes effect"),
+    ("G001.suggestion.3", "Run ANALYZE TABLE to refresh statistics"),
+    ("G001.suggestion.4", "Check whether the JOIN order is optimal"),
+    ("G001.suggestion.5", "Consider using Runtime Filters"),
+    ("G001.suggestion.6", "Check for data skew"),
+    ("G001.suggestion.7", "Check whether the aggregation mode is appropriate"),
+    ("G001.suggestion.8", "Consider pre-aggregation or a materialized view"),
+    ("G001.suggestion.9", "Review the choice of GROUP BY keys"),
+    ("G001.suggestion.10", "Consider adjusting parallelism"),
+    ("G001.suggestion.11", "Check whether network bandwidth is sufficient"),
+    ("G001.suggestion.12", "Add a LIMIT to bound the result set"),
+    ("G001.suggestion.13", "Check whether Top-N optimization can be used"),
+    ("G001.suggestion.14", "Consider a materialized view with pre-sorted data"),
+    ("G001.suggestion.15", "Check whether this operator processes too much data"),
+    ("G001.suggestion.16", "Consider optimizing the query plan"),
+    (
+        "G001b.message",
+        "🟠 Operator {0} takes {1}% of the execution time (second most time-consuming node)",
+    ),
+    ("G001b.name", "Operator takes a large share of time"),
+    ("G002.message", "Operator {0} uses too much memory: {1} (threshold: {2})"),
+    ("G002.name", "Operator memory usage too high"),
+    (
+        "G002.reason",
+        "The operator uses too much memory, which may fail the query or trigger spilling. Check for data expansion or oversized intermediate results.",
+    ),
+    ("G002.suggestion.1", "Check for data expansion"),
+    ("G002.suggestion.2", "Consider processing in batches"),
+    ("G002.suggestion.3", "Check whether the HashTable or intermediate results are too large"),
+    ("G003.message", "Operator {0} has skewed execution time, max/avg ratio {1} (threshold: {2})"),
+    ("G003.name", "Operator execution time skew"),
+    (
+        "G003.reason",
+        "Execution time differs a lot between instances of this operator, so some instances become the bottleneck. This is usually caused by uneven data distribution.",
+    ),
+    ("G003.suggestion.1", "Check whether the data is evenly distributed"),
+    ("G003.suggestion.2", "Check whether partitioning or bucketing is reasonable"),
+    ("G003.suggestion.3", "Consider increasing parallelism"),
+    ("I001.message", "Load data is skewed, PushChunkNum max/min ratio {0}"),
+    ("I001.name", "Load data skew"),
+    ("I001.suggestion.1", "Check whether upstream operators have data skew"),
+    ("I001.suggestion.2", "Choose a better bucket key"),
+    (
+        "I002.message",
+        "Load RPC client time is {0}x the server time; network transfer may be the bottleneck",
+    ),
+    ("I002.name", "High load RPC latency"),
+    ("I002.suggestion", "Enable compression to reduce network transfer"),
+    ("I003.message", "Load filtered {0} rows ({1}%); there may be data quality problems"),
+    ("I003.name", "Too many rows filtered during load"),
+    ("I003.suggestion.1", "Check whether the data format matches the table schema"),
+    ("I003.suggestion.2", "Check for NULL values or type mismatches"),
+    ("I003.suggestion.3", "Check the BE logs for the detailed filter reasons"),
+    ("J001.message", "Join output expanded {0}x (output {1} rows / probe {2} rows)"),
+    ("J001.name", "Join output explosion"),
+    (
+        "J001.reason",
+        "The join produces far more rows than its input. Usually a missing join condition caused a cross join, or a wrong condition caused 1:N matches.",
+    ),
+    ("J001.suggestion.1", "Check whether JOIN conditions are missing or incomplete"),
+    ("J001.suggestion.2", "Check for many-to-many relationships"),
+    ("J001.suggestion.3", "Consider adding more filters"),
+    (
+        "J002.message",
+        "Build side rows ({0}) exceed probe side rows ({1}); the join order may be suboptimal",
+    ),
+    ("J002.name", "Join build side too large"),
+    (
+        "J002.reason",
+        "In this hash join the build side is larger than the probe side, making the HashTable too large. Inaccurate statistics may have led the optimizer to pick the wrong build side.",
+    ),
+    ("J002.suggestion.1", "Check whether the optimizer chose the right join order"),
+    ("J002.suggestion.2", "Consider a hint to fix the join order"),
+    ("J003.message", "HashTable uses {0} of memory and may cause memory pressure (threshold: {1})"),
+    ("J003.name", "HashTable memory too large"),
+    (
+        "J003.reason",
+        "The join HashTable uses too much memory, which may cause memory pressure or trigger spilling.",
+    ),
+    ("J003.suggestion.1", "Check whether the build side has too much data"),
+    ("J003.suggestion.2", "Consider Runtime Filters to reduce data"),
+    ("J004.message", "Join did not generate a Runtime Filter; the build side has {0} rows"),
+    ("J004.name", "No Runtime Filter generated"),
+    (
+        "J004.reason",
+        "Runtime Filters are missing or ineffective, so the scan side cannot filter data early. The filter build may have failed or has poor selectivity.",
+    ),
+    ("J004.suggestion.1", "Check whether enable_global_runtime_filter is enabled"),
+    ("J004.suggestion.2", "Check whether the join condition is suitable for generating RFs"),
+    ("J004.suggestion.3", "Check whether the build side row count exceeds the threshold"),
+    ("J005.message", "Severe hash table collisions, {0} keys per bucket on average"),
+    ("J005.name", "Severe hash collisions"),
+    (
+        "J005.reason",
+        "The hash table for join condition `{0}` has many collisions (build side {1} rows), which slows down probing. Join keys may be unevenly distributed or hash poorly.",
+    ),
+    ("J005.suggestion.1", "Check whether join key `{0}` has many duplicate or NULL values"),
+    (
+        "J005.suggestion.2",
+        "Consider adding more equi-join conditions to spread the hash distribution",
+    ),
+    (
+        "J005.suggestion.3",
+        "Check the data distribution of the build table and pre-filter if needed",
+    ),
+    ("J006.message", "Join data distribution is skewed, max/avg ratio {0} (threshold: {1})"),
+    ("J006.name", "Join shuffle skew"),
+    (
+        "J006.reason",
+        "The shuffle join distributes data by `{0}` unevenly: the max instance processes {1} rows while the min instance only {2}. Join keys usually have hot values.",
+    ),
+    (
+        "J006.suggestion.1",
+        "Check whether join key `{0}` has hot values (such as NULL or very frequent values)",
+    ),
+    ("J006.suggestion.2", "Consider adding more join conditions to spread the data"),
+    ("J006.suggestion.3", "Handle hot key values separately or add a salt to scatter them"),
+    ("J007.message", "Partition probing takes {0}% of the time with {1} partitions"),
+    ("J007.name", "High partitioned join probe cost"),
+    (
+        "J007.reason",
+        "Partition probing is too expensive; there may be too many partitions or a poor partitioning strategy.",
+    ),
+    ("J007.suggestion.1", "Check whether the number of partitions is reasonable"),
+    ("J007.suggestion.2", "Consider raising the memory limit to avoid excessive partitioning"),
+    ("J008.message", "Runtime Filters use {0} of memory"),
+    ("J008.name", "High Runtime Filter memory"),
+    (
+        "J008.reason",
+        "Runtime Filters use too much memory; there may be too many filters or a single filter is too large.",
+    ),
+    ("J008.suggestion.1", "Lower the runtime_filter_max_size setting"),
+    ("J008.suggestion.2", "Check whether the join key cardinality is too high"),
+    ("J009.message", "{0} operator is used, possibly because of a non-equi join"),
+    ("J009.name", "Non-equi join fallback"),
+    (
+        "J009.reason",
+        "The join condition contains non-equality predicates, so a hash join cannot be used and it falls back to a slow Nested Loop Join.",
+    ),
+    ("J009.suggestion.1", "Check whether the JOIN condition contains equality predicates"),
+    ("J009.suggestion.2", "Try rewriting non-equality conditions as equality conditions"),
+    ("J009.suggestion.3", "Consider restructuring the query logic"),
+    (
+        "J010.message",
+        "HashTable ({0}) exceeds the L3 cache and probe rows ({1}) far exceed build rows ({2}); probing may be cache-unfriendly",
+    ),
+    ("J010.name", "Cache-unfriendly probing"),
+    (
+        "J010.reason",
+        "Hash table probing has a low cache hit rate. The HashTable may exceed the CPU cache or the probe access pattern is unfriendly.",
+    ),
+    ("J010.suggestion.1", "Consider swapping the left and right join inputs"),
+    ("J010.suggestion.2", "Use a hint to fix the join order"),
+    ("J010.suggestion.3", "Check whether statistics are accurate"),
+    ("J011.message", "Broadcast Join build side is too large ({0} rows, {1})"),
+    ("J011.name", "Join should not use Broadcast"),
+    (
+        "J011.reason",
+        "When a small table joins a large one, a Broadcast Join sends the small table to every node. A large build side increases network and compute costs. The build side of join condition `{0}` has {1} rows and the probe side {2} rows; inaccurate statistics may have led the optimizer to misestimate table sizes.",
+    ),
+    (
+        "J011.suggestion.1",
+        "Add a [shuffle] hint after the JOIN keyword: SELECT ... FROM a JOIN [shuffle] b ON {0}",
+    ),
+    ("J011.suggestion.2", "Run ANALYZE TABLE <build_table> to refresh statistics"),
+    ("J011.suggestion.3", "Check the join order so that the small table is on the build side"),
+    ("L001.message", "LocalExchange uses {0} of memory"),
+    ("L001.name", "LocalExchange memory too high"),
+    ("L001.reason", "See the StarRocks documentation for more information."),
+    (
+        "L001.suggestion.1",
+        "Check whether data flow between upstream and downstream operators is balanced",
+    ),
+    ("L001.suggestion.2", "Adjust the pipeline_dop parameter"),
+    ("P001.message", "Project expression evaluation takes too much time ({0}%)"),
+    ("P001.name", "Expensive Project expressions"),
+    (
+        "P001.reason",
+        "The Project operator runs too long; expressions may be complex or the data volume large.",
+    ),
+    ("P001.suggestion.1", "Simplify complex expressions in SELECT"),
+    ("P001.suggestion.2", "Precompute complex calculations in a materialized view"),
+    ("P001.suggestion.3", "Check for unnecessary type casts"),
+    ("P002.message", "Common sub-expression evaluation took {0} ({1}% of total expression time)"),
+    ("P002.name", "Expensive common sub-expressions"),
+    (
+        "P002.reason",
+        "Complex CASE WHEN expressions or repeated sub-expressions are expensive. StarRocks tries to extract common sub-expressions to avoid recomputation, but extraction itself has a cost.",
+    ),
+    ("P002.suggestion.1", "Simplify CASE WHEN expressions and reduce the number of branches"),
+    ("P002.suggestion.2", "Precompute complex conditions in a materialized view"),
+    ("P002.suggestion.3", "Check for many repeated expression evaluations"),
+    ("P002.suggestion.4", "Consider IF() instead of simple CASE WHEN"),
+    ("PL001.message.1", "Total HMS metadata fetch time {0}"),
+    ("PL001.message.2", "Total HMS metadata fetch time {0}, slow calls: {1}"),
+    ("PL001.message.3", "Total HMS metadata fetch time {0} ({2}% of {1} time)"),
+    ("PL001.message.4", "Total HMS metadata fetch time {0} ({2}% of {1} time), slow calls: {3}"),
+    ("PL001.name", "Slow HMS metadata fetch"),
+    ("PL001.ratio_base.query", "total query"),
+    (
+        "PL001.reason",
+        "A slow Hive MetaStore blocks query planning; the HMS service may be overloaded or the network slow",
+    ),
+    ("PL001.suggestion.1", "Check the HMS service status and load"),
+    ("PL001.suggestion.2", "Reduce the number of partitions the query touches"),
+    ("PL001.suggestion.3", "Consider enabling the metadata cache"),
+    ("PL001.suggestion.4", "Check network latency"),
+    ("PL002.message", "Optimizer took {0} ({1}% of planner time)"),
+    ("PL002.name", "Optimizer takes too long"),
+    (
+        "PL002.reason",
+        "The query optimizer spent too long, likely because the query is very complex or statistics are inaccurate",
+    ),
+    ("PL002.suggestion.1", "Simplify the query and reduce the number of JOINs and subqueries"),
+    ("PL002.suggestion.2", "Refresh table statistics: ANALYZE TABLE"),
+    ("PL002.suggestion.3", "Check the new_planner_optimize_timeout setting"),
+    ("PL002.suggestion.4", "Consider splitting it into several simpler queries"),
+    ("PL003.message", "Planning took {0}, {1}% of the total query time"),
+    ("PL003.name", "Planning takes a large share of time"),
+    (
+        "PL003.reason",
+        "Query planning took a lot of time, usually because of metadata fetching or the optimizer",
+    ),
+    ("PL003.suggestion.1", "See PL001/PL002 for the specific cause"),
+    ("PL003.suggestion.2", "Reduce the number of tables and partitions the query touches"),
+    ("PL004.message", "The optimizer estimated {0} rows but {1} rows were produced, off by {2}x"),
+    ("PL004.name", "Large cardinality misestimation"),
+    (
+        "PL004.reason",
+        "The row count estimated from statistics differs greatly from the actual row count, so the optimizer may have chosen the wrong join order, join distribution or aggregation strategy. This is usually caused by stale or missing statistics.",
+    ),
+    ("PL004.suggestion.1", "Run ANALYZE TABLE {0}; to refresh the statistics"),
+    ("PL004.suggestion.2", "Check for correlation between filter predicates and join keys"),
+    ("PL004.suggestion.3", "Use EXPLAIN COSTS to compare the optimizer's row estimates"),
+    ("Q001.message", "{0} ran for {1}, exceeding the {2} threshold ({3}, {4})"),
+    ("Q001.name", "Query runs too long"),
+    ("Q001.query_type.analyze", "ANALYZE"),
+    ("Q001.query_type.ctas", "CTAS"),
+    ("Q001.query_type.export", "EXPORT"),
+    ("Q001.query_type.insert", "INSERT load"),
+    ("Q001.query_type.load", "LOAD"),
+    ("Q001.query_type.select", "OLAP query"),
+    ("Q001.query_type.unknown", "Query"),
+    (
+        "Q001.reason.1",
+        "The threshold is the historical baseline P95 + 2σ. This query is significantly slower than similar queries in the past.",
+    ),
+    (
+        "Q001.reason.2",
+        "Default threshold for the query type ({0}). OLAP queries are expected to respond quickly (10s) while ETL jobs may run longer (5-30min).",
+    ),
+    ("Q001.suggestion", "Check for bottleneck operators"),
+    ("Q001.threshold_source.baseline", "adaptive baseline"),
+    ("Q001.threshold_source.default", "default"),
+    ("Q002.message", "Query peak memory {0} exceeds the 10GB threshold"),
+    ("Q002.name", "Query memory too high"),
+    ("Q002.suggestion.1", "Check for joins on large tables"),
+    ("Q002.suggestion.2", "Consider enabling spilling"),
+    ("Q002.suggestion.3", "Optimize the query to reduce intermediate results"),
+    ("Q003.message", "Query spilled to disk, spilled data {0}"),
+    ("Q003.name", "Query spilled to disk"),
+    ("Q003.suggestion.1", "Raise the memory limit to reduce spilling"),
+    ("Q003.suggestion.2", "Check whether spilling affects performance"),
+    ("Q004.message", "CPU utilization is only {0}%; there may be waiting or an IO bottleneck"),
+    ("Q004.name", "Low CPU utilization"),
+    ("Q004.suggestion.1", "Check for waiting"),
+    ("Q004.suggestion.2", "Increase parallelism"),
+    ("Q005.message", "Scan takes {0}% of the time; the query is bound by data scanning"),
+    ("Q005.name", "Scan takes a large share of time"),
+    ("Q005.suggestion.1", "Add filters to reduce scanned data"),
+    ("Q005.suggestion.2", "Consider creating a materialized view"),
+    ("Q005.suggestion.3", "Check storage performance"),
+    ("Q006.message", "Network takes {0}% of the time; the query is bound by network transfer"),
+    ("Q006.name", "Network takes a large share of time"),
+    ("Q006.suggestion.1", "Consider a Colocate Join to reduce shuffling"),
+    ("Q006.suggestion.2", "Check network bandwidth"),
+    ("Q007.message", "Profile collection took {0}ms"),
+    ("Q007.name", "Slow profile collection"),
+    ("Q007.suggestion", "Lower pipeline_profile_level"),
+    ("Q008.message", "Scheduling takes {0}% of the time; pipeline scheduling may be a bottleneck"),
+    ("Q008.name", "Scheduling takes too long"),
+    ("Q008.suggestion", "Check for pipeline scheduling bottlenecks"),
+    ("Q009.message", "Result delivery takes {0}% of the time"),
+    ("Q009.name", "Slow result delivery"),
+    ("Q009.suggestion", "Reduce the result set size"),
+    (
+        "Q010.message",
+        "Drivers of Fragment {0} Pipeline {1} wait {2} in the ready queue on average, {3}% of the driver time",
+    ),
+    ("Q010.name", "Pipeline drivers queue for too long"),
+    (
+        "Q010.reason",
+        "Drivers are ready but wait a long time for an execution thread, so the pipeline execution threads of the BE are saturated, usually by too many concurrent queries or insufficient CPU.",
+    ),
+    ("Q010.suggestion.1", "Check the CPU usage and concurrent queries of the BEs"),
+    ("Q010.suggestion.2", "Use resource groups to limit concurrency or isolate large queries"),
+    ("Q010.suggestion.3", "Avoid manually setting an oversized pipeline_dop"),
+    ("REG001.message", "Query execution time {0} is {2}x the historical P90 ({1})"),
+    ("REG001.name", "Performance regression"),
+    (
+        "REG001.reason",
+        "Similar queries ({0}) ran {1} times historically with P50={2}ms P90={3}ms P99={4}ms; this run is significantly slower than usual.",
+    ),
+    (
+        "REG001.suggestion.1",
+        "Check whether a change in data distribution altered the execution plan",
+    ),
+    ("REG001.suggestion.2", "Check whether concurrent queries compete for resources"),
+    ("REG001.suggestion.3", "Check whether statistics of the related tables are stale"),
+    ("REG001.suggestion.4", "Compare against historical execution plans for differences"),
+    ("S001.message", "Scan data is skewed, max/avg ratio {0} (threshold: {1})"),
+    ("S001.name", "Scan data skew"),
+    (
+        "S001.reason.1",
+        "Data of internal table `{0}` is unevenly distributed across nodes (max {1} rows, min {2} rows). A poorly chosen bucket key usually causes this skew.",
+    ),
+    (
+        "S001.reason.2",
+        "Data of external table `{0}` is unevenly distributed across nodes (max {1} rows, min {2} rows). Hive partitions or files may differ a lot in size.",
+    ),
+    (
+        "S001.suggestion.1",
+        "Check whether the bucket key of table `{0}` is a high-cardinality column",
+    ),
+    (
+        "S001.suggestion.2",
+        "Inspect the distribution: SELECT COUNT(*) FROM {0} GROUP BY <bucket_key> ORDER BY 1 DESC",
+    ),
+    (
+        "S001.suggestion.3",
+        "Rebucket if needed: ALTER TABLE {0} DISTRIBUTED BY HASH(<high_cardinality_column>) BUCKETS N",
+    ),
+    (
+        "S001.suggestion.4",
+        "Check whether the partitions of external table `{0}` hold similar amounts of data",
+    ),
+    ("S001.suggestion.5", "Check for hot partitions or very large files"),
+    ("S001.suggestion.6", "Consider repartitioning or merging small files on the Hive side"),
+    ("S002.message", "Scan IO time is skewed, max/avg ratio {0} (threshold: {1})"),
+    ("S002.name", "Scan IO skew"),
+    (
+        "S002.reason",
+        "Some scan instances spend far more time reading data than others. Node IO usage or data placement may be uneven.",
+    ),
+    ("S002.suggestion.1", "Check whether IO usage is uneven across nodes"),
+    ("S002.suggestion.2", "Check storage devices for performance problems"),
+    (
+        "S003.message",
+        "Poor filtering, only {0}% of the data was filtered (read {1} rows / raw {2} rows)",
+    ),
+    ("S003.name", "Poor filtering"),
+    (
+        "S003.reason.1",
+        "Internal table `{0}` scanned {1} rows but filtered out only {2}%. ZoneMap or BloomFilter indexes and predicate push-down can filter earlier.",
+    ),
+    (
+        "S003.reason.2",
+        "External table `{0}` scanned {1} rows but filtered out only {2}%. External table filtering relies on Hive partition pruning and file format statistics.",
+    ),
+    (
+        "S003.suggestion.1",
+        "Add ZoneMap or BloomFilter indexes on the filter columns of table `{0}`",
+    ),
+    (
+        "S003.suggestion.2",
+        "Check whether WHERE conditions can be pushed down (avoid wrapping columns in functions or casts)",
+    ),
+    ("S003.suggestion.3", "Check whether partitions of table `{0}` can be pruned"),
+    ("S003.suggestion.4", "Use EXPLAIN to check predicate push-down"),
+    (
+        "S003.suggestion.5",
+        "Check whether Hive partitions of external table `{0}` can be pruned (WHERE includes partition columns)",
+    ),
+    (
+        "S003.suggestion.6",
+        "ORC/Parquet files filter with min/max statistics; make sure the files have statistics",
+    ),
+    (
+        "S003.suggestion.7",
+        "Check whether WHERE conditions can be pushed down to the external storage",
+    ),
+    (
+        "S004.message",
+        "Predicates could not be pushed down to storage; {0} rows ({1}%) were filtered in the expression layer",
+    ),
+    ("S004.name", "Predicate not pushed down"),
+    (
+        "S004.reason",
+        "Query conditions were not pushed down to the storage layer, so a lot of data is filtered in the compute layer. The conditions may contain functions, mismatched types or expressions that cannot be pushed down.",
+    ),
+    (
+        "S004.suggestion.1",
+        "Rewrite predicates as simple comparisons (avoid wrapping columns in functions)",
+    ),
+    ("S004.suggestion.2", "Add ZoneMap/Bloom indexes on the filter columns"),
+    ("S004.suggestion.3", "Check whether column types match (avoid implicit casts)"),
+    ("S004.suggestion.4", "Make sure the filter columns are Hive partition columns"),
+    ("S004.suggestion.5", "Check whether the ORC/Parquet files contain statistics"),
+    ("S005.message", "The IO thread pool may be saturated, wait time {0}s"),
+    ("S005.name", "IO thread pool saturated"),
+    (
+        "S005.reason",
+        "The IO thread pool is overused, so IO tasks wait too long. There may be too many concurrent queries or insufficient storage performance.",
+    ),
+    ("S005.suggestion", "Increase max_io_threads on the BEs"),
+    ("S006.message", "Too many rowsets ({0}), initialization took {1}ms"),
+    ("S006.name", "Rowset fragmentation"),
+    (
+        "S006.reason",
+        "Table `{0}` has too many rowsets ({1}), so segment initialization took {2}ms. Frequent small loads or lagging compaction usually cause this.",
+    ),
+    ("S006.suggestion.1", "Trigger a manual compaction: ALTER TABLE {0} COMPACT"),
+    ("S006.suggestion.2", "Check compaction status: SHOW TABLET FROM {0}"),
+    ("S006.suggestion.3", "Batch small load jobs together and load less often"),
+    ("S007.message", "IO takes {0}% of the time and read {1}; storage may be a bottleneck"),
+    ("S007.name", "Cold storage access"),
+    (
+        "S007.reason",
+        "Data lives on cold storage (such as object storage) with high IO latency. Cold storage usually has lower IOPS and throughput than local SSDs.",
+    ),
+    ("S007.suggestion.1", "Check storage performance and consider SSDs"),
+    ("S007.suggestion.2", "Increase the PageCache size"),
+    ("S007.suggestion.3", "Check network bandwidth (for remote storage)"),
+    ("S008.message", "ZoneMap index did not filter any data"),
+    ("S008.name", "ZoneMap index ineffective"),
+    (
+        "S008.reason",
+        "Table `{0}` scanned {1} rows but the ZoneMap filtered nothing. ZoneMap filters on min/max values of the sort key, so WHERE must include a prefix of the sort key columns.",
+    ),
+    ("S008.suggestion.1", "Check the sort key of table `{0}`: SHOW CREATE TABLE {1}"),
+    (
+        "S008.suggestion.2",
+        "Make sure WHERE includes a prefix of the sort key columns (e.g. WHERE dt = '2024-01-01')",
+    ),
+    ("S008.suggestion.3", "Avoid functions on sort key columns (e.g. WHERE DATE(dt) = ...)"),
+    (
+        "S009.message.1",
+        "DataCache hit rate {0}%, {1}% of data read from remote storage (local: {2}, remote: {3})",
+    ),
+    (
+        "S009.message.2",
+        "DataCache IO hit rate {0}%, {1}% of IO went to remote storage (local: {2}, remote: {3})",
+    ),
+    ("S009.message.3", "PageCache hit rate is only {0}% ({1}/{2} pages)"),
+    ("S009.name", "Low cache hit rate"),
+    (
+        "S009.reason.1",
+        "In shared-data deployments DataCache is key to query performance. When much data must be read from remote storage (such as S3/OSS), network latency slows queries significantly.",
+    ),
+    (
+        "S009.reason.2",
+        "In shared-data deployments DataCache is key to query performance. When many IO requests must access remote storage, network latency slows queries significantly.",
+    ),
+    (
+        "S009.reason.3",
+        "The PageCache hit rate is low, so a lot of data is read from disk. The cache may be too small or the access pattern does not suit caching.",
+    ),
+    ("S009.suggestion.1", "Increase the DataCache capacity (datacache_disk_size)"),
+    ("S009.suggestion.2", "Check whether the DataCache disk has enough space"),
+    ("S009.suggestion.3", "Warm up the cache for hot data (CACHE SELECT)"),
+    ("S009.suggestion.4", "Check whether other queries compete for cache resources"),
+    ("S009.suggestion.5", "Increase the PageCache capacity (storage_page_cache_limit)"),
+    ("S009.suggestion.6", "Check whether other queries compete for the cache"),
+    ("S010.message", "Runtime Filters filtered no rows; {0} rows scanned"),
+    ("S010.name", "Scan Runtime Filter ineffective"),
+    (
+        "S010.reason",
+        "Table `{0}` scanned {1} rows but Runtime Filters filtered nothing. The RF build may have failed, timed out or has poor selectivity.",
+    ),
+    ("S010.suggestion.1", "Check whether the join side generated a Runtime Filter for `{0}`"),
+    ("S010.suggestion.2", "Make sure enable_global_runtime_filter = true"),
+    ("S010.suggestion.3", "Check whether the RF was skipped because the build side is too large"),
+    ("S011.message", "Soft-deleted rows make up {1}% of table {0}; run a compaction"),
+    ("S011.name", "Too many accumulated soft deletes"),
+    (
+        "S011.reason",
+        "Table {0} has many soft-deleted records ({1} rows) that every scan must filter out, hurting query performance. Run a compaction to clear the delete markers.",
+    ),
+    ("S011.suggestion.1", "Run compaction: {0}"),
+    ("S011.suggestion.2", "Check compaction status: SHOW PROC '/compactions';"),
+    ("S011.suggestion.3", "Check the table's tablets: SHOW TABLET FROM {0};"),
+    ("S012.message", "Bitmap index filtered no data; expressions filtered {0} rows"),
+    ("S012.name", "Bitmap index ineffective"),
+    (
+        "S012.reason",
+        "Bitmap indexes suit low-cardinality columns with many repeated values (such as gender or status). If such columns are filtered without hitting the index, the index may be missing or the condition unsupported.",
+    ),
+    (
+        "S012.suggestion.1",
+        "Create Bitmap indexes on low-cardinality columns (such as status or type)",
+    ),
+    ("S012.suggestion.2", "Make sure conditions use equality matching (=, IN)"),
+    ("S012.suggestion.3", "Check the BitmapIndexFilterRows metric in the profile"),
+    ("S013.message", "Bloom Filter index filtered no data; expressions filtered {0} rows"),
+    ("S013.name", "Bloom Filter index ineffective"),
+    (
+        "S013.reason",
+        "Bloom Filter indexes suit equality queries on high-cardinality columns (such as IDs). Only = and IN are supported, and TINYINT/FLOAT/DOUBLE/DECIMAL columns are not.",
+    ),
+    ("S013.suggestion.1", "Create Bloom Filter indexes on high-cardinality columns (such as IDs)"),
+    (
+        "S013.suggestion.2",
+        "Note: TINYINT/FLOAT/DOUBLE/DECIMAL columns do not support Bloom Filters",
+    ),
+    ("S013.suggestion.3", "Check the BloomFilterFilterRows metric in the profile"),
+    ("S014.message", "Shuffle Join transferred {0} over the network; consider a Colocate Join"),
+    ("S014.name", "Can be optimized into a Colocate Join"),
+    (
+        "S014.reason",
+        "A Colocate Join avoids network transfer and greatly speeds up joins. It can be used when both tables have the same bucket key and bucket count.",
+    ),
+    ("S014.suggestion.1", "Put frequently joined tables into the same Colocation Group"),
+    ("S014.suggestion.2", "Make sure both tables have the same bucket key and bucket count"),
+    ("S014.suggestion.3", "Use SHOW COLOCATION GROUP to list existing groups"),
+    (
+        "S014.suggestion.4",
+        "A Colocate Join avoids network transfer and greatly improves performance",
+    ),
+    ("S016.message", "Scanned {0} files with an average size of only {1} (recommended > {2})"),
+    ("S016.name", "Too many small files in external table"),
+    (
+        "S016.reason",
+        "{0} external table {1} has many small files, causing high metadata overhead and poor IO efficiency.",
+    ),
+    ("S016.scan_type.s3", "S3/object storage"),
+    ("S016.suggestion.connector.1", "Merge small files to improve query performance"),
+    ("S016.suggestion.connector.2", "Consider loading hot data into StarRocks internal tables"),
+    ("S016.suggestion.connector.3", "Increase the file size setting of upstream writers"),
+    ("S016.suggestion.delta.1", "Run OPTIMIZE {0} ZORDER BY ..."),
+    ("S016.suggestion.delta.2", "Enable Delta Lake auto compaction"),
+    ("S016.suggestion.delta.3", "Tune spark.databricks.delta.autoCompact.minNumFiles"),
+    ("S016.suggestion.file.1", "Merge local small files"),
+    ("S016.suggestion.file.2", "Increase the output file size of the writer"),
+    ("S016.suggestion.hdfs.1", "Merge small files with Hadoop Archive (HAR)"),
+    (
+        "S016.suggestion.hdfs.2",
+        "Increase the output file size of upstream ETL jobs (128MB-256MB recommended)",
+    ),
+    ("S016.suggestion.hdfs.3", "Merge output files with Spark coalesce/repartition"),
+    ("S016.suggestion.hive.1", "Merge small files: INSERT OVERWRITE {0} SELECT * FROM {0}"),
+    (
+        "S016.suggestion.hive.2",
+        "Tune mapreduce.input.fileinputformat.split.minsize of the Hive table",
+    ),
+    ("S016.suggestion.hive.3", "Merge small files with ALTER TABLE CONCATENATE"),
+    ("S016.suggestion.hudi.1", "Run Hudi compaction to merge small files"),
+    ("S016.suggestion.hudi.2", "Tune hoodie.parquet.small.file.limit"),
+    ("S016.suggestion.hudi.3", "Check the compaction strategy of the Hudi table"),
+    ("S016.suggestion.iceberg.1", "Run compaction: CALL rewrite_data_files(table => '{0}')"),
+    ("S016.suggestion.iceberg.2", "Tune write.target-file-size-bytes (128MB-256MB recommended)"),
+    ("S016.suggestion.iceberg.3", "Enable Iceberg automatic compaction"),
+    ("S016.suggestion.paimon.1", "Run Paimon compaction to merge small files"),
+    ("S016.suggestion.paimon.2", "Tune write.target-file-size"),
+    ("S016.suggestion.paimon.3", "Check the compaction settings of the Paimon table"),
+    ("S016.suggestion.s3.1", "Merge small files with Spark/Flink"),
+    ("S016.suggestion.s3.2", "Lower the parallelism of write jobs to produce fewer small files"),
+    ("S016.suggestion.s3.3", "Consider a larger Parquet row group size"),
+    ("S016.suggestion.s3.4", "For Iceberg/Hudi tables, run OPTIMIZE or compaction"),
+    ("S017.message.1", "ORC files contain {0} stripes (average {1}), {2}% are tiny stripes"),
+    ("S017.message.2", "Parquet files contain {0} row groups and are heavily fragmented"),
+    ("S017.name", "File format fragmentation"),
+    (
+        "S017.reason.1",
+        "ORC files of {0} `{1}` are heavily fragmented into stripes ({2} in total), causing many IO requests and file open overhead.",
+    ),
+    (
+        "S017.reason.2",
+        "Parquet files of {0} `{1}` have too many row groups ({2} in total), causing metadata overhead and poor IO efficiency.",
+    ),
+    (
+        "S017.suggestion.1",
+        "Small-file merge options for external tables: ① simple Hive merge: ALTER TABLE {0} PARTITION(...) CONCATENATE; ② recommended rewrite: INSERT OVERWRITE TABLE {1} PARTITION(...) SELECT * FROM {2}; ③ Spark for large data: df.repartition(N).saveAsTable('{3}'); ④ temporary StarRocks tuning: SET connector_io_tasks_per_scan_operator=64",
+    ),
+    ("S017.suggestion.2", "Run compaction to merge fragments: ALTER TABLE {0} COMPACT"),
+    ("S017.table_kind.external", "External table"),
+    ("S017.table_kind.internal", "Internal table"),
+    ("S018.message", "IO wait time {0} ({1}% of total IO time); IO requests are queuing"),
+    ("S018.name", "IO wait too long"),
+    (
+        "S018.reason",
+        "Many concurrent IO requests are waiting in the queue, likely due to file fragmentation or insufficient IO resources",
+    ),
+    ("S018.suggestion.1", "Merge small files to reduce IO requests"),
+    ("S018.suggestion.2", "Increase the io_tasks_per_scan_operator parameter"),
+    ("S018.suggestion.3", "Check the IO performance of the storage system"),
+    ("S018.suggestion.4", "Consider enabling Data Cache for hot data"),
+    ("T001.message", "Too many rows to sort ({0} rows), which may hurt performance"),
+    ("T001.name", "Too many rows to sort"),
+    (
+        "T001.reason",
+        "Sorting too much data consumes a lot of CPU and memory. Add filters to reduce the sorted data or use TopN optimization.",
+    ),
+    ("T002.message", "Sort spilled to disk, spilled data {0}"),
+    ("T002.name", "Sort spilled to disk"),
+    (
+        "T002.reason",
+        "Sort data exceeded the memory limit and spilled to disk. Spilling significantly slows sorting.",
+    ),
+    ("T002.suggestion.1", "Raise the memory limit to avoid spilling"),
+    ("T002.suggestion.2", "Add a LIMIT to reduce the sorted data"),
+    ("T002.suggestion.3", "Check whether the query can be optimized to sort less data"),
+    ("T003.message", "Sort uses {0} of memory"),
+    ("T003.name", "Sort memory too high"),
+    (
+        "T003.reason",
+        "Sort uses too much memory, which may cause memory pressure or affect other operators.",
+    ),
+    ("T004.message", "Sort merge phase takes too much time ({0}%)"),
+    ("T004.name", "Sort merge takes too long"),
+    (
+        "T004.reason",
+        "Multi-way merge sort takes too long; there may be too many merge ways or too much data per way.",
+    ),
+    ("T004.suggestion.1", "Check whether the parallelism setting is reasonable"),
+    ("T004.suggestion.2", "Consider reducing the number of partitions"),
+    ("T005.message", "Merge waits for upstream {0}% of the time"),
+    ("T005.name", "Merge waits too long for upstream"),
+    (
+        "T005.reason",
+        "The merge operator waits too long for upstream data; upstream operators may be a bottleneck.",
+    ),
+    ("T005.suggestion.1", "Optimize the producer operator first"),
+    ("T005.suggestion.2", "Enlarge the pipeline buffer"),
+    ("W001.message", "Window function uses {0} of memory"),
+    ("W001.name", "Window function memory too high"),
+    (
+        "W001.reason",
+        "The window function uses too much memory; window partitions or function states may be too large.",
+    ),
+    ("W001.suggestion.1", "Check whether the PARTITION BY cardinality is too high"),
+    ("W001.suggestion.2", "Consider reducing the window size"),
+    ("W001.suggestion.3", "Check whether an aggregate function can be used instead"),
+    ("aggregate.node_count", "{0} nodes have this issue"),
+    ("common.hours", "{0}h"),
+    ("common.minutes", "{0} min"),
+    ("common.seconds", "{0}s"),
+    ("common.unknown", "unknown"),
+    ("conclusion.healthy", "The query ran well; no obvious performance issues found."),
+    ("conclusion.minor", "The query has {0} minor issues; overall performance is acceptable."),
+    (
+        "conclusion.moderate",
+        "The query has {0} moderate performance issues; overall performance needs tuning. Execution time {1}.",
+    ),
+    (
+        "conclusion.severe",
+        "The query has {0} severe performance issues and ran for a long time ({1}). The main issue is {2}. Resolve the severe issues first.",
+    ),
+    ("conclusion.slow", "The query ran for a long time ({0}); review the performance hotspots."),
+    ("hotspot.parameter", "Tune parameter: {0} → {1} (command: {2})"),
+    ("merge.compaction", "Run compaction: ALTER TABLE <{0}> COMPACT"),
+    (
+        "merge.small_files",
+        "Merge small files of external tables (affected: {0}): ① ALTER TABLE <table> PARTITION(...) CONCATENATE; ② INSERT OVERWRITE TABLE <table> SELECT * FROM <table>; ③ Spark: df.repartition(N).saveAsTable('<table>'); ④ SET connector_io_tasks_per_scan_operator=64",
+    ),
+    ("merge.table_count", "{0} tables"),
+    ("merge.tables_more", "{0} and {1} tables in total"),
+    (
+        "param.connector_io_tasks_per_scan_operator.description",
+        "Number of IO tasks per connector scan operator, controls external table scan parallelism",
+    ),
+    (
+        "param.connector_io_tasks_per_scan_operator.impact",
+        "Higher values increase external scan throughput but add load on remote storage",
+    ),
+    (
+        "param.datacache_evict_probability.description",
+        "DataCache eviction probability (0-100), controls how likely cached data is evicted",
+    ),
+    (
+        "param.datacache_evict_probability.impact",
+        "Lowering it reduces cache churn but may leave too little cache space",
+    ),
+    ("param.default.description", "StarRocks parameter {0}"),
+    ("param.default.impact", "See the StarRocks documentation for details"),
+    (
+        "param.enable_adaptive_sink_dop.description",
+        "Enables adaptive sink parallelism, which adjusts write parallelism to the data volume",
+    ),
+    (
+        "param.enable_adaptive_sink_dop.impact",
+        "Can improve write performance and produce fewer small files",
+    ),
+    (
+        "param.enable_connector_adaptive_io_tasks.description",
+        "Enables adaptive IO task counts for connectors, adjusting IO parallelism to the data volume",
+    ),
+    (
+        "param.enable_connector_adaptive_io_tasks.impact",
+        "Can improve external table scans and balance IO and CPU",
+    ),
+    (
+        "param.enable_global_runtime_filter.reason",
+        "Global Runtime Filters improve join performance",
+    ),
+    (
+        "param.enable_local_shuffle_agg.description",
+        "Enables local shuffle aggregation, pre-aggregating locally first",
+    ),
+    (
+        "param.enable_local_shuffle_agg.impact",
+        "Can reduce network transfer and speed up aggregation",
+    ),
+    (
+        "param.enable_populate_datacache.description",
+        "Enables DataCache population so remotely read data is cached locally",
+    ),
+    (
+        "param.enable_populate_datacache.impact",
+        "Later queries can hit the local cache, but the first query pays an extra write cost",
+    ),
+    ("param.enable_populate_datacache.reason", "Cache population warms up the cache"),
+    (
+        "param.enable_query_cache.description",
+        "Enables the query result cache so identical queries return cached results",
+    ),
+    ("param.enable_query_cache.impact", "Greatly speeds up repeated queries but uses extra memory"),
+    ("param.enable_query_cache.reason", "The query cache speeds up repeated queries"),
+    (
+        "param.enable_runtime_adaptive_dop.description",
+        "Enables runtime adaptive parallelism, which adjusts execution parallelism to the actual data volume",
+    ),
+    (
+        "param.enable_runtime_adaptive_dop.impact",
+        "Improves resource usage and keeps small queries from taking too many resources",
+    ),
+    (
+        "param.enable_scan_datacache.description",
+        "Enables DataCache reads so data is read from the local cache instead of remote storage",
+    ),
+    (
+        "param.enable_scan_datacache.impact",
+        "Improves query performance in shared-data deployments and reduces network IO",
+    ),
+    ("param.enable_scan_datacache.reason", "DataCache improves shared-data performance"),
+    (
+        "param.enable_sort_aggregate.description",
+        "Enables sort-based aggregation, suited to high-cardinality GROUP BY",
+    ),
+    ("param.enable_sort_aggregate.impact", "Can reduce memory usage but adds sorting cost"),
+    (
+        "param.enable_spill.description",
+        "Enables spilling intermediate results to disk when memory is insufficient",
+    ),
+    ("param.enable_spill.impact", "Allows very large queries to finish, but slows them down"),
+    ("param.enable_spill.reason", "Avoids OOM on large queries"),
+    (
+        "param.global_runtime_filter_build_max_size.description",
+        "Maximum build size of global Runtime Filters (bytes)",
+    ),
+    (
+        "param.global_runtime_filter_build_max_size.impact",
+        "Higher values allow larger filters but use more memory",
+    ),
+    (
+        "param.hash_join_push_down_right_table.description",
+        "Enables pushing down the right table of a hash join, broadcasting the small table to all nodes",
+    ),
+    ("param.hash_join_push_down_right_table.impact", "Can reduce shuffling and speed up joins"),
+    ("param.impact", "{0} ({1})"),
+    (
+        "param.io_tasks_per_scan_operator.description",
+        "Number of IO tasks per scan operator, controls local table scan parallelism",
+    ),
+    (
+        "param.io_tasks_per_scan_operator.impact",
+        "Higher values increase scan throughput but add IO pressure",
+    ),
+    ("param.io_tasks_per_scan_operator.reason", "Large scan detected; increase IO parallelism"),
+    (
+        "param.parallel_fragment_exec_instance_num.description",
+        "Number of parallel execution instances per fragment",
+    ),
+    (
+        "param.parallel_fragment_exec_instance_num.impact",
+        "Higher values increase parallelism but use more resources",
+    ),
+    (
+        "param.parallel_fragment_exec_instance_num.reason",
+        "Recommended for a cluster with {0} BE nodes",
+    ),
+    ("param.pipeline_dop.description", "Pipeline execution parallelism, 0 means automatic"),
+    (
+        "param.pipeline_dop.impact",
+        "Setting it manually controls resource usage; automatic mode follows the number of CPU cores",
+    ),
+    (
+        "param.pipeline_dop.reason.1",
+        "Automatic mode is recommended; the system adjusts to the number of CPU cores",
+    ),
+    ("param.pipeline_dop.reason.2", "Automatic mode is recommended"),
+    ("param.pipeline_profile_level.description", "Pipeline profile detail level (0-2)"),
+    (
+        "param.pipeline_profile_level.impact",
+        "Higher levels give more detail but cost more to collect",
+    ),
+    ("param.pipeline_profile_level.reason", "A lower profile level reduces collection overhead"),
+    ("param.query_mem_limit.description", "Memory limit of a single query (bytes)"),
+    (
+        "param.query_mem_limit.impact",
+        "Higher values allow more data per query but may affect other queries",
+    ),
+    ("param.query_mem_limit.reason", "{0}GB memory limit recommended for this data volume"),
+    ("param.query_timeout.description", "Query timeout (seconds)"),
+    (
+        "param.query_timeout.impact",
+        "Higher values allow long-running queries but may hold resources too long",
+    ),
+    ("param.query_timeout.reason", "Longer timeout to allow complex queries"),
+    (
+        "param.runtime_filter_on_exchange_node.description",
+        "Enables Runtime Filters on Exchange nodes to pass filters across nodes",
+    ),
+    (
+        "param.runtime_filter_on_exchange_node.impact",
+        "Filters data earlier and reduces shuffling, but adds filter build cost",
+    ),
+    (
+        "param.runtime_join_filter_push_down_limit.reason",
+        "Higher RF push-down limit supports larger build sides",
+    ),
+    ("param.storage_page_cache_limit.description", "BE storage page cache size limit"),
+    (
+        "param.storage_page_cache_limit.impact",
+        "Higher values speed up hot data reads but use more memory",
+    ),
+    ("param.storage_page_cache_limit.reason", "A larger page cache speeds up hot data reads"),
+    (
+        "param.streaming_preaggregation_mode.description",
+        "Streaming pre-aggregation mode (auto/force_streaming/force_preaggregation)",
+    ),
+    (
+        "param.streaming_preaggregation_mode.impact",
+        "auto picks the best strategy automatically; force modes always use the given strategy",
+    ),
+    (
+        "profile.incomplete",
+        "Profile data is incomplete: {1} of {0} fragments ({2}%) are missing execution data; query again later",
+    ),
+    ("report.causal_chains", "Causal chains"),
+    ("report.command", "Command"),
+    ("report.conclusion", "Conclusion"),
+    ("report.confidence", "Confidence"),
+    ("report.current", "Current"),
+    ("report.diagnostics", "Diagnostics"),
+    ("report.evidence", "Evidence"),
+    ("report.execution_tree", "Execution tree"),
+    ("report.generated_at", "Generated at {0}"),
+    ("report.impact", "Impact"),
+    ("report.message", "Message"),
+    ("report.no_diagnostics", "No diagnostics found"),
+    ("report.node", "Node"),
+    ("report.parameter", "Parameter"),
+    ("report.parameters", "Parameter suggestions"),
+    ("report.query_id", "Query ID"),
+    ("report.recommended", "Recommended"),
+    ("report.root_causes", "Root causes"),
+    ("report.rule", "Rule"),
+    ("report.score", "Performance score"),
+    ("report.severity", "Severity"),
+    ("report.state", "State"),
+    ("report.suggestions", "Suggestions"),
+    ("report.summary", "Query summary"),
+    ("report.title", "Query Profile Analysis Report"),
+    ("report.total_time", "Total time"),
+    ("root_cause.chain", "{0} causes {1}"),
+    (
+        "root_cause.inter.A002_G001",
+        "Oversized aggregation HashTable slows the aggregation operator",
+    ),
+    ("root_cause.inter.A002_Q002", "High aggregation memory raises the query peak memory"),
+    ("root_cause.inter.A002_Q003", "High aggregation memory triggers spilling"),
+    ("root_cause.inter.E001_E002", "Large shuffle raises the network time ratio"),
+    ("root_cause.inter.E001_G001", "Large shuffle slows the EXCHANGE operator"),
+    ("root_cause.inter.E003_G003", "Shuffle skew propagates to execution time skew"),
+    ("root_cause.inter.G001_Q005", "Slow SCAN raises the scan time ratio"),
+    ("root_cause.inter.G001b_Q005", "Second slowest SCAN raises the scan time ratio"),
+    ("root_cause.inter.G002_Q002", "High node memory raises the query peak memory"),
+    ("root_cause.inter.J001_A002", "Large join output sends too much data to aggregation"),
+    ("root_cause.inter.J001_G001", "Large join input slows the join operator"),
+    ("root_cause.inter.J001_Q003", "High join memory triggers spilling"),
+    ("root_cause.inter.J003_A001", "Join skew propagates to aggregation skew"),
+    ("root_cause.inter.J003_G001", "Oversized join HashTable slows the join operator"),
+    ("root_cause.inter.J003_Q002", "High join memory raises the query peak memory"),
+    ("root_cause.inter.J005_G002", "Hash collisions raise node memory"),
+    ("root_cause.inter.J009_A002", "Cartesian product causes a downstream data explosion"),
+    ("root_cause.inter.J009_T001", "Cartesian product causes a sort volume explosion"),
+    ("root_cause.inter.J011_G002", "Large broadcast data raises node memory"),
+    (
+        "root_cause.inter.PL004_A002",
+        "Cardinality misestimation makes the aggregation HashTable too large",
+    ),
+    (
+        "root_cause.inter.PL004_A006",
+        "Cardinality misestimation picks an unsuitable aggregation strategy",
+    ),
+    (
+        "root_cause.inter.PL004_J002",
+        "Cardinality underestimation picks the wrong join order with an oversized build side",
+    ),
+    ("root_cause.inter.PL004_J003", "Cardinality misestimation makes the HashTable too large"),
+    ("root_cause.inter.PL004_J011", "Cardinality underestimation wrongly chooses a broadcast join"),
+    ("root_cause.inter.Q002_Q001", "Heavy memory pressure may cause a query timeout"),
+    ("root_cause.inter.Q003_G001", "Spilling to disk slows down nodes"),
+    ("root_cause.inter.Q008_Q001", "High scheduling overhead causes a query timeout"),
+    ("root_cause.inter.S001_A001", "SCAN data skew propagates to aggregation skew"),
+    ("root_cause.inter.S001_E003", "SCAN data skew propagates to shuffle partition skew"),
+    ("root_cause.inter.S001_G003", "SCAN data skew propagates to downstream execution time skew"),
+    ("root_cause.inter.S001_J003", "SCAN data skew propagates to the join probe side"),
+    ("root_cause.inter.S002_E001", "Full table scan causes a large shuffle"),
+    ("root_cause.inter.S002_J001", "Full table scan sends too much data to joins"),
+    ("root_cause.inter.S003_A002", "Poor filtering sends too much data to aggregation"),
+    ("root_cause.inter.S003_E001", "Poor filtering causes a large shuffle"),
+    ("root_cause.inter.S003_J001", "Poor filtering sends too much data to downstream joins"),
+    ("root_cause.inter.S003_T001", "Poor filtering causes a large sort volume"),
+    ("root_cause.inter.S007_G001", "IO bottleneck slows down SCAN nodes"),
+    ("root_cause.inter.S017_G001", "Stripe fragmentation slows down SCAN nodes"),
+    ("root_cause.inter.S017_S018", "Stripe fragmentation causes IO waits"),
+    ("root_cause.inter.S018_G001", "IO waits slow down nodes"),
+    ("root_cause.inter.S018_Q004", "IO waits cause low CPU utilization"),
+    ("root_cause.inter.T001_Q003", "Large sort volume triggers spilling"),
+    ("root_cause.inter.W001_Q003", "Window function memory triggers spilling"),
+    ("root_cause.intra.A001_G003", "Aggregation skew causes execution time skew"),
+    ("root_cause.intra.A001_I002", "Aggregation skew causes load data skew"),
+    ("root_cause.intra.A001_Q003", "Aggregation skew causes memory spilling"),
+    ("root_cause.intra.A002_G002", "Aggregation HashTable memory raises node memory"),
+    ("root_cause.intra.A002_Q003", "Oversized aggregation hash table causes spilling"),
+    ("root_cause.intra.A003_A002", "Too many distinct keys make the hash table too large"),
+    ("root_cause.intra.A004_A002", "Not using streaming aggregation causes high memory usage"),
+    ("root_cause.intra.A005_G002", "High aggregation cardinality causes high CPU usage"),
+    ("root_cause.intra.E001_E002", "Large shuffle causes a network bottleneck"),
+    ("root_cause.intra.E001_Q008", "Large shuffle adds scheduling overhead"),
+    ("root_cause.intra.E002_I001", "Network bottleneck slows data loading"),
+    ("root_cause.intra.E002_Q004", "Network waits cause low CPU utilization"),
+    ("root_cause.intra.E003_G003", "Partition skew causes execution time skew"),
+    ("root_cause.intra.G001_G001b_Q005", "Slow scan operators raise the scan time ratio"),
+    ("root_cause.intra.G001_Q001", "Time-consuming operators cause a query timeout"),
+    ("root_cause.intra.G002_Q002", "High operator memory raises the query peak memory"),
+    ("root_cause.intra.J001_Q003", "Oversized hash table spills memory to disk"),
+    ("root_cause.intra.J002_J001", "Suboptimal join order makes the hash table too large"),
+    ("root_cause.intra.J003_G002", "Join HashTable memory raises node memory"),
+    ("root_cause.intra.J003_G003", "Join probe side skew causes execution time skew"),
+    ("root_cause.intra.J005_E002", "Oversized broadcast table causes a network bottleneck"),
+    ("root_cause.intra.J005_J003", "Hash collisions enlarge HashTable memory"),
+    (
+        "root_cause.intra.J006_S003",
+        "Missing Runtime Filter causes poor filtering on the probe side",
+    ),
+    ("root_cause.intra.J007_S003", "Runtime Filter not pushed down causes poor filtering"),
+    ("root_cause.intra.J008_J001", "Suboptimal join condition makes the hash table too large"),
+    ("root_cause.intra.J009_G002", "Cartesian product causes high CPU usage"),
+    ("root_cause.intra.J010_E002", "Suboptimal join type transfers too much data"),
+    ("root_cause.intra.J011_G002", "Large broadcast side raises node memory"),
+    ("root_cause.intra.J011_J003", "Large broadcast side raises HashTable memory"),
+    ("root_cause.intra.P001_G002", "Complex expression evaluation causes high CPU usage"),
+    ("root_cause.intra.Q003_Q001", "Spilling to disk slows the query and may cause a timeout"),
+    ("root_cause.intra.Q006_Q001", "Waiting in the resource queue may cause a timeout"),
+    ("root_cause.intra.Q008_Q001", "High scheduling overhead slows the query"),
+    ("root_cause.intra.S001_G003", "Data skew causes execution time skew"),
+    ("root_cause.intra.S003_S002", "Poor filtering causes a full table scan"),
+    ("root_cause.intra.S007_Q004", "IO bottleneck causes low CPU utilization"),
+    ("root_cause.intra.S008_S012_S013_S003", "Ineffective indexes cause poor filtering"),
+    ("root_cause.intra.S009_G001", "Low cache hit rate slows down SCAN"),
+    ("root_cause.intra.S009_Q004", "Cache misses wait on remote IO and leave the CPU idle"),
+    ("root_cause.intra.S009_S007", "Low cache hit rate causes an IO bottleneck"),
+    ("root_cause.intra.S010_S007", "High compression ratio causes heavy decompression cost"),
+    ("root_cause.intra.S014_S007", "Too many segments cause an IO bottleneck"),
+    ("root_cause.intra.S016_S006_S007", "Small files / fragmentation cause an IO bottleneck"),
+    ("root_cause.intra.S017_G001", "Stripe fragmentation slows down SCAN"),
+    ("root_cause.intra.S017_S007", "Stripe fragmentation causes an IO bottleneck"),
+    ("root_cause.intra.S017_S018", "Stripe fragmentation causes long IO waits"),
+    ("root_cause.intra.S018_G001", "Long IO waits slow down SCAN"),
+    ("root_cause.intra.S018_Q004", "IO waits leave the CPU idle"),
+    ("root_cause.intra.T001_Q003", "Too much sort data causes spilling"),
+    ("root_cause.intra.T002_T001", "Unoptimized TopN causes a large sort volume"),
+    ("root_cause.intra.T003_T001", "Full sort causes too much data"),
+    ("root_cause.intra.T004_G002", "Multiple sort keys cause high CPU usage"),
+    (
+        "root_cause.summary_multiple",
+        "Found {0} independent root causes, mainly: {1}. Resolve them in order of priority",
+    ),
+    ("root_cause.summary_none", "No obvious performance root cause found"),
+    ("root_cause.summary_single", "Found 1 root cause: {0}"),
+    (
+        "root_cause.summary_single_with_symptoms",
+        "Found 1 root cause: {0}, which led to {1} downstream issues",
+    ),
+];
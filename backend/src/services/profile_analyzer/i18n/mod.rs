@@ -0,0 +1,382 @@
+//! Localization of profile analysis output
+//!
+//! Rules, root cause analysis and the rule engine never produce user-facing text directly.
+//! They emit a [`Message`]: a catalog key such as `A001.message` plus positional parameters.
+//! The text is rendered from the bundle of the requested locale (`zh.rs` or `en.rs`) when the
+//! `ProfileAnalysisResponse` is assembled. Parameters can be nested messages, e.g. a rule
+//! name or a duration, which are rendered in the same locale.
+//!
+//! A key missing from the requested bundle falls back to the Chinese bundle and then to the
+//! key itself, so a missing translation never turns into an empty string.
+
+mod en;
+mod zh;
+
+use once_cell::sync::Lazy;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// Output language of profile diagnostics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    Zh,
+    En,
+}
+
+impl Locale {
+    /// Parse a language tag such as `en`, `en-US` or `zh_CN`
+    pub fn parse(tag: &str) -> Option<Self> {
+        let primary = tag.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+        match primary.as_str() {
+            "zh" => Some(Locale::Zh),
+            "en" => Some(Locale::En),
+            _ => None,
+        }
+    }
+
+    /// Pick the preferred supported locale from an `Accept-Language` header value
+    pub fn from_accept_language(header: &str) -> Option<Self> {
+        let mut candidates: Vec<(Locale, f32)> = header
+            .split(',')
+            .filter_map(|part| {
+                let mut pieces = part.split(';');
+                let locale = Locale::parse(pieces.next()?)?;
+                let quality = pieces
+                    .find_map(|p| p.trim().strip_prefix("q="))
+                    .and_then(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                (quality > 0.0).then_some((locale, quality))
+            })
+            .collect();
+        // Stable sort keeps header order among equal weights
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+        candidates.first().map(|(locale, _)| *locale)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::Zh => "zh",
+            Locale::En => "en",
+        }
+    }
+
+    fn bundle(&self) -> &'static HashMap<&'static str, &'static str> {
+        static ZH: Lazy<HashMap<&str, &str>> = Lazy::new(|| zh::MESSAGES.iter().copied().collect());
+        static EN: Lazy<HashMap<&str, &str>> = Lazy::new(|| en::MESSAGES.iter().copied().collect());
+        match self {
+            Locale::Zh => &ZH,
+            Locale::En => &EN,
+        }
+    }
+}
+
+/// A catalog key with its positional parameters, rendered lazily for a locale
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Message {
+    key: Cow<'static, str>,
+    args: Vec<Arg>,
+}
+
+/// Parameter of a [`Message`], substituted for `{0}`, `{1}`, ... in the template
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Arg {
+    /// Locale independent text: table names, numbers, SQL
+    Text(String),
+    /// Text that is itself localized
+    Message(Message),
+}
+
+impl<T: Display> From<T> for Arg {
+    fn from(value: T) -> Self {
+        Arg::Text(value.to_string())
+    }
+}
+
+impl From<Message> for Arg {
+    fn from(message: Message) -> Self {
+        Arg::Message(message)
+    }
+}
+
+impl From<&Message> for Arg {
+    fn from(message: &Message) -> Self {
+        Arg::Message(message.clone())
+    }
+}
+
+impl From<&Arg> for Arg {
+    fn from(arg: &Arg) -> Self {
+        arg.clone()
+    }
+}
+
+impl Message {
+    pub fn new(key: impl Into<Cow<'static, str>>) -> Self {
+        Self { key: key.into(), args: Vec::new() }
+    }
+
+    /// Append the next positional parameter
+    pub fn arg(mut self, arg: impl Into<Arg>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn args(&self) -> &[Arg] {
+        &self.args
+    }
+
+    /// Render the message from the bundle of the given locale
+    pub fn render(&self, locale: Locale) -> String {
+        let template = locale
+            .bundle()
+            .get(self.key.as_ref())
+            .or_else(|| Locale::Zh.bundle().get(self.key.as_ref()))
+            .copied()
+            .unwrap_or(self.key.as_ref());
+
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            let index = after
+                .find('}')
+                .and_then(|end| after[..end].parse::<usize>().ok().map(|i| (i, end)));
+            match index {
+                Some((i, end)) => {
+                    if let Some(arg) = self.args.get(i) {
+                        match arg {
+                            Arg::Text(text) => out.push_str(text),
+                            Arg::Message(message) => out.push_str(&message.render(locale)),
+                        }
+                    }
+                    rest = &after[end + 1..];
+                },
+                None => {
+                    out.push('{');
+                    rest = after;
+                },
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// Build a [`Message`] from a catalog key and its parameters
+///
+/// `msg!("A001.message", ratio, max)` is `Message::new("A001.message").arg(&ratio).arg(&max)`;
+/// like `format!`, parameters are taken by reference.
+macro_rules! msg {
+    ($key:expr $(, $arg:expr)* $(,)?) => {
+        $crate::services::profile_analyzer::i18n::Message::new($key)$(.arg(&$arg))*
+    };
+}
+pub(crate) use msg;
+
+/// Render a list of messages, e.g. the suggestions of a diagnostic
+pub fn render_all(messages: &[Message], locale: Locale) -> Vec<String> {
+    messages.iter().map(|m| m.render(locale)).collect()
+}
+
+/// Look up a catalog entry by key, e.g. the section labels of exported reports
+///
+/// Falls back to the key itself when the entry is missing.
+pub fn message(key: &'static str, locale: Locale) -> &'static str {
+    locale.bundle().get(key).copied().unwrap_or(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::profile_analyzer::analyzer::rules;
+    use crate::services::profile_analyzer::{AnalysisContext, analyze_profile_with_context};
+    use regex::Regex;
+    use std::collections::BTreeSet;
+
+    fn bundle(messages: &[(&'static str, &'static str)]) -> HashMap<&'static str, &'static str> {
+        messages.iter().copied().collect()
+    }
+
+    fn placeholders(text: &str) -> BTreeSet<String> {
+        let placeholder = Regex::new(r"\{\d+\}").unwrap();
+        placeholder
+            .find_iter(text)
+            .map(|m| m.as_str().to_string())
+            .collect()
+    }
+
+    fn contains_han(text: &str) -> bool {
+        text.chars()
+            .any(|c| matches!(c, '\u{4e00}'..='\u{9fff}' | '\u{3400}'..='\u{4dbf}'))
+    }
+
+    fn assert_translated(key: &str) {
+        assert!(Locale::Zh.bundle().contains_key(key), "missing zh entry: {key}");
+        assert!(Locale::En.bundle().contains_key(key), "missing en entry: {key}");
+    }
+
+    #[test]
+    fn test_bundles_have_same_keys_and_placeholders() {
+        let zh = bundle(zh::MESSAGES);
+        let en = bundle(en::MESSAGES);
+        assert_eq!(zh.len(), zh::MESSAGES.len(), "duplicate key in zh bundle");
+        assert_eq!(en.len(), en::MESSAGES.len(), "duplicate key in en bundle");
+
+        let zh_keys: BTreeSet<_> = zh.keys().collect();
+        let en_keys: BTreeSet<_> = en.keys().collect();
+        assert_eq!(zh_keys, en_keys);
+
+        for (key, source) in &zh {
+            assert_eq!(placeholders(source), placeholders(en[key]), "placeholders differ: {key}");
+            assert!(!contains_han(en[key]), "untranslated en entry: {key}");
+        }
+    }
+
+    #[test]
+    fn test_every_rule_has_localized_name() {
+        let mut ids: Vec<String> = rules::get_all_rules()
+            .iter()
+            .map(|r| r.id().to_string())
+            .collect();
+        ids.extend(rules::get_query_rules().iter().map(|r| r.id().to_string()));
+        ids.extend(
+            rules::planner::get_rules()
+                .iter()
+                .map(|r| r.id().to_string()),
+        );
+        ids.extend(
+            rules::explain::get_rules()
+                .iter()
+                .map(|r| r.id().to_string()),
+        );
+        ids.push("REG001".to_string());
+        ids.push("PL004".to_string());
+
+        for id in ids {
+            assert_translated(&format!("{}.name", id));
+        }
+        for name in rules::DOCUMENTED_PARAMETERS {
+            assert_translated(&format!("param.{}.description", name));
+            assert_translated(&format!("param.{}.impact", name));
+        }
+    }
+
+    /// Every literal key passed to `msg!` in the analyzer must exist in both bundles
+    #[test]
+    fn test_every_emitted_key_is_translated() {
+        let key_pattern = Regex::new(r#"msg!\(\s*"([^"]+)""#).unwrap();
+        let root = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/services/profile_analyzer");
+        let mut dirs = vec![root];
+        let mut keys = BTreeSet::new();
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else if path.extension().is_some_and(|ext| ext == "rs") {
+                    let source = std::fs::read_to_string(&path).unwrap();
+                    keys.extend(key_pattern.captures_iter(&source).map(|c| c[1].to_string()));
+                }
+            }
+        }
+
+        assert!(keys.len() > 300, "only found {} keys", keys.len());
+        for key in keys {
+            assert_translated(&key);
+        }
+    }
+
+    #[test]
+    fn test_render_message() {
+        let message = msg!("A001.message", "3.20", "2.0");
+        assert_eq!(message.render(Locale::Zh), "聚合存在数据倾斜，max/avg 比率为 3.20 (阈值: 2.0)");
+        assert_eq!(
+            message.render(Locale::En),
+            "Aggregation data is skewed, max/avg ratio 3.20 (threshold: 2.0)"
+        );
+
+        // Nested messages are rendered in the same locale
+        let summary = msg!("root_cause.summary_single", msg!("root_cause.intra.S001_G003"));
+        assert_eq!(summary.render(Locale::Zh), "发现 1 个根因: 数据倾斜导致执行时间倾斜");
+        assert_eq!(
+            summary.render(Locale::En),
+            "Found 1 root cause: Data skew causes execution time skew"
+        );
+        assert_eq!(
+            msg!("conclusion.slow", msg!("common.minutes", 3)).render(Locale::En),
+            "The query ran for a long time (3 min); review the performance hotspots."
+        );
+
+        // Unknown keys degrade to the key itself
+        assert_eq!(Message::new("missing.key").render(Locale::En), "missing.key");
+    }
+
+    #[test]
+    fn test_message_by_key() {
+        assert_eq!(message("report.diagnostics", Locale::Zh), "诊断结果");
+        assert_eq!(message("report.diagnostics", Locale::En), "Diagnostics");
+        assert_eq!(message("report.missing", Locale::En), "report.missing");
+    }
+
+    fn collect_han(value: &serde_json::Value, out: &mut BTreeSet<String>) {
+        match value {
+            serde_json::Value::String(s) if contains_han(s) => {
+                out.insert(s.clone());
+            },
+            serde_json::Value::Array(items) => items.iter().for_each(|v| collect_han(v, out)),
+            serde_json::Value::Object(map) => map.values().for_each(|v| collect_han(v, out)),
+            _ => {},
+        }
+    }
+
+    #[test]
+    fn test_fixture_analysis_fully_translated() {
+        let dir =
+            std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/profiles");
+        let context = AnalysisContext { locale: Locale::En, ..Default::default() };
+        let mut untranslated = BTreeSet::new();
+
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "png") {
+                continue;
+            }
+            let text = std::fs::read_to_string(&path).unwrap();
+            let Ok(response) = analyze_profile_with_context(&text, &context) else {
+                continue;
+            };
+
+            // Only the analyzer's own output; the profile itself may contain Chinese SQL
+            let output = serde_json::json!({
+                "hotspots": response.hotspots,
+                "conclusion": response.conclusion,
+                "suggestions": response.suggestions,
+                "diagnostics": response.diagnostics,
+                "aggregated_diagnostics": response.aggregated_diagnostics,
+                "root_cause_analysis": response.root_cause_analysis,
+            });
+            collect_han(&output, &mut untranslated);
+        }
+
+        assert!(untranslated.is_empty(), "untranslated output: {:#?}", untranslated);
+    }
+
+    #[test]
+    fn test_accept_language() {
+        assert_eq!(Locale::from_accept_language("en-US,en;q=0.9"), Some(Locale::En));
+        assert_eq!(Locale::from_accept_language("zh-CN,zh;q=0.9,en;q=0.8"), Some(Locale::Zh));
+        assert_eq!(Locale::from_accept_language("fr-FR, en;q=0.5, zh;q=0.7"), Some(Locale::Zh));
+        assert_eq!(Locale::from_accept_language("de, fr;q=0.8"), None);
+        assert_eq!(Locale::from_accept_language("zh;q=0, en;q=0.1"), Some(Locale::En));
+        assert_eq!(Locale::parse("zh_CN"), Some(Locale::Zh));
+        assert_eq!(Locale::parse("EN"), Some(Locale::En));
+    }
+}
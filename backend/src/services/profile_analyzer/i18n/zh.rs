@@ -0,0 +1,925 @@
+//! Chinese (default) message catalog. Keys are shared with `en.rs`; values use
+//! `{0}`-style placeholders that match the positional arguments of the rule templates.
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    ("A001.message", "聚合存在数据倾斜，max/avg 比率为 {0} (阈值: {1})"),
+    ("A001.name", "聚合数据倾斜"),
+    (
+        "A001.reason",
+        "聚合算子多个实例处理的数据量存在明显差异，部分实例成为瓶颈。通常是 GROUP BY 键的数据分布不均匀导致。",
+    ),
+    ("A001.suggestion.1", "检查 GROUP BY 键的数据分布"),
+    ("A001.suggestion.2", "考虑使用两阶段聚合"),
+    ("A001.suggestion.3", "检查是否存在热点键"),
+    ("A002.message", "聚合 HashTable 内存使用 {0} (阈值: {1})"),
+    ("A002.name", "聚合 HashTable 过大"),
+    (
+        "A002.reason",
+        "HashTable 占用内存过大，可能导致内存压力或触发 Spill。通常是 GROUP BY 键基数过高或聚合函数状态过大。",
+    ),
+    ("A002.suggestion.1", "检查 GROUP BY 基数是否过高"),
+    ("A002.suggestion.2", "考虑使用物化视图预聚合"),
+    ("A002.suggestion.3", "启用 Spill 功能避免 OOM"),
+    ("A003.message", "聚合存在数据倾斜，max/avg 比率为 {0}"),
+    ("A003.name", "聚合数据倾斜"),
+    ("A003.reason", "聚合算子的输入数据在各个实例间分布不均匀，导致部分实例处理更多数据。"),
+    ("A003.suggestion.1", "优化分组键选择"),
+    ("A003.suggestion.2", "考虑对热点键单独处理"),
+    ("A004.message", "GROUP BY 基数过高 ({0} 个分组)"),
+    ("A004.name", "高基数 GROUP BY"),
+    (
+        "A004.reason",
+        "GROUP BY 键「{0}」的基数过高（{1} 个唯一值），导致 HashTable 占用 {2} 内存，聚合效率下降。",
+    ),
+    ("A004.suggestion.1", "检查 GROUP BY 键「{0}」是否都必要，减少不必要的分组列"),
+    (
+        "A004.suggestion.2",
+        "考虑使用流式聚合: SET streaming_preaggregation_mode = 'force_streaming'",
+    ),
+    ("A004.suggestion.3", "考虑创建物化视图预聚合常用分组"),
+    ("A005.message", "GROUP BY 键表达式计算占比过高 ({0}%)"),
+    ("A005.name", "GROUP BY 键表达式计算开销高"),
+    (
+        "A005.reason",
+        "GROUP BY 键包含复杂表达式，每行数据都需要计算表达式，增加 CPU 开销。建议将表达式提前计算或使用生成列。",
+    ),
+    ("A005.suggestion.1", "在子查询中物化复杂表达式"),
+    ("A005.suggestion.2", "将表达式提升为生成列"),
+    ("A005.suggestion.3", "避免在 GROUP BY 中使用复杂函数"),
+    ("A006.message", "本地聚合效果差，聚合比 {0}:1 (输入 {1} 行 → 输出 {2} 行)"),
+    ("A006.name", "Aggregate 本地聚合度低"),
+    (
+        "A006.reason",
+        "GROUP BY「{0}」在本地聚合时，输入 {1} 行仅聚合为 {2} 行（缩减比 {3}:1），未能有效减少数据量。这会增加网络传输和后续计算开销。",
+    ),
+    (
+        "A006.suggestion.1",
+        "GROUP BY「{0}」基数可能过高，考虑关闭二阶段聚合: SET new_planner_agg_stage = 1",
+    ),
+    ("A006.suggestion.2", "检查 GROUP BY 键是否包含高基数列（如 ID、时间戳）"),
+    ("A006.suggestion.3", "考虑在数据写入时预聚合或使用物化视图"),
+    ("E001.message", "网络传输数据量 {0}，可能存在网络瓶颈"),
+    ("E001.name", "网络传输数据量大"),
+    (
+        "E001.reason",
+        "网络传输数据量过大，占用大量网络带宽和时间。可能是 Shuffle 数据量大或缺少有效的数据裁剪。",
+    ),
+    ("E001.suggestion.1", "检查是否可以减少 Shuffle 数据量"),
+    ("E001.suggestion.2", "考虑使用 Colocate Join 避免 Shuffle"),
+    ("E002.message", "网络时间占比 {0}%，可能存在网络瓶颈"),
+    ("E002.name", "网络时间占比高"),
+    ("E002.reason", "网络传输时间占比过高，查询瓶颈在网络。可能是网络带宽不足或跨机房传输。"),
+    ("E002.suggestion.1", "检查网络带宽和延迟"),
+    ("E002.suggestion.2", "考虑使用 Colocate Join"),
+    ("E002.suggestion.3", "减少跨节点数据传输"),
+    ("E003.message", "Shuffle 存在数据倾斜，max/avg 比率为 {0}"),
+    ("E003.name", "Shuffle 数据倾斜"),
+    (
+        "E003.reason",
+        "Shuffle 数据在各节点间分布不均匀，部分节点接收更多数据。通常是 Shuffle 键存在热点值。",
+    ),
+    ("E003.suggestion.1", "检查分区键选择是否合理"),
+    ("E003.suggestion.2", "考虑使用 Skew Join 优化"),
+    ("E003.suggestion.3", "检查是否存在热点数据"),
+    ("EX001.message", "{0} 没有关联条件，将产生笛卡尔积 (预估输出 {1} 行)"),
+    ("EX001.name", "笛卡尔积 Join"),
+    (
+        "EX001.reason",
+        "CROSS JOIN 会把两侧的每一行两两组合，输出行数是两侧行数的乘积，数据量稍大就会导致查询超时或内存不足。",
+    ),
+    ("EX001.suggestion.1", "检查是否遗漏了 JOIN 条件"),
+    ("EX001.suggestion.2", "将关联条件改写为等值条件以使用 Hash Join"),
+    ("EX002.message", "Broadcast Join 预估广播 {0} 行 (阈值: {1} 行)"),
+    ("EX002.name", "Broadcast 大表"),
+    (
+        "EX002.reason",
+        "Broadcast Join 会把右表复制到每个 BE 节点并构建 HashTable，右表过大时网络传输和内存开销成倍增加。优化器选择 Broadcast 通常是因为统计信息缺失或过期导致低估了右表大小。",
+    ),
+    ("EX002.suggestion.1", "使用 JOIN [shuffle] Hint 改为 Shuffle Join"),
+    ("EX002.suggestion.2", "执行 ANALYZE TABLE 更新右表的统计信息"),
+    ("EX003.message", "表 {0} 扫描了全部 {1} 个分区，未发生分区裁剪"),
+    ("EX003.name", "分区未裁剪"),
+    (
+        "EX003.reason",
+        "查询条件没有命中分区列，需要读取所有分区的数据。在分区列上添加过滤条件可以大幅减少扫描量。",
+    ),
+    ("EX003.suggestion.1", "在 WHERE 条件中添加分区列过滤"),
+    ("EX003.suggestion.2", "避免对分区列使用函数或类型转换，否则无法裁剪分区"),
+    ("EX004.message", "{0} 上的过滤条件未下推到扫描节点 {1}"),
+    ("EX004.name", "谓词未下推"),
+    (
+        "EX004.reason",
+        "过滤条件在扫描之后单独执行，存储层无法利用分区、索引和 ZoneMap 跳过数据，扫描量和传输量都会增加。常见原因是谓词中使用了函数、UDF、非确定性表达式或跨列的 OR 条件。",
+    ),
+    ("EX004.suggestion.1", "避免在过滤列上使用函数或隐式类型转换"),
+    ("EX004.suggestion.2", "将复杂表达式改写为可下推的简单比较条件"),
+    ("EX005.message", "{0} 对预估 {1} 行做全量排序且没有 LIMIT"),
+    ("EX005.name", "ORDER BY 未带 LIMIT"),
+    (
+        "EX005.reason",
+        "没有 LIMIT 的 ORDER BY 需要对全部结果排序并全部返回给客户端，数据量大时会占用大量内存并可能触发落盘。",
+    ),
+    ("EX005.suggestion.1", "如果只需要前 N 行，添加 LIMIT 以使用 TopN 优化"),
+    ("EX005.suggestion.2", "去掉不必要的 ORDER BY，由客户端排序"),
+    ("EX006.message", "外表 {0} 扫描了全部 {1} 个分区，没有分区过滤条件"),
+    ("EX006.name", "外表扫描无分区过滤"),
+    (
+        "EX006.reason",
+        "外表 (Hive/Iceberg/Hudi 等) 没有分区过滤时，需要从元数据服务获取并读取所有分区的文件，元数据获取和远端扫描都会很慢。",
+    ),
+    ("EX006.suggestion.1", "在 WHERE 条件中添加分区列过滤"),
+    ("EX006.suggestion.2", "确认过滤值的类型与分区列类型一致"),
+    ("F001.message", "实例执行时间存在倾斜，max/avg 比率为 {0}"),
+    ("F001.name", "实例执行时间倾斜"),
+    (
+        "F001.reason",
+        "Fragment 执行时间过长，是查询的主要瓶颈。需要分析 Fragment 内的算子找出具体问题。",
+    ),
+    ("F001.suggestion.1", "检查数据分布"),
+    ("F001.suggestion.2", "优化分桶策略"),
+    ("F002.message", "实例内存分配不均，max/avg 比率为 {0}"),
+    ("F002.name", "实例内存分配不均"),
+    ("F002.reason", "Fragment 内存使用过高，可能导致查询失败。"),
+    ("F002.suggestion", "检查数据倾斜"),
+    ("F003.message", "Fragment 准备时间 {0}s"),
+    ("F003.name", "Fragment 准备时间过长"),
+    ("F003.reason", "Fragment 各实例执行时间差异大，存在数据倾斜或资源不均。"),
+    ("F003.suggestion", "检查元数据加载"),
+    ("G001.message", "🔴 算子 {0} 占用 {1}% 的执行时间（最耗时节点）"),
+    ("G001.name", "算子时间占比过高"),
+    (
+        "G001.reason",
+        "算子执行时间占整体查询时间比例过高，是查询的主要瓶颈。优化该算子可获得最大收益。",
+    ),
+    ("G001.suggestion.1", "检查是否可以添加过滤条件减少扫描数据量"),
+    ("G001.suggestion.2", "检查分区裁剪是否生效"),
+    ("G001.suggestion.3", "执行 ANALYZE TABLE 更新统计信息"),
+    ("G001.suggestion.4", "检查 JOIN 顺序是否最优"),
+    ("G001.suggestion.5", "考虑使用 Runtime Filter"),
+    ("G001.suggestion.6", "检查是否存在数据倾斜"),
+    ("G001.suggestion.7", "检查聚合模式是否合适"),
+    ("G001.suggestion.8", "考虑使用预聚合或物化视图"),
+    ("G001.suggestion.9", "检查 GROUP BY 键的选择"),
+    ("G001.suggestion.10", "考虑调整并行度"),
+    ("G001.suggestion.11", "检查网络带宽是否充足"),
+    ("G001.suggestion.12", "添加 LIMIT 限制结果集大小"),
+    ("G001.suggestion.13", "检查是否可以使用 Top-N 优化"),
+    ("G001.suggestion.14", "考虑使用物化视图预排序"),
+    ("G001.suggestion.15", "检查该算子是否处理数据量过大"),
+    ("G001.suggestion.16", "考虑优化查询计划"),
+    ("G001b.message", "🟠 算子 {0} 占用 {1}% 的执行时间（次耗时节点）"),
+    ("G001b.name", "算子时间占比较高"),
+    ("G002.message", "算子 {0} 内存使用过高: {1} (阈值: {2})"),
+    ("G002.name", "算子内存使用过高"),
+    (
+        "G002.reason",
+        "算子内存使用过高，可能导致查询失败或触发 Spill。检查是否存在数据膨胀或中间结果过大。",
+    ),
+    ("G002.suggestion.1", "检查是否存在数据膨胀"),
+    ("G002.suggestion.2", "考虑分批处理"),
+    ("G002.suggestion.3", "检查 HashTable 或中间结果是否过大"),
+    ("G003.message", "算子 {0} 存在执行时间倾斜，max/avg 比率为 {1} (阈值: {2})"),
+    ("G003.name", "算子执行时间倾斜"),
+    ("G003.reason", "算子在多个实例间执行时间差异大，部分实例成为瓶颈。通常是数据分布不均匀导致。"),
+    ("G003.suggestion.1", "检查数据分布是否均匀"),
+    ("G003.suggestion.2", "检查数据分区或分桶是否合理"),
+    ("G003.suggestion.3", "考虑增加并行度"),
+    ("I001.message", "导入存在数据倾斜，PushChunkNum max/min 比率为 {0}"),
+    ("I001.name", "导入数据倾斜"),
+    ("I001.suggestion.1", "检查上游算子是否存在数据倾斜"),
+    ("I001.suggestion.2", "优化分桶键选择"),
+    ("I002.message", "导入 RPC 客户端耗时是服务端的 {0} 倍，网络传输可能是瓶颈"),
+    ("I002.name", "导入 RPC 延迟高"),
+    ("I002.suggestion", "启用数据压缩减少网络传输量"),
+    ("I003.message", "导入过滤了 {0} 行 ({1}%)，可能存在数据质量问题"),
+    ("I003.name", "导入过滤行数过多"),
+    ("I003.suggestion.1", "检查数据格式是否符合表结构"),
+    ("I003.suggestion.2", "检查是否有空值或类型不匹配"),
+    ("I003.suggestion.3", "查看 BE 日志获取详细过滤原因"),
+    ("J001.message", "Join 结果膨胀 {0} 倍 (输出 {1} 行 / 探测 {2} 行)"),
+    ("J001.name", "Join 结果膨胀"),
+    (
+        "J001.reason",
+        "Join 输出结果显著大于输入，通常是缺少 Join 条件导致 Cross Join，或 Join 条件错误导致 1:N 匹配。",
+    ),
+    ("J001.suggestion.1", "检查 JOIN 条件是否缺失或不完整"),
+    ("J001.suggestion.2", "检查是否存在多对多关系"),
+    ("J001.suggestion.3", "考虑添加更多过滤条件"),
+    ("J002.message", "Build 端行数 ({0}) 大于 Probe 端 ({1})，Join 顺序可能不优"),
+    ("J002.name", "Join Build 端过大"),
+    (
+        "J002.reason",
+        "在 Hash Join 中，Build 端数据量大于 Probe 端，导致 HashTable 过大。优化器可能因统计信息不准确选择了错误的 Build 端。",
+    ),
+    ("J002.suggestion.1", "检查优化器是否选择了正确的 Join 顺序"),
+    ("J002.suggestion.2", "考虑使用 Hint 指定 Join 顺序"),
+    ("J003.message", "HashTable 内存使用 {0}，可能导致内存压力 (阈值: {1})"),
+    ("J003.name", "HashTable 内存过大"),
+    ("J003.reason", "Join 的 HashTable 占用内存过大，可能导致内存压力或触发 Spill。"),
+    ("J003.suggestion.1", "检查 Build 端数据量是否过大"),
+    ("J003.suggestion.2", "考虑使用 Runtime Filter 减少数据量"),
+    ("J004.message", "Join 未生成 Runtime Filter，Build 端有 {0} 行"),
+    ("J004.name", "未生成 Runtime Filter"),
+    (
+        "J004.reason",
+        "Runtime Filter 未生效或效果差，Scan 端未能有效过滤数据。可能是 Filter 构建失败或选择性差。",
+    ),
+    ("J004.suggestion.1", "检查 enable_global_runtime_filter 是否启用"),
+    ("J004.suggestion.2", "检查 Join 条件是否适合生成 RF"),
+    ("J004.suggestion.3", "检查 Build 端行数是否超过阈值"),
+    ("J005.message", "Hash 表碰撞严重，平均每桶 {0} 个键"),
+    ("J005.name", "Hash 碰撞严重"),
+    (
+        "J005.reason",
+        "Join 条件「{0}」的 Hash 表存在大量冲突（Build 端 {1} 行），导致探测效率下降。可能是 Join 键分布不均匀或 Hash 函数效果差。",
+    ),
+    ("J005.suggestion.1", "检查 Join 键「{0}」是否存在大量重复值或 NULL 值"),
+    ("J005.suggestion.2", "考虑添加更多等值 Join 条件分散 Hash 分布"),
+    ("J005.suggestion.3", "检查 Build 表的数据分布，必要时添加预过滤"),
+    ("J006.message", "Join 数据分布倾斜，max/avg 比率为 {0} (阈值: {1})"),
+    ("J006.name", "Join Shuffle 倾斜"),
+    (
+        "J006.reason",
+        "Shuffle Join 按「{0}」分发数据时分布不均匀，max 实例处理 {1} 行，min 实例仅 {2} 行。通常是 Join 键存在热点值导致。",
+    ),
+    ("J006.suggestion.1", "检查 Join 键「{0}」中是否存在热点值（如 NULL 或高频值）"),
+    ("J006.suggestion.2", "考虑添加更多 Join 条件分散数据分布"),
+    ("J006.suggestion.3", "对热点键值单独处理或添加盐值打散"),
+    ("J007.message", "分区探测开销占比 {0}%，分区数为 {1}"),
+    ("J007.name", "分区 Join 探测开销高"),
+    ("J007.reason", "分区探测开销过高，可能是分区数过多或分区策略不当。"),
+    ("J007.suggestion.1", "检查分区数是否合理"),
+    ("J007.suggestion.2", "考虑增加内存限制避免过度分区"),
+    ("J008.message", "Runtime Filter 内存占用 {0}"),
+    ("J008.name", "Runtime Filter 内存占用高"),
+    ("J008.reason", "Runtime Filter 占用内存过高，可能是 Filter 数量过多或单个 Filter 过大。"),
+    ("J008.suggestion.1", "降低 runtime_filter_max_size 配置"),
+    ("J008.suggestion.2", "检查 Join 键基数是否过高"),
+    ("J009.message", "使用了 {0} 算子，可能是非等式 Join 导致"),
+    ("J009.name", "非等式 Join 回退"),
+    (
+        "J009.reason",
+        "Join 条件包含非等式条件，无法使用 Hash Join，退化为 Nested Loop Join，性能较差。",
+    ),
+    ("J009.suggestion.1", "检查 JOIN 条件是否包含等式条件"),
+    ("J009.suggestion.2", "尝试将非等式条件转换为等式条件"),
+    ("J009.suggestion.3", "考虑重构查询逻辑"),
+    (
+        "J010.message",
+        "HashTable ({0}) 超过 L3 缓存，探测行数 ({1}) 远大于构建行数 ({2})，可能存在缓存不友好",
+    ),
+    ("J010.name", "探测缓存不友好"),
+    (
+        "J010.reason",
+        "Hash 表探测时缓存命中率低，可能是 HashTable 过大超出 CPU 缓存或探测数据访问模式不友好。",
+    ),
+    ("J010.suggestion.1", "考虑交换 Join 左右表顺序"),
+    ("J010.suggestion.2", "使用 Hint 指定 Join 顺序"),
+    ("J010.suggestion.3", "检查统计信息是否准确"),
+    ("J011.message", "Broadcast Join Build 端数据量过大 ({0} 行, {1})"),
+    ("J011.name", "Join 不应使用 Broadcast"),
+    (
+        "J011.reason",
+        "当小表与大表 Join 时，Broadcast Join 会将小表广播到所有节点。但当 Build 端数据量过大时，会增加网络和计算成本。当前 Join 条件「{0}」的 Build 端有 {1} 行，Probe 端有 {2} 行，可能是统计信息不准确导致优化器错误估计了表大小。",
+    ),
+    (
+        "J011.suggestion.1",
+        "在 JOIN 关键字后添加 [shuffle] Hint: SELECT ... FROM a JOIN [shuffle] b ON {0}",
+    ),
+    ("J011.suggestion.2", "执行 ANALYZE TABLE <build_table> 更新统计信息"),
+    ("J011.suggestion.3", "检查 Join 顺序，确保小表在 Build 端"),
+    ("L001.message", "LocalExchange 内存使用 {0}"),
+    ("L001.name", "LocalExchange 内存使用过高"),
+    ("L001.reason", "请参考 StarRocks 官方文档了解更多信息。"),
+    ("L001.suggestion.1", "检查上下游算子的数据流是否平衡"),
+    ("L001.suggestion.2", "调整 pipeline_dop 参数"),
+    ("P001.message", "Project 表达式计算占比过高 ({0}%)"),
+    ("P001.name", "Project 表达式计算耗时高"),
+    ("P001.reason", "Project 算子执行时间过长，可能是表达式计算复杂或数据量大。"),
+    ("P001.suggestion.1", "简化 SELECT 中的复杂表达式"),
+    ("P001.suggestion.2", "将复杂计算移到物化视图中预计算"),
+    ("P001.suggestion.3", "检查是否有不必要的类型转换"),
+    ("P002.message", "公共子表达式计算耗时 {0}（占表达式总时间 {1}%）"),
+    ("P002.name", "公共子表达式计算耗时高"),
+    (
+        "P002.reason",
+        "复杂 CASE WHEN 表达式或重复子表达式导致计算开销高。StarRocks 会尝试提取公共子表达式以避免重复计算，但提取本身也有开销。",
+    ),
+    ("P002.suggestion.1", "简化 CASE WHEN 表达式，减少分支数量"),
+    ("P002.suggestion.2", "将复杂条件判断移到物化视图预计算"),
+    ("P002.suggestion.3", "检查是否存在大量重复的表达式计算"),
+    ("P002.suggestion.4", "考虑使用 IF() 替代简单的 CASE WHEN"),
+    ("PL001.message.1", "HMS 元数据获取总耗时 {0}"),
+    ("PL001.message.2", "HMS 元数据获取总耗时 {0}，慢调用: {1}"),
+    ("PL001.message.3", "HMS 元数据获取总耗时 {0}（占{1}时间 {2}%）"),
+    ("PL001.message.4", "HMS 元数据获取总耗时 {0}（占{1}时间 {2}%），慢调用: {3}"),
+    ("PL001.name", "HMS 元数据获取慢"),
+    ("PL001.ratio_base.query", "查询总"),
+    ("PL001.reason", "Hive MetaStore 响应慢会阻塞查询规划，可能是 HMS 服务负载高或网络延迟"),
+    ("PL001.suggestion.1", "检查 HMS 服务状态和负载"),
+    ("PL001.suggestion.2", "减少查询涉及的分区数量"),
+    ("PL001.suggestion.3", "考虑启用元数据缓存"),
+    ("PL001.suggestion.4", "检查网络延迟"),
+    ("PL002.message", "优化器耗时 {0}（占 Planner 时间 {1}%）"),
+    ("PL002.name", "优化器耗时过长"),
+    ("PL002.reason", "查询优化器花费过长时间，可能是查询过于复杂或统计信息不准确"),
+    ("PL002.suggestion.1", "简化查询结构，减少 JOIN 和子查询数量"),
+    ("PL002.suggestion.2", "更新表的统计信息: ANALYZE TABLE"),
+    ("PL002.suggestion.3", "检查 new_planner_optimize_timeout 参数设置"),
+    ("PL002.suggestion.4", "考虑拆分为多个简单查询"),
+    ("PL003.message", "规划时间 {0} 占查询总时间的 {1}%"),
+    ("PL003.name", "规划时间占比过高"),
+    ("PL003.reason", "查询规划占用了大量时间，通常是元数据获取或优化器导致"),
+    ("PL003.suggestion.1", "参见 PL001/PL002 获取具体原因"),
+    ("PL003.suggestion.2", "减少查询涉及的表和分区数量"),
+    ("PL004.message", "优化器估算 {0} 行，实际 {1} 行，偏差 {2} 倍"),
+    ("PL004.name", "基数估算偏差大"),
+    (
+        "PL004.reason",
+        "优化器基于统计信息估算的行数与实际行数差距过大，可能选择了错误的 Join 顺序、Join 分布方式或聚合策略，通常由统计信息过期或缺失导致。",
+    ),
+    ("PL004.suggestion.1", "执行 ANALYZE TABLE {0}; 更新统计信息"),
+    ("PL004.suggestion.2", "检查过滤条件与关联键之间的数据相关性"),
+    ("PL004.suggestion.3", "使用 EXPLAIN COSTS 对比优化器的估算行数"),
+    ("Q001.message", "{0}执行时间 {1}，超过{2}阈值 ({3}, {4})"),
+    ("Q001.name", "查询执行时间过长"),
+    ("Q001.query_type.analyze", "ANALYZE 分析"),
+    ("Q001.query_type.ctas", "CTAS 建表"),
+    ("Q001.query_type.export", "EXPORT 导出"),
+    ("Q001.query_type.insert", "INSERT 导入"),
+    ("Q001.query_type.load", "LOAD 导入"),
+    ("Q001.query_type.select", "OLAP 查询"),
+    ("Q001.query_type.unknown", "查询"),
+    ("Q001.reason.1", "阈值基于历史基线 P95 + 2σ 计算。当前查询显著慢于同类查询的历史表现。"),
+    (
+        "Q001.reason.2",
+        "根据查询类型 ({0}) 使用默认阈值。OLAP 查询期望快速响应 (10s)，而 ETL 任务允许更长时间 (5-30min)。",
+    ),
+    ("Q001.suggestion", "检查是否存在性能瓶颈算子"),
+    ("Q001.threshold_source.baseline", "自适应基线"),
+    ("Q001.threshold_source.default", "默认"),
+    ("Q002.message", "查询峰值内存 {0}，超过 10GB 阈值"),
+    ("Q002.name", "查询内存使用过高"),
+    ("Q002.suggestion.1", "检查是否存在大表 Join"),
+    ("Q002.suggestion.2", "考虑启用 Spill 功能"),
+    ("Q002.suggestion.3", "优化查询减少中间结果"),
+    ("Q003.message", "查询发生磁盘溢写，溢写数据量 {0}"),
+    ("Q003.name", "查询发生落盘"),
+    ("Q003.suggestion.1", "增加内存限制以减少 Spill"),
+    ("Q003.suggestion.2", "检查 Spill 是否影响性能"),
+    ("Q004.message", "CPU 利用率仅 {0}%，可能存在等待或 IO 瓶颈"),
+    ("Q004.name", "CPU 利用率低"),
+    ("Q004.suggestion.1", "检查是否存在等待"),
+    ("Q004.suggestion.2", "增加并行度"),
+    ("Q005.message", "扫描时间占比 {0}%，查询瓶颈在数据扫描"),
+    ("Q005.name", "扫描时间占比过高"),
+    ("Q005.suggestion.1", "添加过滤条件减少扫描数据量"),
+    ("Q005.suggestion.2", "考虑创建物化视图"),
+    ("Q005.suggestion.3", "检查存储性能"),
+    ("Q006.message", "网络时间占比 {0}%，查询瓶颈在网络传输"),
+    ("Q006.name", "网络时间占比过高"),
+    ("Q006.suggestion.1", "考虑使用 Colocate Join 减少 Shuffle"),
+    ("Q006.suggestion.2", "检查网络带宽"),
+    ("Q007.message", "Profile 收集时间 {0}ms"),
+    ("Q007.name", "Profile 收集慢"),
+    ("Q007.suggestion", "降低 pipeline_profile_level"),
+    ("Q008.message", "调度时间占比 {0}%，Pipeline 调度可能存在瓶颈"),
+    ("Q008.name", "调度时间过长"),
+    ("Q008.suggestion", "检查 Pipeline 调度瓶颈"),
+    ("Q009.message", "结果传输时间占比 {0}%"),
+    ("Q009.name", "结果传输慢"),
+    ("Q009.suggestion", "减少结果集大小"),
+    ("Q010.message", "Fragment {0} Pipeline {1} 的 Driver 平均排队 {2}，占 Driver 总时间 {3}%"),
+    ("Q010.name", "Pipeline Driver 排队时间过长"),
+    (
+        "Q010.reason",
+        "Driver 已就绪但长时间等待执行线程，说明 BE 的 Pipeline 执行线程繁忙，通常由并发查询过多或 CPU 资源不足导致。",
+    ),
+    ("Q010.suggestion.1", "检查 BE 的 CPU 使用率与并发查询数"),
+    ("Q010.suggestion.2", "使用资源组限制并发或隔离大查询"),
+    ("Q010.suggestion.3", "避免手动设置过大的 pipeline_dop"),
+    ("REG001.message", "查询执行时间 {0} 是历史 P90 ({1}) 的 {2} 倍"),
+    ("REG001.name", "性能回归"),
+    (
+        "REG001.reason",
+        "同类查询（{0}）历史执行 {1} 次，P50={2}ms P90={3}ms P99={4}ms，当前执行显著慢于历史表现。",
+    ),
+    ("REG001.suggestion.1", "检查是否有数据分布变化导致执行计划改变"),
+    ("REG001.suggestion.2", "检查是否有并发查询导致资源竞争"),
+    ("REG001.suggestion.3", "检查相关表的统计信息是否过期"),
+    ("REG001.suggestion.4", "对比历史执行计划是否有差异"),
+    ("S001.message", "Scan 存在数据倾斜，max/avg 比率为 {0} (阈值: {1})"),
+    ("S001.name", "Scan 数据倾斜"),
+    (
+        "S001.reason.1",
+        "内表「{0}」数据在各节点分布不均（max {1} 行，min {2} 行）。通常是分桶键选择不当导致数据倾斜。",
+    ),
+    (
+        "S001.reason.2",
+        "外表「{0}」数据在各节点分布不均（max {1} 行，min {2} 行）。可能是 Hive 分区大小不均或文件分布不均。",
+    ),
+    ("S001.suggestion.1", "检查表「{0}」的分桶键是否选择了高基数列"),
+    (
+        "S001.suggestion.2",
+        "查看数据分布: SELECT COUNT(*) FROM {0} GROUP BY <bucket_key> ORDER BY 1 DESC",
+    ),
+    (
+        "S001.suggestion.3",
+        "必要时重建分桶: ALTER TABLE {0} DISTRIBUTED BY HASH(<high_cardinality_column>) BUCKETS N",
+    ),
+    ("S001.suggestion.4", "检查外表「{0}」的分区数据量是否均衡"),
+    ("S001.suggestion.5", "检查是否存在热点分区或超大文件"),
+    ("S001.suggestion.6", "考虑在 Hive 侧重新分区或合并小文件"),
+    ("S002.message", "Scan IO 耗时存在倾斜，max/avg 比率为 {0} (阈值: {1})"),
+    ("S002.name", "Scan IO 倾斜"),
+    (
+        "S002.reason",
+        "Scan 算子多个实例在读取数据时，部分实例花费的时间显著大于其它实例。可能是节点 IO 使用率不均或数据在节点上分布不均。",
+    ),
+    ("S002.suggestion.1", "检查节点 IO 使用率是否不均"),
+    ("S002.suggestion.2", "检查存储设备是否存在性能问题"),
+    ("S003.message", "过滤效果差，仅过滤了 {0}% 的数据 (读取 {1} 行 / 原始 {2} 行)"),
+    ("S003.name", "过滤效果差"),
+    (
+        "S003.reason.1",
+        "内表「{0}」扫描了 {1} 行但仅过滤掉 {2}%。可通过 ZoneMap、BloomFilter 索引或谓词下推提前过滤。",
+    ),
+    (
+        "S003.reason.2",
+        "外表「{0}」扫描了 {1} 行但仅过滤掉 {2}%。外表过滤依赖 Hive 分区裁剪和文件格式的统计信息。",
+    ),
+    ("S003.suggestion.1", "为表「{0}」的过滤列添加 ZoneMap 或 BloomFilter 索引"),
+    ("S003.suggestion.2", "检查 WHERE 条件是否支持下推（避免函数包裹、类型转换）"),
+    ("S003.suggestion.3", "检查表「{0}」的分区是否能裁剪"),
+    ("S003.suggestion.4", "通过 EXPLAIN 查看谓词下推情况"),
+    ("S003.suggestion.5", "检查外表「{0}」的 Hive 分区是否能裁剪（WHERE 条件包含分区列）"),
+    ("S003.suggestion.6", "ORC/Parquet 文件利用 min/max 统计信息过滤，确保文件有 statistics"),
+    ("S003.suggestion.7", "检查 WHERE 条件是否支持下推到外部存储"),
+    ("S004.message", "谓词未能下推到存储层，{0} 行 ({1}%) 在表达式层过滤"),
+    ("S004.name", "谓词未下推"),
+    (
+        "S004.reason",
+        "查询条件未能下推到存储层执行，导致需要在计算层过滤大量数据。可能是查询条件包含函数、类型不匹配或不支持下推的表达式。",
+    ),
+    ("S004.suggestion.1", "将谓词重写为简单比较（避免函数包裹）"),
+    ("S004.suggestion.2", "为过滤列添加 ZoneMap/Bloom 索引"),
+    ("S004.suggestion.3", "检查列类型是否匹配（避免隐式转换）"),
+    ("S004.suggestion.4", "确保过滤列在 Hive 分区列中"),
+    ("S004.suggestion.5", "检查 ORC/Parquet 文件是否有统计信息"),
+    ("S005.message", "IO 线程池可能已饱和，等待时间 {0}s"),
+    ("S005.name", "IO 线程池饱和"),
+    (
+        "S005.reason",
+        "IO 线程池使用率过高，导致 IO 任务等待时间过长。可能是并发查询过多或存储性能不足。",
+    ),
+    ("S005.suggestion", "增加 BE 上的 max_io_threads 配置"),
+    ("S006.message", "Rowset 数量过多 ({0})，初始化耗时 {1}ms"),
+    ("S006.name", "Rowset 碎片化"),
+    (
+        "S006.reason",
+        "表「{0}」的 Rowset 数量过多（{1} 个），导致 Segment 初始化耗时 {2}ms。通常是频繁小批量导入或 Compaction 不及时导致。",
+    ),
+    ("S006.suggestion.1", "触发手动 Compaction: ALTER TABLE {0} COMPACT"),
+    ("S006.suggestion.2", "查看 Compaction 状态: SHOW TABLET FROM {0}"),
+    ("S006.suggestion.3", "批量合并小型导入任务，减少导入频率"),
+    ("S007.message", "IO 时间占比 {0}%，读取数据量 {1}，可能存在存储瓶颈"),
+    ("S007.name", "冷存储访问"),
+    (
+        "S007.reason",
+        "数据存储在冷存储（如对象存储）上，IO 延迟较高。冷存储的 IOPS 和吞吐量通常低于本地 SSD。",
+    ),
+    ("S007.suggestion.1", "检查存储性能，考虑使用 SSD"),
+    ("S007.suggestion.2", "增大 PageCache 缓存"),
+    ("S007.suggestion.3", "检查网络带宽（如果是远程存储）"),
+    ("S008.message", "ZoneMap 索引未能过滤数据"),
+    ("S008.name", "ZoneMap 索引未生效"),
+    (
+        "S008.reason",
+        "表「{0}」扫描了 {1} 行但 ZoneMap 未过滤任何数据。ZoneMap 基于排序键的 min/max 值过滤，需要 WHERE 条件包含排序键前缀列。",
+    ),
+    ("S008.suggestion.1", "查看表「{0}」的排序键: SHOW CREATE TABLE {1}"),
+    ("S008.suggestion.2", "确保 WHERE 条件包含排序键的前缀列（如 WHERE dt = '2024-01-01'）"),
+    ("S008.suggestion.3", "避免在排序键上使用函数（如 WHERE DATE(dt) = ...）"),
+    ("S009.message.1", "DataCache 命中率 {0}%，{1}% 数据从远程存储读取 (本地: {2}, 远程: {3})"),
+    ("S009.message.2", "DataCache IO 命中率 {0}%，{1}% IO 访问远程存储 (本地: {2}, 远程: {3})"),
+    ("S009.message.3", "PageCache 命中率仅 {0}% ({1}/{2} pages)"),
+    ("S009.name", "缓存命中率低"),
+    (
+        "S009.reason.1",
+        "存算分离架构下，DataCache 是提升查询性能的关键。当大量数据需要从远程存储（如 S3/OSS）读取时，网络延迟会显著影响查询性能。",
+    ),
+    (
+        "S009.reason.2",
+        "存算分离架构下，DataCache 是提升查询性能的关键。当大量 IO 请求需要访问远程存储时，网络延迟会显著影响查询性能。",
+    ),
+    (
+        "S009.reason.3",
+        "PageCache 命中率低，大量数据需要从磁盘读取。可能是缓存容量不足或数据访问模式不适合缓存。",
+    ),
+    ("S009.suggestion.1", "增大 DataCache 容量 (datacache_disk_size)"),
+    ("S009.suggestion.2", "检查 DataCache 磁盘空间是否充足"),
+    ("S009.suggestion.3", "对热点数据执行缓存预热 (CACHE SELECT)"),
+    ("S009.suggestion.4", "检查是否有其他查询竞争缓存资源"),
+    ("S009.suggestion.5", "增大 PageCache 容量 (storage_page_cache_limit)"),
+    ("S009.suggestion.6", "检查是否有其他查询竞争缓存"),
+    ("S010.message", "Runtime Filter 未过滤任何行，扫描了 {0} 行"),
+    ("S010.name", "Scan Runtime Filter 未生效"),
+    (
+        "S010.reason",
+        "表「{0}」扫描了 {1} 行但 Runtime Filter 未过滤任何数据。可能是 RF 构建失败、超时或选择性差。",
+    ),
+    ("S010.suggestion.1", "检查 Join 侧是否生成了针对「{0}」的 Runtime Filter"),
+    ("S010.suggestion.2", "确认 enable_global_runtime_filter = true"),
+    ("S010.suggestion.3", "检查 RF 是否因 Build 端数据量过大而被跳过"),
+    ("S011.message", "表 {0} 软删除行占比 {1}%，建议执行 Compaction"),
+    ("S011.name", "累积软删除过多"),
+    (
+        "S011.reason",
+        "表 {0} 中存在大量软删除记录 ({1} 行)，扫描时需要过滤这些已删除的行，影响查询性能。建议执行 Compaction 清理删除标记。",
+    ),
+    ("S011.suggestion.1", "执行 Compaction: {0}"),
+    ("S011.suggestion.2", "检查 Compaction 状态: SHOW PROC '/compactions';"),
+    ("S011.suggestion.3", "查看表 Tablet 状态: SHOW TABLET FROM {0};"),
+    ("S012.message", "Bitmap 索引未过滤数据，表达式过滤了 {0} 行"),
+    ("S012.name", "Bitmap 索引未生效"),
+    (
+        "S012.reason",
+        "Bitmap 索引适用于基数较低且大量重复的字段（如性别、状态）。如果查询条件包含这类字段但未命中索引，可能是未创建索引或查询条件不支持。",
+    ),
+    ("S012.suggestion.1", "对低基数列（如状态、类型）创建 Bitmap 索引"),
+    ("S012.suggestion.2", "确保查询条件使用等值匹配 (=, IN)"),
+    ("S012.suggestion.3", "检查 Profile 中 BitmapIndexFilterRows 指标"),
+    ("S013.message", "Bloom Filter 索引未过滤数据，表达式过滤了 {0} 行"),
+    ("S013.name", "Bloom Filter 索引未生效"),
+    (
+        "S013.reason",
+        "Bloom Filter 索引适用于高基数列（如 ID 列）的等值查询。仅支持 = 和 IN 条件，且 TINYINT/FLOAT/DOUBLE/DECIMAL 类型不支持。",
+    ),
+    ("S013.suggestion.1", "对高基数列（如 ID 列）创建 Bloom Filter 索引"),
+    ("S013.suggestion.2", "注意: TINYINT/FLOAT/DOUBLE/DECIMAL 类型不支持 Bloom Filter"),
+    ("S013.suggestion.3", "检查 Profile 中 BloomFilterFilterRows 指标"),
+    ("S014.message", "Shuffle Join 网络传输 {0}，考虑使用 Colocate Join 优化"),
+    ("S014.name", "可优化为 Colocate Join"),
+    (
+        "S014.reason",
+        "Colocate Join 可以避免数据网络传输，显著提升 Join 性能。当两个表的分桶键相同且分桶数相同时，可以使用 Colocate Join。",
+    ),
+    ("S014.suggestion.1", "将频繁 Join 的表设置为同一 Colocation Group"),
+    ("S014.suggestion.2", "确保两表的分桶键和分桶数相同"),
+    ("S014.suggestion.3", "使用 SHOW COLOCATION GROUP 查看现有分组"),
+    ("S014.suggestion.4", "Colocate Join 可避免数据网络传输，显著提升性能"),
+    ("S016.message", "扫描了 {0} 个文件，平均大小仅 {1}（建议 > {2}）"),
+    ("S016.name", "外表小文件过多"),
+    ("S016.reason", "{0} 外表 {1} 存在大量小文件，导致元数据开销大、IO 效率低。"),
+    ("S016.scan_type.s3", "S3/对象存储"),
+    ("S016.suggestion.connector.1", "合并小文件以提升查询性能"),
+    ("S016.suggestion.connector.2", "考虑将热点数据导入 StarRocks 内表"),
+    ("S016.suggestion.connector.3", "调整上游数据写入的文件大小配置"),
+    ("S016.suggestion.delta.1", "执行 OPTIMIZE {0} ZORDER BY ..."),
+    ("S016.suggestion.delta.2", "启用 Delta Lake Auto Compaction"),
+    ("S016.suggestion.delta.3", "调整 spark.databricks.delta.autoCompact.minNumFiles"),
+    ("S016.suggestion.file.1", "合并本地小文件"),
+    ("S016.suggestion.file.2", "调整写入程序的输出文件大小"),
+    ("S016.suggestion.hdfs.1", "使用 Hadoop Archive (HAR) 合并小文件"),
+    ("S016.suggestion.hdfs.2", "调整上游 ETL 输出文件大小（建议 128MB-256MB）"),
+    ("S016.suggestion.hdfs.3", "使用 Spark coalesce/repartition 合并输出文件"),
+    ("S016.suggestion.hive.1", "合并小文件: INSERT OVERWRITE {0} SELECT * FROM {0}"),
+    ("S016.suggestion.hive.2", "调整 Hive 表的 mapreduce.input.fileinputformat.split.minsize"),
+    ("S016.suggestion.hive.3", "使用 ALTER TABLE CONCATENATE 合并小文件"),
+    ("S016.suggestion.hudi.1", "执行 Hudi Compaction 合并小文件"),
+    ("S016.suggestion.hudi.2", "调整 hoodie.parquet.small.file.limit 参数"),
+    ("S016.suggestion.hudi.3", "检查 Hudi 表的 Compaction 策略配置"),
+    ("S016.suggestion.iceberg.1", "执行 Compaction: CALL rewrite_data_files(table => '{0}')"),
+    ("S016.suggestion.iceberg.2", "调整 write.target-file-size-bytes 参数（建议 128MB-256MB）"),
+    ("S016.suggestion.iceberg.3", "启用 Iceberg 自动 Compaction"),
+    ("S016.suggestion.paimon.1", "执行 Paimon Compaction 合并小文件"),
+    ("S016.suggestion.paimon.2", "调整 write.target-file-size 参数"),
+    ("S016.suggestion.paimon.3", "检查 Paimon 表的 Compaction 配置"),
+    ("S016.suggestion.s3.1", "使用 Spark/Flink 合并小文件"),
+    ("S016.suggestion.s3.2", "调整写入任务的并行度减少小文件产生"),
+    ("S016.suggestion.s3.3", "考虑使用更大的 Parquet row group size"),
+    ("S016.suggestion.s3.4", "对于 Iceberg/Hudi 表，执行 OPTIMIZE 或 Compaction"),
+    ("S017.message.1", "ORC 文件存在 {0} 个 Stripe（平均 {1}），TinyStripe 占比 {2}%"),
+    ("S017.message.2", "Parquet 文件存在 {0} 个 RowGroup，文件碎片化严重"),
+    ("S017.name", "文件格式碎片化"),
+    (
+        "S017.reason.1",
+        "{0}「{1}」的 ORC 文件 Stripe 碎片化严重（共 {2} 个），导致大量 IO 请求和文件打开开销。",
+    ),
+    (
+        "S017.reason.2",
+        "{0}「{1}」的 Parquet 文件 RowGroup 过多（共 {2} 个），导致元数据开销和 IO 效率低。",
+    ),
+    (
+        "S017.suggestion.1",
+        "外表小文件合并方案: ①Hive简单合并: ALTER TABLE {0} PARTITION(...) CONCATENATE; ②推荐重写: INSERT OVERWRITE TABLE {1} PARTITION(...) SELECT * FROM {2}; ③大数据量用Spark: df.repartition(N).saveAsTable('{3}'); ④StarRocks临时优化: SET connector_io_tasks_per_scan_operator=64",
+    ),
+    ("S017.suggestion.2", "执行 Compaction 合并碎片: ALTER TABLE {0} COMPACT"),
+    ("S017.table_kind.external", "外表"),
+    ("S017.table_kind.internal", "内表"),
+    ("S018.message", "IO 等待时间 {0}（占 IO 总时间 {1}%），存在 IO 排队瓶颈"),
+    ("S018.name", "IO 等待时间过长"),
+    ("S018.reason", "大量并发 IO 请求在队列中等待，可能是文件碎片化或 IO 资源不足导致"),
+    ("S018.suggestion.1", "合并小文件减少 IO 请求数"),
+    ("S018.suggestion.2", "增加 io_tasks_per_scan_operator 参数"),
+    ("S018.suggestion.3", "检查存储系统 IO 性能"),
+    ("S018.suggestion.4", "考虑启用 Data Cache 缓存热点数据"),
+    ("T001.message", "排序行数过多 ({0} 行)，可能导致性能问题"),
+    ("T001.name", "排序行数过多"),
+    (
+        "T001.reason",
+        "排序数据量过大，消耗大量 CPU 和内存资源。考虑添加过滤条件减少排序数据量或使用 TopN 优化。",
+    ),
+    ("T002.message", "Sort 发生磁盘溢写，溢写数据量 {0}"),
+    ("T002.name", "排序发生落盘"),
+    ("T002.reason", "排序数据量超出内存限制，触发磁盘溢写。Spill 会显著降低排序性能。"),
+    ("T002.suggestion.1", "增加内存限制以避免 Spill"),
+    ("T002.suggestion.2", "添加 LIMIT 减少排序数据量"),
+    ("T002.suggestion.3", "检查是否可以优化查询减少排序数据"),
+    ("T003.message", "排序内存使用 {0}"),
+    ("T003.name", "排序内存过高"),
+    ("T003.reason", "排序占用内存过高，可能导致内存压力或影响其他算子。"),
+    ("T004.message", "Sort 合并阶段占比过高 ({0}%)"),
+    ("T004.name", "Sort 合并时间过长"),
+    ("T004.reason", "多路归并排序时间过长，可能是归并路数过多或单路数据量大。"),
+    ("T004.suggestion.1", "检查并行度设置是否合理"),
+    ("T004.suggestion.2", "考虑减少分区数量"),
+    ("T005.message", "Merge 等待上游时间占比 {0}%"),
+    ("T005.name", "Merge 等待上游过长"),
+    ("T005.reason", "Merge 算子等待上游数据时间过长，上游算子可能存在性能瓶颈。"),
+    ("T005.suggestion.1", "首先优化生产者 operator"),
+    ("T005.suggestion.2", "扩大管道缓冲区"),
+    ("W001.message", "窗口函数内存使用 {0}"),
+    ("W001.name", "窗口函数内存过高"),
+    ("W001.reason", "窗口函数占用内存过高，可能是窗口分区过大或窗口函数状态过大。"),
+    ("W001.suggestion.1", "检查 PARTITION BY 基数是否过高"),
+    ("W001.suggestion.2", "考虑减少窗口大小"),
+    ("W001.suggestion.3", "检查是否可以使用聚合函数替代"),
+    ("aggregate.node_count", "{0} 个节点存在此问题"),
+    ("common.hours", "{0}小时"),
+    ("common.minutes", "{0}分钟"),
+    ("common.seconds", "{0}秒"),
+    ("common.unknown", "未知"),
+    ("conclusion.healthy", "查询执行良好，未发现明显性能问题。"),
+    ("conclusion.minor", "查询发现{0}个小问题，整体性能可接受。"),
+    ("conclusion.moderate", "查询存在{0}个中等程度性能问题，整体性能需优化。执行时间{1}。"),
+    (
+        "conclusion.severe",
+        "查询存在{0}个严重性能问题，执行时间较长（{1}）。主要问题是{2}。建议优先解决严重问题。",
+    ),
+    ("conclusion.slow", "查询执行时间较长（{0}），建议关注性能热点。"),
+    ("hotspot.parameter", "调整参数: {0} → {1} (命令: {2})"),
+    ("merge.compaction", "执行 Compaction: ALTER TABLE <{0}> COMPACT"),
+    (
+        "merge.small_files",
+        "外表小文件合并 (涉及: {0}): ①ALTER TABLE <table> PARTITION(...) CONCATENATE; ②INSERT OVERWRITE TABLE <table> SELECT * FROM <table>; ③Spark: df.repartition(N).saveAsTable('<table>'); ④SET connector_io_tasks_per_scan_operator=64",
+    ),
+    ("merge.table_count", "{0} 个表"),
+    ("merge.tables_more", "{0} 等 {1} 个表"),
+    (
+        "param.connector_io_tasks_per_scan_operator.description",
+        "每个连接器扫描算子的 IO 任务数，控制外部表扫描并行度",
+    ),
+    (
+        "param.connector_io_tasks_per_scan_operator.impact",
+        "增大可提升外部表扫描吞吐，但会增加远程存储压力",
+    ),
+    (
+        "param.datacache_evict_probability.description",
+        "DataCache 淘汰概率 (0-100)，控制缓存数据被淘汰的可能性",
+    ),
+    ("param.datacache_evict_probability.impact", "降低该值可减少缓存抖动，但可能导致缓存空间不足"),
+    ("param.default.description", "StarRocks 参数 {0}"),
+    ("param.default.impact", "请参考 StarRocks 官方文档了解详情"),
+    (
+        "param.enable_adaptive_sink_dop.description",
+        "启用自适应 Sink 并行度，根据数据量动态调整写入并行度",
+    ),
+    ("param.enable_adaptive_sink_dop.impact", "可优化数据写入性能，减少小文件产生"),
+    (
+        "param.enable_connector_adaptive_io_tasks.description",
+        "启用连接器自适应 IO 任务数，根据数据量动态调整 IO 并行度",
+    ),
+    (
+        "param.enable_connector_adaptive_io_tasks.impact",
+        "可优化外部表扫描性能，平衡 IO 和 CPU 资源",
+    ),
+    ("param.enable_global_runtime_filter.reason", "启用全局 Runtime Filter 提升 Join 性能"),
+    ("param.enable_local_shuffle_agg.description", "启用本地 Shuffle 聚合，在本地先进行预聚合"),
+    ("param.enable_local_shuffle_agg.impact", "可减少网络传输数据量，提升聚合性能"),
+    (
+        "param.enable_populate_datacache.description",
+        "启用 DataCache 写入填充，将远程读取的数据缓存到本地",
+    ),
+    (
+        "param.enable_populate_datacache.impact",
+        "后续查询可命中本地缓存，但首次查询会有额外写入开销",
+    ),
+    ("param.enable_populate_datacache.reason", "启用缓存填充以预热缓存"),
+    ("param.enable_query_cache.description", "启用查询结果缓存，相同查询可直接返回缓存结果"),
+    ("param.enable_query_cache.impact", "对重复查询有显著加速，但会占用额外内存"),
+    ("param.enable_query_cache.reason", "启用查询缓存可加速重复查询"),
+    (
+        "param.enable_runtime_adaptive_dop.description",
+        "启用运行时自适应并行度，根据实际数据量动态调整执行并行度",
+    ),
+    ("param.enable_runtime_adaptive_dop.impact", "可优化资源利用率，避免小数据量查询占用过多资源"),
+    (
+        "param.enable_scan_datacache.description",
+        "启用 DataCache 读取缓存，允许从本地缓存读取数据而非远程存储",
+    ),
+    ("param.enable_scan_datacache.impact", "提升存算分离架构下的查询性能，减少网络 IO"),
+    ("param.enable_scan_datacache.reason", "启用 DataCache 提升存算分离性能"),
+    ("param.enable_sort_aggregate.description", "启用排序聚合，适用于高基数 GROUP BY"),
+    ("param.enable_sort_aggregate.impact", "可减少内存使用，但需要额外排序开销"),
+    ("param.enable_spill.description", "启用中间结果落盘，当内存不足时将数据写入磁盘"),
+    ("param.enable_spill.impact", "可处理超大数据量查询，但会降低查询性能"),
+    ("param.enable_spill.reason", "启用后可避免大查询 OOM"),
+    (
+        "param.global_runtime_filter_build_max_size.description",
+        "全局 Runtime Filter 最大构建大小 (字节)",
+    ),
+    (
+        "param.global_runtime_filter_build_max_size.impact",
+        "增大可支持更大的 Filter，但会占用更多内存",
+    ),
+    (
+        "param.hash_join_push_down_right_table.description",
+        "启用 Hash Join 右表下推，将小表广播到各节点",
+    ),
+    ("param.hash_join_push_down_right_table.impact", "可减少数据 Shuffle，提升 Join 性能"),
+    ("param.impact", "{0} ({1})"),
+    (
+        "param.io_tasks_per_scan_operator.description",
+        "每个扫描算子的 IO 任务数，控制本地表扫描并行度",
+    ),
+    ("param.io_tasks_per_scan_operator.impact", "增大可提升扫描吞吐，但会增加 IO 压力"),
+    ("param.io_tasks_per_scan_operator.reason", "大数据量扫描，建议增加 IO 并行度"),
+    ("param.parallel_fragment_exec_instance_num.description", "每个 Fragment 的并行执行实例数"),
+    ("param.parallel_fragment_exec_instance_num.impact", "增大可提升并行度，但会占用更多资源"),
+    ("param.parallel_fragment_exec_instance_num.reason", "根据集群 {0} 个 BE 节点推荐"),
+    ("param.pipeline_dop.description", "Pipeline 执行并行度，0 表示自动"),
+    ("param.pipeline_dop.impact", "手动设置可控制资源使用，自动模式根据 CPU 核数调整"),
+    ("param.pipeline_dop.reason.1", "推荐使用自动模式，系统会根据 CPU 核数自动调整"),
+    ("param.pipeline_dop.reason.2", "推荐使用自动模式"),
+    ("param.pipeline_profile_level.description", "Pipeline Profile 详细级别 (0-2)"),
+    ("param.pipeline_profile_level.impact", "级别越高信息越详细，但收集开销也越大"),
+    ("param.pipeline_profile_level.reason", "降低 Profile 级别减少收集开销"),
+    ("param.query_mem_limit.description", "单个查询的内存限制 (字节)"),
+    ("param.query_mem_limit.impact", "增大可处理更大数据量，但可能影响其他查询"),
+    ("param.query_mem_limit.reason", "根据数据量推荐 {0}GB 内存限制"),
+    ("param.query_timeout.description", "查询超时时间 (秒)"),
+    ("param.query_timeout.impact", "增大可允许长时间运行的查询，但可能占用资源过久"),
+    ("param.query_timeout.reason", "延长超时时间以支持复杂查询"),
+    (
+        "param.runtime_filter_on_exchange_node.description",
+        "在 Exchange 节点启用 Runtime Filter，跨节点传递过滤条件",
+    ),
+    (
+        "param.runtime_filter_on_exchange_node.impact",
+        "可提前过滤数据减少 Shuffle，但会增加 Filter 构建开销",
+    ),
+    ("param.runtime_join_filter_push_down_limit.reason", "增大 RF 下推阈值以支持更大的 Build 端"),
+    ("param.storage_page_cache_limit.description", "BE 存储页缓存大小限制"),
+    ("param.storage_page_cache_limit.impact", "增大可提升热数据读取性能，但会占用更多内存"),
+    ("param.storage_page_cache_limit.reason", "增大页缓存提升热数据读取性能"),
+    (
+        "param.streaming_preaggregation_mode.description",
+        "流式预聚合模式 (auto/force_streaming/force_preaggregation)",
+    ),
+    (
+        "param.streaming_preaggregation_mode.impact",
+        "auto 模式自动选择最优策略，force 模式强制使用指定策略",
+    ),
+    (
+        "profile.incomplete",
+        "Profile 数据不完整: {0} 个 Fragment 中有 {1} 个 ({2}%) 的执行数据缺失，建议稍后重新查询",
+    ),
+    ("report.causal_chains", "因果链"),
+    ("report.command", "命令"),
+    ("report.conclusion", "结论"),
+    ("report.confidence", "置信度"),
+    ("report.current", "当前值"),
+    ("report.diagnostics", "诊断结果"),
+    ("report.evidence", "证据"),
+    ("report.execution_tree", "执行树"),
+    ("report.generated_at", "生成时间: {0}"),
+    ("report.impact", "影响"),
+    ("report.message", "说明"),
+    ("report.no_diagnostics", "未发现诊断问题"),
+    ("report.node", "节点"),
+    ("report.parameter", "参数"),
+    ("report.parameters", "参数建议"),
+    ("report.query_id", "查询 ID"),
+    ("report.recommended", "推荐值"),
+    ("report.root_causes", "根因分析"),
+    ("report.rule", "规则"),
+    ("report.score", "性能评分"),
+    ("report.severity", "级别"),
+    ("report.state", "状态"),
+    ("report.suggestions", "优化建议"),
+    ("report.summary", "查询概要"),
+    ("report.title", "查询 Profile 分析报告"),
+    ("report.total_time", "总耗时"),
+    ("root_cause.chain", "{0} 导致 {1}"),
+    ("root_cause.inter.A002_G001", "聚合HashTable过大导致聚合算子耗时长"),
+    ("root_cause.inter.A002_Q002", "聚合内存高导致查询峰值内存高"),
+    ("root_cause.inter.A002_Q003", "聚合内存占用高导致触发Spill"),
+    ("root_cause.inter.E001_E002", "大Shuffle导致网络时间占比高"),
+    ("root_cause.inter.E001_G001", "Shuffle数据量大导致EXCHANGE算子耗时长"),
+    ("root_cause.inter.E003_G003", "Shuffle倾斜传导到执行时间倾斜"),
+    ("root_cause.inter.G001_Q005", "SCAN耗时长导致扫描时间占比高"),
+    ("root_cause.inter.G001b_Q005", "次耗时SCAN导致扫描时间占比高"),
+    ("root_cause.inter.G002_Q002", "节点内存高导致查询峰值内存高"),
+    ("root_cause.inter.J001_A002", "Join输出数据量大导致聚合数据量大"),
+    ("root_cause.inter.J001_G001", "Join数据量大导致Join算子耗时长"),
+    ("root_cause.inter.J001_Q003", "Join内存占用高导致触发Spill"),
+    ("root_cause.inter.J003_A001", "Join倾斜传导到聚合倾斜"),
+    ("root_cause.inter.J003_G001", "Join HashTable过大导致Join算子耗时长"),
+    ("root_cause.inter.J003_Q002", "Join内存高导致查询峰值内存高"),
+    ("root_cause.inter.J005_G002", "Hash碰撞导致节点内存增大"),
+    ("root_cause.inter.J009_A002", "笛卡尔积导致下游数据爆炸"),
+    ("root_cause.inter.J009_T001", "笛卡尔积导致排序数据量爆炸"),
+    ("root_cause.inter.J011_G002", "Broadcast数据大导致节点内存高"),
+    ("root_cause.inter.PL004_A002", "基数估算偏差导致聚合HashTable过大"),
+    ("root_cause.inter.PL004_A006", "基数估算偏差导致聚合策略选择不当"),
+    ("root_cause.inter.PL004_J002", "基数低估导致选错Join顺序，Build端过大"),
+    ("root_cause.inter.PL004_J003", "基数估算偏差导致HashTable过大"),
+    ("root_cause.inter.PL004_J011", "基数低估导致错误选择Broadcast Join"),
+    ("root_cause.inter.Q002_Q001", "内存压力过大可能导致查询超时"),
+    ("root_cause.inter.Q003_G001", "磁盘溢出导致节点耗时长"),
+    ("root_cause.inter.Q008_Q001", "调度开销大导致查询超时"),
+    ("root_cause.inter.S001_A001", "SCAN数据倾斜传导到聚合倾斜"),
+    ("root_cause.inter.S001_E003", "SCAN数据倾斜传导到Shuffle分区倾斜"),
+    ("root_cause.inter.S001_G003", "SCAN数据倾斜传导到下游执行时间倾斜"),
+    ("root_cause.inter.S001_J003", "SCAN数据倾斜传导到Join探测端"),
+    ("root_cause.inter.S002_E001", "全表扫描导致Shuffle数据量大"),
+    ("root_cause.inter.S002_J001", "全表扫描导致Join数据量大"),
+    ("root_cause.inter.S003_A002", "过滤效果差导致聚合处理数据量大"),
+    ("root_cause.inter.S003_E001", "过滤效果差导致Shuffle数据量大"),
+    ("root_cause.inter.S003_J001", "过滤效果差导致下游Join数据量大"),
+    ("root_cause.inter.S003_T001", "过滤效果差导致排序数据量大"),
+    ("root_cause.inter.S007_G001", "IO瓶颈导致SCAN节点耗时长"),
+    ("root_cause.inter.S017_G001", "Stripe碎片化导致SCAN节点耗时长"),
+    ("root_cause.inter.S017_S018", "Stripe碎片化导致IO等待"),
+    ("root_cause.inter.S018_G001", "IO等待导致节点耗时长"),
+    ("root_cause.inter.S018_Q004", "IO等待导致CPU利用率低"),
+    ("root_cause.inter.T001_Q003", "排序数据量大导致触发Spill"),
+    ("root_cause.inter.W001_Q003", "窗口函数内存占用导致Spill"),
+    ("root_cause.intra.A001_G003", "聚合倾斜导致执行时间倾斜"),
+    ("root_cause.intra.A001_I002", "聚合倾斜导致导入数据倾斜"),
+    ("root_cause.intra.A001_Q003", "聚合倾斜导致内存溢出"),
+    ("root_cause.intra.A002_G002", "聚合HashTable内存高导致节点内存高"),
+    ("root_cause.intra.A002_Q003", "聚合Hash表过大导致溢出"),
+    ("root_cause.intra.A003_A002", "Distinct键过多导致Hash表过大"),
+    ("root_cause.intra.A004_A002", "未使用流式聚合导致内存占用高"),
+    ("root_cause.intra.A005_G002", "聚合基数过高导致CPU占用高"),
+    ("root_cause.intra.E001_E002", "Shuffle数据量大导致网络瓶颈"),
+    ("root_cause.intra.E001_Q008", "Shuffle数据量大导致调度开销增加"),
+    ("root_cause.intra.E002_I001", "网络瓶颈导致数据导入慢"),
+    ("root_cause.intra.E002_Q004", "网络等待导致CPU利用率低"),
+    ("root_cause.intra.E003_G003", "分区倾斜导致执行时间倾斜"),
+    ("root_cause.intra.G001_G001b_Q005", "扫描算子耗时长导致扫描时间占比高"),
+    ("root_cause.intra.G001_Q001", "耗时算子导致查询超时"),
+    ("root_cause.intra.G002_Q002", "算子内存高导致查询峰值内存高"),
+    ("root_cause.intra.J001_Q003", "Hash表过大导致内存溢出到磁盘"),
+    ("root_cause.intra.J002_J001", "Join顺序不优导致Hash表过大"),
+    ("root_cause.intra.J003_G002", "Join HashTable内存高导致节点内存高"),
+    ("root_cause.intra.J003_G003", "Join探测端数据倾斜导致时间倾斜"),
+    ("root_cause.intra.J005_E002", "Broadcast表过大导致网络瓶颈"),
+    ("root_cause.intra.J005_J003", "Hash碰撞导致HashTable内存增大"),
+    ("root_cause.intra.J006_S003", "缺少Runtime Filter导致探测端过滤差"),
+    ("root_cause.intra.J007_S003", "Runtime Filter未下推导致过滤效果差"),
+    ("root_cause.intra.J008_J001", "Join条件不优导致Hash表过大"),
+    ("root_cause.intra.J009_G002", "笛卡尔积导致CPU使用率高"),
+    ("root_cause.intra.J010_E002", "Join类型不优导致数据传输过多"),
+    ("root_cause.intra.J011_G002", "Broadcast端数据量大导致节点内存高"),
+    ("root_cause.intra.J011_J003", "Broadcast端数据量大导致HashTable内存高"),
+    ("root_cause.intra.P001_G002", "复杂表达式计算导致CPU占用高"),
+    ("root_cause.intra.Q003_Q001", "磁盘溢出导致查询变慢可能超时"),
+    ("root_cause.intra.Q006_Q001", "资源队列等待可能导致超时"),
+    ("root_cause.intra.Q008_Q001", "调度开销大导致查询变慢"),
+    ("root_cause.intra.S001_G003", "数据倾斜导致执行时间倾斜"),
+    ("root_cause.intra.S003_S002", "过滤效果差导致全表扫描"),
+    ("root_cause.intra.S007_Q004", "IO瓶颈导致CPU利用率低"),
+    ("root_cause.intra.S008_S012_S013_S003", "索引未生效导致过滤效果差"),
+    ("root_cause.intra.S009_G001", "缓存命中率低导致SCAN耗时长"),
+    ("root_cause.intra.S009_Q004", "缓存未命中导致等待远程IO，CPU空闲"),
+    ("root_cause.intra.S009_S007", "缓存命中率低导致IO瓶颈"),
+    ("root_cause.intra.S010_S007", "高压缩率导致解压开销大"),
+    ("root_cause.intra.S014_S007", "Segment过多导致IO瓶颈"),
+    ("root_cause.intra.S016_S006_S007", "小文件/碎片化导致IO瓶颈"),
+    ("root_cause.intra.S017_G001", "Stripe碎片化导致SCAN耗时长"),
+    ("root_cause.intra.S017_S007", "Stripe碎片化导致IO瓶颈"),
+    ("root_cause.intra.S017_S018", "Stripe碎片化导致IO等待时间长"),
+    ("root_cause.intra.S018_G001", "IO等待时间长导致SCAN耗时长"),
+    ("root_cause.intra.S018_Q004", "IO等待导致CPU空闲"),
+    ("root_cause.intra.T001_Q003", "排序数据量过大导致溢出"),
+    ("root_cause.intra.T002_T001", "TopN未优化导致排序数据量大"),
+    ("root_cause.intra.T003_T001", "全量排序导致数据量过大"),
+    ("root_cause.intra.T004_G002", "多排序键导致CPU占用高"),
+    ("root_cause.summary_multiple", "发现 {0} 个独立根因，主要包括: {1}。建议按优先级依次解决"),
+    ("root_cause.summary_none", "未发现明显的性能问题根因"),
+    ("root_cause.summary_single", "发现 1 个根因: {0}"),
+    ("root_cause.summary_single_with_symptoms", "发现 1 个根因: {0}，导致了 {1} 个下游问题"),
+];
@@ -44,6 +44,8 @@
 //! ```
 
 pub mod analyzer;
+pub mod export;
+pub mod i18n;
 pub mod models;
 pub mod parser;
 
@@ -51,9 +53,12 @@ pub mod parser;
 mod tests;
 
 pub use analyzer::RuleEngine;
+pub use i18n::Locale;
 pub use models::*;
 pub use parser::ProfileComposer;
 
+use analyzer::rules::Diagnostic;
+use i18n::{msg, render_all};
 use std::collections::HashMap;
 
 /// Cluster session variables fetched from the live cluster
@@ -67,6 +72,10 @@ pub struct AnalysisContext {
     pub cluster_variables: Option<ClusterVariables>,
     /// Cluster ID for baseline lookup
     pub cluster_id: Option<i64>,
+    /// Output language of diagnostics, conclusions and suggestions
+    pub locale: Locale,
+    /// EXPLAIN COSTS and statistics freshness for cardinality analysis
+    pub cardinality_input: Option<analyzer::CardinalityInput>,
 }
 
 /// Analyze a profile text and return complete analysis results
@@ -172,51 +181,41 @@ pub fn analyze_profile_with_context(
         }
     }
 
+    if let (Some(total), Some(missing)) =
+        (summary.total_instance_count, summary.missing_instance_count)
+    {
+        let missing_pct = (missing as f64 / total as f64 * 100.0).round() as i32;
+        summary.profile_completeness_warning =
+            Some(msg!("profile.incomplete", total, missing, missing_pct).render(context.locale));
+    }
+
     let summary = Some(summary);
     let mut execution_tree = execution_tree;
 
+    let cardinality_analysis = match (&execution_tree, &context.cardinality_input) {
+        (Some(tree), Some(input)) => analyzer::CardinalityAnalyzer::analyze(
+            tree,
+            input,
+            profile.summary.default_db.as_deref(),
+        ),
+        _ => None,
+    };
+
+    // Shared by diagnostic ranking, scoring and the response
+    let critical_path = analyzer::CriticalPathAnalyzer::analyze(&profile);
+
     let rule_engine = RuleEngine::new();
-    let rule_diagnostics = rule_engine.analyze_with_baseline(
+    let rule_diagnostics = rule_engine.analyze_with_cardinality(
         &profile,
         context.cluster_variables.as_ref(),
         context.cluster_id,
+        cardinality_analysis.as_ref(),
+        critical_path.as_ref(),
     );
 
     let diagnostics: Vec<DiagnosticResult> = rule_diagnostics
         .iter()
-        .map(|d| DiagnosticResult {
-            rule_id: d.rule_id.clone(),
-            rule_name: d.rule_name.clone(),
-            severity: format!("{:?}", d.severity),
-            node_path: d.node_path.clone(),
-            plan_node_id: d.plan_node_id,
-            message: d.message.clone(),
-            reason: d.reason.clone(),
-            suggestions: d.suggestions.clone(),
-            parameter_suggestions: d
-                .parameter_suggestions
-                .iter()
-                .map(|p| ParameterTuningSuggestion {
-                    name: p.name.clone(),
-                    param_type: format!("{:?}", p.param_type),
-                    current: p.current.clone(),
-                    recommended: p.recommended.clone(),
-                    command: p.command.clone(),
-                    description: p.description.clone(),
-                    impact: p.impact.clone(),
-                })
-                .collect(),
-
-            threshold_metadata: d
-                .threshold_metadata
-                .as_ref()
-                .map(|tm| ThresholdMetadataResult {
-                    threshold_value: tm.threshold_value,
-                    threshold_source: tm.threshold_source.clone(),
-                    baseline_p95_ms: tm.baseline_p95_ms,
-                    baseline_sample_count: tm.baseline_sample_count,
-                }),
-        })
+        .map(|d| d.to_result(context.locale))
         .collect();
 
     let mut node_diagnostics: HashMap<i32, Vec<DiagnosticResult>> = HashMap::new();
@@ -240,16 +239,28 @@ pub fn analyze_profile_with_context(
         }
     }
 
-    let aggregated_diagnostics = aggregate_diagnostics(&diagnostics);
+    let aggregated_diagnostics = aggregate_diagnostics(&rule_diagnostics, context.locale);
 
-    let hotspots: Vec<HotSpot> = rule_diagnostics.iter().map(|d| d.to_hotspot()).collect();
+    let hotspots: Vec<HotSpot> = rule_diagnostics
+        .iter()
+        .map(|d| d.to_hotspot(context.locale))
+        .collect();
+
+    let conclusion =
+        RuleEngine::generate_conclusion(&rule_diagnostics, &profile).render(context.locale);
+    let all_suggestions =
+        render_all(&RuleEngine::generate_suggestions(&rule_diagnostics), context.locale);
+    let performance_score = RuleEngine::calculate_performance_score(
+        &rule_diagnostics,
+        &profile,
+        critical_path.as_ref(),
+    );
 
-    let conclusion = RuleEngine::generate_conclusion(&rule_diagnostics, &profile);
-    let all_suggestions = RuleEngine::generate_suggestions(&rule_diagnostics);
-    let performance_score = RuleEngine::calculate_performance_score(&rule_diagnostics, &profile);
+    let timeline = analyzer::TimelineAnalyzer::analyze(&profile);
+    let runtime_filters = analyzer::RuntimeFilterAnalyzer::analyze(&profile);
 
     let root_cause_analysis = if !rule_diagnostics.is_empty() {
-        Some(analyzer::RootCauseAnalyzer::analyze(&rule_diagnostics))
+        Some(analyzer::RootCauseAnalyzer::analyze(&rule_diagnostics, context.locale))
     } else {
         None
     };
@@ -267,16 +278,20 @@ pub fn analyze_profile_with_context(
         profile_content: Some(profile_text.to_string()),
         fragments: profile.fragments.clone(),
         root_cause_analysis,
+        critical_path,
+        cardinality_analysis,
+        timeline,
+        runtime_filters,
         llm_analysis: None, // Filled by handler if LLM is enabled
     })
 }
 
 /// Aggregate diagnostics by rule_id for overview display
 /// Groups multiple diagnostics of the same rule together
-fn aggregate_diagnostics(diagnostics: &[DiagnosticResult]) -> Vec<AggregatedDiagnostic> {
+fn aggregate_diagnostics(diagnostics: &[Diagnostic], locale: Locale) -> Vec<AggregatedDiagnostic> {
     use std::collections::HashMap;
 
-    let mut groups: HashMap<String, Vec<&DiagnosticResult>> = HashMap::new();
+    let mut groups: HashMap<String, Vec<&Diagnostic>> = HashMap::new();
     for diag in diagnostics {
         groups.entry(diag.rule_id.clone()).or_default().push(diag);
     }
@@ -284,19 +299,22 @@ fn aggregate_diagnostics(diagnostics: &[DiagnosticResult]) -> Vec<AggregatedDiag
     let mut result: Vec<AggregatedDiagnostic> = groups
         .into_iter()
         .map(|(rule_id, diags)| {
-            let first = diags.first().unwrap();
-            let affected_nodes: Vec<String> = diags.iter().map(|d| d.node_path.clone()).collect();
+            let results: Vec<DiagnosticResult> =
+                diags.iter().map(|d| d.to_result(locale)).collect();
+            let first = results.first().unwrap();
+            let affected_nodes: Vec<String> = results.iter().map(|d| d.node_path.clone()).collect();
             let node_count = affected_nodes.len();
 
-            let suggestions = merge_suggestions(&diags, node_count);
+            let suggestions =
+                render_all(&analyzer::RootCauseAnalyzer::merge_suggestions(&diags), locale);
 
-            let parameter_suggestions = diags
+            let parameter_suggestions = results
                 .iter()
                 .find(|d| !d.parameter_suggestions.is_empty())
                 .map(|d| d.parameter_suggestions.clone())
                 .unwrap_or_default();
 
-            let severity = diags
+            let severity = results
                 .iter()
                 .map(|d| &d.severity)
                 .max_by(|a, b| severity_order(a).cmp(&severity_order(b)))
@@ -304,7 +322,7 @@ fn aggregate_diagnostics(diagnostics: &[DiagnosticResult]) -> Vec<AggregatedDiag
                 .clone();
 
             let message = if node_count > 1 {
-                format!("{} 个节点存在此问题", node_count)
+                msg!("aggregate.node_count", node_count).render(locale)
             } else {
                 first.message.clone()
             };
@@ -335,70 +353,6 @@ fn aggregate_diagnostics(diagnostics: &[DiagnosticResult]) -> Vec<AggregatedDiag
     result
 }
 
-/// Merge suggestions intelligently to avoid repetition
-/// When multiple nodes have similar suggestions (differing only by table name), consolidate them
-fn merge_suggestions(diags: &[&DiagnosticResult], node_count: usize) -> Vec<String> {
-    if node_count == 1 {
-        return diags
-            .first()
-            .map(|d| d.suggestions.clone())
-            .unwrap_or_default();
-    }
-
-    let tables: Vec<String> = diags
-        .iter()
-        .filter_map(|d| extract_table_from_reason(&d.reason))
-        .collect::<std::collections::HashSet<_>>()
-        .into_iter()
-        .collect();
-
-    let first_sug = diags
-        .first()
-        .and_then(|d| d.suggestions.first())
-        .map(|s| s.as_str())
-        .unwrap_or("");
-
-    if first_sug.contains("外表小文件合并方案") || first_sug.contains("Compaction 合并碎片")
-    {
-        let tables_str = if tables.is_empty() {
-            format!("{} 个表", node_count)
-        } else if tables.len() <= 3 {
-            tables.join(", ")
-        } else {
-            format!("{} 等 {} 个表", tables.first().unwrap_or(&"unknown".to_string()), tables.len())
-        };
-
-        let generic = if first_sug.contains("外表小文件合并方案") {
-            format!(
-                "外表小文件合并 (涉及: {}): ①ALTER TABLE <table> PARTITION(...) CONCATENATE; \
-                 ②INSERT OVERWRITE TABLE <table> SELECT * FROM <table>; \
-                 ③Spark: df.repartition(N).saveAsTable('<table>'); \
-                 ④SET connector_io_tasks_per_scan_operator=64",
-                tables_str
-            )
-        } else {
-            format!("执行 Compaction: ALTER TABLE <{}> COMPACT", tables_str)
-        };
-        return vec![generic];
-    }
-
-    let mut seen = std::collections::HashSet::new();
-    diags
-        .iter()
-        .flat_map(|d| d.suggestions.iter())
-        .filter(|s| seen.insert(s.as_str()))
-        .take(3)
-        .cloned()
-        .collect()
-}
-
-/// Extract table name from reason field like "外表「table_name」的 ORC..."
-fn extract_table_from_reason(reason: &str) -> Option<String> {
-    let start = reason.find('「')?;
-    let end = reason.find('」')?;
-    if end > start { Some(reason[start + 3..end].to_string()) } else { None }
-}
-
 /// Get severity order for sorting (higher = more severe)
 fn severity_order(severity: &str) -> u8 {
     match severity {
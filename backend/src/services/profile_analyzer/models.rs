@@ -365,6 +365,10 @@ pub struct Fragment {
     pub id: String,
     pub backend_addresses: Vec<String>,
     pub instance_ids: Vec<String>,
+    /// Fragment-level counters (instance memory, driver counts, merge time)
+    /// including their `__MAX_OF_`/`__MIN_OF_` instance extremes
+    #[serde(default)]
+    pub metrics: HashMap<String, String>,
     pub pipelines: Vec<Pipeline>,
 }
 
@@ -610,6 +614,18 @@ pub struct ProfileAnalysisResponse {
     /// Root cause analysis result (rule-based, without LLM)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub root_cause_analysis: Option<super::analyzer::RootCauseAnalysis>,
+    /// Chain of operators that determined the wall-clock time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical_path: Option<super::analyzer::CriticalPath>,
+    /// Optimizer estimated rows vs actual rows (requires EXPLAIN COSTS from the cluster)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cardinality_analysis: Option<super::analyzer::CardinalityAnalysis>,
+    /// Fragment/pipeline driver timeline per BE (Gantt data)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeline: Option<super::analyzer::ExecutionTimeline>,
+    /// Join runtime filters with build cost and rows filtered on the probe side
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime_filters: Option<super::analyzer::RuntimeFilterReport>,
     /// LLM-enhanced analysis result (async loaded, may be None initially)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub llm_analysis: Option<LLMEnhancedAnalysis>,
@@ -3,12 +3,12 @@
 //! Orchestrates all parsing components to produce a complete Profile structure.
 
 use crate::services::profile_analyzer::models::{
-    ExecutionInfo, ExecutionTreeNode, Fragment, HotSeverity, OperatorMetrics, PlannerInfo, Profile,
+    ExecutionInfo, ExecutionTreeNode, Fragment, HotSeverity, OperatorMetrics, Profile,
     ProfileSummary, TopNode, TopologyGraph, constants::time_thresholds,
 };
 use crate::services::profile_analyzer::parser::core::{
-    FragmentParser, MetricsParser, OperatorParser, SectionParser, TopologyParser, TreeBuilder,
-    ValueParser,
+    DorisProfileAdapter, FragmentParser, MetricsParser, OperatorParser, SectionParser,
+    TopologyParser, TreeBuilder, ValueParser,
 };
 use crate::services::profile_analyzer::parser::error::{ParseError, ParseResult};
 use crate::services::profile_analyzer::parser::specialized::SpecializedMetricsParser;
@@ -39,12 +39,8 @@ impl ProfileComposer {
 
         let mut summary = SectionParser::parse_summary(text)?;
 
-        // Doris doesn't have Planner section, create empty PlannerInfo
-        let planner_info = if is_doris_format {
-            PlannerInfo::default()
-        } else {
-            SectionParser::parse_planner(text)?
-        };
+        // Doris has no Planner section, its planner timings come from the Execution Summary
+        let planner_info = SectionParser::parse_planner(text)?;
 
         // Doris uses MergedProfile instead of Execution
         let execution_info = if is_doris_format {
@@ -83,8 +79,11 @@ impl ProfileComposer {
 
         // Extract fragments: StarRocks from Execution section, Doris from MergedProfile section
         let fragments = if is_doris_format {
-            // Doris: Extract fragments from MergedProfile section
-            let frags = Self::extract_fragments_from_merged_profile(text)?;
+            // Doris: Extract fragments from MergedProfile section, then map operator names and
+            // counters onto the StarRocks ones and fill instance info from DetailProfile
+            let mut frags = Self::extract_fragments_from_merged_profile(text)?;
+            DorisProfileAdapter::normalize(&mut frags);
+            DorisProfileAdapter::apply_detail_profile(text, &mut frags);
             tracing::debug!("[Doris] Extracted {} fragments from MergedProfile", frags.len());
             for (i, frag) in frags.iter().enumerate() {
                 tracing::debug!("[Doris] Fragment {}: {} pipelines", i, frag.pipelines.len());
@@ -103,9 +102,14 @@ impl ProfileComposer {
             FragmentParser::extract_all_fragments(text)
         };
 
-        let topology_result = Self::extract_topology_json(&execution_info.topology)
-            .and_then(|json| TopologyParser::parse_with_fragments(&json, text, &fragments))
-            .ok();
+        // Doris profiles carry no topology JSON, derive it from the pipelines instead
+        let topology_result = if is_doris_format {
+            DorisProfileAdapter::build_topology(&fragments)
+        } else {
+            Self::extract_topology_json(&execution_info.topology)
+                .and_then(|json| TopologyParser::parse_with_fragments(&json, text, &fragments))
+                .ok()
+        };
 
         let execution_tree = if let Some(ref topology) = topology_result {
             let nodes = self.build_nodes_from_topology_and_fragments(topology, &fragments)?;
@@ -165,11 +169,14 @@ impl ProfileComposer {
 
         let rest = &text[start + marker.len()..];
 
-        // Find the end: either DetailProfile: or Execution Profile or end of text
+        // Find the end: either DetailProfile: / DetailProfile(<query id>): or Execution Profile
+        // or end of text
         // Note: DetailProfile comes after MergedProfile and contains Execution Profile
         // Also check for "Execution Profile" which may appear without DetailProfile prefix
         let end_marker = if let Some(pos) = rest.find("\nDetailProfile:") {
             Some(("\nDetailProfile:", pos))
+        } else if let Some(pos) = rest.find("\nDetailProfile(") {
+            Some(("\nDetailProfile(", pos))
         } else if let Some(pos) = rest.find("\nExecution Profile") {
             Some(("\nExecution Profile", pos))
         } else {
@@ -740,17 +747,8 @@ impl ProfileComposer {
         summary.missing_instance_count =
             if missing_fragments > 0 { Some(missing_fragments as i32) } else { None };
 
-        let is_complete = missing_fragments == 0;
-        summary.is_profile_complete = Some(is_complete);
-
-        if !is_complete && total_fragments > 0 {
-            let missing_pct =
-                (missing_fragments as f64 / total_fragments as f64 * 100.0).round() as i32;
-            summary.profile_completeness_warning = Some(format!(
-                "Profile 数据不完整: {} 个 Fragment 中有 {} 个 ({}%) 的执行数据缺失，建议稍后重新查询",
-                total_fragments, missing_fragments, missing_pct
-            ));
-        }
+        // The warning text itself is rendered per locale by the analyzer
+        summary.is_profile_complete = Some(missing_fragments == 0);
     }
 }
 
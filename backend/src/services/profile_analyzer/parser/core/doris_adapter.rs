@@ -0,0 +1,471 @@
+//! Doris profile adapter
+//!
+//! Maps Doris `MergedProfile`/`DetailProfile` operators and counters onto the
+//! StarRocks shapes the rest of the analyzer works with, and derives the plan
+//! topology that Doris profiles do not print.
+
+use crate::services::profile_analyzer::models::{Fragment, TopologyGraph, TopologyNode};
+use crate::services::profile_analyzer::parser::core::OperatorParser;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
+
+static FRAGMENT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*Fragment\s+(\d+):").unwrap());
+
+static HOST_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"FragmentLevelProfile:\s*\(host=TNetworkAddress\(hostname:([^,]+),\s*port:(\d+)\)")
+        .unwrap()
+});
+
+/// Plan node id given to the final sink, which Doris reports with an arbitrary id
+const FINAL_SINK_PLAN_ID: i32 = -1;
+
+/// Doris CommonCounters and their StarRocks CommonMetrics names
+const COMMON_ALIASES: &[(&str, &str)] = &[
+    ("ExecTime", "OperatorTotalTime"),
+    ("RowsProduced", "PullRowNum"),
+    ("BlocksProduced", "PullChunkNum"),
+    ("InputRows", "PushRowNum"),
+];
+
+/// Doris CustomCounters and their StarRocks UniqueMetrics names
+const UNIQUE_ALIASES: &[(&str, &str)] = &[
+    ("ScanBytes", "BytesRead"),
+    ("ScanRows", "RawRowsRead"),
+    ("RowsVectorPredFiltered", "PredFilterRows"),
+    ("RowsStatsFiltered", "ZoneMapIndexFilterRows"),
+    ("RowsBloomFilterFiltered", "BloomFilterFilterRows"),
+    ("RowsBitmapIndexFiltered", "BitmapIndexFilterRows"),
+    ("RowsDelFiltered", "DelVecFilterRows"),
+    ("IOTimer", "IOTime"),
+    ("FileReadTime", "IOTime"),
+    ("ScannerGetBlockTime", "ScanTime"),
+    ("FileScannerGetBlockTime", "ScanTime"),
+    ("FileNumber", "ScanFileCount"),
+    ("MemoryUsageHashTable", "HashTableMemoryUsage"),
+    ("ProbeWhenSearchHashTableTime", "SearchHashTableTime"),
+    ("RuntimeFilterComputeTime", "RuntimeFilterBuildTime"),
+    ("RpcSumTime", "NetworkTime"),
+];
+
+/// Doris pipeline counters and their StarRocks pipeline metric names
+const PIPELINE_ALIASES: &[(&str, &str)] =
+    &[("WaitWorkerTime", "ScheduleTime"), ("ExecuteTime", "ActiveTime")];
+
+/// Adapter that makes Doris profiles look like StarRocks ones to the analyzer
+pub struct DorisProfileAdapter;
+
+impl DorisProfileAdapter {
+    /// Normalize operator names, plan node ids and counters of MergedProfile fragments
+    ///
+    /// - `OLAP_SCAN_OPERATOR(id=0. nereids_id=3)` becomes `OLAP_SCAN`; a local exchange
+    ///   type such as `(LOCAL_MERGE_SORT)` is kept as the `PartType` unique metric
+    /// - the final sink gets plan node id `-1`, like StarRocks
+    /// - merged values (`sum S, avg A, max M, min N`) are split into the base value
+    ///   (sum, or avg for times) plus `__MAX_OF_`/`__MIN_OF_` counters
+    /// - StarRocks counter names are added next to their Doris counterparts
+    pub fn normalize(fragments: &mut [Fragment]) {
+        for fragment in fragments.iter_mut() {
+            for pipeline in &mut fragment.pipelines {
+                Self::split_merged_values(&mut pipeline.metrics);
+                Self::add_aliases(&mut pipeline.metrics, PIPELINE_ALIASES);
+
+                for operator in &mut pipeline.operators {
+                    let (name, part_type) = Self::pure_operator_name(&operator.name);
+                    if let Some(part_type) = part_type {
+                        operator
+                            .unique_metrics
+                            .entry("PartType".to_string())
+                            .or_insert(part_type);
+                    }
+                    if Self::is_final_sink(&name) {
+                        operator.plan_node_id = Some(FINAL_SINK_PLAN_ID.to_string());
+                    }
+
+                    Self::split_merged_values(&mut operator.common_metrics);
+                    Self::split_merged_values(&mut operator.unique_metrics);
+                    Self::add_aliases(&mut operator.common_metrics, COMMON_ALIASES);
+                    Self::add_aliases(&mut operator.unique_metrics, UNIQUE_ALIASES);
+
+                    // Rules read UniqueMetrics, so operator-specific row counts land there
+                    let common = operator.common_metrics.clone();
+                    let unique = &mut operator.unique_metrics;
+                    Self::copy_metric(
+                        &common,
+                        unique,
+                        "MemoryUsagePeak",
+                        "OperatorPeakMemoryUsage",
+                    );
+                    match name.as_str() {
+                        "HASH_JOIN_SINK" | "PARTITIONED_HASH_JOIN_SINK" => {
+                            Self::copy_metric(&common, unique, "InputRows", "BuildRows");
+                        },
+                        "AGGREGATION_SINK"
+                        | "STREAMING_AGGREGATION"
+                        | "DISTINCT_STREAMING_AGGREGATION" => {
+                            Self::copy_metric(&common, unique, "InputRows", "InputRowCount");
+                            Self::copy_metric(&common, unique, "RowsProduced", "OutputRowCount");
+                        },
+                        "AGGREGATION" => {
+                            Self::copy_metric(&common, unique, "RowsProduced", "OutputRowCount");
+                        },
+                        _ => {},
+                    }
+
+                    operator.name = name;
+                }
+            }
+        }
+    }
+
+    /// Fill backend addresses and instance counts from the `DetailProfile` section
+    ///
+    /// Each fragment instance starts with
+    /// `FragmentLevelProfile:(host=TNetworkAddress(hostname:H, port:P)):`.
+    pub fn apply_detail_profile(text: &str, fragments: &mut [Fragment]) {
+        let Some(start) = text.find("\nDetailProfile") else {
+            return;
+        };
+
+        let mut hosts: HashMap<String, Vec<String>> = HashMap::new();
+        let mut instances: HashMap<String, usize> = HashMap::new();
+        let mut current: Option<String> = None;
+
+        for line in text[start..].lines() {
+            if let Some(caps) = FRAGMENT_REGEX.captures(line) {
+                current = Some(caps[1].to_string());
+            } else if let (Some(id), Some(caps)) = (&current, HOST_REGEX.captures(line)) {
+                let address = format!("{}:{}", caps[1].trim(), &caps[2]);
+                let addresses = hosts.entry(id.clone()).or_default();
+                if !addresses.contains(&address) {
+                    addresses.push(address);
+                }
+                *instances.entry(id.clone()).or_default() += 1;
+            }
+        }
+
+        for fragment in fragments.iter_mut() {
+            if fragment.backend_addresses.is_empty()
+                && let Some(addresses) = hosts.remove(&fragment.id)
+            {
+                fragment.backend_addresses = addresses;
+            }
+            if let Some(count) = instances.get(&fragment.id) {
+                fragment
+                    .metrics
+                    .entry("InstanceNum".to_string())
+                    .or_insert(count.to_string());
+            }
+        }
+    }
+
+    /// Derive the plan topology from the operator order inside each pipeline
+    ///
+    /// A Doris pipeline lists its operators from sink to source, so consecutive
+    /// operators are parent and child. Build sides (edges from a `*_SINK`) are
+    /// ordered after probe sides, matching StarRocks' `[probe, build]` children.
+    /// Returns `None` when the plan cannot be rooted under the final sink.
+    pub fn build_topology(fragments: &[Fragment]) -> Option<TopologyGraph> {
+        let mut names: BTreeMap<i32, (String, bool)> = BTreeMap::new();
+        let mut edges: Vec<(i32, i32, bool)> = Vec::new();
+
+        for pipeline in fragments.iter().flat_map(|f| &f.pipelines) {
+            let mut previous: Option<(i32, bool)> = None;
+            for operator in &pipeline.operators {
+                let Some(plan_id) = operator
+                    .plan_node_id
+                    .as_ref()
+                    .and_then(|id| id.parse::<i32>().ok())
+                else {
+                    continue;
+                };
+                let is_sink = operator.name.ends_with("_SINK");
+
+                // Name the plan node after its source operator (HASH_JOIN, not HASH_JOIN_SINK)
+                let canonical = OperatorParser::canonical_topology_name(&operator.name);
+                match names.get(&plan_id) {
+                    Some((_, from_sink)) if !*from_sink || is_sink => {},
+                    _ => {
+                        names.insert(plan_id, (canonical, is_sink));
+                    },
+                }
+
+                if let Some((parent, parent_is_sink)) = previous
+                    && parent != plan_id
+                {
+                    edges.push((parent, plan_id, parent_is_sink));
+                }
+                previous = Some((plan_id, is_sink));
+            }
+        }
+
+        let mut children: HashMap<i32, Vec<i32>> = HashMap::new();
+        for pass_sink_edges in [false, true] {
+            for &(parent, child, from_sink) in &edges {
+                let list = children.entry(parent).or_default();
+                if from_sink == pass_sink_edges && !list.contains(&child) {
+                    list.push(child);
+                }
+            }
+        }
+
+        let root_id = *children.get(&FINAL_SINK_PLAN_ID)?.first()?;
+
+        let nodes = names
+            .into_iter()
+            .map(|(id, (name, _))| TopologyNode {
+                id,
+                name,
+                properties: HashMap::new(),
+                // The tree builder attaches the root under the sink itself
+                children: if id == FINAL_SINK_PLAN_ID {
+                    Vec::new()
+                } else {
+                    children.remove(&id).unwrap_or_default()
+                },
+            })
+            .collect();
+
+        Some(TopologyGraph { root_id, nodes })
+    }
+
+    /// `LOCAL_EXCHANGE_OPERATOR (LOCAL_MERGE_SORT)` -> (`LOCAL_EXCHANGE`, `LOCAL_MERGE_SORT`)
+    fn pure_operator_name(full_name: &str) -> (String, Option<String>) {
+        let (head, rest) = match full_name.find('(') {
+            Some(pos) => (&full_name[..pos], Some(&full_name[pos + 1..])),
+            None => (full_name, None),
+        };
+        let name = head.trim();
+        let name = name.strip_suffix("_OPERATOR").unwrap_or(name).to_string();
+
+        let part_type = rest
+            .and_then(|r| r.split(')').next())
+            .map(str::trim)
+            .filter(|t| !t.is_empty() && !t.contains('='))
+            .map(str::to_string);
+
+        (name, part_type)
+    }
+
+    fn is_final_sink(name: &str) -> bool {
+        matches!(name, "RESULT_SINK" | "RESULT_FILE_SINK") || name.ends_with("TABLE_SINK")
+    }
+
+    /// Split `sum S, avg A, max M, min N` into `key`, `__MAX_OF_key` and `__MIN_OF_key`
+    fn split_merged_values(metrics: &mut HashMap<String, String>) {
+        let mut extremes = Vec::new();
+
+        for (key, value) in metrics.iter_mut() {
+            let Some(parts) = Self::parse_merged_value(value) else {
+                continue;
+            };
+            let base = parts.get("sum").or_else(|| parts.get("avg"));
+            if let Some(base) = base {
+                *value = base.clone();
+            }
+            for (stat, prefix) in [("max", "__MAX_OF_"), ("min", "__MIN_OF_")] {
+                if let Some(v) = parts.get(stat) {
+                    extremes.push((format!("{}{}", prefix, key), v.clone()));
+                }
+            }
+        }
+
+        for (key, value) in extremes {
+            metrics.entry(key).or_insert(value);
+        }
+    }
+
+    /// Parse a merged counter value, `None` unless every part is a labelled statistic
+    fn parse_merged_value(value: &str) -> Option<HashMap<&'static str, String>> {
+        let mut parts = HashMap::new();
+        for part in value.split(", ") {
+            let part = part.trim();
+            let stat = ["sum", "avg", "max", "min"]
+                .into_iter()
+                .find(|s| part.starts_with(&format!("{} ", s)))?;
+            let v = part[stat.len()..].trim();
+            if v.is_empty() {
+                return None;
+            }
+            parts.insert(stat, v.to_string());
+        }
+        (!parts.is_empty()).then_some(parts)
+    }
+
+    /// Add StarRocks names, with their instance extremes, unless already present
+    fn add_aliases(metrics: &mut HashMap<String, String>, aliases: &[(&str, &str)]) {
+        for (from, to) in aliases {
+            let source = metrics.clone();
+            Self::copy_metric(&source, metrics, from, to);
+        }
+    }
+
+    fn copy_metric(
+        source: &HashMap<String, String>,
+        target: &mut HashMap<String, String>,
+        from: &str,
+        to: &str,
+    ) {
+        for prefix in ["", "__MAX_OF_", "__MIN_OF_"] {
+            if let Some(value) = source.get(&format!("{}{}", prefix, from)) {
+                target
+                    .entry(format!("{}{}", prefix, to))
+                    .or_insert_with(|| value.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::profile_analyzer::models::{Operator, Pipeline};
+
+    fn operator(name: &str, plan_id: &str, common: &[(&str, &str)]) -> Operator {
+        Operator {
+            name: name.to_string(),
+            plan_node_id: Some(plan_id.to_string()),
+            operator_id: None,
+            common_metrics: common
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            unique_metrics: HashMap::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn fragment(id: &str, pipelines: Vec<Vec<Operator>>) -> Fragment {
+        Fragment {
+            id: id.to_string(),
+            backend_addresses: Vec::new(),
+            instance_ids: Vec::new(),
+            metrics: HashMap::new(),
+            pipelines: pipelines
+                .into_iter()
+                .enumerate()
+                .map(|(i, operators)| Pipeline {
+                    id: i.to_string(),
+                    metrics: HashMap::new(),
+                    operators,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_pure_operator_name() {
+        assert_eq!(
+            DorisProfileAdapter::pure_operator_name("OLAP_SCAN_OPERATOR"),
+            ("OLAP_SCAN".to_string(), None)
+        );
+        assert_eq!(
+            DorisProfileAdapter::pure_operator_name("LOCAL_EXCHANGE_OPERATOR (LOCAL_MERGE_SORT)"),
+            ("LOCAL_EXCHANGE".to_string(), Some("LOCAL_MERGE_SORT".to_string()))
+        );
+        assert_eq!(
+            DorisProfileAdapter::pure_operator_name("DATA_STREAM_SINK_OPERATOR(dest_id=1)"),
+            ("DATA_STREAM_SINK".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_normalize_splits_and_aliases_counters() {
+        let mut fragments = vec![fragment(
+            "0",
+            vec![vec![operator(
+                "HASH_JOIN_SINK_OPERATOR",
+                "3",
+                &[
+                    ("ExecTime", "avg 402.610ms, max 512.004ms, min 301.772ms"),
+                    (
+                        "InputRows",
+                        "sum 15.000000M (15000000), avg 625.000K (625000), max 640.112K (640112), min 610.004K (610004)",
+                    ),
+                ],
+            )]],
+        )];
+
+        DorisProfileAdapter::normalize(&mut fragments);
+        let op = &fragments[0].pipelines[0].operators[0];
+
+        assert_eq!(op.name, "HASH_JOIN_SINK");
+        assert_eq!(op.common_metrics["OperatorTotalTime"], "402.610ms");
+        assert_eq!(op.common_metrics["__MAX_OF_OperatorTotalTime"], "512.004ms");
+        assert_eq!(op.common_metrics["PushRowNum"], "15.000000M (15000000)");
+        assert_eq!(op.unique_metrics["BuildRows"], "15.000000M (15000000)");
+        assert_eq!(op.unique_metrics["__MIN_OF_BuildRows"], "610.004K (610004)");
+    }
+
+    #[test]
+    fn test_normalize_keeps_unlabelled_values() {
+        let mut metrics: HashMap<String, String> =
+            [("JoinType", "INNER_JOIN"), ("RuntimeFilterInfo", "sum , avg , max , min")]
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+
+        DorisProfileAdapter::split_merged_values(&mut metrics);
+
+        assert_eq!(metrics.len(), 2);
+        assert_eq!(metrics["JoinType"], "INNER_JOIN");
+    }
+
+    #[test]
+    fn test_build_topology_orders_probe_before_build() {
+        let mut fragments = vec![
+            fragment(
+                "0",
+                vec![vec![
+                    operator("RESULT_SINK_OPERATOR", "2147483647", &[]),
+                    operator("EXCHANGE_OPERATOR", "4", &[]),
+                ]],
+            ),
+            fragment(
+                "1",
+                vec![
+                    vec![
+                        operator("DATA_STREAM_SINK_OPERATOR(dest_id=4)", "4", &[]),
+                        operator("HASH_JOIN_OPERATOR", "3", &[]),
+                        operator("OLAP_SCAN_OPERATOR", "0", &[]),
+                    ],
+                    vec![
+                        operator("HASH_JOIN_SINK_OPERATOR", "3", &[]),
+                        operator("EXCHANGE_OPERATOR", "2", &[]),
+                    ],
+                ],
+            ),
+            fragment(
+                "2",
+                vec![vec![
+                    operator("DATA_STREAM_SINK_OPERATOR(dest_id=2)", "2", &[]),
+                    operator("OLAP_SCAN_OPERATOR", "1", &[]),
+                ]],
+            ),
+        ];
+        DorisProfileAdapter::normalize(&mut fragments);
+
+        let topology = DorisProfileAdapter::build_topology(&fragments).unwrap();
+        let node = |id: i32| topology.nodes.iter().find(|n| n.id == id).unwrap();
+
+        assert_eq!(topology.root_id, 4);
+        assert_eq!(node(-1).name, "RESULT_SINK");
+        assert!(node(-1).children.is_empty());
+        assert_eq!(node(4).name, "EXCHANGE");
+        assert_eq!(node(4).children, vec![3]);
+        assert_eq!(node(3).name, "HASH_JOIN");
+        assert_eq!(node(3).children, vec![0, 2]);
+        assert_eq!(node(2).children, vec![1]);
+    }
+
+    #[test]
+    fn test_apply_detail_profile() {
+        let text = "MergedProfile:\nDetailProfile(q1):\n  Fragments:\n    Fragment 1:\n      FragmentLevelProfile:(host=TNetworkAddress(hostname:10.0.0.1, port:9050)):\n      FragmentLevelProfile:(host=TNetworkAddress(hostname:10.0.0.2, port:9050)):\n";
+        let mut fragments = vec![fragment("1", Vec::new())];
+
+        DorisProfileAdapter::apply_detail_profile(text, &mut fragments);
+
+        assert_eq!(fragments[0].backend_addresses, vec!["10.0.0.1:9050", "10.0.0.2:9050"]);
+        assert_eq!(fragments[0].metrics.get("InstanceNum").map(String::as_str), Some("2"));
+    }
+}
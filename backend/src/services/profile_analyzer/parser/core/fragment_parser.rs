@@ -27,9 +27,10 @@ impl FragmentParser {
     pub fn parse_fragment(text: &str, id: &str) -> ParseResult<Fragment> {
         let backend_addresses = Self::extract_backend_addresses(text);
         let instance_ids = Self::extract_instance_ids(text);
+        let metrics = Self::extract_fragment_metrics(text);
         let pipelines = Self::parse_pipelines(text)?;
 
-        Ok(Fragment { id: id.to_string(), backend_addresses, instance_ids, pipelines })
+        Ok(Fragment { id: id.to_string(), backend_addresses, instance_ids, metrics, pipelines })
     }
 
     /// Extract all fragments from profile text
@@ -102,7 +103,14 @@ impl FragmentParser {
                 }
 
                 let pipeline_text = lines[start_idx..end_idx].join("\n");
-                let pipeline = Self::parse_single_pipeline(&pipeline_text, &id)?;
+                let mut pipeline = Self::parse_single_pipeline(&pipeline_text, &id)?;
+                // Doris reports the pipeline parallelism in the header: 0(instance_num=N)
+                if let Some(instance_num) = caps.get(3) {
+                    pipeline
+                        .metrics
+                        .entry("DegreeOfParallelism".to_string())
+                        .or_insert_with(|| instance_num.as_str().to_string());
+                }
                 pipelines.push(pipeline);
                 i = end_idx;
             } else {
@@ -139,6 +147,29 @@ impl FragmentParser {
         metrics
     }
 
+    /// Extract fragment-level metrics, i.e. the counters listed before the first pipeline.
+    /// Instance extremes (`__MAX_OF_`/`__MIN_OF_`) are kept for the execution timeline.
+    fn extract_fragment_metrics(text: &str) -> HashMap<String, String> {
+        let mut metrics = HashMap::new();
+
+        for line in text.lines() {
+            let trimmed = line.trim();
+            if PIPELINE_REGEX.is_match(trimmed) {
+                break;
+            }
+            if let Some(rest) = trimmed.strip_prefix("- ")
+                && let Some((key, value)) = rest.split_once(": ")
+            {
+                let key = key.trim();
+                if key != "BackendAddresses" && key != "InstanceIds" {
+                    metrics.insert(key.to_string(), value.trim().to_string());
+                }
+            }
+        }
+
+        metrics
+    }
+
     /// Extract operators from pipeline text
     fn extract_operators(text: &str) -> Vec<Operator> {
         let mut operators = Vec::new();
@@ -432,4 +463,19 @@ mod tests {
         assert_eq!(addrs.len(), 2);
         assert_eq!(addrs[0], "192.168.1.1:9060");
     }
+
+    #[test]
+    fn test_fragment_metrics_stop_at_first_pipeline() {
+        let text = "Fragment 1:\n   - BackendAddresses: 10.0.0.1:9060\n   - InstanceNum: 2\n   - InstancePeakMemoryUsage: 85.063 MB\n     - __MIN_OF_InstancePeakMemoryUsage: 40.910 MB\n  Pipeline (id=0):\n     - DegreeOfParallelism: 16\n";
+        let fragment = FragmentParser::parse_fragment(text, "1").unwrap();
+        assert_eq!(fragment.metrics.get("InstanceNum").map(String::as_str), Some("2"));
+        assert!(
+            fragment
+                .metrics
+                .contains_key("__MIN_OF_InstancePeakMemoryUsage")
+        );
+        assert!(!fragment.metrics.contains_key("BackendAddresses"));
+        assert!(!fragment.metrics.contains_key("DegreeOfParallelism"));
+        assert_eq!(fragment.pipelines.len(), 1);
+    }
 }
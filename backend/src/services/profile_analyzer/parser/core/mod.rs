@@ -1,5 +1,6 @@
-//! Core parsing components for StarRocks profile analysis
+//! Core parsing components for StarRocks and Doris profile analysis
 
+pub mod doris_adapter;
 pub mod fragment_parser;
 pub mod metrics_parser;
 pub mod operator_parser;
@@ -8,6 +9,7 @@ pub mod topology_parser;
 pub mod tree_builder;
 pub mod value_parser;
 
+pub use doris_adapter::DorisProfileAdapter;
 pub use fragment_parser::FragmentParser;
 pub use metrics_parser::MetricsParser;
 pub use operator_parser::OperatorParser;
@@ -60,14 +60,19 @@ impl OperatorParser {
         match name.as_str() {
             "OLAP_SCAN" => NodeType::OlapScan,
             "CONNECTOR_SCAN" => NodeType::ConnectorScan,
-            "HASH_JOIN" | "NEST_LOOP_JOIN" | "NESTLOOP_JOIN" => NodeType::HashJoin,
-            "AGGREGATE" | "AGGREGATION" => NodeType::Aggregate,
+            "FILE_SCAN" => NodeType::ConnectorScan,
+            "HASH_JOIN" | "HASH_JOIN_SINK" | "NEST_LOOP_JOIN" | "NESTLOOP_JOIN" => {
+                NodeType::HashJoin
+            },
+            "AGGREGATE" | "AGGREGATION" | "AGGREGATION_SINK" | "STREAMING_AGGREGATION" => {
+                NodeType::Aggregate
+            },
             "LIMIT" | "TOP_N" => NodeType::Limit,
-            "EXCHANGE_SINK" | "LOCAL_EXCHANGE_SINK" => NodeType::ExchangeSink,
+            "EXCHANGE_SINK" | "LOCAL_EXCHANGE_SINK" | "DATA_STREAM_SINK" => NodeType::ExchangeSink,
             "EXCHANGE" | "EXCHANGE_SOURCE" | "MERGE_EXCHANGE" => NodeType::ExchangeSource,
             "RESULT_SINK" => NodeType::ResultSink,
             "CHUNK_ACCUMULATE" => NodeType::ChunkAccumulate,
-            "SORT" => NodeType::Sort,
+            "SORT" | "SORT_SINK" => NodeType::Sort,
             "PROJECT" => NodeType::Project,
             "TABLE_FUNCTION" => NodeType::TableFunction,
             "OLAP_TABLE_SINK" => NodeType::OlapTableSink,
@@ -96,6 +101,21 @@ impl OperatorParser {
                 "EXCHANGE".to_string()
             },
 
+            // Doris splits blocking operators into a sink and a source sharing one plan node
+            "HASH_JOIN_SINK" | "PARTITIONED_HASH_JOIN" | "PARTITIONED_HASH_JOIN_SINK" => {
+                "HASH_JOIN".to_string()
+            },
+            "NESTED_LOOP_JOIN_PROBE" | "NESTED_LOOP_JOIN_BUILD_SINK" => "NESTLOOP_JOIN".to_string(),
+            "AGGREGATION_SINK"
+            | "STREAMING_AGGREGATION"
+            | "DISTINCT_STREAMING_AGGREGATION"
+            | "PARTITIONED_AGGREGATION"
+            | "PARTITIONED_AGGREGATION_SINK" => "AGGREGATE".to_string(),
+            "DATA_STREAM_SINK" => "EXCHANGE".to_string(),
+            "SORT_SINK" => "SORT".to_string(),
+            "LOCAL_EXCHANGE_SINK" => "LOCAL_EXCHANGE".to_string(),
+            "ANALYTIC_EVAL_SINK" => "ANALYTIC_EVAL".to_string(),
+
             _ => name,
         }
     }
@@ -210,5 +230,7 @@ mod tests {
         assert_eq!(OperatorParser::canonical_topology_name("OLAP_SCAN"), "OLAP_SCAN");
         assert_eq!(OperatorParser::canonical_topology_name("HASH_JOIN_BUILD"), "HASH_JOIN");
         assert_eq!(OperatorParser::canonical_topology_name("AGGREGATE_BLOCKING"), "AGGREGATE");
+        assert_eq!(OperatorParser::canonical_topology_name("HASH_JOIN_SINK"), "HASH_JOIN");
+        assert_eq!(OperatorParser::canonical_topology_name("DATA_STREAM_SINK"), "EXCHANGE");
     }
 }
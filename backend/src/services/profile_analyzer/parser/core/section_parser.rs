@@ -211,9 +211,9 @@ impl SectionParser {
     pub fn parse_planner(text: &str) -> ParseResult<PlannerInfo> {
         use crate::services::profile_analyzer::models::HMSMetrics;
 
-        // Doris format doesn't have Planner section
+        // Doris has no Planner section, its planner timings live in the Execution Summary
         if text.trim_start().starts_with("Summary:") {
-            return Ok(PlannerInfo::default());
+            return Ok(Self::parse_doris_planner(text));
         }
 
         let planner_block = Self::extract_block(text, "Planner:")?;
@@ -266,6 +266,53 @@ impl SectionParser {
         Ok(PlannerInfo { details, hms_metrics, total_time_ms, optimizer_time_ms })
     }
 
+    /// Parse Doris planner timings from the Execution Summary section
+    ///
+    /// Keeps `Parse SQL Time`, `Plan Time` and every counter nested under `Plan Time`
+    /// (Nereids phases, scan node init/finalize). Counters reported as `N/A` are skipped.
+    fn parse_doris_planner(text: &str) -> PlannerInfo {
+        let Ok(block) = Self::extract_block(text, "Execution Summary:") else {
+            return PlannerInfo::default();
+        };
+
+        let mut details = HashMap::new();
+        let mut total_time_ms = 0.0;
+        let mut optimizer_time_ms = 0.0;
+        let mut plan_indent: Option<usize> = None;
+
+        for line in block.lines() {
+            let Some(cap) = SUMMARY_LINE_REGEX.captures(line) else {
+                continue;
+            };
+            let key = cap.get(1).map(|m| m.as_str().trim()).unwrap_or("");
+            let value = cap.get(2).map(|m| m.as_str().trim()).unwrap_or("");
+            let indent = Self::get_indent(line);
+
+            let in_plan = match plan_indent {
+                Some(base) if indent > base => true,
+                _ => {
+                    plan_indent = None;
+                    false
+                },
+            };
+
+            if key == "Plan Time" {
+                plan_indent = Some(indent);
+                total_time_ms = ValueParser::parse_time_to_ms(value).unwrap_or(0.0);
+            } else if key == "Nereids Optimize Time" {
+                optimizer_time_ms = ValueParser::parse_time_to_ms(value).unwrap_or(0.0);
+            } else if !in_plan && key != "Parse SQL Time" {
+                continue;
+            }
+
+            if !value.is_empty() && value != "N/A" {
+                details.insert(key.to_string(), value.to_string());
+            }
+        }
+
+        PlannerInfo { details, total_time_ms, optimizer_time_ms, ..Default::default() }
+    }
+
     /// Parse HMS metric line: "HMS.getTable[2] 29ms" or "HMS.PARTITIONS.LIST_FS_PARTITIONS[4] 350ms"
     fn parse_hms_metric(line: &str) -> Option<(String, f64)> {
         use super::ValueParser;
@@ -558,4 +605,16 @@ Query:
         assert_eq!(summary.query_id, "b1f9a935-a967-11f0-b3d8-f69e292b7593");
         assert_eq!(summary.total_time, "1h30m");
     }
+
+    #[test]
+    fn test_parse_doris_planner() {
+        let profile = "Summary:\n   - Profile ID: q1\nExecution Summary:\n   - Parse SQL Time: 2ms\n   - Plan Time: 85ms\n     - Nereids Analysis Time: 12ms\n     - Nereids Optimize Time: 38ms\n     - Nereids Translate Time: 6ms\n       - Get Splits Time: N/A\n   - Schedule Time: 31ms\n";
+        let planner = SectionParser::parse_planner(profile).unwrap();
+        assert_eq!(planner.total_time_ms, 85.0);
+        assert_eq!(planner.optimizer_time_ms, 38.0);
+        assert_eq!(planner.details.get("Parse SQL Time").map(String::as_str), Some("2ms"));
+        assert!(planner.details.contains_key("Nereids Translate Time"));
+        assert!(!planner.details.contains_key("Get Splits Time"));
+        assert!(!planner.details.contains_key("Schedule Time"));
+    }
 }
@@ -498,7 +498,8 @@ impl TreeBuilder {
                     .flat_map(|p| &p.operators)
                     .filter_map(|op| {
                         op.common_metrics
-                            .get("ExecTime")
+                            .get("__MAX_OF_ExecTime")
+                            .or_else(|| op.common_metrics.get("ExecTime"))
                             .and_then(|time_str| {
                                 // Extract max value (prefer max, fallback to avg) for aggregation
                                 let extracted = if time_str.contains("max") {
@@ -616,6 +617,7 @@ mod tests {
             id: "0".to_string(),
             backend_addresses: vec![],
             instance_ids: vec![],
+            metrics: HashMap::new(),
             pipelines: vec![crate::services::profile_analyzer::models::Pipeline {
                 id: "0".to_string(),
                 metrics: HashMap::new(),
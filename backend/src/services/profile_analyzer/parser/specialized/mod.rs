@@ -35,11 +35,15 @@ impl SpecializedMetricsParser {
     pub fn parse(&self, operator_name: &str, text: &str) -> OperatorSpecializedMetrics {
         match operator_name.to_uppercase().as_str() {
             "OLAP_SCAN" => self.scan.parse_olap_scan(text),
-            "CONNECTOR_SCAN" => self.scan.parse_connector_scan(text),
-            "EXCHANGE_SINK" => self.exchange.parse_sink(text),
+            "CONNECTOR_SCAN" | "FILE_SCAN" => self.scan.parse_connector_scan(text),
+            "EXCHANGE_SINK" | "DATA_STREAM_SINK" => self.exchange.parse_sink(text),
             "EXCHANGE" | "EXCHANGE_SOURCE" | "MERGE_EXCHANGE" => self.exchange.parse_source(text),
-            "HASH_JOIN" | "NEST_LOOP_JOIN" | "NESTLOOP_JOIN" => self.join.parse(text),
-            "AGGREGATE" | "AGGREGATION" => self.aggregate.parse(text),
+            "HASH_JOIN" | "HASH_JOIN_SINK" | "NEST_LOOP_JOIN" | "NESTLOOP_JOIN" => {
+                self.join.parse(text)
+            },
+            "AGGREGATE" | "AGGREGATION" | "AGGREGATION_SINK" | "STREAMING_AGGREGATION" => {
+                self.aggregate.parse(text)
+            },
             "RESULT_SINK" => self.sink.parse_result_sink(text),
             "OLAP_TABLE_SINK" => self.sink.parse_olap_table_sink(text),
             _ => OperatorSpecializedMetrics::None,
@@ -69,12 +69,24 @@ impl ScanMetricsParser {
                             metrics.io_time_ns = Some(duration.as_nanos() as u64);
                         }
                     },
-                    "BytesRead" | "CompressedBytesRead" => {
+                    // BytesRead / RowsRead win over the compressed / raw counters, whatever
+                    // order the counters come in
+                    "BytesRead" => {
                         if let Ok(bytes) = ValueParser::parse_bytes(value) {
                             metrics.bytes_read = Some(bytes);
                         }
                     },
-                    "RowsRead" | "RawRowsRead" => {
+                    "CompressedBytesRead" if metrics.bytes_read.is_none() => {
+                        if let Ok(bytes) = ValueParser::parse_bytes(value) {
+                            metrics.bytes_read = Some(bytes);
+                        }
+                    },
+                    "RowsRead" => {
+                        if let Ok(rows) = ValueParser::parse_number::<u64>(value) {
+                            metrics.rows_read = Some(rows);
+                        }
+                    },
+                    "RawRowsRead" if metrics.rows_read.is_none() => {
                         if let Ok(rows) = ValueParser::parse_number::<u64>(value) {
                             metrics.rows_read = Some(rows);
                         }
@@ -87,3 +99,38 @@ impl ScanMetricsParser {
         metrics
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_counters_win_over_raw_counters_listed_first() {
+        let text = r#"
+OLAP_SCAN (plan_node_id=0):
+  CommonMetrics:
+     - OperatorTotalTime: 1s
+  UniqueMetrics:
+     - Table: lineorder
+     - RawRowsRead: 5000000
+     - CompressedBytesRead: 800.00 MB
+     - RowsRead: 1200000
+     - BytesRead: 200.00 MB
+"#;
+        let OperatorSpecializedMetrics::OlapScan(metrics) = ScanMetricsParser.parse_olap_scan(text)
+        else {
+            panic!("expected OLAP_SCAN metrics");
+        };
+        assert_eq!(metrics.rows_read, Some(1_200_000));
+        assert_eq!(metrics.bytes_read, Some(200 * 1024 * 1024));
+
+        let raw_only = "   - RawRowsRead: 5000000\n   - CompressedBytesRead: 800.00 MB\n";
+        let OperatorSpecializedMetrics::OlapScan(metrics) =
+            ScanMetricsParser.parse_olap_scan(raw_only)
+        else {
+            panic!("expected OLAP_SCAN metrics");
+        };
+        assert_eq!(metrics.rows_read, Some(5_000_000));
+        assert_eq!(metrics.bytes_read, Some(800 * 1024 * 1024));
+    }
+}
@@ -7,7 +7,7 @@
 mod profile_tests {
     use crate::services::profile_analyzer::models::*;
     use crate::services::profile_analyzer::parser::core::*;
-    use crate::services::profile_analyzer::{ProfileComposer, analyze_profile};
+    use crate::services::profile_analyzer::{Locale, ProfileComposer, analyze_profile};
     use std::fs;
     use std::path::PathBuf;
 
@@ -763,7 +763,12 @@ mod profile_tests {
 
             println!("Detected {} diagnostics for profile1", diagnostics.len());
             for diag in &diagnostics {
-                println!("  - [{}] {}: {}", diag.rule_id, diag.node_path, diag.message);
+                println!(
+                    "  - [{}] {}: {}",
+                    diag.rule_id,
+                    diag.node_path,
+                    diag.message.render(Locale::Zh)
+                );
             }
         }
 
@@ -1109,7 +1114,10 @@ Query:
 
             result.diagnostics_count = diagnostics.len();
             result.rule_ids = diagnostics.iter().map(|d| d.rule_id.clone()).collect();
-            result.messages = diagnostics.iter().map(|d| d.message.clone()).collect();
+            result.messages = diagnostics
+                .iter()
+                .map(|d| d.message.render(Locale::Zh))
+                .collect();
 
             result
         }
@@ -2111,10 +2119,19 @@ Query:
             assert!(profile.execution_tree.is_some(), "Execution tree should be built");
             let tree = profile.execution_tree.as_ref().unwrap();
             assert!(!tree.nodes.is_empty(), "Execution tree should have nodes");
+            // Sink and source operators of one plan node (e.g. DATA_STREAM_SINK/EXCHANGE)
+            // collapse into a single tree node, like StarRocks topology nodes
+            let plan_node_ids: std::collections::HashSet<&String> = profile
+                .fragments
+                .iter()
+                .flat_map(|f| &f.pipelines)
+                .flat_map(|p| &p.operators)
+                .filter_map(|op| op.plan_node_id.as_ref())
+                .collect();
             assert_eq!(
                 tree.nodes.len(),
-                total_operators,
-                "Tree nodes count should match operators count"
+                plan_node_ids.len(),
+                "Tree nodes count should match plan node count"
             );
 
             // Verify root node
@@ -2421,21 +2438,20 @@ MergedProfile:
             let tree = profile.execution_tree.as_ref().expect("Execution tree is missing");
             assert!(!tree.nodes.is_empty(), "Execution tree should not be empty");
             println!("Execution Tree Nodes: {}", tree.nodes.len());
-            assert_eq!(tree.nodes.len(), 10, "Should have exactly 10 nodes in execution tree. Found: {}", tree.nodes.len());
+            // One node per plan node: sink/source operator pairs share a node
+            assert_eq!(tree.nodes.len(), 6, "Should have exactly 6 nodes in execution tree. Found: {}", tree.nodes.len());
 
             // Step 6: Verify all expected operators are present
             // Note: For Doris format, "_OPERATOR" suffix is removed (e.g., "OLAP_SCAN_OPERATOR" -> "OLAP_SCAN")
             let operator_names: Vec<String> = tree.nodes.iter().map(|n| n.operator_name.clone()).collect();
             println!("Operators found: {:?}", operator_names);
+            assert_eq!(tree.root.operator_name, "RESULT_SINK");
             
             let expected_operators = vec![
                 "RESULT_SINK",
                 "EXCHANGE",
-                "DATA_STREAM_SINK",
                 "LOCAL_EXCHANGE",
-                "LOCAL_EXCHANGE_SINK",
                 "SORT",
-                "SORT_SINK",
                 "OLAP_SCAN",
             ];
             
@@ -2492,5 +2508,113 @@ MergedProfile:
             println!("   - Top time consuming nodes: {}", top_nodes.len());
             println!("   - Reachable Nodes: {}/{}", visited.len(), tree.nodes.len());
         }
+
+        /// Join + two-phase aggregation profile: plan topology, mapped counters,
+        /// planner timings and DetailProfile instances
+        #[test]
+        fn test_doris_profile_join_aggregate() {
+            let profile_text = load_profile("doris_join_agg.txt");
+            let profile = ProfileComposer::new().parse(&profile_text).unwrap();
+
+            // Planner timings come from the Execution Summary
+            assert_eq!(profile.planner.total_time_ms, 85.0);
+            assert_eq!(profile.planner.optimizer_time_ms, 38.0);
+            assert!(profile.planner.details.contains_key("Nereids Rewrite Time"));
+
+            // Tree follows the plan: sink/source operator pairs share one node
+            let tree = profile.execution_tree.as_ref().unwrap();
+            let node = |plan_id: i32| {
+                tree.nodes
+                    .iter()
+                    .find(|n| n.plan_node_id == Some(plan_id))
+                    .unwrap_or_else(|| panic!("plan node {} missing", plan_id))
+            };
+            assert_eq!(tree.nodes.len(), 9);
+            assert_eq!(tree.root.operator_name, "RESULT_SINK");
+            assert_eq!(tree.root.children, vec!["node_7".to_string()]);
+            let chain =
+                [(7, "EXCHANGE", 6), (6, "AGGREGATE", 5), (5, "EXCHANGE", 4), (4, "AGGREGATE", 3)];
+            for (id, name, child) in chain {
+                assert_eq!(node(id).operator_name, name);
+                assert_eq!(node(id).children, vec![format!("node_{}", child)]);
+            }
+            let join = node(3);
+            assert_eq!(join.operator_name, "HASH_JOIN");
+            assert_eq!(join.node_type, NodeType::HashJoin);
+            assert_eq!(join.children, vec!["node_0".to_string(), "node_2".to_string()]);
+            assert_eq!(node(2).children, vec!["node_1".to_string()]);
+            assert_eq!(node(0).node_type, NodeType::OlapScan);
+
+            // Scan: Doris counters are exposed under the StarRocks names
+            let scan = node(0);
+            assert_eq!(scan.unique_metrics.get("BytesRead").map(String::as_str), Some("2.42 GB"));
+            assert_eq!(
+                scan.unique_metrics
+                    .get("__MAX_OF_RawRowsRead")
+                    .map(String::as_str),
+                Some("5.061003M (5061003)")
+            );
+            assert!(scan.unique_metrics.contains_key("IOTime"));
+            assert!(scan.unique_metrics.contains_key("PredFilterRows"));
+            match &scan.metrics.specialized {
+                OperatorSpecializedMetrics::OlapScan(m) => {
+                    assert_eq!(m.rows_read, Some(29998805));
+                    assert!(m.bytes_read.is_some());
+                },
+                other => panic!("unexpected scan metrics: {:?}", other),
+            }
+
+            // Join: build side comes from HASH_JOIN_SINK, probe side from HASH_JOIN
+            assert_eq!(
+                join.unique_metrics.get("BuildRows").map(String::as_str),
+                Some("15.000000M (15000000)")
+            );
+            assert!(join.unique_metrics.contains_key("HashTableMemoryUsage"));
+            assert!(join.unique_metrics.contains_key("__MAX_OF_ProbeRows"));
+            match &join.metrics.specialized {
+                OperatorSpecializedMetrics::Join(m) => {
+                    assert_eq!(m.build_rows, Some(15000000));
+                    assert_eq!(m.probe_rows, Some(29998805));
+                    assert_eq!(m.join_type, "INNER_JOIN");
+                },
+                other => panic!("unexpected join metrics: {:?}", other),
+            }
+            assert!(join.metrics.operator_total_time.is_some());
+
+            // Aggregate and exchange
+            assert_eq!(
+                node(6)
+                    .unique_metrics
+                    .get("InputRowCount")
+                    .map(String::as_str),
+                Some("120")
+            );
+            assert!(node(4).unique_metrics.contains_key("HashTableMemoryUsage"));
+            assert_eq!(
+                node(5).unique_metrics.get("BytesSent").map(String::as_str),
+                Some("12.30 KB")
+            );
+            assert!(node(5).unique_metrics.contains_key("NetworkTime"));
+
+            // Instances and parallelism from DetailProfile and pipeline headers
+            let fragment = |id: &str| profile.fragments.iter().find(|f| f.id == id).unwrap();
+            assert_eq!(fragment("1").backend_addresses.len(), 3);
+            assert_eq!(fragment("3").backend_addresses, vec!["10.0.0.11:9050", "10.0.0.12:9050"]);
+            assert_eq!(fragment("2").metrics.get("InstanceNum").map(String::as_str), Some("3"));
+            assert_eq!(
+                fragment("2").pipelines[0]
+                    .metrics
+                    .get("DegreeOfParallelism")
+                    .map(String::as_str),
+                Some("24")
+            );
+
+            // The scan dominates the operator time and the analysis runs end to end
+            assert!(tree.nodes.iter().all(|n| n.time_percentage.is_some()));
+            let top = profile.summary.top_time_consuming_nodes.as_ref().unwrap();
+            assert_eq!(top[0].plan_node_id, 0);
+            let analysis = analyze_profile(&profile_text).unwrap();
+            assert_eq!(analysis.execution_tree.as_ref().unwrap().nodes.len(), 9);
+        }
     }
 }
@@ -0,0 +1,1121 @@
+// Query Guard Service
+// Purpose: Periodically check running queries against per-cluster guard policies (elapsed time,
+// scan bytes, memory, users, tables), log every violation and kill it when the policy enforces.
+
+use crate::models::{Cluster, ClusterType, Query};
+use crate::services::{ClusterService, MySQLClient, MySQLPoolManager, create_adapter};
+use crate::utils::{ApiError, ApiResult, ScheduledTask};
+use chrono::{NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use utoipa::ToSchema;
+
+/// Guard actions older than this are removed
+const ACTION_RETENTION_DAYS: i64 = 30;
+
+/// SQL text kept in the action log and notifications
+const MAX_LOGGED_SQL_CHARS: usize = 2000;
+
+/// Webhook request timeout
+const WEBHOOK_TIMEOUT_SECONDS: u64 = 10;
+
+pub const MODE_DRY_RUN: &str = "dry_run";
+pub const MODE_ENFORCE: &str = "enforce";
+
+/// Guard policy of one cluster
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct QueryGuardPolicy {
+    pub id: i64,
+    pub organization_id: Option<i64>,
+    pub cluster_id: i64,
+    pub name: String,
+    /// dry_run: only log violations | enforce: kill them
+    pub mode: String,
+    pub enabled: bool,
+    pub max_elapsed_seconds: Option<i64>,
+    pub max_scan_bytes: Option<i64>,
+    pub max_memory_bytes: Option<i64>,
+    /// Only queries of these users are guarded (all users when empty)
+    pub users: Vec<String>,
+    /// Queries of these users are never touched
+    pub exempt_users: Vec<String>,
+    /// Only queries referencing these tables (`table` or `db.table`) are guarded
+    pub tables: Vec<String>,
+    /// Webhook called with the query owner on every violation
+    pub notify_webhook: Option<String>,
+    pub created_by: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Create / update request of a guard policy
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct QueryGuardPolicyRequest {
+    pub name: String,
+    pub cluster_id: i64,
+    #[serde(default = "default_mode")]
+    pub mode: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    pub max_elapsed_seconds: Option<i64>,
+    pub max_scan_bytes: Option<i64>,
+    pub max_memory_bytes: Option<i64>,
+    #[serde(default)]
+    pub users: Vec<String>,
+    #[serde(default)]
+    pub exempt_users: Vec<String>,
+    #[serde(default)]
+    pub tables: Vec<String>,
+    pub notify_webhook: Option<String>,
+}
+
+fn default_mode() -> String {
+    MODE_DRY_RUN.to_string()
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// A running query that breaks a policy
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq)]
+pub struct QueryGuardViolation {
+    pub query_id: String,
+    pub connection_id: String,
+    pub user: String,
+    pub database: String,
+    pub sql: String,
+    pub elapsed_seconds: Option<f64>,
+    pub scan_bytes: Option<i64>,
+    pub memory_bytes: Option<i64>,
+    pub reasons: Vec<String>,
+}
+
+/// Logged violation and what the guard did about it
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, sqlx::FromRow)]
+pub struct QueryGuardAction {
+    pub id: i64,
+    pub policy_id: i64,
+    pub policy_name: String,
+    pub cluster_id: i64,
+    pub query_id: String,
+    pub user: String,
+    pub database: String,
+    pub sql: String,
+    pub elapsed_seconds: Option<f64>,
+    pub scan_bytes: Option<i64>,
+    pub memory_bytes: Option<i64>,
+    /// Violated limits, `; ` separated
+    pub reasons: String,
+    /// flagged (dry run) | killed | kill_failed
+    pub action: String,
+    pub error: Option<String>,
+    pub notified: bool,
+    pub created_at: NaiveDateTime,
+}
+
+/// Requester identity passed down from the handler
+pub struct QueryGuardRequester<'a> {
+    pub username: &'a str,
+    pub organization_id: Option<i64>,
+    pub is_super_admin: bool,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct PolicyRow {
+    id: i64,
+    organization_id: Option<i64>,
+    cluster_id: i64,
+    name: String,
+    mode: String,
+    enabled: bool,
+    max_elapsed_seconds: Option<i64>,
+    max_scan_bytes: Option<i64>,
+    max_memory_bytes: Option<i64>,
+    users: String,
+    exempt_users: String,
+    tables: String,
+    notify_webhook: Option<String>,
+    created_by: String,
+    created_at: NaiveDateTime,
+    updated_at: NaiveDateTime,
+}
+
+impl From<PolicyRow> for QueryGuardPolicy {
+    fn from(row: PolicyRow) -> Self {
+        let list = |s: &str| serde_json::from_str::<Vec<String>>(s).unwrap_or_default();
+        Self {
+            users: list(&row.users),
+            exempt_users: list(&row.exempt_users),
+            tables: list(&row.tables),
+            id: row.id,
+            organization_id: row.organization_id,
+            cluster_id: row.cluster_id,
+            name: row.name,
+            mode: row.mode,
+            enabled: row.enabled,
+            max_elapsed_seconds: row.max_elapsed_seconds,
+            max_scan_bytes: row.max_scan_bytes,
+            max_memory_bytes: row.max_memory_bytes,
+            notify_webhook: row.notify_webhook,
+            created_by: row.created_by,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+const POLICY_COLUMNS: &str = "id, organization_id, cluster_id, name, mode, enabled, \
+     max_elapsed_seconds, max_scan_bytes, max_memory_bytes, users, exempt_users, tables, \
+     notify_webhook, created_by, created_at, updated_at";
+
+/// Split `12.5 MB` / `1.234 s` / `42` into number and lower-cased unit
+fn split_number_unit(value: &str) -> Option<(f64, String)> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    let number = value[..split].parse::<f64>().ok()?;
+    Some((number, value[split..].trim().to_lowercase()))
+}
+
+/// Elapsed time of a running query
+///
+/// Bare numbers are milliseconds on StarRocks (`current_queries` `ExecTime`) and seconds on
+/// Doris (processlist `Time`).
+pub fn parse_elapsed_seconds(cluster_type: &ClusterType, value: &str) -> Option<f64> {
+    let (number, unit) = split_number_unit(value)?;
+    let factor = match unit.as_str() {
+        "" => match cluster_type {
+            ClusterType::StarRocks => 0.001,
+            ClusterType::Doris => 1.0,
+        },
+        "s" | "sec" => 1.0,
+        "ms" => 0.001,
+        "us" => 0.000_001,
+        "m" | "min" => 60.0,
+        "h" => 3600.0,
+        _ => return None,
+    };
+    Some(number * factor)
+}
+
+/// Byte counts as shown by `current_queries`, e.g. `1.234 GB`; bare numbers are bytes
+pub fn parse_size_bytes(value: &str) -> Option<i64> {
+    let (number, unit) = split_number_unit(value)?;
+    let factor: f64 = match unit.as_str() {
+        "" | "b" => 1.0,
+        "k" | "kb" => 1024.0,
+        "m" | "mb" => 1024.0 * 1024.0,
+        "g" | "gb" => 1024.0 * 1024.0 * 1024.0,
+        "t" | "tb" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    Some((number * factor) as i64)
+}
+
+/// Whether the SQL references `table` (`db.table` matches qualified references only)
+fn references_table(normalized_sql: &str, table: &str) -> bool {
+    let table = table.trim().trim_matches('`').to_lowercase();
+    if table.is_empty() {
+        return false;
+    }
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    normalized_sql.match_indices(&table).any(|(start, _)| {
+        let before = normalized_sql[..start].chars().next_back();
+        let after = normalized_sql[start + table.len()..].chars().next();
+        // `db.orders` must not match `orders` alone, `orders` may follow a `db.` qualifier
+        !before.is_some_and(is_ident) && !after.is_some_and(|c| is_ident(c) || c == '.')
+    })
+}
+
+fn contains_user(users: &[String], user: &str) -> bool {
+    users.iter().any(|u| u.trim().eq_ignore_ascii_case(user))
+}
+
+/// Check one running query against a policy
+///
+/// Without numeric limits every query in the user / table scope is a violation.
+pub fn evaluate_query(
+    cluster_type: &ClusterType,
+    policy: &QueryGuardPolicy,
+    query: &Query,
+) -> Option<QueryGuardViolation> {
+    if contains_user(&policy.exempt_users, &query.user) {
+        return None;
+    }
+    let mut reasons = Vec::new();
+    if !policy.users.is_empty() {
+        if !contains_user(&policy.users, &query.user) {
+            return None;
+        }
+        reasons.push(format!("user {} is guarded", query.user));
+    }
+    if !policy.tables.is_empty() {
+        let sql = query.sql.replace('`', "").to_lowercase();
+        let hit: Vec<&str> = policy
+            .tables
+            .iter()
+            .filter(|t| references_table(&sql, t))
+            .map(|t| t.as_str())
+            .collect();
+        if hit.is_empty() {
+            return None;
+        }
+        reasons.push(format!("references {}", hit.join(", ")));
+    }
+
+    let elapsed_seconds = parse_elapsed_seconds(cluster_type, &query.exec_time);
+    let scan_bytes = parse_size_bytes(&query.scan_bytes);
+    let memory_bytes = query.memory_usage.as_deref().and_then(parse_size_bytes);
+
+    let limited = policy.max_elapsed_seconds.is_some()
+        || policy.max_scan_bytes.is_some()
+        || policy.max_memory_bytes.is_some();
+    if limited {
+        // Scope matches only narrow the limits down
+        reasons.clear();
+    }
+    if let (Some(limit), Some(value)) = (policy.max_elapsed_seconds, elapsed_seconds)
+        && value > limit as f64
+    {
+        reasons.push(format!("elapsed {:.0}s > {}s", value, limit));
+    }
+    if let (Some(limit), Some(value)) = (policy.max_scan_bytes, scan_bytes)
+        && value > limit
+    {
+        reasons.push(format!("scan bytes {} > {}", value, limit));
+    }
+    if let (Some(limit), Some(value)) = (policy.max_memory_bytes, memory_bytes)
+        && value > limit
+    {
+        reasons.push(format!("memory {} > {}", value, limit));
+    }
+    if reasons.is_empty() {
+        return None;
+    }
+
+    Some(QueryGuardViolation {
+        query_id: query.query_id.clone(),
+        connection_id: query.connection_id.clone(),
+        user: query.user.clone(),
+        database: query.database.clone(),
+        sql: query.sql.chars().take(MAX_LOGGED_SQL_CHARS).collect(),
+        elapsed_seconds,
+        scan_bytes,
+        memory_bytes,
+        reasons,
+    })
+}
+
+/// Doris lists idle connections in SHOW PROCESSLIST, they have no statement
+fn is_running(cluster_type: &ClusterType, query: &Query) -> bool {
+    match cluster_type {
+        ClusterType::Doris => !query.sql.trim().is_empty(),
+        _ => true,
+    }
+}
+
+fn kill_statement(
+    cluster_type: &ClusterType,
+    violation: &QueryGuardViolation,
+) -> ApiResult<String> {
+    let (id, quoted) = match cluster_type {
+        ClusterType::Doris => (&violation.connection_id, false),
+        _ => (&violation.query_id, true),
+    };
+    let valid = !id.is_empty() && id.len() <= 64;
+    if !valid || !id.chars().all(|c| c.is_ascii_hexdigit() || c == '-') {
+        return Err(ApiError::validation_error(format!("Invalid query ID: {}", id)));
+    }
+    Ok(if quoted { format!("KILL QUERY '{}'", id) } else { format!("KILL QUERY {}", id) })
+}
+
+fn validate_request(request: &QueryGuardPolicyRequest) -> ApiResult<()> {
+    if request.name.trim().is_empty() {
+        return Err(ApiError::validation_error("Policy name cannot be empty"));
+    }
+    if request.mode != MODE_DRY_RUN && request.mode != MODE_ENFORCE {
+        return Err(ApiError::validation_error(format!(
+            "Invalid mode {}, expected {} or {}",
+            request.mode, MODE_DRY_RUN, MODE_ENFORCE
+        )));
+    }
+    let limits = [request.max_elapsed_seconds, request.max_scan_bytes, request.max_memory_bytes];
+    if limits.iter().flatten().any(|v| *v <= 0) {
+        return Err(ApiError::validation_error("Limits must be positive"));
+    }
+    if limits.iter().all(Option::is_none) && request.users.is_empty() && request.tables.is_empty() {
+        return Err(ApiError::validation_error("A policy needs at least one limit, user or table"));
+    }
+    if let Some(url) = request
+        .notify_webhook
+        .as_deref()
+        .filter(|u| !u.trim().is_empty())
+    {
+        parse_webhook_url(url)?;
+    }
+    Ok(())
+}
+
+/// Webhooks are POSTed by the server, so they must not reach loopback, private,
+/// link-local or other internal targets
+fn parse_webhook_url(url: &str) -> ApiResult<reqwest::Url> {
+    let parsed = reqwest::Url::parse(url.trim())
+        .map_err(|e| ApiError::validation_error(format!("Invalid webhook URL: {}", e)))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(ApiError::validation_error("Webhook URL must be http(s)"));
+    }
+    let blocked = match parsed.host_str() {
+        Some(host) => match host_ip(host) {
+            Some(ip) => is_internal_ip(ip),
+            None => is_internal_domain(host),
+        },
+        None => true,
+    };
+    if blocked {
+        return Err(ApiError::validation_error(
+            "Webhook URL must not point to a loopback, private or internal host",
+        ));
+    }
+    Ok(parsed)
+}
+
+/// IP address of an IP-literal URL host (IPv6 hosts come bracketed)
+fn host_ip(host: &str) -> Option<IpAddr> {
+    host.trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse()
+        .ok()
+}
+
+fn is_internal_domain(domain: &str) -> bool {
+    let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+    !domain.contains('.')
+        || [".localhost", ".local", ".internal", ".localdomain"]
+            .iter()
+            .any(|suffix| domain.ends_with(suffix))
+}
+
+fn is_internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+            ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || a == 0
+                // Carrier-grade NAT, 100.64.0.0/10
+                || (a == 100 && (b & 0xc0) == 64)
+        },
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(v4) => is_internal_ip(IpAddr::V4(v4)),
+            None => {
+                let first = ip.segments()[0];
+                ip.is_loopback()
+                    || ip.is_unspecified()
+                    // Unique local fc00::/7 and link-local fe80::/10
+                    || (first & 0xfe00) == 0xfc00
+                    || (first & 0xffc0) == 0xfe80
+            },
+        },
+    }
+}
+
+/// Resolve the webhook host and build a client pinned to the checked addresses,
+/// so a DNS answer cannot swap in an internal address between check and connect
+async fn webhook_client(url: &reqwest::Url) -> ApiResult<reqwest::Client> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| ApiError::validation_error("Webhook URL has no host"))?;
+    let port = url.port_or_known_default().unwrap_or(80);
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| ApiError::validation_error(format!("Cannot resolve webhook host: {}", e)))?
+        .collect();
+    if addrs.is_empty() || addrs.iter().any(|addr| is_internal_ip(addr.ip())) {
+        return Err(ApiError::validation_error(format!(
+            "Webhook host {} resolves to an internal address",
+            host
+        )));
+    }
+
+    let mut builder = reqwest::Client::builder()
+        .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECONDS))
+        .redirect(reqwest::redirect::Policy::none());
+    if host_ip(host).is_none() {
+        builder = builder.resolve_to_addrs(host, &addrs);
+    }
+    builder
+        .build()
+        .map_err(|e| ApiError::internal_error(format!("Failed to build webhook client: {}", e)))
+}
+
+fn request_policy(request: &QueryGuardPolicyRequest) -> QueryGuardPolicy {
+    let now = Utc::now().naive_utc();
+    QueryGuardPolicy {
+        id: 0,
+        organization_id: None,
+        cluster_id: request.cluster_id,
+        name: request.name.trim().to_string(),
+        mode: request.mode.clone(),
+        enabled: request.enabled,
+        max_elapsed_seconds: request.max_elapsed_seconds,
+        max_scan_bytes: request.max_scan_bytes,
+        max_memory_bytes: request.max_memory_bytes,
+        users: request.users.clone(),
+        exempt_users: request.exempt_users.clone(),
+        tables: request.tables.clone(),
+        notify_webhook: request.notify_webhook.clone(),
+        created_by: String::new(),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+#[derive(Clone)]
+pub struct QueryGuardService {
+    db: SqlitePool,
+    cluster_service: Arc<ClusterService>,
+    mysql_pool_manager: Arc<MySQLPoolManager>,
+}
+
+impl QueryGuardService {
+    pub fn new(
+        db: SqlitePool,
+        cluster_service: Arc<ClusterService>,
+        mysql_pool_manager: Arc<MySQLPoolManager>,
+    ) -> Self {
+        Self { db, cluster_service, mysql_pool_manager }
+    }
+
+    pub async fn list_policies(
+        &self,
+        requester: &QueryGuardRequester<'_>,
+    ) -> ApiResult<Vec<QueryGuardPolicy>> {
+        let rows: Vec<PolicyRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM query_guard_policies WHERE ? OR organization_id IS ? \
+             ORDER BY cluster_id, name",
+            POLICY_COLUMNS
+        ))
+        .bind(requester.is_super_admin)
+        .bind(requester.organization_id)
+        .fetch_all(&self.db)
+        .await?;
+        Ok(rows.into_iter().map(QueryGuardPolicy::from).collect())
+    }
+
+    pub async fn get_policy(
+        &self,
+        policy_id: i64,
+        requester: &QueryGuardRequester<'_>,
+    ) -> ApiResult<QueryGuardPolicy> {
+        let row: PolicyRow = sqlx::query_as(&format!(
+            "SELECT {} FROM query_guard_policies WHERE id = ?",
+            POLICY_COLUMNS
+        ))
+        .bind(policy_id)
+        .fetch_optional(&self.db)
+        .await?
+        .ok_or_else(|| {
+            ApiError::not_found(format!("Query guard policy {} not found", policy_id))
+        })?;
+
+        if !requester.is_super_admin && row.organization_id != requester.organization_id {
+            return Err(ApiError::forbidden("You can only manage policies of your organization"));
+        }
+        Ok(row.into())
+    }
+
+    pub async fn create_policy(
+        &self,
+        request: &QueryGuardPolicyRequest,
+        requester: &QueryGuardRequester<'_>,
+    ) -> ApiResult<QueryGuardPolicy> {
+        validate_request(request)?;
+        let cluster = self.check_cluster(request.cluster_id, requester).await?;
+
+        let now = Utc::now().naive_utc();
+        let policy_id = sqlx::query(
+            r#"
+            INSERT INTO query_guard_policies (
+                organization_id, cluster_id, name, mode, enabled, max_elapsed_seconds,
+                max_scan_bytes, max_memory_bytes, users, exempt_users, tables, notify_webhook,
+                created_by, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(cluster.organization_id)
+        .bind(request.cluster_id)
+        .bind(request.name.trim())
+        .bind(&request.mode)
+        .bind(request.enabled)
+        .bind(request.max_elapsed_seconds)
+        .bind(request.max_scan_bytes)
+        .bind(request.max_memory_bytes)
+        .bind(serde_json::to_string(&request.users).unwrap_or_default())
+        .bind(serde_json::to_string(&request.exempt_users).unwrap_or_default())
+        .bind(serde_json::to_string(&request.tables).unwrap_or_default())
+        .bind(
+            request
+                .notify_webhook
+                .as_deref()
+                .filter(|u| !u.trim().is_empty()),
+        )
+        .bind(requester.username)
+        .bind(now)
+        .bind(now)
+        .execute(&self.db)
+        .await?
+        .last_insert_rowid();
+
+        tracing::info!(
+            "Query guard policy {} ({}) created by {} in {} mode",
+            policy_id,
+            request.name,
+            requester.username,
+            request.mode
+        );
+        self.get_policy(policy_id, requester).await
+    }
+
+    pub async fn update_policy(
+        &self,
+        policy_id: i64,
+        request: &QueryGuardPolicyRequest,
+        requester: &QueryGuardRequester<'_>,
+    ) -> ApiResult<QueryGuardPolicy> {
+        self.get_policy(policy_id, requester).await?;
+        validate_request(request)?;
+        let cluster = self.check_cluster(request.cluster_id, requester).await?;
+
+        sqlx::query(
+            r#"
+            UPDATE query_guard_policies
+            SET organization_id = ?, cluster_id = ?, name = ?, mode = ?, enabled = ?,
+                max_elapsed_seconds = ?, max_scan_bytes = ?, max_memory_bytes = ?, users = ?,
+                exempt_users = ?, tables = ?, notify_webhook = ?, updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(cluster.organization_id)
+        .bind(request.cluster_id)
+        .bind(request.name.trim())
+        .bind(&request.mode)
+        .bind(request.enabled)
+        .bind(request.max_elapsed_seconds)
+        .bind(request.max_scan_bytes)
+        .bind(request.max_memory_bytes)
+        .bind(serde_json::to_string(&request.users).unwrap_or_default())
+        .bind(serde_json::to_string(&request.exempt_users).unwrap_or_default())
+        .bind(serde_json::to_string(&request.tables).unwrap_or_default())
+        .bind(
+            request
+                .notify_webhook
+                .as_deref()
+                .filter(|u| !u.trim().is_empty()),
+        )
+        .bind(Utc::now().naive_utc())
+        .bind(policy_id)
+        .execute(&self.db)
+        .await?;
+
+        tracing::info!(
+            "Query guard policy {} updated by {} ({} mode)",
+            policy_id,
+            requester.username,
+            request.mode
+        );
+        self.get_policy(policy_id, requester).await
+    }
+
+    pub async fn delete_policy(
+        &self,
+        policy_id: i64,
+        requester: &QueryGuardRequester<'_>,
+    ) -> ApiResult<()> {
+        self.get_policy(policy_id, requester).await?;
+        sqlx::query("DELETE FROM query_guard_policies WHERE id = ?")
+            .bind(policy_id)
+            .execute(&self.db)
+            .await?;
+        tracing::info!("Query guard policy {} deleted by {}", policy_id, requester.username);
+        Ok(())
+    }
+
+    /// Running queries a policy would kill right now; nothing is logged or killed
+    pub async fn preview(
+        &self,
+        request: &QueryGuardPolicyRequest,
+        requester: &QueryGuardRequester<'_>,
+    ) -> ApiResult<Vec<QueryGuardViolation>> {
+        validate_request(request)?;
+        let cluster = self.check_cluster(request.cluster_id, requester).await?;
+        let policy = request_policy(request);
+
+        let queries = create_adapter(cluster.clone(), Arc::clone(&self.mysql_pool_manager))
+            .get_queries()
+            .await?;
+        Ok(queries
+            .iter()
+            .filter(|q| is_running(&cluster.cluster_type, q))
+            .filter_map(|q| evaluate_query(&cluster.cluster_type, &policy, q))
+            .collect())
+    }
+
+    /// Logged violations, newest first
+    pub async fn list_actions(
+        &self,
+        policy_id: Option<i64>,
+        limit: i64,
+        requester: &QueryGuardRequester<'_>,
+    ) -> ApiResult<Vec<QueryGuardAction>> {
+        if let Some(policy_id) = policy_id {
+            self.get_policy(policy_id, requester).await?;
+        }
+        let actions = sqlx::query_as(
+            r#"
+            SELECT a.id, a.policy_id, a.policy_name, a.cluster_id, a.query_id, a.user, a.database,
+                   a.sql, a.elapsed_seconds, a.scan_bytes, a.memory_bytes, a.reasons, a.action,
+                   a.error, a.notified, a.created_at
+            FROM query_guard_actions a
+            LEFT JOIN clusters c ON c.id = a.cluster_id
+            WHERE (? IS NULL OR a.policy_id = ?) AND (? OR c.organization_id IS ?)
+            ORDER BY a.created_at DESC, a.id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(policy_id)
+        .bind(policy_id)
+        .bind(requester.is_super_admin)
+        .bind(requester.organization_id)
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+        Ok(actions)
+    }
+
+    /// Evaluate every enabled policy against the running queries of its cluster
+    pub async fn run_all(&self) -> Result<(), anyhow::Error> {
+        let rows: Vec<PolicyRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM query_guard_policies WHERE enabled = 1",
+            POLICY_COLUMNS
+        ))
+        .fetch_all(&self.db)
+        .await?;
+
+        let mut by_cluster: HashMap<i64, Vec<QueryGuardPolicy>> = HashMap::new();
+        for row in rows {
+            by_cluster
+                .entry(row.cluster_id)
+                .or_default()
+                .push(row.into());
+        }
+
+        for (cluster_id, policies) in by_cluster {
+            let cluster = match self.cluster_service.get_cluster(cluster_id).await {
+                Ok(cluster) => cluster,
+                Err(e) => {
+                    tracing::warn!("Query guard skipped cluster {}: {}", cluster_id, e);
+                    continue;
+                },
+            };
+            if let Err(e) = self.guard_cluster(&cluster, &policies).await {
+                tracing::warn!("Query guard failed for cluster {}: {}", cluster.name, e);
+            }
+        }
+
+        let cutoff = Utc::now().naive_utc() - chrono::Duration::days(ACTION_RETENTION_DAYS);
+        sqlx::query("DELETE FROM query_guard_actions WHERE created_at < ?")
+            .bind(cutoff)
+            .execute(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn guard_cluster(
+        &self,
+        cluster: &Cluster,
+        policies: &[QueryGuardPolicy],
+    ) -> ApiResult<()> {
+        let queries = create_adapter(cluster.clone(), Arc::clone(&self.mysql_pool_manager))
+            .get_queries()
+            .await?;
+        let queries: Vec<&Query> = queries
+            .iter()
+            .filter(|q| is_running(&cluster.cluster_type, q))
+            .collect();
+        if queries.is_empty() {
+            return Ok(());
+        }
+        let mut killed: HashSet<String> = HashSet::new();
+
+        for policy in policies {
+            for query in &queries {
+                if killed.contains(&query.query_id) {
+                    continue;
+                }
+                let Some(violation) = evaluate_query(&cluster.cluster_type, policy, query) else {
+                    continue;
+                };
+                if self.already_handled(policy.id, &violation.query_id).await? {
+                    continue;
+                }
+
+                let (action, error) = if policy.mode == MODE_ENFORCE {
+                    match self.kill(cluster, &violation).await {
+                        Ok(()) => {
+                            killed.insert(violation.query_id.clone());
+                            ("killed", None)
+                        },
+                        Err(e) => ("kill_failed", Some(e.to_string())),
+                    }
+                } else {
+                    ("flagged", None)
+                };
+                tracing::info!(
+                    "Query guard policy {} {} query {} of {} on cluster {}: {}",
+                    policy.name,
+                    action,
+                    violation.query_id,
+                    violation.user,
+                    cluster.name,
+                    violation.reasons.join("; ")
+                );
+
+                // A kill failing every round keeps a single row per query, and the owner
+                // is only paged until one notification got through
+                let previous_failure = if action == "kill_failed" {
+                    self.kill_failure(policy.id, &violation.query_id).await?
+                } else {
+                    None
+                };
+                let already_notified = previous_failure.is_some_and(|(_, notified)| notified);
+                let notified = match &policy.notify_webhook {
+                    Some(url) if !already_notified => {
+                        self.notify(url, cluster, policy, &violation, action).await
+                    },
+                    _ => already_notified,
+                };
+                match previous_failure {
+                    Some((id, _)) => {
+                        self.update_kill_failure(id, &violation, error.as_deref(), notified)
+                            .await?
+                    },
+                    None => {
+                        self.record_action(
+                            cluster,
+                            policy,
+                            &violation,
+                            action,
+                            error.as_deref(),
+                            notified,
+                        )
+                        .await?
+                    },
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// A query is acted on once per policy; a failed kill is retried on the next round
+    async fn already_handled(&self, policy_id: i64, query_id: &str) -> ApiResult<bool> {
+        let handled: Option<(i64,)> = sqlx::query_as(
+            "SELECT id FROM query_guard_actions \
+             WHERE policy_id = ? AND query_id = ? AND action != 'kill_failed' LIMIT 1",
+        )
+        .bind(policy_id)
+        .bind(query_id)
+        .fetch_optional(&self.db)
+        .await?;
+        Ok(handled.is_some())
+    }
+
+    /// The failed-kill row of the query, if an earlier round already recorded one
+    async fn kill_failure(&self, policy_id: i64, query_id: &str) -> ApiResult<Option<(i64, bool)>> {
+        let failure: Option<(i64, bool)> = sqlx::query_as(
+            "SELECT id, notified FROM query_guard_actions \
+             WHERE policy_id = ? AND query_id = ? AND action = 'kill_failed' LIMIT 1",
+        )
+        .bind(policy_id)
+        .bind(query_id)
+        .fetch_optional(&self.db)
+        .await?;
+        Ok(failure)
+    }
+
+    /// Refresh the failed-kill row with the latest attempt
+    async fn update_kill_failure(
+        &self,
+        id: i64,
+        violation: &QueryGuardViolation,
+        error: Option<&str>,
+        notified: bool,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            "UPDATE query_guard_actions SET elapsed_seconds = ?, scan_bytes = ?, memory_bytes = ?, \
+             reasons = ?, error = ?, notified = ? WHERE id = ?",
+        )
+        .bind(violation.elapsed_seconds)
+        .bind(violation.scan_bytes)
+        .bind(violation.memory_bytes)
+        .bind(violation.reasons.join("; "))
+        .bind(error)
+        .bind(notified)
+        .bind(id)
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    async fn kill(&self, cluster: &Cluster, violation: &QueryGuardViolation) -> ApiResult<()> {
+        let sql = kill_statement(&cluster.cluster_type, violation)?;
+        let pool = self.mysql_pool_manager.get_pool(cluster).await?;
+        MySQLClient::from_pool(pool).execute(&sql).await?;
+        Ok(())
+    }
+
+    /// POST the violation to the policy webhook; `owner` is the user who ran the query
+    async fn notify(
+        &self,
+        url: &str,
+        cluster: &Cluster,
+        policy: &QueryGuardPolicy,
+        violation: &QueryGuardViolation,
+        action: &str,
+    ) -> bool {
+        let payload = serde_json::json!({
+            "event": "query_guard",
+            "action": action,
+            "cluster": cluster.name,
+            "policy": policy.name,
+            "mode": policy.mode,
+            "owner": violation.user,
+            "query_id": violation.query_id,
+            "database": violation.database,
+            "reasons": violation.reasons,
+            "elapsed_seconds": violation.elapsed_seconds,
+            "scan_bytes": violation.scan_bytes,
+            "memory_bytes": violation.memory_bytes,
+            "sql": violation.sql,
+        });
+        let client = match parse_webhook_url(url) {
+            Ok(parsed) => webhook_client(&parsed).await.map(|client| (client, parsed)),
+            Err(e) => Err(e),
+        };
+        let (client, url) = match client {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::warn!("Query guard webhook {} rejected: {}", url, e);
+                return false;
+            },
+        };
+        match client.post(url.clone()).json(&payload).send().await {
+            Ok(response) if response.status().is_success() => true,
+            Ok(response) => {
+                tracing::warn!("Query guard webhook {} returned {}", url, response.status());
+                false
+            },
+            Err(e) => {
+                tracing::warn!("Query guard webhook {} failed: {}", url, e);
+                false
+            },
+        }
+    }
+
+    async fn record_action(
+        &self,
+        cluster: &Cluster,
+        policy: &QueryGuardPolicy,
+        violation: &QueryGuardViolation,
+        action: &str,
+        error: Option<&str>,
+        notified: bool,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO query_guard_actions (
+                policy_id, policy_name, cluster_id, query_id, user, database, sql,
+                elapsed_seconds, scan_bytes, memory_bytes, reasons, action, error, notified,
+                created_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(policy.id)
+        .bind(&policy.name)
+        .bind(cluster.id)
+        .bind(&violation.query_id)
+        .bind(&violation.user)
+        .bind(&violation.database)
+        .bind(&violation.sql)
+        .bind(violation.elapsed_seconds)
+        .bind(violation.scan_bytes)
+        .bind(violation.memory_bytes)
+        .bind(violation.reasons.join("; "))
+        .bind(action)
+        .bind(error)
+        .bind(notified)
+        .bind(Utc::now().naive_utc())
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    /// Policy cluster must belong to the requester's organization
+    async fn check_cluster(
+        &self,
+        cluster_id: i64,
+        requester: &QueryGuardRequester<'_>,
+    ) -> ApiResult<Cluster> {
+        let cluster = self.cluster_service.get_cluster(cluster_id).await?;
+        if !requester.is_super_admin && cluster.organization_id != requester.organization_id {
+            return Err(ApiError::forbidden(format!(
+                "Cluster {} does not belong to your organization",
+                cluster.name
+            )));
+        }
+        Ok(cluster)
+    }
+}
+
+impl ScheduledTask for QueryGuardService {
+    fn run(&self) -> Pin<Box<dyn Future<Output = Result<(), anyhow::Error>> + Send + '_>> {
+        Box::pin(async move { self.run_all().await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(user: &str, sql: &str, exec_time: &str, scan_bytes: &str) -> Query {
+        Query {
+            query_id: "a1b2-c3".to_string(),
+            connection_id: "42".to_string(),
+            database: "sales".to_string(),
+            user: user.to_string(),
+            scan_bytes: scan_bytes.to_string(),
+            process_rows: "0".to_string(),
+            cpu_time: "0".to_string(),
+            exec_time: exec_time.to_string(),
+            sql: sql.to_string(),
+            start_time: None,
+            fe_ip: None,
+            memory_usage: Some("2.000 GB".to_string()),
+            disk_spill_size: None,
+            exec_progress: None,
+            warehouse: None,
+            custom_query_id: None,
+            resource_group: None,
+        }
+    }
+
+    fn policy() -> QueryGuardPolicy {
+        request_policy(&QueryGuardPolicyRequest {
+            name: "long scans".to_string(),
+            cluster_id: 1,
+            mode: MODE_ENFORCE.to_string(),
+            enabled: true,
+            max_elapsed_seconds: Some(600),
+            max_scan_bytes: None,
+            max_memory_bytes: Some(1024 * 1024 * 1024),
+            users: Vec::new(),
+            exempt_users: vec!["root".to_string()],
+            tables: Vec::new(),
+            notify_webhook: None,
+        })
+    }
+
+    #[test]
+    fn test_parse_units() {
+        let starrocks = ClusterType::StarRocks;
+        assert_eq!(parse_elapsed_seconds(&starrocks, "1.5 s"), Some(1.5));
+        assert_eq!(parse_elapsed_seconds(&starrocks, "1200 ms"), Some(1.2));
+        assert_eq!(parse_elapsed_seconds(&starrocks, "2 min"), Some(120.0));
+        // Bare ExecTime of StarRocks is milliseconds, bare processlist Time of Doris seconds
+        assert_eq!(parse_elapsed_seconds(&starrocks, "75000"), Some(75.0));
+        assert_eq!(parse_elapsed_seconds(&ClusterType::Doris, "75"), Some(75.0));
+        assert_eq!(parse_size_bytes("1.000 KB"), Some(1024));
+        assert_eq!(parse_size_bytes("3 GB"), Some(3 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size_bytes("512"), Some(512));
+        assert_eq!(parse_size_bytes("n/a"), None);
+    }
+
+    #[test]
+    fn test_evaluate_query_limits_and_scope() {
+        let starrocks = ClusterType::StarRocks;
+        let mut policy = policy();
+        let long = query("etl", "select * from orders", "900.1 s", "0");
+        let violation = evaluate_query(&starrocks, &policy, &long).unwrap();
+        assert_eq!(violation.reasons.len(), 2);
+        assert!(violation.reasons[0].starts_with("elapsed 900s"));
+
+        // Bare StarRocks ExecTime is milliseconds: 900100 ms is over the limit, 900 ms is not
+        let bare = query("etl", "select * from orders", "900100", "0");
+        let violation = evaluate_query(&starrocks, &policy, &bare).unwrap();
+        assert!(violation.reasons[0].starts_with("elapsed 900s"));
+        let short = query("etl", "select * from orders", "900", "0");
+        let violation = evaluate_query(&starrocks, &policy, &short).unwrap();
+        assert_eq!(violation.reasons.len(), 1);
+        assert!(violation.reasons[0].starts_with("memory"));
+
+        // Exempt users are never touched
+        assert!(
+            evaluate_query(&starrocks, &policy, &query("root", "select 1", "900 s", "0")).is_none()
+        );
+
+        // Table scope narrows the limits down
+        policy.tables = vec!["sales.orders".to_string()];
+        assert!(evaluate_query(&starrocks, &policy, &long).is_none());
+        let qualified = query("etl", "SELECT * FROM `sales`.`orders` o", "900 s", "0");
+        assert!(evaluate_query(&starrocks, &policy, &qualified).is_some());
+
+        // Without limits the scope alone is a violation
+        policy.max_elapsed_seconds = None;
+        policy.max_memory_bytes = None;
+        policy.users = vec!["ETL".to_string()];
+        let violation = evaluate_query(&starrocks, &policy, &qualified).unwrap();
+        assert_eq!(violation.reasons, vec!["user etl is guarded", "references sales.orders"]);
+
+        assert_eq!(
+            kill_statement(&ClusterType::StarRocks, &violation).unwrap(),
+            "KILL QUERY 'a1b2-c3'"
+        );
+        assert_eq!(kill_statement(&ClusterType::Doris, &violation).unwrap(), "KILL QUERY 42");
+    }
+
+    #[test]
+    fn test_webhook_url_rejects_internal_targets() {
+        assert!(parse_webhook_url("https://hooks.example.com/guard").is_ok());
+        assert!(parse_webhook_url("http://203.0.113.7.nip.io:8080/x").is_ok());
+        for url in [
+            "ftp://hooks.example.com/guard",
+            "http://localhost:8080/",
+            "http://metadata.google.internal/",
+            "http://intranet/hook",
+            "http://127.0.0.1/",
+            "http://10.1.2.3/",
+            "http://172.16.0.1/",
+            "http://192.168.1.10/",
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1/",
+            "http://0.0.0.0/",
+            "http://[::1]/",
+            "http://[fd00::1]/",
+            "http://[fe80::1]/",
+            "http://[::ffff:127.0.0.1]/",
+        ] {
+            assert!(parse_webhook_url(url).is_err(), "{} should be rejected", url);
+        }
+    }
+}
@@ -0,0 +1,552 @@
+// Resource Group Service
+// Purpose: SQL builders and result parsers shared by the adapters for StarRocks resource groups and
+// Doris workload groups, plus per-group usage aggregation across BEs.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::models::{
+    AlterResourceGroupRequest, CreateResourceGroupRequest, ResourceGroup, ResourceGroupClassifier,
+    ResourceGroupUsage,
+};
+use crate::utils::{ApiError, ApiResult, RowView};
+
+static GROUP_NAME_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[A-Za-z_][A-Za-z0-9_]{0,63}$").unwrap());
+
+static PROPERTY_KEY_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[A-Za-z][A-Za-z0-9_.]*$").unwrap());
+
+static CLASSIFIER_ID_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\bid\s*=\s*(\d+)").unwrap());
+
+/// `name` label of a BE metric: `{name="rg1"}`, `{"name":"rg1"}` or `name=rg1`
+static METRIC_NAME_LABEL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\bname"?\s*[:=]\s*"?([^",}\s]+)"#).unwrap());
+
+/// Usage of one group reported by one BE
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GroupUsageSample {
+    pub name: String,
+    pub id: Option<String>,
+    pub backend: String,
+    pub running_queries: Option<i64>,
+    pub cpu_cores: Option<f64>,
+    pub cpu_pct: Option<f64>,
+    pub mem_bytes: Option<i64>,
+}
+
+/// Running / queued query counts of one group as seen by the FE query queue
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct GroupQueueCounts {
+    pub running: i64,
+    pub queued: i64,
+}
+
+pub fn validate_group_name(name: &str) -> ApiResult<()> {
+    if GROUP_NAME_REGEX.is_match(name) {
+        Ok(())
+    } else {
+        Err(ApiError::validation_error(format!("Invalid resource group name: {}", name)))
+    }
+}
+
+/// `'key'='value', ...` for a WITH / PROPERTIES clause
+pub fn properties_clause(properties: &BTreeMap<String, String>) -> ApiResult<String> {
+    let mut parts = Vec::with_capacity(properties.len());
+    for (key, value) in properties {
+        if !PROPERTY_KEY_REGEX.is_match(key) {
+            return Err(ApiError::validation_error(format!("Invalid property name: {}", key)));
+        }
+        parts.push(format!("'{}'='{}'", key, value.replace('\\', "\\\\").replace('\'', "\\'")));
+    }
+    Ok(parts.join(", "))
+}
+
+/// Classifier condition list without the surrounding parentheses
+pub fn validate_classifier(condition: &str) -> ApiResult<String> {
+    let mut condition = condition.trim();
+    if condition.starts_with('(') && condition.ends_with(')') {
+        condition = condition[1..condition.len() - 1].trim();
+    }
+    if condition.is_empty() {
+        return Err(ApiError::validation_error("Classifier cannot be empty"));
+    }
+
+    // Only plain single-quoted values are tracked, so escapes and double-quoted strings that
+    // could hide a `'` from the quote tracking are refused
+    if condition.contains(['\\', '"']) {
+        return Err(ApiError::validation_error(format!(
+            "Classifier values must be single-quoted without backslashes or double quotes: {}",
+            condition
+        )));
+    }
+
+    let mut depth = 0i32;
+    let mut in_quote = false;
+    for c in condition.chars() {
+        match c {
+            '\'' => in_quote = !in_quote,
+            '(' if !in_quote => depth += 1,
+            ')' if !in_quote => depth -= 1,
+            ';' if !in_quote => depth = -1,
+            _ => {},
+        }
+        if depth < 0 {
+            break;
+        }
+    }
+    if depth != 0 || in_quote {
+        return Err(ApiError::validation_error(format!("Invalid classifier: {}", condition)));
+    }
+    Ok(condition.to_string())
+}
+
+pub fn build_starrocks_create_sql(request: &CreateResourceGroupRequest) -> ApiResult<String> {
+    validate_group_name(&request.name)?;
+    if request.properties.is_empty() {
+        return Err(ApiError::validation_error("At least one property is required"));
+    }
+    let mut sql = format!("CREATE RESOURCE GROUP {}", request.name);
+    if !request.classifiers.is_empty() {
+        let classifiers = request
+            .classifiers
+            .iter()
+            .map(|c| validate_classifier(c).map(|c| format!("({})", c)))
+            .collect::<ApiResult<Vec<_>>>()?;
+        sql.push_str(&format!(" TO {}", classifiers.join(", ")));
+    }
+    sql.push_str(&format!(" WITH ({})", properties_clause(&request.properties)?));
+    Ok(sql)
+}
+
+/// ALTER statements in execution order: drop classifiers, add classifiers, change properties
+pub fn build_starrocks_alter_sqls(
+    name: &str,
+    request: &AlterResourceGroupRequest,
+) -> ApiResult<Vec<String>> {
+    validate_group_name(name)?;
+    let mut statements = Vec::new();
+    if request.drop_all_classifiers {
+        statements.push(format!("ALTER RESOURCE GROUP {} DROP ALL", name));
+    } else if !request.drop_classifier_ids.is_empty() {
+        let ids: Vec<String> = request
+            .drop_classifier_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+        statements.push(format!("ALTER RESOURCE GROUP {} DROP ({})", name, ids.join(", ")));
+    }
+    if !request.add_classifiers.is_empty() {
+        let classifiers = request
+            .add_classifiers
+            .iter()
+            .map(|c| validate_classifier(c).map(|c| format!("({})", c)))
+            .collect::<ApiResult<Vec<_>>>()?;
+        statements.push(format!("ALTER RESOURCE GROUP {} ADD {}", name, classifiers.join(", ")));
+    }
+    if !request.properties.is_empty() {
+        statements.push(format!(
+            "ALTER RESOURCE GROUP {} WITH ({})",
+            name,
+            properties_clause(&request.properties)?
+        ));
+    }
+    if statements.is_empty() {
+        return Err(ApiError::validation_error("Nothing to alter"));
+    }
+    Ok(statements)
+}
+
+pub fn build_doris_create_sql(request: &CreateResourceGroupRequest) -> ApiResult<String> {
+    validate_group_name(&request.name)?;
+    if !request.classifiers.is_empty() {
+        return Err(ApiError::validation_error(
+            "Doris workload groups have no classifiers, bind users via workload_group properties",
+        ));
+    }
+    if request.properties.is_empty() {
+        return Err(ApiError::validation_error("At least one property is required"));
+    }
+    Ok(format!(
+        "CREATE WORKLOAD GROUP {} PROPERTIES ({})",
+        request.name,
+        properties_clause(&request.properties)?
+    ))
+}
+
+pub fn build_doris_alter_sql(name: &str, request: &AlterResourceGroupRequest) -> ApiResult<String> {
+    validate_group_name(name)?;
+    if !request.add_classifiers.is_empty()
+        || !request.drop_classifier_ids.is_empty()
+        || request.drop_all_classifiers
+    {
+        return Err(ApiError::validation_error("Doris workload groups have no classifiers"));
+    }
+    if request.properties.is_empty() {
+        return Err(ApiError::validation_error("Nothing to alter"));
+    }
+    Ok(format!(
+        "ALTER WORKLOAD GROUP {} PROPERTIES ({})",
+        name,
+        properties_clause(&request.properties)?
+    ))
+}
+
+/// Rows of StarRocks `SHOW RESOURCE GROUPS ALL`, one row per classifier
+pub fn parse_starrocks_resource_groups(
+    columns: &[String],
+    rows: &[Vec<String>],
+) -> Vec<ResourceGroup> {
+    let mut groups: Vec<ResourceGroup> = Vec::new();
+    for row in rows {
+        let v = RowView::new(columns, row);
+        let name = v.string(&["name"]);
+        if name.is_empty() {
+            continue;
+        }
+        let index = match groups.iter().position(|g| g.name == name) {
+            Some(i) => i,
+            None => {
+                let properties = columns
+                    .iter()
+                    .zip(row.iter())
+                    .filter(|(c, _)| {
+                        !["name", "id", "classifiers"].contains(&c.to_lowercase().as_str())
+                    })
+                    .map(|(c, value)| (c.to_lowercase(), value.clone()))
+                    .collect();
+                groups.push(ResourceGroup {
+                    name: name.clone(),
+                    id: v.get(&["id"]).map(str::to_string),
+                    properties,
+                    classifiers: Vec::new(),
+                });
+                groups.len() - 1
+            },
+        };
+        if let Some(condition) = v.get(&["classifiers"]).filter(|c| !c.trim().is_empty()) {
+            groups[index].classifiers.push(ResourceGroupClassifier {
+                id: CLASSIFIER_ID_REGEX
+                    .captures(condition)
+                    .and_then(|c| c[1].parse().ok()),
+                condition: condition.to_string(),
+            });
+        }
+    }
+    groups
+}
+
+/// Rows of Doris `information_schema.workload_groups` (one column per property) or of
+/// `SHOW WORKLOAD GROUPS` on 2.0 (one `Item` / `Value` row per property)
+pub fn parse_doris_workload_groups(columns: &[String], rows: &[Vec<String>]) -> Vec<ResourceGroup> {
+    let key_value = columns.iter().any(|c| c.eq_ignore_ascii_case("Item"));
+    let mut groups: Vec<ResourceGroup> = Vec::new();
+    for row in rows {
+        let v = RowView::new(columns, row);
+        let name = v.string(&["NAME"]);
+        if name.is_empty() {
+            continue;
+        }
+        let index = match groups.iter().position(|g| g.name == name) {
+            Some(i) => i,
+            None => {
+                groups.push(ResourceGroup {
+                    name: name.clone(),
+                    id: v.get(&["ID"]).map(str::to_string),
+                    properties: BTreeMap::new(),
+                    classifiers: Vec::new(),
+                });
+                groups.len() - 1
+            },
+        };
+        if key_value {
+            if let Some(item) = v.get(&["Item"]) {
+                groups[index]
+                    .properties
+                    .insert(item.to_lowercase(), v.string(&["Value"]));
+            }
+        } else {
+            for (column, value) in columns.iter().zip(row.iter()) {
+                let key = column.to_lowercase();
+                if !["id", "name", "running_query_num", "waiting_query_num"].contains(&key.as_str())
+                {
+                    groups[index].properties.insert(key, value.clone());
+                }
+            }
+        }
+    }
+    groups
+}
+
+/// Group name from the labels of a BE metric
+pub fn metric_group_name(labels: &str) -> Option<String> {
+    METRIC_NAME_LABEL_REGEX
+        .captures(labels)
+        .map(|c| c[1].to_string())
+}
+
+/// Rows of StarRocks `information_schema.be_metrics` (BE_ID, NAME, LABELS, VALUE)
+pub fn parse_starrocks_be_metrics(
+    columns: &[String],
+    rows: &[Vec<String>],
+) -> Vec<GroupUsageSample> {
+    let mut samples: HashMap<(String, String), GroupUsageSample> = HashMap::new();
+    for row in rows {
+        let v = RowView::new(columns, row);
+        let metric = v.string(&["NAME"]).to_lowercase();
+        let Some(group) = v.get(&["LABELS"]).and_then(metric_group_name) else {
+            continue;
+        };
+        let backend = v.string(&["BE_ID"]);
+        let value = v.float(&["VALUE"]);
+        let sample = samples
+            .entry((group.clone(), backend.clone()))
+            .or_insert_with(|| GroupUsageSample { name: group, backend, ..Default::default() });
+        if metric.ends_with("resource_group_running_queries") {
+            sample.running_queries = value.map(|v| v as i64);
+        } else if metric.ends_with("resource_group_inuse_cpu_cores") {
+            sample.cpu_cores = value;
+        } else if metric.ends_with("resource_group_mem_inuse_bytes") {
+            sample.mem_bytes = value.map(|v| v as i64);
+        } else if metric.ends_with("resource_group_cpu_use_ratio") {
+            sample.cpu_pct = value.map(|v| v * 100.0);
+        }
+    }
+    let mut samples: Vec<GroupUsageSample> = samples.into_values().collect();
+    samples.sort_by(|a, b| (&a.name, &a.backend).cmp(&(&b.name, &b.backend)));
+    samples
+}
+
+/// Rows of StarRocks `SHOW USAGE RESOURCE GROUPS`
+pub fn parse_starrocks_usage_rows(
+    columns: &[String],
+    rows: &[Vec<String>],
+) -> Vec<GroupUsageSample> {
+    rows.iter()
+        .map(|row| {
+            let v = RowView::new(columns, row);
+            GroupUsageSample {
+                name: v.string(&["Name"]),
+                id: v.get(&["Id"]).map(str::to_string),
+                backend: v.string(&["Backend"]),
+                running_queries: v.int(&["BERunningQueries"]),
+                cpu_cores: v.float(&["BEInUseCpuCores"]),
+                cpu_pct: None,
+                mem_bytes: v.int(&["BEInUseMemBytes"]),
+            }
+        })
+        .filter(|s| !s.name.is_empty())
+        .collect()
+}
+
+/// Rows of Doris `information_schema.workload_group_resource_usage`, keyed to group names by id
+pub fn parse_doris_usage_rows(
+    columns: &[String],
+    rows: &[Vec<String>],
+    groups: &[ResourceGroup],
+) -> Vec<GroupUsageSample> {
+    rows.iter()
+        .filter_map(|row| {
+            let v = RowView::new(columns, row);
+            let id = v.string(&["WORKLOAD_GROUP_ID"]);
+            let group = groups
+                .iter()
+                .find(|g| g.id.as_deref() == Some(id.as_str()))?;
+            Some(GroupUsageSample {
+                name: group.name.clone(),
+                id: Some(id),
+                backend: v.string(&["BE_ID"]),
+                running_queries: None,
+                cpu_cores: None,
+                cpu_pct: v.float(&["CPU_USAGE_PERCENT"]),
+                mem_bytes: v.int(&["MEMORY_USAGE_BYTES"]),
+            })
+        })
+        .collect()
+}
+
+/// Rows of StarRocks `SHOW RUNNING QUERIES`, counted per group
+pub fn parse_starrocks_running_queries(
+    columns: &[String],
+    rows: &[Vec<String>],
+    groups: &[ResourceGroup],
+) -> HashMap<String, GroupQueueCounts> {
+    let mut counts: HashMap<String, GroupQueueCounts> = HashMap::new();
+    for row in rows {
+        let v = RowView::new(columns, row);
+        let id = v.string(&["ResourceGroupId"]);
+        let Some(group) = groups.iter().find(|g| g.id.as_deref() == Some(id.as_str())) else {
+            continue;
+        };
+        let entry = counts.entry(group.name.clone()).or_default();
+        if v.string(&["State"]).eq_ignore_ascii_case("PENDING") {
+            entry.queued += 1;
+        } else {
+            entry.running += 1;
+        }
+    }
+    counts
+}
+
+/// Running / waiting counts from the Doris `workload_groups` rows
+pub fn parse_doris_queue_counts(
+    columns: &[String],
+    rows: &[Vec<String>],
+) -> HashMap<String, GroupQueueCounts> {
+    rows.iter()
+        .filter_map(|row| {
+            let v = RowView::new(columns, row);
+            let running = v.int(&["RUNNING_QUERY_NUM"]);
+            let queued = v.int(&["WAITING_QUERY_NUM"]);
+            if running.is_none() && queued.is_none() {
+                return None;
+            }
+            Some((
+                v.string(&["NAME"]),
+                GroupQueueCounts { running: running.unwrap_or(0), queued: queued.unwrap_or(0) },
+            ))
+        })
+        .collect()
+}
+
+/// Sum BE samples per group and merge the FE queue counts
+///
+/// A distributed query runs on many BEs at once, so the BE running count is the maximum over
+/// BEs; the FE count replaces it when the query queue reports one.
+pub fn aggregate_group_usage(
+    groups: &[ResourceGroup],
+    samples: &[GroupUsageSample],
+    queue: &HashMap<String, GroupQueueCounts>,
+) -> Vec<ResourceGroupUsage> {
+    let mut usage: BTreeMap<String, ResourceGroupUsage> = groups
+        .iter()
+        .map(|g| {
+            (
+                g.name.clone(),
+                ResourceGroupUsage { name: g.name.clone(), id: g.id.clone(), ..Default::default() },
+            )
+        })
+        .collect();
+    let mut cpu_pct: HashMap<String, Vec<f64>> = HashMap::new();
+
+    for sample in samples {
+        let entry = usage
+            .entry(sample.name.clone())
+            .or_insert_with(|| ResourceGroupUsage {
+                name: sample.name.clone(),
+                id: sample.id.clone(),
+                ..Default::default()
+            });
+        entry.backend_count += 1;
+        entry.running_queries = entry
+            .running_queries
+            .max(sample.running_queries.unwrap_or(0));
+        if let Some(cores) = sample.cpu_cores {
+            *entry.cpu_cores_used.get_or_insert(0.0) += cores;
+        }
+        if let Some(bytes) = sample.mem_bytes {
+            *entry.mem_used_bytes.get_or_insert(0) += bytes;
+        }
+        if let Some(pct) = sample.cpu_pct {
+            cpu_pct.entry(sample.name.clone()).or_default().push(pct);
+        }
+    }
+
+    for (name, entry) in usage.iter_mut() {
+        if let Some(values) = cpu_pct.get(name) {
+            entry.cpu_usage_pct = Some(values.iter().sum::<f64>() / values.len() as f64);
+        }
+        if let Some(counts) = queue.get(name) {
+            entry.running_queries = counts.running;
+            entry.queued_queries = counts.queued;
+        }
+    }
+    usage.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_build_starrocks_statements() {
+        let request = CreateResourceGroupRequest {
+            name: "rg_etl".to_string(),
+            classifiers: vec!["(user='etl', query_type in ('insert'))".to_string()],
+            properties: BTreeMap::from([
+                ("cpu_weight".to_string(), "8".to_string()),
+                ("mem_limit".to_string(), "30%".to_string()),
+            ]),
+        };
+        assert_eq!(
+            build_starrocks_create_sql(&request).unwrap(),
+            "CREATE RESOURCE GROUP rg_etl TO (user='etl', query_type in ('insert')) \
+             WITH ('cpu_weight'='8', 'mem_limit'='30%')"
+        );
+
+        let alter = AlterResourceGroupRequest {
+            add_classifiers: vec!["db='ods'".to_string()],
+            drop_classifier_ids: vec![3, 4],
+            drop_all_classifiers: false,
+            properties: BTreeMap::from([("concurrency_limit".to_string(), "10".to_string())]),
+        };
+        assert_eq!(
+            build_starrocks_alter_sqls("rg_etl", &alter).unwrap(),
+            vec![
+                "ALTER RESOURCE GROUP rg_etl DROP (3, 4)",
+                "ALTER RESOURCE GROUP rg_etl ADD (db='ods')",
+                "ALTER RESOURCE GROUP rg_etl WITH ('concurrency_limit'='10')",
+            ]
+        );
+
+        assert!(validate_classifier("user='a'); DROP DATABASE x; (").is_err());
+        assert!(validate_classifier(r"user='\'';DROP DATABASE d;--'").is_err());
+        assert!(validate_classifier(r#"user="'";DROP DATABASE d;--'"#).is_err());
+        assert_eq!(validate_classifier("user='o''brien'").unwrap(), "user='o''brien'");
+        assert!(validate_group_name("rg; drop").is_err());
+        assert!(build_doris_create_sql(&request).is_err());
+    }
+
+    #[test]
+    fn test_parse_groups_and_usage() {
+        let columns = strings(&["name", "id", "cpu_weight", "mem_limit", "classifiers"]);
+        let rows = vec![
+            strings(&["rg_etl", "10", "8", "30%", "(id=3, weight=2.0, user=etl)"]),
+            strings(&["rg_etl", "10", "8", "30%", "(id=4, weight=1.0, db='ods')"]),
+            strings(&["rg_bi", "11", "4", "20%", ""]),
+        ];
+        let groups = parse_starrocks_resource_groups(&columns, &rows);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].classifiers.len(), 2);
+        assert_eq!(groups[0].classifiers[1].id, Some(4));
+        assert_eq!(groups[0].properties.get("cpu_weight").map(String::as_str), Some("8"));
+        assert!(groups[1].classifiers.is_empty());
+
+        let metric_columns = strings(&["BE_ID", "NAME", "LABELS", "VALUE"]);
+        let metric_rows = vec![
+            strings(&["1", "resource_group_running_queries", r#"{"name":"rg_etl"}"#, "2"]),
+            strings(&["1", "resource_group_inuse_cpu_cores", r#"{"name":"rg_etl"}"#, "1.5"]),
+            strings(&["2", "resource_group_running_queries", r#"{"name":"rg_etl"}"#, "3"]),
+            strings(&["2", "resource_group_mem_inuse_bytes", r#"name="rg_etl""#, "1024"]),
+            strings(&["2", "query_latency", "{}", "9"]),
+        ];
+        let samples = parse_starrocks_be_metrics(&metric_columns, &metric_rows);
+        assert_eq!(samples.len(), 2);
+
+        let queue =
+            HashMap::from([("rg_bi".to_string(), GroupQueueCounts { running: 4, queued: 7 })]);
+        let usage = aggregate_group_usage(&groups, &samples, &queue);
+        let etl = usage.iter().find(|u| u.name == "rg_etl").unwrap();
+        assert_eq!(etl.running_queries, 3);
+        assert_eq!(etl.cpu_cores_used, Some(1.5));
+        assert_eq!(etl.mem_used_bytes, Some(1024));
+        assert_eq!(etl.backend_count, 2);
+        let bi = usage.iter().find(|u| u.name == "rg_bi").unwrap();
+        assert_eq!((bi.running_queries, bi.queued_queries), (4, 7));
+    }
+}
@@ -0,0 +1,370 @@
+// Routine Load History Service
+// Purpose: Periodically snapshot Routine Load progress / lag / error rows into SQLite and flag
+// jobs that are paused or whose offsets stopped advancing.
+
+use crate::models::Cluster;
+use crate::services::load_job_service::{RoutineLoadJob, RoutineLoadPartition};
+use crate::services::{ClusterService, LoadJobService};
+use crate::utils::{ApiResult, ScheduledTask};
+use chrono::{NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+/// Number of consecutive snapshots with unchanged offsets (and pending lag) before a job is stalled
+const STALL_SNAPSHOTS: i64 = 3;
+
+/// Alerts only consider snapshots newer than this
+const ALERT_FRESHNESS_MINUTES: i64 = 60;
+
+/// One recorded observation of a Routine Load job
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct RoutineLoadSnapshot {
+    pub job_id: String,
+    pub job_name: String,
+    pub database: String,
+    pub table: String,
+    pub state: String,
+    pub total_lag: Option<i64>,
+    pub loaded_rows: Option<i64>,
+    pub error_rows: Option<i64>,
+    pub partitions: Vec<RoutineLoadPartition>,
+    pub reason_of_state_changed: String,
+    pub stalled: bool,
+    pub collected_at: NaiveDateTime,
+}
+
+/// Why a Routine Load job needs attention
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutineLoadAlertKind {
+    /// Job is PAUSED (usually by too many error rows or a Kafka issue)
+    Paused,
+    /// Job is RUNNING but its offsets have not advanced while lag is pending
+    Stalled,
+}
+
+/// Routine Load job flagged by the collector
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct RoutineLoadAlert {
+    pub kind: RoutineLoadAlertKind,
+    pub job_id: String,
+    pub job_name: String,
+    pub database: String,
+    pub state: String,
+    pub total_lag: Option<i64>,
+    pub error_rows: Option<i64>,
+    pub reason_of_state_changed: String,
+    pub collected_at: NaiveDateTime,
+}
+
+#[derive(sqlx::FromRow)]
+struct SnapshotRow {
+    job_id: String,
+    job_name: String,
+    database_name: String,
+    table_name: String,
+    state: String,
+    total_lag: Option<i64>,
+    loaded_rows: Option<i64>,
+    error_rows: Option<i64>,
+    partitions: String,
+    reason_of_state_changed: Option<String>,
+    stalled: bool,
+    collected_at: NaiveDateTime,
+}
+
+impl From<SnapshotRow> for RoutineLoadSnapshot {
+    fn from(r: SnapshotRow) -> Self {
+        Self {
+            job_id: r.job_id,
+            job_name: r.job_name,
+            database: r.database_name,
+            table: r.table_name,
+            state: r.state,
+            total_lag: r.total_lag,
+            loaded_rows: r.loaded_rows,
+            error_rows: r.error_rows,
+            partitions: serde_json::from_str(&r.partitions).unwrap_or_default(),
+            reason_of_state_changed: r.reason_of_state_changed.unwrap_or_default(),
+            stalled: r.stalled,
+            collected_at: r.collected_at,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RoutineLoadHistoryService {
+    db: SqlitePool,
+    cluster_service: Arc<ClusterService>,
+    load_job_service: Arc<LoadJobService>,
+    retention_days: i64,
+}
+
+impl RoutineLoadHistoryService {
+    pub fn new(
+        db: SqlitePool,
+        cluster_service: Arc<ClusterService>,
+        load_job_service: Arc<LoadJobService>,
+        retention_days: i64,
+    ) -> Self {
+        Self { db, cluster_service, load_job_service, retention_days }
+    }
+
+    /// Execute one collection cycle (called by the ScheduledExecutor)
+    pub async fn collect_once(&self) -> Result<(), anyhow::Error> {
+        let clusters = self.cluster_service.list_clusters().await?;
+        for cluster in clusters {
+            if let Err(e) = self.collect_cluster(&cluster).await {
+                tracing::warn!(
+                    "Failed to collect routine load history for cluster {} ({}): {}",
+                    cluster.id,
+                    cluster.name,
+                    e
+                );
+            }
+        }
+
+        let cutoff = (Utc::now() - chrono::Duration::days(self.retention_days)).naive_utc();
+        sqlx::query("DELETE FROM routine_load_snapshots WHERE collected_at < ?")
+            .bind(cutoff)
+            .execute(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn collect_cluster(&self, cluster: &Cluster) -> ApiResult<()> {
+        let jobs = self
+            .load_job_service
+            .fetch_routine_loads(cluster, None, false)
+            .await?;
+
+        for job in &jobs {
+            let previous = self.recent_offsets(cluster.id, &job.id).await?;
+            let stalled = job.state == "RUNNING" && is_stalled(job, &previous);
+            if stalled {
+                tracing::warn!(
+                    "Routine load {}.{} on cluster {} is stalled (lag={:?})",
+                    job.database,
+                    job.name,
+                    cluster.name,
+                    job.total_lag
+                );
+            }
+            self.save_snapshot(cluster.id, job, stalled).await?;
+        }
+        Ok(())
+    }
+
+    /// Offsets of the most recent snapshots of a job (newest first)
+    async fn recent_offsets(
+        &self,
+        cluster_id: i64,
+        job_id: &str,
+    ) -> ApiResult<Vec<HashMap<String, i64>>> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT partitions FROM routine_load_snapshots
+             WHERE cluster_id = ? AND job_id = ?
+             ORDER BY collected_at DESC, id DESC LIMIT ?",
+        )
+        .bind(cluster_id)
+        .bind(job_id)
+        .bind(STALL_SNAPSHOTS - 1)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(json,)| {
+                offsets_of(
+                    &serde_json::from_str::<Vec<RoutineLoadPartition>>(&json).unwrap_or_default(),
+                )
+            })
+            .collect())
+    }
+
+    async fn save_snapshot(
+        &self,
+        cluster_id: i64,
+        job: &RoutineLoadJob,
+        stalled: bool,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO routine_load_snapshots (
+                cluster_id, job_id, job_name, database_name, table_name, state, total_lag,
+                loaded_rows, error_rows, partitions, reason_of_state_changed, stalled, collected_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(&job.id)
+        .bind(&job.name)
+        .bind(&job.database)
+        .bind(&job.table)
+        .bind(&job.state)
+        .bind(job.total_lag)
+        .bind(job.loaded_rows)
+        .bind(job.error_rows)
+        .bind(serde_json::to_string(&job.partitions)?)
+        .bind(&job.reason_of_state_changed)
+        .bind(stalled)
+        .bind(Utc::now().naive_utc())
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    /// Snapshot history of one Routine Load job, oldest first
+    pub async fn get_history(
+        &self,
+        cluster_id: i64,
+        database: &str,
+        job_name: &str,
+        hours: i64,
+    ) -> ApiResult<Vec<RoutineLoadSnapshot>> {
+        let since = (Utc::now() - chrono::Duration::hours(hours)).naive_utc();
+        let rows = sqlx::query_as::<_, SnapshotRow>(
+            r#"
+            SELECT job_id, job_name, database_name, table_name, state, total_lag, loaded_rows,
+                   error_rows, partitions, reason_of_state_changed, stalled, collected_at
+            FROM routine_load_snapshots
+            WHERE cluster_id = ? AND database_name = ? AND job_name = ? AND collected_at >= ?
+            ORDER BY collected_at ASC
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(database)
+        .bind(job_name)
+        .bind(since)
+        .fetch_all(&self.db)
+        .await?;
+        Ok(rows.into_iter().map(RoutineLoadSnapshot::from).collect())
+    }
+
+    /// Paused or stalled jobs according to their latest snapshot
+    pub async fn get_alerts(&self, cluster_id: i64) -> ApiResult<Vec<RoutineLoadAlert>> {
+        let since = (Utc::now() - chrono::Duration::minutes(ALERT_FRESHNESS_MINUTES)).naive_utc();
+        let rows = sqlx::query_as::<_, SnapshotRow>(
+            r#"
+            SELECT s.job_id, s.job_name, s.database_name, s.table_name, s.state, s.total_lag,
+                   s.loaded_rows, s.error_rows, s.partitions, s.reason_of_state_changed, s.stalled,
+                   s.collected_at
+            FROM routine_load_snapshots s
+            JOIN (
+                SELECT job_id, MAX(id) AS max_id FROM routine_load_snapshots
+                WHERE cluster_id = ? AND collected_at >= ?
+                GROUP BY job_id
+            ) latest ON s.id = latest.max_id
+            WHERE s.state = 'PAUSED' OR s.stalled = 1
+            ORDER BY s.collected_at DESC
+            "#,
+        )
+        .bind(cluster_id)
+        .bind(since)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| RoutineLoadAlert {
+                kind: if r.state == "PAUSED" {
+                    RoutineLoadAlertKind::Paused
+                } else {
+                    RoutineLoadAlertKind::Stalled
+                },
+                job_id: r.job_id,
+                job_name: r.job_name,
+                database: r.database_name,
+                state: r.state,
+                total_lag: r.total_lag,
+                error_rows: r.error_rows,
+                reason_of_state_changed: r.reason_of_state_changed.unwrap_or_default(),
+                collected_at: r.collected_at,
+            })
+            .collect())
+    }
+}
+
+impl ScheduledTask for RoutineLoadHistoryService {
+    fn run(&self) -> Pin<Box<dyn Future<Output = Result<(), anyhow::Error>> + Send + '_>> {
+        Box::pin(async move { self.collect_once().await })
+    }
+}
+
+fn offsets_of(partitions: &[RoutineLoadPartition]) -> HashMap<String, i64> {
+    partitions
+        .iter()
+        .filter_map(|p| p.current_offset.map(|o| (p.partition.clone(), o)))
+        .collect()
+}
+
+/// A job is stalled when it still has lag but its offsets equal those of the
+/// previous `STALL_SNAPSHOTS - 1` snapshots
+fn is_stalled(job: &RoutineLoadJob, previous: &[HashMap<String, i64>]) -> bool {
+    if job.total_lag.unwrap_or(0) <= 0 || previous.len() < (STALL_SNAPSHOTS - 1) as usize {
+        return false;
+    }
+    let current = offsets_of(&job.partitions);
+    !current.is_empty() && previous.iter().all(|p| *p == current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(offset: i64, lag: i64) -> RoutineLoadJob {
+        RoutineLoadJob {
+            id: "1".to_string(),
+            name: "job".to_string(),
+            database: "db".to_string(),
+            table: "tbl".to_string(),
+            state: "RUNNING".to_string(),
+            data_source_type: "KAFKA".to_string(),
+            create_time: String::new(),
+            pause_time: String::new(),
+            current_task_num: None,
+            loaded_rows: None,
+            error_rows: None,
+            received_bytes: None,
+            load_rows_rate: None,
+            total_lag: Some(lag),
+            partitions: vec![RoutineLoadPartition {
+                partition: "0".to_string(),
+                current_offset: Some(offset),
+                latest_offset: Some(offset + lag),
+                lag: Some(lag),
+                consume_rate: None,
+            }],
+            reason_of_state_changed: String::new(),
+            error_log_urls: String::new(),
+            other_msg: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_stalled_when_offsets_do_not_move() {
+        let previous = vec![offsets_of(&job(100, 5).partitions); 2];
+        assert!(is_stalled(&job(100, 50), &previous));
+    }
+
+    #[test]
+    fn test_not_stalled_when_advancing_or_caught_up() {
+        let previous =
+            vec![offsets_of(&job(90, 5).partitions), offsets_of(&job(100, 5).partitions)];
+        assert!(!is_stalled(&job(100, 50), &previous));
+
+        let previous = vec![offsets_of(&job(100, 0).partitions); 2];
+        assert!(!is_stalled(&job(100, 0), &previous));
+    }
+
+    #[test]
+    fn test_not_stalled_without_enough_history() {
+        let previous = vec![offsets_of(&job(100, 5).partitions)];
+        assert!(!is_stalled(&job(100, 50), &previous));
+    }
+}
@@ -0,0 +1,230 @@
+// Running Query Service
+// Purpose: Summarize the runtime profile of a still-running query - which operators have consumed
+// the most time so far - together with a progress and remaining-time estimate.
+
+use crate::models::{ClusterType, Query};
+use crate::services::profile_analyzer::{ExecutionTree, Profile};
+use crate::services::query_guard_service::parse_elapsed_seconds;
+use serde::{Deserialize, Serialize};
+
+/// Operators listed as hot in the running-query detail
+pub const HOT_OPERATOR_LIMIT: usize = 5;
+
+/// Time consumed so far by one operator of a running query
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunningOperator {
+    pub plan_node_id: Option<i32>,
+    pub operator_name: String,
+    pub fragment_id: Option<String>,
+    /// Share of the operator time of the whole query so far
+    pub time_percentage: Option<f64>,
+    pub operator_time_ms: Option<f64>,
+    pub rows: Option<u64>,
+}
+
+/// Running query with its in-flight profile
+#[derive(Debug, Serialize)]
+pub struct RunningQueryDetail {
+    pub query: Query,
+    /// RUNNING / PENDING as reported by the query queue (StarRocks `SHOW RUNNING QUERIES`)
+    pub queue_state: Option<String>,
+    pub elapsed_seconds: Option<f64>,
+    pub progress_pct: Option<f64>,
+    /// Remaining time assuming the query keeps its current pace
+    pub estimated_remaining_seconds: Option<f64>,
+    pub profile_available: bool,
+    /// Why the runtime profile could not be used
+    pub profile_message: Option<String>,
+    /// Operators that consumed the most time so far
+    pub hot_operators: Vec<RunningOperator>,
+    pub execution_tree: Option<ExecutionTree>,
+}
+
+/// `ExecProgress` of `current_queries`, e.g. `45.67%`
+pub fn parse_progress_pct(value: &str) -> Option<f64> {
+    let pct = value
+        .trim()
+        .trim_end_matches('%')
+        .trim()
+        .parse::<f64>()
+        .ok()?;
+    (0.0..=100.0).contains(&pct).then_some(pct)
+}
+
+pub fn estimate_remaining_seconds(elapsed_seconds: f64, progress_pct: f64) -> Option<f64> {
+    if progress_pct >= 100.0 {
+        Some(0.0)
+    } else if progress_pct > 0.0 {
+        Some(elapsed_seconds * (100.0 - progress_pct) / progress_pct)
+    } else {
+        None
+    }
+}
+
+/// Operators ordered by time consumed so far
+pub fn hot_operators(tree: &ExecutionTree, limit: usize) -> Vec<RunningOperator> {
+    let mut operators: Vec<RunningOperator> = tree
+        .nodes
+        .iter()
+        .filter(|n| n.time_percentage.is_some() || n.metrics.operator_total_time.is_some())
+        .map(|n| RunningOperator {
+            plan_node_id: n.plan_node_id,
+            operator_name: n.operator_name.clone(),
+            fragment_id: n.fragment_id.clone(),
+            time_percentage: n.time_percentage,
+            operator_time_ms: n
+                .metrics
+                .operator_total_time
+                .map(|ns| ns as f64 / 1_000_000.0),
+            rows: n.rows,
+        })
+        .collect();
+    operators.sort_by(|a, b| {
+        let key = |o: &RunningOperator| (o.time_percentage, o.operator_time_ms);
+        key(b)
+            .partial_cmp(&key(a))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    operators.truncate(limit);
+    operators
+}
+
+/// What has to be enabled for a running query to report its profile
+pub fn runtime_profile_hint(cluster_type: &ClusterType) -> &'static str {
+    match cluster_type {
+        ClusterType::StarRocks => {
+            "Runtime profiles are only reported when the session runs with enable_profile=true \
+             (refreshed every runtime_profile_report_interval seconds)"
+        },
+        ClusterType::Doris => {
+            "Runtime profiles are only reported when the session runs with enable_profile=true"
+        },
+    }
+}
+
+/// Combine the `current_queries` row, the queue state and the parsed runtime profile
+pub fn build_running_query_detail(
+    cluster_type: &ClusterType,
+    query: Query,
+    queue_state: Option<String>,
+    profile: Result<Profile, String>,
+) -> RunningQueryDetail {
+    let elapsed_seconds = parse_elapsed_seconds(cluster_type, &query.exec_time);
+    let progress_pct = query.exec_progress.as_deref().and_then(parse_progress_pct);
+    let estimated_remaining_seconds = match (elapsed_seconds, progress_pct) {
+        (Some(elapsed), Some(pct)) => estimate_remaining_seconds(elapsed, pct),
+        _ => None,
+    };
+
+    let (execution_tree, profile_message) = match profile {
+        Ok(profile) if profile.execution_tree.is_some() => (profile.execution_tree, None),
+        Ok(_) => (None, Some("The runtime profile has no execution tree yet".to_string())),
+        Err(message) => (None, Some(message)),
+    };
+    let hot_operators = execution_tree
+        .as_ref()
+        .map(|tree| hot_operators(tree, HOT_OPERATOR_LIMIT))
+        .unwrap_or_default();
+
+    RunningQueryDetail {
+        query,
+        queue_state,
+        elapsed_seconds,
+        progress_pct,
+        estimated_remaining_seconds,
+        profile_available: execution_tree.is_some(),
+        profile_message,
+        hot_operators,
+        execution_tree,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::profile_analyzer::{ExecutionTreeNode, OperatorMetrics};
+
+    fn node(id: &str, name: &str, pct: Option<f64>, time_ns: Option<u64>) -> ExecutionTreeNode {
+        ExecutionTreeNode {
+            id: id.to_string(),
+            operator_name: name.to_string(),
+            node_type: Default::default(),
+            plan_node_id: id.parse().ok(),
+            parent_plan_node_id: None,
+            metrics: OperatorMetrics { operator_total_time: time_ns, ..Default::default() },
+            children: Vec::new(),
+            depth: 0,
+            is_hotspot: false,
+            hotspot_severity: Default::default(),
+            fragment_id: None,
+            pipeline_id: None,
+            time_percentage: pct,
+            rows: None,
+            is_most_consuming: false,
+            is_second_most_consuming: false,
+            unique_metrics: Default::default(),
+            has_diagnostic: false,
+            diagnostic_ids: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_progress_estimate() {
+        assert_eq!(parse_progress_pct("25.00%"), Some(25.0));
+        assert_eq!(parse_progress_pct(" 100 % "), Some(100.0));
+        assert_eq!(parse_progress_pct("n/a"), None);
+        assert_eq!(parse_progress_pct("140%"), None);
+        assert_eq!(estimate_remaining_seconds(30.0, 25.0), Some(90.0));
+        assert_eq!(estimate_remaining_seconds(30.0, 100.0), Some(0.0));
+        assert_eq!(estimate_remaining_seconds(30.0, 0.0), None);
+    }
+
+    #[test]
+    fn test_detail_reads_bare_exec_time_per_cluster_type() {
+        let query = || Query {
+            query_id: "a1b2-c3".to_string(),
+            connection_id: "42".to_string(),
+            database: "sales".to_string(),
+            user: "etl".to_string(),
+            scan_bytes: "0".to_string(),
+            process_rows: "0".to_string(),
+            cpu_time: "0".to_string(),
+            exec_time: "30000".to_string(),
+            sql: "select 1".to_string(),
+            start_time: None,
+            fe_ip: None,
+            memory_usage: None,
+            disk_spill_size: None,
+            exec_progress: Some("25.00%".to_string()),
+            warehouse: None,
+            custom_query_id: None,
+            resource_group: None,
+        };
+        let no_profile = || Err("no profile".to_string());
+
+        // StarRocks ExecTime is milliseconds
+        let detail =
+            build_running_query_detail(&ClusterType::StarRocks, query(), None, no_profile());
+        assert_eq!(detail.elapsed_seconds, Some(30.0));
+        assert_eq!(detail.estimated_remaining_seconds, Some(90.0));
+
+        let detail = build_running_query_detail(&ClusterType::Doris, query(), None, no_profile());
+        assert_eq!(detail.elapsed_seconds, Some(30000.0));
+    }
+
+    #[test]
+    fn test_hot_operators_ordering() {
+        let nodes = vec![
+            node("0", "RESULT_SINK", Some(1.0), Some(1_000_000)),
+            node("1", "HASH_JOIN", Some(62.5), Some(5_000_000_000)),
+            node("2", "OLAP_SCAN", Some(30.0), Some(2_400_000_000)),
+            node("3", "EXCHANGE", None, None),
+        ];
+        let tree = ExecutionTree { root: nodes[0].clone(), nodes };
+        let hot = hot_operators(&tree, 2);
+        assert_eq!(hot.len(), 2);
+        assert_eq!(hot[0].operator_name, "HASH_JOIN");
+        assert_eq!(hot[0].operator_time_ms, Some(5000.0));
+        assert_eq!(hot[1].operator_name, "OLAP_SCAN");
+    }
+}
@@ -0,0 +1,475 @@
+// Session Template Service
+// Purpose: Named session-variable templates per organization ("big ETL", "dashboard", ...) that are
+// applied before the SQL editor runs a statement, and turning parameter suggestions into SET
+// statements for the editor session or user properties.
+
+use crate::models::ClusterType;
+use crate::utils::{ApiError, ApiResult};
+use chrono::{NaiveDateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/// Variables one template may set
+const MAX_TEMPLATE_VARIABLES: usize = 50;
+
+pub const TARGET_SESSION: &str = "session";
+pub const TARGET_USER: &str = "user";
+
+static VARIABLE_NAME: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[A-Za-z_][A-Za-z0-9_.]{0,127}$").unwrap());
+static USER_NAME: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[A-Za-z0-9_.\-]{1,128}$").unwrap());
+static NUMERIC_VALUE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^-?[0-9]+(\.[0-9]+)?$").unwrap());
+/// `SET [SESSION] name = value[;] [-- comment]` as produced by the profile diagnostics
+static SET_COMMAND: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^\s*SET\s+(?:SESSION\s+|@@session\.)?([A-Za-z_][A-Za-z0-9_.]*)\s*=\s*([^;]+?)\s*;?\s*(?:--.*)?$")
+        .unwrap()
+});
+
+/// Named set of session variables of one organization
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct SessionVariableTemplate {
+    pub id: i64,
+    pub organization_id: Option<i64>,
+    pub name: String,
+    pub description: Option<String>,
+    /// Variable name -> value, applied in name order
+    pub variables: BTreeMap<String, String>,
+    pub created_by: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Create / update request of a session-variable template
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SessionVariableTemplateRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub variables: BTreeMap<String, String>,
+}
+
+/// Apply a parameter suggestion to the editor session or to a user
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ApplySuggestionRequest {
+    /// `SET name = value;` command of the suggestion; alternative to name / value
+    pub command: Option<String>,
+    pub name: Option<String>,
+    pub value: Option<String>,
+    /// session: validate and return the statement for the editor | user: persist as user property
+    #[serde(default = "default_target")]
+    pub target: String,
+    /// Required for the user target
+    pub user: Option<String>,
+}
+
+fn default_target() -> String {
+    TARGET_SESSION.to_string()
+}
+
+/// Normalized suggestion and the statement that applies it
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq)]
+pub struct AppliedSuggestion {
+    pub name: String,
+    pub value: String,
+    pub target: String,
+    pub user: Option<String>,
+    pub statement: String,
+}
+
+/// Requester identity passed down from the handler
+pub struct SessionTemplateRequester<'a> {
+    pub username: &'a str,
+    pub organization_id: Option<i64>,
+    pub is_super_admin: bool,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct TemplateRow {
+    id: i64,
+    organization_id: Option<i64>,
+    name: String,
+    description: Option<String>,
+    variables: String,
+    created_by: String,
+    created_at: NaiveDateTime,
+    updated_at: NaiveDateTime,
+}
+
+impl From<TemplateRow> for SessionVariableTemplate {
+    fn from(row: TemplateRow) -> Self {
+        Self {
+            variables: serde_json::from_str(&row.variables).unwrap_or_default(),
+            id: row.id,
+            organization_id: row.organization_id,
+            name: row.name,
+            description: row.description,
+            created_by: row.created_by,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+const TEMPLATE_COLUMNS: &str =
+    "id, organization_id, name, description, variables, created_by, created_at, updated_at";
+
+fn validate_variable(name: &str, value: &str) -> ApiResult<()> {
+    if !VARIABLE_NAME.is_match(name) {
+        return Err(ApiError::validation_error(format!("Invalid variable name: {}", name)));
+    }
+    let value = value.trim();
+    if value.is_empty() || value.len() > 1024 {
+        return Err(ApiError::validation_error(format!("Invalid value for {}", name)));
+    }
+    if value.contains(['\'', '"', '\\', ';', '\n', '\r', '`']) {
+        return Err(ApiError::validation_error(format!(
+            "Value of {} must not contain quotes, backslashes, semicolons or line breaks",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Numbers and booleans are passed as is, everything else as string literal
+fn render_value(value: &str) -> String {
+    let value = value.trim();
+    if NUMERIC_VALUE.is_match(value)
+        || value.eq_ignore_ascii_case("true")
+        || value.eq_ignore_ascii_case("false")
+    {
+        value.to_string()
+    } else {
+        format!("'{}'", value)
+    }
+}
+
+/// `SET name = value` for the current session
+pub fn set_statement(name: &str, value: &str) -> ApiResult<String> {
+    validate_variable(name, value)?;
+    Ok(format!("SET {} = {}", name, render_value(value)))
+}
+
+/// Split a suggestion command into variable name and unquoted value
+///
+/// Only session `SET` commands qualify; GLOBAL variables and BE configs are rejected.
+pub fn parse_set_command(command: &str) -> ApiResult<(String, String)> {
+    let caps = SET_COMMAND.captures(command.trim()).ok_or_else(|| {
+        ApiError::validation_error(format!("Not a session SET command: {}", command.trim()))
+    })?;
+    let name = caps[1].to_string();
+    let value = caps[2]
+        .trim()
+        .trim_matches(|c| c == '\'' || c == '"')
+        .to_string();
+    validate_variable(&name, &value)?;
+    Ok((name, value))
+}
+
+/// Persist a session variable as default of a user
+///
+/// StarRocks keeps it as `session.<name>` user property, Doris only accepts its user property keys.
+pub fn user_property_statement(
+    cluster_type: &ClusterType,
+    user: &str,
+    name: &str,
+    value: &str,
+) -> ApiResult<String> {
+    if !USER_NAME.is_match(user) {
+        return Err(ApiError::validation_error(format!("Invalid user name: {}", user)));
+    }
+    validate_variable(name, value)?;
+    let value = value.trim();
+    Ok(match cluster_type {
+        ClusterType::StarRocks => {
+            format!("ALTER USER '{}' SET PROPERTIES (\"session.{}\" = \"{}\")", user, name, value)
+        },
+        ClusterType::Doris => format!("SET PROPERTY FOR '{}' '{}' = '{}'", user, name, value),
+    })
+}
+
+/// Resolve an apply request into the statement for its target
+pub fn build_applied_suggestion(
+    cluster_type: &ClusterType,
+    request: &ApplySuggestionRequest,
+) -> ApiResult<AppliedSuggestion> {
+    let (name, value) = match (&request.command, &request.name, &request.value) {
+        (Some(command), _, _) if !command.trim().is_empty() => parse_set_command(command)?,
+        (_, Some(name), Some(value)) => (name.trim().to_string(), value.trim().to_string()),
+        _ => {
+            return Err(ApiError::validation_error("Either command or name and value is required"));
+        },
+    };
+
+    let (statement, user) = match request.target.as_str() {
+        TARGET_SESSION => (set_statement(&name, &value)?, None),
+        TARGET_USER => {
+            let user = request
+                .user
+                .as_deref()
+                .map(str::trim)
+                .filter(|u| !u.is_empty())
+                .ok_or_else(|| {
+                    ApiError::validation_error("User is required for the user target")
+                })?;
+            (user_property_statement(cluster_type, user, &name, &value)?, Some(user.to_string()))
+        },
+        other => {
+            return Err(ApiError::validation_error(format!(
+                "Invalid target {}, expected {} or {}",
+                other, TARGET_SESSION, TARGET_USER
+            )));
+        },
+    };
+
+    Ok(AppliedSuggestion { name, value, target: request.target.clone(), user, statement })
+}
+
+fn validate_request(request: &SessionVariableTemplateRequest) -> ApiResult<()> {
+    if request.name.trim().is_empty() {
+        return Err(ApiError::validation_error("Template name cannot be empty"));
+    }
+    if request.variables.is_empty() {
+        return Err(ApiError::validation_error("A template needs at least one variable"));
+    }
+    if request.variables.len() > MAX_TEMPLATE_VARIABLES {
+        return Err(ApiError::validation_error(format!(
+            "A template can set at most {} variables",
+            MAX_TEMPLATE_VARIABLES
+        )));
+    }
+    for (name, value) in &request.variables {
+        validate_variable(name, value)?;
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct SessionTemplateService {
+    db: SqlitePool,
+}
+
+impl SessionTemplateService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    pub async fn list_templates(
+        &self,
+        requester: &SessionTemplateRequester<'_>,
+    ) -> ApiResult<Vec<SessionVariableTemplate>> {
+        let rows: Vec<TemplateRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM session_variable_templates WHERE ? OR organization_id IS ? \
+             ORDER BY name",
+            TEMPLATE_COLUMNS
+        ))
+        .bind(requester.is_super_admin)
+        .bind(requester.organization_id)
+        .fetch_all(&self.db)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(SessionVariableTemplate::from)
+            .collect())
+    }
+
+    pub async fn get_template(
+        &self,
+        template_id: i64,
+        requester: &SessionTemplateRequester<'_>,
+    ) -> ApiResult<SessionVariableTemplate> {
+        let row: TemplateRow = sqlx::query_as(&format!(
+            "SELECT {} FROM session_variable_templates WHERE id = ?",
+            TEMPLATE_COLUMNS
+        ))
+        .bind(template_id)
+        .fetch_optional(&self.db)
+        .await?
+        .ok_or_else(|| {
+            ApiError::not_found(format!("Session variable template {} not found", template_id))
+        })?;
+
+        if !requester.is_super_admin && row.organization_id != requester.organization_id {
+            return Err(ApiError::forbidden("You can only use templates of your organization"));
+        }
+        Ok(row.into())
+    }
+
+    pub async fn create_template(
+        &self,
+        request: &SessionVariableTemplateRequest,
+        requester: &SessionTemplateRequester<'_>,
+    ) -> ApiResult<SessionVariableTemplate> {
+        validate_request(request)?;
+        self.check_unique_name(request.name.trim(), requester.organization_id, None)
+            .await?;
+
+        let now = Utc::now().naive_utc();
+        let template_id = sqlx::query(
+            r#"
+            INSERT INTO session_variable_templates (
+                organization_id, name, description, variables, created_by, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(requester.organization_id)
+        .bind(request.name.trim())
+        .bind(&request.description)
+        .bind(serde_json::to_string(&request.variables).unwrap_or_default())
+        .bind(requester.username)
+        .bind(now)
+        .bind(now)
+        .execute(&self.db)
+        .await?
+        .last_insert_rowid();
+
+        tracing::info!(
+            "Session variable template {} ({}) created by {}",
+            template_id,
+            request.name,
+            requester.username
+        );
+        self.get_template(template_id, requester).await
+    }
+
+    pub async fn update_template(
+        &self,
+        template_id: i64,
+        request: &SessionVariableTemplateRequest,
+        requester: &SessionTemplateRequester<'_>,
+    ) -> ApiResult<SessionVariableTemplate> {
+        let existing = self.get_template(template_id, requester).await?;
+        validate_request(request)?;
+        self.check_unique_name(request.name.trim(), existing.organization_id, Some(template_id))
+            .await?;
+
+        sqlx::query(
+            r#"
+            UPDATE session_variable_templates
+            SET name = ?, description = ?, variables = ?, updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(request.name.trim())
+        .bind(&request.description)
+        .bind(serde_json::to_string(&request.variables).unwrap_or_default())
+        .bind(Utc::now().naive_utc())
+        .bind(template_id)
+        .execute(&self.db)
+        .await?;
+
+        self.get_template(template_id, requester).await
+    }
+
+    pub async fn delete_template(
+        &self,
+        template_id: i64,
+        requester: &SessionTemplateRequester<'_>,
+    ) -> ApiResult<()> {
+        self.get_template(template_id, requester).await?;
+        sqlx::query("DELETE FROM session_variable_templates WHERE id = ?")
+            .bind(template_id)
+            .execute(&self.db)
+            .await?;
+        tracing::info!(
+            "Session variable template {} deleted by {}",
+            template_id,
+            requester.username
+        );
+        Ok(())
+    }
+
+    /// SET statements run before the SQL editor executes its statements
+    ///
+    /// The template is applied first, explicitly given variables override it.
+    pub async fn session_statements(
+        &self,
+        template_id: Option<i64>,
+        overrides: Option<&BTreeMap<String, String>>,
+        requester: &SessionTemplateRequester<'_>,
+    ) -> ApiResult<Vec<String>> {
+        let mut variables = match template_id {
+            Some(id) => self.get_template(id, requester).await?.variables,
+            None => BTreeMap::new(),
+        };
+        if let Some(overrides) = overrides {
+            variables.extend(overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        variables
+            .iter()
+            .map(|(name, value)| set_statement(name, value))
+            .collect()
+    }
+
+    async fn check_unique_name(
+        &self,
+        name: &str,
+        organization_id: Option<i64>,
+        exclude_id: Option<i64>,
+    ) -> ApiResult<()> {
+        let existing: Option<i64> = sqlx::query_scalar(
+            "SELECT id FROM session_variable_templates \
+             WHERE organization_id IS ? AND name = ? AND id IS NOT ?",
+        )
+        .bind(organization_id)
+        .bind(name)
+        .bind(exclude_id)
+        .fetch_optional(&self.db)
+        .await?;
+        if existing.is_some() {
+            return Err(ApiError::validation_error(format!("Template {} already exists", name)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_suggestion_commands() {
+        assert_eq!(
+            parse_set_command("SET enable_scan_datacache = true;").unwrap(),
+            ("enable_scan_datacache".to_string(), "true".to_string())
+        );
+        assert_eq!(
+            parse_set_command("SET runtime_filter_max_size = 67108864; -- 64MB").unwrap(),
+            ("runtime_filter_max_size".to_string(), "67108864".to_string())
+        );
+        assert_eq!(
+            parse_set_command("set session query_timeout='600'").unwrap(),
+            ("query_timeout".to_string(), "600".to_string())
+        );
+        assert!(parse_set_command("SET GLOBAL query_timeout = 600").is_err());
+        assert!(parse_set_command("ADMIN SET FRONTEND CONFIG (\"a\" = \"b\")").is_err());
+        assert!(parse_set_command("SET a = 1; DROP TABLE t").is_err());
+    }
+
+    #[test]
+    fn test_statements_per_target() {
+        assert_eq!(set_statement("query_timeout", "600").unwrap(), "SET query_timeout = 600");
+        assert_eq!(set_statement("sql_dialect", "trino").unwrap(), "SET sql_dialect = 'trino'");
+        assert!(set_statement("query_timeout", "1' OR '1").is_err());
+
+        let request = ApplySuggestionRequest {
+            command: Some("SET query_mem_limit = 8589934592;".to_string()),
+            name: None,
+            value: None,
+            target: TARGET_USER.to_string(),
+            user: Some("etl".to_string()),
+        };
+        let applied = build_applied_suggestion(&ClusterType::StarRocks, &request).unwrap();
+        assert_eq!(
+            applied.statement,
+            "ALTER USER 'etl' SET PROPERTIES (\"session.query_mem_limit\" = \"8589934592\")"
+        );
+        let applied = build_applied_suggestion(&ClusterType::Doris, &request).unwrap();
+        assert_eq!(applied.statement, "SET PROPERTY FOR 'etl' 'query_mem_limit' = '8589934592'");
+
+        let request = ApplySuggestionRequest { user: Some("etl'@'%".to_string()), ..request };
+        assert!(build_applied_suggestion(&ClusterType::StarRocks, &request).is_err());
+    }
+}
@@ -0,0 +1,763 @@
+// SQL Blacklist Service
+// Purpose: Preview a blacklist pattern against recent audit-log SQL before it goes live, and keep
+// blacklist rules (owner, reason, expiry) in Stellar so they are re-applied after FE restarts and
+// kept in sync across all clusters they target.
+
+use crate::models::{Cluster, SqlBlacklistItem};
+use crate::services::audit_log_service::AuditStatement;
+use crate::services::{ClusterService, MySQLPoolManager, create_adapter};
+use crate::utils::{ApiError, ApiResult, ScheduledTask};
+use chrono::{NaiveDateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+/// Default audit-log window of a pattern test
+pub const DEFAULT_TEST_DAYS: i64 = 7;
+
+/// Default number of sample statements kept per user
+pub const DEFAULT_SAMPLES_PER_USER: usize = 5;
+
+/// Request to test a blacklist pattern against recent SQL
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SqlBlacklistTestRequest {
+    pub pattern: String,
+    /// Audit-log window in days (default: 7)
+    pub days: Option<i64>,
+    /// Sample statements per user (default: 5)
+    pub samples_per_user: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct SqlBlacklistSample {
+    pub query_id: String,
+    pub database: String,
+    pub timestamp: String,
+    pub stmt: String,
+}
+
+/// Statements of one user the pattern would have blocked
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct SqlBlacklistUserImpact {
+    pub user: String,
+    pub matched: usize,
+    pub samples: Vec<SqlBlacklistSample>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct SqlBlacklistTestResult {
+    pub pattern: String,
+    pub days: i64,
+    pub scanned_statements: usize,
+    pub matched_statements: usize,
+    /// True when the scan hit the statement limit, so counts are a lower bound
+    pub truncated: bool,
+    /// Affected users, most matches first
+    pub users: Vec<SqlBlacklistUserImpact>,
+}
+
+/// A blacklist rule managed by Stellar
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, sqlx::FromRow)]
+pub struct SqlBlacklistRule {
+    pub id: i64,
+    pub organization_id: Option<i64>,
+    pub pattern: String,
+    pub reason: Option<String>,
+    pub owner_id: i64,
+    pub owner_name: String,
+    /// The rule is removed from its clusters once this time (UTC) has passed
+    pub expires_at: Option<NaiveDateTime>,
+    pub enabled: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+/// Apply state of a rule on one cluster
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, sqlx::FromRow)]
+pub struct SqlBlacklistRuleTarget {
+    pub cluster_id: i64,
+    pub cluster_name: String,
+    /// Blacklist ID (StarRocks) / block rule name (Doris) on the cluster
+    pub remote_id: Option<String>,
+    /// pending | applied | removed | failed
+    pub status: String,
+    pub last_error: Option<String>,
+    pub synced_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
+pub struct SqlBlacklistRuleDetail {
+    #[serde(flatten)]
+    pub rule: SqlBlacklistRule,
+    pub expired: bool,
+    pub targets: Vec<SqlBlacklistRuleTarget>,
+}
+
+/// Create / update request of a managed rule
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SqlBlacklistRuleRequest {
+    pub pattern: String,
+    pub reason: Option<String>,
+    pub expires_at: Option<NaiveDateTime>,
+    /// Clusters the rule is applied to
+    pub cluster_ids: Vec<i64>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Requester identity passed down from the handler
+pub struct SqlBlacklistRequester<'a> {
+    pub user_id: i64,
+    pub username: &'a str,
+    pub organization_id: Option<i64>,
+    pub is_super_admin: bool,
+}
+
+/// Target row joined with the rule state the reconciler needs
+#[derive(Debug, sqlx::FromRow)]
+struct TargetState {
+    rule_id: i64,
+    cluster_id: i64,
+    applied_pattern: Option<String>,
+    detached: bool,
+    pattern: String,
+    enabled: bool,
+    expires_at: Option<NaiveDateTime>,
+}
+
+/// Remote changes needed to bring one cluster in line with one rule
+#[derive(Debug, Default, PartialEq)]
+struct TargetPlan {
+    /// Remote entries of a previously applied pattern to delete
+    remove_ids: Vec<String>,
+    /// Pattern to add
+    add: Option<String>,
+    /// Remote ID of the desired pattern when it is already present
+    existing_id: Option<String>,
+}
+
+/// Validate a blacklist pattern the way the FE will compile it
+pub fn compile_blacklist_pattern(pattern: &str) -> ApiResult<Regex> {
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return Err(ApiError::validation_error("Pattern cannot be empty"));
+    }
+    Regex::new(pattern)
+        .map_err(|e| ApiError::validation_error(format!("Invalid blacklist pattern: {}", e)))
+}
+
+/// SQL text as the FE matches it: trimmed, lower-cased, whitespace collapsed
+pub fn normalize_blacklist_sql(sql: &str) -> String {
+    sql.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Count and sample the statements a pattern would block, grouped by user
+pub fn preview_blacklist_pattern(
+    regex: &Regex,
+    statements: &[AuditStatement],
+    samples_per_user: usize,
+) -> (usize, Vec<SqlBlacklistUserImpact>) {
+    let mut by_user: BTreeMap<String, SqlBlacklistUserImpact> = BTreeMap::new();
+    let mut matched = 0;
+    for statement in statements {
+        if !regex.is_match(&normalize_blacklist_sql(&statement.stmt)) {
+            continue;
+        }
+        matched += 1;
+        let impact =
+            by_user
+                .entry(statement.user.clone())
+                .or_insert_with(|| SqlBlacklistUserImpact {
+                    user: statement.user.clone(),
+                    matched: 0,
+                    samples: Vec::new(),
+                });
+        impact.matched += 1;
+        if impact.samples.len() < samples_per_user {
+            impact.samples.push(SqlBlacklistSample {
+                query_id: statement.query_id.clone(),
+                database: statement.database.clone(),
+                timestamp: statement.timestamp.clone(),
+                stmt: statement.stmt.clone(),
+            });
+        }
+    }
+
+    let mut users: Vec<SqlBlacklistUserImpact> = by_user.into_values().collect();
+    users.sort_by(|a, b| b.matched.cmp(&a.matched).then_with(|| a.user.cmp(&b.user)));
+    (matched, users)
+}
+
+/// Double-quoted pattern literal of StarRocks `ADD SQLBLACKLIST`
+///
+/// The FE unescapes the literal, so regex escapes such as `\d` are doubled to be stored and
+/// listed back unchanged; otherwise the stored pattern never matches the rule and is re-added.
+pub fn quote_blacklist_pattern(pattern: &str) -> String {
+    format!("\"{}\"", pattern.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn plan_target(
+    desired: Option<&str>,
+    applied: Option<&str>,
+    remote: &[SqlBlacklistItem],
+) -> TargetPlan {
+    let mut plan = TargetPlan::default();
+    if let Some(applied) = applied
+        && Some(applied) != desired
+    {
+        plan.remove_ids = remote
+            .iter()
+            .filter(|item| item.pattern.trim() == applied)
+            .map(|item| item.id.clone())
+            .collect();
+    }
+    if let Some(desired) = desired {
+        match remote.iter().find(|item| item.pattern.trim() == desired) {
+            Some(item) => plan.existing_id = Some(item.id.clone()),
+            None => plan.add = Some(desired.to_string()),
+        }
+    }
+    plan
+}
+
+fn is_expired(expires_at: Option<NaiveDateTime>) -> bool {
+    expires_at.is_some_and(|t| t <= Utc::now().naive_utc())
+}
+
+#[derive(Clone)]
+pub struct SqlBlacklistService {
+    db: SqlitePool,
+    cluster_service: Arc<ClusterService>,
+    mysql_pool_manager: Arc<MySQLPoolManager>,
+}
+
+impl SqlBlacklistService {
+    pub fn new(
+        db: SqlitePool,
+        cluster_service: Arc<ClusterService>,
+        mysql_pool_manager: Arc<MySQLPoolManager>,
+    ) -> Self {
+        Self { db, cluster_service, mysql_pool_manager }
+    }
+
+    pub async fn list_rules(
+        &self,
+        requester: &SqlBlacklistRequester<'_>,
+    ) -> ApiResult<Vec<SqlBlacklistRuleDetail>> {
+        let rules: Vec<SqlBlacklistRule> = sqlx::query_as(
+            r#"
+            SELECT id, organization_id, pattern, reason, owner_id, owner_name, expires_at, enabled,
+                   created_at, updated_at
+            FROM sql_blacklist_rules
+            WHERE ? OR organization_id IS ?
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(requester.is_super_admin)
+        .bind(requester.organization_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        let mut details = Vec::with_capacity(rules.len());
+        for rule in rules {
+            details.push(self.detail(rule).await?);
+        }
+        Ok(details)
+    }
+
+    pub async fn get_rule(
+        &self,
+        rule_id: i64,
+        requester: &SqlBlacklistRequester<'_>,
+    ) -> ApiResult<SqlBlacklistRuleDetail> {
+        let rule = self.load_rule(rule_id, requester).await?;
+        self.detail(rule).await
+    }
+
+    /// Store a rule and push it to its clusters right away
+    pub async fn create_rule(
+        &self,
+        request: &SqlBlacklistRuleRequest,
+        requester: &SqlBlacklistRequester<'_>,
+    ) -> ApiResult<SqlBlacklistRuleDetail> {
+        let pattern = request.pattern.trim();
+        compile_blacklist_pattern(pattern)?;
+        self.check_clusters(&request.cluster_ids, requester).await?;
+
+        let now = Utc::now().naive_utc();
+        let rule_id = sqlx::query(
+            r#"
+            INSERT INTO sql_blacklist_rules (
+                organization_id, pattern, reason, owner_id, owner_name, expires_at, enabled,
+                created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(requester.organization_id)
+        .bind(pattern)
+        .bind(request.reason.as_deref())
+        .bind(requester.user_id)
+        .bind(requester.username)
+        .bind(request.expires_at)
+        .bind(request.enabled)
+        .bind(now)
+        .bind(now)
+        .execute(&self.db)
+        .await?
+        .last_insert_rowid();
+
+        for cluster_id in &request.cluster_ids {
+            self.attach_cluster(rule_id, *cluster_id).await?;
+        }
+        tracing::info!("SQL blacklist rule {} created by {}", rule_id, requester.username);
+
+        self.sync_rule(rule_id, requester).await
+    }
+
+    /// Change a rule; clusters dropped from `cluster_ids` have the rule removed
+    pub async fn update_rule(
+        &self,
+        rule_id: i64,
+        request: &SqlBlacklistRuleRequest,
+        requester: &SqlBlacklistRequester<'_>,
+    ) -> ApiResult<SqlBlacklistRuleDetail> {
+        self.load_rule(rule_id, requester).await?;
+        let pattern = request.pattern.trim();
+        compile_blacklist_pattern(pattern)?;
+        self.check_clusters(&request.cluster_ids, requester).await?;
+
+        sqlx::query(
+            r#"
+            UPDATE sql_blacklist_rules
+            SET pattern = ?, reason = ?, expires_at = ?, enabled = ?, updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(pattern)
+        .bind(request.reason.as_deref())
+        .bind(request.expires_at)
+        .bind(request.enabled)
+        .bind(Utc::now().naive_utc())
+        .bind(rule_id)
+        .execute(&self.db)
+        .await?;
+
+        let current: Vec<(i64,)> =
+            sqlx::query_as("SELECT cluster_id FROM sql_blacklist_rule_targets WHERE rule_id = ?")
+                .bind(rule_id)
+                .fetch_all(&self.db)
+                .await?;
+        for (cluster_id,) in current {
+            if !request.cluster_ids.contains(&cluster_id) {
+                sqlx::query(
+                    "UPDATE sql_blacklist_rule_targets SET detached = 1 \
+                     WHERE rule_id = ? AND cluster_id = ?",
+                )
+                .bind(rule_id)
+                .bind(cluster_id)
+                .execute(&self.db)
+                .await?;
+            }
+        }
+        for cluster_id in &request.cluster_ids {
+            self.attach_cluster(rule_id, *cluster_id).await?;
+        }
+        tracing::info!("SQL blacklist rule {} updated by {}", rule_id, requester.username);
+
+        self.sync_rule(rule_id, requester).await
+    }
+
+    /// Remove a rule from every cluster, then forget it
+    pub async fn delete_rule(
+        &self,
+        rule_id: i64,
+        requester: &SqlBlacklistRequester<'_>,
+    ) -> ApiResult<()> {
+        self.load_rule(rule_id, requester).await?;
+        sqlx::query("UPDATE sql_blacklist_rule_targets SET detached = 1 WHERE rule_id = ?")
+            .bind(rule_id)
+            .execute(&self.db)
+            .await?;
+        self.reconcile(Some(rule_id)).await?;
+
+        let remaining: Vec<(String, Option<String>)> = sqlx::query_as(
+            r#"
+            SELECT COALESCE(c.name, CAST(t.cluster_id AS TEXT)), t.last_error
+            FROM sql_blacklist_rule_targets t
+            LEFT JOIN clusters c ON c.id = t.cluster_id
+            WHERE t.rule_id = ?
+            "#,
+        )
+        .bind(rule_id)
+        .fetch_all(&self.db)
+        .await?;
+        if !remaining.is_empty() {
+            let failures: Vec<String> = remaining
+                .into_iter()
+                .map(|(cluster, error)| format!("{}: {}", cluster, error.unwrap_or_default()))
+                .collect();
+            return Err(ApiError::internal_error(format!(
+                "Failed to remove the rule from some clusters, it will be retried: {}",
+                failures.join("; ")
+            )));
+        }
+
+        sqlx::query("DELETE FROM sql_blacklist_rules WHERE id = ?")
+            .bind(rule_id)
+            .execute(&self.db)
+            .await?;
+        tracing::info!("SQL blacklist rule {} deleted by {}", rule_id, requester.username);
+        Ok(())
+    }
+
+    /// Push one rule to its clusters now instead of waiting for the next sync round
+    pub async fn sync_rule(
+        &self,
+        rule_id: i64,
+        requester: &SqlBlacklistRequester<'_>,
+    ) -> ApiResult<SqlBlacklistRuleDetail> {
+        let rule = self.load_rule(rule_id, requester).await?;
+        self.reconcile(Some(rule_id)).await?;
+        self.detail(rule).await
+    }
+
+    /// Re-apply missing rules (e.g. after an FE restart) and retire expired ones on all clusters
+    pub async fn sync_all(&self) -> Result<(), anyhow::Error> {
+        self.reconcile(None).await?;
+        Ok(())
+    }
+
+    async fn reconcile(&self, rule_id: Option<i64>) -> ApiResult<()> {
+        let targets: Vec<TargetState> = sqlx::query_as(
+            r#"
+            SELECT t.rule_id, t.cluster_id, t.applied_pattern, t.detached, r.pattern, r.enabled,
+                   r.expires_at
+            FROM sql_blacklist_rule_targets t
+            JOIN sql_blacklist_rules r ON r.id = t.rule_id
+            WHERE ? IS NULL OR t.rule_id = ?
+            "#,
+        )
+        .bind(rule_id)
+        .bind(rule_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        let mut by_cluster: HashMap<i64, Vec<TargetState>> = HashMap::new();
+        for target in targets {
+            by_cluster
+                .entry(target.cluster_id)
+                .or_default()
+                .push(target);
+        }
+
+        for (cluster_id, targets) in by_cluster {
+            let cluster = match self.cluster_service.get_cluster(cluster_id).await {
+                Ok(cluster) => cluster,
+                Err(e) => {
+                    for target in &targets {
+                        self.record_failure(target, &e.to_string()).await?;
+                    }
+                    continue;
+                },
+            };
+            self.reconcile_cluster(&cluster, &targets).await?;
+        }
+        Ok(())
+    }
+
+    async fn reconcile_cluster(&self, cluster: &Cluster, targets: &[TargetState]) -> ApiResult<()> {
+        let adapter = create_adapter(cluster.clone(), Arc::clone(&self.mysql_pool_manager));
+        let mut remote = match adapter.list_sql_blacklist().await {
+            Ok(items) => items,
+            Err(e) => {
+                tracing::warn!("Failed to list SQL blacklist of cluster {}: {}", cluster.name, e);
+                for target in targets {
+                    self.record_failure(target, &e.to_string()).await?;
+                }
+                return Ok(());
+            },
+        };
+
+        for target in targets {
+            let desired = (target.enabled && !target.detached && !is_expired(target.expires_at))
+                .then_some(target.pattern.as_str());
+            let plan = plan_target(desired, target.applied_pattern.as_deref(), &remote);
+
+            let mut result: ApiResult<Option<String>> = Ok(plan.existing_id.clone());
+            for id in &plan.remove_ids {
+                if let Err(e) = adapter.delete_sql_blacklist(id).await {
+                    result = Err(e);
+                    break;
+                }
+            }
+            if result.is_ok()
+                && let Some(pattern) = &plan.add
+            {
+                result = async {
+                    adapter.add_sql_blacklist(pattern).await?;
+                    remote = adapter.list_sql_blacklist().await?;
+                    Ok(remote
+                        .iter()
+                        .find(|item| item.pattern.trim() == pattern)
+                        .map(|item| item.id.clone()))
+                }
+                .await;
+            } else if !plan.remove_ids.is_empty() {
+                remote.retain(|item| !plan.remove_ids.contains(&item.id));
+            }
+
+            match result {
+                Ok(remote_id) => {
+                    if let Some(pattern) = &plan.add {
+                        tracing::info!(
+                            "Applied SQL blacklist rule {} to cluster {}: {}",
+                            target.rule_id,
+                            cluster.name,
+                            pattern
+                        );
+                    }
+                    self.record_success(target, desired, remote_id).await?
+                },
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to sync SQL blacklist rule {} to cluster {}: {}",
+                        target.rule_id,
+                        cluster.name,
+                        e
+                    );
+                    self.record_failure(target, &e.to_string()).await?
+                },
+            }
+        }
+        Ok(())
+    }
+
+    async fn record_success(
+        &self,
+        target: &TargetState,
+        applied: Option<&str>,
+        remote_id: Option<String>,
+    ) -> ApiResult<()> {
+        if applied.is_none() && target.detached {
+            sqlx::query(
+                "DELETE FROM sql_blacklist_rule_targets WHERE rule_id = ? AND cluster_id = ?",
+            )
+            .bind(target.rule_id)
+            .bind(target.cluster_id)
+            .execute(&self.db)
+            .await?;
+            return Ok(());
+        }
+        sqlx::query(
+            r#"
+            UPDATE sql_blacklist_rule_targets
+            SET applied_pattern = ?, remote_id = ?, status = ?, last_error = NULL, synced_at = ?
+            WHERE rule_id = ? AND cluster_id = ?
+            "#,
+        )
+        .bind(applied)
+        .bind(remote_id)
+        .bind(if applied.is_some() { "applied" } else { "removed" })
+        .bind(Utc::now().naive_utc())
+        .bind(target.rule_id)
+        .bind(target.cluster_id)
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    async fn record_failure(&self, target: &TargetState, error: &str) -> ApiResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE sql_blacklist_rule_targets
+            SET status = 'failed', last_error = ?, synced_at = ?
+            WHERE rule_id = ? AND cluster_id = ?
+            "#,
+        )
+        .bind(error)
+        .bind(Utc::now().naive_utc())
+        .bind(target.rule_id)
+        .bind(target.cluster_id)
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    async fn attach_cluster(&self, rule_id: i64, cluster_id: i64) -> ApiResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO sql_blacklist_rule_targets (rule_id, cluster_id, status)
+            VALUES (?, ?, 'pending')
+            ON CONFLICT(rule_id, cluster_id) DO UPDATE SET detached = 0
+            "#,
+        )
+        .bind(rule_id)
+        .bind(cluster_id)
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    /// Target clusters must belong to the requester's organization
+    async fn check_clusters(
+        &self,
+        cluster_ids: &[i64],
+        requester: &SqlBlacklistRequester<'_>,
+    ) -> ApiResult<()> {
+        if cluster_ids.is_empty() {
+            return Err(ApiError::validation_error("At least one cluster is required"));
+        }
+        for cluster_id in cluster_ids {
+            let cluster = self.cluster_service.get_cluster(*cluster_id).await?;
+            if !requester.is_super_admin && cluster.organization_id != requester.organization_id {
+                return Err(ApiError::forbidden(format!(
+                    "Cluster {} does not belong to your organization",
+                    cluster.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    async fn load_rule(
+        &self,
+        rule_id: i64,
+        requester: &SqlBlacklistRequester<'_>,
+    ) -> ApiResult<SqlBlacklistRule> {
+        let rule: SqlBlacklistRule = sqlx::query_as(
+            r#"
+            SELECT id, organization_id, pattern, reason, owner_id, owner_name, expires_at, enabled,
+                   created_at, updated_at
+            FROM sql_blacklist_rules
+            WHERE id = ?
+            "#,
+        )
+        .bind(rule_id)
+        .fetch_optional(&self.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found(format!("SQL blacklist rule {} not found", rule_id)))?;
+
+        if !requester.is_super_admin && rule.organization_id != requester.organization_id {
+            return Err(ApiError::forbidden("You can only manage rules of your organization"));
+        }
+        Ok(rule)
+    }
+
+    async fn detail(&self, rule: SqlBlacklistRule) -> ApiResult<SqlBlacklistRuleDetail> {
+        let targets: Vec<SqlBlacklistRuleTarget> = sqlx::query_as(
+            r#"
+            SELECT t.cluster_id, COALESCE(c.name, '') AS cluster_name, t.remote_id, t.status,
+                   t.last_error, t.synced_at
+            FROM sql_blacklist_rule_targets t
+            LEFT JOIN clusters c ON c.id = t.cluster_id
+            WHERE t.rule_id = ? AND t.detached = 0
+            ORDER BY t.cluster_id
+            "#,
+        )
+        .bind(rule.id)
+        .fetch_all(&self.db)
+        .await?;
+        Ok(SqlBlacklistRuleDetail { expired: is_expired(rule.expires_at), rule, targets })
+    }
+}
+
+impl ScheduledTask for SqlBlacklistService {
+    fn run(&self) -> Pin<Box<dyn Future<Output = Result<(), anyhow::Error>> + Send + '_>> {
+        Box::pin(async move { self.sync_all().await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statement(user: &str, stmt: &str) -> AuditStatement {
+        AuditStatement {
+            query_id: format!("q-{}", stmt.len()),
+            user: user.to_string(),
+            database: "sales".to_string(),
+            timestamp: "2024-05-01 10:00:00".to_string(),
+            stmt: stmt.to_string(),
+        }
+    }
+
+    fn item(id: &str, pattern: &str) -> SqlBlacklistItem {
+        SqlBlacklistItem { id: id.to_string(), pattern: pattern.to_string() }
+    }
+
+    #[test]
+    fn test_preview_groups_matches_by_user() {
+        let regex = compile_blacklist_pattern(r"select \* from orders").unwrap();
+        let statements = vec![
+            statement("etl", "SELECT *\n  FROM orders"),
+            statement("bi", "select * from orders where id = 1"),
+            statement("bi", "SELECT * FROM   ORDERS LIMIT 10"),
+            statement("bi", "select id from orders"),
+        ];
+
+        let (matched, users) = preview_blacklist_pattern(&regex, &statements, 1);
+
+        assert_eq!(matched, 3);
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].user, "bi");
+        assert_eq!(users[0].matched, 2);
+        assert_eq!(users[0].samples.len(), 1);
+        assert!(compile_blacklist_pattern("select (").is_err());
+        assert!(compile_blacklist_pattern("  ").is_err());
+    }
+
+    #[test]
+    fn test_plan_target() {
+        let remote = vec![
+            item("1", "select .* from a"),
+            item("2", "select .* from b"),
+            item("3", r"select \* from t where id = \d+"),
+        ];
+
+        // Missing after an FE restart: add again
+        assert_eq!(
+            plan_target(Some("select .* from c"), Some("select .* from c"), &remote),
+            TargetPlan { add: Some("select .* from c".to_string()), ..Default::default() }
+        );
+        // Pattern changed: drop the old entry, keep the already present new one
+        assert_eq!(
+            plan_target(Some("select .* from b"), Some("select .* from a"), &remote),
+            TargetPlan {
+                remove_ids: vec!["1".to_string()],
+                existing_id: Some("2".to_string()),
+                ..Default::default()
+            }
+        );
+        // Expired / disabled: remove
+        assert_eq!(
+            plan_target(None, Some("select .* from b"), &remote),
+            TargetPlan { remove_ids: vec!["2".to_string()], ..Default::default() }
+        );
+        // Backslash escapes are listed back verbatim: in sync, nothing to add
+        let pattern = r"select \* from t where id = \d+";
+        assert_eq!(
+            plan_target(Some(pattern), Some(pattern), &remote),
+            TargetPlan { existing_id: Some("3".to_string()), ..Default::default() }
+        );
+    }
+
+    #[test]
+    fn test_quote_blacklist_pattern() {
+        assert_eq!(
+            quote_blacklist_pattern(r#"select \* from t where c = "\d""#),
+            r#""select \\* from t where c = \"\\d\"""#
+        );
+    }
+}
@@ -54,6 +54,7 @@ impl UserService {
             organization_id: Option<i64>,
             created_at: DateTime<Utc>,
             updated_at: DateTime<Utc>,
+            language: Option<String>,
             organization_name: Option<String>,
         }
 
@@ -75,6 +76,7 @@ impl UserService {
                     organization_id: user_with_org.organization_id,
                     created_at: user_with_org.created_at,
                     updated_at: user_with_org.updated_at,
+                    language: user_with_org.language,
                 };
                 let roles = roles_map.get(&user.id);
                 self.compose_user_with_org(user, user_with_org.organization_name, roles)
@@ -4,8 +4,10 @@ pub mod handler_helpers;
 pub mod jwt;
 pub mod macros;
 pub mod organization_filter;
+pub mod row_view;
 pub mod scheduled_executor;
 pub mod string_ext;
+pub mod system_databases;
 
 pub use collection_ext::{diff_sets, group_by, unique_ordered, vec_to_map, vec_to_map_with};
 pub use error::{ApiError, ApiResult};
@@ -13,5 +15,6 @@ pub use handler_helpers::{
     check_org_access, check_org_override, check_org_reassignment, get_active_cluster_for_org,
 };
 pub use jwt::JwtUtil;
+pub use row_view::RowView;
 pub use scheduled_executor::{ScheduledExecutor, ScheduledTask};
 pub use string_ext::{clean_optional_string, trim_string, StringExt};
@@ -0,0 +1,44 @@
+//! Row accessor for untyped result sets
+//!
+//! SHOW statements and information_schema views differ in column naming between StarRocks,
+//! Doris and their versions, so values are looked up by a list of aliases.
+
+use std::collections::HashMap;
+
+/// Row accessor resolving the first matching column alias (case-insensitive)
+pub struct RowView<'a> {
+    index: HashMap<String, usize>,
+    row: &'a [String],
+}
+
+impl<'a> RowView<'a> {
+    pub fn new(columns: &[String], row: &'a [String]) -> Self {
+        let index = columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.to_lowercase(), i))
+            .collect();
+        Self { index, row }
+    }
+
+    pub fn get(&self, aliases: &[&str]) -> Option<&'a str> {
+        aliases
+            .iter()
+            .filter_map(|a| self.index.get(&a.to_lowercase()))
+            .filter_map(|i| self.row.get(*i))
+            .map(|s| s.as_str())
+            .find(|s| !s.is_empty() && !s.eq_ignore_ascii_case("NULL") && *s != "N/A")
+    }
+
+    pub fn string(&self, aliases: &[&str]) -> String {
+        self.get(aliases).unwrap_or_default().to_string()
+    }
+
+    pub fn int(&self, aliases: &[&str]) -> Option<i64> {
+        self.get(aliases).and_then(|v| v.parse().ok())
+    }
+
+    pub fn float(&self, aliases: &[&str]) -> Option<f64> {
+        self.get(aliases).and_then(|v| v.parse().ok())
+    }
+}
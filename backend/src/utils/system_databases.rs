@@ -0,0 +1,34 @@
+//! Built-in databases of StarRocks and Doris
+//!
+//! Cluster-wide scans (partitions, load jobs) skip these so only user data is listed.
+
+use crate::services::MySQLClient;
+use crate::utils::ApiResult;
+
+/// Databases managed by the engine itself, compared in lower case
+pub const SYSTEM_DATABASES: [&str; 6] = [
+    "information_schema",
+    "_statistics_",
+    "starrocks_audit_db__",
+    "__internal_schema",
+    "sys",
+    "mysql",
+];
+
+pub fn is_system_database(name: &str) -> bool {
+    SYSTEM_DATABASES.contains(&name.to_lowercase().as_str())
+}
+
+/// `SHOW DATABASES` without the system databases
+pub async fn list_user_databases(client: &MySQLClient) -> ApiResult<Vec<String>> {
+    let (columns, rows) = client.query_raw("SHOW DATABASES").await?;
+    let idx = columns
+        .iter()
+        .position(|c| c.eq_ignore_ascii_case("Database"))
+        .unwrap_or(0);
+    Ok(rows
+        .into_iter()
+        .filter_map(|r| r.get(idx).cloned())
+        .filter(|db| !is_system_database(db))
+        .collect())
+}
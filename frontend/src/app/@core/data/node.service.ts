@@ -461,8 +461,13 @@ export class NodeService {
     return this.api.get<ProfileDetail>(`/clusters/profiles/${queryId}`);
   }
 
-  analyzeProfile(queryId: string): Observable<ProfileAnalysisResult> {
-    return this.api.get<ProfileAnalysisResult>(`/clusters/profiles/${queryId}/analyze`);
+  /**
+   * @param cardinality Also compare the optimizer's estimates with actual rows.
+   * This re-runs `EXPLAIN COSTS` of the profiled SQL on the cluster.
+   */
+  analyzeProfile(queryId: string, cardinality = false): Observable<ProfileAnalysisResult> {
+    const params = cardinality ? { cardinality: true } : {};
+    return this.api.get<ProfileAnalysisResult>(`/clusters/profiles/${queryId}/analyze`, params);
   }
 
   /**